@@ -148,10 +148,16 @@ impl FromStr for PoolSize {
 
 /// Configuration for the HashQL compiler and execution pool.
 #[derive(Debug, Clone, Parser)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "the field names are the command line flags"
+)]
 pub struct CompilerConfig {
     /// Number of retained heap/scratch instances in the compiler memory pool.
     ///
-    /// Set to 0 for an unbounded pool that grows without limit.
+    /// Compiled queries are cached with the heap they were compiled into, at most this many
+    /// queries are cached. Set to 0 for an unbounded pool that grows without limit, which disables
+    /// caching of compiled queries.
     #[clap(
         long,
         default_value = "16",
@@ -165,6 +171,27 @@ pub struct CompilerConfig {
     /// of available CPU cores.
     #[clap(long, default_value = "0", env = "HASH_GRAPH_COMPILER_EXEC_POOL_SIZE")]
     pub compiler_exec_pool_size: PoolSize,
+
+    /// Maximum number of steps the interpreter may execute for a single query.
+    ///
+    /// Counts the steps of the filters and closures evaluated for every row read by the query as
+    /// well. Unlimited if not set.
+    #[clap(long, env = "HASH_GRAPH_COMPILER_FUEL")]
+    pub compiler_fuel: Option<u64>,
+
+    /// Maximum number of bytes the interpreter may allocate for values of a single query.
+    ///
+    /// The bytes are estimated from the values built, and are not released once a value is
+    /// dropped, so this bounds the total allocation rather than the memory in use at any time.
+    /// Unlimited if not set.
+    #[clap(long, env = "HASH_GRAPH_COMPILER_MEMORY_LIMIT")]
+    pub compiler_memory_limit: Option<usize>,
+
+    /// Maximum time the interpreter may spend executing a single query (in milliseconds).
+    ///
+    /// Includes the time spent waiting for the database. Unlimited if not set.
+    #[clap(long, env = "HASH_GRAPH_COMPILER_TIME_LIMIT")]
+    pub compiler_time_limit_ms: Option<u64>,
 }
 
 /// Configuration for Kratos session authentication.
@@ -647,10 +674,20 @@ pub async fn server(mut args: ServerArgs) -> Result<(), Report<GraphError>> {
         None
     };
 
-    let compiler = Arc::new(CompilerContext::new(
-        args.config.compiler.compiler_memory_pool_size.as_usize(),
-        args.config.compiler.compiler_exec_pool_size.get(),
-    ));
+    let compiler = Arc::new(
+        CompilerContext::new(
+            args.config.compiler.compiler_memory_pool_size.as_usize(),
+            args.config.compiler.compiler_exec_pool_size.get(),
+        )
+        .with_runtime_limits(
+            args.config.compiler.compiler_fuel,
+            args.config.compiler.compiler_memory_limit,
+            args.config
+                .compiler
+                .compiler_time_limit_ms
+                .map(Duration::from_millis),
+        ),
+    );
 
     if let Err(error) = start_server(
         pool,
@@ -0,0 +1,267 @@
+//! Cache of compiled HashQL queries.
+//!
+//! A compiled query borrows from the heap it was compiled into, so it cannot be stored on its
+//! own. Instead, every cached query is owned by a task on the compiler pool, which keeps the heap
+//! alive and executes the query whenever it receives an [`Execution`]. The cache only stores the
+//! channel to that task: evicting a query closes the channel, after which the task finishes the
+//! executions in flight and returns its heap to the pool.
+//!
+//! The caches of all threads together hold at most as many queries as the heap pool retains
+//! heaps, as every cached query holds on to the heap it was compiled into. Once the cache is full,
+//! a thread evicts its own least recently used query to cache another one, a thread without cached
+//! queries does not cache any until the other threads evicted theirs.
+//!
+//! Inputs are decoded without interning into the heap of the query, but executing a query may
+//! still allocate into its heap, for example decoding the properties of an entity interns their
+//! keys, and these allocations are only freed once the heap is reset. A task therefore evicts its
+//! own query once the heap grew to more than twice its size after compilation, so that the next
+//! execution compiles the query into a fresh heap.
+//!
+//! Queries are cached by their exact text, as the spans of the compiled query, and with them the
+//! diagnostics of its executions, refer to the text it was compiled from.
+//!
+//! Tasks cannot move between the threads of the compiler pool, so every thread keeps a cache of
+//! its own, and a query is compiled on every thread it is executed on. Executions of the same
+//! query are therefore spread across the pool instead of being confined to a single thread.
+
+use alloc::sync::Arc;
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use hashql_core::{collections::FastHashMap, heap::Heap};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::Execution;
+
+/// Returns whether the query owning `heap` must be evicted, because the heap grew to more than
+/// twice the `compiled` bytes it held once the query was compiled.
+pub(crate) fn outgrown(heap: &Heap, compiled: usize) -> bool {
+    heap.allocated_bytes() > compiled.saturating_mul(2)
+}
+
+struct Entry {
+    sender: UnboundedSender<Execution>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct Entries {
+    map: FastHashMap<Arc<str>, Entry>,
+    clock: u64,
+}
+
+thread_local! {
+    static ENTRIES: RefCell<Entries> = RefCell::default();
+}
+
+/// Compiled queries, keyed by their text.
+///
+/// The entries are kept per thread, the cache must therefore only be accessed from the threads of
+/// the compiler pool.
+pub(crate) struct QueryCache {
+    capacity: usize,
+    /// Number of queries cached across all threads.
+    cached: AtomicUsize,
+}
+
+impl QueryCache {
+    /// Creates a cache which holds at most `capacity` queries across all threads, `0` disables
+    /// caching.
+    pub(crate) const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            cached: AtomicUsize::new(0),
+        }
+    }
+
+    /// Removes the queries of `map`, whose task stopped.
+    fn prune_entries(&self, map: &mut FastHashMap<Arc<str>, Entry>) {
+        let len = map.len();
+        map.retain(|_, entry| !entry.sender.is_closed());
+
+        self.cached.fetch_sub(len - map.len(), Ordering::Relaxed);
+    }
+
+    /// Removes the queries cached on the current thread, whose task stopped.
+    ///
+    /// Called by a task once it stopped, so that its query no longer counts towards the capacity
+    /// of the cache.
+    pub(crate) fn prune(&self) {
+        ENTRIES.with_borrow_mut(|Entries { map, clock: _ }| self.prune_entries(map));
+    }
+
+    /// Returns the channel to the task owning the compiled `query`, if it is cached on the current
+    /// thread.
+    pub(crate) fn get(&self, query: &str) -> Option<UnboundedSender<Execution>> {
+        ENTRIES.with_borrow_mut(|Entries { map, clock }| {
+            let entry = map.get_mut(query)?;
+            if entry.sender.is_closed() {
+                // The task owning the query stopped in the meantime
+                map.remove(query);
+                self.cached.fetch_sub(1, Ordering::Relaxed);
+                return None;
+            }
+
+            *clock += 1;
+            entry.last_used = *clock;
+
+            Some(entry.sender.clone())
+        })
+    }
+
+    /// Caches the channel to the task owning the compiled `query` on the current thread.
+    ///
+    /// If the cache is full, the least recently used query of the current thread is evicted. If
+    /// the current thread does not cache any query, the query is not cached, dropping `sender`.
+    pub(crate) fn insert(&self, query: Arc<str>, sender: UnboundedSender<Execution>) {
+        ENTRIES.with_borrow_mut(|Entries { map, clock }| {
+            self.prune_entries(map);
+            *clock += 1;
+
+            if let Some(entry) = map.get_mut(&query) {
+                // Replaces the query compiled concurrently by another request
+                entry.sender = sender;
+                entry.last_used = *clock;
+                return;
+            }
+
+            let reserved = self
+                .cached
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |cached| {
+                    (cached < self.capacity).then_some(cached + 1)
+                })
+                .is_ok();
+
+            if !reserved {
+                // The evicted query hands its place over to `query`
+                let Some(evicted) = map
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(query, _)| Arc::clone(query))
+                else {
+                    return;
+                };
+
+                map.remove(&evicted);
+            }
+
+            map.insert(
+                query,
+                Entry {
+                    sender,
+                    last_used: *clock,
+                },
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = QueryCache::new(2);
+
+        let (first, _first) = mpsc::unbounded_channel();
+        let (second, _second) = mpsc::unbounded_channel();
+        let (third, _third) = mpsc::unbounded_channel();
+
+        cache.insert(Arc::from("first"), first);
+        cache.insert(Arc::from("second"), second);
+        assert!(cache.get("first").is_some());
+
+        cache.insert(Arc::from("third"), third);
+        assert!(cache.get("first").is_some());
+        assert!(cache.get("second").is_none());
+        assert!(cache.get("third").is_some());
+    }
+
+    #[test]
+    fn removes_closed_entries() {
+        let cache = QueryCache::new(1);
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        cache.insert(Arc::from("a"), sender);
+        assert!(cache.get("a").is_some());
+
+        drop(receiver);
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let cache = QueryCache::new(0);
+
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        cache.insert(Arc::from("a"), sender);
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn keys_are_exact() {
+        let cache = QueryCache::new(1);
+
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        cache.insert(Arc::from(r#"["input", "a"]"#), sender);
+        assert!(cache.get(r#"["input", "a"]"#).is_some());
+        assert!(cache.get(r#"["input","a"]"#).is_none());
+    }
+
+    #[test]
+    fn outgrown_heap() {
+        let heap = Heap::new();
+        let compiled = heap.allocated_bytes();
+        assert!(!outgrown(&heap, compiled));
+
+        heap.intern_symbol(&"a".repeat(compiled + 1));
+        assert!(outgrown(&heap, compiled));
+    }
+
+    #[test]
+    fn entries_are_per_thread() {
+        let cache = QueryCache::new(2);
+
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        cache.insert(Arc::from("a"), sender);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| assert!(cache.get("a").is_none()));
+        });
+        assert!(cache.get("a").is_some());
+    }
+
+    #[test]
+    fn capacity_is_shared_across_threads() {
+        let cache = QueryCache::new(1);
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        cache.insert(Arc::from("a"), sender);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                // The other thread holds the only place in the cache
+                let (sender, _receiver) = mpsc::unbounded_channel();
+                cache.insert(Arc::from("b"), sender);
+                assert!(cache.get("b").is_none());
+            });
+        });
+
+        // The task owning `a` stopped, releasing its place
+        drop(receiver);
+        cache.prune();
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let (sender, _receiver) = mpsc::unbounded_channel();
+                cache.insert(Arc::from("b"), sender);
+                assert!(cache.get("b").is_some());
+            });
+        });
+    }
+}
@@ -1,10 +1,11 @@
 use hashql_ast::error::AstDiagnosticCategory;
 use hashql_core::{
+    collections::FastHashMap,
     heap::{Heap, ResetAllocator as _, Scratch},
     module::ModuleRegistry,
     span::{SpanId, SpanTable},
-    symbol::sym,
-    r#type::environment::Environment,
+    symbol::{Symbol, sym},
+    r#type::{TypeId, environment::Environment},
 };
 use hashql_diagnostics::{DiagnosticIssues, IntoStatus as _, Status, StatusExt as _, Success};
 use hashql_eval::{
@@ -37,6 +38,9 @@ pub(crate) struct Compilation<'heap> {
     pub interner: hashql_eval::intern::Interner<'heap>,
     pub env: Environment<'heap>,
 
+    /// The type of every input the query loads.
+    pub inputs: FastHashMap<Symbol<'heap>, TypeId>,
+
     pub entrypoint: DefId,
     pub artifact: CodeCompilationArtifact<'heap>,
 }
@@ -89,7 +93,7 @@ impl<'heap> Compilation<'heap> {
 
         // Lower the HIR
         let Success {
-            value: hir,
+            value: (hir, residual),
             advisories,
         } = hashql_hir::lower::lower(hir, &types, &mut env, &mut hir_context)
             .map_category(|category| {
@@ -170,6 +174,7 @@ impl<'heap> Compilation<'heap> {
             root_span,
             env,
             interner,
+            inputs: residual.inputs,
             entrypoint,
             artifact: CodeCompilationArtifact {
                 assignment: execution,
@@ -34,6 +34,7 @@ pub(crate) enum HashQlDiagnosticCategory {
     Hir(HirDiagnosticCategory),
     Mir(MirDiagnosticCategory),
     Eval(EvalDiagnosticCategory),
+    Input(&'static TerminalDiagnosticCategory),
     Infrastructure,
 }
 
@@ -62,6 +63,7 @@ impl DiagnosticCategory for HashQlDiagnosticCategory {
             Self::Hir(hir) => Some(hir),
             Self::Mir(mir) => Some(mir),
             Self::Eval(eval) => Some(eval),
+            Self::Input(input) => Some(*input),
             Self::Infrastructure => Some(&INFRASTRUCTURE_CATEGORY),
         }
     }
@@ -97,52 +99,67 @@ impl PointerSpan {
     }
 }
 
+/// Serializes the value of a successful query, together with its advisories.
+pub(crate) fn success_to_response<T: serde::Serialize>(
+    Success { value, advisories }: Success<T, HashQlDiagnosticCategory, SpanId>,
+    spans: &SpanTable<Span>,
+) -> BoxedResponse {
+    let advisories = advisories.map_spans(|span| PointerSpan::resolve(span, spans));
+
+    Json(Success { value, advisories }).into_response().into()
+}
+
+pub(crate) fn failure_to_response(
+    Failure { primary, secondary }: Failure<HashQlDiagnosticCategory, SpanId>,
+    sources: &Sources<'_>,
+    mut spans: &SpanTable<Span>,
+    options: &CompilationOutputOptions,
+) -> BoxedResponse {
+    let severity = primary.severity;
+    let status_code = if severity == Critical::ERROR {
+        StatusCode::BAD_REQUEST
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    let mut response = if options.interactive {
+        let mut diagnostics = secondary.generalize();
+        diagnostics.insert_front(primary.generalize());
+
+        let output = diagnostics.render(RenderOptions::new(Format::Html, sources), &mut spans);
+        Html(output).into_response()
+    } else {
+        Json(Failure {
+            primary: Box::new(primary.map_spans(|span| PointerSpan::resolve(span, spans))),
+            secondary: secondary.map_spans(|span| PointerSpan::resolve(span, spans)),
+        })
+        .into_response()
+    };
+
+    *response.status_mut() = status_code;
+    response.into()
+}
+
 pub(crate) fn status_to_response(
     status: Status<OwnedValue, HashQlDiagnosticCategory, SpanId>,
     sources: &Sources<'_>,
-    mut spans: &SpanTable<Span>,
+    spans: &SpanTable<Span>,
     options: &CompilationOutputOptions,
 ) -> BoxedResponse {
     match status {
         Ok(Success { value, advisories }) => {
-            let advisories = advisories.map_spans(|span| PointerSpan::resolve(span, spans));
-
             if options.json_compat {
-                Json(Success {
-                    value: JsonValueSerialize(&value),
-                    advisories,
-                })
-                .into_response()
-                .into()
+                success_to_response(
+                    Success {
+                        value: JsonValueSerialize(&value),
+                        advisories,
+                    },
+                    spans,
+                )
             } else {
-                Json(Success { value, advisories }).into_response().into()
+                success_to_response(Success { value, advisories }, spans)
             }
         }
-        Err(Failure { primary, secondary }) => {
-            let severity = primary.severity;
-            let status_code = if severity == Critical::ERROR {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-
-            let mut response = if options.interactive {
-                let mut diagnostics = secondary.generalize();
-                diagnostics.insert_front(primary.generalize());
-
-                let output =
-                    diagnostics.render(RenderOptions::new(Format::Html, sources), &mut spans);
-                Html(output).into_response()
-            } else {
-                Json(Failure {
-                    primary: Box::new(primary.map_spans(|span| PointerSpan::resolve(span, spans))),
-                    secondary: secondary.map_spans(|span| PointerSpan::resolve(span, spans)),
-                })
-                .into_response()
-            };
-
-            *response.status_mut() = status_code;
-            response.into()
-        }
+        Err(failure) => failure_to_response(failure, sources, spans, options),
     }
 }
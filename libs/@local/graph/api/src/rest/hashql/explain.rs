@@ -0,0 +1,112 @@
+//! Explain mode for HashQL queries.
+//!
+//! An explained query responds with how it is executed instead of its result: the target every
+//! block of a filter has been placed on together with its estimated cost, the islands the filters
+//! have been partitioned into, and the generated SQL with its parameters. With `analyze`, the
+//! query is executed as well, and the timings of the execution are included.
+
+use std::collections::HashMap;
+
+use axum::response::IntoResponse as _;
+use hashql_core::span::{SpanId, SpanTable};
+use hashql_diagnostics::{DiagnosticIssues, Status, Success, severity::Advisory};
+use hashql_eval::{
+    explain::{Analysis, Explain},
+    orchestrator::TimedEventLog,
+};
+use hashql_syntax_jexpr::span::Span;
+
+use super::{
+    ExecutionContext, compile::Compilation, error::HashQlDiagnosticCategory, run_compiled,
+};
+use crate::rest::status::BoxedResponse;
+
+/// The representation of an explained query.
+///
+/// `json` responds with a structured plan, `text` with the MIR of the query annotated with the
+/// placement of every block, followed by the islands, the SQL and the timings.
+#[derive(Debug, Copy, Clone, Default, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ExplainFormat {
+    #[default]
+    Json,
+    Text,
+}
+
+/// Options for explaining a query instead of returning its result.
+#[derive(Debug, Copy, Clone, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct ExplainOptions {
+    /// Executes the query and includes the timings of the execution.
+    #[serde(default)]
+    analyze: bool,
+    #[serde(default)]
+    format: ExplainFormat,
+}
+
+/// The explanation of a query, in the requested format.
+pub(crate) enum Explanation {
+    Plan(hashql_eval::explain::Plan),
+    Text(String),
+}
+
+impl Explanation {
+    pub(crate) fn into_response(
+        success: Success<Self, HashQlDiagnosticCategory, SpanId>,
+        spans: &SpanTable<Span>,
+    ) -> BoxedResponse {
+        match success.value {
+            Self::Plan(plan) => super::error::success_to_response(
+                Success {
+                    value: plan,
+                    advisories: success.advisories,
+                },
+                spans,
+            ),
+            Self::Text(text) => text.into_response().into(),
+        }
+    }
+}
+
+/// Explains a compiled query, executing it first if `analyze` is requested.
+#[expect(clippy::future_not_send)]
+pub(crate) async fn explain(
+    compilation: &Compilation<'_>,
+    advisories: DiagnosticIssues<HashQlDiagnosticCategory, SpanId, Advisory>,
+    exec: ExecutionContext,
+    inputs: &HashMap<String, serde_json::Value>,
+    options: ExplainOptions,
+) -> Status<Explanation, HashQlDiagnosticCategory, SpanId> {
+    let mut explain = Explain::new(compilation.context(), &compilation.artifact.postgres);
+
+    let advisories = if options.analyze {
+        let events = TimedEventLog::new();
+        let Success {
+            value: _,
+            advisories,
+        } = run_compiled(compilation, advisories, exec, inputs, &events).await?;
+
+        explain = explain.with_analysis(Analysis {
+            duration: events.elapsed(),
+            events: events.take(),
+        });
+
+        advisories
+    } else {
+        advisories
+    };
+
+    let value = match options.format {
+        ExplainFormat::Json => Explanation::Plan(explain.plan()),
+        ExplainFormat::Text => {
+            let mut output = Vec::new();
+            explain
+                .render(&mut output)
+                .expect("writing to a `Vec` should not fail");
+
+            Explanation::Text(String::from_utf8_lossy(&output).into_owned())
+        }
+    };
+
+    Ok(Success { value, advisories })
+}
@@ -0,0 +1,149 @@
+//! Decoding of the named inputs provided alongside a HashQL query.
+//!
+//! Inputs arrive as plain JSON and are decoded against the types the query declares for them,
+//! using the same type-directed decoder the orchestrator uses for database results.
+
+use alloc::alloc::Global;
+use std::collections::HashMap;
+
+use hashql_core::{
+    collections::FastHashMap,
+    pretty::{Formatter, RenderOptions},
+    span::SpanId,
+    symbol::Symbol,
+    r#type::{TypeFormatter, TypeFormatterOptions, TypeId, environment::Environment},
+};
+use hashql_diagnostics::{
+    Diagnostic, DiagnosticIssues, Label, Message, Status, category::TerminalDiagnosticCategory,
+    severity::Severity,
+};
+use hashql_eval::orchestrator::{
+    codec::{DecodeError, Decoder, JsonValueRef},
+    error::add_decode_error_notes,
+};
+use hashql_mir::interpret::Inputs;
+
+use super::{compile::Compilation, error::HashQlDiagnosticCategory};
+
+const UNKNOWN_INPUT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unknown-input",
+    name: "Unknown Input",
+};
+
+const INPUT_DESERIALIZATION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "input-deserialization",
+    name: "Input Deserialization",
+};
+
+type InputDiagnostic = Diagnostic<HashQlDiagnosticCategory, SpanId>;
+
+fn unknown_input(
+    span: SpanId,
+    name: &str,
+    declared: &FastHashMap<Symbol<'_>, TypeId>,
+) -> InputDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        HashQlDiagnosticCategory::Input(&UNKNOWN_INPUT),
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        format!("query does not declare an input named `{name}`"),
+    ));
+
+    if declared.is_empty() {
+        diagnostic.add_message(Message::help(format!(
+            "the query does not declare any inputs, remove `{name}` from the provided inputs"
+        )));
+    } else {
+        let mut names: Vec<_> = declared.keys().map(|name| format!("`{name}`")).collect();
+        names.sort_unstable();
+
+        diagnostic.add_message(Message::note(format!(
+            "the query declares the inputs {}",
+            names.join(", ")
+        )));
+    }
+
+    diagnostic
+}
+
+fn input_deserialization(
+    span: SpanId,
+    name: &str,
+    r#type: TypeId,
+    source: &DecodeError<'_>,
+    env: &Environment<'_>,
+) -> InputDiagnostic {
+    let fmt = Formatter::new(env.heap);
+    let mut type_fmt = TypeFormatter::new(&fmt, env, TypeFormatterOptions::default());
+
+    let mut diagnostic = Diagnostic::new(
+        HashQlDiagnosticCategory::Input(&INPUT_DESERIALIZATION),
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        format!(
+            "cannot decode input `{name}` as `{}`",
+            type_fmt.render(r#type, RenderOptions::default())
+        ),
+    ));
+
+    add_decode_error_notes(&mut diagnostic, source, env);
+
+    diagnostic
+}
+
+/// Decodes the provided `inputs` against the types the query declares for them.
+///
+/// Every input is decoded, even if a previous one failed, so that all mismatches are reported at
+/// once. Inputs which are declared but not provided are left to the interpreter, which either
+/// falls back to their default or reports them as missing.
+///
+/// # Errors
+///
+/// Returns a diagnostic for every input the query does not declare, and for every input whose
+/// value does not match the declared type.
+pub(crate) fn decode<'heap>(
+    compilation: &Compilation<'heap>,
+    inputs: &HashMap<String, serde_json::Value>,
+) -> Status<Inputs<'heap>, HashQlDiagnosticCategory, SpanId> {
+    // The heap outlives the request if the query is cached, the inputs are therefore decoded
+    // without interning into it.
+    let decoder = Decoder::new(&compilation.env, &compilation.interner, Global).without_interning();
+
+    let mut diagnostics = DiagnosticIssues::new();
+    let mut values = Inputs::with_capacity(inputs.len());
+
+    for (name, value) in inputs {
+        // Names provided by the client are looked up instead of interned for the same reason
+        let Some((&symbol, &r#type)) = compilation
+            .inputs
+            .iter()
+            .find(|(symbol, _)| symbol.as_str() == name)
+        else {
+            diagnostics.push(unknown_input(
+                compilation.root_span,
+                name,
+                &compilation.inputs,
+            ));
+            continue;
+        };
+
+        match decoder.decode(r#type, JsonValueRef::from(value)) {
+            Ok(value) => {
+                values.insert(symbol, value);
+            }
+            Err(error) => diagnostics.push(input_deserialization(
+                compilation.root_span,
+                name,
+                r#type,
+                &error,
+                &compilation.env,
+            )),
+        }
+    }
+
+    diagnostics.into_status(values)
+}
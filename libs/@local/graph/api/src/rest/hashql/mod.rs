@@ -1,19 +1,39 @@
-//! HashQL query endpoint.
+//! HashQL query endpoints.
 //!
 //! Accepts a HashQL query as raw JSON, compiles it through the full pipeline
 //! (parse, type-check, optimize, codegen), executes the generated SQL, and returns
 //! the result. Compilation errors are reported as structured diagnostics with
 //! source spans.
+//!
+//! Queries may declare inputs, whose values are provided by name alongside the query and
+//! decoded against the declared types before execution.
+//!
+//! Compiled queries are cached, so executing a query again, for example with different inputs,
+//! skips compilation. Queries can also be registered under a name, and then be executed by that
+//! name by the actor which registered them.
+//!
+//! Instead of its result, a query can respond with an explanation of how it is executed, see
+//! [`explain`].
 
+mod cache;
 mod compile;
 mod error;
+mod explain;
+mod input;
+mod registry;
 mod value;
 
 use alloc::sync::Arc;
-use core::num::NonZero;
-use std::thread::available_parallelism;
-
-use axum::{Extension, Router, response::IntoResponse as _, routing::post};
+use core::{fmt, future, num::NonZero, task::Poll, time::Duration};
+use std::{collections::HashMap, thread::available_parallelism};
+
+use axum::{
+    Extension, Router,
+    extract::Path,
+    response::IntoResponse as _,
+    routing::{post, put},
+};
+use futures::{StreamExt as _, stream::FuturesUnordered};
 use hash_graph_postgres_store::store::PostgresStorePool;
 use hash_graph_store::pool::StorePool as _;
 use hash_temporal_client::TemporalClient;
@@ -22,29 +42,47 @@ use hashql_core::{
     span::{SpanId, SpanTable},
 };
 use hashql_diagnostics::{
-    Diagnostic, IntoStatus as _, Label, Message, Source, Sources, Status, StatusExt as _, Success,
-    severity::Critical,
+    Diagnostic, DiagnosticIssues, Failure, IntoStatus as _, Label, Message, Source, Sources,
+    Status, StatusExt as _, Success,
+    severity::{Advisory, Critical},
+};
+use hashql_eval::{
+    error::EvalDiagnosticCategory,
+    orchestrator::{EventLog, Orchestrator, StoredEmbeddings},
 };
-use hashql_eval::{error::EvalDiagnosticCategory, orchestrator::Orchestrator};
-use hashql_mir::interpret::Inputs;
+use hashql_mir::interpret::{CancellationToken, RuntimeConfig};
 use hashql_syntax_jexpr::span::Span;
 use http::StatusCode;
 use serde_json::value::RawValue;
+use tokio::sync::{
+    mpsc::{self, UnboundedSender, error::SendError},
+    oneshot,
+};
 use tokio_util::task::LocalPoolHandle;
 use utoipa::OpenApi;
 
 use self::{
+    cache::QueryCache,
     compile::Compilation,
-    error::{HashQlDiagnosticCategory, status_to_response},
+    error::{HashQlDiagnosticCategory, failure_to_response, status_to_response},
+    explain::{ExplainFormat, ExplainOptions, Explanation},
+    registry::{MAX_QUERIES_PER_ACTOR, QueryRegistry},
     value::OwnedValue,
 };
-use crate::rest::{InteractiveHeader, JsonCompatHeader, json::Json, status::BoxedResponse};
+use crate::rest::{
+    self, AuthenticatedActorId, InteractiveHeader, JsonCompatHeader, json::Json,
+    status::BoxedResponse,
+};
 
 /// Shared resources for HashQL query compilation and execution, created once at server startup.
 pub struct CompilerContext {
     pub scratches: ScratchPool,
     pub heaps: HeapPool,
     pub pool: LocalPoolHandle,
+
+    cache: QueryCache,
+    registry: QueryRegistry,
+    runtime: RuntimeConfig,
 }
 
 impl CompilerContext {
@@ -52,6 +90,9 @@ impl CompilerContext {
     ///
     /// `memory_pool_size` bounds the heap and scratch pools; `None` leaves them unbounded.
     /// `exec_pool_size` sets the thread count; `None` uses the number of available CPU cores.
+    ///
+    /// Every cached query holds on to a heap, the compiled queries cached across all threads are
+    /// therefore bounded by `memory_pool_size` as well. An unbounded pool disables caching.
     pub fn new(memory_pool_size: Option<usize>, exec_pool_size: Option<NonZero<usize>>) -> Self {
         let scratches = memory_pool_size.map_or_else(ScratchPool::new, ScratchPool::bounded);
         let heaps = memory_pool_size.map_or_else(HeapPool::new, HeapPool::bounded);
@@ -65,14 +106,54 @@ impl CompilerContext {
             scratches,
             heaps,
             pool,
+            cache: QueryCache::new(memory_pool_size.unwrap_or(0)),
+            registry: QueryRegistry::default(),
+            runtime: RuntimeConfig::default(),
         }
     }
+
+    /// Limits the resources the interpreter may use to execute a single query.
+    ///
+    /// `fuel` bounds the number of executed steps, `memory_limit` the number of bytes allocated
+    /// for values and `time_limit` the wall-clock time of the execution; `None` leaves the
+    /// respective resource unlimited. The limits apply to each execution as a whole, including the
+    /// filters and closures evaluated for every row it reads.
+    #[must_use]
+    pub const fn with_runtime_limits(
+        mut self,
+        fuel: Option<u64>,
+        memory_limit: Option<usize>,
+        time_limit: Option<Duration>,
+    ) -> Self {
+        self.runtime = RuntimeConfig {
+            fuel,
+            memory_limit,
+            time_limit,
+            ..self.runtime
+        };
+        self
+    }
 }
 
-/// Per-request database context.
-struct ExecutionContext {
+/// Per-request execution context.
+pub(crate) struct ExecutionContext {
     postgres: PostgresStorePool,
     temporal: Option<Arc<TemporalClient>>,
+    runtime: RuntimeConfig,
+    /// Cancelled once the client disconnected, see [`CancelOnDrop`].
+    cancellation: CancellationToken,
+}
+
+/// Cancels the execution of a query once dropped.
+///
+/// Axum drops the future handling a request once the client disconnects, dropping the guard with
+/// it, which stops the interpreter executing the query.
+struct CancelOnDrop(CancellationToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
 }
 
 /// Controls the response format for a HashQL query.
@@ -81,29 +162,81 @@ pub(crate) struct CompilationOutputOptions {
     pub interactive: bool,
     /// Serialize the result as plain JSON values, stripping HashQL-specific type wrappers.
     pub json_compat: bool,
+    /// Respond with an explanation of how the query is executed instead of its result.
+    pub explain: Option<ExplainOptions>,
 }
 
-/// Compiles and executes a HashQL query, returning the result as a [`Status`].
-#[expect(clippy::future_not_send)]
-async fn query_local_impl(
-    ctx: Arc<CompilerContext>,
+/// A request to execute a compiled query.
+pub(crate) struct Execution {
     exec: ExecutionContext,
-    spans: &mut SpanTable<Span>,
-    query: &[u8],
-) -> Status<OwnedValue, HashQlDiagnosticCategory, SpanId> {
-    // Heap and scratch must be created inside this function because `spawn_pinned` requires
-    // `'static`. Moving them across the spawn boundary isn't possible since they borrow from
-    // the pool guards.
-    let mut scratch = ctx.scratches.get();
-    let heap = ctx.heaps.get();
+    inputs: HashMap<String, serde_json::Value>,
+    options: CompilationOutputOptions,
+    reply: oneshot::Sender<BoxedResponse>,
+}
+
+/// The request which caused a query to be compiled.
+enum Request {
+    /// Only compiles the query, replying with the diagnostics if compilation failed.
+    Prepare {
+        options: CompilationOutputOptions,
+        reply: oneshot::Sender<Result<(), BoxedResponse>>,
+    },
+    Execute(Execution),
+}
 
-    let inputs = Inputs::new(); // TODO: https://linear.app/hash/issue/BE-41/hashql-expose-input-in-graph-api
+impl Request {
+    /// Hands the request to the task owning the compiled query.
+    ///
+    /// Returns the request if the task stopped in the meantime.
+    fn forward(self, sender: &UnboundedSender<Execution>) -> Option<Self> {
+        match self {
+            Self::Prepare { options: _, reply } => {
+                let _: Result<(), _> = reply.send(Ok(()));
+                None
+            }
+            Self::Execute(execution) => sender
+                .send(execution)
+                .err()
+                .map(|SendError(execution)| Self::Execute(execution)),
+        }
+    }
+
+    /// Answers the request with the diagnostics of the failed compilation.
+    fn reject(
+        self,
+        failure: Failure<HashQlDiagnosticCategory, SpanId>,
+        sources: &Sources<'_>,
+        spans: &SpanTable<Span>,
+    ) {
+        // The client may have disconnected in the meantime, in which case the response is dropped
+        match self {
+            Self::Prepare { options, reply } => {
+                let response = status_to_response(Err(failure), sources, spans, &options);
+                let _: Result<(), _> = reply.send(Err(response));
+            }
+            Self::Execute(Execution { options, reply, .. }) => {
+                let response = status_to_response(Err(failure), sources, spans, &options);
+                let _: Result<(), _> = reply.send(response);
+            }
+        }
+    }
+}
 
+/// Executes a compiled query, returning the result as a [`Status`].
+///
+/// The orchestrator reports the steps of the execution to `events`.
+#[expect(clippy::future_not_send)]
+async fn run_compiled(
+    compilation: &Compilation<'_>,
+    advisories: DiagnosticIssues<HashQlDiagnosticCategory, SpanId, Advisory>,
+    exec: ExecutionContext,
+    inputs: &HashMap<String, serde_json::Value>,
+    events: impl EventLog,
+) -> Status<OwnedValue, HashQlDiagnosticCategory, SpanId> {
     let Success {
-        value: compilation,
+        value: inputs,
         advisories,
-    } = Compilation::compile(&heap, &mut scratch, spans, query)?;
-    drop(scratch);
+    } = input::decode(compilation, inputs).with_diagnostics(advisories)?;
 
     let context = compilation.context();
 
@@ -131,7 +264,13 @@ async fn query_local_impl(
         .into_status()
         .with_diagnostics(advisories)?;
 
-    let orchestrator = Orchestrator::new(client, &compilation.artifact.postgres, &context);
+    // The embeddings are read through the same connection as the entities, which is safe because
+    // the orchestrator only requests them once all rows of a graph read have been received
+    let orchestrator = Orchestrator::new(&client, &compilation.artifact.postgres, &context)
+        .with_embeddings(StoredEmbeddings::new(&client))
+        .with_runtime_config(exec.runtime)
+        .with_cancellation(exec.cancellation)
+        .with_event_log(events);
     orchestrator
         .run(&inputs, compilation.entrypoint, [])
         .await
@@ -143,61 +282,249 @@ async fn query_local_impl(
         .map_value(OwnedValue::from)
 }
 
+/// Executes a compiled query and replies with the response.
 #[expect(clippy::future_not_send)]
-async fn query_local(
-    ctx: Arc<CompilerContext>,
-    exec: ExecutionContext,
-    query: Arc<RawValue>,
-    options: CompilationOutputOptions,
-) -> BoxedResponse {
+async fn execute(
+    compilation: &Compilation<'_>,
+    advisories: &DiagnosticIssues<HashQlDiagnosticCategory, SpanId, Advisory>,
+    sources: &Sources<'_>,
+    spans: &SpanTable<Span>,
+    Execution {
+        exec,
+        inputs,
+        options,
+        reply,
+    }: Execution,
+) {
+    if exec.cancellation.is_cancelled() {
+        // The client disconnected before the execution started
+        return;
+    }
+
+    let response = if let Some(explain) = options.explain {
+        match explain::explain(compilation, advisories.clone(), exec, &inputs, explain).await {
+            Ok(success) => Explanation::into_response(success, spans),
+            Err(failure) => failure_to_response(failure, sources, spans, &options),
+        }
+    } else {
+        let status = run_compiled(compilation, advisories.clone(), exec, &inputs, ()).await;
+        status_to_response(status, sources, spans, &options)
+    };
+
+    // The client may have disconnected in the meantime, in which case the response is dropped
+    let _: Result<(), _> = reply.send(response);
+}
+
+/// Compiles a query and executes it on request, until it is evicted from the cache.
+///
+/// The compiled query borrows from the heap owned by this task, which is why the task, rather
+/// than the cache, holds on to it.
+#[expect(clippy::future_not_send)]
+#[expect(
+    clippy::significant_drop_tightening,
+    reason = "the compiled query borrows from the heap"
+)]
+async fn serve(ctx: Arc<CompilerContext>, query: Arc<str>, request: Request) {
+    // Heap and scratch must be created inside this function because `spawn_pinned` requires
+    // `'static`. Moving them across the spawn boundary isn't possible since they borrow from
+    // the pool guards.
+    let mut scratch = ctx.scratches.get();
+    let heap = ctx.heaps.get();
+
     let mut sources = Sources::new();
-    let source_id = sources.push(Source::new(query.get()));
+    let source_id = sources.push(Source::new(&*query));
 
     let mut spans = SpanTable::new(source_id);
 
-    let status = query_local_impl(ctx, exec, &mut spans, query.get().as_bytes()).await;
-    status_to_response(status, &sources, &spans, &options)
+    let status = Compilation::compile(&heap, &mut scratch, &mut spans, query.as_bytes());
+    drop(scratch);
+
+    let Success {
+        value: compilation,
+        advisories,
+    } = match status {
+        Ok(success) => success,
+        Err(failure) => {
+            // Failed compilations are not cached
+            request.reject(failure, &sources, &spans);
+            return;
+        }
+    };
+
+    let compiled = heap.allocated_bytes();
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    ctx.cache.insert(Arc::clone(&query), sender);
+
+    let mut running = FuturesUnordered::new();
+
+    match request {
+        Request::Prepare { options: _, reply } => {
+            let _: Result<(), _> = reply.send(Ok(()));
+        }
+        Request::Execute(execution) => {
+            running.push(execute(
+                &compilation,
+                &advisories,
+                &sources,
+                &spans,
+                execution,
+            ));
+        }
+    }
+
+    // Executions of the same query run concurrently. Once the query is evicted the channel is
+    // closed, and the task finishes after the remaining executions completed. Executions may
+    // allocate into the heap, which is only reset once the task finishes, so the task evicts its
+    // own query once the heap grew too large.
+    let mut closed = false;
+    future::poll_fn(|cx| {
+        while !closed {
+            match receiver.poll_recv(cx) {
+                Poll::Ready(Some(execution)) => running.push(execute(
+                    &compilation,
+                    &advisories,
+                    &sources,
+                    &spans,
+                    execution,
+                )),
+                Poll::Ready(None) => closed = true,
+                Poll::Pending => break,
+            }
+        }
+
+        while running.poll_next_unpin(cx) == Poll::Ready(Some(())) {}
+
+        if !closed && cache::outgrown(&heap, compiled) {
+            // Poll again to receive the executions which were sent before the channel was closed
+            receiver.close();
+            cx.waker().wake_by_ref();
+        }
+
+        if closed && running.is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+
+    ctx.cache.prune();
+}
+
+/// Answers `request` on the compiler pool, and waits for the `response` to it.
+///
+/// The request is handed to the task owning the compiled query, unless the query is not cached on
+/// the thread the request is dispatched to, in which case the query is compiled first. Returns
+/// `None` if the request was dropped without being answered.
+async fn dispatch<T: Send + 'static>(
+    ctx: &Arc<CompilerContext>,
+    query: Arc<str>,
+    request: Request,
+    response: oneshot::Receiver<T>,
+) -> Option<T> {
+    // The compiler and interpreter hold references into bump-allocated heaps, making their
+    // futures `!Send`. `spawn_pinned` runs them on a dedicated thread, picking the thread with the
+    // fewest requests in flight. The pinned task waits for the response, so that the request counts
+    // towards the load of its thread until it has been answered.
+    let task_ctx = Arc::clone(ctx);
+    ctx.pool
+        .spawn_pinned(move || async move {
+            let request = match task_ctx.cache.get(&query) {
+                Some(sender) => request.forward(&sender),
+                None => Some(request),
+            };
+
+            if let Some(request) = request {
+                // The task outlives the request if the query is cached, so its handle is not
+                // awaited.
+                drop(tokio::task::spawn_local(serve(task_ctx, query, request)));
+            }
+
+            response.await.ok()
+        })
+        .await
+        .ok()
+        .flatten()
 }
 
-/// Spawns a query onto the local thread pool and awaits the response.
+/// The response if the task executing the query stopped without answering.
+fn execution_failed() -> BoxedResponse {
+    tracing::error!("panicked while executing query");
+
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({"fatal": "internal error: query execution failed"})),
+    )
+        .into_response()
+        .into()
+}
+
+/// Executes a query, compiling it first unless it is cached.
 async fn run_query(
     ctx: Arc<CompilerContext>,
+    query: Arc<str>,
     exec: ExecutionContext,
-    query: Arc<RawValue>,
+    inputs: HashMap<String, serde_json::Value>,
     options: CompilationOutputOptions,
 ) -> BoxedResponse {
-    // The compiler and interpreter hold references into bump-allocated heaps, making their
-    // futures `!Send`. `spawn_pinned` runs them on a dedicated thread; the returned handle
-    // is `Send` so the HTTP handler can await it normally.
-    let pool = ctx.pool.clone();
-    let result = pool
-        .spawn_pinned(|| query_local(ctx, exec, query, options))
-        .await;
-
-    result.unwrap_or_else(|error| {
-        tracing::error!(?error, "panicked while executing query");
+    let _cancel = CancelOnDrop(exec.cancellation.clone());
+
+    let (reply, response) = oneshot::channel();
+    let execution = Execution {
+        exec,
+        inputs,
+        options,
+        reply,
+    };
 
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"fatal": "internal error: query execution failed"})),
-        )
-            .into_response()
-            .into()
-    })
+    dispatch(&ctx, query, Request::Execute(execution), response)
+        .await
+        .unwrap_or_else(execution_failed)
 }
 
-fn deserialize_empty_inputs<'de, D>(deserializer: D) -> Result<Vec<()>, D::Error>
+/// Deserializes the values for the inputs of a query, keyed by their name.
+///
+/// Before inputs were supported, `inputs` had to be an empty array. To not break existing clients,
+/// an empty array is still accepted, and treated as if no inputs were provided.
+fn deserialize_inputs<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, serde_json::Value>, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    let inputs: Vec<()> = serde::Deserialize::<'de>::deserialize(deserializer)?;
-    if inputs.is_empty() {
-        return Ok(inputs);
+    struct InputsVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for InputsVisitor {
+        type Value = HashMap<String, serde_json::Value>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an object of input values keyed by their name")
+        }
+
+        fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+        where
+            M: serde::de::MapAccess<'de>,
+        {
+            serde::Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+        }
+
+        fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+        where
+            S: serde::de::SeqAccess<'de>,
+        {
+            if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                return Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Seq,
+                    &"an object of input values keyed by their name, or an empty array",
+                ));
+            }
+
+            Ok(HashMap::new())
+        }
     }
 
-    Err(serde::de::Error::custom(
-        "`inputs` must be an empty array until input support ships",
-    ))
+    deserializer.deserialize_any(InputsVisitor)
 }
 
 /// Request body for the `/hashql` endpoint.
@@ -206,13 +533,15 @@ where
 pub(crate) struct HashQlRequest {
     #[schema(value_type = serde_json::Value)]
     query: Arc<RawValue>,
-    /// Input values for the query. Must be an empty list until input support ships.
-    #[expect(
-        dead_code,
-        reason = "inputs will be required once HashQL input support ships"
-    )]
-    #[serde(deserialize_with = "deserialize_empty_inputs")]
-    inputs: Vec<()>,
+    /// Values for the inputs declared by the query, keyed by their name.
+    ///
+    /// Each value is decoded against the type the query declares for the input. Declared inputs
+    /// which are omitted use their default, if any. For compatibility, an empty array is accepted
+    /// in place of an empty object.
+    #[serde(default, deserialize_with = "deserialize_inputs")]
+    inputs: HashMap<String, serde_json::Value>,
+    /// Respond with an explanation of how the query is executed instead of its result.
+    explain: Option<ExplainOptions>,
 }
 
 #[utoipa::path(
@@ -225,7 +554,7 @@ pub(crate) struct HashQlRequest {
         ("Json-Compat" = Option<bool>, Header, description = "When true, serializes the result as plain JSON values, stripping HashQL-specific type wrappers"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "Query executed successfully"),
+        (status = 200, content_type = "application/json", description = "Query executed successfully, or its explanation if requested"),
         (status = 400, content_type = "application/json", description = "Query compilation or validation error"),
         (status = 500, description = "Internal compiler or database error"),
     )
@@ -241,26 +570,296 @@ pub(crate) async fn query_hashql(
     let exec = ExecutionContext {
         postgres: (*postgres).clone(),
         temporal,
+        runtime: compiler.runtime,
+        cancellation: CancellationToken::new(),
+    };
+
+    let HashQlRequest {
+        query,
+        inputs,
+        explain,
+    } = request;
+
+    let options = CompilationOutputOptions {
+        interactive,
+        json_compat,
+        explain,
+    };
+
+    run_query(compiler, Arc::from(query.get()), exec, inputs, options).await
+}
+
+/// Request body for registering a named HashQL query.
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RegisterHashQlQueryRequest {
+    #[schema(value_type = serde_json::Value)]
+    query: Arc<RawValue>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/hashql/queries/{name}",
+    request_body = RegisterHashQlQueryRequest,
+    tag = "HashQL",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("name" = String, Path, description = "The name to register the query under"),
+        ("Interactive" = Option<bool>, Header, description = "When true, error responses are rendered as HTML instead of JSON"),
+    ),
+    responses(
+        (status = 204, description = "Query compiled and registered successfully"),
+        (status = 400, content_type = "application/json", description = "Query compilation error"),
+        (status = 429, content_type = "application/json", description = "The actor registered the maximum number of queries already"),
+        (status = 500, description = "Internal compiler error"),
+    )
+)]
+pub(crate) async fn register_hashql_query(
+    Extension(compiler): Extension<Arc<CompilerContext>>,
+    AuthenticatedActorId(actor_id): AuthenticatedActorId,
+    InteractiveHeader(interactive): InteractiveHeader,
+    Path(name): Path<String>,
+    Json(request): Json<RegisterHashQlQueryRequest>,
+) -> BoxedResponse {
+    let query = Arc::<str>::from(request.query.get());
+
+    // Compile the query upfront, so that compilation errors are reported on registration
+    let (reply, response) = oneshot::channel();
+    let options = CompilationOutputOptions {
+        interactive,
+        json_compat: false,
+        explain: None,
+    };
+
+    match dispatch(
+        &compiler,
+        Arc::clone(&query),
+        Request::Prepare { options, reply },
+        response,
+    )
+    .await
+    {
+        Some(Ok(())) => {}
+        Some(Err(response)) => return response,
+        None => return execution_failed(),
+    }
+
+    if compiler.registry.register(actor_id, name, query).is_err() {
+        return rest::status::status_to_response(hash_graph_type_defs::error::Status::new(
+            hash_status::StatusCode::ResourceExhausted,
+            Some(format!(
+                "At most {MAX_QUERIES_PER_ACTOR} HashQL queries can be registered, replace a \
+                 registered query instead"
+            )),
+            vec![],
+        ));
+    }
+
+    StatusCode::NO_CONTENT.into_response().into()
+}
+
+/// Request body for executing a named HashQL query.
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExecuteHashQlQueryRequest {
+    /// Values for the inputs declared by the query, keyed by their name.
+    ///
+    /// Each value is decoded against the type the query declares for the input. Declared inputs
+    /// which are omitted use their default, if any.
+    #[serde(default)]
+    inputs: HashMap<String, serde_json::Value>,
+    /// Respond with an explanation of how the query is executed instead of its result.
+    explain: Option<ExplainOptions>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/hashql/queries/{name}",
+    request_body = ExecuteHashQlQueryRequest,
+    tag = "HashQL",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("name" = String, Path, description = "The name the query was registered under"),
+        ("Interactive" = Option<bool>, Header, description = "When true, error responses are rendered as HTML instead of JSON"),
+        ("Json-Compat" = Option<bool>, Header, description = "When true, serializes the result as plain JSON values, stripping HashQL-specific type wrappers"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "Query executed successfully, or its explanation if requested"),
+        (status = 400, content_type = "application/json", description = "Query validation error"),
+        (status = 404, content_type = "application/json", description = "The actor did not register a query under the name"),
+        (status = 500, description = "Internal compiler or database error"),
+    )
+)]
+#[expect(clippy::too_many_arguments, reason = "every argument is an extractor")]
+pub(crate) async fn execute_hashql_query(
+    Extension(compiler): Extension<Arc<CompilerContext>>,
+    Extension(postgres): Extension<Arc<PostgresStorePool>>,
+    Extension(temporal): Extension<Option<Arc<TemporalClient>>>,
+    AuthenticatedActorId(actor_id): AuthenticatedActorId,
+    InteractiveHeader(interactive): InteractiveHeader,
+    JsonCompatHeader(json_compat): JsonCompatHeader,
+    Path(name): Path<String>,
+    Json(request): Json<ExecuteHashQlQueryRequest>,
+) -> BoxedResponse {
+    let Some(query) = compiler.registry.get(actor_id, &name) else {
+        return rest::status::status_to_response(hash_graph_type_defs::error::Status::new(
+            hash_status::StatusCode::NotFound,
+            Some(format!("No HashQL query is registered under `{name}`")),
+            vec![],
+        ));
+    };
+
+    let exec = ExecutionContext {
+        postgres: (*postgres).clone(),
+        temporal,
+        runtime: compiler.runtime,
+        cancellation: CancellationToken::new(),
     };
 
+    let ExecuteHashQlQueryRequest { inputs, explain } = request;
+
     let options = CompilationOutputOptions {
         interactive,
         json_compat,
+        explain,
     };
 
-    run_query(compiler, exec, request.query, options).await
+    run_query(compiler, query, exec, inputs, options).await
 }
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(query_hashql),
-    components(schemas(HashQlRequest)),
+    paths(query_hashql, register_hashql_query, execute_hashql_query),
+    components(schemas(
+        HashQlRequest,
+        RegisterHashQlQueryRequest,
+        ExecuteHashQlQueryRequest,
+        ExplainOptions,
+        ExplainFormat,
+    )),
     tags((name = "HashQL", description = "HashQL query execution API"))
 )]
 pub(crate) struct HashQlResource;
 
 impl HashQlResource {
     pub(crate) fn routes() -> Router {
-        Router::new().route("/hashql", post(query_hashql))
+        Router::new().route("/hashql", post(query_hashql)).route(
+            "/hashql/queries/{name}",
+            put(register_hashql_query).post(execute_hashql_query),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(request: &str) -> Result<HashMap<String, serde_json::Value>, String> {
+        serde_json::from_str::<HashQlRequest>(request)
+            .map(|request| request.inputs)
+            .map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn inputs_keyed_by_name() {
+        assert_eq!(
+            inputs(r#"{"query": [], "inputs": {"limit": 10}}"#),
+            Ok(HashMap::from([("limit".to_owned(), serde_json::json!(10))]))
+        );
+    }
+
+    #[test]
+    fn inputs_default_to_empty() {
+        assert_eq!(inputs(r#"{"query": []}"#), Ok(HashMap::new()));
+    }
+
+    #[test]
+    fn inputs_accept_legacy_empty_array() {
+        assert_eq!(inputs(r#"{"query": [], "inputs": []}"#), Ok(HashMap::new()));
+    }
+
+    #[test]
+    fn inputs_reject_non_empty_array() {
+        let error = inputs(r#"{"query": [], "inputs": [{"limit": 10}]}"#)
+            .expect_err("non-empty arrays are not valid inputs");
+        assert!(error.contains("or an empty array"), "{error}");
+    }
+
+    const QUERY: &str = r##"
+        ["::graph::tail::collect",
+          ["::graph::body::filter",
+            ["::graph::head::entities", ["input", "temporal_axes", "_"]],
+            ["fn", { "#tuple": [] }, { "#struct": { "vertex": "_" } }, "_",
+              {"#literal": true}
+            ]
+          ]
+        ]
+    "##;
+
+    /// Compiles `query` through the compiler pool, as a registration does.
+    async fn prepare(ctx: &Arc<CompilerContext>, query: &Arc<str>) {
+        let (reply, response) = oneshot::channel();
+        let options = CompilationOutputOptions {
+            interactive: false,
+            json_compat: false,
+            explain: None,
+        };
+
+        let response = dispatch(
+            ctx,
+            Arc::clone(query),
+            Request::Prepare { options, reply },
+            response,
+        )
+        .await;
+        assert!(matches!(response, Some(Ok(()))), "the query should compile");
+    }
+
+    /// Returns the channel to the task owning the compiled `query` on the pool.
+    async fn cached(
+        ctx: &Arc<CompilerContext>,
+        query: &Arc<str>,
+    ) -> Option<UnboundedSender<Execution>> {
+        let task_ctx = Arc::clone(ctx);
+        let query = Arc::clone(query);
+
+        ctx.pool
+            .spawn_pinned(move || async move { task_ctx.cache.get(&query) })
+            .await
+            .expect("the task should not panic")
+    }
+
+    #[tokio::test]
+    async fn repeated_requests_reuse_the_compiled_query() {
+        // A single thread, so that every request is dispatched to the thread caching the query
+        let ctx = Arc::new(CompilerContext::new(
+            Some(4),
+            Some(const { NonZero::new(1).unwrap() }),
+        ));
+        let query = Arc::<str>::from(QUERY);
+
+        prepare(&ctx, &query).await;
+        let compiled = cached(&ctx, &query)
+            .await
+            .expect("the compiled query should be cached");
+
+        // Compiling the query again would replace the cached channel
+        prepare(&ctx, &query).await;
+        let reused = cached(&ctx, &query)
+            .await
+            .expect("the compiled query should still be cached");
+        assert!(compiled.same_channel(&reused));
+    }
+
+    #[tokio::test]
+    async fn unbounded_pool_does_not_cache_queries() {
+        let ctx = Arc::new(CompilerContext::new(
+            None,
+            Some(const { NonZero::new(1).unwrap() }),
+        ));
+        let query = Arc::<str>::from(QUERY);
+
+        prepare(&ctx, &query).await;
+        assert!(cached(&ctx, &query).await.is_none());
     }
 }
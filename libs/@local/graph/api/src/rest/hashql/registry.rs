@@ -0,0 +1,112 @@
+//! Named HashQL queries.
+//!
+//! Services register a query once under a name and execute it later by that name, providing only
+//! the inputs. Names are scoped to the actor registering them, so an actor can neither execute nor
+//! replace the queries of another actor. Registrations are kept in memory, and therefore need to
+//! be repeated after the server restarts.
+
+use alloc::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{PoisonError, RwLock},
+};
+
+use type_system::principal::actor::ActorEntityUuid;
+
+/// The number of queries a single actor can register.
+pub(crate) const MAX_QUERIES_PER_ACTOR: usize = 256;
+
+/// The actor registered [`MAX_QUERIES_PER_ACTOR`] queries already.
+#[derive(Debug)]
+pub(crate) struct RegistryFull;
+
+#[derive(Default)]
+pub(crate) struct QueryRegistry {
+    queries: RwLock<HashMap<ActorEntityUuid, HashMap<String, Arc<str>>>>,
+}
+
+impl QueryRegistry {
+    /// Registers the `query` under `name` for `actor`, replacing any query the actor previously
+    /// registered under it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryFull`] if `name` is new and the actor registered
+    /// [`MAX_QUERIES_PER_ACTOR`] queries already.
+    pub(crate) fn register(
+        &self,
+        actor: ActorEntityUuid,
+        name: String,
+        query: Arc<str>,
+    ) -> Result<(), RegistryFull> {
+        let mut queries = self.queries.write().unwrap_or_else(PoisonError::into_inner);
+        let registered = queries.entry(actor).or_default();
+
+        if registered.len() >= MAX_QUERIES_PER_ACTOR && !registered.contains_key(&name) {
+            return Err(RegistryFull);
+        }
+
+        registered.insert(name, query);
+        drop(queries);
+
+        Ok(())
+    }
+
+    /// Returns the query `actor` registered under `name`.
+    pub(crate) fn get(&self, actor: ActorEntityUuid, name: &str) -> Option<Arc<str>> {
+        self.queries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&actor)?
+            .get(name)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn names_are_scoped_to_actors() {
+        let registry = QueryRegistry::default();
+
+        let alice = ActorEntityUuid::new(Uuid::new_v4());
+        let bob = ActorEntityUuid::new(Uuid::new_v4());
+
+        registry
+            .register(alice, "query".to_owned(), Arc::from("alice"))
+            .expect("should be able to register the query");
+        assert!(registry.get(bob, "query").is_none());
+
+        registry
+            .register(bob, "query".to_owned(), Arc::from("bob"))
+            .expect("should be able to register the query");
+        assert_eq!(registry.get(alice, "query").as_deref(), Some("alice"));
+        assert_eq!(registry.get(bob, "query").as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn limits_queries_per_actor() {
+        let registry = QueryRegistry::default();
+        let actor = ActorEntityUuid::new(Uuid::new_v4());
+
+        for index in 0..MAX_QUERIES_PER_ACTOR {
+            registry
+                .register(actor, format!("query-{index}"), Arc::from("query"))
+                .expect("should be able to register the query");
+        }
+
+        registry
+            .register(actor, "another".to_owned(), Arc::from("query"))
+            .expect_err("should not be able to register another query");
+
+        // Replacing an existing query does not count towards the limit
+        registry
+            .register(actor, "query-0".to_owned(), Arc::from("replaced"))
+            .expect("should be able to replace the query");
+        assert_eq!(registry.get(actor, "query-0").as_deref(), Some("replaced"));
+    }
+}
@@ -11,6 +11,8 @@ use crate::store::postgres::query::{Expression, PostgresType, Transpile};
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
+    Sum(Box<Expression>),
+    Avg(Box<Expression>),
     JsonAgg(Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractAsText(Box<Expression>, PathToken<'static>),
@@ -56,6 +58,35 @@ pub enum Function {
     BinaryQuantize(Box<Expression>),
     Unnest(Vec<Expression>),
     Now,
+    /// Counts the rows of a group.
+    ///
+    /// Transpiles to `count(*)` in PostgreSQL.
+    CountAll,
+    /// Returns the number of characters in a string.
+    ///
+    /// Transpiles to `length(<expr>)` in PostgreSQL.
+    Length(Box<Expression>),
+    /// Removes leading and trailing spaces from a string.
+    ///
+    /// Transpiles to `btrim(<expr>)` in PostgreSQL, which only strips spaces (`' '`), not other
+    /// whitespace like tabs or newlines.
+    Trim(Box<Expression>),
+    /// Reverses the characters of a string.
+    ///
+    /// Transpiles to `reverse(<expr>)` in PostgreSQL.
+    Reverse(Box<Expression>),
+    /// Returns whether a string starts with a prefix.
+    ///
+    /// Transpiles to `starts_with(<string>, <prefix>)` in PostgreSQL.
+    StartsWith(Box<Expression>, Box<Expression>),
+    /// Returns the 1-based position of a substring, or `0` if it is not present.
+    ///
+    /// Transpiles to `strpos(<string>, <substring>)` in PostgreSQL.
+    StrPos(Box<Expression>, Box<Expression>),
+    /// Splits a string at every occurrence of a delimiter.
+    ///
+    /// Transpiles to `string_to_array(<string>, <delimiter>)` in PostgreSQL.
+    StringToArray(Box<Expression>, Box<Expression>),
 }
 
 /// Direct-child traversal for [`Expression::visit`] and [`Expression::visit_mut`].
@@ -70,6 +101,8 @@ impl Function {
         match self {
             Self::Min(expr)
             | Self::Max(expr)
+            | Self::Sum(expr)
+            | Self::Avg(expr)
             | Self::JsonAgg(expr)
             | Self::JsonExtractText(expr)
             | Self::JsonExtractAsText(expr, _)
@@ -82,10 +115,16 @@ impl Function {
             | Self::LowerInf(expr)
             | Self::UpperInf(expr)
             | Self::ExtractEpochMs(expr)
-            | Self::BinaryQuantize(expr) => visitor(expr),
+            | Self::BinaryQuantize(expr)
+            | Self::Length(expr)
+            | Self::Trim(expr)
+            | Self::Reverse(expr) => visitor(expr),
             Self::JsonContains(lhs, rhs)
             | Self::JsonPathQueryFirst(lhs, rhs)
-            | Self::Coalesce(lhs, rhs) => {
+            | Self::Coalesce(lhs, rhs)
+            | Self::StartsWith(lhs, rhs)
+            | Self::StrPos(lhs, rhs)
+            | Self::StringToArray(lhs, rhs) => {
                 visitor(lhs)?;
                 visitor(rhs)
             }
@@ -107,7 +146,7 @@ impl Function {
                 }
                 ControlFlow::Continue(())
             }
-            Self::Now => ControlFlow::Continue(()),
+            Self::Now | Self::CountAll => ControlFlow::Continue(()),
         }
     }
 
@@ -118,6 +157,8 @@ impl Function {
         match self {
             Self::Min(expr)
             | Self::Max(expr)
+            | Self::Sum(expr)
+            | Self::Avg(expr)
             | Self::JsonAgg(expr)
             | Self::JsonExtractText(expr)
             | Self::JsonExtractAsText(expr, _)
@@ -130,10 +171,16 @@ impl Function {
             | Self::LowerInf(expr)
             | Self::UpperInf(expr)
             | Self::ExtractEpochMs(expr)
-            | Self::BinaryQuantize(expr) => visitor(expr),
+            | Self::BinaryQuantize(expr)
+            | Self::Length(expr)
+            | Self::Trim(expr)
+            | Self::Reverse(expr) => visitor(expr),
             Self::JsonContains(lhs, rhs)
             | Self::JsonPathQueryFirst(lhs, rhs)
-            | Self::Coalesce(lhs, rhs) => {
+            | Self::Coalesce(lhs, rhs)
+            | Self::StartsWith(lhs, rhs)
+            | Self::StrPos(lhs, rhs)
+            | Self::StringToArray(lhs, rhs) => {
                 visitor(lhs)?;
                 visitor(rhs)
             }
@@ -155,7 +202,7 @@ impl Function {
                 }
                 ControlFlow::Continue(())
             }
-            Self::Now => ControlFlow::Continue(()),
+            Self::Now | Self::CountAll => ControlFlow::Continue(()),
         }
     }
 }
@@ -177,6 +224,16 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Avg(expression) => {
+                fmt.write_str("AVG(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonAgg(expression) => {
                 fmt.write_str("jsonb_agg(")?;
                 expression.transpile(fmt)?;
@@ -239,6 +296,7 @@ impl Transpile for Function {
                 fmt.write_char(')')
             }
             Self::Now => fmt.write_str("now()"),
+            Self::CountAll => fmt.write_str("count(*)"),
             Self::ToJson(expression) => {
                 fmt.write_str("to_jsonb(")?;
                 expression.transpile(fmt)?;
@@ -311,6 +369,42 @@ impl Transpile for Function {
                 path.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Length(expression) => {
+                fmt.write_str("length(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Trim(expression) => {
+                fmt.write_str("btrim(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Reverse(expression) => {
+                fmt.write_str("reverse(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::StartsWith(string, prefix) => {
+                fmt.write_str("starts_with(")?;
+                string.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                prefix.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::StrPos(string, substring) => {
+                fmt.write_str("strpos(")?;
+                string.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                substring.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::StringToArray(string, delimiter) => {
+                fmt.write_str("string_to_array(")?;
+                string.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                delimiter.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::ArrayLiteral {
                 elements,
                 element_type,
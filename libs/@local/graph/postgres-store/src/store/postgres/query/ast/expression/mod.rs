@@ -746,6 +746,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transpile_string_functions() {
+        let text = || Box::new(Expression::Parameter(1));
+        let pattern = || Box::new(Expression::Parameter(2));
+
+        assert_eq!(
+            Expression::Function(Function::Length(text())).transpile_to_string(),
+            "length($1)"
+        );
+        assert_eq!(
+            Expression::Function(Function::Trim(text())).transpile_to_string(),
+            "btrim($1)"
+        );
+        assert_eq!(
+            Expression::Function(Function::StartsWith(
+                Box::new(Expression::Function(Function::Reverse(text()))),
+                Box::new(Expression::Function(Function::Reverse(pattern()))),
+            ))
+            .transpile_to_string(),
+            "starts_with(reverse($1), reverse($2))"
+        );
+        assert_eq!(
+            Expression::Function(Function::StrPos(text(), pattern())).transpile_to_string(),
+            "strpos($1, $2)"
+        );
+        assert_eq!(
+            Expression::Function(Function::StringToArray(text(), pattern())).transpile_to_string(),
+            "string_to_array($1, $2)"
+        );
+    }
+
     #[test]
     fn transpile_json_null_constant() {
         assert_eq!(
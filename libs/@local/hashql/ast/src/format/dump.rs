@@ -26,11 +26,13 @@ use hashql_core::{span::SpanId, value};
 use crate::node::{
     expr::{
         AsExpr, CallExpr, ClosureExpr, DictExpr, Expr, ExprKind, FieldExpr, IfExpr, IndexExpr,
-        InputExpr, LetExpr, ListExpr, LiteralExpr, NewTypeExpr, StructExpr, TupleExpr, TypeExpr,
+        InputExpr, LetExpr, ListExpr, LiteralExpr, MatchExpr, NewTypeExpr, StructExpr, TupleExpr,
+        TypeExpr,
         call::{Argument, LabeledArgument},
         closure::{ClosureParam, ClosureSignature},
         dict::DictEntry,
         list::ListElement,
+        r#match::{ConstructorPattern, MatchArm, Pattern, PatternKind, StructPattern},
         r#struct::StructEntry,
         tuple::TupleElement,
     },
@@ -360,6 +362,60 @@ impl_syntax_dump!(struct ClosureExpr(); signature body);
 #[rustfmt::skip]
 impl_syntax_dump!(struct IfExpr(); test then ?r#else);
 
+#[rustfmt::skip]
+impl_syntax_dump!(struct Pattern(); kind);
+
+impl SyntaxDump for PatternKind<'_> {
+    fn syntax_dump(&self, fmt: &mut Formatter, depth: usize) -> fmt::Result {
+        match self {
+            Self::Wildcard => write_header(fmt, depth, "PatternKind", None, None, Some("Wildcard")),
+            Self::Binding(name) => write_header(
+                fmt,
+                depth,
+                "PatternKind",
+                None,
+                None,
+                Some(&format!("Binding, name: {name}")),
+            ),
+            Self::Literal(literal) => {
+                write_header(fmt, depth, "PatternKind", None, None, Some("Literal"))?;
+
+                literal.syntax_dump(fmt, depth + 1)
+            }
+            Self::Tuple(elements) => {
+                write_header(fmt, depth, "PatternKind", None, None, Some("Tuple"))?;
+
+                for element in elements {
+                    element.syntax_dump(fmt, depth + 1)?;
+                }
+
+                Ok(())
+            }
+            Self::Struct(fields) => {
+                write_header(fmt, depth, "PatternKind", None, None, Some("Struct"))?;
+
+                for field in fields {
+                    field.syntax_dump(fmt, depth + 1)?;
+                }
+
+                Ok(())
+            }
+            Self::Constructor(constructor) => {
+                write_header(fmt, depth, "PatternKind", None, None, Some("Constructor"))?;
+
+                constructor.syntax_dump(fmt, depth + 1)
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl_syntax_dump!(struct StructPattern(name); pattern);
+impl_syntax_dump!(struct ConstructorPattern(); path ?argument);
+
+impl_syntax_dump!(struct MatchArm(); pattern body);
+impl_syntax_dump!(struct MatchExpr(); scrutinee []arms);
+
 impl_syntax_dump!(struct AsExpr(); value r#type);
 
 #[rustfmt::skip]
@@ -435,6 +491,11 @@ impl SyntaxDump for ExprKind<'_> {
 
                 if_expr.syntax_dump(fmt, depth + 1)
             }
+            Self::Match(match_expr) => {
+                write_header(fmt, depth, "ExprKind", None, None, Some("Match"))?;
+
+                match_expr.syntax_dump(fmt, depth + 1)
+            }
             Self::As(as_expr) => {
                 write_header(fmt, depth, "ExprKind", None, None, Some("As"))?;
 
@@ -228,6 +228,16 @@ const USE_PATH_GENERIC_ARGUMENTS: TerminalDiagnosticCategory = TerminalDiagnosti
     name: "Generic arguments in use path",
 };
 
+const INVALID_MATCH_PATTERN: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-match-pattern",
+    name: "Invalid match pattern",
+};
+
+const DUPLICATE_MATCH_BINDING: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "duplicate-match-binding",
+    name: "Duplicate match binding",
+};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ExpanderDiagnosticCategory {
     EmptyPath,
@@ -269,6 +279,8 @@ pub enum ExpanderDiagnosticCategory {
     DuplicateUseBinding,
     InvalidUsePath,
     UsePathGenericArguments,
+    InvalidMatchPattern,
+    DuplicateMatchBinding,
 }
 
 impl DiagnosticCategory for ExpanderDiagnosticCategory {
@@ -321,6 +333,8 @@ impl DiagnosticCategory for ExpanderDiagnosticCategory {
             Self::DuplicateUseBinding => Some(&DUPLICATE_USE_BINDING),
             Self::InvalidUsePath => Some(&INVALID_USE_PATH),
             Self::UsePathGenericArguments => Some(&USE_PATH_GENERIC_ARGUMENTS),
+            Self::InvalidMatchPattern => Some(&INVALID_MATCH_PATTERN),
+            Self::DuplicateMatchBinding => Some(&DUPLICATE_MATCH_BINDING),
         }
     }
 }
@@ -2034,6 +2048,7 @@ pub(crate) fn invalid_expression_in_type_position(
         ExprKind::Input(_) => "an `input` expression",
         ExprKind::Closure(_) => "a closure expression",
         ExprKind::If(_) => "an `if` expression",
+        ExprKind::Match(_) => "a `match` expression",
         ExprKind::Field(_) => "a field access expression",
         ExprKind::Index(_) => "an index expression",
         ExprKind::As(_) => "an `as` expression",
@@ -2481,3 +2496,151 @@ pub(crate) fn use_path_generic_arguments(path_span: SpanId) -> ExpanderDiagnosti
 
     diagnostic
 }
+
+/// A `match` call was passed labeled arguments.
+///
+/// `match` only accepts positional arguments.
+pub(crate) fn labeled_arguments_in_match(
+    labeled_arguments: &[LabeledArgument<'_>],
+) -> ExpanderDiagnostic {
+    let (first, rest) = labeled_arguments
+        .split_first()
+        .expect("caller should check that labeled_arguments is non-empty");
+
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::LabeledArgumentsNotSupported,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        first.span,
+        "labeled arguments are not allowed in `match`",
+    ));
+
+    for argument in rest {
+        diagnostic.add_label(Label::new(
+            argument.span,
+            "labeled argument not allowed here",
+        ));
+    }
+
+    diagnostic.add_message(Message::help(
+        "pass the arguments positionally: `(match value pattern body ...)`",
+    ));
+
+    diagnostic
+}
+
+/// A `match` call was passed the wrong number of arguments.
+///
+/// `match` accepts the value followed by at least one pair of pattern and body
+/// `(match value pattern body ...)`.
+pub(crate) fn invalid_match_argument_count(
+    call_span: SpanId,
+    arguments: &[Argument<'_>],
+) -> ExpanderDiagnostic {
+    let count = arguments.len();
+
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::InvalidArgumentCount,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        call_span,
+        format!("expected an odd number of at least 3 arguments to `match`, found {count}"),
+    ));
+
+    if count >= 3
+        && let Some(last) = arguments.last()
+    {
+        diagnostic.add_label(Label::new(last.span, "this pattern has no body"));
+    }
+
+    diagnostic.add_message(Message::help(
+        "use `(match value pattern body ...)`, with a body for every pattern",
+    ));
+
+    diagnostic.add_message(Message::note(
+        "the arguments are, in order: the value to match on, followed by pairs of a pattern and \
+         the body evaluated if the pattern matches",
+    ));
+
+    diagnostic
+}
+
+/// An expression was used as a pattern in a `match` arm that cannot be interpreted as one.
+pub(crate) fn invalid_match_pattern(span: SpanId) -> ExpanderDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::InvalidMatchPattern,
+        Severity::Error,
+    )
+    .primary(Label::new(span, "expected a pattern"));
+
+    diagnostic.add_message(Message::help(
+        "patterns are `_`, a name to bind, a literal, a tuple `(a, b)`, a struct `(name: n)`, or \
+         a constructor like `None` or `Some(x)`",
+    ));
+
+    diagnostic
+}
+
+/// A path in constructor position of a pattern does not refer to a constructor.
+pub(crate) fn match_pattern_not_constructor(span: SpanId) -> ExpanderDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::InvalidMatchPattern,
+        Severity::Error,
+    )
+    .primary(Label::new(span, "expected a constructor"));
+
+    diagnostic.add_message(Message::help(
+        "only constructors created through `newtype`, such as `Some` or `Ok`, can be used to \
+         destructure a value",
+    ));
+
+    diagnostic
+}
+
+/// A constructor pattern was given more than a single argument, or labeled arguments.
+pub(crate) fn invalid_constructor_pattern_arguments(span: SpanId) -> ExpanderDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::InvalidMatchPattern,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        "constructor patterns take at most one positional argument",
+    ));
+
+    diagnostic.add_message(Message::help(
+        "a constructor wraps a single value, use a tuple or struct pattern to destructure it \
+         further, e.g. `Some((a, b))`",
+    ));
+
+    diagnostic
+}
+
+/// The same name was bound twice in the pattern of a `match` arm.
+pub(crate) fn duplicate_match_binding(
+    duplicate_span: SpanId,
+    name: Symbol<'_>,
+    original_span: SpanId,
+) -> ExpanderDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ExpanderDiagnosticCategory::DuplicateMatchBinding,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        duplicate_span,
+        format!("`{name}` is bound more than once in the same pattern"),
+    ));
+
+    diagnostic.add_label(Label::new(
+        original_span,
+        format!("`{name}` was first bound here"),
+    ));
+
+    diagnostic.add_message(Message::help(
+        "use a different name for one of the bindings, or `_` to ignore the value",
+    ));
+
+    diagnostic
+}
@@ -0,0 +1,391 @@
+use core::mem;
+
+use hashql_core::{
+    heap::{self, BumpAllocator},
+    module::{
+        Reference, Universe,
+        item::{Item, ItemKind},
+        namespace::{ResolutionMode, ResolveOptions},
+    },
+    span::SpanId,
+    symbol::Ident,
+};
+
+use super::{CurrentItem, Expander};
+use crate::{
+    lower::expander::error,
+    node::{
+        expr::{
+            CallExpr, Expr, ExprKind, LiteralExpr, MatchExpr, StructExpr, TupleExpr,
+            call::Argument,
+            r#match::{ConstructorPattern, MatchArm, Pattern, PatternKind, StructPattern},
+        },
+        id::NodeId,
+        path::Path,
+    },
+};
+
+/// Returns `true` if `ident` resolves to a constructor in the current namespace.
+///
+/// This is used to disambiguate between a binding and a nullary constructor, such as `None`,
+/// which are syntactically indistinguishable.
+fn is_constructor<'heap, S>(expander: &Expander<'_, 'heap, S>, ident: Ident<'heap>) -> bool {
+    let reference = expander.namespace.resolve(
+        [ident.value],
+        ResolveOptions {
+            universe: Universe::Value,
+            mode: ResolutionMode::Relative,
+        },
+    );
+
+    matches!(
+        reference,
+        Ok(Reference::Item(Item {
+            kind: ItemKind::Constructor(_),
+            ..
+        }))
+    )
+}
+
+/// Resolves the path of a constructor pattern.
+///
+/// Returns `None` if the path could not be resolved, or does not refer to a constructor, in which
+/// case a diagnostic has been issued.
+fn lower_constructor_path<'heap, S>(
+    expander: &mut Expander<'_, 'heap, S>,
+    mut expr: Expr<'heap>,
+) -> Option<Path<'heap>>
+where
+    S: BumpAllocator,
+{
+    let span = expr.span;
+    let item = expander.visit(&mut expr);
+
+    match (expr.kind, item) {
+        // Resolution failed, a diagnostic has already been issued.
+        (ExprKind::Dummy, _) => None,
+        (
+            ExprKind::Path(path),
+            Some(CurrentItem {
+                item:
+                    Item {
+                        kind: ItemKind::Constructor(_),
+                        ..
+                    },
+                has_arguments: _,
+            }),
+        ) => Some(path),
+        _ => {
+            expander
+                .diagnostics
+                .push(error::match_pattern_not_constructor(span));
+
+            None
+        }
+    }
+}
+
+struct PatternLowering<'expander, 'env, 'heap, S> {
+    expander: &'expander mut Expander<'env, 'heap, S>,
+    bindings: Vec<Ident<'heap>>,
+}
+
+impl<'heap, S> PatternLowering<'_, '_, 'heap, S>
+where
+    S: BumpAllocator,
+{
+    fn binding(&mut self, name: Ident<'heap>) -> PatternKind<'heap> {
+        if let Some(original) = self
+            .bindings
+            .iter()
+            .find(|binding| binding.value == name.value)
+        {
+            self.expander
+                .diagnostics
+                .push(error::duplicate_match_binding(
+                    name.span,
+                    name.value,
+                    original.span,
+                ));
+        } else {
+            self.bindings.push(name);
+        }
+
+        PatternKind::Binding(name)
+    }
+
+    fn constructor(
+        &mut self,
+        span: SpanId,
+        function: Expr<'heap>,
+        argument: Option<Expr<'heap>>,
+    ) -> Option<PatternKind<'heap>> {
+        let path = lower_constructor_path(self.expander, function);
+        let argument = argument.map(|argument| self.lower(argument));
+
+        let argument = match argument {
+            Some(argument) => Some(Box::new_in(argument?, self.expander.heap)),
+            None => None,
+        };
+
+        Some(PatternKind::Constructor(ConstructorPattern {
+            id: NodeId::PLACEHOLDER,
+            span,
+            path: path?,
+            argument,
+        }))
+    }
+
+    fn call(&mut self, span: SpanId, call: CallExpr<'heap>) -> Option<PatternKind<'heap>> {
+        let CallExpr {
+            id: _,
+            span: _,
+            function,
+            arguments,
+            labeled_arguments,
+        } = call;
+
+        if !labeled_arguments.is_empty() {
+            self.expander
+                .diagnostics
+                .push(error::invalid_constructor_pattern_arguments(span));
+
+            return None;
+        }
+
+        let mut arguments = arguments.into_iter();
+        let argument = arguments.next().map(|argument| argument.value);
+
+        if arguments.next().is_some() {
+            self.expander
+                .diagnostics
+                .push(error::invalid_constructor_pattern_arguments(span));
+
+            return None;
+        }
+
+        self.constructor(span, *function, argument)
+    }
+
+    fn tuple(&mut self, tuple: TupleExpr<'heap>) -> Option<PatternKind<'heap>> {
+        if let Some(r#type) = &tuple.r#type {
+            self.expander
+                .diagnostics
+                .push(error::invalid_match_pattern(r#type.span));
+
+            return None;
+        }
+
+        let mut elements = heap::Vec::with_capacity_in(tuple.elements.len(), self.expander.heap);
+        let mut is_valid = true;
+
+        for element in tuple.elements {
+            match self.lower(element.value) {
+                Some(pattern) => elements.push(pattern),
+                None => is_valid = false,
+            }
+        }
+
+        is_valid.then_some(PatternKind::Tuple(elements))
+    }
+
+    fn r#struct(&mut self, r#struct: StructExpr<'heap>) -> Option<PatternKind<'heap>> {
+        if let Some(r#type) = &r#struct.r#type {
+            self.expander
+                .diagnostics
+                .push(error::invalid_match_pattern(r#type.span));
+
+            return None;
+        }
+
+        let mut fields = heap::Vec::with_capacity_in(r#struct.entries.len(), self.expander.heap);
+        let mut is_valid = true;
+
+        for entry in r#struct.entries {
+            match self.lower(entry.value) {
+                Some(pattern) => fields.push(StructPattern {
+                    id: NodeId::PLACEHOLDER,
+                    span: entry.span,
+                    name: entry.key,
+                    pattern,
+                }),
+                None => is_valid = false,
+            }
+        }
+
+        is_valid.then_some(PatternKind::Struct(fields))
+    }
+
+    /// Converts an expression into a pattern.
+    ///
+    /// Every error encountered is reported, `None` is returned if any sub-pattern is invalid.
+    fn lower(&mut self, expr: Expr<'heap>) -> Option<Pattern<'heap>> {
+        let span = expr.span;
+
+        let kind = match expr.kind {
+            ExprKind::Underscore => Some(PatternKind::Wildcard),
+            ExprKind::Path(path) => match path.as_ident().copied() {
+                Some(ident) if !is_constructor(self.expander, ident) => Some(self.binding(ident)),
+                _ => self.constructor(
+                    span,
+                    Expr {
+                        id: expr.id,
+                        span,
+                        kind: ExprKind::Path(path),
+                    },
+                    None,
+                ),
+            },
+            ExprKind::Literal(LiteralExpr {
+                id: _,
+                span: _,
+                kind,
+                r#type: None,
+            }) => Some(PatternKind::Literal(kind)),
+            ExprKind::Call(call) => self.call(span, call),
+            ExprKind::Tuple(tuple) => self.tuple(tuple),
+            ExprKind::Struct(r#struct) => self.r#struct(r#struct),
+            // A dummy expression is the result of a previous error, which has already been
+            // reported.
+            ExprKind::Dummy => None,
+            ExprKind::Literal(_)
+            | ExprKind::Dict(_)
+            | ExprKind::List(_)
+            | ExprKind::Let(_)
+            | ExprKind::Type(_)
+            | ExprKind::NewType(_)
+            | ExprKind::Input(_)
+            | ExprKind::Closure(_)
+            | ExprKind::If(_)
+            | ExprKind::Match(_)
+            | ExprKind::Field(_)
+            | ExprKind::Index(_)
+            | ExprKind::As(_) => {
+                self.expander
+                    .diagnostics
+                    .push(error::invalid_match_pattern(span));
+
+                None
+            }
+        }?;
+
+        Some(Pattern {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind,
+        })
+    }
+}
+
+fn lower_match_arm<'heap, S>(
+    expander: &mut Expander<'_, 'heap, S>,
+    pattern: &mut Argument<'heap>,
+    body: &mut Argument<'heap>,
+) -> Option<MatchArm<'heap>>
+where
+    S: BumpAllocator,
+{
+    let span = pattern.span;
+
+    let mut lowering = PatternLowering {
+        expander,
+        bindings: Vec::new(),
+    };
+    let pattern = lowering.lower(mem::replace(&mut pattern.value, Expr::dummy()));
+    let bindings = lowering.bindings;
+
+    let mut body = mem::replace(&mut body.value, Expr::dummy());
+
+    // We visit the body even if the pattern is invalid, so that any error inside of the body is
+    // still reported.
+    expander.bind_many(
+        bindings
+            .into_iter()
+            .map(|binding| (binding.value, Universe::Value)),
+        |expander| {
+            expander.visit(&mut body);
+        },
+    );
+
+    Some(MatchArm {
+        id: NodeId::PLACEHOLDER,
+        span,
+        pattern: pattern?,
+        body: Box::new_in(body, expander.heap),
+    })
+}
+
+/// Lowers a `match` call into a [`MatchExpr`].
+///
+/// Accepts the form `(match value pattern body ...)`, with at least one pair of pattern and
+/// body. The bindings introduced by a pattern are in scope for the corresponding body only.
+///
+/// [`MatchExpr`]: crate::node::expr::MatchExpr
+pub(super) fn lower_match<'heap, S>(
+    expander: &mut Expander<'_, 'heap, S>,
+    CallExpr {
+        id: _,
+        span,
+        function: _,
+        arguments,
+        labeled_arguments,
+    }: &mut CallExpr<'heap>,
+) -> Expr<'heap>
+where
+    S: BumpAllocator,
+{
+    if !labeled_arguments.is_empty() {
+        expander
+            .diagnostics
+            .push(error::labeled_arguments_in_match(labeled_arguments));
+    }
+
+    let [scrutinee, arms @ ..] = &mut **arguments else {
+        expander
+            .diagnostics
+            .push(error::invalid_match_argument_count(*span, arguments));
+
+        return Expr::dummy();
+    };
+
+    if arms.is_empty() || !arms.len().is_multiple_of(2) {
+        expander
+            .diagnostics
+            .push(error::invalid_match_argument_count(*span, arguments));
+
+        return Expr::dummy();
+    }
+
+    let mut scrutinee = mem::replace(&mut scrutinee.value, Expr::dummy());
+    expander.visit(&mut scrutinee);
+
+    let arms = arms.chunks_exact_mut(2);
+    let mut lowered = heap::Vec::with_capacity_in(arms.len(), expander.heap);
+    let mut is_valid = true;
+
+    for arm in arms {
+        let [pattern, body] = arm else {
+            unreachable!("chunks are exactly two elements long");
+        };
+
+        match lower_match_arm(expander, pattern, body) {
+            Some(arm) => lowered.push(arm),
+            None => is_valid = false,
+        }
+    }
+
+    if !is_valid {
+        return Expr::dummy();
+    }
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span: *span,
+        kind: ExprKind::Match(MatchExpr {
+            id: NodeId::PLACEHOLDER,
+            span: *span,
+            scrutinee: Box::new_in(scrutinee, expander.heap),
+            arms: lowered,
+        }),
+    }
+}
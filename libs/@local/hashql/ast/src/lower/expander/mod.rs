@@ -14,6 +14,7 @@ mod r#if;
 mod index;
 mod input;
 mod r#let;
+mod r#match;
 mod newtype;
 mod r#type;
 mod r#use;
@@ -32,7 +33,7 @@ use hashql_core::{
 
 use self::{
     access::lower_access, r#as::lower_as, error::ExpanderDiagnosticIssues, r#fn::lower_fn,
-    r#if::lower_if, index::lower_index, input::lower_input, r#let::lower_let,
+    r#if::lower_if, index::lower_index, input::lower_input, r#let::lower_let, r#match::lower_match,
     newtype::lower_newtype, r#type::lower_type, r#use::lower_use,
 };
 use crate::{
@@ -425,6 +426,10 @@ where
                     self.trampoline = Some(lower_let(self, expr));
                     return;
                 }
+                sym::path::r#match::CONST => {
+                    self.trampoline = Some(lower_match(self, expr));
+                    return;
+                }
                 sym::path::r#type::CONST => {
                     self.trampoline = Some(lower_type(self, expr));
                     return;
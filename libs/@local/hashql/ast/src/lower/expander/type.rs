@@ -204,6 +204,7 @@ where
         | ExprKind::Input(_)
         | ExprKind::Closure(_)
         | ExprKind::If(_)
+        | ExprKind::Match(_)
         | ExprKind::Field(_)
         | ExprKind::Index(_)
         | ExprKind::As(_)
@@ -88,6 +88,7 @@ fn lower_imports_struct<'heap, S>(
             | ExprKind::Input(_)
             | ExprKind::Closure(_)
             | ExprKind::If(_)
+            | ExprKind::Match(_)
             | ExprKind::Field(_)
             | ExprKind::Index(_)
             | ExprKind::As(_)
@@ -141,6 +142,7 @@ where
         | ExprKind::Input(_)
         | ExprKind::Closure(_)
         | ExprKind::If(_)
+        | ExprKind::Match(_)
         | ExprKind::Field(_)
         | ExprKind::Index(_)
         | ExprKind::As(_)
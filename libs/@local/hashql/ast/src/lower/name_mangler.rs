@@ -44,7 +44,11 @@ use hashql_core::{
 
 use crate::{
     node::{
-        expr::{ClosureExpr, Expr, LetExpr, NewTypeExpr, TypeExpr, closure::ClosureSignature},
+        expr::{
+            ClosureExpr, Expr, LetExpr, NewTypeExpr, TypeExpr,
+            closure::ClosureSignature,
+            r#match::{ConstructorPattern, MatchArm, Pattern, PatternKind},
+        },
         generic::GenericConstraint,
         path::Path,
         r#type::Type,
@@ -128,6 +132,7 @@ struct MangledSignature<'heap> {
 /// - `type` expressions (type scope)
 /// - `newtype` expressions (both type and value scopes)
 /// - Closure parameters (value scope)
+/// - Bindings in `match` patterns (value scope)
 /// - Generic parameters (type scope)
 ///
 /// # Examples
@@ -297,6 +302,41 @@ impl<'heap> NameMangler<'heap> {
         }
     }
 
+    /// Mangles every binding introduced by a pattern.
+    ///
+    /// The bindings are collected into `bindings`, so that they can be brought into scope for the
+    /// body of the arm.
+    fn mangle_pattern(
+        &mut self,
+        pattern: &mut Pattern<'heap>,
+        bindings: &mut Vec<(Symbol<'heap>, Symbol<'heap>)>,
+    ) {
+        match &mut pattern.kind {
+            PatternKind::Wildcard | PatternKind::Literal(_) => {}
+            PatternKind::Binding(name) => {
+                let original = name.value;
+                let mangled = self.mangle(&mut name.value);
+
+                bindings.push((original, mangled));
+            }
+            PatternKind::Tuple(elements) => {
+                for element in elements {
+                    self.mangle_pattern(element, bindings);
+                }
+            }
+            PatternKind::Struct(fields) => {
+                for field in fields {
+                    self.mangle_pattern(&mut field.pattern, bindings);
+                }
+            }
+            PatternKind::Constructor(ConstructorPattern { argument, .. }) => {
+                if let Some(argument) = argument {
+                    self.mangle_pattern(argument, bindings);
+                }
+            }
+        }
+    }
+
     fn mangle_constraints(
         &mut self,
         original: Symbol<'heap>,
@@ -374,6 +414,26 @@ impl<'heap> Visitor<'heap> for NameMangler<'heap> {
         });
     }
 
+    fn visit_match_arm(&mut self, arm: &mut MatchArm<'heap>) {
+        let mut bindings = Vec::new();
+        self.mangle_pattern(&mut arm.pattern, &mut bindings);
+
+        let MatchArm {
+            id,
+            span,
+            pattern,
+            body,
+        } = arm;
+
+        self.visit_id(id);
+        self.visit_span(span);
+        self.visit_pattern(pattern);
+
+        self.enter_many(Universe::Value, bindings, |this| {
+            this.visit_expr(body);
+        });
+    }
+
     fn visit_type_expr(&mut self, expr: &mut TypeExpr<'heap>) {
         let original = expr.name.value;
         let mangled = self.mangle(&mut expr.name.value);
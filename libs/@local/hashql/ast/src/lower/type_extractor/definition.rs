@@ -305,6 +305,7 @@ impl<'heap> Visitor<'heap> for TypeDefinitionExtractor<'_, 'heap> {
             | ExprKind::Input(_)
             | ExprKind::Closure(_)
             | ExprKind::If(_)
+            | ExprKind::Match(_)
             | ExprKind::Field(_)
             | ExprKind::Index(_)
             | ExprKind::As(_)
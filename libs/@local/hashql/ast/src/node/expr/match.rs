@@ -0,0 +1,136 @@
+use hashql_core::{heap, span::SpanId, symbol::Ident, value::Primitive};
+
+use super::Expr;
+use crate::node::{id::NodeId, path::Path};
+
+/// A pattern matching expression in the HashQL Abstract Syntax Tree.
+///
+/// Represents a `match` expression that evaluates a scrutinee and compares it against a list of
+/// arms in order. The body of the first arm whose pattern matches the value is evaluated, with
+/// every name bound by the pattern in scope.
+///
+/// Arms are checked for exhaustiveness and reachability once the types of the program are known.
+///
+/// # Examples
+///
+/// ## J-Expr
+///
+/// ```json
+/// ["match", "value",
+///     ["Some", "x"], "x",
+///     "None", 0
+/// ]
+/// ```
+///
+/// ## Documentation Format
+///
+/// ```text
+/// match value {
+///     Some(x) => x,
+///     None => 0,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MatchExpr<'heap> {
+    pub id: NodeId,
+    pub span: SpanId,
+
+    pub scrutinee: heap::Box<'heap, Expr<'heap>>,
+    pub arms: heap::Vec<'heap, MatchArm<'heap>>,
+}
+
+/// A single arm of a [`MatchExpr`].
+///
+/// The arm consists of a pattern, which is compared against the scrutinee, and the body, which is
+/// evaluated if the pattern matches.
+#[derive(Debug)]
+pub struct MatchArm<'heap> {
+    pub id: NodeId,
+    pub span: SpanId,
+
+    pub pattern: Pattern<'heap>,
+    pub body: heap::Box<'heap, Expr<'heap>>,
+}
+
+/// A pattern in the HashQL Abstract Syntax Tree.
+///
+/// Patterns describe the shape of a value and are used in the arms of a [`MatchExpr`]. A pattern
+/// may introduce bindings, which are in scope for the body of the arm.
+#[derive(Debug)]
+pub struct Pattern<'heap> {
+    pub id: NodeId,
+    pub span: SpanId,
+
+    pub kind: PatternKind<'heap>,
+}
+
+/// The different kinds of patterns.
+#[derive(Debug)]
+pub enum PatternKind<'heap> {
+    /// Matches any value without binding it.
+    ///
+    /// ```json
+    /// "_"
+    /// ```
+    Wildcard,
+
+    /// Matches any value and binds it to the given name.
+    ///
+    /// ```json
+    /// "x"
+    /// ```
+    Binding(Ident<'heap>),
+
+    /// Matches a value equal to the given literal.
+    ///
+    /// ```json
+    /// {"#literal": 42}
+    /// ```
+    Literal(Primitive<'heap>),
+
+    /// Matches a tuple, with a sub-pattern for every element.
+    ///
+    /// ```json
+    /// {"#tuple": ["x", "_"]}
+    /// ```
+    Tuple(heap::Vec<'heap, Pattern<'heap>>),
+
+    /// Matches a struct, with a sub-pattern for every mentioned field.
+    ///
+    /// Fields which are not mentioned are ignored.
+    ///
+    /// ```json
+    /// {"#struct": {"name": "n", "age": "_"}}
+    /// ```
+    Struct(heap::Vec<'heap, StructPattern<'heap>>),
+
+    /// Matches a value constructed by the given constructor.
+    ///
+    /// ```json
+    /// ["Some", "x"]
+    /// "None"
+    /// ```
+    Constructor(ConstructorPattern<'heap>),
+}
+
+/// A field in a struct pattern.
+#[derive(Debug)]
+pub struct StructPattern<'heap> {
+    pub id: NodeId,
+    pub span: SpanId,
+
+    pub name: Ident<'heap>,
+    pub pattern: Pattern<'heap>,
+}
+
+/// A constructor pattern, matching values created through a `newtype` constructor.
+///
+/// The argument is omitted for constructors that do not take a value, such as `None`.
+#[derive(Debug)]
+pub struct ConstructorPattern<'heap> {
+    pub id: NodeId,
+    pub span: SpanId,
+
+    pub path: Path<'heap>,
+    pub argument: Option<heap::Box<'heap, Pattern<'heap>>>,
+}
@@ -7,10 +7,10 @@
 //!
 //! # Special Forms
 //!
-//! Several expression kinds (`Let`, `Use`, `Input`, `Closure`, `If`, `Field`, `Index`) are
-//! implemented as "special forms". Initially, these are parsed as ordinary function calls into the
-//! AST. During a subsequent AST transformation phase, these function calls are recognized by their
-//! names and expanded into their corresponding specialized AST nodes. For example, a `let`
+//! Several expression kinds (`Let`, `Use`, `Input`, `Closure`, `If`, `Match`, `Field`, `Index`)
+//! are implemented as "special forms". Initially, these are parsed as ordinary function calls into
+//! the AST. During a subsequent AST transformation phase, these function calls are recognized by
+//! their names and expanded into their corresponding specialized AST nodes. For example, a `let`
 //! function call is transformed into a dedicated [`LetExpr`] structure in the AST after parsing.
 //!
 //! # Memory Management
@@ -47,6 +47,7 @@ pub mod input;
 pub mod r#let;
 pub mod list;
 pub mod literal;
+pub mod r#match;
 pub mod newtype;
 pub mod r#struct;
 pub mod tuple;
@@ -58,8 +59,8 @@ use hashql_core::span::SpanId;
 pub use self::{
     r#as::AsExpr, call::CallExpr, closure::ClosureExpr, dict::DictExpr, field::FieldExpr,
     r#if::IfExpr, index::IndexExpr, input::InputExpr, r#let::LetExpr, list::ListExpr,
-    literal::LiteralExpr, newtype::NewTypeExpr, r#struct::StructExpr, tuple::TupleExpr,
-    r#type::TypeExpr,
+    literal::LiteralExpr, r#match::MatchExpr, newtype::NewTypeExpr, r#struct::StructExpr,
+    tuple::TupleExpr, r#type::TypeExpr,
 };
 use super::{id::NodeId, path::Path};
 
@@ -394,6 +395,29 @@ pub enum ExprKind<'heap> {
     /// ```
     If(IfExpr<'heap>),
 
+    /// A pattern matching expression (special form).
+    ///
+    /// Compares a value against a list of patterns and evaluates the body of the first arm that
+    /// matches. This is expanded from a function call during AST transformation.
+    ///
+    /// # Examples
+    ///
+    /// ## J-Expr
+    ///
+    /// ```json
+    /// ["match", "value", ["Some", "x"], "x", "None", 0]
+    /// ```
+    ///
+    /// ## Documentation Format
+    ///
+    /// ```text
+    /// match value {
+    ///     Some(x) => x,
+    ///     None => 0,
+    /// }
+    /// ```
+    Match(MatchExpr<'heap>),
+
     /// A field access expression (special form).
     ///
     /// Accesses a field of a struct or tuple. This is expanded from a function call
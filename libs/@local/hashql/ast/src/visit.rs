@@ -78,12 +78,13 @@ use hashql_core::{span::SpanId, symbol::Ident};
 use crate::node::{
     expr::{
         CallExpr, ClosureExpr, DictExpr, Expr, ExprKind, FieldExpr, IfExpr, IndexExpr, InputExpr,
-        LetExpr, ListExpr, LiteralExpr, NewTypeExpr, StructExpr, TupleExpr, TypeExpr,
+        LetExpr, ListExpr, LiteralExpr, MatchExpr, NewTypeExpr, StructExpr, TupleExpr, TypeExpr,
         r#as::AsExpr,
         call::{Argument, LabeledArgument},
         closure::{ClosureParam, ClosureSignature},
         dict::DictEntry,
         list::ListElement,
+        r#match::{ConstructorPattern, MatchArm, Pattern, PatternKind, StructPattern},
         r#struct::StructEntry,
         tuple::TupleElement,
     },
@@ -277,6 +278,26 @@ pub trait Visitor<'heap> {
         walk_if_expr(self, expr);
     }
 
+    fn visit_match_expr(&mut self, expr: &mut MatchExpr<'heap>) {
+        walk_match_expr(self, expr);
+    }
+
+    fn visit_match_arm(&mut self, arm: &mut MatchArm<'heap>) {
+        walk_match_arm(self, arm);
+    }
+
+    fn visit_pattern(&mut self, pattern: &mut Pattern<'heap>) {
+        walk_pattern(self, pattern);
+    }
+
+    fn visit_struct_pattern(&mut self, pattern: &mut StructPattern<'heap>) {
+        walk_struct_pattern(self, pattern);
+    }
+
+    fn visit_constructor_pattern(&mut self, pattern: &mut ConstructorPattern<'heap>) {
+        walk_constructor_pattern(self, pattern);
+    }
+
     fn visit_as_expr(&mut self, expr: &mut AsExpr<'heap>) {
         walk_as_expr(self, expr);
     }
@@ -413,6 +434,7 @@ pub fn walk_expr<'heap, T: Visitor<'heap> + ?Sized>(
         ExprKind::Input(input_expr) => visitor.visit_input_expr(input_expr),
         ExprKind::Closure(closure_expr) => visitor.visit_closure_expr(closure_expr),
         ExprKind::If(if_expr) => visitor.visit_if_expr(if_expr),
+        ExprKind::Match(match_expr) => visitor.visit_match_expr(match_expr),
         ExprKind::As(is_expr) => visitor.visit_as_expr(is_expr),
         ExprKind::Field(field_expr) => visitor.visit_field_expr(field_expr),
         ExprKind::Index(index_expr) => visitor.visit_index_expr(index_expr),
@@ -817,6 +839,100 @@ pub fn walk_if_expr<'heap, T: Visitor<'heap> + ?Sized>(
     }
 }
 
+pub fn walk_match_expr<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    MatchExpr {
+        id,
+        span,
+        scrutinee,
+        arms,
+    }: &mut MatchExpr<'heap>,
+) {
+    visitor.visit_id(id);
+    visitor.visit_span(span);
+
+    visitor.visit_expr(scrutinee);
+
+    for arm in arms {
+        visitor.visit_match_arm(arm);
+    }
+}
+
+pub fn walk_match_arm<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    MatchArm {
+        id,
+        span,
+        pattern,
+        body,
+    }: &mut MatchArm<'heap>,
+) {
+    visitor.visit_id(id);
+    visitor.visit_span(span);
+
+    visitor.visit_pattern(pattern);
+    visitor.visit_expr(body);
+}
+
+pub fn walk_pattern<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Pattern { id, span, kind }: &mut Pattern<'heap>,
+) {
+    visitor.visit_id(id);
+    visitor.visit_span(span);
+
+    match kind {
+        PatternKind::Wildcard | PatternKind::Literal(_) => {}
+        PatternKind::Binding(name) => visitor.visit_ident(name),
+        PatternKind::Tuple(elements) => {
+            for element in elements {
+                visitor.visit_pattern(element);
+            }
+        }
+        PatternKind::Struct(fields) => {
+            for field in fields {
+                visitor.visit_struct_pattern(field);
+            }
+        }
+        PatternKind::Constructor(constructor) => visitor.visit_constructor_pattern(constructor),
+    }
+}
+
+pub fn walk_struct_pattern<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    StructPattern {
+        id,
+        span,
+        name,
+        pattern,
+    }: &mut StructPattern<'heap>,
+) {
+    visitor.visit_id(id);
+    visitor.visit_span(span);
+
+    visitor.visit_ident(name);
+    visitor.visit_pattern(pattern);
+}
+
+pub fn walk_constructor_pattern<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    ConstructorPattern {
+        id,
+        span,
+        path,
+        argument,
+    }: &mut ConstructorPattern<'heap>,
+) {
+    visitor.visit_id(id);
+    visitor.visit_span(span);
+
+    visitor.visit_path(path);
+
+    if let Some(argument) = argument {
+        visitor.visit_pattern(argument);
+    }
+}
+
 pub fn walk_as_expr<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     AsExpr {
@@ -160,7 +160,7 @@ fn find_test_cases(entry_point: &EntryPoint) -> Vec<TestCase> {
             .filter_map(|component| component.as_os_str().to_str().map(ToOwned::to_owned))
             .collect();
 
-        if extension.is_some_and(|extension| extension == "jsonc") {
+        if extension.is_some_and(|extension| extension == "jsonc" || extension == "hql") {
             candidates.push((file_path, namespace));
         }
     }
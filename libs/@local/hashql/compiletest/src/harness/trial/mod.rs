@@ -19,7 +19,6 @@ use hashql_diagnostics::{
     diagnostic::render::{ColorDepth, Format, RenderOptions},
     source::{DiagnosticSpan, SourceId},
 };
-use hashql_syntax_jexpr::Parser;
 use line_index::LineIndex;
 use nextest_filtering::{BinaryQuery, EvalContext, Filterset, TestQuery};
 use nextest_metadata::TestCaseName;
@@ -86,12 +85,12 @@ where
     Some(output.join("\n\n"))
 }
 
-fn parse_source<'heap>(
+fn parse_jexpr_source<'heap>(
     source: &str,
     heap: &'heap Heap,
 ) -> Result<(Expr<'heap>, SpanTable<hashql_syntax_jexpr::span::Span>), Report<TrialError>> {
     let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
-    let mut parser = Parser::new(heap, &mut spans);
+    let mut parser = hashql_syntax_jexpr::Parser::new(heap, &mut spans);
 
     let expr = parser
         .parse_expr(source.as_bytes())
@@ -100,6 +99,22 @@ fn parse_source<'heap>(
     Ok((expr, spans))
 }
 
+fn parse_text_source<'heap>(
+    source: &str,
+    heap: &'heap Heap,
+) -> Result<(Expr<'heap>, SpanTable<hashql_syntax_text::span::Span>), Report<TrialError>> {
+    let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+    let mut parser = hashql_syntax_text::Parser::new(heap, &mut spans);
+
+    let expr = parser
+        .parse_expr(source)
+        .map_err(|failure| *failure.primary)
+        .change_context(TrialError::SourceParsing)?
+        .value;
+
+    Ok((expr, spans))
+}
+
 fn bless_output(
     stats: &mut TrialStatistics,
     path: &Path,
@@ -302,18 +317,61 @@ impl Trial {
         let (source, line_index, annotations) =
             stats.time(TrialSection::ReadSource, |stats| self.load_source(stats))?;
 
-        let (expr, spans) = stats.time(TrialSection::Parse, |_| parse_source(&source, &heap))?;
+        // Test files written in the text syntax use the `.hql` extension, everything else is
+        // J-Expr.
+        if self
+            .path
+            .extension()
+            .is_some_and(|extension| extension == "hql")
+        {
+            let (expr, spans) =
+                stats.time(TrialSection::Parse, |_| parse_text_source(&source, &heap))?;
+
+            self.run_parsed(
+                context,
+                stats,
+                &heap,
+                (&source, &line_index, &annotations),
+                expr,
+                &spans,
+            )
+        } else {
+            let (expr, spans) =
+                stats.time(TrialSection::Parse, |_| parse_jexpr_source(&source, &heap))?;
+
+            self.run_parsed(
+                context,
+                stats,
+                &heap,
+                (&source, &line_index, &annotations),
+                expr,
+                &spans,
+            )
+        }
+    }
 
+    fn run_parsed<'heap, S>(
+        &self,
+        context: &TrialContext,
+        stats: &mut TrialStatistics,
+        heap: &'heap Heap,
+        (source, line_index, annotations): (&str, &LineIndex, &FileAnnotations),
+        expr: Expr<'heap>,
+        spans: &SpanTable<S>,
+    ) -> Result<(), Report<[TrialError]>>
+    where
+        S: Span,
+    {
         let (received_stdout, diagnostics, secondary) =
-            stats.time(TrialSection::Run, |_| self.run_suite(&heap, expr))?;
+            stats.time(TrialSection::Run, |_| self.run_suite(heap, expr))?;
 
         let mut sink = ReportSink::new_armed();
 
         let result = stats.time(TrialSection::Verify, |_| {
             verify_annotations(
-                &source,
-                &mut &spans,
-                &line_index,
+                source,
+                &mut &*spans,
+                line_index,
                 &diagnostics,
                 &annotations.diagnostics,
             )
@@ -321,7 +379,7 @@ impl Trial {
         sink.attempt(result.change_context(TrialError::Annotation));
 
         let received_stderr = stats.time(TrialSection::RenderStderr, |_| {
-            render_stderr(&source, &spans, &diagnostics)
+            render_stderr(source, spans, &diagnostics)
         });
 
         let result = stats.time(TrialSection::Assert, |stats| {
@@ -170,7 +170,7 @@ impl<'heap> Pipeline<'heap> {
             &types
         ));
 
-        let node = tri!(hashql_hir::lower::lower(
+        let (node, _) = tri!(hashql_hir::lower::lower(
             node,
             &types,
             &mut self.env,
@@ -0,0 +1,118 @@
+use hashql_ast::node::expr::Expr;
+use hashql_core::{heap::ResetAllocator as _, r#type::environment::Environment};
+use hashql_diagnostics::DiagnosticIssues;
+use hashql_eval::{
+    context::{CodeExecutionContext, CodeGenerationContext},
+    explain::Explain,
+    postgres::PostgresCompiler,
+};
+use hashql_mir::{
+    context::MirContext,
+    intern::Interner,
+    pass::{
+        GlobalAnalysisPass as _, analysis::SizeEstimationAnalysis, execution::ExecutionAnalysis,
+    },
+};
+
+use super::{
+    RunContext, Suite, SuiteDiagnostic, common::process_issues,
+    mir_pass_transform_post_inline::mir_pass_transform_post_inline,
+};
+
+pub(crate) struct EvalExplain;
+
+impl Suite for EvalExplain {
+    fn name(&self) -> &'static str {
+        "eval/explain"
+    }
+
+    fn description(&self) -> &'static str {
+        "Explain output of compiled queries"
+    }
+
+    fn secondary_file_extensions(&self) -> &[&str] {
+        &["json"]
+    }
+
+    fn run<'heap>(
+        &self,
+        RunContext {
+            heap,
+            diagnostics,
+            secondary_outputs,
+            ..
+        }: RunContext<'_, 'heap>,
+        expr: Expr<'heap>,
+    ) -> Result<String, SuiteDiagnostic> {
+        let mut environment = Environment::new(heap);
+        let interner = Interner::new(heap);
+
+        let (_, mut bodies, mut scratch) = mir_pass_transform_post_inline(
+            heap,
+            expr,
+            &interner,
+            (),
+            &mut environment,
+            diagnostics,
+        )?;
+
+        let mut context = MirContext {
+            heap,
+            env: &environment,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        };
+
+        let mut size_estimation_analysis = SizeEstimationAnalysis::new_in(&scratch);
+        size_estimation_analysis.run(&mut context, &bodies);
+        let footprints = size_estimation_analysis.finish();
+        scratch.reset();
+
+        let analysis = ExecutionAnalysis {
+            footprints: &footprints,
+            scratch: &mut scratch,
+        };
+        let analysis = analysis.run_all_in(&mut context, &mut bodies, heap);
+        scratch.reset();
+
+        process_issues(diagnostics, context.diagnostics)?;
+
+        let interner = interner.into();
+        let mut context = CodeGenerationContext::new_in(
+            &environment,
+            &interner,
+            &bodies,
+            &analysis,
+            heap,
+            &mut scratch,
+        );
+        scratch.reset();
+
+        let queries = PostgresCompiler::new_in(&mut context, &mut scratch).compile();
+        scratch.reset();
+
+        process_issues(diagnostics, context.diagnostics)?;
+
+        let explain = Explain::new(
+            CodeExecutionContext {
+                env: &environment,
+                interner: &interner,
+                bodies: &bodies,
+                execution: &analysis,
+                alloc: heap,
+            },
+            &queries,
+        );
+
+        let plan = serde_json::to_string_pretty(&explain.plan())
+            .expect("should be able to serialize the plan");
+        secondary_outputs.insert("json", plan);
+
+        let mut output = Vec::new();
+        explain
+            .render(&mut output)
+            .expect("should be able to write to buffer");
+
+        Ok(String::from_utf8_lossy_owned(output))
+    }
+}
@@ -1,15 +1,211 @@
-use hashql_core::r#type::environment::Environment;
-use hashql_diagnostics::Diagnostic;
+use alloc::alloc::Global;
+use core::{convert::Infallible, fmt::Write as _, ops::ControlFlow};
+
+use error_stack::ReportSink;
+use hashql_core::{id::Id as _, span::SpanId, r#type::environment::Environment};
+use hashql_diagnostics::{
+    Diagnostic, Label,
+    category::{DiagnosticCategory, TerminalDiagnosticCategory},
+    diagnostic::Message,
+    severity::Severity,
+};
 use hashql_mir::{
+    body::{Body, basic_block::BasicBlockId},
+    def::{DefId, DefIdSlice},
     intern::Interner,
-    interpret::{CallStack, Inputs, Runtime, RuntimeConfig},
+    interpret::{
+        Breakpoint, CallStack, Inputs, Runtime, RuntimeConfig, Yield, error::InterpretDiagnostic,
+        trace::Trace, value::Value,
+    },
+    pretty::{TextFormat, TextFormatOptions},
 };
 
 use super::{
-    RunContext, Suite, SuiteDiagnostic,
+    RunContext, Suite, SuiteDiagnostic, SuiteDirectives,
     mir_pass_transform_post_inline::mir_pass_transform_post_inline,
     mir_pass_transform_pre_inline::TextRenderer,
 };
+use crate::harness::trial::TrialError;
+
+const UNSUPPORTED_SUSPENSION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-suspension",
+    name: "Unsupported suspension",
+};
+
+/// The suite has no data store to fulfil a suspension, such as a graph read, with.
+fn unsupported_suspension(span: SpanId) -> SuiteDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        Box::new(UNSUPPORTED_SUSPENSION) as Box<dyn DiagnosticCategory>,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        "The interpreter suspended, but suspensions cannot be fulfilled",
+    ));
+
+    diagnostic.add_message(Message::help(
+        "The `mir/interpret` suite runs without a data store. Queries that read from the graph \
+         need to be run through the `eval/orchestrator` suite instead.",
+    ));
+
+    diagnostic
+}
+
+fn trace_enabled(
+    this: &impl Suite,
+    directives: &SuiteDirectives,
+    reports: &mut ReportSink<TrialError>,
+) -> bool {
+    let Some(trace) = directives.get("trace") else {
+        return false;
+    };
+
+    let Some(trace) = trace.as_bool() else {
+        reports.capture(TrialError::Run(
+            this.name(),
+            "suite#trace must be a valid boolean",
+        ));
+
+        return false;
+    };
+
+    trace
+}
+
+/// How the interpreter is stepped through, when requested by the directives.
+enum StepMode {
+    /// Stop whenever any block is entered.
+    Step,
+    /// Stop whenever one of the breakpoints is entered.
+    Breakpoints(Vec<Breakpoint>),
+}
+
+fn step_mode(
+    this: &impl Suite,
+    directives: &SuiteDirectives,
+    reports: &mut ReportSink<TrialError>,
+) -> Option<StepMode> {
+    if let Some(step) = directives.get("step") {
+        let Some(step) = step.as_bool() else {
+            reports.capture(TrialError::Run(
+                this.name(),
+                "suite#step must be a valid boolean",
+            ));
+
+            return None;
+        };
+
+        if step {
+            return Some(StepMode::Step);
+        }
+    }
+
+    let breakpoints = directives.get("breakpoints")?;
+
+    // Breakpoints are given as `[body, block]` pairs of indices.
+    let breakpoints = breakpoints.as_array().and_then(|breakpoints| {
+        breakpoints
+            .iter()
+            .map(|breakpoint| match breakpoint.as_array()?.as_slice() {
+                [body, block] => Some(Breakpoint {
+                    body: DefId::from_usize(usize::try_from(body.as_integer()?).ok()?),
+                    block: BasicBlockId::from_usize(usize::try_from(block.as_integer()?).ok()?),
+                }),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+    });
+
+    let Some(breakpoints) = breakpoints else {
+        reports.capture(TrialError::Run(
+            this.name(),
+            "suite#breakpoints must be an array of `[body, block]` pairs",
+        ));
+
+        return None;
+    };
+
+    Some(StepMode::Breakpoints(breakpoints))
+}
+
+fn format_trace<'heap>(bodies: &DefIdSlice<Body<'heap>>, trace: &Trace<'heap>) -> String {
+    let mut formatter = TextFormat::new(TextFormatOptions {
+        writer: Vec::new(),
+        indent: 4,
+        sources: bodies,
+        types: (),
+        annotations: (),
+    });
+
+    formatter
+        .format_trace(bodies, trace.events())
+        .expect("should be able to write to buffer");
+
+    String::from_utf8_lossy_owned(formatter.writer)
+}
+
+/// Drives the interpreter in step mode, recording the state at every stop.
+///
+/// At every stop, the events since the previous stop are rendered, followed by the locals of the
+/// innermost frame.
+#[expect(clippy::use_debug, reason = "values have no textual representation")]
+fn run_stepped<'ctx, 'heap>(
+    bodies: &DefIdSlice<Body<'heap>>,
+    runtime: &mut Runtime<'ctx, 'heap, Global, &mut Trace<'heap>>,
+    mut callstack: CallStack<'ctx, 'heap>,
+    mode: &StepMode,
+    output: &mut String,
+) -> Result<Value<'heap>, SuiteDiagnostic> {
+    runtime.reset();
+
+    let mut index = 0_usize;
+    loop {
+        let result = match mode {
+            StepMode::Step => runtime.run_until_block_entry::<Infallible>(&mut callstack),
+            StepMode::Breakpoints(breakpoints) => {
+                runtime.run_until_breakpoint::<Infallible>(&mut callstack, breakpoints)
+            }
+        };
+
+        let result = result.map_err(|error| {
+            let spans = callstack.unwind();
+            let diagnostic: InterpretDiagnostic = error.into_diagnostic(
+                spans.map(|(_, span)| span),
+                |suspension| match suspension {},
+            );
+
+            diagnostic.generalize().boxed()
+        })?;
+
+        output.push_str(&format_trace(bodies, runtime.observer()));
+        runtime.observer_mut().clear();
+
+        let breakpoint = match result {
+            ControlFlow::Break(breakpoint) => breakpoint,
+            ControlFlow::Continue(Yield::Return(value)) => return Ok(value),
+            ControlFlow::Continue(Yield::Suspension(_)) => {
+                let span = callstack
+                    .unwind()
+                    .map(|(_, span)| span)
+                    .next()
+                    .unwrap_or(SpanId::SYNTHETIC);
+
+                return Err(unsupported_suspension(span));
+            }
+        };
+
+        index += 1;
+        let _ = writeln!(output, "\n──── stop {index} at {breakpoint} ────");
+
+        if let Ok(locals) = callstack.locals::<Infallible, Global>() {
+            for (local, value) in locals.iter() {
+                let _ = writeln!(output, "{local} = {value:?}");
+            }
+        }
+
+        output.push('\n');
+    }
+}
 
 pub(crate) struct MirInterpret;
 
@@ -23,7 +219,7 @@ impl Suite for MirInterpret {
     }
 
     fn secondary_file_extensions(&self) -> &[&str] {
-        &["mir"]
+        &["mir", "trace", "steps"]
     }
 
     fn run<'heap>(
@@ -31,7 +227,9 @@ impl Suite for MirInterpret {
         RunContext {
             heap,
             diagnostics,
+            suite_directives,
             secondary_outputs,
+            reports,
             ..
         }: RunContext<'_, 'heap>,
         expr: hashql_ast::node::expr::Expr<'heap>,
@@ -39,6 +237,9 @@ impl Suite for MirInterpret {
         let mut environment = Environment::new(heap);
         let interner = Interner::new(heap);
 
+        let emit_trace = trace_enabled(self, suite_directives, reports);
+        let step_mode = step_mode(self, suite_directives, reports);
+
         let mut buffer = Vec::new();
 
         let (root, bodies, _) = mir_pass_transform_post_inline(
@@ -53,13 +254,30 @@ impl Suite for MirInterpret {
         secondary_outputs.insert("mir", String::from_utf8_lossy_owned(buffer));
 
         let inputs = Inputs::new();
-        let mut runtime = Runtime::new(RuntimeConfig::default(), &bodies, &inputs);
+        let mut trace = Trace::new();
+        let mut runtime =
+            Runtime::new(RuntimeConfig::default(), &bodies, &inputs).with_observer(&mut trace);
         let callstack = CallStack::new(&runtime, root, []);
 
-        let output = runtime
-            .run(callstack, |_| unimplemented!())
-            .map_err(Diagnostic::generalize)
-            .map_err(Diagnostic::boxed)?;
+        let output = if let Some(mode) = &step_mode {
+            let mut steps = String::new();
+            let output = run_stepped(&bodies, &mut runtime, callstack, mode, &mut steps)?;
+
+            secondary_outputs.insert("steps", steps);
+            output
+        } else {
+            runtime
+                .run(callstack, |_| unimplemented!())
+                .map_err(Diagnostic::generalize)
+                .map_err(Diagnostic::boxed)?
+        };
+
+        drop(runtime);
+
+        // In step mode the trace has been rendered alongside the stops instead
+        if emit_trace && step_mode.is_none() {
+            secondary_outputs.insert("trace", format_trace(&bodies, &trace));
+        }
 
         Ok(format!("{output:#?}"))
     }
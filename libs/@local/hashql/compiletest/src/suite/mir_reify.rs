@@ -53,7 +53,7 @@ pub(crate) fn mir_reify<'heap>(
         NodeData::from_ast(expr, &mut hir_context, &types),
     )?;
 
-    let node = process_status(
+    let (node, _) = process_status(
         diagnostics,
         hashql_hir::lower::lower(node, &types, environment, &mut hir_context),
     )?;
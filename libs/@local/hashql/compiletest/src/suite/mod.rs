@@ -6,6 +6,7 @@ mod ast_lower_sanitizer;
 mod ast_lower_type_definition_extractor;
 mod ast_lower_type_extractor;
 pub(crate) mod common;
+mod eval_explain;
 mod eval_postgres;
 mod hir_lower_alias_replacement;
 mod hir_lower_checking;
@@ -23,10 +24,12 @@ mod mir_pass_transform_administrative_reduction;
 mod mir_pass_transform_cfg_simplify;
 mod mir_pass_transform_dse;
 mod mir_pass_transform_forward_substitution;
+mod mir_pass_transform_gvn;
 mod mir_pass_transform_inline;
 mod mir_pass_transform_inst_simplify;
 mod mir_pass_transform_post_inline;
 mod mir_pass_transform_pre_inline;
+mod mir_pass_transform_sccp;
 mod mir_reify;
 mod parse_syntax_dump;
 
@@ -42,8 +45,8 @@ use self::{
     ast_lower_node_renumberer::AstLowerNodeRenumbererSuite,
     ast_lower_sanitizer::AstLowerSanitizerSuite,
     ast_lower_type_definition_extractor::AstLowerTypeDefinitionExtractorSuite,
-    ast_lower_type_extractor::AstLowerTypeExtractorSuite, eval_postgres::EvalPostgres,
-    hir_lower_alias_replacement::HirLowerAliasReplacementSuite,
+    ast_lower_type_extractor::AstLowerTypeExtractorSuite, eval_explain::EvalExplain,
+    eval_postgres::EvalPostgres, hir_lower_alias_replacement::HirLowerAliasReplacementSuite,
     hir_lower_checking::HirLowerTypeCheckingSuite, hir_lower_ctor::HirLowerCtorSuite,
     hir_lower_graph_hoisting::HirLowerGraphHoistingSuite,
     hir_lower_inference::HirLowerTypeInferenceSuite,
@@ -56,10 +59,11 @@ use self::{
     mir_pass_transform_cfg_simplify::MirPassTransformCfgSimplify,
     mir_pass_transform_dse::MirPassTransformDse,
     mir_pass_transform_forward_substitution::MirPassTransformForwardSubstitution,
-    mir_pass_transform_inline::MirPassTransformInline,
+    mir_pass_transform_gvn::MirPassTransformGvn, mir_pass_transform_inline::MirPassTransformInline,
     mir_pass_transform_inst_simplify::MirPassTransformInstSimplify,
     mir_pass_transform_post_inline::MirPassTransformPostInline,
-    mir_pass_transform_pre_inline::MirPassTransformPreInline, mir_reify::MirReifySuite,
+    mir_pass_transform_pre_inline::MirPassTransformPreInline,
+    mir_pass_transform_sccp::MirPassTransformSccp, mir_reify::MirReifySuite,
     parse_syntax_dump::ParseSyntaxDumpSuite,
 };
 use crate::harness::trial::TrialError;
@@ -140,6 +144,7 @@ const SUITES: &[&dyn Suite] = &[
     &AstLowerSanitizerSuite,
     &AstLowerTypeDefinitionExtractorSuite,
     &AstLowerTypeExtractorSuite,
+    &EvalExplain,
     &EvalPostgres,
     &HirLowerAliasReplacementSuite,
     &HirLowerCtorSuite,
@@ -157,10 +162,12 @@ const SUITES: &[&dyn Suite] = &[
     &MirPassTransformCfgSimplify,
     &MirPassTransformDse,
     &MirPassTransformForwardSubstitution,
+    &MirPassTransformGvn,
     &MirPassTransformInline,
     &MirPassTransformInstSimplify,
     &MirPassTransformPostInline,
     &MirPassTransformPreInline,
+    &MirPassTransformSccp,
     &MirReifySuite,
     &ParseSyntaxDumpSuite,
 ];
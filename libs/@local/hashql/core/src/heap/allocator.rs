@@ -35,6 +35,12 @@ impl Allocator {
     pub(crate) fn alloc_with<T>(&self, func: impl FnOnce() -> T) -> &mut T {
         BumpBox::leak(self.0.alloc_with(func))
     }
+
+    /// Returns the number of bytes allocated in the arena since the last reset.
+    #[inline]
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.0.stats().allocated()
+    }
 }
 
 impl BumpAllocator for Allocator {
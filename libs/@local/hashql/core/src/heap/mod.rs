@@ -285,6 +285,15 @@ impl Heap {
         // symbols have static lifetime.
         unsafe { Symbol::from_repr(repr) }
     }
+
+    /// Returns the number of bytes allocated in the heap since the last reset.
+    ///
+    /// Allocations are never freed individually, so this only grows until the heap is reset.
+    #[inline]
+    #[must_use]
+    pub fn allocated_bytes(&self) -> usize {
+        self.inner.allocated_bytes()
+    }
 }
 
 impl Default for Heap {
@@ -208,6 +208,15 @@ impl<'env, 'heap> ModuleNamespace<'env, 'heap> {
             .collect()
     }
 
+    /// Return all the items that have been imported so far, together with the name they are
+    /// available under.
+    pub fn items(&self) -> impl Iterator<Item = (Symbol<'heap>, Item<'heap>)> {
+        self.imports.iter().filter_map(|import| match import.item {
+            ImportReference::Item(item) => Some((import.name, item)),
+            ImportReference::Binding(_) => None,
+        })
+    }
+
     fn import_absolute_static(
         &mut self,
         name: &'static str,
@@ -354,6 +363,7 @@ impl<'env, 'heap> ModuleNamespace<'env, 'heap> {
         successful &= self.import_absolute_static("if", ["kernel", "special_form", "if"]);
         successful &= self.import_absolute_static("as", ["kernel", "special_form", "as"]);
         successful &= self.import_absolute_static("let", ["kernel", "special_form", "let"]);
+        successful &= self.import_absolute_static("match", ["kernel", "special_form", "match"]);
         successful &= self.import_absolute_static("type", ["kernel", "special_form", "type"]);
         successful &= self.import_absolute_static("newtype", ["kernel", "special_form", "newtype"]);
         successful &= self.import_absolute_static("use", ["kernel", "special_form", "use"]);
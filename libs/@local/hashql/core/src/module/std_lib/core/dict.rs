@@ -0,0 +1,77 @@
+use super::{func, option::types::option};
+use crate::{
+    module::{
+        locals::TypeDef,
+        std_lib::{ModuleDef, StandardLibrary, StandardLibraryModule, decl},
+    },
+    symbol::{Symbol, sym},
+};
+
+pub(in crate::module::std_lib) struct Dict {
+    _dependencies: (),
+}
+
+impl<'heap> StandardLibraryModule<'heap> for Dict {
+    type Children = ();
+
+    fn name() -> Symbol<'heap> {
+        sym::dict
+    }
+
+    #[expect(non_snake_case)]
+    fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
+        let mut def = ModuleDef::new();
+
+        let Integer = lib.ty.integer();
+        let Boolean = lib.ty.boolean();
+
+        let items = [
+            (
+                sym::path::core::dict::len,
+                &[sym::len] as &[Symbol<'heap>],
+                decl!(lib; <K, V>(dict: lib.ty.dict(K, V)) -> Integer),
+            ),
+            (
+                sym::path::core::dict::get,
+                &[sym::get],
+                decl!(lib; <K, V>(dict: lib.ty.dict(K, V), key: K) -> option(&lib.ty, V)),
+            ),
+            (
+                sym::path::core::dict::contains,
+                &[sym::contains],
+                decl!(lib; <K, V>(dict: lib.ty.dict(K, V), key: K) -> Boolean),
+            ),
+            (
+                sym::path::core::dict::keys,
+                &[sym::keys],
+                decl!(lib; <K, V>(dict: lib.ty.dict(K, V)) -> lib.ty.list(K)),
+            ),
+            (
+                sym::path::core::dict::values,
+                &[sym::values],
+                decl!(lib; <K, V>(dict: lib.ty.dict(K, V)) -> lib.ty.list(V)),
+            ),
+            (
+                sym::path::core::dict::insert,
+                &[sym::insert],
+                decl!(lib;
+                    <K, V>(dict: lib.ty.dict(K, V), key: K, value: V) -> lib.ty.dict(K, V)
+                ),
+            ),
+            (
+                sym::path::core::dict::concat,
+                &[sym::concat],
+                // Entries of `rhs` take precedence over entries of `lhs` with the same key
+                decl!(lib;
+                    <K, V>(lhs: lib.ty.dict(K, V), rhs: lib.ty.dict(K, V)) -> lib.ty.dict(K, V)
+                ),
+            ),
+        ];
+
+        for (name, alias, r#type) in items {
+            func(&mut def, name, alias.iter().copied(), r#type);
+        }
+
+        def
+    }
+}
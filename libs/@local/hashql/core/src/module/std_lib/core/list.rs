@@ -0,0 +1,85 @@
+use super::{func, option::types::option};
+use crate::{
+    module::{
+        locals::TypeDef,
+        std_lib::{ModuleDef, StandardLibrary, StandardLibraryModule, decl},
+    },
+    symbol::{Symbol, sym},
+};
+
+pub(in crate::module::std_lib) struct List {
+    _dependencies: (),
+}
+
+impl<'heap> StandardLibraryModule<'heap> for List {
+    type Children = ();
+
+    fn name() -> Symbol<'heap> {
+        sym::list
+    }
+
+    #[expect(non_snake_case)]
+    fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
+        let mut def = ModuleDef::new();
+
+        let Integer = lib.ty.integer();
+        let Boolean = lib.ty.boolean();
+
+        let items = [
+            (
+                sym::path::core::list::len,
+                &[sym::len] as &[Symbol<'heap>],
+                decl!(lib; <T>(list: lib.ty.list(T)) -> Integer),
+            ),
+            (
+                sym::path::core::list::get,
+                &[sym::get],
+                // Negative indices count from the end of the list
+                decl!(lib; <T>(list: lib.ty.list(T), index: Integer) -> option(&lib.ty, T)),
+            ),
+            (
+                sym::path::core::list::contains,
+                &[sym::contains],
+                decl!(lib; <T>(list: lib.ty.list(T), value: T) -> Boolean),
+            ),
+            (
+                sym::path::core::list::concat,
+                &[sym::concat],
+                decl!(lib; <T>(lhs: lib.ty.list(T), rhs: lib.ty.list(T)) -> lib.ty.list(T)),
+            ),
+            (
+                sym::path::core::list::sort,
+                &[sym::sort],
+                decl!(lib; <T>(list: lib.ty.list(T)) -> lib.ty.list(T)),
+            ),
+            (
+                sym::path::core::list::map,
+                &[sym::map],
+                decl!(lib;
+                    <T, U>(list: lib.ty.list(T), func: lib.ty.closure([T], U)) -> lib.ty.list(U)
+                ),
+            ),
+            (
+                sym::path::core::list::filter,
+                &[sym::filter],
+                decl!(lib;
+                    <T>(list: lib.ty.list(T), predicate: lib.ty.closure([T], Boolean))
+                        -> lib.ty.list(T)
+                ),
+            ),
+            (
+                sym::path::core::list::fold,
+                &[sym::fold],
+                decl!(lib;
+                    <T, U>(list: lib.ty.list(T), initial: U, func: lib.ty.closure([U, T], U)) -> U
+                ),
+            ),
+        ];
+
+        for (name, alias, r#type) in items {
+            func(&mut def, name, alias.iter().copied(), r#type);
+        }
+
+        def
+    }
+}
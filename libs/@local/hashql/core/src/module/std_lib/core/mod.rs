@@ -7,10 +7,13 @@ use crate::{
 pub(in crate::module::std_lib) mod bits;
 pub(in crate::module::std_lib) mod bool;
 pub(in crate::module::std_lib) mod cmp;
+pub(in crate::module::std_lib) mod dict;
 pub(in crate::module::std_lib) mod json;
+pub(in crate::module::std_lib) mod list;
 pub(in crate::module::std_lib) mod math;
 pub mod option;
 pub(in crate::module::std_lib) mod result;
+pub(in crate::module::std_lib) mod string;
 pub mod url;
 pub mod uuid;
 
@@ -34,10 +37,13 @@ impl<'heap> StandardLibraryModule<'heap> for Core {
         self::bits::Bits,
         self::bool::Bool,
         self::cmp::Cmp,
+        self::dict::Dict,
         self::json::Json,
+        self::list::List,
         self::math::Math,
         self::option::Option,
         self::result::Result,
+        self::string::String,
         self::url::Url,
         self::uuid::Uuid,
     );
@@ -0,0 +1,84 @@
+use super::func;
+use crate::{
+    module::{
+        locals::TypeDef,
+        std_lib::{ModuleDef, StandardLibrary, StandardLibraryModule, decl},
+    },
+    symbol::{Symbol, sym},
+};
+
+pub(in crate::module::std_lib) struct String {
+    _dependencies: (),
+}
+
+impl<'heap> StandardLibraryModule<'heap> for String {
+    type Children = ();
+
+    fn name() -> Symbol<'heap> {
+        sym::string
+    }
+
+    #[expect(non_snake_case)]
+    fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
+        let mut def = ModuleDef::new();
+
+        let String = lib.ty.string();
+        let Integer = lib.ty.integer();
+        let Boolean = lib.ty.boolean();
+
+        let items = [
+            (
+                sym::path::core::string::lowercase,
+                &[sym::lowercase] as &[Symbol<'heap>],
+                decl!(lib; <>(value: String) -> String),
+            ),
+            (
+                sym::path::core::string::uppercase,
+                &[sym::uppercase],
+                decl!(lib; <>(value: String) -> String),
+            ),
+            (
+                sym::path::core::string::contains,
+                &[sym::contains],
+                decl!(lib; <>(haystack: String, needle: String) -> Boolean),
+            ),
+            (
+                sym::path::core::string::starts_with,
+                &[sym::starts_with],
+                decl!(lib; <>(value: String, prefix: String) -> Boolean),
+            ),
+            (
+                sym::path::core::string::ends_with,
+                &[sym::ends_with],
+                decl!(lib; <>(value: String, suffix: String) -> Boolean),
+            ),
+            (
+                sym::path::core::string::length,
+                &[sym::length],
+                // The number of characters (Unicode scalar values), not bytes
+                decl!(lib; <>(value: String) -> Integer),
+            ),
+            (
+                sym::path::core::string::concat,
+                &[sym::concat],
+                decl!(lib; <>(lhs: String, rhs: String) -> String),
+            ),
+            (
+                sym::path::core::string::split,
+                &[sym::split],
+                decl!(lib; <>(value: String, separator: String) -> lib.ty.list(String)),
+            ),
+            (
+                sym::path::core::string::trim,
+                &[sym::trim],
+                decl!(lib; <>(value: String) -> String),
+            ),
+        ];
+
+        for (name, alias, r#type) in items {
+            func(&mut def, name, alias.iter().copied(), r#type);
+        }
+
+        def
+    }
+}
@@ -8,7 +8,10 @@ use crate::{
 };
 
 pub(in crate::module::std_lib) struct Body {
-    _dependencies: (std_lib::graph::Graph,),
+    _dependencies: (
+        std_lib::graph::Graph,
+        std_lib::graph::types::knowledge::entity::Entity,
+    ),
 }
 
 impl<'heap> StandardLibraryModule<'heap> for Body {
@@ -41,6 +44,58 @@ impl<'heap> StandardLibraryModule<'heap> for Body {
 
         func(&mut def, sym::path::graph_body_filter, [sym::filter], decl);
 
+        let mut entity = lib
+            .manifest::<std_lib::graph::types::knowledge::entity::Entity>()
+            .expect_newtype(heap.intern_symbol("Entity"));
+        entity.instantiate(&mut lib.instantiate);
+
+        // Links are entities themselves, which is why the properties of the entities reached by a
+        // traversal are unknown.
+        let entity = lib
+            .ty
+            .apply([(entity.arguments[0].id, lib.ty.unknown())], entity.id);
+        let returns = lib
+            .ty
+            .apply([(graph_returns.arguments[0].id, entity)], graph_returns.id);
+
+        // The entities a traversal starts from are selected by the database, every filter applied
+        // before a traversal (including `traverse`) must therefore be evaluated in SQL. A filter
+        // which would fall back to the interpreter, e.g. because it accesses the vectors of the
+        // entity, is rejected during compilation, it has to be applied after the traversal instead.
+        //
+        // `outgoing_links<T: Entity<?>>(graph: Graph<T>) -> Graph<Entity<?>>;`
+        // `incoming_links<T: Entity<?>>(graph: Graph<T>) -> Graph<Entity<?>>;`
+        // `left_entities<T: Entity<?>>(graph: Graph<T>) -> Graph<Entity<?>>;`
+        // `right_entities<T: Entity<?>>(graph: Graph<T>) -> Graph<Entity<?>>;`
+        for (name, alias) in [
+            (sym::path::graph_body_outgoing_links, sym::outgoing_links),
+            (sym::path::graph_body_incoming_links, sym::incoming_links),
+            (sym::path::graph_body_left_entities, sym::left_entities),
+            (sym::path::graph_body_right_entities, sym::right_entities),
+        ] {
+            let decl = decl!(lib;
+                <T: entity>(graph: lib.ty.apply([(graph_param.arguments[0].id, T)], graph_param.id))
+                    -> returns
+            );
+
+            func(&mut def, name, [alias], decl);
+        }
+
+        // `traverse<T: Entity<?>>(graph: Graph<T>, depth: Integer) -> Graph<Entity<?>>;`
+        // `depth` must be an integer literal, as it determines the shape of the query.
+        let decl = decl!(lib;
+            <T: entity>(graph: lib.ty.apply([(graph_param.arguments[0].id, T)], graph_param.id),
+                depth: lib.ty.integer()
+            ) -> returns
+        );
+
+        func(
+            &mut def,
+            sym::path::graph_body_traverse,
+            [sym::traverse],
+            decl,
+        );
+
         def
     }
 }
@@ -12,6 +12,9 @@ pub(in crate::module::std_lib) struct Head {
         std_lib::graph::Graph,
         std_lib::graph::temporal::Temporal,
         std_lib::graph::types::knowledge::entity::Entity,
+        std_lib::graph::types::ontology::data_type::DataType,
+        std_lib::graph::types::ontology::property_type::PropertyType,
+        std_lib::graph::types::ontology::entity_type::EntityType,
     ),
 }
 
@@ -56,6 +59,48 @@ impl<'heap> StandardLibraryModule<'heap> for Head {
             decl!(lib; <>(axis: query_temporal_axes_ty.id) -> entities_returns),
         );
 
+        let data_type = lib
+            .manifest::<std_lib::graph::types::ontology::data_type::DataType>()
+            .expect_newtype(heap.intern_symbol("DataType"));
+        let property_type = lib
+            .manifest::<std_lib::graph::types::ontology::property_type::PropertyType>()
+            .expect_newtype(heap.intern_symbol("PropertyType"));
+        let entity_type = lib
+            .manifest::<std_lib::graph::types::ontology::entity_type::EntityType>()
+            .expect_newtype(heap.intern_symbol("EntityType"));
+
+        // ::graph::head::data_types(axis: TimeAxis) -> Graph<DataType>;
+        // ::graph::head::property_types(axis: TimeAxis) -> Graph<PropertyType>;
+        // ::graph::head::entity_types(axis: TimeAxis) -> Graph<EntityType>;
+        for (name, alias, vertex) in [
+            (
+                sym::path::graph_head_data_types,
+                sym::data_types,
+                data_type.id,
+            ),
+            (
+                sym::path::graph_head_property_types,
+                sym::property_types,
+                property_type.id,
+            ),
+            (
+                sym::path::graph_head_entity_types,
+                sym::entity_types,
+                entity_type.id,
+            ),
+        ] {
+            let returns = lib
+                .ty
+                .apply([(graph_ty.arguments[0].id, vertex)], graph_ty.id);
+
+            func(
+                &mut def,
+                name,
+                [alias],
+                decl!(lib; <>(axis: query_temporal_axes_ty.id) -> returns),
+            );
+        }
+
         def
     }
 }
@@ -2,7 +2,11 @@ use crate::{
     module::{
         StandardLibrary,
         locals::TypeDef,
-        std_lib::{self, ModuleDef, StandardLibraryModule, core::func, decl},
+        std_lib::{
+            self, ModuleDef, StandardLibraryModule,
+            core::{func, option::types::option},
+            decl,
+        },
     },
     symbol::{Symbol, sym},
 };
@@ -18,14 +22,22 @@ impl<'heap> StandardLibraryModule<'heap> for Tail {
         sym::tail
     }
 
+    #[expect(non_snake_case)]
     fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
         let mut def = ModuleDef::new();
         let heap = lib.heap;
 
+        let Integer = lib.ty.integer();
+        let Number = lib.ty.number();
+        let Boolean = lib.ty.boolean();
+
         let graph = lib.manifest::<std_lib::graph::Graph>();
 
         let mut graph_ty = graph.expect_type(heap.intern_symbol("Graph"));
+        let mut graph_returns = graph_ty;
+
         graph_ty.instantiate(&mut lib.instantiate);
+        graph_returns.instantiate(&mut lib.instantiate);
 
         // `collect<T>(graph: Graph<T>) -> List<T>;`
         let decl = decl!(lib;
@@ -39,6 +51,104 @@ impl<'heap> StandardLibraryModule<'heap> for Tail {
             decl,
         );
 
+        // `count<T>(graph: Graph<T>) -> Integer;`
+        let decl = decl!(lib;
+            <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id)) -> Integer
+        );
+
+        func(&mut def, sym::path::graph_tail_count, [sym::count], decl);
+
+        // `exists<T>(graph: Graph<T>) -> Boolean;`
+        let decl = decl!(lib;
+            <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id)) -> Boolean
+        );
+
+        func(&mut def, sym::path::graph_tail_exists, [sym::exists], decl);
+
+        // `first<T>(graph: Graph<T>) -> Option<T>;`
+        let decl = decl!(lib;
+            <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id))
+                -> option(&lib.ty, T)
+        );
+
+        func(&mut def, sym::path::graph_tail_first, [sym::first], decl);
+
+        // `group_by<T, K>(graph: Graph<T>, key: fn(vertex: T) -> K) -> Dict<K, List<T>>;`
+        let decl = decl!(lib;
+            <T, K>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id),
+                key: lib.ty.closure([T], K)
+            ) -> lib.ty.dict(K, lib.ty.list(T))
+        );
+
+        func(
+            &mut def,
+            sym::path::graph_tail_group_by,
+            [sym::group_by],
+            decl,
+        );
+
+        // `sum<T>(graph: Graph<T>, value: fn(vertex: T) -> Number) -> Number;`
+        let decl = decl!(lib;
+            <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id),
+                value: lib.ty.closure([T], Number)
+            ) -> Number
+        );
+
+        func(&mut def, sym::path::graph_tail_sum, [sym::sum], decl);
+
+        // The remaining aggregates are undefined for an empty graph.
+        //
+        // `min<T>(graph: Graph<T>, value: fn(vertex: T) -> Number) -> Option<Number>;`
+        // `max<T>(graph: Graph<T>, value: fn(vertex: T) -> Number) -> Option<Number>;`
+        // `avg<T>(graph: Graph<T>, value: fn(vertex: T) -> Number) -> Option<Number>;`
+        for (name, alias) in [
+            (sym::path::graph_tail_min, sym::min),
+            (sym::path::graph_tail_max, sym::max),
+            (sym::path::graph_tail_avg, sym::avg),
+        ] {
+            let decl = decl!(lib;
+                <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id),
+                    value: lib.ty.closure([T], Number)
+                ) -> option(&lib.ty, Number)
+            );
+
+            func(&mut def, name, [alias], decl);
+        }
+
+        // Ordering and pagination keep the graph intact, so that they can be followed by any other
+        // tail. They must come after all filters and traversals.
+        //
+        // `sort_by<T, K>(graph: Graph<T>, key: fn(vertex: T) -> K) -> Graph<T>;`
+        let decl = decl!(lib;
+            <T, K>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id),
+                key: lib.ty.closure([T], K)
+            ) -> lib.ty.apply([(graph_returns.arguments[0].id, T)], graph_returns.id)
+        );
+
+        func(
+            &mut def,
+            sym::path::graph_tail_sort_by,
+            [sym::sort_by],
+            decl,
+        );
+
+        // `count` must be an integer literal, as it determines the shape of the query.
+        //
+        // `limit<T>(graph: Graph<T>, count: Integer) -> Graph<T>;`
+        // `offset<T>(graph: Graph<T>, count: Integer) -> Graph<T>;`
+        for (name, alias) in [
+            (sym::path::graph_tail_limit, sym::limit),
+            (sym::path::graph_tail_offset, sym::offset),
+        ] {
+            let decl = decl!(lib;
+                <T>(graph: lib.ty.apply([(graph_ty.arguments[0].id, T)], graph_ty.id),
+                    count: Integer
+                ) -> lib.ty.apply([(graph_returns.arguments[0].id, T)], graph_returns.id)
+            );
+
+            func(&mut def, name, [alias], decl);
+        }
+
         def
     }
 }
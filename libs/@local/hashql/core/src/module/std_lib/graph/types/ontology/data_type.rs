@@ -0,0 +1,59 @@
+use crate::{
+    module::{
+        StandardLibrary,
+        std_lib::{self, ItemDef, ModuleDef, StandardLibraryModule, core::option::types::option},
+    },
+    symbol::{Symbol, sym},
+};
+
+pub(in crate::module::std_lib) struct DataType {
+    _dependencies: (
+        std_lib::graph::types::principal::actor_group::web::Web,
+        std_lib::graph::types::ontology::Ontology,
+    ),
+}
+
+impl<'heap> StandardLibraryModule<'heap> for DataType {
+    type Children = ();
+
+    fn name() -> Symbol<'heap> {
+        sym::data_type
+    }
+
+    fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
+        let mut def = ModuleDef::new();
+        let heap = lib.heap;
+
+        // newtype DataTypeMetadata = (web_id: Option<WebId>)
+        let web_id = lib
+            .manifest::<std_lib::graph::types::principal::actor_group::web::Web>()
+            .expect_newtype(heap.intern_symbol("WebId"));
+        let data_type_metadata_ty = lib.ty.opaque(
+            sym::path::DataTypeMetadata,
+            lib.ty.r#struct([("web_id", option(&lib.ty, web_id.id))]),
+        );
+        def.push(
+            heap.intern_symbol("DataTypeMetadata"),
+            ItemDef::newtype(lib.ty.env, data_type_metadata_ty, &[]),
+        );
+
+        // newtype DataType = (id: VersionedUrl, metadata: DataTypeMetadata, schema: ?)
+        let versioned_url = lib
+            .manifest::<std_lib::graph::types::ontology::Ontology>()
+            .expect_newtype(heap.intern_symbol("VersionedUrl"));
+        let data_type_ty = lib.ty.opaque(
+            sym::path::DataType,
+            lib.ty.r#struct([
+                ("id", versioned_url.id),
+                ("metadata", data_type_metadata_ty),
+                ("schema", lib.ty.unknown()),
+            ]),
+        );
+        def.push(
+            heap.intern_symbol("DataType"),
+            ItemDef::newtype(lib.ty.env, data_type_ty, &[]),
+        );
+
+        def
+    }
+}
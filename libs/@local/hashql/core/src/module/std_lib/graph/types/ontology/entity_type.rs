@@ -29,7 +29,7 @@ impl<'heap> StandardLibraryModule<'heap> for EntityType {
             .manifest::<std_lib::graph::types::principal::actor_group::web::Web>()
             .expect_newtype(heap.intern_symbol("WebId"));
         let entity_type_metadata_ty = lib.ty.opaque(
-            "::graph::types::ontology::entity_type::EntityTypeMetadata",
+            sym::path::EntityTypeMetadata,
             lib.ty.r#struct([("web_id", option(&lib.ty, web_id.id))]),
         );
         def.push(
@@ -37,15 +37,16 @@ impl<'heap> StandardLibraryModule<'heap> for EntityType {
             ItemDef::newtype(lib.ty.env, entity_type_metadata_ty, &[]),
         );
 
-        // newtype EntityType = (id: VersionedUrl, metadata: EntityTypeMetadata)
+        // newtype EntityType = (id: VersionedUrl, metadata: EntityTypeMetadata, schema: ?)
         let versioned_url = lib
             .manifest::<std_lib::graph::types::ontology::Ontology>()
             .expect_newtype(heap.intern_symbol("VersionedUrl"));
         let entity_id_ty = lib.ty.opaque(
-            "::graph::types::ontology::entity_type::EntityType",
+            sym::path::EntityType,
             lib.ty.r#struct([
                 ("id", versioned_url.id),
                 ("metadata", entity_type_metadata_ty),
+                ("schema", lib.ty.unknown()),
             ]),
         );
         def.push(
@@ -6,7 +6,9 @@ use crate::{
     symbol::{Symbol, sym},
 };
 
+pub(in crate::module::std_lib) mod data_type;
 pub(in crate::module::std_lib) mod entity_type;
+pub(in crate::module::std_lib) mod property_type;
 
 pub mod types {
     use crate::{
@@ -63,7 +65,11 @@ pub(in crate::module::std_lib) struct Ontology {
 }
 
 impl<'heap> StandardLibraryModule<'heap> for Ontology {
-    type Children = (self::entity_type::EntityType,);
+    type Children = (
+        self::data_type::DataType,
+        self::property_type::PropertyType,
+        self::entity_type::EntityType,
+    );
 
     fn name() -> Symbol<'heap> {
         sym::ontology
@@ -0,0 +1,59 @@
+use crate::{
+    module::{
+        StandardLibrary,
+        std_lib::{self, ItemDef, ModuleDef, StandardLibraryModule, core::option::types::option},
+    },
+    symbol::{Symbol, sym},
+};
+
+pub(in crate::module::std_lib) struct PropertyType {
+    _dependencies: (
+        std_lib::graph::types::principal::actor_group::web::Web,
+        std_lib::graph::types::ontology::Ontology,
+    ),
+}
+
+impl<'heap> StandardLibraryModule<'heap> for PropertyType {
+    type Children = ();
+
+    fn name() -> Symbol<'heap> {
+        sym::property_type
+    }
+
+    fn define(lib: &mut StandardLibrary<'_, 'heap>) -> ModuleDef<'heap> {
+        let mut def = ModuleDef::new();
+        let heap = lib.heap;
+
+        // newtype PropertyTypeMetadata = (web_id: Option<WebId>)
+        let web_id = lib
+            .manifest::<std_lib::graph::types::principal::actor_group::web::Web>()
+            .expect_newtype(heap.intern_symbol("WebId"));
+        let property_type_metadata_ty = lib.ty.opaque(
+            sym::path::PropertyTypeMetadata,
+            lib.ty.r#struct([("web_id", option(&lib.ty, web_id.id))]),
+        );
+        def.push(
+            heap.intern_symbol("PropertyTypeMetadata"),
+            ItemDef::newtype(lib.ty.env, property_type_metadata_ty, &[]),
+        );
+
+        // newtype PropertyType = (id: VersionedUrl, metadata: PropertyTypeMetadata, schema: ?)
+        let versioned_url = lib
+            .manifest::<std_lib::graph::types::ontology::Ontology>()
+            .expect_newtype(heap.intern_symbol("VersionedUrl"));
+        let property_type_ty = lib.ty.opaque(
+            sym::path::PropertyType,
+            lib.ty.r#struct([
+                ("id", versioned_url.id),
+                ("metadata", property_type_metadata_ty),
+                ("schema", lib.ty.unknown()),
+            ]),
+        );
+        def.push(
+            heap.intern_symbol("PropertyType"),
+            ItemDef::newtype(lib.ty.env, property_type_ty, &[]),
+        );
+
+        def
+    }
+}
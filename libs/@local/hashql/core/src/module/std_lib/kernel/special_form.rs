@@ -42,6 +42,7 @@ impl<'heap> StandardLibraryModule<'heap> for SpecialForm {
         Self::make(lib, &mut def, sym::path::r#if, [sym::r#if]);
         Self::make(lib, &mut def, sym::path::r#as, [sym::r#as]);
         Self::make(lib, &mut def, sym::path::r#let, [sym::r#let]);
+        Self::make(lib, &mut def, sym::path::r#match, [sym::r#match]);
         Self::make(lib, &mut def, sym::path::r#type, [sym::r#type]);
         Self::make(lib, &mut def, sym::path::newtype, [sym::newtype]);
         Self::make(lib, &mut def, sym::path::r#use, [sym::r#use]);
@@ -15,6 +15,7 @@ hashql_macros::define_symbols! {
     and,
     archived,
     archived_by_id,
+    avg,
     bar,
     base_url,
     BaseUrl,
@@ -30,9 +31,12 @@ hashql_macros::define_symbols! {
     cbrt,
     cmp,
     collect,
+    concat,
     Confidence,
     confidence,
+    contains,
     core,
+    count,
     created_at_decision_time,
     CreatedAtDecisionTime,
     created_at_transaction_time,
@@ -42,6 +46,7 @@ hashql_macros::define_symbols! {
     decision_time,
     DecisionTime,
     Dict,
+    dict,
     div,
     draft_id,
     DraftId,
@@ -53,6 +58,7 @@ hashql_macros::define_symbols! {
     EditionCreatedById,
     encodings,
     end,
+    ends_with,
     entity,
     Entity,
     entity_edition_id,
@@ -69,35 +75,52 @@ hashql_macros::define_symbols! {
     eq,
     Err,
     ExclusiveTemporalBound,
+    exists,
     filter,
     FiniteTemporalBound,
+    first,
+    fold,
     foo,
+    get,
+    group_by,
     gt,
     gte,
     id,
     InclusiveTemporalBound,
+    incoming_links,
     index,
     inferred,
     InferredEntityProvenance,
     input,
     input_exists: "$exists",
+    insert,
     Integer,
     Intersection,
     Interval,
     kernel,
+    keys,
+    left_entities,
     left_entity_confidence,
     left_entity_id,
     left_entity_provenance,
     left_entity_uuid,
     left_entity_web_id,
     LeftClosedTemporalInterval,
+    len,
+    length,
+    limit,
     link_data,
     LinkData,
     List,
+    list,
+    lowercase,
     lt,
     lte,
+    map,
     math,
+    max,
     metadata,
+    min,
     mul,
     ne,
     Never,
@@ -106,12 +129,14 @@ hashql_macros::define_symbols! {
     Null,
     null,
     Number,
+    offset,
     Ok,
     OntologyTypeVersion,
     OpenTemporalBound,
     option,
     Option,
     or,
+    outgoing_links,
     pinned,
     PinnedDecisionTimeTemporalAxes,
     PinnedTransactionTimeTemporalAxes,
@@ -134,6 +159,7 @@ hashql_macros::define_symbols! {
     r#in: "in",
     r#is: "is",
     r#let: "let",
+    r#match: "match",
     r#mod: "mod",
     r#newtype: "newtype",
     r#true: "true",
@@ -145,6 +171,7 @@ hashql_macros::define_symbols! {
     RecordId,
     rem,
     Result,
+    right_entities,
     right_entity_confidence,
     right_entity_id,
     right_entity_provenance,
@@ -156,11 +183,17 @@ hashql_macros::define_symbols! {
     shl,
     shr,
     Some,
+    sort,
+    sort_by,
     special_form,
+    split,
     sqrt,
     start,
+    starts_with,
     String,
+    string,
     sub,
+    sum,
     T,
     temporal,
     temporal_versioning,
@@ -172,14 +205,18 @@ hashql_macros::define_symbols! {
     Timestamp,
     transaction_time,
     TransactionTime,
+    traverse,
+    trim,
     U,
     UnboundedTemporalBound,
     Union,
     Unknown,
     unknown,
+    uppercase,
     Url,
     uuid,
     Uuid,
+    values,
     variable,
     vectors,
     version,
@@ -202,6 +239,12 @@ hashql_macros::define_symbols! {
     actor_group,
     ontology,
     entity_type,
+    data_type,
+    property_type,
+    data_types,
+    property_types,
+    entity_types,
+    schema,
     knowledge,
     url,
     result,
@@ -226,6 +269,7 @@ hashql_macros::define_symbols! {
         asteriskasterisk: "**",
         exclamation: "!",
         excleq: "!=",
+        fat_arrow: "=>",
         brackets: "[]",
         caret: "^",
         colon: ":",
@@ -249,6 +293,7 @@ hashql_macros::define_symbols! {
         question_mark: "?",
         slash: "/",
         tilde: "~",
+        underscore: "_",
         // [tidy] sort alphabetically end
     },
 
@@ -275,6 +320,8 @@ hashql_macros::define_symbols! {
         CreatedAtTransactionTime: "::graph::types::knowledge::entity::CreatedAtTransactionTime",
         CreatedById: "::graph::types::knowledge::entity::CreatedById",
         EditionCreatedById: "::graph::types::knowledge::entity::EditionCreatedById",
+        DataType: "::graph::types::ontology::data_type::DataType",
+        DataTypeMetadata: "::graph::types::ontology::data_type::DataTypeMetadata",
         DecisionTime: "::graph::temporal::DecisionTime",
         Dict: "::kernel::type::Dict",
         Union: "::kernel::type::Union",
@@ -287,11 +334,32 @@ hashql_macros::define_symbols! {
         EntityId: "::graph::types::knowledge::entity::EntityId",
         EntityMetadata: "::graph::types::knowledge::entity::EntityMetadata",
         EntityProvenance: "::graph::types::knowledge::entity::EntityProvenance",
+        EntityType: "::graph::types::ontology::entity_type::EntityType",
+        EntityTypeMetadata: "::graph::types::ontology::entity_type::EntityTypeMetadata",
         EntityUuid: "::graph::types::knowledge::entity::EntityUuid",
         ExclusiveTemporalBound: "::graph::temporal::ExclusiveTemporalBound",
         graph_body_filter: "::graph::body::filter",
+        graph_body_incoming_links: "::graph::body::incoming_links",
+        graph_body_left_entities: "::graph::body::left_entities",
+        graph_body_outgoing_links: "::graph::body::outgoing_links",
+        graph_body_right_entities: "::graph::body::right_entities",
+        graph_body_traverse: "::graph::body::traverse",
+        graph_head_data_types: "::graph::head::data_types",
         graph_head_entities: "::graph::head::entities",
+        graph_head_entity_types: "::graph::head::entity_types",
+        graph_head_property_types: "::graph::head::property_types",
+        graph_tail_avg: "::graph::tail::avg",
         graph_tail_collect: "::graph::tail::collect",
+        graph_tail_count: "::graph::tail::count",
+        graph_tail_exists: "::graph::tail::exists",
+        graph_tail_first: "::graph::tail::first",
+        graph_tail_group_by: "::graph::tail::group_by",
+        graph_tail_limit: "::graph::tail::limit",
+        graph_tail_max: "::graph::tail::max",
+        graph_tail_min: "::graph::tail::min",
+        graph_tail_offset: "::graph::tail::offset",
+        graph_tail_sort_by: "::graph::tail::sort_by",
+        graph_tail_sum: "::graph::tail::sum",
         InclusiveTemporalBound: "::graph::temporal::InclusiveTemporalBound",
         index: "::kernel::special_form::index",
         InferredEntityProvenance: "::graph::types::knowledge::entity::InferredEntityProvenance",
@@ -310,10 +378,13 @@ hashql_macros::define_symbols! {
         PinnedTransactionTimeTemporalAxes: "::graph::temporal::PinnedTransactionTimeTemporalAxes",
         PropertyObjectMetadata: "::graph::types::knowledge::entity::PropertyObjectMetadata",
         PropertyProvenance: "::graph::types::knowledge::entity::PropertyProvenance",
+        PropertyType: "::graph::types::ontology::property_type::PropertyType",
+        PropertyTypeMetadata: "::graph::types::ontology::property_type::PropertyTypeMetadata",
         r#as: "::kernel::special_form::as",
         r#fn: "::kernel::special_form::fn",
         r#if: "::kernel::special_form::if",
         r#let: "::kernel::special_form::let",
+        r#match: "::kernel::special_form::match",
         r#type: "::kernel::special_form::type",
         r#use: "::kernel::special_form::use",
         RecordId: "::graph::types::knowledge::entity::RecordId",
@@ -350,6 +421,25 @@ hashql_macros::define_symbols! {
                 eq: "::core::cmp::eq",
                 ne: "::core::cmp::ne",
             },
+            dict: {
+                len: "::core::dict::len",
+                get: "::core::dict::get",
+                contains: "::core::dict::contains",
+                keys: "::core::dict::keys",
+                values: "::core::dict::values",
+                insert: "::core::dict::insert",
+                concat: "::core::dict::concat",
+            },
+            list: {
+                len: "::core::list::len",
+                get: "::core::list::get",
+                contains: "::core::list::contains",
+                concat: "::core::list::concat",
+                sort: "::core::list::sort",
+                map: "::core::list::map",
+                filter: "::core::list::filter",
+                fold: "::core::list::fold",
+            },
             math: {
                 add: "::core::math::add",
                 sub: "::core::math::sub",
@@ -361,6 +451,17 @@ hashql_macros::define_symbols! {
                 sqrt: "::core::math::sqrt",
                 cbrt: "::core::math::cbrt",
                 root: "::core::math::root",
+            },
+            string: {
+                lowercase: "::core::string::lowercase",
+                uppercase: "::core::string::uppercase",
+                contains: "::core::string::contains",
+                starts_with: "::core::string::starts_with",
+                ends_with: "::core::string::ends_with",
+                length: "::core::string::length",
+                concat: "::core::string::concat",
+                split: "::core::string::split",
+                trim: "::core::string::trim",
             }
         },
         graph: {
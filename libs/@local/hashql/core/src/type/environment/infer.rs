@@ -6,7 +6,7 @@ use super::{
 };
 use crate::{
     span::SpanId,
-    symbol::Ident,
+    symbol::{Ident, Symbol},
     r#type::{
         TypeId,
         inference::{
@@ -172,6 +172,27 @@ impl<'env, 'heap> InferenceEnvironment<'env, 'heap> {
         variable
     }
 
+    pub fn add_downcast(&mut self, span: SpanId, r#type: TypeId, name: Symbol<'heap>) -> Variable {
+        let hole = self.counter.hole.next();
+        let variable = Variable {
+            span,
+            kind: VariableKind::Hole(hole),
+        };
+
+        let downcast = SelectionConstraint::Downcast {
+            subject: Subject::Type(r#type),
+            name,
+            output: variable,
+        };
+        self.constraints.push(Constraint::Selection(
+            downcast,
+            ResolutionStrategy::default(),
+            DeferralDepth::default(),
+        ));
+
+        variable
+    }
+
     pub fn add_variables(&mut self, variables: impl IntoIterator<Item = Variable>) {
         // This acts like registering variables, because we unify each variable with themselves,
         // therefore adding the node, but no edges.
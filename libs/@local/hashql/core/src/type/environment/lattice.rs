@@ -8,9 +8,10 @@ use super::{
     simplify::SimplifyEnvironmentSkeleton,
 };
 use crate::{
-    symbol::Ident,
+    symbol::{Ident, Symbol},
     r#type::{
         PartialType, Type, TypeId,
+        collections::TypeIdSet,
         error::{
             TypeCheckDiagnosticIssues, circular_type_reference, recursive_type_projection,
             recursive_type_subscript,
@@ -414,6 +415,79 @@ impl<'env, 'heap> LatticeEnvironment<'env, 'heap> {
         result
     }
 
+    /// Narrows the type to the representation of the opaque type named `name`.
+    ///
+    /// Unions are narrowed member-wise, members which are not an opaque type of the given name
+    /// are discarded. If no member matches, the result is [`TypeKind::Never`].
+    pub fn downcast(&mut self, id: TypeId, name: Symbol<'heap>) -> Projection {
+        let r#type = self.environment.r#type(id);
+
+        if self.boundary.enter(r#type, r#type).is_break() {
+            // A type which (indirectly) contains itself without an opaque boundary cannot contain
+            // the opaque type we're looking for.
+            return Projection::Resolved(self.intern_type(PartialType {
+                span: r#type.span,
+                kind: self.intern_kind(TypeKind::Never),
+            }));
+        }
+
+        let result = self.downcast_impl(r#type, name);
+
+        self.boundary.exit(r#type, r#type);
+        result
+    }
+
+    fn downcast_impl(&mut self, r#type: Type<'heap>, name: Symbol<'heap>) -> Projection {
+        let Some(r#type) = self.resolve_type(r#type) else {
+            return Projection::Pending;
+        };
+
+        match r#type.kind {
+            TypeKind::Opaque(opaque) if opaque.name == name => Projection::Resolved(opaque.repr),
+            TypeKind::Apply(apply) => self.downcast(apply.base, name),
+            TypeKind::Generic(generic) => self.downcast(generic.base, name),
+            TypeKind::Union(union) => {
+                let variants = r#type.with(union).unnest(self.environment);
+                let mut result = TypeIdSet::<16>::with_capacity(self.environment, variants.len());
+
+                for variant in variants {
+                    match self.downcast(variant, name) {
+                        Projection::Pending => return Projection::Pending,
+                        Projection::Error => return Projection::Error,
+                        Projection::Resolved(id) => result.push(id),
+                    }
+                }
+
+                let kind = match &*result.finish() {
+                    [] => TypeKind::Never,
+                    &[variant] => return Projection::Resolved(variant),
+                    variants => TypeKind::Union(UnionType {
+                        variants: self.intern_type_ids(variants),
+                    }),
+                };
+
+                Projection::Resolved(self.intern_type(PartialType {
+                    span: r#type.span,
+                    kind: self.intern_kind(kind),
+                }))
+            }
+            TypeKind::Opaque(_)
+            | TypeKind::Primitive(_)
+            | TypeKind::Intrinsic(_)
+            | TypeKind::Struct(_)
+            | TypeKind::Tuple(_)
+            | TypeKind::Intersection(_)
+            | TypeKind::Closure(_)
+            | TypeKind::Param(_)
+            | TypeKind::Infer(_)
+            | TypeKind::Never
+            | TypeKind::Unknown => Projection::Resolved(self.intern_type(PartialType {
+                span: r#type.span,
+                kind: self.intern_kind(TypeKind::Never),
+            })),
+        }
+    }
+
     #[inline]
     pub fn is_bottom(&mut self, id: TypeId) -> bool {
         self.simplify.is_bottom(id)
@@ -1610,6 +1610,7 @@ where
     diagnostic
 }
 
+#[expect(clippy::too_many_lines)]
 pub(crate) fn unresolved_selection_constraint<'heap>(
     constraint: SelectionConstraint<'heap>,
     env: &Environment<'heap>,
@@ -1696,6 +1697,42 @@ pub(crate) fn unresolved_selection_constraint<'heap>(
 
             diagnostic
         }
+
+        SelectionConstraint::Downcast {
+            subject,
+            name,
+            output,
+        } => {
+            let mut diagnostic = diagnostic.primary(Label::new(
+                output.span,
+                format!("Cannot resolve pattern for '{name}'"),
+            ));
+
+            let subject_type = subject.r#type(env);
+            diagnostic.labels.push(Label::new(
+                subject_type.span,
+                "... when matching against this type",
+            ));
+
+            diagnostic.add_message(Message::help(format!(
+                    "The type checker could not determine whether the matched value can be of \
+                     type                  '{name}', because the type of the value contains \
+                     unconstrained type variables                  that remain unsolved after \
+                     processing all other constraints.\n\nTry adding                  explicit \
+                     type annotations to constrain the type of the matched value."
+                )));
+
+            diagnostic.add_message(
+                Message::note(
+                    "Patterns over opaque types are resolved after all other type constraints \
+                     have                  been processed. If any type variables involved in the \
+                     matched value remain                  unconstrained at this point, the \
+                     pattern cannot be validated.",
+                ),
+            );
+
+            diagnostic
+        }
     }
 }
 
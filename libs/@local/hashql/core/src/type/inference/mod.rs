@@ -16,7 +16,10 @@ use super::{
     environment::{Environment, InferenceEnvironment, instantiate::InstantiateEnvironment},
     kind::{generic::GenericArgumentId, infer::HoleId},
 };
-use crate::{collections::FastHashMap, symbol::Ident};
+use crate::{
+    collections::FastHashMap,
+    symbol::{Ident, Symbol},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Subject {
@@ -195,6 +198,18 @@ pub enum SelectionConstraint<'heap> {
         index: Subject,
         output: Variable,
     },
+
+    /// Downcast constraint (`source as Name`).
+    ///
+    /// This constraint represents narrowing either an inference variable or a concrete type to
+    /// the representation of the opaque type with the given name. When the source is a union,
+    /// every member with the given name contributes to the result, any other member is
+    /// discarded.
+    Downcast {
+        subject: Subject,
+        name: Symbol<'heap>,
+        output: Variable,
+    },
 }
 
 impl<'heap> SelectionConstraint<'heap> {
@@ -204,6 +219,11 @@ impl<'heap> SelectionConstraint<'heap> {
                 subject,
                 field: _,
                 output: _,
+            }
+            | SelectionConstraint::Downcast {
+                subject,
+                name: _,
+                output: _,
             } => [Some(subject), None],
             SelectionConstraint::Subscript {
                 subject,
@@ -253,7 +273,8 @@ pub enum Constraint<'heap> {
     /// given: `_1 <: (name: _2)`, `_1` flows into `_2`.
     Dependency { source: Variable, target: Variable },
 
-    /// Constraints for component selection operations (`subject.field` or `subject[index]`).
+    /// Constraints for component selection operations (`subject.field`, `subject[index]` or
+    /// narrowing `subject` to an opaque type).
     ///
     /// Selection constraints handle field projection and subscript operations where the
     /// result type must be inferred. These constraints are deferred until sufficient
@@ -282,6 +303,11 @@ impl Constraint<'_> {
                     subject,
                     field: _,
                     output,
+                }
+                | SelectionConstraint::Downcast {
+                    subject,
+                    name: _,
+                    output,
                 },
                 _,
                 _,
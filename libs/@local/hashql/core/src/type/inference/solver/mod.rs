@@ -1393,6 +1393,62 @@ impl<'env, 'heap> InferenceSolver<'env, 'heap> {
                         }
                     }
                 }
+                SelectionConstraint::Downcast {
+                    subject,
+                    name,
+                    output,
+                } => {
+                    let mut subject_type = subject.r#type(self.lattice.environment);
+                    if mode == ResolutionStrategy::Simplify {
+                        let simplified = self.lattice.simplify(subject_type.id);
+                        subject_type = self.lattice.r#type(simplified);
+                    }
+
+                    let repr = match self.lattice.downcast(subject_type.id, name) {
+                        Projection::Pending => {
+                            self.constraints.push(Constraint::Selection(
+                                selection,
+                                mode,
+                                depth.increment(),
+                            ));
+
+                            // In case we do not make any progress, add an error (will be cleared
+                            // every iteration)
+                            self.diagnostics.push(unresolved_selection_constraint(
+                                selection,
+                                self.lattice.environment,
+                            ));
+                            continue;
+                        }
+                        Projection::Error => {
+                            made_progress |= mode.degenerate();
+
+                            self.constraints
+                                .push(Constraint::Selection(selection, mode, depth));
+                            continue;
+                        }
+                        Projection::Resolved(repr) => repr,
+                    };
+
+                    made_progress = true;
+
+                    let repr = self.lattice.r#type(repr);
+
+                    match repr.into_variable() {
+                        Some(repr_variable) => {
+                            self.constraints.push(Constraint::Unify {
+                                lhs: repr_variable,
+                                rhs: output,
+                            });
+                        }
+                        None => {
+                            self.constraints.push(Constraint::Equals {
+                                variable: output,
+                                r#type: repr.id,
+                            });
+                        }
+                    }
+                }
                 SelectionConstraint::Subscript {
                     subject,
                     index,
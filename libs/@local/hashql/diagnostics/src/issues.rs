@@ -84,7 +84,7 @@ pub type AdvisoryDiagnosticIssues<C, S> = DiagnosticIssues<C, S, Advisory>;
 /// assert_eq!(issues.len(), 1);
 /// ```
 #[must_use]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DiagnosticIssues<C, S, K = Severity> {
     diagnostics: Vec<Diagnostic<C, S, K>>,
     critical: usize,
@@ -0,0 +1,371 @@
+//! Explain output for compiled queries.
+//!
+//! An [`Explain`] describes how a compiled query is executed: the target every block of a filter
+//! body has been placed on together with its estimated cost, how the blocks have been partitioned
+//! into islands, and the SQL sent to PostgreSQL together with its parameters. If the query has
+//! been executed, the timings recorded by a [`TimedEventLog`] can be attached as well.
+//!
+//! The explanation can be turned into a serializable [`Plan`], or rendered as text, which prints
+//! the MIR of every body with the placement of each block.
+//!
+//! [`TimedEventLog`]: crate::orchestrator::TimedEventLog
+
+use core::{alloc::Allocator, time::Duration};
+use std::io;
+
+use hashql_core::{
+    graph::DirectedGraph as _,
+    id::Id as _,
+    pretty::Formatter,
+    r#type::{TypeFormatter, TypeFormatterOptions},
+};
+use hashql_mir::{
+    body::basic_block::{BasicBlock, BasicBlockId},
+    pass::execution::{ExecutionAnalysisResidual, IslandEdge, IslandId, IslandKind},
+    pretty::{TextFormatAnnotations, TextFormatOptions},
+};
+
+use crate::{context::CodeExecutionContext, orchestrator::TimedEvent, postgres::PreparedQueries};
+
+/// Timings recorded while executing a query.
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    /// Total time the execution took.
+    pub duration: Duration,
+    pub events: Vec<TimedEvent>,
+}
+
+/// Placement of a single basic block of a filter body.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockPlan {
+    pub block: u32,
+    pub target: String,
+    /// The estimated cost of the block on its target.
+    pub cost: f32,
+    pub island: u32,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IslandPlanKind {
+    /// The island executes the listed blocks.
+    Exec,
+    /// The island only fetches data required by other islands.
+    Data,
+}
+
+/// An island of a filter body, a set of blocks executed on the same target.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandPlan {
+    pub island: u32,
+    pub target: String,
+    pub kind: IslandPlanKind,
+    pub blocks: Vec<u32>,
+}
+
+/// A dependency between two islands of a filter body.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandEdgePlan {
+    pub source: u32,
+    pub target: u32,
+    pub kind: String,
+}
+
+/// Placement of a filter body.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterPlan {
+    pub body: u32,
+    pub blocks: Vec<BlockPlan>,
+    pub islands: Vec<IslandPlan>,
+    pub edges: Vec<IslandEdgePlan>,
+}
+
+/// A compiled SQL query.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPlan {
+    /// The body containing the graph read the query has been compiled from.
+    pub body: u32,
+    /// The block terminated by the graph read the query has been compiled from.
+    pub block: u32,
+    pub sql: String,
+    /// The values bound to the parameters, in the order of their placeholders.
+    pub parameters: Vec<String>,
+}
+
+/// An event recorded during execution.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPlan {
+    /// Microseconds elapsed since the start of the execution.
+    pub elapsed: u128,
+    pub event: String,
+}
+
+/// Timings recorded during execution.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisPlan {
+    /// Microseconds the execution took.
+    pub duration: u128,
+    pub events: Vec<EventPlan>,
+}
+
+/// The serializable form of an [`Explain`].
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plan {
+    pub filters: Vec<FilterPlan>,
+    pub queries: Vec<QueryPlan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<AnalysisPlan>,
+}
+
+const fn edge_kind(edge: IslandEdge) -> &'static str {
+    match edge {
+        IslandEdge::ControlFlow => "controlFlow",
+        IslandEdge::DataFlow => "dataFlow",
+        IslandEdge::Inherits => "inherits",
+    }
+}
+
+fn filter_plan<A: Allocator>(body: u32, residual: &ExecutionAnalysisResidual<A>) -> FilterPlan {
+    let blocks = residual
+        .assignment
+        .iter_enumerated()
+        .map(|(block, target)| BlockPlan {
+            block: block.as_u32(),
+            target: target.to_string(),
+            cost: residual.costs[block].as_f32(),
+            island: residual.islands.lookup(block).0.as_u32(),
+        })
+        .collect();
+
+    let islands = residual
+        .islands
+        .iter_nodes()
+        .map(|(island, node)| IslandPlan {
+            island: island.as_u32(),
+            target: node.target().to_string(),
+            kind: match node.kind() {
+                IslandKind::Exec(_) => IslandPlanKind::Exec,
+                IslandKind::Data => IslandPlanKind::Data,
+            },
+            blocks: node.members().map(BasicBlockId::as_u32).collect(),
+        })
+        .collect();
+
+    let edges = residual
+        .islands
+        .iter_edges()
+        .map(|edge| IslandEdgePlan {
+            source: edge.source().as_u32(),
+            target: edge.target().as_u32(),
+            kind: edge_kind(edge.data).to_owned(),
+        })
+        .collect();
+
+    FilterPlan {
+        body,
+        blocks,
+        islands,
+        edges,
+    }
+}
+
+/// Annotates every block of a filter body with its placement.
+struct PlacementAnnotation<'ctx, A: Allocator> {
+    /// The residual for the body currently being rendered, if it has one.
+    current: Option<&'ctx ExecutionAnalysisResidual<A>>,
+}
+
+impl<A: Allocator> TextFormatAnnotations for PlacementAnnotation<'_, A> {
+    type BasicBlockAnnotation<'this, 'heap>
+        = String
+    where
+        Self: 'this;
+
+    fn annotate_basic_block<'heap>(
+        &self,
+        id: BasicBlockId,
+        _: &BasicBlock<'heap>,
+    ) -> Option<Self::BasicBlockAnnotation<'_, 'heap>> {
+        let residual = self.current?;
+        let target = residual.assignment.get(id)?;
+        let (island, _) = residual.islands.lookup(id);
+
+        Some(format!(
+            "{target}, cost {}, island {island}",
+            residual.costs[id]
+        ))
+    }
+}
+
+/// Explanation of how a compiled query is executed.
+pub struct Explain<'ctx, 'heap, A: Allocator> {
+    context: CodeExecutionContext<'ctx, 'heap, A>,
+    queries: &'ctx PreparedQueries<'heap, A>,
+    analysis: Option<Analysis>,
+}
+
+impl<'ctx, 'heap, A: Allocator> Explain<'ctx, 'heap, A> {
+    #[must_use]
+    pub const fn new(
+        context: CodeExecutionContext<'ctx, 'heap, A>,
+        queries: &'ctx PreparedQueries<'heap, A>,
+    ) -> Self {
+        Self {
+            context,
+            queries,
+            analysis: None,
+        }
+    }
+
+    /// Attaches the timings recorded while executing the query.
+    #[must_use]
+    pub fn with_analysis(mut self, analysis: Analysis) -> Self {
+        self.analysis = Some(analysis);
+        self
+    }
+
+    /// Returns the serializable form of the explanation.
+    #[must_use]
+    pub fn plan(&self) -> Plan {
+        let filters = self
+            .context
+            .execution
+            .iter_enumerated()
+            .filter_map(|(body, residual)| Some(filter_plan(body.as_u32(), residual.as_ref()?)))
+            .collect();
+
+        let queries = self
+            .queries
+            .iter()
+            .map(|(body, block, query)| QueryPlan {
+                body: body.as_u32(),
+                block: block.as_u32(),
+                sql: query.transpile().to_string(),
+                parameters: query.parameters.iter().map(ToString::to_string).collect(),
+            })
+            .collect();
+
+        let analysis = self.analysis.as_ref().map(|analysis| AnalysisPlan {
+            duration: analysis.duration.as_micros(),
+            events: analysis
+                .events
+                .iter()
+                .map(|event| EventPlan {
+                    elapsed: event.elapsed.as_micros(),
+                    event: event.event.to_string(),
+                })
+                .collect(),
+        });
+
+        Plan {
+            filters,
+            queries,
+            analysis,
+        }
+    }
+
+    /// Renders the explanation as text.
+    ///
+    /// Prints the MIR of every body, with the blocks of filter bodies annotated with their
+    /// target, cost and island, followed by the island graph of every filter body, the compiled
+    /// SQL queries and, if attached, the recorded timings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if writing to `writer` fails.
+    #[expect(clippy::use_debug, reason = "durations have no textual representation")]
+    pub fn render(&self, writer: &mut impl io::Write) -> io::Result<()> {
+        let formatter = Formatter::new(self.context.env.heap);
+        let types = TypeFormatter::new(
+            &formatter,
+            self.context.env,
+            TypeFormatterOptions::terse().with_qualified_opaque_names(true),
+        );
+
+        let mut text_format = TextFormatOptions {
+            writer: &mut *writer,
+            indent: 4,
+            sources: self.context.bodies,
+            types,
+            annotations: PlacementAnnotation { current: None },
+        }
+        .build();
+
+        for (index, body) in self.context.bodies.iter().enumerate() {
+            text_format.replace_annotations(PlacementAnnotation {
+                current: self.context.execution.get(body.id).and_then(Option::as_ref),
+            });
+
+            if index > 0 {
+                text_format.writer.write_all(b"\n\n")?;
+            }
+
+            text_format.format_body(body)?;
+        }
+
+        for (body, residual) in self.context.execution.iter_enumerated() {
+            let Some(residual) = residual else {
+                continue;
+            };
+
+            write!(writer, "\n\nislands of {body}:")?;
+
+            for (island, node) in residual.islands.iter_nodes() {
+                write!(writer, "\n    island {island} ({}): ", node.target())?;
+
+                match node.kind() {
+                    IslandKind::Exec(_) => {
+                        for (index, block) in node.members().enumerate() {
+                            if index > 0 {
+                                writer.write_all(b", ")?;
+                            }
+
+                            write!(writer, "{block}")?;
+                        }
+                    }
+                    IslandKind::Data => writer.write_all(b"data")?,
+                }
+            }
+
+            for edge in residual.islands.iter_edges() {
+                write!(
+                    writer,
+                    "\n    island {} -> island {} ({})",
+                    IslandId::from_u32(edge.source().as_u32()),
+                    IslandId::from_u32(edge.target().as_u32()),
+                    edge_kind(edge.data)
+                )?;
+            }
+        }
+
+        for (body, block, query) in self.queries.iter() {
+            write!(
+                writer,
+                "\n\nquery of {body} {block}:\n\n{}",
+                query.transpile()
+            )?;
+
+            if !query.parameters.is_empty() {
+                write!(writer, "\n\nparameters:\n\n{}", query.parameters)?;
+            }
+        }
+
+        if let Some(analysis) = &self.analysis {
+            write!(writer, "\n\nexecution took {:?}:\n", analysis.duration)?;
+
+            for event in &analysis.events {
+                write!(writer, "\n{event}")?;
+            }
+        }
+
+        writer.write_all(b"\n")
+    }
+}
@@ -24,6 +24,7 @@
 extern crate alloc;
 pub mod context;
 pub mod error;
+pub mod explain;
 pub mod intern;
 pub mod orchestrator;
 pub mod postgres;
@@ -36,6 +36,9 @@ mod tests;
 /// a structural decoder that uses JSON shape alone: objects become structs or
 /// dicts, arrays become lists, etc.
 ///
+/// Decoding an object as a struct interns its keys into the heap of the environment, see
+/// [`Self::without_interning`] to avoid this.
+///
 /// [`Value`]: hashql_mir::interpret::value::Value
 /// [`Param`]: hashql_core::type::kind::TypeKind::Param
 /// [`Infer`]: hashql_core::type::kind::TypeKind::Infer
@@ -43,6 +46,7 @@ mod tests;
 pub struct Decoder<'env, 'heap, A> {
     env: &'env Environment<'heap>,
     interner: &'env crate::intern::Interner<'heap>,
+    intern_keys: bool,
 
     alloc: A,
 }
@@ -56,10 +60,24 @@ impl<'env, 'heap, A: Allocator> Decoder<'env, 'heap, A> {
         Self {
             env,
             interner,
+            intern_keys: true,
             alloc,
         }
     }
 
+    /// Decodes every object of unknown type as a dict keyed by strings, instead of decoding
+    /// objects keyed by URLs as structs.
+    ///
+    /// The keys of a struct are interned into the heap, which only frees them once it is reset.
+    /// The fields of a struct of known type are interned once per type, but the keys of objects of
+    /// unknown type differ from value to value. Values decoded using a heap that outlives them,
+    /// for example the heap of a cached query, would therefore grow the heap with every value.
+    #[must_use]
+    pub const fn without_interning(mut self) -> Self {
+        self.intern_keys = false;
+        self
+    }
+
     fn decode_unknown(&self, value: JsonValueRef<'_>) -> Result<Value<'heap, A>, DecodeError<'heap>>
     where
         A: Clone,
@@ -93,19 +111,21 @@ impl<'env, 'heap, A: Allocator> Decoder<'env, 'heap, A> {
                 Ok(Value::List(output))
             }
             JsonValueRef::Object(map) => {
-                if !map.keys().all(|key| {
-                    // Mirrors the implementation of `BaseUrl` parse validation.
-                    if key.len() < 2048
-                        && let Ok(url) = url::Url::parse(key)
-                        && matches!(url.scheme(), "http" | "https")
-                        && !url.cannot_be_a_base()
-                        && key.ends_with('/')
-                    {
-                        true
-                    } else {
-                        false
-                    }
-                }) {
+                if !self.intern_keys
+                    || !map.keys().all(|key| {
+                        // Mirrors the implementation of `BaseUrl` parse validation.
+                        if key.len() < 2048
+                            && let Ok(url) = url::Url::parse(key)
+                            && matches!(url.scheme(), "http" | "https")
+                            && !url.cannot_be_a_base()
+                            && key.ends_with('/')
+                        {
+                            true
+                        } else {
+                            false
+                        }
+                    })
+                {
                     let mut dict = value::Dict::new();
 
                     for (key, value) in map {
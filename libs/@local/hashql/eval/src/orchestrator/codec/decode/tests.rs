@@ -486,3 +486,31 @@ fn unknown_type_url_object_becomes_struct() {
     assert_eq!(fields.len(), 1);
     assert_eq!(fields.values()[0], str_value("Alice"));
 }
+
+#[test]
+fn unknown_type_url_object_without_interning_becomes_dict() {
+    let heap = Heap::new();
+    let env = Environment::new(&heap);
+    let interner = Interner::testing(&heap);
+    let types = TypeBuilder::synthetic(&env);
+    let decoder = decoder(&env, &interner).without_interning();
+
+    let mut object = serde_json::Map::new();
+    object.insert(
+        "https://example.com/types/property-type/name/".to_owned(),
+        serde_json::Value::String("Alice".to_owned()),
+    );
+
+    let allocated = heap.allocated_bytes();
+    let result = decoder
+        .decode(types.unknown(), JsonValueRef::Object(&object))
+        .expect("should succeed");
+    let Value::Dict(dict) = &result else {
+        panic!("expected Value::Dict, got {result:?}");
+    };
+    assert_eq!(
+        dict.get(&str_value("https://example.com/types/property-type/name/")),
+        Some(&str_value("Alice"))
+    );
+    assert_eq!(heap.allocated_bytes(), allocated);
+}
@@ -0,0 +1,522 @@
+//! Embedding backends fulfilling [`Embedding`] islands.
+//!
+//! The embeddings of an entity are not stored alongside the entity in PostgreSQL: the placement
+//! pass assigns every access to `encodings.vectors` to the [`Embedding`] target instead. Once the
+//! orchestrator enters an island which requires the vectors of the entity, it requests them from
+//! the [`EmbeddingBackend`] of the [`Orchestrator`], identifying the entity through its
+//! [`EntityKey`], and hydrates them into the vertex before the island is evaluated.
+//!
+//! The vectors are requested for every entity received by a graph read at once, through
+//! [`EmbeddingBackend::batch_vectors`], rather than for each entity on its own.
+//!
+//! Available backends:
+//!
+//! - `()`: the default backend, which has no embeddings and fails every request.
+//! - [`InMemoryEmbeddings`]: a fixed set of embeddings, used as a stand-in for tests.
+//! - [`StoredEmbeddings`]: the embeddings stored in the `entity_embeddings` table of the graph.
+//! - [`GeneratedEmbeddings`]: generates embeddings through an [`EmbeddingGenerator`].
+//!
+//! [`Embedding`]: hashql_mir::pass::execution::TargetId::Embedding
+//! [`Orchestrator`]: super::Orchestrator
+
+use alloc::string::String;
+use core::{alloc::Allocator, error::Error, fmt};
+
+use hash_graph_embeddings::EmbeddingGenerator;
+use hashql_core::{
+    collections::{FastHashMap, fast_hash_map},
+    symbol::sym,
+};
+use hashql_mir::interpret::value::{List, Num, Value};
+use tokio_postgres::Client;
+use uuid::Uuid;
+
+/// Identity of the entity whose embedding is requested.
+///
+/// Mirrors the `EntityId` of the entity, with every identifier unwrapped to its [`Uuid`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EntityKey {
+    pub web_id: Uuid,
+    pub entity_uuid: Uuid,
+    pub draft_id: Option<Uuid>,
+}
+
+impl EntityKey {
+    /// Reads the key from the `metadata.record_id.entity_id` of a hydrated `entity`.
+    ///
+    /// Returns [`None`] if the entity id has not been hydrated.
+    pub(crate) fn from_entity<A: Allocator>(entity: &Value<'_, A>) -> Option<Self> {
+        let entity_id = [sym::metadata, sym::record_id, sym::entity_id]
+            .into_iter()
+            .try_fold(entity, |value, field| {
+                value.project_by_name::<!>(field).ok()
+            })?;
+
+        let web_id = unwrap_uuid(entity_id.project_by_name::<!>(sym::web_id).ok()?)?;
+        let entity_uuid = unwrap_uuid(entity_id.project_by_name::<!>(sym::entity_uuid).ok()?)?;
+        let draft_id = match entity_id.project_by_name::<!>(sym::draft_id) {
+            Ok(Value::Opaque(opaque)) if opaque.name() == sym::path::None => None,
+            Ok(draft_id) => Some(unwrap_uuid(draft_id)?),
+            Err(_) => None,
+        };
+
+        Some(Self {
+            web_id,
+            entity_uuid,
+            draft_id,
+        })
+    }
+}
+
+impl fmt::Display for EntityKey {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}~{}", self.web_id, self.entity_uuid)?;
+
+        if let Some(draft_id) = self.draft_id {
+            write!(fmt, "~{draft_id}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Unwraps the opaque wrappers of an identifier down to the [`Uuid`] they contain.
+fn unwrap_uuid<A: Allocator>(mut value: &Value<'_, A>) -> Option<Uuid> {
+    loop {
+        match value {
+            Value::Opaque(opaque) => value = opaque.value(),
+            Value::String(string) => return Uuid::parse_str(string.as_str()).ok(),
+            Value::Unit
+            | Value::Integer(_)
+            | Value::Number(_)
+            | Value::Pointer(_)
+            | Value::Struct(_)
+            | Value::Tuple(_)
+            | Value::List(_)
+            | Value::Dict(_) => return None,
+        }
+    }
+}
+
+/// Converts the `vectors` returned by a backend into the value of `encodings.vectors`.
+///
+/// Entities without an embedding have no vectors, which is represented as an empty list.
+pub(crate) fn vectors_value<'heap, A: Allocator + Clone>(
+    vectors: Option<&[f32]>,
+) -> Value<'heap, A> {
+    let mut list = List::new();
+
+    for &component in vectors.into_iter().flatten() {
+        list.push_back(Value::Number(Num::from(f64::from(component))));
+    }
+
+    Value::List(list)
+}
+
+/// Source of the embeddings of entities.
+///
+/// Used by the [`Orchestrator`] to fulfil [`Embedding`] islands. Implementations must return
+/// embeddings from the same model for every entity, otherwise the vectors cannot be compared.
+///
+/// [`Orchestrator`]: super::Orchestrator
+/// [`Embedding`]: hashql_mir::pass::execution::TargetId::Embedding
+pub trait EmbeddingBackend {
+    /// Returns the embedding of the entity identified by `entity`.
+    ///
+    /// Returns [`None`] if no embedding exists for the entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend failed to retrieve the embedding.
+    fn vectors(
+        &self,
+        entity: EntityKey,
+    ) -> impl Future<Output = Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>>>;
+
+    /// Returns the embeddings of `entities`, omitting the entities without an embedding.
+    ///
+    /// The orchestrator requests the embeddings of every entity received by a graph read at once.
+    /// The default implementation requests them one after another through
+    /// [`vectors`](Self::vectors).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend failed to retrieve any of the embeddings.
+    fn batch_vectors(
+        &self,
+        entities: &[EntityKey],
+    ) -> impl Future<Output = Result<FastHashMap<EntityKey, Vec<f32>>, Box<dyn Error + Send + Sync>>>
+    {
+        async move {
+            let mut batch = fast_hash_map();
+
+            for &entity in entities {
+                if let Some(vectors) = self.vectors(entity).await? {
+                    batch.insert(entity, vectors);
+                }
+            }
+
+            Ok(batch)
+        }
+    }
+}
+
+/// Error returned by the `()` backend, which has no embeddings.
+#[derive(Debug)]
+struct NoEmbeddingBackend;
+
+impl fmt::Display for NoEmbeddingBackend {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("no embedding backend has been configured")
+    }
+}
+
+impl Error for NoEmbeddingBackend {}
+
+impl EmbeddingBackend for () {
+    async fn vectors(
+        &self,
+        _: EntityKey,
+    ) -> Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>> {
+        Err(Box::new(NoEmbeddingBackend))
+    }
+}
+
+impl<T: EmbeddingBackend> EmbeddingBackend for &T {
+    #[inline]
+    fn vectors(
+        &self,
+        entity: EntityKey,
+    ) -> impl Future<Output = Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>>> {
+        T::vectors(self, entity)
+    }
+
+    #[inline]
+    fn batch_vectors(
+        &self,
+        entities: &[EntityKey],
+    ) -> impl Future<Output = Result<FastHashMap<EntityKey, Vec<f32>>, Box<dyn Error + Send + Sync>>>
+    {
+        T::batch_vectors(self, entities)
+    }
+}
+
+/// An [`EmbeddingBackend`] serving a fixed set of embeddings from memory.
+///
+/// Entities which have not been inserted have no embedding.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEmbeddings {
+    vectors: FastHashMap<EntityKey, Vec<f32>>,
+}
+
+impl InMemoryEmbeddings {
+    /// Creates a backend without any embeddings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            vectors: fast_hash_map(),
+        }
+    }
+
+    /// Sets the embedding of `entity`, returning the previous one, if any.
+    pub fn insert(&mut self, entity: EntityKey, vectors: impl Into<Vec<f32>>) -> Option<Vec<f32>> {
+        self.vectors.insert(entity, vectors.into())
+    }
+
+    /// Returns the number of entities with an embedding.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    /// Returns whether no entity has an embedding.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+}
+
+impl EmbeddingBackend for InMemoryEmbeddings {
+    async fn vectors(
+        &self,
+        entity: EntityKey,
+    ) -> Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>> {
+        Ok(self.vectors.get(&entity).cloned())
+    }
+}
+
+/// An [`EmbeddingBackend`] serving the embeddings stored in the graph.
+///
+/// Reads the combined embedding of an entity, the row of `entity_embeddings` without a
+/// `property`, through `client`. Entities which have not been embedded yet have no embedding.
+///
+/// The embeddings of a batch are read through a single query.
+pub struct StoredEmbeddings<C> {
+    client: C,
+}
+
+impl<C> StoredEmbeddings<C> {
+    /// Creates a backend reading the embeddings through `client`.
+    pub const fn new(client: C) -> Self {
+        Self { client }
+    }
+}
+
+#[expect(clippy::future_not_send)]
+impl<C: AsRef<Client>> EmbeddingBackend for StoredEmbeddings<C> {
+    async fn vectors(
+        &self,
+        entity: EntityKey,
+    ) -> Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>> {
+        let row = self
+            .client
+            .as_ref()
+            .query_opt(
+                "SELECT embedding::real[] FROM entity_embeddings WHERE web_id = $1 AND \
+                 entity_uuid = $2 AND property IS NULL",
+                &[&entity.web_id, &entity.entity_uuid],
+            )
+            .await?;
+
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    async fn batch_vectors(
+        &self,
+        entities: &[EntityKey],
+    ) -> Result<FastHashMap<EntityKey, Vec<f32>>, Box<dyn Error + Send + Sync>> {
+        let (web_ids, entity_uuids): (Vec<_>, Vec<_>) = entities
+            .iter()
+            .map(|entity| (entity.web_id, entity.entity_uuid))
+            .unzip();
+
+        let rows = self
+            .client
+            .as_ref()
+            .query(
+                "SELECT web_id, entity_uuid, embedding::real[] FROM entity_embeddings WHERE \
+                 property IS NULL AND (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], \
+                 $2::uuid[]))",
+                &[&web_ids, &entity_uuids],
+            )
+            .await?;
+
+        let mut stored: FastHashMap<(Uuid, Uuid), Vec<f32>> = fast_hash_map();
+        for row in rows {
+            stored.insert((row.get(0), row.get(1)), row.get(2));
+        }
+
+        // Drafts share the embedding of their entity
+        Ok(entities
+            .iter()
+            .filter_map(|&entity| {
+                let vectors = stored.get(&(entity.web_id, entity.entity_uuid))?;
+                Some((entity, vectors.clone()))
+            })
+            .collect())
+    }
+}
+
+/// An [`EmbeddingBackend`] generating embeddings through an [`EmbeddingGenerator`].
+///
+/// The generator embeds text, `describe` provides the text for an entity, for example a rendering
+/// of its properties. Entities without a description have no embedding.
+pub struct GeneratedEmbeddings<G, F> {
+    generator: G,
+    describe: F,
+}
+
+impl<G, F> GeneratedEmbeddings<G, F> {
+    /// Creates a backend embedding the description of each entity using `generator`.
+    pub const fn new(generator: G, describe: F) -> Self {
+        Self {
+            generator,
+            describe,
+        }
+    }
+}
+
+#[expect(clippy::future_not_send)]
+impl<G, F> EmbeddingBackend for GeneratedEmbeddings<G, F>
+where
+    G: EmbeddingGenerator,
+    F: Fn(EntityKey) -> Option<String>,
+{
+    async fn vectors(
+        &self,
+        entity: EntityKey,
+    ) -> Result<Option<Vec<f32>>, Box<dyn Error + Send + Sync>> {
+        let Some(description) = (self.describe)(entity) else {
+            return Ok(None);
+        };
+
+        let embeddings = self.generator.create_embeddings(&[&description]).await?;
+
+        Ok(embeddings
+            .first()
+            .map(|embedding| embedding.iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{alloc::Global, rc::Rc};
+
+    use futures_lite::future::block_on;
+    use hashql_core::{
+        heap::Heap,
+        symbol::{Symbol, sym},
+    };
+    use hashql_mir::{
+        intern::Interner,
+        interpret::value::{Int, Opaque, Str, StructBuilder, Value},
+    };
+    use uuid::Uuid;
+
+    use super::{EmbeddingBackend as _, EntityKey, InMemoryEmbeddings, vectors_value};
+
+    const fn key(entity_uuid: u128) -> EntityKey {
+        EntityKey {
+            web_id: Uuid::from_u128(1),
+            entity_uuid: Uuid::from_u128(entity_uuid),
+            draft_id: None,
+        }
+    }
+
+    fn opaque<'heap>(name: Symbol<'heap>, value: Value<'heap, Global>) -> Value<'heap, Global> {
+        Value::Opaque(Opaque::new(name, Rc::new(value)))
+    }
+
+    fn uuid(value: Uuid) -> Value<'static, Global> {
+        opaque(
+            sym::path::Uuid,
+            Value::String(Str::from(Rc::<str>::from(value.to_string()))),
+        )
+    }
+
+    /// Builds an entity whose `metadata.record_id` is `record_id`, like the hydrated entities of
+    /// the orchestrator.
+    fn entity<'heap>(
+        interner: &Interner<'heap>,
+        record_id: Value<'heap, Global>,
+    ) -> Value<'heap, Global> {
+        let mut metadata = StructBuilder::<'heap, Global, 1>::new();
+        metadata.push(sym::record_id, record_id);
+        let metadata = Value::Struct(metadata.finish(&interner.symbols, Global));
+
+        let mut entity = StructBuilder::<'heap, Global, 1>::new();
+        entity.push(sym::metadata, metadata);
+
+        opaque(
+            sym::path::Entity,
+            Value::Struct(entity.finish(&interner.symbols, Global)),
+        )
+    }
+
+    #[test]
+    fn entity_key_from_hydrated_entity() {
+        let heap = Heap::new();
+        let interner = Interner::new(&heap);
+
+        let mut entity_id = StructBuilder::<'_, Global, 3>::new();
+        entity_id.push(
+            sym::web_id,
+            opaque(
+                sym::path::WebId,
+                opaque(sym::path::ActorGroupEntityUuid, uuid(Uuid::from_u128(1))),
+            ),
+        );
+        entity_id.push(
+            sym::entity_uuid,
+            opaque(sym::path::EntityUuid, uuid(Uuid::from_u128(2))),
+        );
+        entity_id.push(sym::draft_id, opaque(sym::path::None, Value::Unit));
+        let entity_id = opaque(
+            sym::path::EntityId,
+            Value::Struct(entity_id.finish(&interner.symbols, Global)),
+        );
+
+        let mut record_id = StructBuilder::<'_, Global, 1>::new();
+        record_id.push(sym::entity_id, entity_id);
+        let record_id = Value::Struct(record_id.finish(&interner.symbols, Global));
+
+        assert_eq!(
+            EntityKey::from_entity(&entity(&interner, record_id)),
+            Some(key(2))
+        );
+    }
+
+    #[test]
+    fn entity_key_requires_entity_id() {
+        let heap = Heap::new();
+        let interner = Interner::new(&heap);
+
+        // The record id has been hydrated, but not the entity id within it
+        let mut record_id = StructBuilder::<'_, Global, 1>::new();
+        record_id.push(sym::edition_id, uuid(Uuid::from_u128(3)));
+        let record_id = Value::Struct(record_id.finish(&interner.symbols, Global));
+
+        assert_eq!(EntityKey::from_entity(&entity(&interner, record_id)), None);
+    }
+
+    #[test]
+    fn in_memory_returns_inserted_vectors() {
+        let mut embeddings = InMemoryEmbeddings::new();
+        embeddings.insert(key(2), [0.5, 1.0]);
+
+        assert_eq!(embeddings.len(), 1);
+        assert_eq!(
+            block_on(embeddings.vectors(key(2))).expect("should not fail"),
+            Some(vec![0.5, 1.0])
+        );
+    }
+
+    #[test]
+    fn in_memory_unknown_entity_has_no_vectors() {
+        let mut embeddings = InMemoryEmbeddings::new();
+        embeddings.insert(key(2), [0.5, 1.0]);
+
+        assert_eq!(
+            block_on(embeddings.vectors(key(3))).expect("should not fail"),
+            None
+        );
+    }
+
+    #[test]
+    fn in_memory_batch_omits_entities_without_vectors() {
+        let mut embeddings = InMemoryEmbeddings::new();
+        embeddings.insert(key(2), [0.5, 1.0]);
+        embeddings.insert(key(4), [1.0, 0.5]);
+
+        let batch =
+            block_on(embeddings.batch_vectors(&[key(2), key(3), key(4)])).expect("should not fail");
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.get(&key(2)), Some(&vec![0.5, 1.0]));
+        assert_eq!(batch.get(&key(3)), None);
+        assert_eq!(batch.get(&key(4)), Some(&vec![1.0, 0.5]));
+    }
+
+    #[test]
+    fn unit_backend_fails() {
+        block_on(().vectors(key(2))).expect_err("the unit backend has no embeddings");
+    }
+
+    #[test]
+    fn vectors_value_is_list_of_numbers() {
+        let Value::List(list) = vectors_value::<Global>(Some(&[0.5, 1.0])) else {
+            panic!("vectors should be a list");
+        };
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            list.get(Int::from(1_i32)),
+            Some(&Value::Number(1.0_f64.into()))
+        );
+
+        let Value::List(list) = vectors_value::<Global>(None) else {
+            panic!("vectors should be a list");
+        };
+        assert!(list.is_empty());
+    }
+}
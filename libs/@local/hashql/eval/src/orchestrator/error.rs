@@ -1,14 +1,15 @@
 //! Error types for the orchestration layer.
 //!
 //! The orchestrator sits between the MIR interpreter and external data sources
-//! (PostgreSQL). Errors fall into two families:
+//! (PostgreSQL and the embedding backend). Errors fall into two families:
 //!
 //! - **Interpreter errors**: failures in the MIR interpreter itself (type invariant violations,
 //!   control flow errors, etc.). These are produced by the interpreter and forwarded through the
 //!   orchestrator.
 //! - **Bridge errors**: failures while fulfilling [`GraphRead`] suspensions (query execution, row
 //!   decoding, parameter encoding). The user wrote HashQL, not SQL; if the bridge fails, it
-//!   indicates a bug in the compiler or runtime.
+//!   indicates a bug in the compiler or runtime, unless the embedding backend failed to provide the
+//!   vectors of an entity.
 //!
 //! [`OrchestratorDiagnosticCategory`] unifies both families under a single
 //! category hierarchy so that downstream consumers (the eval crate) see one
@@ -36,7 +37,7 @@ use hashql_mir::{
     interpret::error::InterpretDiagnosticCategory,
 };
 
-use super::{Indexed, codec::JsonValueKind};
+use super::{EntityKey, Indexed, codec::JsonValueKind};
 use crate::postgres::ColumnDescriptor;
 
 const QUERY_EXECUTION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
@@ -94,11 +95,26 @@ const INVALID_FILTER_RETURN: TerminalDiagnosticCategory = TerminalDiagnosticCate
     name: "Invalid Filter Return",
 };
 
+const INVALID_AGGREGATE_VALUE: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-aggregate-value",
+    name: "Invalid Aggregate Value",
+};
+
 const VALUE_SERIALIZATION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
     id: "value-serialization",
     name: "Value Serialization",
 };
 
+const EMBEDDING_LOOKUP: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "embedding-lookup",
+    name: "Embedding Lookup",
+};
+
+const MISSING_ENTITY_KEY: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "missing-entity-key",
+    name: "Missing Entity Key",
+};
+
 /// Type alias for orchestrator diagnostics.
 ///
 /// The default severity kind is [`Critical`].
@@ -411,6 +427,26 @@ pub enum BridgeError<'heap> {
         body: DefId,
     },
 
+    /// An aggregated value is not a number.
+    ///
+    /// The closures passed to `sum`, `min`, `max` and `avg` must evaluate to a
+    /// number. If the interpreter produces any other value, the HIR type
+    /// checking or lowering pass has a bug.
+    InvalidAggregateValue {
+        /// The closure definition that returned a non-number.
+        body: DefId,
+    },
+
+    /// The result of a `count` or aggregate query could not be decoded.
+    ///
+    /// Counts and aggregates that are compiled into the query return a single
+    /// `bigint` or `double precision`. Any other result shape indicates a bug
+    /// in the SQL lowering pass.
+    AggregateHydration {
+        /// The database error describing the type mismatch.
+        source: tokio_postgres::Error,
+    },
+
     /// A runtime value could not be serialized to JSON.
     ///
     /// Serialization failures indicate a bug in the encoder or an unsupported
@@ -419,6 +455,29 @@ pub enum BridgeError<'heap> {
         /// The serialization error from `serde_json`.
         source: serde_json::Error,
     },
+
+    /// The embedding backend failed to provide the vectors of an entity.
+    ///
+    /// Unlike the other variants this is not necessarily a bug: the backend
+    /// may be unavailable, or no backend has been configured at all.
+    EmbeddingLookup {
+        /// The filter definition requiring the vectors.
+        body: DefId,
+        /// The entity whose vectors were requested.
+        entity: EntityKey,
+        /// The error reported by the backend.
+        source: Box<dyn core::error::Error + Send + Sync>,
+    },
+
+    /// The vectors of an entity are required, but its id has not been hydrated.
+    ///
+    /// The embedding backend identifies entities by their id, so the SQL
+    /// lowering pass must select it for every filter with islands on the
+    /// embedding target.
+    MissingEntityKey {
+        /// The filter definition requiring the vectors.
+        body: DefId,
+    },
 }
 
 impl<'heap> BridgeError<'heap> {
@@ -449,7 +508,15 @@ impl<'heap> BridgeError<'heap> {
             }
             Self::MissingExecutionResidual { body } => missing_execution_residual(span, body),
             Self::InvalidFilterReturn { body } => invalid_filter_return(span, body),
+            Self::InvalidAggregateValue { body } => invalid_aggregate_value(span, body),
+            Self::AggregateHydration { source } => aggregate_hydration(span, &source),
             Self::ValueSerialization { source } => value_serialization(span, &source),
+            Self::EmbeddingLookup {
+                body,
+                entity,
+                source,
+            } => embedding_lookup(span, body, entity, &*source),
+            Self::MissingEntityKey { body } => missing_entity_key(span, body),
         }
     }
 }
@@ -498,8 +565,10 @@ fn row_hydration(
 }
 
 /// Adds notes describing a [`DecodeError`] to a diagnostic.
-fn add_decode_error_notes(
-    diagnostic: &mut OrchestratorDiagnostic,
+///
+/// The notes describe where in the type tree decoding failed, rendering types using `env`.
+pub fn add_decode_error_notes<C, K>(
+    diagnostic: &mut Diagnostic<C, SpanId, K>,
     source: &DecodeError<'_>,
     env: &Environment<'_>,
 ) {
@@ -778,6 +847,35 @@ fn invalid_filter_return(span: SpanId, body: DefId) -> OrchestratorDiagnostic {
     diagnostic
 }
 
+fn invalid_aggregate_value(span: SpanId, body: DefId) -> OrchestratorDiagnostic {
+    let mut diagnostic = Diagnostic::new(category(&INVALID_AGGREGATE_VALUE), Critical::BUG)
+        .primary(Label::new(span, "aggregated value is not a number"));
+
+    diagnostic.add_message(Message::note(format!(
+        "aggregate definition {body} must evaluate to a number"
+    )));
+
+    diagnostic.add_message(Message::help(
+        "the HIR type checking pass should ensure aggregated values are numbers",
+    ));
+
+    diagnostic
+}
+
+fn aggregate_hydration(span: SpanId, source: &tokio_postgres::Error) -> OrchestratorDiagnostic {
+    let mut diagnostic = Diagnostic::new(category(&ROW_HYDRATION), Critical::BUG).primary(
+        Label::new(span, "cannot decode the aggregate of the matching rows"),
+    );
+
+    diagnostic.add_message(Message::note(format!("the database reported: {source}")));
+
+    diagnostic.add_message(Message::help(
+        "the SQL lowering pass should produce aggregating queries which return a single number",
+    ));
+
+    diagnostic
+}
+
 fn value_serialization(span: SpanId, error: &serde_json::Error) -> OrchestratorDiagnostic {
     let mut diagnostic = Diagnostic::new(category(&VALUE_SERIALIZATION), Critical::BUG)
         .primary(Label::new(span, "cannot serialize runtime value to JSON"));
@@ -790,3 +888,47 @@ fn value_serialization(span: SpanId, error: &serde_json::Error) -> OrchestratorD
 
     diagnostic
 }
+
+fn embedding_lookup(
+    span: SpanId,
+    body: DefId,
+    entity: EntityKey,
+    error: &(dyn core::error::Error + Send + Sync),
+) -> OrchestratorDiagnostic {
+    let mut diagnostic = Diagnostic::new(category(&EMBEDDING_LOOKUP), Critical::ERROR).primary(
+        Label::new(span, "cannot retrieve the embedding of an entity"),
+    );
+
+    diagnostic.add_message(Message::note(format!(
+        "definition {body} requires the vectors of entity {entity}"
+    )));
+
+    diagnostic.add_message(Message::note(format!(
+        "the embedding backend reported: {error}"
+    )));
+
+    diagnostic.add_message(Message::help(
+        "queries accessing `encodings.vectors` require an embedding backend which can provide the \
+         embeddings of the queried entities",
+    ));
+
+    diagnostic
+}
+
+fn missing_entity_key(span: SpanId, body: DefId) -> OrchestratorDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(category(&MISSING_ENTITY_KEY), Critical::BUG).primary(Label::new(
+            span,
+            "cannot identify the entity whose embedding is required",
+        ));
+
+    diagnostic.add_message(Message::note(format!(
+        "definition {body} requires the vectors of an entity whose id has not been hydrated"
+    )));
+
+    diagnostic.add_message(Message::help(
+        "the SQL lowering pass should select the entity id for every filter with embedding islands",
+    ));
+
+    diagnostic
+}
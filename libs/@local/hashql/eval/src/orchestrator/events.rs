@@ -1,10 +1,12 @@
 //! Opt-in event tracing for the orchestrator execution pipeline.
 //!
 //! The orchestrator emits [`Event`]s at key decision points: query dispatch,
-//! row hydration, filter evaluation, island transitions, and result collection.
+//! row hydration, filter evaluation, island transitions, embedding lookups, and
+//! result collection.
 //! An [`EventLog`] sink receives them. The default `()` implementation compiles
 //! to a no-op with zero runtime cost. [`AppendEventLog`] collects events into
-//! a [`Vec`] for test assertions.
+//! a [`Vec`] for test assertions, [`TimedEventLog`] additionally records when
+//! each event occurred, which is used to explain executions.
 //!
 //! # Design
 //!
@@ -23,7 +25,9 @@
 use core::{
     fmt::{self, Display},
     mem,
+    time::Duration,
 };
+use std::time::Instant;
 
 use hashql_core::sync::lock::LocalLock;
 use hashql_mir::{
@@ -62,6 +66,9 @@ pub enum Event {
     ContinuationFlushed { body: DefId, island: IslandId },
     /// Postgres island had no continuation state (implicit true).
     ContinuationImplicitTrue { body: DefId },
+    /// The vectors of the entity were fetched from the embedding backend, because the island
+    /// requires them.
+    EmbeddingsFetched { body: DefId, island: IslandId },
 
     /// A row survived all filters and was added to the output.
     RowAccepted,
@@ -93,6 +100,9 @@ impl Display for Event {
             Self::ContinuationImplicitTrue { body } => {
                 write!(f, "continuation implicit true: body {body}")
             }
+            Self::EmbeddingsFetched { body, island } => {
+                write!(f, "embeddings fetched: body {body}, island {island}")
+            }
             Self::RowAccepted => f.write_str("row accepted"),
             Self::RowRejected => f.write_str("row rejected"),
         }
@@ -157,3 +167,65 @@ impl EventLog for AppendEventLog {
         self.0.lock().push(event);
     }
 }
+
+/// An [`Event`] together with the time at which it occurred.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimedEvent {
+    /// Time elapsed between the creation of the [`TimedEventLog`] and the event.
+    pub elapsed: Duration,
+    pub event: Event,
+}
+
+impl Display for TimedEvent {
+    #[expect(clippy::use_debug, reason = "durations have no textual representation")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>12?}  {}", self.elapsed, self.event)
+    }
+}
+
+/// An [`EventLog`] that records each event together with the time it occurred.
+///
+/// Times are measured relative to the creation of the log, so the log should be
+/// created right before the orchestrator is run. Retrieve collected events with
+/// [`take`](Self::take), which drains the buffer.
+#[derive(Debug)]
+pub struct TimedEventLog {
+    start: Instant,
+    events: LocalLock<Vec<TimedEvent>>,
+}
+
+impl TimedEventLog {
+    /// Creates an empty event log, measuring time from now on.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            events: LocalLock::new(Vec::new()),
+        }
+    }
+
+    /// Returns the time elapsed since the log was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Drains and returns all collected events, leaving the buffer empty.
+    pub fn take(&self) -> Vec<TimedEvent> {
+        mem::take(&mut *self.events.lock())
+    }
+}
+
+impl Default for TimedEventLog {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventLog for TimedEventLog {
+    fn log(&self, event: Event) {
+        let elapsed = self.start.elapsed();
+        self.events.lock().push(TimedEvent { elapsed, event });
+    }
+}
@@ -13,6 +13,8 @@
 //!
 //! - [`Orchestrator`]: top-level driver that owns the database client and query registry. Provides
 //!   [`run_in`] for full query execution and [`fulfill_in`] for resolving a single suspension.
+//! - [`EmbeddingBackend`]: source of entity embeddings, which the orchestrator consults whenever an
+//!   island requires the `encodings.vectors` of an entity.
 //! - [`Indexed`]: positional wrapper that carries a column's index alongside its descriptor through
 //!   the hydration pipeline, used for error reporting.
 //!
@@ -29,10 +31,13 @@
 //!   continuation columns (target block, locals, serialized values), this module hydrates and
 //!   validates them, then flushes the decoded state into the interpreter's callstack.
 //! - `request`: per-suspension-type handlers (currently [`GraphRead`]).
+//! - `embedding`: the [`EmbeddingBackend`]s which provide the vectors of entities to islands placed
+//!   on the embedding target.
 //! - `tail`: result accumulation strategies (currently collection into a list).
 //! - `error`: diagnostic category hierarchy ([`OrchestratorDiagnosticCategory`]) and bridge error
 //!   types. Bridge errors use `Severity::Bug` because the user wrote HashQL, not SQL: if the bridge
-//!   fails, the compiler or runtime produced something invalid.
+//!   fails, the compiler or runtime produced something invalid. The only exception are failures of
+//!   the embedding backend, which is an external service.
 //!
 //! [`GraphRead`]: hashql_mir::body::terminator::GraphRead
 //! [`Suspension`]: hashql_mir::interpret::suspension::Suspension
@@ -42,26 +47,30 @@
 //! [`fulfill_in`]: Orchestrator::fulfill_in
 
 use alloc::alloc::Global;
-use core::{alloc::Allocator, ops::Deref};
+use core::{alloc::Allocator, cell::Cell, ops::Deref};
 
 use hashql_mir::{
     def::DefId,
     interpret::{
-        CallStack, Inputs, Runtime, RuntimeConfig, RuntimeError,
+        Budget, CallStack, CancellationToken, Inputs, Runtime, RuntimeConfig, RuntimeError,
         suspension::{Continuation, Suspension},
         value::Value,
     },
 };
 use tokio_postgres::Client;
 
-use self::{error::BridgeError, request::GraphReadOrchestrator};
 pub use self::{
+    embedding::{
+        EmbeddingBackend, EntityKey, GeneratedEmbeddings, InMemoryEmbeddings, StoredEmbeddings,
+    },
     error::{OrchestratorDiagnostic, OrchestratorDiagnosticCategory},
-    events::{AppendEventLog, Event, EventLog},
+    events::{AppendEventLog, Event, EventLog, TimedEvent, TimedEventLog},
 };
+use self::{error::BridgeError, request::GraphReadOrchestrator};
 use crate::{context::CodeExecutionContext, postgres::PreparedQueries};
 
 pub mod codec;
+mod embedding;
 pub mod error;
 mod events;
 mod partial;
@@ -105,27 +114,45 @@ impl<T> Deref for Indexed<T> {
 /// the evaluation context (type environment, body definitions, execution
 /// analysis results). The type parameter `C` is reserved for future
 /// configuration; `A` is the allocator used by the query registry; `E` is
-/// the [`EventLog`] sink for execution tracing.
+/// the [`EventLog`] sink for execution tracing; `B` is the [`EmbeddingBackend`]
+/// providing the vectors of entities.
 ///
 /// By default `E` is `()`, which compiles all event logging to no-ops. Use
 /// [`with_event_log`](Self::with_event_log) to attach a collector such as
 /// [`AppendEventLog`] for test assertions or debugging.
 ///
+/// By default `B` is `()`, which has no embeddings, so that any query
+/// accessing `encodings.vectors`, or requiring the complete entity, fails. Use
+/// [`with_embeddings`](Self::with_embeddings) to attach a backend such as
+/// [`StoredEmbeddings`], [`InMemoryEmbeddings`] or [`GeneratedEmbeddings`].
+///
+/// Every interpreter created by the orchestrator uses the same [`RuntimeConfig`], set through
+/// [`with_runtime_config`](Self::with_runtime_config), and stops once the token set through
+/// [`with_cancellation`](Self::with_cancellation) has been cancelled. The interpreters share a
+/// single [`Budget`], so that the limits of the configuration bound the query as a whole, including
+/// every filter and closure evaluated for its rows.
+///
 /// Use [`run_in`](Self::run_in) to execute a complete query from scratch, or
 /// [`fulfill_in`](Self::fulfill_in) / [`fulfill`](Self::fulfill) to resolve an
 /// individual [`Suspension`] when driving the interpreter manually.
 ///
 /// [`Suspension`]: hashql_mir::interpret::suspension::Suspension
-pub struct Orchestrator<'env, 'ctx, 'heap, C, E, A: Allocator> {
+pub struct Orchestrator<'env, 'ctx, 'heap, C, E, A: Allocator, B = ()> {
     client: C,
     queries: &'env PreparedQueries<'heap, A>,
     context: &'env CodeExecutionContext<'ctx, 'heap, A>,
+    runtime_config: RuntimeConfig,
+    cancellation: Option<CancellationToken>,
+    /// Resources consumed by the interpreters so far, started by [`run_in`](Self::run_in), or by
+    /// the first interpreter run otherwise.
+    budget: Cell<Option<Budget>>,
+    embeddings: B,
     /// Event sink for execution tracing. See [`EventLog`].
     pub event_log: E,
 }
 
 impl<'env, 'ctx, 'heap, C, A: Allocator> Orchestrator<'env, 'ctx, 'heap, C, (), A> {
-    pub const fn new(
+    pub fn new(
         client: C,
         queries: &'env PreparedQueries<'heap, A>,
         context: &'env CodeExecutionContext<'ctx, 'heap, A>,
@@ -134,26 +161,109 @@ impl<'env, 'ctx, 'heap, C, A: Allocator> Orchestrator<'env, 'ctx, 'heap, C, (),
             client,
             queries,
             context,
+            runtime_config: RuntimeConfig::default(),
+            cancellation: None,
+            budget: Cell::new(None),
+            embeddings: (),
             event_log: (),
         }
     }
 }
 
-impl<'env, 'ctx, 'heap, C, E, A: Allocator> Orchestrator<'env, 'ctx, 'heap, C, E, A> {
+impl<'env, 'ctx, 'heap, C, E, A: Allocator, B> Orchestrator<'env, 'ctx, 'heap, C, E, A, B> {
     /// Replaces the event log, returning a new orchestrator with the given
     /// sink.
-    pub fn with_event_log<E2>(self, event_log: E2) -> Orchestrator<'env, 'ctx, 'heap, C, E2, A> {
+    pub fn with_event_log<E2>(self, event_log: E2) -> Orchestrator<'env, 'ctx, 'heap, C, E2, A, B> {
         Orchestrator {
             client: self.client,
             queries: self.queries,
             context: self.context,
+            runtime_config: self.runtime_config,
+            cancellation: self.cancellation,
+            budget: self.budget,
+            embeddings: self.embeddings,
             event_log,
         }
     }
+
+    /// Replaces the embedding backend, returning a new orchestrator with the
+    /// given backend.
+    pub fn with_embeddings<B2>(
+        self,
+        embeddings: B2,
+    ) -> Orchestrator<'env, 'ctx, 'heap, C, E, A, B2> {
+        Orchestrator {
+            client: self.client,
+            queries: self.queries,
+            context: self.context,
+            runtime_config: self.runtime_config,
+            cancellation: self.cancellation,
+            budget: self.budget,
+            embeddings,
+            event_log: self.event_log,
+        }
+    }
+
+    /// Sets the configuration, including the resource limits, of every interpreter run by the
+    /// orchestrator.
+    #[must_use]
+    pub const fn with_runtime_config(mut self, config: RuntimeConfig) -> Self {
+        self.runtime_config = config;
+        self
+    }
+
+    /// Attaches a cancellation token, stopping every interpreter run by the orchestrator once
+    /// cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Creates a runtime using the configuration and cancellation token of the orchestrator.
+    fn runtime_in<'rt, L: Allocator + Clone>(
+        &self,
+        inputs: &'rt Inputs<'heap, L>,
+        alloc: L,
+    ) -> Runtime<'rt, 'heap, L>
+    where
+        'ctx: 'rt,
+    {
+        let runtime = Runtime::new_in(self.runtime_config, self.context.bodies, inputs, alloc);
+
+        match &self.cancellation {
+            Some(token) => runtime.with_cancellation(token.clone()),
+            None => runtime,
+        }
+    }
+
+    /// Calls `run` with `runtime` charged to the budget shared by every interpreter of the
+    /// orchestrator.
+    ///
+    /// The budget is handed to `runtime` before, and taken back after `run` returned, which is why
+    /// every call to the interpreter must go through this function.
+    fn metered<'rt, L: Allocator + Clone, T>(
+        &self,
+        runtime: &mut Runtime<'rt, 'heap, L>,
+        run: impl FnOnce(&mut Runtime<'rt, 'heap, L>) -> T,
+    ) -> T {
+        let budget = self
+            .budget
+            .get()
+            .unwrap_or_else(|| Budget::start(&self.runtime_config));
+
+        runtime.set_budget(budget);
+        let output = run(runtime);
+        self.budget.set(Some(runtime.budget()));
+
+        output
+    }
 }
 
 #[expect(clippy::future_not_send)]
-impl<'ctx, 'heap, C, E: EventLog, A: Allocator> Orchestrator<'_, 'ctx, 'heap, C, E, A> {
+impl<'ctx, 'heap, C, E: EventLog, A: Allocator, B: EmbeddingBackend>
+    Orchestrator<'_, 'ctx, 'heap, C, E, A, B>
+{
     /// Executes a complete query, resolving suspensions in a loop until the
     /// interpreter returns a final [`Value`].
     ///
@@ -183,19 +293,17 @@ impl<'ctx, 'heap, C, E: EventLog, A: Allocator> Orchestrator<'_, 'ctx, 'heap, C,
     where
         C: AsRef<Client>,
     {
-        let mut runtime = Runtime::new_in(
-            RuntimeConfig::default(),
-            self.context.bodies,
-            inputs,
-            alloc.clone(),
-        );
-        runtime.reset();
+        let mut runtime = self.runtime_in(inputs, alloc.clone());
+        runtime.clear_scratch();
+        self.budget.set(Some(Budget::start(&self.runtime_config)));
 
         let mut callstack = CallStack::new(&runtime, body, args);
 
         let Err(error) = try {
             loop {
-                let next = runtime.run_until_suspension(&mut callstack)?;
+                let next = self.metered(&mut runtime, |runtime| {
+                    runtime.run_until_suspension(&mut callstack)
+                })?;
                 match next {
                     hashql_mir::interpret::Yield::Return(value) => {
                         return Ok(value);
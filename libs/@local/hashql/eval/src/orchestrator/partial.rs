@@ -27,14 +27,15 @@ use alloc::rc::Rc;
 use core::alloc::Allocator;
 
 use hashql_core::{
+    module::std_lib::graph::types::principal::actor_group::web::types as web,
     symbol::{Symbol, sym},
-    r#type::{TypeId, environment::Environment},
+    r#type::{TypeBuilder, TypeId, environment::Environment},
 };
 use hashql_mir::{
     interpret::value::{Int, Num, Opaque, StructBuilder, Value},
     pass::execution::{
         VertexType,
-        traversal::{EntityPath, TraversalPath},
+        traversal::{EntityPath, OntologyPath, TraversalPath},
     },
 };
 use tokio_postgres::Row;
@@ -640,6 +641,43 @@ impl<'heap, A: Allocator> PartialEntity<'heap, A> {
         Value::Opaque(Opaque::new(sym::path::Entity, Rc::new_in(value, alloc)))
     }
 
+    /// Adds `vectors` as the `encodings.vectors` of an already finished `entity`.
+    ///
+    /// The vectors are never hydrated from PostgreSQL, they're provided by the embedding backend
+    /// once an island requires them, which is after the entity has been finished.
+    pub(crate) fn hydrate_vectors_in(
+        entity: &Value<'heap, A>,
+        vectors: Value<'heap, A>,
+        interner: &Interner<'heap>,
+        alloc: A,
+    ) -> Value<'heap, A>
+    where
+        A: Clone,
+    {
+        let Value::Opaque(entity) = entity else {
+            unreachable!("finished entities are always wrapped in their opaque")
+        };
+        let Value::Struct(fields) = entity.value() else {
+            unreachable!("finished entities are always structs")
+        };
+
+        let encodings = PartialEncodings {
+            vectors: Required::Value(vectors),
+        }
+        .finish_in(interner, alloc.clone());
+
+        let mut builder: StructBuilder<'heap, A, 4> = StructBuilder::new();
+        for (field, value) in fields {
+            if field != sym::encodings {
+                builder.push(field, value.clone());
+            }
+        }
+        builder.push(sym::encodings, encodings);
+
+        let value = Value::Struct(builder.finish(&interner.symbols, alloc.clone()));
+        Value::Opaque(Opaque::new(sym::path::Entity, Rc::new_in(value, alloc)))
+    }
+
     #[expect(clippy::too_many_lines)]
     fn hydrate_from_postgres(
         &mut self,
@@ -1136,14 +1174,207 @@ impl<A: Allocator> Default for PartialEntity<'_, A> {
     }
 }
 
+/// Partial representation of `VersionedUrl`, the identity of a type of the ontology.
+pub(crate) struct PartialVersionedUrl<'heap, A: Allocator> {
+    pub base_url: Required<Value<'heap, A>>,
+    pub version: Required<Value<'heap, A>>,
+}
+
+impl<'heap, A: Allocator> PartialVersionedUrl<'heap, A> {
+    pub(crate) fn finish_in(self, interner: &Interner<'heap>, alloc: A) -> Value<'heap, A>
+    where
+        A: Clone,
+    {
+        let mut builder: StructBuilder<'heap, A, 2> = StructBuilder::new();
+        self.base_url.finish_in(&mut builder, sym::base_url);
+        self.version.finish_in(&mut builder, sym::version);
+
+        let value = Value::Struct(builder.finish(&interner.symbols, alloc.clone()));
+        Value::Opaque(Opaque::new(
+            sym::path::VersionedUrl,
+            Rc::new_in(value, alloc),
+        ))
+    }
+}
+
+impl<A: Allocator> Default for PartialVersionedUrl<'_, A> {
+    fn default() -> Self {
+        Self {
+            base_url: Required::Skipped,
+            version: Required::Skipped,
+        }
+    }
+}
+
+/// Partial representation of the metadata of a type of the ontology.
+///
+/// Schema field `web_id` is `Option<WebId>`, external types are not owned by any web.
+pub(crate) struct PartialOntologyMetadata<'heap, A: Allocator> {
+    pub web_id: Optional<Value<'heap, A>>,
+}
+
+impl<'heap, A: Allocator> PartialOntologyMetadata<'heap, A> {
+    pub(crate) fn finish_in(
+        self,
+        name: Symbol<'static>,
+        interner: &Interner<'heap>,
+        alloc: A,
+    ) -> Value<'heap, A>
+    where
+        A: Clone,
+    {
+        let mut builder: StructBuilder<'heap, A, 1> = StructBuilder::new();
+        self.web_id
+            .finish_in(&mut builder, sym::web_id, alloc.clone());
+
+        let value = Value::Struct(builder.finish(&interner.symbols, alloc.clone()));
+        Value::Opaque(Opaque::new(name, Rc::new_in(value, alloc)))
+    }
+}
+
+impl<A: Allocator> Default for PartialOntologyMetadata<'_, A> {
+    fn default() -> Self {
+        Self {
+            web_id: Optional::Skipped,
+        }
+    }
+}
+
+/// Partial representation of `DataType`, `PropertyType` and `EntityType`.
+///
+/// All three share the same shape, only the names of their opaque wrappers differ, which are
+/// determined by the `vertex`.
+pub(crate) struct PartialOntologyType<'heap, A: Allocator> {
+    vertex: VertexType,
+
+    pub id: Required<PartialVersionedUrl<'heap, A>>,
+    pub metadata: Required<PartialOntologyMetadata<'heap, A>>,
+    pub schema: Required<Value<'heap, A>>,
+}
+
+impl<'heap, A: Allocator> PartialOntologyType<'heap, A> {
+    const fn new(vertex: VertexType) -> Self {
+        Self {
+            vertex,
+            id: Required::Skipped,
+            metadata: Required::Skipped,
+            schema: Required::Skipped,
+        }
+    }
+
+    pub(crate) fn finish_in(self, interner: &Interner<'heap>, alloc: A) -> Value<'heap, A>
+    where
+        A: Clone,
+    {
+        let (name, metadata_name) = match self.vertex {
+            VertexType::DataType => (sym::path::DataType, sym::path::DataTypeMetadata),
+            VertexType::PropertyType => (sym::path::PropertyType, sym::path::PropertyTypeMetadata),
+            VertexType::EntityType => (sym::path::EntityType, sym::path::EntityTypeMetadata),
+            VertexType::Entity => unreachable!("entities are not a type of the ontology"),
+        };
+
+        let mut builder: StructBuilder<'heap, A, 3> = StructBuilder::new();
+        self.id
+            .map(|partial| partial.finish_in(interner, alloc.clone()))
+            .finish_in(&mut builder, sym::id);
+        self.metadata
+            .map(|partial| partial.finish_in(metadata_name, interner, alloc.clone()))
+            .finish_in(&mut builder, sym::metadata);
+        self.schema.finish_in(&mut builder, sym::schema);
+
+        let value = Value::Struct(builder.finish(&interner.symbols, alloc.clone()));
+        Value::Opaque(Opaque::new(name, Rc::new_in(value, alloc)))
+    }
+
+    fn hydrate_from_postgres(
+        &mut self,
+        env: &Environment<'heap>,
+        decoder: &Decoder<'_, 'heap, A>,
+        path: OntologyPath,
+        r#type: TypeId,
+        column: Indexed<ColumnDescriptor>,
+        row: &Row,
+    ) -> Result<(), BridgeError<'heap>>
+    where
+        A: Clone,
+    {
+        let row_hydration_error = |source| BridgeError::RowHydration { column, source };
+
+        match path {
+            OntologyPath::VersionedUrl => {
+                let value: serde_json::Value =
+                    row.try_get(column.index).map_err(row_hydration_error)?;
+
+                let base_url = decoder.try_decode(
+                    OntologyPath::BaseUrl.resolve_type(env),
+                    (&value["base_url"]).into(),
+                    column,
+                )?;
+                let version = decoder.try_decode(
+                    OntologyPath::Version.resolve_type(env),
+                    (&value["version"]).into(),
+                    column,
+                )?;
+
+                hydrate!(self->id->base_url = base_url);
+                hydrate!(self->id->version = version);
+            }
+            OntologyPath::BaseUrl => {
+                let value: String = row.try_get(column.index).map_err(row_hydration_error)?;
+                let value = decoder.try_decode(r#type, JsonValueRef::String(&value), column)?;
+                hydrate!(self->id->base_url = value);
+            }
+            OntologyPath::Version => {
+                let value: String = row.try_get(column.index).map_err(row_hydration_error)?;
+                let value = decoder.try_decode(r#type, JsonValueRef::String(&value), column)?;
+                hydrate!(self->id->version = value);
+            }
+            OntologyPath::WebId => {
+                let value: Option<Uuid> = row.try_get(column.index).map_err(row_hydration_error)?;
+
+                // The path is typed as `Option<WebId>`, but the option is applied by
+                // `Optional::finish_in`, so only the web id itself is decoded.
+                let web_id = web::web_id(&TypeBuilder::synthetic(env), None);
+
+                let value = value
+                    .map(|uuid| {
+                        decoder.try_decode(
+                            web_id,
+                            JsonValueRef::String(&uuid.hyphenated().to_string()),
+                            column,
+                        )
+                    })
+                    .transpose()?;
+                hydrate!(self->metadata->web_id = value);
+            }
+            OntologyPath::Schema => {
+                let value: serde_json::Value =
+                    row.try_get(column.index).map_err(row_hydration_error)?;
+                let value = decoder.try_decode(r#type, (&value).into(), column)?;
+                self.schema.set(value);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[expect(
+    clippy::large_enum_variant,
+    reason = "entities are the common case, boxing them would allocate for every row"
+)]
 pub(crate) enum Partial<'heap, A: Allocator> {
     Entity(PartialEntity<'heap, A>),
+    OntologyType(PartialOntologyType<'heap, A>),
 }
 
 impl<'heap, A: Allocator> Partial<'heap, A> {
     pub(crate) fn new(vertex_type: VertexType) -> Self {
         match vertex_type {
             VertexType::Entity => Self::Entity(PartialEntity::default()),
+            VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                Self::OntologyType(PartialOntologyType::new(vertex_type))
+            }
         }
     }
 
@@ -1163,6 +1394,22 @@ impl<'heap, A: Allocator> Partial<'heap, A> {
             (Self::Entity(entity), TraversalPath::Entity(entity_path)) => {
                 entity.hydrate_from_postgres(env, decoder, entity_path, r#type, column, row)
             }
+            (
+                Self::OntologyType(ontology_type),
+                TraversalPath::DataType(ontology_path)
+                | TraversalPath::PropertyType(ontology_path)
+                | TraversalPath::EntityType(ontology_path),
+            ) => ontology_type.hydrate_from_postgres(
+                env,
+                decoder,
+                ontology_path,
+                r#type,
+                column,
+                row,
+            ),
+            (Self::Entity(_), _) | (Self::OntologyType(_), TraversalPath::Entity(_)) => {
+                unreachable!("the column of a different kind of vertex")
+            }
         }
     }
 
@@ -1172,6 +1419,7 @@ impl<'heap, A: Allocator> Partial<'heap, A> {
     {
         match self {
             Self::Entity(entity) => entity.finish_in(interner, alloc),
+            Self::OntologyType(ontology_type) => ontology_type.finish_in(interner, alloc),
         }
     }
 }
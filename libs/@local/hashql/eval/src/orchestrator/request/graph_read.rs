@@ -8,27 +8,40 @@
 //! 3. Executing the query against PostgreSQL and streaming rows.
 //! 4. For each row: hydrating flat columns into a nested vertex [`Value`], decoding any
 //!    continuation state, running client-side filter chains (which may themselves involve
-//!    interpreter and postgres interleaving), and accumulating accepted values via a [`Tail`]
-//!    strategy.
-//! 5. Packaging the collected output into a [`Continuation`] for the interpreter to resume with.
+//!    interpreter, postgres and embedding interleaving), and accumulating accepted values via a
+//!    [`Tail`] strategy. If the vectors of the entities are required, all rows are received before
+//!    the first one is processed, so that the vectors can be fetched in a single batch.
+//! 5. Applying any ordering and pagination the query could not, which buffers the accepted values
+//!    until all rows have been received.
+//! 6. Packaging the collected output into a [`Continuation`] for the interpreter to resume with.
 //!
 //! [`GraphRead`]: hashql_mir::body::terminator::GraphRead
 //! [`Value`]: hashql_mir::interpret::value::Value
 //! [`Continuation`]: hashql_mir::interpret::suspension::Continuation
 //! [`Tail`]: super::super::tail::Tail
 
-use core::{alloc::Allocator, pin::pin};
+use core::{alloc::Allocator, cell::RefCell, mem, ops::ControlFlow, pin::pin};
 
 use futures_lite::StreamExt as _;
+use hashql_core::{
+    collections::{FastHashMap, fast_hash_map},
+    graph::DirectedGraph as _,
+};
 use hashql_mir::{
-    body::terminator::{GraphRead, GraphReadBody},
+    body::{
+        local::Local,
+        terminator::{GraphRead, GraphReadBody, GraphReadTail},
+    },
     def::DefId,
     interpret::{
-        CallStack, Inputs, Runtime, RuntimeConfig, RuntimeError, Yield,
+        CallStack, Inputs, Runtime, RuntimeError, Yield,
         suspension::{Continuation, GraphReadSuspension},
-        value::Value,
+        value::{Int, Value},
+    },
+    pass::execution::{
+        IslandKind, TargetId, VertexType,
+        traversal::{EntityPath, TraversalPath, TraversalPathBitSet},
     },
-    pass::execution::TargetId,
 };
 use tokio_postgres::{Client, Row};
 
@@ -36,13 +49,14 @@ use crate::{
     orchestrator::{
         Indexed, Orchestrator,
         codec::{decode::Decoder, encode::encode_parameter_in},
+        embedding::{EmbeddingBackend, EntityKey, vectors_value},
         error::BridgeError,
         events::{Event, EventLog},
-        partial::Partial,
+        partial::{Partial, PartialEntity},
         postgres::{PartialPostgresState, PostgresState},
-        tail::Tail,
+        tail::{NotANumber, Tail},
     },
-    postgres::{ColumnDescriptor, PreparedQuery},
+    postgres::{ColumnDescriptor, PreparedQuery, QueryOutput},
 };
 
 type PartialState<'heap, L> = (Partial<'heap, L>, Vec<PartialPostgresState<L>, L>);
@@ -57,17 +71,36 @@ type State<'heap, L> = (Value<'heap, L>, Vec<PostgresState<'heap, L>, L>);
 ///
 /// [`GraphRead`]: hashql_mir::body::terminator::GraphRead
 /// [`Orchestrator`]: super::super::Orchestrator
-pub(crate) struct GraphReadOrchestrator<'or, 'env, 'ctx, 'heap, C, E, A: Allocator> {
-    inner: &'or Orchestrator<'env, 'ctx, 'heap, C, E, A>,
+pub(crate) struct GraphReadOrchestrator<'or, 'env, 'ctx, 'heap, C, E, A: Allocator, B> {
+    inner: &'or Orchestrator<'env, 'ctx, 'heap, C, E, A, B>,
+    vectors: RefCell<VectorBatch>,
+}
+
+/// The vectors of the entities received by a graph read.
+///
+/// The vectors are fetched from the embedding backend in a single batch, once the first entity
+/// requires them. Fetching them while the rows are being received would stall the query, as the
+/// backend may share the connection the rows are received from.
+struct VectorBatch {
+    /// Entities whose vectors have not been fetched yet.
+    pending: Vec<EntityKey>,
+    /// Vectors fetched so far, [`None`] if the entity has no embedding.
+    fetched: FastHashMap<EntityKey, Option<Vec<f32>>>,
 }
 
 #[expect(clippy::future_not_send)]
-impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
-    GraphReadOrchestrator<'or, 'env, 'ctx, 'heap, C, E, A>
+impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator, B: EmbeddingBackend>
+    GraphReadOrchestrator<'or, 'env, 'ctx, 'heap, C, E, A, B>
 {
-    pub(crate) const fn new(orchestrator: &'or Orchestrator<'env, 'ctx, 'heap, C, E, A>) -> Self {
+    pub(crate) const fn new(
+        orchestrator: &'or Orchestrator<'env, 'ctx, 'heap, C, E, A, B>,
+    ) -> Self {
         Self {
             inner: orchestrator,
+            vectors: RefCell::new(VectorBatch {
+                pending: Vec::new(),
+                fetched: fast_hash_map(),
+            }),
         }
     }
 
@@ -156,6 +189,130 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
         Ok((entity, states))
     }
 
+    /// Returns whether any filter or closure evaluated for the rows of `read` requires the vectors
+    /// of the entities.
+    ///
+    /// Mirrors the conditions under which [`hydrate_vectors_in`](Self::hydrate_vectors_in) is
+    /// called.
+    fn requires_vectors(&self, read: &GraphRead<'heap>, vertex: VertexType) -> bool {
+        let reads_vectors = |body: DefId| {
+            TraversalPathBitSet::from_body(vertex, &self.inner.context.bodies[body])
+                .contains(TraversalPath::Entity(EntityPath::Vectors))
+        };
+
+        let filters = read
+            .body
+            .iter()
+            .rposition(|body| matches!(body, GraphReadBody::Traverse(_)))
+            .map_or(0, |index| index + 1);
+
+        let body = read.body[filters..].iter().any(|body| match *body {
+            GraphReadBody::Filter(body, _) => self
+                .inner
+                .context
+                .execution
+                .lookup(body)
+                .is_some_and(|residual| {
+                    residual.islands.iter_nodes().any(|(_, island)| {
+                        matches!(island.kind(), IslandKind::Exec(_))
+                            && island.target() != TargetId::Postgres
+                            && island
+                                .requires()
+                                .contains(TraversalPath::Entity(EntityPath::Vectors))
+                    })
+                }),
+            GraphReadBody::SortBy(body, _) => reads_vectors(body),
+            GraphReadBody::Traverse(_) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {
+                false
+            }
+        });
+
+        let tail = match read.tail {
+            GraphReadTail::GroupBy(body, _) | GraphReadTail::Aggregate(_, body, _) => {
+                reads_vectors(body)
+            }
+            GraphReadTail::Collect
+            | GraphReadTail::Count
+            | GraphReadTail::Exists
+            | GraphReadTail::First => false,
+        };
+
+        body || tail
+    }
+
+    /// Returns the vectors of `key`, fetching the vectors of every pending entity alongside them.
+    async fn vectors(
+        &self,
+        body: DefId,
+        key: EntityKey,
+    ) -> Result<Option<Vec<f32>>, BridgeError<'heap>> {
+        let batch = {
+            let mut vectors = self.vectors.borrow_mut();
+            if let Some(fetched) = vectors.fetched.get(&key) {
+                return Ok(fetched.clone());
+            }
+
+            let mut batch = mem::take(&mut vectors.pending);
+            if !batch.contains(&key) {
+                batch.push(key);
+            }
+
+            batch
+        };
+
+        let mut fetched = self
+            .inner
+            .embeddings
+            .batch_vectors(&batch)
+            .await
+            .map_err(|source| BridgeError::EmbeddingLookup {
+                body,
+                entity: key,
+                source,
+            })?;
+
+        let mut vectors = self.vectors.borrow_mut();
+        for entity in batch {
+            let entity_vectors = fetched.remove(&entity);
+            vectors.fetched.insert(entity, entity_vectors);
+        }
+
+        Ok(vectors.fetched[&key].clone())
+    }
+
+    /// Fetches the vectors of the entity from the embedding backend and hydrates them into the
+    /// vertex of the filter `body`.
+    async fn hydrate_vectors_in<L: Allocator + Clone>(
+        &self,
+        body: DefId,
+        callstack: &mut CallStack<'ctx, 'heap, L>,
+        alloc: L,
+    ) -> Result<(), RuntimeError<'heap, BridgeError<'heap>, L>> {
+        let entity = callstack.locals()?.local(Local::VERTEX)?;
+
+        let key = EntityKey::from_entity(entity)
+            .ok_or_else(|| RuntimeError::Suspension(BridgeError::MissingEntityKey { body }))?;
+
+        let vectors = self
+            .vectors(body, key)
+            .await
+            .map_err(RuntimeError::Suspension)?;
+
+        let entity = PartialEntity::hydrate_vectors_in(
+            entity,
+            vectors_value(vectors.as_deref()),
+            self.inner.context.interner,
+            alloc,
+        );
+
+        *callstack
+            .locals_mut()
+            .map_err(RuntimeError::widen)?
+            .local_mut(Local::VERTEX) = entity;
+
+        Ok(())
+    }
+
     #[expect(clippy::too_many_arguments)]
     async fn process_row_filter_in<L: Allocator + Clone>(
         &self,
@@ -183,6 +340,8 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
 
         self.inner.event_log.log(Event::FilterStarted { body });
 
+        let mut vectors_hydrated = false;
+
         let eval = 'eval: loop {
             let (island_id, island_node) = residual.islands.lookup(callstack.current_block()?);
             let target = island_node.target();
@@ -193,11 +352,39 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
                 target,
             });
 
+            // The vectors are only fetched once the first island requires them, which is either
+            // an island on the embedding target or an interpreter island they've been provided to.
+            // This includes islands requiring the complete vertex (because one of their
+            // projections could not be resolved), as the complete vertex includes the vectors.
+            // Postgres islands never read the vertex, they continue from the state of the row.
+            // TODO: in the future this may benefit from a dispatch barrier, the idea that we wait
+            // for sufficient embedding calls to the same island to dispatch. Must be smaller than
+            // the buffer size.
+            if !vectors_hydrated
+                && target != TargetId::Postgres
+                && island_node
+                    .requires()
+                    .contains(TraversalPath::Entity(EntityPath::Vectors))
+            {
+                self.hydrate_vectors_in(body, &mut callstack, alloc.clone())
+                    .await?;
+                vectors_hydrated = true;
+
+                self.inner.event_log.log(Event::EmbeddingsFetched {
+                    body,
+                    island: island_id,
+                });
+            }
+
             match target {
-                TargetId::Interpreter => {
+                // Once the vectors have been hydrated, the projections of an embedding island are
+                // evaluated by the interpreter.
+                TargetId::Interpreter | TargetId::Embedding => {
                     loop {
-                        let next = runtime.run_until_transition(&mut callstack, |target| {
-                            residual.islands.lookup(target).0 == island_id
+                        let next = self.inner.metered(runtime, |runtime| {
+                            runtime.run_until_transition(&mut callstack, |target| {
+                                residual.islands.lookup(target).0 == island_id
+                            })
                         })?;
 
                         match next {
@@ -265,12 +452,6 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
                         island: island_id,
                     });
                 }
-                TargetId::Embedding => {
-                    // TODO: in the future this may benefit from a dispatch barrier, the
-                    // idea that we wait for sufficient embedding calls to the same
-                    // island to dispatch. Must be smaller than the buffer size.
-                    unimplemented!()
-                }
             }
         };
 
@@ -290,19 +471,22 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
 
         alloc: L,
     ) -> Result<Option<Value<'heap, L>>, RuntimeError<'heap, BridgeError<'heap>, L>> {
-        let mut runtime = Runtime::new_in(
-            RuntimeConfig::default(),
-            self.inner.context.bodies,
-            inputs,
-            alloc.clone(),
-        );
+        let mut runtime = self.inner.runtime_in(inputs, alloc.clone());
+
+        // Filters before the last traversal select the entities the traversal starts from, the
+        // database evaluates them in full.
+        let output = read
+            .body
+            .iter()
+            .rposition(|body| matches!(body, GraphReadBody::Traverse(_)))
+            .map_or(0, |index| index + 1);
 
-        for body in &read.body {
+        for body in &read.body[output..] {
             match body {
                 &GraphReadBody::Filter(body, env) => {
                     let env = parent.locals()?.local(env)?;
 
-                    runtime.reset();
+                    runtime.clear_scratch();
                     let result = self
                         .process_row_filter_in(
                             inputs,
@@ -324,24 +508,185 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
                         return Ok(None);
                     }
                 }
+                GraphReadBody::Traverse(_) => {
+                    unreachable!("only the filters after the last traversal are evaluated")
+                }
+                // Ordering and pagination come after all filters, they are applied once all rows
+                // have been received.
+                GraphReadBody::SortBy(..) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {
+                    break;
+                }
             }
         }
 
         Ok(Some(entity))
     }
 
-    async fn process_row_in<L: Allocator + Clone>(
+    /// Evaluates the closure `body` for `entity` in the interpreter.
+    ///
+    /// Used for the key closures of `sort_by`, `group_by` and the numeric aggregates. Unlike
+    /// filters these closures aren't split into islands, they're always evaluated in full.
+    #[expect(clippy::too_many_arguments)]
+    async fn process_closure_in<L: Allocator + Clone>(
         &self,
         inputs: &Inputs<'heap, L>,
         parent: &CallStack<'ctx, 'heap, L>,
 
-        read: &GraphRead<'heap>,
-        query: &PreparedQuery<'heap, impl Allocator>,
+        runtime: &mut Runtime<'ctx, 'heap, L>,
+
+        vertex: VertexType,
+        body: DefId,
+        env: Local,
 
-        row: Row,
+        entity: &Value<'heap, L>,
 
         alloc: L,
-    ) -> Result<Option<Value<'heap, L>>, RuntimeError<'heap, BridgeError<'heap>, L>> {
+    ) -> Result<Value<'heap, L>, RuntimeError<'heap, BridgeError<'heap>, L>> {
+        let env = parent.locals()?.local(env)?;
+
+        let Ok(mut callstack) = CallStack::new_in(
+            &self.inner.context.bodies[body],
+            [Ok::<_, !>(env.clone()), Ok(entity.clone())],
+            alloc.clone(),
+        );
+
+        // The query selects every field the closure reads, except for the vectors, which are
+        // fetched from the embedding backend.
+        if TraversalPathBitSet::from_body(vertex, &self.inner.context.bodies[body])
+            .contains(TraversalPath::Entity(EntityPath::Vectors))
+        {
+            self.hydrate_vectors_in(body, &mut callstack, alloc.clone())
+                .await?;
+        }
+
+        runtime.clear_scratch();
+        loop {
+            match self.inner.metered(runtime, |runtime| {
+                runtime.run_until_suspension(&mut callstack)
+            })? {
+                Yield::Return(value) => return Ok(value),
+                Yield::Suspension(suspension) => {
+                    let continuation = Box::pin(self.inner.fulfill_in(
+                        inputs,
+                        &callstack,
+                        suspension,
+                        alloc.clone(),
+                    ))
+                    .await?;
+
+                    continuation.apply(&mut callstack)?;
+                }
+            }
+        }
+    }
+
+    /// Applies the ordering and pagination operations which haven't been compiled into the
+    /// query to the accepted rows.
+    #[expect(clippy::too_many_arguments)]
+    async fn process_ordering_in<L: Allocator + Clone>(
+        &self,
+        inputs: &Inputs<'heap, L>,
+        parent: &CallStack<'ctx, 'heap, L>,
+
+        runtime: &mut Runtime<'ctx, 'heap, L>,
+
+        vertex: VertexType,
+        ordering: &[GraphReadBody],
+        rows: &mut Vec<Value<'heap, L>, L>,
+
+        alloc: L,
+    ) -> Result<(), RuntimeError<'heap, BridgeError<'heap>, L>> {
+        for body in ordering {
+            match *body {
+                GraphReadBody::SortBy(body, env) => {
+                    let mut keyed = Vec::with_capacity_in(rows.len(), alloc.clone());
+
+                    for entity in rows.drain(..) {
+                        let key = self
+                            .process_closure_in(
+                                inputs,
+                                parent,
+                                runtime,
+                                vertex,
+                                body,
+                                env,
+                                &entity,
+                                alloc.clone(),
+                            )
+                            .await?;
+
+                        keyed.push((key, entity));
+                    }
+
+                    // The sort must be stable, so that subsequent sorts keep the order of equal
+                    // keys.
+                    keyed.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+                    rows.extend(keyed.into_iter().map(|(_, entity)| entity));
+                }
+                GraphReadBody::Limit(count) => rows.truncate(count),
+                GraphReadBody::Offset(count) => {
+                    rows.drain(..count.min(rows.len()));
+                }
+                GraphReadBody::Filter(..) | GraphReadBody::Traverse(_) => {
+                    unreachable!("ordering and pagination come after all filters and traversals")
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Accumulates an accepted row into the `output`, evaluating the key closure of the tail if
+    /// required.
+    ///
+    /// If the query selected the column the key reads, the key is read from the `entity` instead.
+    #[expect(clippy::too_many_arguments)]
+    async fn process_tail_in<L: Allocator + Clone>(
+        &self,
+        inputs: &Inputs<'heap, L>,
+        parent: &CallStack<'ctx, 'heap, L>,
+
+        runtime: &mut Runtime<'ctx, 'heap, L>,
+
+        vertex: VertexType,
+        tail: GraphReadTail,
+        key: Option<EntityPath>,
+        output: &mut Tail<'heap, L>,
+
+        entity: Value<'heap, L>,
+
+        alloc: L,
+    ) -> Result<ControlFlow<()>, RuntimeError<'heap, BridgeError<'heap>, L>> {
+        let (GraphReadTail::GroupBy(body, env) | GraphReadTail::Aggregate(_, body, env)) = tail
+        else {
+            return Ok(output.push(entity));
+        };
+
+        let key = if let Some(path) = key {
+            let mut key = &entity;
+            for &field in path.field_path() {
+                key = key.project_by_name(field)?;
+            }
+
+            key.clone()
+        } else {
+            self.process_closure_in(inputs, parent, runtime, vertex, body, env, &entity, alloc)
+                .await?
+        };
+
+        output.push_keyed(&key, entity).map_err(|NotANumber| {
+            RuntimeError::Suspension(BridgeError::InvalidAggregateValue { body })
+        })?;
+
+        Ok(ControlFlow::Continue(()))
+    }
+
+    fn hydrate_row_in<L: Allocator + Clone>(
+        &self,
+        query: &PreparedQuery<'heap, impl Allocator>,
+        row: &Row,
+        alloc: L,
+    ) -> Result<State<'heap, L>, RuntimeError<'heap, BridgeError<'heap>, L>> {
         let decoder = Decoder::new(
             self.inner.context.env,
             self.inner.context.interner,
@@ -349,21 +694,14 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
         );
 
         let (partial, partial_states) =
-            self.postgres_hydrate_in(&decoder, query, &row, alloc.clone())?;
-
-        let (entity, states) = self.finish_in(&decoder, partial, partial_states, alloc.clone())?;
+            self.postgres_hydrate_in(&decoder, query, row, alloc.clone())?;
 
-        // Now that we have the completed states, it's time to fulfill the graph read, by running
-        // everything through the filter chain.
-        // This is sequential in nature, because in the future filters may depend on the mapped
-        // value. The parallelisation opportunity of sequential filters isn't applicable here,
-        // instead that should be done inside either the HIR or MIR.
-        self.process_row_transform_in(inputs, parent, &states, entity, read, alloc)
-            .await
+        self.finish_in(&decoder, partial, partial_states, alloc)
     }
 
     // The entrypoint for graph read operations. The entrypoint is *always* postgres, because that's
     // the primary data store.
+    #[expect(clippy::too_many_lines)]
     pub(crate) async fn fulfill_in<L: Allocator + Clone>(
         &self,
         inputs: &Inputs<'heap, L>,
@@ -420,31 +758,190 @@ impl<'or, 'env, 'ctx, 'heap, C: AsRef<Client>, E: EventLog, A: Allocator>
 
         let mut response = pin!(response);
 
+        let (compiled, key) = match query.output {
+            QueryOutput::Rows { ordering, key } => (ordering, key),
+            QueryOutput::Count => {
+                // The database counted the rows, every row carries a partial count.
+                let mut count = 0_i64;
+                while let Some(row) = response.next().await {
+                    let row = row
+                        .map_err(|error| BridgeError::QueryExecution {
+                            sql: statement.clone(),
+                            source: error,
+                        })
+                        .map_err(RuntimeError::Suspension)?;
+
+                    count += row
+                        .try_get::<_, i64>(0)
+                        .map_err(|source| BridgeError::AggregateHydration { source })
+                        .map_err(RuntimeError::Suspension)?;
+                }
+
+                return Ok(suspension.resolve(Value::Integer(Int::from(count))));
+            }
+            QueryOutput::Aggregate(aggregate) => {
+                // The database aggregated the keys, the aggregate is `NULL` if there are no rows.
+                let mut value = None;
+                while let Some(row) = response.next().await {
+                    let row = row
+                        .map_err(|error| BridgeError::QueryExecution {
+                            sql: statement.clone(),
+                            source: error,
+                        })
+                        .map_err(RuntimeError::Suspension)?;
+
+                    value = row
+                        .try_get::<_, Option<f64>>(0)
+                        .map_err(|source| BridgeError::AggregateHydration { source })
+                        .map_err(RuntimeError::Suspension)?;
+                }
+
+                let output = Tail::aggregated(aggregate, value).finish_in(alloc);
+                return Ok(suspension.resolve(output));
+            }
+        };
+
+        // The ordering and pagination operations which have not been compiled into the query
+        // require all accepted rows, before any of them can be accumulated.
+        let ordering = read
+            .body
+            .iter()
+            .position(|body| {
+                matches!(
+                    body,
+                    GraphReadBody::SortBy(..) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_)
+                )
+            })
+            .unwrap_or(read.body.len());
+        let ordering = &read.body[ordering + compiled..];
+
+        let mut runtime = self.inner.runtime_in(inputs, alloc.clone());
+
+        // The vectors are fetched in a single batch, which must not happen while rows are still
+        // being received, so every row is received and hydrated up front.
+        let mut received = None;
+        if self.requires_vectors(read, query.vertex_type) {
+            let mut states = Vec::new_in(alloc.clone());
+            while let Some(row) = response.next().await {
+                let row = row
+                    .map_err(|error| BridgeError::QueryExecution {
+                        sql: statement.clone(),
+                        source: error,
+                    })
+                    .map_err(RuntimeError::Suspension)?;
+
+                let state = self.hydrate_row_in(query, &row, alloc.clone())?;
+                if let Some(key) = EntityKey::from_entity(&state.0) {
+                    self.vectors.borrow_mut().pending.push(key);
+                }
+
+                states.push(state);
+            }
+
+            received = Some(states.into_iter());
+        }
+
         // TODO: parallelisation opportunity
         let mut output = Tail::new(read.tail);
-        while let Some(row) = response.next().await {
-            let row = row
-                .map_err(|error| BridgeError::QueryExecution {
-                    sql: statement.clone(),
-                    source: error,
-                })
-                .map_err(RuntimeError::Suspension)?;
+        let mut rows = Vec::new_in(alloc.clone());
+        loop {
+            let (entity, states) = if let Some(received) = &mut received {
+                let Some(state) = received.next() else {
+                    break;
+                };
+
+                state
+            } else {
+                let Some(row) = response.next().await else {
+                    break;
+                };
+
+                let row = row
+                    .map_err(|error| BridgeError::QueryExecution {
+                        sql: statement.clone(),
+                        source: error,
+                    })
+                    .map_err(RuntimeError::Suspension)?;
+
+                self.hydrate_row_in(query, &row, alloc.clone())?
+            };
 
             self.inner.event_log.log(Event::RowReceived);
 
+            // Now that we have the completed states, it's time to fulfill the graph read, by
+            // running everything through the filter chain.
+            // This is sequential in nature, because in the future filters may depend on the mapped
+            // value. The parallelisation opportunity of sequential filters isn't applicable here,
+            // instead that should be done inside either the HIR or MIR.
             let item = self
-                .process_row_in(inputs, callstack, read, query, row, alloc.clone())
+                .process_row_transform_in(inputs, callstack, &states, entity, read, alloc.clone())
                 .await?;
 
-            if let Some(item) = item {
-                self.inner.event_log.log(Event::RowAccepted);
-                output.push(item);
-            } else {
+            let Some(item) = item else {
                 self.inner.event_log.log(Event::RowRejected);
+                continue;
+            };
+
+            self.inner.event_log.log(Event::RowAccepted);
+
+            if !ordering.is_empty() {
+                rows.push(item);
+                continue;
+            }
+
+            let flow = self
+                .process_tail_in(
+                    inputs,
+                    callstack,
+                    &mut runtime,
+                    query.vertex_type,
+                    read.tail,
+                    key,
+                    &mut output,
+                    item,
+                    alloc.clone(),
+                )
+                .await?;
+
+            if flow.is_break() {
+                break;
+            }
+        }
+
+        if !ordering.is_empty() {
+            self.process_ordering_in(
+                inputs,
+                callstack,
+                &mut runtime,
+                query.vertex_type,
+                ordering,
+                &mut rows,
+                alloc.clone(),
+            )
+            .await?;
+
+            for item in rows {
+                let flow = self
+                    .process_tail_in(
+                        inputs,
+                        callstack,
+                        &mut runtime,
+                        query.vertex_type,
+                        read.tail,
+                        key,
+                        &mut output,
+                        item,
+                        alloc.clone(),
+                    )
+                    .await?;
+
+                if flow.is_break() {
+                    break;
+                }
             }
         }
 
-        let output = output.finish();
+        let output = output.finish_in(alloc);
         Ok(suspension.resolve(output))
     }
 }
@@ -2,48 +2,191 @@
 //!
 //! After each row is hydrated and passes any filter chains, the resulting
 //! [`Value`] must be collected into a final output. The [`Tail`] enum
-//! determines the accumulation strategy, currently only [`Collect`], which
-//! gathers all values into a [`List`].
+//! determines the accumulation strategy: [`Collect`] gathers all values into a
+//! [`List`], the remaining variants reduce the values into a single result.
+//!
+//! Tails which take a closure (`group_by` and the numeric aggregates) receive
+//! the result of the closure for every value as a key, via
+//! [`push_keyed`](Tail::push_keyed).
 //!
 //! [`Value`]: hashql_mir::interpret::value::Value
 //! [`Collect`]: Tail::Collect
 //! [`List`]: hashql_mir::interpret::value::List
 
-use core::alloc::Allocator;
+use alloc::rc::Rc;
+use core::{alloc::Allocator, ops::ControlFlow};
 
+use hashql_core::symbol::sym;
 use hashql_mir::{
-    body::terminator::GraphReadTail,
-    interpret::value::{self, Value},
+    body::terminator::{GraphAggregate, GraphReadTail},
+    interpret::value::{self, Int, Num, Opaque, Value},
 };
 
+/// The key passed to a numeric aggregate is not a number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct NotANumber;
+
 /// Accumulator for row results, determined by the [`GraphReadTail`] variant.
 ///
 /// Created once per graph read suspension, receives each post-filter value via
-/// [`push`](Self::push), and produces the final output via
-/// [`finish`](Self::finish).
+/// [`push`](Self::push) or [`push_keyed`](Self::push_keyed), and produces the
+/// final output via [`finish_in`](Self::finish_in).
 pub(crate) enum Tail<'heap, A: Allocator> {
     Collect(value::List<'heap, A>),
+    Count(usize),
+    Exists(bool),
+    First(Option<Value<'heap, A>>),
+    GroupBy(value::Dict<'heap, A>),
+    Sum(f64),
+    Min(Option<f64>),
+    Max(Option<f64>),
+    Avg { sum: f64, count: usize },
 }
 
 impl<'heap, A: Allocator> Tail<'heap, A> {
     pub(crate) fn new(tail: GraphReadTail) -> Self {
         match tail {
             GraphReadTail::Collect => Self::Collect(value::List::new()),
+            GraphReadTail::Count => Self::Count(0),
+            GraphReadTail::Exists => Self::Exists(false),
+            GraphReadTail::First => Self::First(None),
+            GraphReadTail::GroupBy(..) => Self::GroupBy(value::Dict::new()),
+            GraphReadTail::Aggregate(GraphAggregate::Sum, ..) => Self::Sum(0.0),
+            GraphReadTail::Aggregate(GraphAggregate::Min, ..) => Self::Min(None),
+            GraphReadTail::Aggregate(GraphAggregate::Max, ..) => Self::Max(None),
+            GraphReadTail::Aggregate(GraphAggregate::Avg, ..) => Self::Avg { sum: 0.0, count: 0 },
+        }
+    }
+
+    /// Creates a numeric aggregate from the `value` the database computed, which is `None` if
+    /// there were no values.
+    pub(crate) fn aggregated(aggregate: GraphAggregate, value: Option<f64>) -> Self {
+        match aggregate {
+            GraphAggregate::Sum => Self::Sum(value.unwrap_or(0.0)),
+            GraphAggregate::Min => Self::Min(value),
+            GraphAggregate::Max => Self::Max(value),
+            GraphAggregate::Avg => value.map_or(Self::Avg { sum: 0.0, count: 0 }, |avg| {
+                Self::Avg { sum: avg, count: 1 }
+            }),
         }
     }
 
-    pub(crate) fn push(&mut self, value: value::Value<'heap, A>)
+    /// Accumulates a value into a tail which does not take a closure.
+    ///
+    /// Returns [`ControlFlow::Break`] once the result can no longer change, any further values
+    /// may be skipped.
+    pub(crate) fn push(&mut self, value: Value<'heap, A>) -> ControlFlow<()>
     where
         A: Clone,
     {
         match self {
             Self::Collect(list) => list.push_back(value),
+            Self::Count(count) => *count += 1,
+            Self::Exists(exists) => {
+                *exists = true;
+                return ControlFlow::Break(());
+            }
+            Self::First(first) => {
+                *first = Some(value);
+                return ControlFlow::Break(());
+            }
+            Self::GroupBy(_) | Self::Sum(_) | Self::Min(_) | Self::Max(_) | Self::Avg { .. } => {
+                unreachable!("keyed tails are accumulated using `push_keyed`")
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Accumulates a value into a tail which takes a closure, `key` is the result of the closure
+    /// for the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotANumber`] if the tail is a numeric aggregate and `key` is not a number.
+    pub(crate) fn push_keyed(
+        &mut self,
+        key: &Value<'heap, A>,
+        value: Value<'heap, A>,
+    ) -> Result<(), NotANumber>
+    where
+        A: Clone,
+    {
+        let number = match self {
+            Self::GroupBy(groups) => {
+                let group = groups.get_mut(key);
+
+                if let Value::List(list) = group {
+                    list.push_back(value);
+                } else {
+                    let mut list = value::List::new();
+                    list.push_back(value);
+                    *group = Value::List(list);
+                }
+
+                return Ok(());
+            }
+            Self::Sum(_) | Self::Min(_) | Self::Max(_) | Self::Avg { .. } => match key {
+                Value::Integer(int) if !int.is_bool() => int.as_f64(),
+                Value::Number(num) => num.as_f64(),
+                Value::Unit
+                | Value::Integer(_)
+                | Value::String(_)
+                | Value::Pointer(_)
+                | Value::Opaque(_)
+                | Value::Struct(_)
+                | Value::Tuple(_)
+                | Value::List(_)
+                | Value::Dict(_) => return Err(NotANumber),
+            },
+            Self::Collect(_) | Self::Count(_) | Self::Exists(_) | Self::First(_) => {
+                unreachable!("unkeyed tails are accumulated using `push`")
+            }
+        };
+
+        #[expect(clippy::float_arithmetic)]
+        match self {
+            Self::Sum(sum) => *sum += number,
+            Self::Min(min) => *min = Some(min.map_or(number, |min| min.min(number))),
+            Self::Max(max) => *max = Some(max.map_or(number, |max| max.max(number))),
+            Self::Avg { sum, count } => {
+                *sum += number;
+                *count += 1;
+            }
+            Self::Collect(_)
+            | Self::Count(_)
+            | Self::Exists(_)
+            | Self::First(_)
+            | Self::GroupBy(_) => unreachable!(),
         }
+
+        Ok(())
     }
 
-    pub(crate) fn finish(self) -> Value<'heap, A> {
+    pub(crate) fn finish_in(self, alloc: A) -> Value<'heap, A> {
+        let option = |value: Option<Value<'heap, A>>| match value {
+            Some(value) => Value::Opaque(Opaque::new(sym::path::Some, Rc::new_in(value, alloc))),
+            None => Value::Opaque(Opaque::new(sym::path::None, Rc::new_in(Value::Unit, alloc))),
+        };
+
         match self {
             Self::Collect(list) => Value::List(list),
+            Self::Count(count) => Value::Integer(Int::from(count)),
+            Self::Exists(exists) => Value::Integer(Int::from(exists)),
+            Self::First(first) => option(first),
+            Self::GroupBy(groups) => Value::Dict(groups),
+            Self::Sum(sum) => Value::Number(Num::from(sum)),
+            Self::Min(number) | Self::Max(number) => {
+                option(number.map(|number| Value::Number(Num::from(number))))
+            }
+            #[expect(
+                clippy::cast_precision_loss,
+                clippy::float_arithmetic,
+                reason = "the number of rows is far below the precision of a float"
+            )]
+            Self::Avg { sum, count } => {
+                option((count > 0).then(|| Value::Number(Num::from(sum / count as f64))))
+            }
         }
     }
 }
@@ -0,0 +1,254 @@
+//! Compilation of graph traversals into semi-join conditions.
+//!
+//! A [`GraphTraversal`] connects the entities of two stages of a graph read. The entities of the
+//! earlier stage are compiled into a subquery selecting their `(web_id, entity_uuid)` keys, the
+//! traversal into a condition which holds for an entity of the later stage if it is reachable
+//! from any of these keys:
+//!
+//! ```sql
+//! ROW("base"."web_id", "base"."entity_uuid") = ANY(
+//!     SELECT "edge"."web_id", "edge"."entity_uuid"
+//!     FROM "entity_has_left_entity" AS "edge"
+//!     WHERE ROW("edge"."left_web_id", "edge"."left_entity_uuid") = ANY(<source>)
+//! )
+//! ```
+//!
+//! Link endpoints are not versioned, the entities reached by a traversal are restricted to the
+//! queried time axes by the stage they end up in.
+
+use core::alloc::Allocator;
+
+use hash_graph_postgres_store::store::postgres::query::{
+    Column, ColumnReference, Expression, FromItem, SelectExpression, SelectStatement, SimpleSelect,
+    Table, TableReference, table,
+};
+use hashql_mir::body::terminator::GraphTraversal;
+
+use super::DatabaseContext;
+
+/// One of the two tables storing the endpoints of links.
+#[derive(Debug, Copy, Clone)]
+enum Endpoint {
+    Left,
+    Right,
+}
+
+impl Endpoint {
+    const fn table(self) -> Table {
+        match self {
+            Self::Left => Table::EntityHasLeftEntity,
+            Self::Right => Table::EntityHasRightEntity,
+        }
+    }
+
+    /// The key of the link entity.
+    const fn link(self) -> [Column; 2] {
+        match self {
+            Self::Left => [
+                Column::EntityHasLeftEntity(table::EntityHasLeftEntity::WebId),
+                Column::EntityHasLeftEntity(table::EntityHasLeftEntity::EntityUuid),
+            ],
+            Self::Right => [
+                Column::EntityHasRightEntity(table::EntityHasRightEntity::WebId),
+                Column::EntityHasRightEntity(table::EntityHasRightEntity::EntityUuid),
+            ],
+        }
+    }
+
+    /// The key of the entity the link points to.
+    const fn entity(self) -> [Column; 2] {
+        match self {
+            Self::Left => [
+                Column::EntityHasLeftEntity(table::EntityHasLeftEntity::LeftEntityWebId),
+                Column::EntityHasLeftEntity(table::EntityHasLeftEntity::LeftEntityUuid),
+            ],
+            Self::Right => [
+                Column::EntityHasRightEntity(table::EntityHasRightEntity::RightEntityWebId),
+                Column::EntityHasRightEntity(table::EntityHasRightEntity::RightEntityUuid),
+            ],
+        }
+    }
+}
+
+const ENTITY_KEY: [Column; 2] = [
+    Column::EntityTemporalMetadata(table::EntityTemporalMetadata::WebId),
+    Column::EntityTemporalMetadata(table::EntityTemporalMetadata::EntityUuid),
+];
+
+fn columns(table: &TableReference<'static>, columns: [Column; 2]) -> [Expression; 2] {
+    columns.map(|column| {
+        Expression::ColumnReference(ColumnReference {
+            correlation: Some(table.clone()),
+            name: column.into(),
+        })
+    })
+}
+
+/// `ROW(<columns>)`, the key of an entity in the table `table`.
+fn key(table: &TableReference<'static>, key: [Column; 2]) -> Expression {
+    Expression::Row(columns(table, key).into())
+}
+
+/// `ROW(<web_id>, <entity_uuid>)` of an `entity_temporal_metadata` table.
+pub(crate) fn entity_key(table: &TableReference<'static>) -> Expression {
+    key(table, ENTITY_KEY)
+}
+
+/// `<key> = ANY(<set>)`.
+fn member_of(key: Expression, set: SelectStatement) -> Expression {
+    Expression::r#in(key, Expression::Select(Box::new(set)))
+}
+
+fn select_key(
+    table: &TableReference<'static>,
+    key: [Column; 2],
+    from: FromItem<'static>,
+    condition: Expression,
+) -> SelectStatement {
+    let selects = columns(table, key)
+        .into_iter()
+        .map(|expression| SelectExpression::Expression {
+            expression,
+            output_name: None,
+        })
+        .collect();
+
+    SimpleSelect::builder()
+        .selects(selects)
+        .from(from)
+        .where_clause(condition)
+        .build()
+        .into()
+}
+
+/// Selects the keys on the `to` side of the endpoint table, whose `from` side is in any of the
+/// `sources`.
+fn follow<A: Allocator>(
+    db: &mut DatabaseContext<'_, A>,
+    endpoint: Endpoint,
+    from: [Column; 2],
+    to: [Column; 2],
+    sources: Vec<SelectStatement>,
+) -> SelectStatement {
+    let alias = db.projections.fresh_alias();
+    let table = endpoint.table().aliased(alias);
+
+    let conditions = sources
+        .into_iter()
+        .map(|source| member_of(key(&table, from), source))
+        .collect();
+
+    select_key(
+        &table,
+        to,
+        FromItem::table(endpoint.table())
+            .alias(endpoint.table().aliased_name(alias))
+            .build(),
+        Expression::any(conditions),
+    )
+}
+
+/// Restricts the entities in `set` to the ones which exist on the queried time axes.
+fn versioned<A: Allocator>(
+    db: &mut DatabaseContext<'_, A>,
+    set: SelectStatement,
+) -> SelectStatement {
+    let alias = db.projections.fresh_alias();
+    let table = Table::EntityTemporalMetadata.aliased(alias);
+
+    let [transaction_time, decision_time] = db.temporal_conditions(table.clone());
+    let condition = Expression::all(vec![
+        transaction_time,
+        decision_time,
+        member_of(entity_key(&table), set),
+    ]);
+
+    select_key(
+        &table,
+        ENTITY_KEY,
+        FromItem::table(Table::EntityTemporalMetadata)
+            .alias(Table::EntityTemporalMetadata.aliased_name(alias))
+            .build(),
+        condition,
+    )
+}
+
+/// Selects the entities reachable from `sources` by following a single outgoing link.
+///
+/// The link itself has to exist on the queried time axes.
+fn hop<A: Allocator>(
+    db: &mut DatabaseContext<'_, A>,
+    sources: Vec<SelectStatement>,
+) -> SelectStatement {
+    let links = follow(
+        db,
+        Endpoint::Left,
+        Endpoint::Left.entity(),
+        Endpoint::Left.link(),
+        sources,
+    );
+    let links = versioned(db, links);
+
+    let reached = follow(
+        db,
+        Endpoint::Right,
+        Endpoint::Right.link(),
+        Endpoint::Right.entity(),
+        vec![links],
+    );
+
+    versioned(db, reached)
+}
+
+/// Compiles the condition under which the entity identified by `key` is reached by `traversal`
+/// from any entity selected by `source`.
+pub(crate) fn traversal_condition<A: Allocator>(
+    db: &mut DatabaseContext<'_, A>,
+    traversal: GraphTraversal,
+    key: Expression,
+    source: SelectStatement,
+) -> Expression {
+    let reached = match traversal {
+        GraphTraversal::OutgoingLinks => follow(
+            db,
+            Endpoint::Left,
+            Endpoint::Left.entity(),
+            Endpoint::Left.link(),
+            vec![source],
+        ),
+        GraphTraversal::IncomingLinks => follow(
+            db,
+            Endpoint::Right,
+            Endpoint::Right.entity(),
+            Endpoint::Right.link(),
+            vec![source],
+        ),
+        GraphTraversal::LeftEntities => follow(
+            db,
+            Endpoint::Left,
+            Endpoint::Left.link(),
+            Endpoint::Left.entity(),
+            vec![source],
+        ),
+        GraphTraversal::RightEntities => follow(
+            db,
+            Endpoint::Right,
+            Endpoint::Right.link(),
+            Endpoint::Right.entity(),
+            vec![source],
+        ),
+        GraphTraversal::Linked { depth } => {
+            // The entities reachable in at most `n` steps are the ones reachable in a single step
+            // from either the sources or the entities reachable in at most `n - 1` steps. This
+            // keeps the size of the query linear in the depth.
+            let mut reached = hop(db, vec![source.clone()]);
+            for _ in 1..depth {
+                reached = hop(db, vec![source.clone(), reached]);
+            }
+
+            reached
+        }
+    };
+
+    member_of(key, reached)
+}
@@ -13,6 +13,7 @@ use hashql_diagnostics::{
     diagnostic::Message,
     severity::Severity,
 };
+use hashql_hir::node::operation::IntrinsicOp;
 
 use crate::error::{EvalDiagnostic, EvalDiagnosticCategory};
 
@@ -46,6 +47,16 @@ const CLOSURE_AGGREGATE: TerminalDiagnosticCategory = TerminalDiagnosticCategory
     name: "Closure Construction Not Supported in SQL",
 };
 
+const UNSUPPORTED_INTRINSIC: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-intrinsic",
+    name: "Intrinsic Operation Not Supported in SQL",
+};
+
+const DISCRIMINANT_READ: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "discriminant-read",
+    name: "Discriminant Reads Not Supported in SQL",
+};
+
 const FUNCTION_POINTER_CONSTANT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
     id: "function-pointer-constant",
     name: "Function Pointers Not Supported in SQL",
@@ -71,6 +82,11 @@ const MISSING_ISLAND_GRAPH: TerminalDiagnosticCategory = TerminalDiagnosticCateg
     name: "Missing Island Graph for Body",
 };
 
+const TRAVERSED_FILTER_PLACEMENT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "traversed-filter-placement",
+    name: "Filter Before Traversal Not Supported Outside SQL",
+};
+
 /// Diagnostic categories for bugs and unsupported constructs encountered during SQL compilation.
 ///
 /// These categories cover internal compiler invariants (e.g. "placement should have rejected
@@ -92,6 +108,10 @@ pub enum PostgresDiagnosticCategory {
     ClosureApplication,
     /// A closure value construction reached the SQL backend.
     ClosureAggregate,
+    /// An intrinsic operation without an SQL equivalent reached the SQL backend.
+    UnsupportedIntrinsic,
+    /// A read of the discriminant of an opaque value reached the SQL backend.
+    DiscriminantRead,
     /// A function pointer constant reached the SQL backend.
     FunctionPointerConstant,
     /// MIR contained an assignment to a projected place (invalid in SSA form).
@@ -107,6 +127,8 @@ pub enum PostgresDiagnosticCategory {
     AmbiguousIntegerType,
     /// Island analysis did not produce an island graph for a filter body.
     MissingIslandGraph,
+    /// A filter applied before a graph traversal could not be placed entirely on Postgres.
+    TraversedFilterPlacement,
 }
 
 impl DiagnosticCategory for PostgresDiagnosticCategory {
@@ -126,11 +148,14 @@ impl DiagnosticCategory for PostgresDiagnosticCategory {
             Self::InvalidEnvProjection => Some(&INVALID_ENV_PROJECTION),
             Self::ClosureApplication => Some(&CLOSURE_APPLICATION),
             Self::ClosureAggregate => Some(&CLOSURE_AGGREGATE),
+            Self::UnsupportedIntrinsic => Some(&UNSUPPORTED_INTRINSIC),
+            Self::DiscriminantRead => Some(&DISCRIMINANT_READ),
             Self::FunctionPointerConstant => Some(&FUNCTION_POINTER_CONSTANT),
             Self::ProjectedAssignment => Some(&PROJECTED_ASSIGNMENT),
             Self::GraphReadTerminator => Some(&GRAPH_READ_TERMINATOR),
             Self::AmbiguousIntegerType => Some(&AMBIGUOUS_INTEGER_TYPE),
             Self::MissingIslandGraph => Some(&MISSING_ISLAND_GRAPH),
+            Self::TraversedFilterPlacement => Some(&TRAVERSED_FILTER_PLACEMENT),
         }
     }
 }
@@ -303,6 +328,43 @@ pub(super) fn graph_read_terminator(span: SpanId) -> EvalDiagnostic {
     diagnostic
 }
 
+#[coverage(off)]
+pub(super) fn unsupported_intrinsic(span: SpanId, op: IntrinsicOp) -> EvalDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        category(PostgresDiagnosticCategory::UnsupportedIntrinsic),
+        Severity::Bug,
+    )
+    .primary(Label::new(
+        span,
+        format!("`{}` cannot be compiled to SQL", op.as_symbol()),
+    ));
+
+    diagnostic.add_message(Message::note(
+        "the statement placement pass should have rejected this from the Postgres backend",
+    ));
+
+    diagnostic
+}
+
+#[coverage(off)]
+pub(super) fn discriminant_read(span: SpanId) -> EvalDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        category(PostgresDiagnosticCategory::DiscriminantRead),
+        Severity::Bug,
+    )
+    .primary(Label::new(
+        span,
+        "the discriminant of this value cannot be compiled to SQL",
+    ));
+
+    diagnostic.add_message(Message::note(
+        "opaque types are erased in the database representation; the statement placement pass \
+         should have rejected this from the Postgres backend",
+    ));
+
+    diagnostic
+}
+
 #[coverage(off)]
 pub(super) fn ambiguous_integer_type(span: SpanId, operator: &str) -> EvalDiagnostic {
     let mut diagnostic = Diagnostic::new(
@@ -341,3 +403,26 @@ pub(super) fn missing_island_graph(span: SpanId) -> EvalDiagnostic {
 
     diagnostic
 }
+
+pub(super) fn traversed_filter_placement(span: SpanId) -> EvalDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        category(PostgresDiagnosticCategory::TraversedFilterPlacement),
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        "this filter is applied before a graph traversal, but cannot be evaluated in SQL",
+    ));
+
+    diagnostic.add_message(Message::help(
+        "move the filter after the traversal, or restrict it to operations which can be evaluated \
+         by the database",
+    ));
+
+    diagnostic.add_message(Message::note(
+        "the entities a traversal starts from are selected inside the database, filters applied \
+         to them cannot fall back to the interpreter",
+    ));
+
+    diagnostic
+}
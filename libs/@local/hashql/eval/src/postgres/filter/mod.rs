@@ -36,7 +36,7 @@ use hashql_core::{
     span::SpanId,
 };
 use hashql_diagnostics::DiagnosticIssues;
-use hashql_hir::node::operation::InputOp;
+use hashql_hir::node::operation::{InputOp, IntrinsicOp, StringOp};
 use hashql_mir::{
     body::{
         Body,
@@ -45,21 +45,28 @@ use hashql_mir::{
         local::{Local, LocalSnapshotVec},
         operand::Operand,
         place::{FieldIndex, Place, Projection, ProjectionKind},
-        rvalue::{Aggregate, AggregateKind, Apply, BinOp, Binary, Input, RValue, UnOp, Unary},
+        rvalue::{
+            Aggregate, AggregateKind, Apply, ArgIndex, BinOp, Binary, Discriminant, Input,
+            Intrinsic, RValue, UnOp, Unary,
+        },
         statement::{Assign, Statement, StatementKind},
         terminator::{Goto, Return, SwitchInt, SwitchTargets, Target, TerminatorKind},
     },
-    pass::execution::{IslandNode, TargetId, VertexType, traversal::EntityPath},
+    pass::execution::{
+        IslandNode, TargetId, VertexType,
+        traversal::{EntityPath, OntologyPath},
+    },
 };
 
 use super::{
     DatabaseContext,
     error::{
-        ambiguous_integer_type, closure_aggregate, closure_application, entity_path_resolution,
-        function_pointer_constant, graph_read_terminator, invalid_env_access,
-        invalid_env_projection, projected_assignment, unsupported_vertex_type,
+        ambiguous_integer_type, closure_aggregate, closure_application, discriminant_read,
+        entity_path_resolution, function_pointer_constant, graph_read_terminator,
+        invalid_env_access, invalid_env_projection, projected_assignment, unsupported_intrinsic,
+        unsupported_vertex_type,
     },
-    traverse::eval_entity_path,
+    traverse::{eval_entity_path, eval_ontology_path},
     types::{IntegerType, integer_type},
 };
 use crate::{context::CodeGenerationContext, error::EvalDiagnosticIssues};
@@ -291,6 +298,16 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
 
                 let base = eval_entity_path(db, path);
 
+                (base, &place.projections[consumed..])
+            }
+            VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                let Some((path, consumed)) = OntologyPath::resolve(&place.projections) else {
+                    self.diagnostics.push(entity_path_resolution(span));
+                    return (Expression::Constant(query::Constant::Null), &[]);
+                };
+
+                let base = eval_ontology_path(db, path);
+
                 (base, &place.projections[consumed..])
             }
         }
@@ -361,6 +378,8 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
                         .lookup(local)
                         .unwrap_or_else(|| unreachable!("use before def"))
                         .clone(),
+                    // Opaque types are erased in the database representation
+                    ProjectionKind::Downcast(_) => continue,
                 };
 
                 // `json_extract_path` takes text arguments, so all indices (including
@@ -481,8 +500,9 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
         let operands = Operands { left, right };
 
         // Operands coming from jsonb extraction are untyped from Postgres' perspective.
-        // Arithmetic and bitwise operators need explicit casts; comparisons work on jsonb
-        // directly.
+        // Arithmetic and bitwise operators need explicit casts; comparisons normalize both
+        // operands to jsonb, as intrinsics and nested operators yield native text, integer or
+        // boolean values, which Postgres refuses to compare against jsonb.
         match *op {
             BinOp::Add => operands
                 .cast(PostgresType::Numeric)
@@ -522,10 +542,18 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
             BinOp::Ne => operands
                 .call(query::Function::ToJson)
                 .binary(BinaryOperator::NotEqual),
-            BinOp::Lt => operands.binary(BinaryOperator::Less),
-            BinOp::Lte => operands.binary(BinaryOperator::LessOrEqual),
-            BinOp::Gt => operands.binary(BinaryOperator::Greater),
-            BinOp::Gte => operands.binary(BinaryOperator::GreaterOrEqual),
+            BinOp::Lt => operands
+                .call(query::Function::ToJson)
+                .binary(BinaryOperator::Less),
+            BinOp::Lte => operands
+                .call(query::Function::ToJson)
+                .binary(BinaryOperator::LessOrEqual),
+            BinOp::Gt => operands
+                .call(query::Function::ToJson)
+                .binary(BinaryOperator::Greater),
+            BinOp::Gte => operands
+                .call(query::Function::ToJson)
+                .binary(BinaryOperator::GreaterOrEqual),
         }
     }
 
@@ -617,11 +645,73 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
         }
     }
 
+    fn compile_intrinsic(
+        &mut self,
+        db: &mut DatabaseContext<'heap, A>,
+        span: SpanId,
+        Intrinsic { op, arguments }: &Intrinsic<'heap>,
+    ) -> Expression {
+        // String operands are either `text` parameters or `jsonb` values, `to_jsonb` normalizes
+        // both to `jsonb`, from which the string is then extracted as `text`.
+        let mut text = |index: u32| {
+            let operand = self.compile_operand(db, span, &arguments[ArgIndex::new(index)]);
+
+            Box::new(Expression::Function(Function::JsonExtractText(Box::new(
+                Expression::Function(Function::ToJson(Box::new(operand))),
+            ))))
+        };
+
+        match *op {
+            IntrinsicOp::String(StringOp::Lowercase) => {
+                Expression::Function(Function::Lower(text(0)))
+            }
+            IntrinsicOp::String(StringOp::Uppercase) => {
+                Expression::Function(Function::Upper(text(0)))
+            }
+            IntrinsicOp::String(StringOp::Contains) => Expression::Binary(BinaryExpression {
+                op: BinaryOperator::Greater,
+                left: Box::new(Expression::Function(Function::StrPos(text(0), text(1)))),
+                right: Box::new(Expression::Constant(query::Constant::U32(0))),
+            }),
+            IntrinsicOp::String(StringOp::StartsWith) => {
+                Expression::Function(Function::StartsWith(text(0), text(1)))
+            }
+            IntrinsicOp::String(StringOp::EndsWith) => {
+                // Postgres has no `ends_with`, a suffix is a prefix of the reversed string
+                let value = Expression::Function(Function::Reverse(text(0)));
+                let suffix = Expression::Function(Function::Reverse(text(1)));
+
+                Expression::Function(Function::StartsWith(Box::new(value), Box::new(suffix)))
+            }
+            IntrinsicOp::String(StringOp::Length) => {
+                Expression::Function(Function::Length(text(0)))
+            }
+            IntrinsicOp::String(StringOp::Concat) => {
+                Expression::concatenate(vec![*text(0), *text(1)])
+            }
+            IntrinsicOp::String(StringOp::Split) => {
+                let parts = Expression::Function(Function::StringToArray(text(0), text(1)));
+
+                Expression::Function(Function::ToJson(Box::new(parts)))
+            }
+            IntrinsicOp::String(StringOp::Trim) => Expression::Function(Function::Trim(text(0))),
+            IntrinsicOp::List(_) | IntrinsicOp::Dict(_) => {
+                self.diagnostics.push(unsupported_intrinsic(span, *op));
+                Expression::Constant(query::Constant::Null)
+            }
+        }
+    }
+
     fn compile_apply(&mut self, span: SpanId, _: &Apply<'heap>) -> Expression {
         self.diagnostics.push(closure_application(span));
         Expression::Constant(query::Constant::Null)
     }
 
+    fn compile_discriminant(&mut self, span: SpanId, _: &Discriminant<'heap>) -> Expression {
+        self.diagnostics.push(discriminant_read(span));
+        Expression::Constant(query::Constant::Null)
+    }
+
     fn compile_rvalue(
         &mut self,
         db: &mut DatabaseContext<'heap, A>,
@@ -634,6 +724,8 @@ impl<'ctx, 'heap, A: Allocator, S: Allocator> GraphReadFilterCompiler<'ctx, 'hea
             RValue::Unary(unary) => self.compile_unary(db, span, unary),
             RValue::Aggregate(aggregate) => self.compile_aggregate(db, span, aggregate),
             RValue::Input(input) => Self::compile_input(db, input),
+            RValue::Intrinsic(intrinsic) => self.compile_intrinsic(db, span, intrinsic),
+            RValue::Discriminant(discriminant) => self.compile_discriminant(span, discriminant),
             RValue::Apply(apply) => self.compile_apply(span, apply),
         }
     }
@@ -23,15 +23,45 @@
 //! Table joins are *lazy*: the compiler only requests joins when an [`EntityPath`] is actually
 //! referenced by filters or required outputs (the "provides" set).
 //!
+//! ## Traversals
+//!
+//! A graph read is split into stages at every traversal. The stages before the last traversal
+//! are compiled into nested subqueries selecting the keys of their entities, which are connected
+//! to the next stage by semi-joins over the link endpoint tables. Only the last stage, whose
+//! entities are returned, is compiled into the top-level query and may exit to the interpreter.
+//!
+//! ## Ontology
+//!
+//! Graph reads over data types, property types and entity types are compiled like reads over
+//! entities, but are based on `ontology_temporal_metadata` and are only constrained along the
+//! transaction axis. They never contain traversals.
+//!
+//! ## Tails
+//!
+//! Ordering and pagination (`sort_by`, `limit` and `offset`) are compiled into `ORDER BY`,
+//! `LIMIT` and `OFFSET` if the database evaluates every filter of the last stage in full, and, for
+//! `sort_by`, if the key is a scalar column of the entity. The longest prefix of these operations
+//! which can be compiled is recorded in the [`QueryOutput`], the interpreter applies the rest.
+//!
+//! Under the same condition a `count` is compiled into a `count(*)` over the query, and `exists`
+//! and `first` limit the query to a single row. A numeric aggregate whose key is a scalar column
+//! is compiled into the corresponding aggregate function. All other tails are accumulated by the
+//! interpreter. The key of a `group_by` is read from its column if it is a scalar column.
+//!
+//! Key closures evaluated by the interpreter are evaluated in full, the query selects every field
+//! of the vertex they read.
+//!
 //! [`GraphRead`]: hashql_mir::body::terminator::GraphRead
 //! [`TargetId::Postgres`]: hashql_mir::pass::execution::TargetId::Postgres
 //! [`EntityPath`]: hashql_mir::pass::execution::traversal::EntityPath
 
-use core::{alloc::Allocator, fmt::Display};
+use core::{alloc::Allocator, fmt::Display, mem};
 
 use hash_graph_postgres_store::store::postgres::query::{
-    self, Column, Expression, Identifier, SelectExpression, SelectStatement, SimpleSelect,
-    Transpile as _, table::EntityTemporalMetadata,
+    self, Column, ColumnName, Expression, Identifier, NonEmptyVec, OrderByClause, PostgresType,
+    SelectExpression, SelectStatement, SimpleSelect, SortBy, TableName, TableReference,
+    Transpile as _,
+    table::{EntityTemporalMetadata, OntologyTemporalMetadata},
 };
 use hashql_core::{
     debug_panic,
@@ -44,14 +74,21 @@ use hashql_mir::{
         Body,
         basic_block::BasicBlockId,
         local::Local,
-        terminator::{GraphRead, GraphReadBody, GraphReadHead, TerminatorKind},
+        operand::Operand,
+        terminator::{
+            GraphAggregate, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, GraphTraversal,
+            TerminatorKind,
+        },
     },
     def::{DefId, DefIdSlice},
     pass::{
         analysis::dataflow::lattice::HasBottom as _,
         execution::{
             IslandId, IslandKind, IslandNode, TargetId, VertexType,
-            traversal::{EntityPath, TraversalMapLattice, TraversalPath, TraversalPathBitMap},
+            traversal::{
+                EntityPath, TraversalMapLattice, TraversalPath, TraversalPathBitMap,
+                TraversalPathBitSet,
+            },
         },
     },
 };
@@ -67,6 +104,7 @@ pub use self::{
 use crate::context::CodeGenerationContext;
 
 mod continuation;
+mod edges;
 pub(crate) mod error;
 mod filter;
 mod parameters;
@@ -90,13 +128,22 @@ pub(crate) struct DatabaseContext<'heap, A: Allocator> {
 }
 
 impl<A: Allocator> DatabaseContext<'_, A> {
+    #[cfg(test)]
     pub(crate) fn new_in(alloc: A) -> Self
+    where
+        A: Clone,
+    {
+        Self::vertex_in(VertexType::Entity, alloc)
+    }
+
+    /// Creates the context of a query over vertices of the given type.
+    pub(crate) fn vertex_in(vertex: VertexType, alloc: A) -> Self
     where
         A: Clone,
     {
         Self {
             parameters: Parameters::new_in(alloc.clone()),
-            projections: Projections::new(),
+            projections: Projections::for_vertex(vertex),
             conditions: Vec::new(),
             laterals: Vec::new_in(alloc.clone()),
             continuation_aliases: Vec::new_in(alloc),
@@ -116,34 +163,72 @@ impl<A: Allocator> DatabaseContext<'_, A> {
     ///
     /// This avoids a `CASE`-based approach which would hide the operators from the planner and
     /// prevent index scans on generic plans.
+    ///
+    /// The types of the ontology are only versioned along the transaction time, so only the
+    /// transaction axis constrains them.
     fn add_temporal_conditions(&mut self) {
         let temporal_metadata = self.projections.temporal_metadata();
 
+        if self.projections.vertex().is_ontology() {
+            let condition = self.ontology_temporal_condition(temporal_metadata);
+            self.conditions.push(condition);
+
+            return;
+        }
+
+        let conditions = self.temporal_conditions(temporal_metadata);
+
+        self.conditions.extend(conditions);
+    }
+
+    /// Returns the temporal overlap constraint of the `ontology_temporal_metadata` table `table`.
+    fn ontology_temporal_condition(&mut self, table: query::TableReference<'static>) -> Expression {
         let tx_param = self
             .parameters
             .temporal_axis(TemporalAxis::Transaction)
             .to_expr();
-        let dt_param = self
-            .parameters
-            .temporal_axis(TemporalAxis::Decision)
-            .to_expr();
 
-        self.conditions.push(Expression::overlap(
+        Expression::overlap(
             Expression::ColumnReference(query::ColumnReference {
-                correlation: Some(temporal_metadata.clone()),
-                name: Column::EntityTemporalMetadata(EntityTemporalMetadata::TransactionTime)
+                correlation: Some(table),
+                name: Column::OntologyTemporalMetadata(OntologyTemporalMetadata::TransactionTime)
                     .into(),
             }),
             tx_param,
-        ));
+        )
+    }
 
-        self.conditions.push(Expression::overlap(
-            Expression::ColumnReference(query::ColumnReference {
-                correlation: Some(temporal_metadata),
-                name: Column::EntityTemporalMetadata(EntityTemporalMetadata::DecisionTime).into(),
-            }),
-            dt_param,
-        ));
+    /// Returns the temporal overlap constraints of the `entity_temporal_metadata` table `table`.
+    ///
+    /// See [`Self::add_temporal_conditions`] for how the constraints are expressed.
+    fn temporal_conditions(&mut self, table: query::TableReference<'static>) -> [Expression; 2] {
+        let tx_param = self
+            .parameters
+            .temporal_axis(TemporalAxis::Transaction)
+            .to_expr();
+        let dt_param = self
+            .parameters
+            .temporal_axis(TemporalAxis::Decision)
+            .to_expr();
+
+        [
+            Expression::overlap(
+                Expression::ColumnReference(query::ColumnReference {
+                    correlation: Some(table.clone()),
+                    name: Column::EntityTemporalMetadata(EntityTemporalMetadata::TransactionTime)
+                        .into(),
+                }),
+                tx_param,
+            ),
+            Expression::overlap(
+                Expression::ColumnReference(query::ColumnReference {
+                    correlation: Some(table),
+                    name: Column::EntityTemporalMetadata(EntityTemporalMetadata::DecisionTime)
+                        .into(),
+                }),
+                dt_param,
+            ),
+        ]
     }
 }
 
@@ -155,7 +240,7 @@ impl<A: Allocator> DatabaseContext<'_, A> {
 /// control flow back to the interpreter.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ColumnDescriptor {
-    /// An entity field produced by the provides set.
+    /// A vertex field produced by the provides set.
     ///
     /// The [`TraversalPath`] identifies the storage location; the [`TypeId`] is the
     /// field's type within the instantiated vertex type, used for type-directed
@@ -172,7 +257,7 @@ pub enum ColumnDescriptor {
 impl Display for ColumnDescriptor {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Path { path, .. } => write!(fmt, "entity path `{}`", path.as_symbol()),
+            Self::Path { path, .. } => write!(fmt, "vertex path `{}`", path.as_symbol()),
             Self::Continuation {
                 body,
                 island,
@@ -188,16 +273,38 @@ impl Display for ColumnDescriptor {
     }
 }
 
+/// Describes the rows returned by a compiled query.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QueryOutput {
+    /// One row per vertex, decoded using the column manifest.
+    ///
+    /// `ordering` is the number of leading ordering and pagination operations of the graph read
+    /// which have been compiled into the query, the bridge applies the remaining ones.
+    ///
+    /// `key` is the column the key of a `group_by` reads, if any. It is selected as part of the
+    /// vertex, the bridge reads the key from the vertex instead of evaluating the closure.
+    Rows {
+        ordering: usize,
+        key: Option<EntityPath>,
+    },
+    /// A single `bigint` column holding the number of vertices.
+    Count,
+    /// A single `double precision` column holding the aggregate of the key over all vertices,
+    /// which is `NULL` if there are none.
+    Aggregate(GraphAggregate),
+}
+
 /// A fully-compiled SQL query ready for execution.
 ///
 /// Contains the typed query AST ([`SelectStatement`]), the parameter catalog ([`Parameters`])
-/// for binding runtime values, and a column manifest ([`ColumnDescriptor`]s) that tells the
-/// bridge how to decode each result column.
+/// for binding runtime values, a column manifest ([`ColumnDescriptor`]s) that tells the
+/// bridge how to decode each result column, and the shape of the result ([`QueryOutput`]).
 pub struct PreparedQuery<'heap, A: Allocator> {
     pub vertex_type: VertexType,
     pub parameters: Parameters<'heap, A>,
     pub statement: SelectStatement,
     pub columns: Vec<ColumnDescriptor, A>,
+    pub output: QueryOutput,
 }
 
 impl<A: Allocator> PreparedQuery<'_, A> {
@@ -230,6 +337,21 @@ impl<'heap, A: Allocator> PreparedQueries<'heap, A> {
             .find(|(id, _)| *id == block)
             .map(|(_, query)| query)
     }
+
+    /// Iterates over all compiled queries, together with the body and block of the
+    /// [`GraphRead`] they have been compiled from.
+    ///
+    /// [`GraphRead`]: hashql_mir::body::terminator::GraphRead
+    pub fn iter(&self) -> impl Iterator<Item = (DefId, BasicBlockId, &PreparedQuery<'heap, A>)> {
+        self.offsets
+            .ids()
+            .take(self.offsets.len() - 1)
+            .flat_map(move |body| {
+                self.queries[self.offsets[body]..self.offsets[body.plus(1)]]
+                    .iter()
+                    .map(move |(block, query)| (body, *block, query))
+            })
+    }
 }
 
 /// Compiles Postgres-targeted MIR islands into a single PostgreSQL `SELECT`.
@@ -287,26 +409,38 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
     /// Each filter body may operate on a different `Entity<T>`. This computes the
     /// least upper bound of all the `T` parameters, producing the unified property type
     /// for the query's SELECT list. Returns `unknown` if there are no filter bodies.
+    ///
+    /// Only the filter bodies after the last traversal are considered, the ones before it operate
+    /// on the entities the traversal starts from.
     fn resolve_property_type(&self, read: &GraphRead<'heap>) -> TypeId {
         let mut lattice = LatticeEnvironment::new(self.context.env).without_warnings();
 
         read.body
             .iter()
-            .map(|body| match body {
+            .rev()
+            .take_while(|body| !matches!(body, GraphReadBody::Traverse(_)))
+            .filter_map(|body| match body {
                 &GraphReadBody::Filter(def_id, _) => {
                     let vertex = self.context.bodies[def_id].local_decls[Local::VERTEX].r#type;
 
                     let path = EntityPath::Properties.field_path();
 
-                    traverse_struct(self.context.env, vertex, path).unwrap_or_else(|| {
-                        debug_panic!(
-                            "failed to extract property type from vertex type {vertex:?}; the \
-                             vertex type should contain a resolvable properties field"
-                        );
+                    let r#type =
+                        traverse_struct(self.context.env, vertex, path).unwrap_or_else(|| {
+                            debug_panic!(
+                                "failed to extract property type from vertex type {vertex:?}; the \
+                                 vertex type should contain a resolvable properties field"
+                            );
+
+                            TypeBuilder::synthetic(self.context.env).unknown()
+                        });
 
-                        TypeBuilder::synthetic(self.context.env).unknown()
-                    })
+                    Some(r#type)
                 }
+                GraphReadBody::SortBy(..) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {
+                    None
+                }
+                GraphReadBody::Traverse(_) => unreachable!(),
             })
             .reduce(|lhs, rhs| lattice.join(lhs, rhs))
             .unwrap_or_else(|| TypeBuilder::synthetic(self.context.env).unknown())
@@ -361,6 +495,14 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
             return;
         };
 
+        // The embedding backend identifies entities through their id, which must therefore be
+        // selected whenever an island requires the vectors of the entity.
+        if residual.islands.find(TargetId::Embedding).next().is_some() {
+            let mut entity_id = TraversalPathBitSet::empty(VertexType::Entity);
+            entity_id.insert(TraversalPath::Entity(EntityPath::EntityId));
+            provides.insert(entity_id);
+        }
+
         let islands = residual.islands.find(TargetId::Postgres);
 
         for (island_id, island) in islands {
@@ -396,11 +538,224 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
         }
     }
 
-    fn compile_graph_read_entity(&mut self, read: &GraphRead<'heap>) -> PreparedQuery<'heap, A>
+    /// Compiles the stages of a graph read which end in a traversal.
+    ///
+    /// Every stage selects the keys of the entities the traversal at its end starts from. Returns
+    /// the last traversal together with its stage, or `None` if there are no stages.
+    fn compile_graph_read_stages(
+        &mut self,
+        db: &mut DatabaseContext<'heap, A>,
+        stages: &[GraphReadBody],
+    ) -> Option<(GraphTraversal, SelectStatement)>
     where
         A: Clone,
     {
-        let mut db = DatabaseContext::new_in(self.alloc.clone());
+        let mut previous = None;
+        let mut start = 0;
+
+        for (index, body) in stages.iter().enumerate() {
+            let &GraphReadBody::Traverse(traversal) = body else {
+                continue;
+            };
+
+            let stage = self.compile_graph_read_stage(
+                db,
+                &stages[start..index],
+                previous.take(),
+                index + 1,
+            );
+
+            previous = Some((traversal, stage));
+            start = index + 1;
+        }
+
+        previous
+    }
+
+    /// Compiles the filters of a single stage into a query selecting the keys of its entities.
+    ///
+    /// The stage runs as a nested query, so there is no interpreter to fall back to: every
+    /// filter has to be evaluated on Postgres.
+    fn compile_graph_read_stage(
+        &mut self,
+        db: &mut DatabaseContext<'heap, A>,
+        filters: &[GraphReadBody],
+        source: Option<(GraphTraversal, SelectStatement)>,
+        chain_depth: usize,
+    ) -> SelectStatement
+    where
+        A: Clone,
+    {
+        let mut stage = DatabaseContext {
+            parameters: mem::replace(&mut db.parameters, Parameters::new_in(self.alloc.clone())),
+            projections: Projections::nested(chain_depth),
+            conditions: Vec::new(),
+            laterals: Vec::new_in(self.alloc.clone()),
+            continuation_aliases: Vec::new_in(self.alloc.clone()),
+        };
+
+        stage.add_temporal_conditions();
+
+        if let Some((traversal, source)) = source {
+            let key = edges::entity_key(&stage.projections.temporal_metadata());
+            let condition = edges::traversal_condition(&mut stage, traversal, key, source);
+
+            stage.conditions.push(condition);
+        }
+
+        // The stage only selects keys, so whatever its filters provide is discarded.
+        let mut provides = TraversalMapLattice.bottom();
+
+        for body in filters {
+            match body {
+                &GraphReadBody::Filter(def_id, env) => {
+                    let filter = &self.context.bodies[def_id];
+
+                    if let Some(residual) = self.context.execution.lookup(filter.id)
+                        && filter.basic_blocks.ids().any(|block| {
+                            residual.islands.lookup(block).1.target() != TargetId::Postgres
+                        })
+                    {
+                        self.context
+                            .diagnostics
+                            .push(error::traversed_filter_placement(filter.span));
+                        continue;
+                    }
+
+                    self.compile_graph_read_filter(&mut stage, def_id, env, &mut provides);
+                }
+                GraphReadBody::Traverse(_) => unreachable!("stages are split at traversals"),
+                GraphReadBody::SortBy(..) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {
+                    unreachable!("ordering and pagination come after all traversals")
+                }
+            }
+        }
+
+        let temporal_metadata = stage.projections.temporal_metadata();
+        let from = stage
+            .projections
+            .build_from(&mut stage.parameters, stage.laterals);
+
+        db.parameters = stage.parameters;
+
+        let selects = [
+            EntityTemporalMetadata::WebId,
+            EntityTemporalMetadata::EntityUuid,
+        ]
+        .map(|column| SelectExpression::Expression {
+            expression: Expression::ColumnReference(query::ColumnReference {
+                correlation: Some(temporal_metadata.clone()),
+                name: Column::EntityTemporalMetadata(column).into(),
+            }),
+            output_name: None,
+        });
+
+        SimpleSelect::builder()
+            .selects(selects.into())
+            .from(from)
+            .maybe_where_clause(Expression::conjunction(stage.conditions))
+            .build()
+            .into()
+    }
+
+    /// Returns whether the filter body is evaluated by the database in full.
+    fn is_exact_filter(&self, def: DefId) -> bool {
+        let body = &self.context.bodies[def];
+
+        self.context
+            .execution
+            .lookup(body.id)
+            .is_some_and(|residual| {
+                body.basic_blocks
+                    .ids()
+                    .all(|block| residual.islands.lookup(block).1.target() == TargetId::Postgres)
+            })
+    }
+
+    /// Resolves the key of a `sort_by`, `group_by` or aggregate to the scalar column it reads, if
+    /// any.
+    ///
+    /// Only keys which directly return a field of the entity are considered, anything else is
+    /// evaluated by the interpreter. The keys of the types of the ontology are always evaluated by
+    /// the interpreter.
+    fn resolve_key(&self, vertex: VertexType, def: DefId) -> Option<EntityPath> {
+        if vertex != VertexType::Entity {
+            return None;
+        }
+
+        let body = &self.context.bodies[def];
+
+        let [block] = body.basic_blocks.as_raw() else {
+            return None;
+        };
+
+        if !block.statements.is_empty() {
+            return None;
+        }
+
+        let TerminatorKind::Return(r#return) = &block.terminator.kind else {
+            return None;
+        };
+
+        let Operand::Place(place) = &r#return.value else {
+            return None;
+        };
+
+        if place.local != Local::VERTEX {
+            return None;
+        }
+
+        let (path, consumed) = EntityPath::resolve(&place.projections)?;
+        if consumed != place.projections.len() {
+            return None;
+        }
+
+        // Composite columns have no meaningful ordering in the database, which would match the
+        // ordering of their values in the interpreter.
+        matches!(
+            path,
+            EntityPath::WebId
+                | EntityPath::EntityUuid
+                | EntityPath::EditionId
+                | EntityPath::Archived
+                | EntityPath::ReadOnly
+                | EntityPath::CreatedById
+                | EntityPath::CreatedAtTransactionTime
+                | EntityPath::CreatedAtDecisionTime
+                | EntityPath::EditionCreatedById
+        )
+        .then_some(path)
+    }
+
+    /// Selects the paths of the vertex which the key closure `def` reads, so that the interpreter
+    /// can evaluate it for every row.
+    fn provide_key(&self, vertex: VertexType, def: DefId, provides: &mut TraversalPathBitMap) {
+        let requires = TraversalPathBitSet::from_body(vertex, &self.context.bodies[def]);
+        let mut selects = TraversalPathBitSet::empty(vertex);
+
+        for path in requires.iter() {
+            if path.origin().contains(TargetId::Postgres) {
+                selects.insert(path);
+            } else {
+                // Only the vectors aren't stored in the database, the bridge fetches them from the
+                // embedding backend, which identifies entities through their id.
+                selects.insert(TraversalPath::Entity(EntityPath::EntityId));
+            }
+        }
+
+        provides.insert(selects);
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn compile_graph_read_vertex(
+        &mut self,
+        read: &GraphRead<'heap>,
+        vertex: VertexType,
+    ) -> PreparedQuery<'heap, A>
+    where
+        A: Clone,
+    {
+        let mut db = DatabaseContext::vertex_in(vertex, self.alloc.clone());
 
         let mut property_type = None;
 
@@ -408,29 +763,180 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
         // and don't depend on anything the filter body produces.
         db.add_temporal_conditions();
 
+        // Everything up to the last traversal selects the entities the traversal starts from,
+        // only the operations after it apply to the entities returned by the query.
+        #[expect(clippy::option_if_let_else, reason = "readability")]
+        let (stages, output) = match read
+            .body
+            .iter()
+            .rposition(|body| matches!(body, GraphReadBody::Traverse(_)))
+        {
+            Some(index) => read.body.split_at(index + 1),
+            None => (&[] as &[_], &*read.body),
+        };
+
+        if let Some((traversal, source)) = self.compile_graph_read_stages(&mut db, stages) {
+            let key = edges::entity_key(&db.projections.temporal_metadata());
+            let condition = edges::traversal_condition(&mut db, traversal, key, source);
+
+            db.conditions.push(condition);
+        }
+
+        // Ordering and pagination come after all filters.
+        let (filters, ordering) = output.split_at(
+            output
+                .iter()
+                .position(|body| {
+                    matches!(
+                        body,
+                        GraphReadBody::SortBy(..)
+                            | GraphReadBody::Limit(_)
+                            | GraphReadBody::Offset(_)
+                    )
+                })
+                .unwrap_or(output.len()),
+        );
+
         let mut provides = TraversalMapLattice.bottom();
 
-        for body in &read.body {
+        for body in filters {
             match body {
                 &GraphReadBody::Filter(def_id, env) => {
                     self.compile_graph_read_filter(&mut db, def_id, env, &mut provides);
                 }
+                GraphReadBody::Traverse(_) => unreachable!("traversals are compiled as stages"),
+                GraphReadBody::SortBy(..) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {
+                    unreachable!("ordering and pagination come after all filters")
+                }
+            }
+        }
+
+        // Ordering and pagination can only be compiled if the query returns exactly the rows
+        // which are accepted, otherwise the interpreter would apply them to rows it rejects.
+        let exact = filters.iter().all(|body| match *body {
+            GraphReadBody::Filter(def_id, _) => self.is_exact_filter(def_id),
+            GraphReadBody::Traverse(_)
+            | GraphReadBody::SortBy(..)
+            | GraphReadBody::Limit(_)
+            | GraphReadBody::Offset(_) => unreachable!(),
+        });
+
+        // The order of the rows doesn't change how many there are.
+        let counted = exact && matches!(read.tail, GraphReadTail::Count);
+
+        let mut sort_by = Vec::new();
+        let mut limit = None;
+        let mut offset = None;
+        let mut compiled = 0;
+
+        for body in ordering {
+            if !exact {
+                break;
+            }
+
+            match *body {
+                GraphReadBody::SortBy(..) if counted => {}
+                GraphReadBody::SortBy(def_id, _) => {
+                    // Sorting after pagination would only sort the remaining rows.
+                    if limit.is_some() || offset.is_some() {
+                        break;
+                    }
+
+                    let Some(path) = self.resolve_key(vertex, def_id) else {
+                        break;
+                    };
+
+                    // The sort is stable, so the last sort takes precedence, and the previous ones
+                    // order the rows with equal keys.
+                    sort_by.insert(
+                        0,
+                        SortBy {
+                            expression: traverse::eval_entity_path(&mut db, path),
+                            direction: None,
+                            nulls: None,
+                        },
+                    );
+                }
+                GraphReadBody::Limit(count) => {
+                    limit = Some(limit.map_or(count, |limit: usize| limit.min(count)));
+                }
+                GraphReadBody::Offset(count) => {
+                    offset = Some(offset.unwrap_or(0) + count);
+                    limit = limit.map(|limit: usize| limit.saturating_sub(count));
+                }
+                GraphReadBody::Filter(..) | GraphReadBody::Traverse(_) => {
+                    unreachable!("ordering and pagination come after all filters and traversals")
+                }
+            }
+
+            compiled += 1;
+        }
+
+        // Whether the query returns the accepted rows in their final order.
+        let ordered = exact && compiled == ordering.len();
+
+        // Once all rows are in their final order, only the first is required to determine the
+        // result.
+        if ordered && matches!(read.tail, GraphReadTail::Exists | GraphReadTail::First) {
+            limit = Some(limit.map_or(1, |limit: usize| limit.min(1)));
+        }
+
+        // The interpreter evaluates the keys of the remaining sorts for every row.
+        for body in &ordering[compiled..] {
+            if let &GraphReadBody::SortBy(def_id, _) = body {
+                self.provide_key(vertex, def_id, &mut provides);
             }
         }
 
+        let mut key = None;
+        let mut aggregate = None;
+
+        match read.tail {
+            // The interpreter builds the groups, but reads the key of each row from its column,
+            // which doesn't depend on which rows are accepted.
+            GraphReadTail::GroupBy(def_id, _) => match self.resolve_key(vertex, def_id) {
+                Some(path) => {
+                    let mut selects = TraversalPathBitSet::empty(vertex);
+                    selects.insert(TraversalPath::Entity(path));
+                    provides.insert(selects);
+
+                    key = Some(path);
+                }
+                None => self.provide_key(vertex, def_id, &mut provides),
+            },
+            GraphReadTail::Aggregate(function, def_id, _) => {
+                match self.resolve_key(vertex, def_id).filter(|_| ordered) {
+                    Some(path) => {
+                        aggregate = Some((function, traverse::eval_entity_path(&mut db, path)));
+                    }
+                    None => self.provide_key(vertex, def_id, &mut provides),
+                }
+            }
+            GraphReadTail::Collect
+            | GraphReadTail::Count
+            | GraphReadTail::Exists
+            | GraphReadTail::First => {}
+        }
+
         // Build SELECT list from what the interpreter needs back.
-        // Each EntityPath in `provides` becomes a SELECT expression via eval_entity_path,
-        // which also registers the necessary projection joins in DatabaseContext.
+        // Each path in `provides` becomes a SELECT expression via eval_entity_path (or
+        // eval_ontology_path), which also registers the necessary projection joins in
+        // DatabaseContext.
         let mut select_expressions = vec![];
         let mut columns = Vec::new_in(self.alloc.clone());
 
-        for traversal_path in provides[VertexType::Entity].iter() {
-            let TraversalPath::Entity(path) = traversal_path;
-
-            let mut expression = traverse::eval_entity_path(&mut db, path);
+        for traversal_path in provides[vertex].iter() {
+            let mut expression = match traversal_path {
+                TraversalPath::Entity(path) => traverse::eval_entity_path(&mut db, path),
+                TraversalPath::DataType(path)
+                | TraversalPath::PropertyType(path)
+                | TraversalPath::EntityType(path) => traverse::eval_ontology_path(&mut db, path),
+            };
 
-            if matches!(path, EntityPath::Properties | EntityPath::PropertyMetadata)
-                && let Some(mask) = &self.property_mask
+            if matches!(
+                traversal_path,
+                TraversalPath::Entity(EntityPath::Properties | EntityPath::PropertyMetadata)
+            ) && let Some(mask) = &self.property_mask
             {
                 expression = Expression::grouped(Expression::subtract(expression, mask.clone()));
             }
@@ -488,17 +994,94 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
             });
         }
 
+        if let Some((_, expression)) = &aggregate {
+            select_expressions.push(SelectExpression::Expression {
+                expression: expression.clone(),
+                output_name: Some(Identifier::from("key")),
+            });
+        }
+
         let query = SimpleSelect::builder()
             .selects(select_expressions)
             .from(from)
             .maybe_where_clause(Expression::conjunction(db.conditions))
             .build();
 
+        let mut statement = SelectStatement::from(query);
+        statement.order_by = NonEmptyVec::try_from(sort_by)
+            .ok()
+            .map(|sort_by| OrderByClause::builder().sort_by(sort_by).build());
+        statement.limit = limit;
+        statement.offset = offset;
+
+        if counted {
+            let statement = SimpleSelect::builder()
+                .selects(vec![SelectExpression::Expression {
+                    expression: Expression::Function(query::Function::CountAll),
+                    output_name: Some(Identifier::from("count")),
+                }])
+                .from(
+                    query::FromItem::subquery(statement)
+                        .alias(Identifier::from("rows"))
+                        .build(),
+                )
+                .build();
+
+            return PreparedQuery {
+                vertex_type: vertex,
+                parameters: db.parameters,
+                statement: statement.into(),
+                columns: Vec::new_in(self.alloc.clone()),
+                output: QueryOutput::Count,
+            };
+        }
+
+        if let Some((function, _)) = aggregate {
+            let key = Box::new(Expression::ColumnReference(query::ColumnReference {
+                correlation: Some(TableReference {
+                    schema: None,
+                    name: TableName::from(Identifier::from("rows")),
+                }),
+                name: ColumnName::from(Identifier::from("key")),
+            }));
+
+            let expression = match function {
+                GraphAggregate::Sum => query::Function::Sum(key),
+                GraphAggregate::Min => query::Function::Min(key),
+                GraphAggregate::Max => query::Function::Max(key),
+                GraphAggregate::Avg => query::Function::Avg(key),
+            };
+
+            let statement = SimpleSelect::builder()
+                .selects(vec![SelectExpression::Expression {
+                    expression: Expression::Function(expression).cast(PostgresType::Float8),
+                    output_name: Some(Identifier::from("aggregate")),
+                }])
+                .from(
+                    query::FromItem::subquery(statement)
+                        .alias(Identifier::from("rows"))
+                        .build(),
+                )
+                .build();
+
+            return PreparedQuery {
+                vertex_type: vertex,
+                parameters: db.parameters,
+                statement: statement.into(),
+                columns: Vec::new_in(self.alloc.clone()),
+                output: QueryOutput::Aggregate(function),
+            };
+        }
+
         PreparedQuery {
-            vertex_type: VertexType::Entity,
+            vertex_type: vertex,
             parameters: db.parameters,
-            statement: query.into(),
+            statement,
             columns,
+            output: QueryOutput::Rows {
+                ordering: compiled,
+                key,
+            },
         }
     }
 
@@ -510,7 +1093,18 @@ impl<'eval, 'ctx, 'heap, A: Allocator, S: BumpAllocator>
         A: Clone,
     {
         match read.head {
-            GraphReadHead::Entity { .. } => self.compile_graph_read_entity(read),
+            GraphReadHead::Entity { .. } => {
+                self.compile_graph_read_vertex(read, VertexType::Entity)
+            }
+            GraphReadHead::DataType { .. } => {
+                self.compile_graph_read_vertex(read, VertexType::DataType)
+            }
+            GraphReadHead::PropertyType { .. } => {
+                self.compile_graph_read_vertex(read, VertexType::PropertyType)
+            }
+            GraphReadHead::EntityType { .. } => {
+                self.compile_graph_read_vertex(read, VertexType::EntityType)
+            }
         }
     }
 
@@ -1,4 +1,4 @@
-//! Lazy join planner for entity-backed SQL queries.
+//! Lazy join planner for entity- and ontology-backed SQL queries.
 //!
 //! See [`Projections`] for the main entry point.
 
@@ -10,6 +10,7 @@ use hash_graph_postgres_store::store::postgres::query::{
     table,
 };
 use hashql_core::symbol::sym;
+use hashql_mir::pass::execution::VertexType;
 
 use super::Parameters;
 
@@ -28,12 +29,22 @@ impl From<ComputedColumn> for ColumnName<'_> {
     }
 }
 
-/// Lazy join planner for entity-backed SQL queries.
+/// Lazy join planner for entity- and ontology-backed SQL queries.
 ///
 /// Accessors like [`Self::entity_editions`] register that a table is needed and return a
 /// reference to it. The actual `FROM` tree is built once at the end via [`Self::build_from`].
+///
+/// Queries over entities are based on `entity_temporal_metadata`, queries over the types of the
+/// ontology on `ontology_temporal_metadata`. Only the accessors of the respective kind of vertex
+/// may be used.
 pub(crate) struct Projections {
     index: usize,
+    /// Distinguishes the aliases of nested queries, which may refer to the tables of the query
+    /// they are nested in.
+    chain_depth: usize,
+
+    /// The kind of vertex the query reads, which determines the base table.
+    vertex: VertexType,
 
     /// Always present as the base table; everything joins through it.
     base_alias: Alias,
@@ -43,28 +54,62 @@ pub(crate) struct Projections {
     entity_type_ids: Option<Alias>,
     left: Option<Alias>,
     right: Option<Alias>,
+
+    /// Always present for ontology vertices, restricts the query to the respective kind of type.
+    ontology_types: Option<Alias>,
+    ontology_ids: Option<Alias>,
+    ontology_owned_metadata: Option<Alias>,
 }
 
 impl Projections {
     pub(crate) const fn new() -> Self {
+        Self::nested(0)
+    }
+
+    /// Creates the projections of a query nested at `chain_depth`.
+    ///
+    /// Aliases of projections with different chain depths never collide.
+    pub(crate) const fn nested(chain_depth: usize) -> Self {
         let mut index = 0;
-        let base_alias = Self::next_alias(&mut index);
+        let base_alias = Self::next_alias(&mut index, chain_depth);
 
         Self {
             index,
+            chain_depth,
+            vertex: VertexType::Entity,
             base_alias,
             entity_editions: None,
             entity_ids: None,
             entity_type_ids: None,
             left: None,
             right: None,
+            ontology_types: None,
+            ontology_ids: None,
+            ontology_owned_metadata: None,
+        }
+    }
+
+    /// Creates the projections of a top-level query over vertices of the given type.
+    pub(crate) const fn for_vertex(vertex: VertexType) -> Self {
+        let mut this = Self::new();
+
+        if vertex.is_ontology() {
+            this.vertex = vertex;
+            this.ontology_types = Some(Self::next_alias(&mut this.index, this.chain_depth));
         }
+
+        this
     }
 
-    const fn next_alias(index: &mut usize) -> Alias {
+    /// The kind of vertex the query reads.
+    pub(crate) const fn vertex(&self) -> VertexType {
+        self.vertex
+    }
+
+    const fn next_alias(index: &mut usize, chain_depth: usize) -> Alias {
         let alias = Alias {
             condition_index: 0,
-            chain_depth: 0,
+            chain_depth,
             number: *index,
         };
 
@@ -76,20 +121,76 @@ impl Projections {
     pub(crate) fn entity_editions(&mut self) -> TableReference<'static> {
         let alias = *self
             .entity_editions
-            .get_or_insert_with(|| Self::next_alias(&mut self.index));
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
 
         Table::EntityEditions.aliased(alias)
     }
 
+    /// Allocates an alias for a table which is not joined into the `FROM` clause, e.g. the table
+    /// of a subquery.
+    pub(crate) const fn fresh_alias(&mut self) -> Alias {
+        Self::next_alias(&mut self.index, self.chain_depth)
+    }
+
     /// Returns the base table reference, which is always present (no lazy join).
+    ///
+    /// This is `entity_temporal_metadata` for entities and `ontology_temporal_metadata` for the
+    /// types of the ontology.
     pub(crate) fn temporal_metadata(&self) -> TableReference<'static> {
-        Table::EntityTemporalMetadata.aliased(self.base_alias)
+        self.base_table().aliased(self.base_alias)
+    }
+
+    const fn base_table(&self) -> Table {
+        match self.vertex {
+            VertexType::Entity => Table::EntityTemporalMetadata,
+            VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                Table::OntologyTemporalMetadata
+            }
+        }
+    }
+
+    /// Returns the table holding the schemas of the kind of type the query reads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the query does not read types of the ontology.
+    pub(crate) fn ontology_types(&self) -> TableReference<'static> {
+        let alias = self
+            .ontology_types
+            .expect("only queries over the ontology have a type table");
+
+        Self::ontology_types_table(self.vertex).aliased(alias)
+    }
+
+    const fn ontology_types_table(vertex: VertexType) -> Table {
+        match vertex {
+            VertexType::DataType => Table::DataTypes,
+            VertexType::PropertyType => Table::PropertyTypes,
+            VertexType::EntityType => Table::EntityTypes,
+            VertexType::Entity => panic!("entities are not a type of the ontology"),
+        }
+    }
+
+    pub(crate) fn ontology_ids(&mut self) -> TableReference<'static> {
+        let alias = *self
+            .ontology_ids
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
+
+        Table::OntologyIds.aliased(alias)
+    }
+
+    pub(crate) fn ontology_owned_metadata(&mut self) -> TableReference<'static> {
+        let alias = *self
+            .ontology_owned_metadata
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
+
+        Table::OntologyOwnedMetadata.aliased(alias)
     }
 
     pub(crate) fn entity_ids(&mut self) -> TableReference<'static> {
         let alias = *self
             .entity_ids
-            .get_or_insert_with(|| Self::next_alias(&mut self.index));
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
 
         Table::EntityIds.aliased(alias)
     }
@@ -99,7 +200,7 @@ impl Projections {
     pub(crate) fn entity_type_ids(&mut self) -> ColumnReference<'static> {
         let alias = *self
             .entity_type_ids
-            .get_or_insert_with(|| Self::next_alias(&mut self.index));
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
 
         ColumnReference {
             correlation: Some(Table::EntityEditionCache.aliased(alias)),
@@ -110,7 +211,7 @@ impl Projections {
     pub(crate) fn left_entity(&mut self) -> TableReference<'static> {
         let alias = *self
             .left
-            .get_or_insert_with(|| Self::next_alias(&mut self.index));
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
 
         Table::EntityHasLeftEntity.aliased(alias)
     }
@@ -118,27 +219,53 @@ impl Projections {
     pub(crate) fn right_entity(&mut self) -> TableReference<'static> {
         let alias = *self
             .right
-            .get_or_insert_with(|| Self::next_alias(&mut self.index));
+            .get_or_insert_with(|| Self::next_alias(&mut self.index, self.chain_depth));
 
         Table::EntityHasRightEntity.aliased(alias)
     }
 
     /// Builds the FROM clause with all joins that were requested during compilation.
     ///
-    /// `entity_temporal_metadata` is always the base table. Other tables are joined
-    /// conditionally based on which paths the filter body and provides set touched.
-    /// CROSS JOIN LATERALs for continuation subqueries are appended last.
+    /// `entity_temporal_metadata` (or `ontology_temporal_metadata`) is always the base table.
+    /// Other tables are joined conditionally based on which paths the filter body and provides
+    /// set touched. CROSS JOIN LATERALs for continuation subqueries are appended last.
     pub(crate) fn build_from(
         &self,
         parameters: &mut Parameters<'_, impl Allocator>,
         laterals: Vec<FromItem<'static>, impl Allocator>,
     ) -> FromItem<'static> {
-        let base = FromItem::table(Table::EntityTemporalMetadata)
-            .alias(Table::EntityTemporalMetadata.aliased_name(self.base_alias))
+        let base_table = self.base_table();
+        let base = FromItem::table(base_table)
+            .alias(base_table.aliased_name(self.base_alias))
             .build();
 
         let mut from = base;
 
+        // <kind>_types ON ontology_id (INNER)
+        if let Some(alias) = self.ontology_types {
+            from = self.build_ontology_join(
+                from,
+                Self::ontology_types_table(self.vertex),
+                alias,
+                JoinType::Inner,
+            );
+        }
+
+        // ontology_ids ON ontology_id (INNER)
+        if let Some(alias) = self.ontology_ids {
+            from = self.build_ontology_join(from, Table::OntologyIds, alias, JoinType::Inner);
+        }
+
+        // ontology_owned_metadata ON ontology_id (LEFT OUTER): external types have no web
+        if let Some(alias) = self.ontology_owned_metadata {
+            from = self.build_ontology_join(
+                from,
+                Table::OntologyOwnedMetadata,
+                alias,
+                JoinType::LeftOuter,
+            );
+        }
+
         // entity_editions ON edition_id (INNER)
         if let Some(alias) = self.entity_editions {
             from = self.build_entity_editions(from, alias);
@@ -186,6 +313,39 @@ impl Projections {
         from
     }
 
+    fn build_ontology_join<'item>(
+        &self,
+        from: FromItem<'item>,
+        table: Table,
+        alias: Alias,
+        join_type: JoinType,
+    ) -> FromItem<'item> {
+        #[expect(clippy::wildcard_enum_match_arm)]
+        let join = match table {
+            Table::OntologyIds => Column::OntologyIds(table::OntologyIds::OntologyId),
+            Table::OntologyOwnedMetadata => {
+                Column::OntologyOwnedMetadata(table::OntologyOwnedMetadata::OntologyId)
+            }
+            Table::DataTypes => Column::DataTypes(table::DataTypes::OntologyId),
+            Table::PropertyTypes => Column::PropertyTypes(table::PropertyTypes::OntologyId),
+            Table::EntityTypes => Column::EntityTypes(table::EntityTypes::OntologyId),
+            _ => unreachable!("`{table:?}` is not joined on the ontology id"),
+        };
+
+        let fk = ForeignKeyReference::Single {
+            on: Column::OntologyTemporalMetadata(table::OntologyTemporalMetadata::OntologyId),
+            join,
+            join_type,
+        };
+
+        from.join(
+            join_type,
+            FromItem::table(table).alias(table.aliased_name(alias)),
+        )
+        .on(fk.conditions(self.base_alias, alias))
+        .build()
+    }
+
     fn build_entity_editions<'item>(&self, from: FromItem<'item>, alias: Alias) -> FromItem<'item> {
         let fk = ForeignKeyReference::Single {
             on: Column::EntityTemporalMetadata(table::EntityTemporalMetadata::EditionId),
@@ -1,16 +1,21 @@
-//! Mapping from logical entity fields to physical PostgreSQL columns.
+//! Mapping from logical vertex fields to physical PostgreSQL columns.
 //!
 //! This module contains [`eval_entity_path`], the single translation table between
 //! [`EntityPath`] values (used by MIR traversal analysis) and the physical Postgres schema
-//! (spanning `entity_temporal_metadata`, `entity_editions`, `entity_ids`, and edge tables).
+//! (spanning `entity_temporal_metadata`, `entity_editions`, `entity_ids`, and edge tables), and
+//! [`eval_ontology_path`], its counterpart for the types of the ontology (spanning `ontology_ids`,
+//! `ontology_owned_metadata` and the table of the respective kind of type).
 
 use core::alloc::Allocator;
 
 use hash_graph_postgres_store::store::postgres::query::{
-    self, Column, ColumnReference, Constant, Expression, table,
+    self, Column, ColumnReference, Constant, Expression, PostgresType, table,
 };
 use hashql_core::symbol::sym;
-use hashql_mir::pass::execution::traversal::EntityPath;
+use hashql_mir::pass::execution::{
+    VertexType,
+    traversal::{EntityPath, OntologyPath},
+};
 
 use super::DatabaseContext;
 
@@ -234,3 +239,55 @@ pub(crate) fn eval_entity_path<A: Allocator>(
         }),
     }
 }
+
+/// Lowers an [`OntologyPath`] to a SQL [`Expression`], requesting joins and allocating parameters
+/// as needed.
+///
+/// The kind of type is determined by the projections of the query, every kind shares the same
+/// columns, except for the schema.
+pub(crate) fn eval_ontology_path<A: Allocator>(
+    db: &mut DatabaseContext<'_, A>,
+    path: OntologyPath,
+) -> Expression {
+    match path {
+        OntologyPath::VersionedUrl => Expression::Function(query::Function::JsonBuildObject(vec![
+            (
+                db.parameters.symbol(sym::base_url).to_expr(),
+                eval_ontology_path(db, OntologyPath::BaseUrl),
+            ),
+            (
+                db.parameters.symbol(sym::version).to_expr(),
+                eval_ontology_path(db, OntologyPath::Version),
+            ),
+        ])),
+        OntologyPath::BaseUrl => Expression::ColumnReference(ColumnReference {
+            correlation: Some(db.projections.ontology_ids()),
+            name: Column::OntologyIds(table::OntologyIds::BaseUrl).into(),
+        }),
+        // `OntologyTypeVersion` is a string, the column is numeric
+        OntologyPath::Version => Expression::ColumnReference(ColumnReference {
+            correlation: Some(db.projections.ontology_ids()),
+            name: Column::OntologyIds(table::OntologyIds::Version).into(),
+        })
+        .cast(PostgresType::Text),
+        OntologyPath::WebId => Expression::ColumnReference(ColumnReference {
+            correlation: Some(db.projections.ontology_owned_metadata()),
+            name: Column::OntologyOwnedMetadata(table::OntologyOwnedMetadata::WebId).into(),
+        }),
+        OntologyPath::Schema => {
+            let types = db.projections.ontology_types();
+
+            let column = match db.projections.vertex() {
+                VertexType::DataType => Column::DataTypes(table::DataTypes::Schema),
+                VertexType::PropertyType => Column::PropertyTypes(table::PropertyTypes::Schema),
+                VertexType::EntityType => Column::EntityTypes(table::EntityTypes::Schema),
+                VertexType::Entity => unreachable!("entities are not a type of the ontology"),
+            };
+
+            Expression::ColumnReference(ColumnReference {
+                correlation: Some(types),
+                name: column.into(),
+            })
+        }
+    }
+}
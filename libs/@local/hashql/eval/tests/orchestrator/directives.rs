@@ -20,16 +20,43 @@ pub(crate) struct AxisDirectives {
     pub transaction: Option<AxisInterval>,
 }
 
-/// Parses `//@ axis[decision]` and `//@ axis[transaction]` directives from
-/// the source text.
+/// Parsed directives from a test file.
+#[derive(Debug, Default)]
+pub(crate) struct Directives {
+    pub axis: AxisDirectives,
+    /// Serve the embeddings of the seeded entities instead of running without an embedding
+    /// backend.
+    pub embeddings: bool,
+    /// Limits the number of steps the interpreters may execute for the whole query.
+    pub fuel: Option<u64>,
+    pub run: Run,
+}
+
+/// Expected outcome of a test, set through `//@ run: pass` or `//@ run: fail`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub(crate) enum Run {
+    /// The query succeeds, its result is compared against the expected output.
+    #[default]
+    Pass,
+    /// The query fails, its diagnostic is compared against the expected output.
+    Fail,
+}
+
+/// Parses the `//@` directives from the source text.
+///
+/// Supported directives:
+/// - `//@ axis[decision] = <interval>` / `//@ axis[transaction] = <interval>`
+/// - `//@ embeddings`
+/// - `//@ fuel: <steps>`
+/// - `//@ run: pass` / `//@ run: fail`
 ///
 /// Supported interval syntax:
 /// - `(T)` : point interval (pinned)
 /// - `[a, b)` / `(a, b]` / `[a, b]` / `(a, b)` : range with bounds
 /// - `(, b]` / `(, b)` : unbounded start
 /// - `[a,)` / `(a,)` : unbounded end
-pub(crate) fn parse_directives(source: &str) -> AxisDirectives {
-    let mut directives = AxisDirectives::default();
+pub(crate) fn parse_directives(source: &str) -> Directives {
+    let mut directives = Directives::default();
 
     for line in source.lines() {
         let trimmed = line.trim();
@@ -44,6 +71,29 @@ pub(crate) fn parse_directives(source: &str) -> AxisDirectives {
 
         let rest = rest.trim();
 
+        if rest == "embeddings" {
+            directives.embeddings = true;
+            continue;
+        }
+
+        if let Some(fuel) = rest.strip_prefix("fuel:") {
+            directives.fuel = Some(
+                fuel.trim()
+                    .parse()
+                    .unwrap_or_else(|error| panic!("could not parse fuel {fuel:?}: {error}")),
+            );
+            continue;
+        }
+
+        if let Some(mode) = rest.strip_prefix("run:") {
+            directives.run = match mode.trim() {
+                "pass" => Run::Pass,
+                "fail" => Run::Fail,
+                other => panic!("unknown run mode: {other}"),
+            };
+            continue;
+        }
+
         let Some(rest) = rest.strip_prefix("axis[") else {
             continue;
         };
@@ -61,8 +111,8 @@ pub(crate) fn parse_directives(source: &str) -> AxisDirectives {
         let interval = parse_interval(rest);
 
         match axis_name {
-            "decision" => directives.decision = Some(interval),
-            "transaction" => directives.transaction = Some(interval),
+            "decision" => directives.axis.decision = Some(interval),
+            "transaction" => directives.axis.transaction = Some(interval),
             other => panic!("unknown axis name: {other}"),
         }
     }
@@ -28,6 +28,7 @@ impl Error for SetupError {}
 pub(crate) enum TestError {
     ReadSource,
     Execution,
+    UnexpectedSuccess,
     Serialization,
     OutputMismatch,
 }
@@ -37,6 +38,7 @@ impl fmt::Display for TestError {
         match self {
             Self::ReadSource => f.write_str("failed to read test source file"),
             Self::Execution => f.write_str("query execution failed"),
+            Self::UnexpectedSuccess => f.write_str("query was expected to fail"),
             Self::Serialization => f.write_str("failed to serialize result value"),
             Self::OutputMismatch => f.write_str("output comparison failed"),
         }
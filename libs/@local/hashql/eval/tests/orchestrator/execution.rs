@@ -8,13 +8,13 @@ use hashql_core::{
 use hashql_diagnostics::{Diagnostic, diagnostic::BoxedDiagnostic};
 use hashql_eval::{
     context::{CodeExecutionContext, CodeGenerationContext},
-    orchestrator::{AppendEventLog, Event, Orchestrator},
+    orchestrator::{AppendEventLog, EmbeddingBackend, Event, Orchestrator},
     postgres::PostgresCompiler,
 };
 use hashql_mir::{
     body::Body,
     def::{DefId, DefIdSlice, DefIdVec},
-    interpret::{Inputs, value::Value},
+    interpret::{Inputs, RuntimeConfig, value::Value},
 };
 use tokio::runtime;
 use tokio_postgres::Client;
@@ -55,7 +55,8 @@ pub(crate) fn lower<'heap>(
 /// Transforms, analyzes, and executes a lowered query.
 ///
 /// The caller provides pre-built inputs (constructed after lowering so that
-/// the type environment is available for decoding).
+/// the type environment is available for decoding), the backend serving
+/// the embeddings of entities, and the configuration of the interpreters.
 ///
 /// # Errors
 ///
@@ -65,6 +66,8 @@ pub(crate) fn run<'heap>(
 
     runtime: &runtime::Runtime,
     client: &Client,
+    embeddings: impl EmbeddingBackend,
+    config: RuntimeConfig,
 
     inputs: &Inputs<'heap, &'heap Heap>,
 
@@ -74,6 +77,8 @@ pub(crate) fn run<'heap>(
         pipeline,
         runtime,
         client,
+        embeddings,
+        config,
         inputs,
         lowered.interner,
         lowered.entry,
@@ -84,7 +89,7 @@ pub(crate) fn run<'heap>(
 /// Executes a pre-built MIR program.
 ///
 /// Used by programmatic tests that construct bodies directly via the `body!`
-/// macro instead of parsing J-Expr source.
+/// macro instead of parsing J-Expr source. No embedding backend is configured.
 ///
 /// # Errors
 ///
@@ -101,21 +106,35 @@ pub(crate) fn execute<'heap>(
     entry: DefId,
     bodies: &mut DefIdSlice<Body<'heap>>,
 ) -> Result<(Value<'heap, &'heap Heap>, Vec<Event>), BoxedDiagnostic<'static, SpanId>> {
-    run_impl(pipeline, runtime, client, inputs, interner, entry, bodies)
+    run_impl(
+        pipeline,
+        runtime,
+        client,
+        (),
+        RuntimeConfig::default(),
+        inputs,
+        interner,
+        entry,
+        bodies,
+    )
 }
 
-struct PostgresClient<'client>(&'client Client);
+/// Provides the [`Client`] of the tests to the orchestrator.
+pub(crate) struct PostgresClient<'client>(pub &'client Client);
 impl AsRef<Client> for PostgresClient<'_> {
     fn as_ref(&self) -> &Client {
         self.0
     }
 }
 
+#[expect(clippy::too_many_arguments)]
 fn run_impl<'heap>(
     pipeline: &mut Pipeline<'heap>,
 
     runtime: &runtime::Runtime,
     client: &Client,
+    embeddings: impl EmbeddingBackend,
+    config: RuntimeConfig,
 
     inputs: &Inputs<'heap, &'heap Heap>,
 
@@ -144,8 +163,10 @@ fn run_impl<'heap>(
 
     let event_log = AppendEventLog::new();
     let context = CodeExecutionContext::from(context);
-    let orchestrator =
-        Orchestrator::new(PostgresClient(client), &queries, &context).with_event_log(&event_log);
+    let orchestrator = Orchestrator::new(PostgresClient(client), &queries, &context)
+        .with_event_log(&event_log)
+        .with_embeddings(embeddings)
+        .with_runtime_config(config);
 
     let value = runtime
         .block_on(orchestrator.run_in(inputs, entry, [], pipeline.heap))
@@ -321,6 +321,11 @@ pub(crate) fn build_inputs<'heap>(
     insert_entity_id(&mut inputs, "bob_id", entities.bob);
     insert_entity_id(&mut inputs, "org_id", entities.organization);
     insert_entity_id(&mut inputs, "friend_link_id", entities.friend_link);
+    insert_entity_id(
+        &mut inputs,
+        "acquaintance_link_id",
+        entities.acquaintance_link,
+    );
     insert_entity_id(&mut inputs, "draft_alice_id", entities.draft_alice);
 
     // WebId input (all seeded entities share the same web).
@@ -338,6 +343,8 @@ pub(crate) fn build_inputs<'heap>(
 
     // String inputs for property-based filtering.
     inputs.insert(heap.intern_symbol("alice_name"), string("Alice"));
+    // Surrounded by whitespace that is not a space, which `trim` must keep.
+    inputs.insert(heap.intern_symbol("padded_text"), string("\t padded \n"));
 
     // Temporal axes from directives (or default: unbounded decision time,
     // far-future transaction pin).
@@ -6,7 +6,10 @@ use alloc::sync::Arc;
 use error_stack::{Report, ResultExt as _};
 use hash_graph_postgres_store::store::{AsClient as _, PostgresStore, PostgresStoreSettings};
 use hashql_compiletest::pipeline::Pipeline;
-use hashql_core::heap::Heap;
+use hashql_core::{heap::Heap, span::SpanId};
+use hashql_diagnostics::diagnostic::BoxedDiagnostic;
+use hashql_eval::orchestrator::Event;
+use hashql_mir::interpret::{RuntimeConfig, value::Value};
 use testcontainers::{ImageExt as _, ReuseDirective, runners::AsyncRunner as _};
 use testcontainers_modules::postgres::Postgres;
 use tokio::runtime::{self, Runtime};
@@ -20,23 +23,37 @@ mod inputs;
 mod output;
 mod programmatic;
 mod seed;
+mod stored;
 
 use self::{
-    directives::{AxisDirectives, parse_directives},
+    directives::{AxisDirectives, Run, parse_directives},
     discover::{
         ProgrammaticBuilder, TestSource, discover_jexpr_tests, discover_programmatic_tests,
         test_ui_dir,
     },
     error::{SetupError, TestError},
     inputs::build_inputs,
-    output::{compare_or_bless, render_failure, render_success},
+    output::{compare_or_bless, render_expected_failure, render_failure, render_success},
     seed::SeededEntities,
+    stored::StoredContext,
 };
 
 struct TestContext {
     _container: testcontainers::ContainerAsync<Postgres>,
     store: Arc<PostgresStore<Client>>,
     entities: SeededEntities,
+    stored: StoredContext,
+}
+
+/// Connects to the database described by `config`.
+async fn connect(config: &tokio_postgres::Config) -> Result<Client, Report<SetupError>> {
+    let (client, connection) = config
+        .connect(NoTls)
+        .await
+        .change_context(SetupError::Connection)?;
+    tokio::spawn(connection);
+
+    Ok(client)
 }
 
 async fn setup() -> Result<TestContext, Report<SetupError>> {
@@ -70,27 +87,59 @@ async fn setup() -> Result<TestContext, Report<SetupError>> {
         .change_context(SetupError::Container)
         .attach("could not resolve container port")?;
 
-    let (client, connection) = tokio_postgres::Config::new()
+    let mut config = tokio_postgres::Config::new();
+    config
         .user("hash")
         .password("hash")
         .host(&host)
         .port(port)
-        .dbname("hash")
-        .connect(NoTls)
-        .await
-        .change_context(SetupError::Connection)?;
-    tokio::spawn(connection);
+        .dbname("hash");
+    let client = connect(&config).await?;
 
     let mut store = PostgresStore::new(client, None, Arc::new(PostgresStoreSettings::default()));
     let entities = seed::setup(&mut store).await?;
+    let stored = stored::setup(store.as_client(), config).await?;
 
     Ok(TestContext {
         _container: container,
         store: Arc::new(store),
         entities,
+        stored,
     })
 }
 
+/// Compares the outcome of a test against its expected output.
+///
+/// Depending on `run`, either the result or the diagnostic of the query is
+/// compared, the other outcome fails the test.
+fn compare_outcome<'heap>(
+    source: &str,
+    pipeline: &Pipeline<'heap>,
+    run: Run,
+    outcome: Result<(Value<'heap, &'heap Heap>, Vec<Event>), BoxedDiagnostic<'static, SpanId>>,
+    expected_output: &std::path::Path,
+    bless: bool,
+) -> Result<(), Report<TestError>> {
+    match (outcome, run) {
+        (Ok((value, events)), Run::Pass) => {
+            let rendered = render_success(source, &value, &events, pipeline)?;
+            compare_or_bless(&rendered, expected_output, bless)
+        }
+        (Ok((value, events)), Run::Fail) => {
+            let rendered = render_success(source, &value, &events, pipeline)?;
+            Err(Report::new(TestError::UnexpectedSuccess).attach(rendered))
+        }
+        (Err(diagnostic), Run::Pass) => {
+            let rendered = render_failure(source, pipeline, &diagnostic);
+            Err(Report::new(TestError::Execution).attach(rendered))
+        }
+        (Err(diagnostic), Run::Fail) => {
+            let rendered = render_expected_failure(source, pipeline, &diagnostic);
+            compare_or_bless(&rendered, expected_output, bless)
+        }
+    }
+}
+
 /// Runs a J-Expr test: parse, lower, build inputs, execute, compare output.
 fn run_jexpr_test(
     runtime: &Runtime,
@@ -104,7 +153,7 @@ fn run_jexpr_test(
         .attach_with(|| format!("{}", path.display()))?;
 
     let source = String::from_utf8_lossy(&bytes);
-    let axis_directives = parse_directives(&source);
+    let directives = parse_directives(&source);
     let heap = Heap::new();
     let mut pipeline = Pipeline::new(&heap);
 
@@ -120,25 +169,38 @@ fn run_jexpr_test(
         &heap,
         &lowered.interner.symbols,
         &context.entities,
-        &axis_directives,
+        &directives.axis,
     );
 
-    match execution::run(
-        &mut pipeline,
-        runtime,
-        context.store.as_client(),
-        &inputs,
-        lowered,
-    ) {
-        Ok((value, events)) => {
-            let rendered = render_success(&source, &value, &events, &pipeline)?;
-            compare_or_bless(&rendered, expected_output, bless)
-        }
-        Err(diagnostic) => {
-            let rendered = render_failure(&source, &pipeline, &diagnostic);
-            Err(Report::new(TestError::Execution).attach(rendered))
-        }
-    }
+    let config = RuntimeConfig {
+        fuel: directives.fuel,
+        ..RuntimeConfig::default()
+    };
+
+    let client = context.store.as_client();
+    let outcome = if directives.embeddings {
+        let embeddings = context.entities.embeddings();
+        execution::run(
+            &mut pipeline,
+            runtime,
+            client,
+            &embeddings,
+            config,
+            &inputs,
+            lowered,
+        )
+    } else {
+        execution::run(&mut pipeline, runtime, client, (), config, &inputs, lowered)
+    };
+
+    compare_outcome(
+        &source,
+        &pipeline,
+        directives.run,
+        outcome,
+        expected_output,
+        bless,
+    )
 }
 
 /// Runs a programmatic test: build MIR directly, execute, compare output.
@@ -169,7 +231,7 @@ fn run_programmatic_test(
     // without source context (all spans are synthetic).
     let source = "";
 
-    match execution::execute(
+    let outcome = execution::execute(
         &mut pipeline,
         runtime,
         context.store.as_client(),
@@ -177,16 +239,16 @@ fn run_programmatic_test(
         interner,
         entry,
         &mut bodies,
-    ) {
-        Ok((value, events)) => {
-            let rendered = render_success(source, &value, &events, &pipeline)?;
-            compare_or_bless(&rendered, expected_output, bless)
-        }
-        Err(diagnostic) => {
-            let rendered = render_failure(source, &pipeline, &diagnostic);
-            Err(Report::new(TestError::Execution).attach(rendered))
-        }
-    }
+    );
+
+    compare_outcome(
+        source,
+        &pipeline,
+        Run::Pass,
+        outcome,
+        expected_output,
+        bless,
+    )
 }
 
 const PROGRAMMATIC_TESTS: &[(&str, ProgrammaticBuilder)] = &[
@@ -212,7 +274,7 @@ fn main() -> Result<(), Report<SetupError>> {
     let mut test_cases = discover_jexpr_tests(&ui_dir);
     test_cases.extend(discover_programmatic_tests(&ui_dir, PROGRAMMATIC_TESTS));
 
-    let trials: Vec<_> = test_cases
+    let mut trials: Vec<_> = test_cases
         .into_iter()
         .map(|test_case| {
             let context = Arc::clone(&context);
@@ -237,5 +299,9 @@ fn main() -> Result<(), Report<SetupError>> {
         })
         .collect();
 
+    trials.push(libtest_mimic::Trial::test("stored::many-rows", move || {
+        stored::many_rows(&runtime, &context.stored).map_err(|report| format!("{report:?}").into())
+    }));
+
     libtest_mimic::run(&arguments, trials).exit();
 }
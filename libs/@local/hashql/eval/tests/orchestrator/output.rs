@@ -158,6 +158,18 @@ pub(crate) fn render_failure(
     output
 }
 
+/// Renders the failure of a query which is expected to fail.
+///
+/// Like [`render_failure`], but normalized like [`render_success`], as the
+/// output is compared against the expected `.stdout` file.
+pub(crate) fn render_expected_failure(
+    source: &str,
+    pipeline: &Pipeline<'_>,
+    diagnostic: &BoxedDiagnostic<'_, SpanId>,
+) -> String {
+    normalize(&render_failure(source, pipeline, diagnostic))
+}
+
 /// Compares rendered output against the expected `.stdout` file.
 ///
 /// If `bless` is true, writes the actual output to the file instead of
@@ -13,6 +13,7 @@ use hash_graph_store::{
     query::ConflictBehavior,
 };
 use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hashql_eval::orchestrator::{EntityKey, InMemoryEmbeddings};
 use tokio_postgres::Client;
 use type_system::{
     knowledge::{
@@ -44,10 +45,31 @@ pub(crate) struct SeededEntities {
     pub bob: EntityId,
     pub organization: EntityId,
     pub friend_link: EntityId,
+    pub acquaintance_link: EntityId,
     pub draft_alice: EntityId,
 }
 
+impl SeededEntities {
+    /// Embeddings of the seeded entities, as served to queries using the `//@ embeddings`
+    /// directive.
+    ///
+    /// Only Alice and Bob have an embedding.
+    pub(crate) fn embeddings(&self) -> InMemoryEmbeddings {
+        let key = |entity_id: EntityId| EntityKey {
+            web_id: entity_id.web_id.into(),
+            entity_uuid: entity_id.entity_uuid.into(),
+            draft_id: entity_id.draft_id.map(Into::into),
+        };
+
+        let mut embeddings = InMemoryEmbeddings::new();
+        embeddings.insert(key(self.alice), [0.5, 1.0]);
+        embeddings.insert(key(self.bob), [1.0, 0.5]);
+        embeddings
+    }
+}
+
 const SEED_KEY: &str = "orchestrator_test_seed";
+const EMBEDDINGS_SEED_KEY: &str = "orchestrator_test_embeddings_seed";
 
 fn entity_type_id(json: &str) -> VersionedUrl {
     serde_json::from_str::<EntityType>(json)
@@ -151,6 +173,87 @@ pub(crate) async fn setup(
     Ok(entities)
 }
 
+/// Creates `count` additional entities and stores an embedding for every entity of the database.
+///
+/// Must be called after [`setup`]. On a reused container where this already completed, does
+/// nothing.
+pub(crate) async fn setup_embeddings(
+    store: &mut PostgresStore<Client>,
+    entities: &SeededEntities,
+    count: usize,
+) -> Result<(), Report<SetupError>> {
+    let completed = store
+        .as_client()
+        .query_opt(
+            "SELECT 1 FROM _orchestrator_test_state WHERE key = $1",
+            &[&EMBEDDINGS_SEED_KEY],
+        )
+        .await
+        .change_context(SetupError::Seed)
+        .attach("could not query seed state")?;
+
+    if completed.is_some() {
+        return Ok(());
+    }
+
+    // The test user owns the web of the seeded entities
+    let web_id = entities.alice.web_id;
+    let actor_id = ActorEntityUuid::new(web_id);
+
+    let properties: PropertyObject =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity properties");
+
+    store
+        .create_entities(
+            actor_id,
+            (0..count)
+                .map(|_| CreateEntityParams {
+                    web_id,
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: std::collections::HashSet::from([entity_type_id(
+                        entity_type::PERSON_V1,
+                    )]),
+                    properties: PropertyObjectWithMetadata::from_parts(properties.clone(), None)
+                        .expect("could not create property metadata"),
+                    confidence: None,
+                    link_data: None,
+                    draft: false,
+                    policies: Vec::new(),
+                    provenance: entity_provenance(),
+                    read_only: false,
+                })
+                .collect(),
+        )
+        .await
+        .change_context(SetupError::Seed)
+        .attach("could not seed embedded entities")?;
+
+    store
+        .as_client()
+        .execute(
+            "INSERT INTO entity_embeddings (web_id, entity_uuid, embedding, \
+             updated_at_decision_time, updated_at_transaction_time) SELECT web_id, entity_uuid, \
+             array_fill(0.5::real, ARRAY[3072])::vector, now(), now() FROM entity_ids",
+            &[],
+        )
+        .await
+        .change_context(SetupError::Seed)
+        .attach("could not seed embeddings")?;
+
+    store
+        .as_client()
+        .execute(
+            "INSERT INTO _orchestrator_test_state (key, value) VALUES ($1, 'true')",
+            &[&EMBEDDINGS_SEED_KEY],
+        )
+        .await
+        .change_context(SetupError::Seed)
+        .attach("could not persist seed state")?;
+
+    Ok(())
+}
+
 /// Seeds all ontology types (data types, property types, entity types).
 async fn seed_ontology(
     store: &mut PostgresStore<Client>,
@@ -274,6 +377,40 @@ async fn create_entity(
     Ok(entity.metadata.record_id.entity_id)
 }
 
+/// Creates a link entity without properties between the entities of `link_data`.
+async fn create_link(
+    store: &mut PostgresStore<Client>,
+    actor_id: ActorEntityUuid,
+    web_id: WebId,
+    entity_type_json: &str,
+    link_data: LinkData,
+) -> Result<EntityId, Report<SetupError>> {
+    let entity = store
+        .create_entity(
+            actor_id,
+            CreateEntityParams {
+                web_id,
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: std::collections::HashSet::from([entity_type_id(
+                    entity_type_json,
+                )]),
+                properties: PropertyObjectWithMetadata::from_parts(PropertyObject::empty(), None)
+                    .expect("could not create property metadata"),
+                confidence: None,
+                link_data: Some(link_data),
+                draft: false,
+                policies: Vec::new(),
+                provenance: entity_provenance(),
+                read_only: false,
+            },
+        )
+        .await
+        .change_context(SetupError::Seed)?;
+
+    Ok(entity.metadata.record_id.entity_id)
+}
+
 async fn seed_data(
     store: &mut PostgresStore<Client>,
 ) -> Result<SeededEntities, Report<SetupError>> {
@@ -342,42 +479,47 @@ async fn seed_data(
     )
     .await?;
 
-    let friend_link = store
-        .create_entity(
-            actor_id,
-            CreateEntityParams {
-                web_id,
-                entity_uuid: None,
-                decision_time: None,
-                entity_type_ids: std::collections::HashSet::from([entity_type_id(
-                    entity_type::link::FRIEND_OF_V1,
-                )]),
-                properties: PropertyObjectWithMetadata::from_parts(PropertyObject::empty(), None)
-                    .expect("could not create property metadata"),
-                confidence: None,
-                link_data: Some(LinkData {
-                    left_entity_id: alice,
-                    right_entity_id: bob,
-                    left_entity_confidence: Confidence::new(0.9),
-                    left_entity_provenance: PropertyProvenance::default(),
-                    right_entity_confidence: Confidence::new(0.8),
-                    right_entity_provenance: PropertyProvenance::default(),
-                }),
-                draft: false,
-                policies: Vec::new(),
-                provenance: entity_provenance(),
-                read_only: false,
-            },
-        )
-        .await
-        .change_context(SetupError::Seed)
-        .attach("could not create friend-of link entity")?;
+    let friend_link = create_link(
+        store,
+        actor_id,
+        web_id,
+        entity_type::link::FRIEND_OF_V1,
+        LinkData {
+            left_entity_id: alice,
+            right_entity_id: bob,
+            left_entity_confidence: Confidence::new(0.9),
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: Confidence::new(0.8),
+            right_entity_provenance: PropertyProvenance::default(),
+        },
+    )
+    .await
+    .attach("could not create friend-of link entity")?;
+
+    // Links back to Alice, so that she is reachable from herself in two steps
+    let acquaintance_link = create_link(
+        store,
+        actor_id,
+        web_id,
+        entity_type::link::ACQUAINTANCE_OF_V1,
+        LinkData {
+            left_entity_id: bob,
+            right_entity_id: alice,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        },
+    )
+    .await
+    .attach("could not create acquaintance-of link entity")?;
 
     Ok(SeededEntities {
         alice,
         bob,
         organization,
-        friend_link: friend_link.metadata.record_id.entity_id,
+        friend_link,
+        acquaintance_link,
         draft_alice,
     })
 }
@@ -0,0 +1,155 @@
+//! Queries against the embeddings stored in the graph, served by [`StoredEmbeddings`].
+//!
+//! These run against a separate database, so that its additional entities don't appear in the
+//! results of the other tests.
+
+use alloc::sync::Arc;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_postgres_store::store::{AsClient as _, PostgresStore, PostgresStoreSettings};
+use hashql_compiletest::pipeline::Pipeline;
+use hashql_core::heap::Heap;
+use hashql_eval::orchestrator::StoredEmbeddings;
+use hashql_mir::interpret::{RuntimeConfig, value::Value};
+use tokio::runtime::Runtime;
+use tokio_postgres::Client;
+
+use crate::{
+    connect,
+    directives::AxisDirectives,
+    error::{SetupError, TestError},
+    execution::{self, PostgresClient},
+    inputs::build_inputs,
+    output::render_failure,
+    seed::{self, SeededEntities},
+};
+
+/// Name of the database the stored embeddings are queried from.
+const DATABASE: &str = "hash_stored_embeddings";
+
+/// Number of entities seeded in addition to the entities of [`seed::setup`].
+///
+/// Their rows exceed what the connection buffers for a single query, so that the query stalls if
+/// the embeddings are requested through the same connection before all rows have been received.
+const ENTITIES: usize = 2000;
+
+/// Counts all entities.
+const COUNT_ENTITIES: &str = r##"
+["::graph::tail::count",
+  ["::graph::head::entities", ["input", "temporal_axes", "_"]]
+]
+"##;
+
+/// Counts the entities with an embedding, which requires the vectors of every entity.
+const COUNT_EMBEDDED_ENTITIES: &str = r##"
+["::graph::tail::count",
+  ["::graph::body::filter",
+    ["::graph::head::entities", ["input", "temporal_axes", "_"]],
+    ["fn", { "#tuple": [] }, { "#struct": { "vertex": "_" } }, "_",
+      ["!=", "vertex.encodings.vectors", { "#list": [], "#type": "List<Number>" }]
+    ]
+  ]
+]
+"##;
+
+pub(crate) struct StoredContext {
+    store: PostgresStore<Client>,
+    entities: SeededEntities,
+}
+
+/// Creates and seeds the database of the stored embeddings, unless it already exists.
+///
+/// `client` is connected to the database created by `config`, which is used to connect to the
+/// database of the stored embeddings.
+pub(crate) async fn setup(
+    client: &Client,
+    mut config: tokio_postgres::Config,
+) -> Result<StoredContext, Report<SetupError>> {
+    let exists = client
+        .query_opt("SELECT 1 FROM pg_database WHERE datname = $1", &[&DATABASE])
+        .await
+        .change_context(SetupError::Connection)
+        .attach("could not query databases")?
+        .is_some();
+
+    if !exists {
+        client
+            .execute(&format!("CREATE DATABASE {DATABASE}"), &[])
+            .await
+            .change_context(SetupError::Connection)
+            .attach("could not create the database of the stored embeddings")?;
+    }
+
+    let client = connect(config.dbname(DATABASE)).await?;
+
+    let mut store = PostgresStore::new(client, None, Arc::new(PostgresStoreSettings::default()));
+    let entities = seed::setup(&mut store).await?;
+    seed::setup_embeddings(&mut store, &entities, ENTITIES).await?;
+
+    Ok(StoredContext { store, entities })
+}
+
+/// Runs `source` against the stored embeddings, returning the resulting count.
+fn count(
+    runtime: &Runtime,
+    context: &StoredContext,
+    source: &str,
+) -> Result<i128, Report<TestError>> {
+    let heap = Heap::new();
+    let mut pipeline = Pipeline::new(&heap);
+
+    let lowered = match execution::lower(&mut pipeline, source) {
+        Ok(lowered) => lowered,
+        Err(diagnostic) => {
+            let rendered = render_failure(source, &pipeline, &diagnostic);
+            return Err(Report::new(TestError::Execution).attach(rendered));
+        }
+    };
+
+    let inputs = build_inputs(
+        &heap,
+        &lowered.interner.symbols,
+        &context.entities,
+        &AxisDirectives::default(),
+    );
+
+    let client = context.store.as_client();
+    let outcome = execution::run(
+        &mut pipeline,
+        runtime,
+        client,
+        StoredEmbeddings::new(PostgresClient(client)),
+        RuntimeConfig::default(),
+        &inputs,
+        lowered,
+    );
+
+    match outcome {
+        Ok((Value::Integer(count), _)) => Ok(count.as_int()),
+        Ok((value, _)) => Err(Report::new(TestError::Execution)
+            .attach(format!("expected a count, the query returned {value:?}"))),
+        Err(diagnostic) => {
+            let rendered = render_failure(source, &pipeline, &diagnostic);
+            Err(Report::new(TestError::Execution).attach(rendered))
+        }
+    }
+}
+
+/// Filters more entities by their stored vectors than the connection buffers.
+///
+/// Every entity of the database has an embedding, so the filter accepts every entity.
+pub(crate) fn many_rows(
+    runtime: &Runtime,
+    context: &StoredContext,
+) -> Result<(), Report<TestError>> {
+    let entities = count(runtime, context, COUNT_ENTITIES)?;
+    let embedded = count(runtime, context, COUNT_EMBEDDED_ENTITIES)?;
+
+    if entities < ENTITIES as i128 || embedded != entities {
+        return Err(Report::new(TestError::OutputMismatch).attach(format!(
+            "expected all of the {entities} entities to have an embedding, but {embedded} have one"
+        )));
+    }
+
+    Ok(())
+}
@@ -64,7 +64,12 @@ use crate::{
     node::{
         HirId, Node, NodeData,
         access::{Access, FieldAccess, IndexAccess},
-        branch::{Branch, If},
+        branch::{
+            Branch, If, Match,
+            r#match::{
+                ConstructorPattern, MatchArm, OpaquePattern, Pattern, PatternKind, StructPattern,
+            },
+        },
         call::{Call, CallArgument},
         closure::{Closure, ClosureParam, ClosureSignature},
         data::{Data, Dict, DictField, List, Struct, StructField, Tuple},
@@ -75,8 +80,8 @@ use crate::{
         kind::NodeKind,
         r#let::{Binder, Binding, Let, VarId},
         operation::{
-            BinaryOperation, InputOperation, Operation, TypeAssertion, TypeConstructor,
-            TypeOperation, UnaryOperation,
+            BinaryOperation, InputOperation, IntrinsicOperation, Operation, TypeAssertion,
+            TypeConstructor, TypeOperation, UnaryOperation,
         },
         thunk::Thunk,
         variable::{LocalVariable, QualifiedVariable, Variable},
@@ -322,6 +327,13 @@ pub trait Fold<'heap> {
         walk_input_operation(self, operation)
     }
 
+    fn fold_intrinsic_operation(
+        &mut self,
+        operation: IntrinsicOperation<'heap>,
+    ) -> Self::Output<IntrinsicOperation<'heap>> {
+        walk_intrinsic_operation(self, operation)
+    }
+
     fn fold_access(&mut self, access: Access<'heap>) -> Self::Output<Access<'heap>> {
         walk_access(self, access)
     }
@@ -366,6 +378,32 @@ pub trait Fold<'heap> {
         walk_if(self, r#if)
     }
 
+    fn fold_match(&mut self, r#match: Match<'heap>) -> Self::Output<Match<'heap>> {
+        walk_match(self, r#match)
+    }
+
+    fn fold_match_arm(&mut self, arm: MatchArm<'heap>) -> Self::Output<MatchArm<'heap>> {
+        walk_match_arm(self, arm)
+    }
+
+    fn fold_match_arms(
+        &mut self,
+        arms: Interned<'heap, [MatchArm<'heap>]>,
+    ) -> Self::Output<Interned<'heap, [MatchArm<'heap>]>> {
+        walk_match_arms(self, arms)
+    }
+
+    fn fold_pattern(&mut self, pattern: Pattern<'heap>) -> Self::Output<Pattern<'heap>> {
+        walk_pattern(self, pattern)
+    }
+
+    fn fold_struct_pattern(
+        &mut self,
+        field: StructPattern<'heap>,
+    ) -> Self::Output<StructPattern<'heap>> {
+        walk_struct_pattern(self, field)
+    }
+
     fn fold_closure(&mut self, closure: Closure<'heap>) -> Self::Output<Closure<'heap>> {
         walk_closure(self, closure)
     }
@@ -424,7 +462,10 @@ pub trait Fold<'heap> {
         walk_graph_read_body_step(self, body)
     }
 
-    fn fold_graph_read_tail(&mut self, tail: GraphReadTail) -> Self::Output<GraphReadTail> {
+    fn fold_graph_read_tail(
+        &mut self,
+        tail: GraphReadTail<'heap>,
+    ) -> Self::Output<GraphReadTail<'heap>> {
         walk_graph_read_tail(self, tail)
     }
 }
@@ -708,6 +749,9 @@ pub fn walk_operation<'heap, T: Fold<'heap> + ?Sized>(
             Operation::Binary(visitor.fold_binary_operation(operation)?)
         }
         Operation::Input(operation) => Operation::Input(visitor.fold_input_operation(operation)?),
+        Operation::Intrinsic(operation) => {
+            Operation::Intrinsic(visitor.fold_intrinsic_operation(operation)?)
+        }
     };
 
     Try::from_output(operation)
@@ -799,6 +843,22 @@ pub fn walk_input_operation<'heap, T: Fold<'heap> + ?Sized>(
     Try::from_output(InputOperation { op, name })
 }
 
+pub fn walk_intrinsic_operation<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    IntrinsicOperation { op, arguments }: IntrinsicOperation<'heap>,
+) -> T::Output<IntrinsicOperation<'heap>> {
+    let op = Spanned {
+        span: visitor.fold_span(op.span)?,
+        value: op.value,
+    };
+
+    let mut arguments = Beef::new(arguments);
+    arguments.try_map::<_, T::Output<()>>(|argument| visitor.fold_nested_node(argument))?;
+    let arguments = arguments.finish(&visitor.interner().nodes);
+
+    Try::from_output(IntrinsicOperation { op, arguments })
+}
+
 pub fn walk_access<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
     access: Access<'heap>,
@@ -879,6 +939,7 @@ pub fn walk_branch<'heap, T: Fold<'heap> + ?Sized>(
 ) -> T::Output<Branch<'heap>> {
     let branch = match branch {
         Branch::If(r#if) => Branch::If(visitor.fold_if(r#if)?),
+        Branch::Match(r#match) => Branch::Match(visitor.fold_match(r#match)?),
     };
 
     Try::from_output(branch)
@@ -895,6 +956,101 @@ pub fn walk_if<'heap, T: Fold<'heap> + ?Sized>(
     Try::from_output(If { test, then, r#else })
 }
 
+pub fn walk_match<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    Match { scrutinee, arms }: Match<'heap>,
+) -> T::Output<Match<'heap>> {
+    let scrutinee = visitor.fold_node(scrutinee)?;
+    let arms = visitor.fold_match_arms(arms)?;
+
+    Try::from_output(Match { scrutinee, arms })
+}
+
+pub fn walk_match_arm<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    MatchArm {
+        span,
+        pattern,
+        body,
+    }: MatchArm<'heap>,
+) -> T::Output<MatchArm<'heap>> {
+    let span = visitor.fold_span(span)?;
+    let pattern = visitor.fold_pattern(pattern)?;
+    let body = visitor.fold_node(body)?;
+
+    Try::from_output(MatchArm {
+        span,
+        pattern,
+        body,
+    })
+}
+
+pub fn walk_match_arms<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    arms: Interned<'heap, [MatchArm<'heap>]>,
+) -> T::Output<Interned<'heap, [MatchArm<'heap>]>> {
+    let mut arms = Beef::new(arms);
+    arms.try_map::<_, T::Output<()>>(|arm| visitor.fold_match_arm(arm))?;
+
+    Try::from_output(arms.finish(&visitor.interner().match_arms))
+}
+
+pub fn walk_pattern<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    Pattern { id, span, kind }: Pattern<'heap>,
+) -> T::Output<Pattern<'heap>> {
+    let id = visitor.fold_hir_id(id)?;
+    let span = visitor.fold_span(span)?;
+
+    let kind = match kind {
+        PatternKind::Wildcard => PatternKind::Wildcard,
+        PatternKind::Binding(binder) => PatternKind::Binding(visitor.fold_binder(binder)?),
+        PatternKind::Literal(literal) => PatternKind::Literal(visitor.fold_primitive(literal)?),
+        PatternKind::Tuple(elements) => {
+            let mut elements = Beef::new(elements);
+            elements.try_map::<_, T::Output<()>>(|element| visitor.fold_pattern(element))?;
+
+            PatternKind::Tuple(elements.finish(&visitor.interner().patterns))
+        }
+        PatternKind::Struct(fields) => {
+            let mut fields = Beef::new(fields);
+            fields.try_map::<_, T::Output<()>>(|field| visitor.fold_struct_pattern(field))?;
+
+            PatternKind::Struct(fields.finish(&visitor.interner().struct_patterns))
+        }
+        PatternKind::Constructor(ConstructorPattern { path, pattern }) => {
+            let path = visitor.fold_qualified_path(path)?;
+            let pattern = visitor.fold_pattern(*pattern)?;
+
+            PatternKind::Constructor(ConstructorPattern {
+                path,
+                pattern: visitor.interner().intern_pattern(pattern),
+            })
+        }
+        PatternKind::Opaque(OpaquePattern { name, pattern }) => {
+            let name = visitor.fold_symbol(name)?;
+            let pattern = visitor.fold_pattern(*pattern)?;
+
+            PatternKind::Opaque(OpaquePattern {
+                name,
+                pattern: visitor.interner().intern_pattern(pattern),
+            })
+        }
+    };
+
+    Try::from_output(Pattern { id, span, kind })
+}
+
+pub fn walk_struct_pattern<'heap, T: Fold<'heap> + ?Sized>(
+    visitor: &mut T,
+    StructPattern { name, pattern }: StructPattern<'heap>,
+) -> T::Output<StructPattern<'heap>> {
+    let name = visitor.fold_ident(name)?;
+    let pattern = visitor.fold_pattern(pattern)?;
+
+    Try::from_output(StructPattern { name, pattern })
+}
+
 pub fn walk_closure<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
     Closure { signature, body }: Closure<'heap>,
@@ -976,12 +1132,9 @@ pub fn walk_graph_read_head<'heap, T: Fold<'heap> + ?Sized>(
     visitor: &mut T,
     head: GraphReadHead<'heap>,
 ) -> T::Output<GraphReadHead<'heap>> {
-    match head {
-        GraphReadHead::Entity { axis } => {
-            let axis = visitor.fold_nested_node(axis)?;
-            Try::from_output(GraphReadHead::Entity { axis })
-        }
-    }
+    let axis = visitor.fold_nested_node(head.axis())?;
+
+    Try::from_output(head.with_axis(axis))
 }
 
 pub fn walk_graph_read_body<'heap, T: Fold<'heap> + ?Sized>(
@@ -1007,13 +1160,32 @@ pub fn walk_graph_read_body_step<'heap, T: Fold<'heap> + ?Sized>(
             let node = visitor.fold_nested_node(node)?;
             Try::from_output(GraphReadBody::Filter(node))
         }
+        GraphReadBody::Traverse(traversal) => Try::from_output(GraphReadBody::Traverse(traversal)),
+        GraphReadBody::SortBy(node) => {
+            let node = visitor.fold_nested_node(node)?;
+            Try::from_output(GraphReadBody::SortBy(node))
+        }
+        GraphReadBody::Limit(count) => Try::from_output(GraphReadBody::Limit(count)),
+        GraphReadBody::Offset(count) => Try::from_output(GraphReadBody::Offset(count)),
     }
 }
 
-#[must_use]
 pub fn walk_graph_read_tail<'heap, T: Fold<'heap> + ?Sized>(
-    _: &mut T,
-    tail: GraphReadTail,
-) -> T::Output<GraphReadTail> {
-    Try::from_output(tail)
+    visitor: &mut T,
+    tail: GraphReadTail<'heap>,
+) -> T::Output<GraphReadTail<'heap>> {
+    match tail {
+        GraphReadTail::Collect
+        | GraphReadTail::Count
+        | GraphReadTail::Exists
+        | GraphReadTail::First => Try::from_output(tail),
+        GraphReadTail::GroupBy(node) => {
+            let node = visitor.fold_nested_node(node)?;
+            Try::from_output(GraphReadTail::GroupBy(node))
+        }
+        GraphReadTail::Aggregate(aggregate, node) => {
+            let node = visitor.fold_nested_node(node)?;
+            Try::from_output(GraphReadTail::Aggregate(aggregate, node))
+        }
+    }
 }
@@ -8,6 +8,7 @@ use hashql_core::{
 
 use crate::node::{
     Node, NodeData,
+    branch::r#match::{MatchArm, Pattern, StructPattern},
     call::CallArgument,
     closure::ClosureParam,
     data::{DictField, StructField},
@@ -24,8 +25,12 @@ pub struct Interner<'heap> {
     pub call_arguments: InternSet<'heap, [CallArgument<'heap>]>,
     pub graph_read_body: InternSet<'heap, [GraphReadBody<'heap>]>,
     pub bindings: InternSet<'heap, [Binding<'heap>]>,
+    pub match_arms: InternSet<'heap, [MatchArm<'heap>]>,
+    pub patterns: InternSet<'heap, [Pattern<'heap>]>,
+    pub struct_patterns: InternSet<'heap, [StructPattern<'heap>]>,
 
     pub node: InternSet<'heap, NodeData<'heap>>,
+    pub pattern: InternSet<'heap, Pattern<'heap>>,
 
     struct_fields: InternSet<'heap, [StructField<'heap>]>,
     pub dict_fields: InternSet<'heap, [DictField<'heap>]>,
@@ -44,8 +49,12 @@ impl<'heap> Interner<'heap> {
             struct_fields: InternSet::new(heap),
             dict_fields: InternSet::new(heap),
             bindings: InternSet::new(heap),
+            match_arms: InternSet::new(heap),
+            patterns: InternSet::new(heap),
+            struct_patterns: InternSet::new(heap),
 
             node: InternSet::new(heap),
+            pattern: InternSet::new(heap),
         }
     }
 
@@ -113,7 +122,32 @@ impl<'heap> Interner<'heap> {
         self.dict_fields.intern_slice(fields)
     }
 
+    pub fn intern_match_arms(
+        &self,
+        arms: &[MatchArm<'heap>],
+    ) -> Interned<'heap, [MatchArm<'heap>]> {
+        self.match_arms.intern_slice(arms)
+    }
+
+    pub fn intern_patterns(
+        &self,
+        patterns: &[Pattern<'heap>],
+    ) -> Interned<'heap, [Pattern<'heap>]> {
+        self.patterns.intern_slice(patterns)
+    }
+
+    pub fn intern_struct_patterns(
+        &self,
+        fields: &[StructPattern<'heap>],
+    ) -> Interned<'heap, [StructPattern<'heap>]> {
+        self.struct_patterns.intern_slice(fields)
+    }
+
     pub fn intern_node(&self, node: NodeData<'heap>) -> Interned<'heap, NodeData<'heap>> {
         self.node.intern(node)
     }
+
+    pub fn intern_pattern(&self, pattern: Pattern<'heap>) -> Interned<'heap, Pattern<'heap>> {
+        self.pattern.intern(pattern)
+    }
 }
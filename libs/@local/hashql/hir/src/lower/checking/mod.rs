@@ -1,3 +1,5 @@
+mod usefulness;
+
 use core::fmt::Display;
 
 use hashql_core::{
@@ -21,10 +23,11 @@ use hashql_core::{
 };
 use hashql_diagnostics::DiagnosticIssues;
 
+use self::usefulness::{DeconstructedPattern, Usefulness};
 use super::{
     error::{
         GenericArgumentContext, LoweringDiagnosticCategory, LoweringDiagnosticIssues,
-        LoweringDiagnosticStatus, type_mismatch_if,
+        LoweringDiagnosticStatus, non_exhaustive_match, type_mismatch_if, unreachable_match_arm,
     },
     inference::{Local, TypeInferenceResidual},
 };
@@ -34,15 +37,15 @@ use crate::{
     node::{
         HirId, HirIdMap, HirIdSet, HirPtr, Node,
         access::{FieldAccess, IndexAccess},
-        branch::If,
+        branch::{If, Match, r#match::Pattern},
         call::Call,
         closure::{Closure, extract_signature},
         data::{Dict, List, Struct, Tuple},
         graph::Graph,
         r#let::{Let, VarIdMap},
         operation::{
-            BinaryOperation, InputOp, InputOperation, TypeAssertion, TypeConstructor,
-            UnaryOperation,
+            BinaryOperation, InputOp, InputOperation, IntrinsicOperation, TypeAssertion,
+            TypeConstructor, UnaryOperation,
         },
         thunk::Thunk,
         variable::{LocalVariable, QualifiedVariable},
@@ -189,6 +192,57 @@ impl<'ctx, 'env, 'hir, 'heap> TypeChecking<'ctx, 'env, 'hir, 'heap> {
         })
     }
 
+    /// Transfers the simplified types of a pattern and all of its sub-patterns.
+    fn transfer_pattern(&mut self, pattern: &'heap Pattern<'heap>) {
+        struct TransferPattern<'this, 'ctx, 'env, 'hir, 'heap>(
+            &'this mut TypeChecking<'ctx, 'env, 'hir, 'heap>,
+        );
+
+        impl<'heap> Visitor<'heap> for TransferPattern<'_, '_, '_, '_, 'heap> {
+            fn visit_pattern(&mut self, pattern: &'heap Pattern<'heap>) {
+                visit::walk_pattern(self, pattern);
+
+                self.0.transfer_type(pattern.id);
+            }
+        }
+
+        TransferPattern(self).visit_pattern(pattern);
+    }
+
+    /// Checks that a `match` expression is exhaustive and that every arm is reachable.
+    fn check_match(&mut self, r#match: &'heap Match<'heap>) {
+        let usefulness = Usefulness::new(self.env);
+        let scrutinee = self.context.map.type_id(r#match.scrutinee.id);
+
+        let mut matrix: Vec<Vec<DeconstructedPattern<'heap>>> =
+            Vec::with_capacity(r#match.arms.len());
+
+        for arm in r#match.arms {
+            let pattern =
+                usefulness.deconstruct(&arm.pattern, &mut |id| self.context.map.type_id(id));
+            let row = vec![pattern];
+
+            if !usefulness.is_useful(&matrix, &row, &[scrutinee]) {
+                self.diagnostics
+                    .push(unreachable_match_arm(arm.pattern.span));
+            }
+
+            matrix.push(row);
+        }
+
+        if let Some(witness) = usefulness.witness(&matrix, &[scrutinee]) {
+            let [witness] = &*witness else {
+                unreachable!("witness has exactly one pattern per column");
+            };
+
+            self.diagnostics.push(non_exhaustive_match(
+                self.current.span,
+                r#match.scrutinee.span,
+                witness,
+            ));
+        }
+    }
+
     /// Finalizes the type checking process and returns the collected results.
     ///
     /// # Errors
@@ -357,6 +411,10 @@ impl<'heap> Visitor<'heap> for TypeChecking<'_, '_, '_, 'heap> {
         unreachable!("access operations shouldn't be present yet");
     }
 
+    fn visit_intrinsic_operation(&mut self, _: &'heap IntrinsicOperation<'heap>) {
+        unreachable!("intrinsic operations shouldn't be present yet");
+    }
+
     fn visit_input_operation(&mut self, operation: &'heap InputOperation<'heap>) {
         self.transfer_type(self.current.id); // We just need to transfer the (simplified) type of the current input
 
@@ -447,6 +505,19 @@ impl<'heap> Visitor<'heap> for TypeChecking<'_, '_, '_, 'heap> {
         self.transfer_type(self.current.id);
     }
 
+    fn visit_match(&mut self, r#match: &'heap Match<'heap>) {
+        self.visit_node(r#match.scrutinee);
+
+        for arm in r#match.arms {
+            self.transfer_pattern(&arm.pattern);
+            self.visit_node(arm.body);
+        }
+
+        self.check_match(r#match);
+
+        self.transfer_type(self.current.id);
+    }
+
     fn visit_closure(&mut self, closure: &'heap Closure<'heap>) {
         visit::walk_closure(self, closure);
 
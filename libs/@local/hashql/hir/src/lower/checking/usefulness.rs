@@ -0,0 +1,881 @@
+//! Usefulness analysis of `match` arms.
+//!
+//! Implements the usefulness algorithm described in "Warnings for pattern matching" (Maranget,
+//! 2007). A pattern is *useful* with respect to a list of patterns if there is a value that is
+//! matched by the pattern, but by none of the patterns in the list. Using this definition:
+//!
+//! - an arm is unreachable if its pattern is not useful with respect to all previous arms, and
+//! - a match is exhaustive if a wildcard is not useful with respect to all arms.
+//!
+//! The analysis is driven by the (simplified) type of the scrutinee. Types that consist of a
+//! finite set of constructors, like `Boolean`, `Null`, tuples, structs, opaque types and unions
+//! thereof, can be matched exhaustively. Every other type, for example `Integer` or `String`, has
+//! infinitely many values and can only be covered by a wildcard or binding.
+
+use core::fmt::{self, Display};
+
+use hashql_core::{
+    symbol::Symbol,
+    r#type::{
+        TypeBuilder, TypeId,
+        environment::Environment,
+        kind::{OpaqueType, PrimitiveType, StructType, TupleType, TypeKind, UnionType},
+    },
+    value::Primitive,
+};
+
+use crate::node::{
+    HirId,
+    branch::r#match::{OpaquePattern, Pattern, PatternKind},
+};
+
+/// A constructor of a value, which is used to split the space of possible values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Constructor<'heap> {
+    Opaque(Symbol<'heap>),
+    Boolean(bool),
+    Null,
+    Tuple(usize),
+    /// A struct with the given (sorted) field names.
+    Struct(Vec<Symbol<'heap>>),
+    /// A literal of a type with infinitely many values, such as `Integer` or `String`.
+    Literal(Primitive<'heap>),
+}
+
+impl Constructor<'_> {
+    const fn arity(&self) -> usize {
+        match self {
+            Self::Opaque(_) => 1,
+            Self::Boolean(_) | Self::Null | Self::Literal(_) => 0,
+            Self::Tuple(length) => *length,
+            Self::Struct(fields) => fields.len(),
+        }
+    }
+}
+
+/// A pattern reduced to the parts relevant to usefulness analysis.
+///
+/// Bindings are indistinguishable from wildcards, as both match every value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DeconstructedPattern<'heap> {
+    Wildcard,
+    Constructor(Constructor<'heap>, Vec<Self>),
+}
+
+impl Display for DeconstructedPattern<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self::Constructor(constructor, fields) = self else {
+            return fmt.write_str("_");
+        };
+
+        match constructor {
+            Constructor::Opaque(name) => match fields.as_slice() {
+                // Constructors over `Null` do not take a value
+                [Self::Constructor(Constructor::Null, _)] => Display::fmt(name, fmt),
+                [field] => write!(fmt, "{name}({field})"),
+                _ => unreachable!("opaque constructors have exactly one field"),
+            },
+            Constructor::Boolean(value) => Display::fmt(value, fmt),
+            Constructor::Null => fmt.write_str("null"),
+            Constructor::Tuple(_) => {
+                fmt.write_str("(")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+
+                    Display::fmt(field, fmt)?;
+                }
+
+                if fields.len() == 1 {
+                    fmt.write_str(",")?;
+                }
+
+                fmt.write_str(")")
+            }
+            Constructor::Struct(names) => {
+                fmt.write_str("(")?;
+                for (index, (name, field)) in names.iter().zip(fields).enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+
+                    write!(fmt, "{name}: {field}")?;
+                }
+
+                fmt.write_str(")")
+            }
+            Constructor::Literal(_) => {
+                unreachable!("literal constructors are never part of a witness")
+            }
+        }
+    }
+}
+
+/// The set of constructors of a type.
+struct Signature<'heap> {
+    constructors: Vec<(Constructor<'heap>, Vec<TypeId>)>,
+    /// Whether the constructors cover every value of the type.
+    is_complete: bool,
+}
+
+type Row<'heap> = Vec<DeconstructedPattern<'heap>>;
+
+pub(crate) struct Usefulness<'env, 'heap> {
+    env: &'env Environment<'heap>,
+    unknown: TypeId,
+}
+
+impl<'env, 'heap> Usefulness<'env, 'heap> {
+    pub(crate) fn new(env: &'env Environment<'heap>) -> Self {
+        Self {
+            env,
+            unknown: TypeBuilder::synthetic(env).unknown(),
+        }
+    }
+
+    /// Converts a pattern into its deconstructed form.
+    ///
+    /// `type_of` returns the (simplified) type of the value a pattern is matched against, which is
+    /// used to determine the complete set of fields of struct patterns.
+    pub(crate) fn deconstruct(
+        &self,
+        pattern: &Pattern<'heap>,
+        type_of: &mut impl FnMut(HirId) -> TypeId,
+    ) -> DeconstructedPattern<'heap> {
+        let (constructor, fields) = match &pattern.kind {
+            PatternKind::Wildcard | PatternKind::Binding(_) => {
+                return DeconstructedPattern::Wildcard;
+            }
+            PatternKind::Literal(Primitive::Boolean(value)) => {
+                (Constructor::Boolean(*value), Vec::new())
+            }
+            PatternKind::Literal(Primitive::Null) => (Constructor::Null, Vec::new()),
+            &PatternKind::Literal(literal) => (Constructor::Literal(literal), Vec::new()),
+            PatternKind::Tuple(elements) => (
+                Constructor::Tuple(elements.len()),
+                elements
+                    .iter()
+                    .map(|element| self.deconstruct(element, type_of))
+                    .collect(),
+            ),
+            PatternKind::Struct(patterns) => {
+                // Fields which aren't mentioned in the pattern are matched by a wildcard
+                let mut names: Vec<_> = self.struct_type(type_of(pattern.id)).map_or_else(
+                    || patterns.iter().map(|field| field.name.value).collect(),
+                    |r#type| r#type.fields.iter().map(|field| field.name).collect(),
+                );
+                names.sort_unstable();
+
+                let fields = names
+                    .iter()
+                    .map(|&name| {
+                        patterns
+                            .iter()
+                            .find(|field| field.name.value == name)
+                            .map_or(DeconstructedPattern::Wildcard, |field| {
+                                self.deconstruct(&field.pattern, type_of)
+                            })
+                    })
+                    .collect();
+
+                (Constructor::Struct(names), fields)
+            }
+            PatternKind::Constructor(_) => {
+                unreachable!("constructor patterns should have been converted prior to this point")
+            }
+            PatternKind::Opaque(OpaquePattern { name, pattern }) => (
+                Constructor::Opaque(*name),
+                vec![self.deconstruct(pattern, type_of)],
+            ),
+        };
+
+        DeconstructedPattern::Constructor(constructor, fields)
+    }
+
+    /// Removes any generic wrappers around a type.
+    fn peel(&self, mut id: TypeId) -> TypeId {
+        loop {
+            #[expect(clippy::wildcard_enum_match_arm)]
+            match self.env.r#type(id).kind {
+                TypeKind::Apply(apply) => id = apply.base,
+                TypeKind::Generic(generic) => id = generic.base,
+                _ => return id,
+            }
+        }
+    }
+
+    fn struct_type(&self, id: TypeId) -> Option<&'heap StructType<'heap>> {
+        #[expect(clippy::wildcard_enum_match_arm)]
+        match self.env.r#type(self.peel(id)).kind {
+            TypeKind::Struct(r#struct) => Some(r#struct),
+            _ => None,
+        }
+    }
+
+    fn collect_signature(&self, id: TypeId, signature: &mut Signature<'heap>) {
+        #[expect(clippy::wildcard_enum_match_arm)]
+        match self.env.r#type(self.peel(id)).kind {
+            TypeKind::Union(UnionType { variants }) => {
+                for &variant in variants {
+                    self.collect_signature(variant, signature);
+                }
+            }
+            &TypeKind::Opaque(OpaqueType { name, repr }) => signature
+                .constructors
+                .push((Constructor::Opaque(name), vec![repr])),
+            TypeKind::Primitive(PrimitiveType::Boolean) => {
+                signature
+                    .constructors
+                    .push((Constructor::Boolean(true), Vec::new()));
+                signature
+                    .constructors
+                    .push((Constructor::Boolean(false), Vec::new()));
+            }
+            TypeKind::Primitive(PrimitiveType::Null) => {
+                signature.constructors.push((Constructor::Null, Vec::new()));
+            }
+            TypeKind::Tuple(TupleType { fields }) => signature
+                .constructors
+                .push((Constructor::Tuple(fields.len()), fields.to_vec())),
+            TypeKind::Struct(StructType { fields }) => signature.constructors.push((
+                Constructor::Struct(fields.iter().map(|field| field.name).collect()),
+                fields.iter().map(|field| field.value).collect(),
+            )),
+            // Every other type has infinitely many values, or values which cannot be
+            // distinguished by a pattern.
+            _ => signature.is_complete = false,
+        }
+    }
+
+    fn signature(&self, id: TypeId) -> Signature<'heap> {
+        let mut signature = Signature {
+            constructors: Vec::new(),
+            is_complete: true,
+        };
+
+        self.collect_signature(id, &mut signature);
+
+        // A type without any constructor (like `Never`) is treated as opaque, so that diagnostics
+        // of previous errors do not cascade into spurious unreachable arms.
+        if signature.constructors.is_empty() {
+            signature.is_complete = false;
+        }
+
+        signature.constructors.dedup_by(|lhs, rhs| lhs.0 == rhs.0);
+        signature
+    }
+
+    fn field_types(
+        &self,
+        signature: &Signature<'heap>,
+        constructor: &Constructor<'heap>,
+    ) -> Vec<TypeId> {
+        signature
+            .constructors
+            .iter()
+            .find(|(candidate, _)| candidate == constructor)
+            .map_or_else(
+                || vec![self.unknown; constructor.arity()],
+                |(_, fields)| fields.clone(),
+            )
+    }
+
+    /// Specializes the matrix by the given constructor.
+    ///
+    /// Keeps every row whose first pattern matches the constructor, and replaces the first pattern
+    /// with the patterns of its fields.
+    fn specialize(matrix: &[Row<'heap>], constructor: &Constructor<'heap>) -> Vec<Row<'heap>> {
+        matrix
+            .iter()
+            .filter_map(|row| {
+                let (head, tail) = row.split_first()?;
+
+                let mut specialized = match head {
+                    DeconstructedPattern::Wildcard => {
+                        vec![DeconstructedPattern::Wildcard; constructor.arity()]
+                    }
+                    DeconstructedPattern::Constructor(candidate, fields)
+                        if candidate == constructor =>
+                    {
+                        fields.clone()
+                    }
+                    DeconstructedPattern::Constructor(..) => return None,
+                };
+
+                specialized.extend_from_slice(tail);
+                Some(specialized)
+            })
+            .collect()
+    }
+
+    /// Computes the default matrix, which consists of all rows that start with a wildcard.
+    fn default(matrix: &[Row<'heap>]) -> Vec<Row<'heap>> {
+        matrix
+            .iter()
+            .filter_map(|row| match row.split_first()? {
+                (DeconstructedPattern::Wildcard, tail) => Some(tail.to_vec()),
+                (DeconstructedPattern::Constructor(..), _) => None,
+            })
+            .collect()
+    }
+
+    fn head_constructors<'matrix>(
+        matrix: &'matrix [Row<'heap>],
+    ) -> impl Iterator<Item = &'matrix Constructor<'heap>> {
+        matrix.iter().filter_map(|row| match row.first() {
+            Some(DeconstructedPattern::Constructor(constructor, _)) => Some(constructor),
+            Some(DeconstructedPattern::Wildcard) | None => None,
+        })
+    }
+
+    fn with_tail(mut fields: Vec<TypeId>, tail: &[TypeId]) -> Vec<TypeId> {
+        fields.extend_from_slice(tail);
+        fields
+    }
+
+    /// Returns `true` if `row` matches a value that isn't matched by any row of the `matrix`.
+    pub(crate) fn is_useful(
+        &self,
+        matrix: &[Row<'heap>],
+        row: &[DeconstructedPattern<'heap>],
+        types: &[TypeId],
+    ) -> bool {
+        let Some((head, tail)) = row.split_first() else {
+            return matrix.is_empty();
+        };
+
+        let signature = self.signature(types[0]);
+
+        match head {
+            DeconstructedPattern::Constructor(constructor, fields) => {
+                if signature.is_complete
+                    && !signature
+                        .constructors
+                        .iter()
+                        .any(|(candidate, _)| candidate == constructor)
+                {
+                    // The constructor can never occur for a value of this type
+                    return false;
+                }
+
+                let mut specialized = fields.clone();
+                specialized.extend_from_slice(tail);
+
+                self.is_useful(
+                    &Self::specialize(matrix, constructor),
+                    &specialized,
+                    &Self::with_tail(self.field_types(&signature, constructor), &types[1..]),
+                )
+            }
+            DeconstructedPattern::Wildcard => {
+                let is_covered = signature.is_complete
+                    && signature.constructors.iter().all(|(constructor, _)| {
+                        Self::head_constructors(matrix).any(|used| used == constructor)
+                    });
+
+                if !is_covered {
+                    return self.is_useful(&Self::default(matrix), tail, &types[1..]);
+                }
+
+                signature.constructors.iter().any(|(constructor, fields)| {
+                    let mut specialized = vec![DeconstructedPattern::Wildcard; constructor.arity()];
+                    specialized.extend_from_slice(tail);
+
+                    self.is_useful(
+                        &Self::specialize(matrix, constructor),
+                        &specialized,
+                        &Self::with_tail(fields.clone(), &types[1..]),
+                    )
+                })
+            }
+        }
+    }
+
+    /// Returns a value which isn't matched by any row of the `matrix`, if one exists.
+    ///
+    /// The returned witness contains one pattern for each of the given `types`.
+    pub(crate) fn witness(
+        &self,
+        matrix: &[Row<'heap>],
+        types: &[TypeId],
+    ) -> Option<Vec<DeconstructedPattern<'heap>>> {
+        let Some((&head, tail)) = types.split_first() else {
+            return matrix.is_empty().then(Vec::new);
+        };
+
+        let signature = self.signature(head);
+
+        let missing = signature.constructors.iter().find(|(constructor, _)| {
+            !Self::head_constructors(matrix).any(|used| used == constructor)
+        });
+
+        if !signature.is_complete || missing.is_some() {
+            let mut witness = self.witness(&Self::default(matrix), tail)?;
+
+            let pattern = match missing {
+                // If no constructor has been used at all, a wildcard is the most descriptive
+                // witness.
+                Some((constructor, fields)) if Self::head_constructors(matrix).next().is_some() => {
+                    DeconstructedPattern::Constructor(
+                        constructor.clone(),
+                        fields.iter().map(|&field| self.wildcard(field)).collect(),
+                    )
+                }
+                Some(_) | None => DeconstructedPattern::Wildcard,
+            };
+
+            witness.insert(0, pattern);
+            return Some(witness);
+        }
+
+        signature
+            .constructors
+            .iter()
+            .find_map(|(constructor, fields)| {
+                let mut witness = self.witness(
+                    &Self::specialize(matrix, constructor),
+                    &Self::with_tail(fields.clone(), tail),
+                )?;
+
+                let rest = witness.split_off(constructor.arity());
+                let mut result = vec![DeconstructedPattern::Constructor(
+                    constructor.clone(),
+                    witness,
+                )];
+                result.extend(rest);
+
+                Some(result)
+            })
+    }
+
+    /// Creates a pattern matching any value of the given type, as used in witnesses.
+    fn wildcard(&self, id: TypeId) -> DeconstructedPattern<'heap> {
+        if matches!(
+            self.env.r#type(self.peel(id)).kind,
+            TypeKind::Primitive(PrimitiveType::Null)
+        ) {
+            DeconstructedPattern::Constructor(Constructor::Null, Vec::new())
+        } else {
+            DeconstructedPattern::Wildcard
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hashql_core::{
+        heap::Heap,
+        r#type::{TypeBuilder, TypeId, environment::Environment},
+        value::{Integer, Primitive},
+    };
+
+    use super::{Constructor, DeconstructedPattern, Row, Usefulness};
+
+    const fn wildcard() -> DeconstructedPattern<'static> {
+        DeconstructedPattern::Wildcard
+    }
+
+    const fn boolean(value: bool) -> DeconstructedPattern<'static> {
+        DeconstructedPattern::Constructor(Constructor::Boolean(value), Vec::new())
+    }
+
+    const fn null() -> DeconstructedPattern<'static> {
+        DeconstructedPattern::Constructor(Constructor::Null, Vec::new())
+    }
+
+    fn tuple<'heap>(
+        fields: impl IntoIterator<Item = DeconstructedPattern<'heap>>,
+    ) -> DeconstructedPattern<'heap> {
+        let fields: Vec<_> = fields.into_iter().collect();
+
+        DeconstructedPattern::Constructor(Constructor::Tuple(fields.len()), fields)
+    }
+
+    /// Creates a struct pattern, the fields need to be sorted by name.
+    fn r#struct<'heap>(
+        heap: &'heap Heap,
+        fields: impl IntoIterator<Item = (&'static str, DeconstructedPattern<'heap>)>,
+    ) -> DeconstructedPattern<'heap> {
+        let (names, fields): (Vec<_>, Vec<_>) = fields
+            .into_iter()
+            .map(|(name, field)| (heap.intern_symbol(name), field))
+            .unzip();
+
+        DeconstructedPattern::Constructor(Constructor::Struct(names), fields)
+    }
+
+    fn opaque<'heap>(
+        heap: &'heap Heap,
+        name: &str,
+        pattern: DeconstructedPattern<'heap>,
+    ) -> DeconstructedPattern<'heap> {
+        DeconstructedPattern::Constructor(
+            Constructor::Opaque(heap.intern_symbol(name)),
+            vec![pattern],
+        )
+    }
+
+    fn integer<'heap>(heap: &'heap Heap, value: &str) -> DeconstructedPattern<'heap> {
+        let integer = Integer::new_unchecked(heap.intern_symbol(value));
+
+        DeconstructedPattern::Constructor(
+            Constructor::Literal(Primitive::Integer(integer)),
+            Vec::new(),
+        )
+    }
+
+    /// The outcome of checking the arms of a `match` over a value of a single type.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Outcome {
+        /// The indices of the arms that can never be reached.
+        unreachable: Vec<usize>,
+        /// A value that isn't matched by any arm, if the match is not exhaustive.
+        missing: Option<String>,
+    }
+
+    /// Checks the arms in the same way as a `match` expression is checked.
+    fn check<'heap>(
+        env: &Environment<'heap>,
+        r#type: TypeId,
+        arms: impl IntoIterator<Item = DeconstructedPattern<'heap>>,
+    ) -> Outcome {
+        let usefulness = Usefulness::new(env);
+
+        let mut matrix: Vec<Row<'heap>> = Vec::new();
+        let mut unreachable = Vec::new();
+
+        for (index, pattern) in arms.into_iter().enumerate() {
+            let row = vec![pattern];
+
+            if !usefulness.is_useful(&matrix, &row, &[r#type]) {
+                unreachable.push(index);
+            }
+
+            matrix.push(row);
+        }
+
+        let missing = usefulness.witness(&matrix, &[r#type]).map(|witness| {
+            let [witness] = &*witness else {
+                panic!("witness should have exactly one pattern, found {witness:?}");
+            };
+
+            witness.to_string()
+        });
+
+        Outcome {
+            unreachable,
+            missing,
+        }
+    }
+
+    fn exhaustive() -> Outcome {
+        Outcome {
+            unreachable: Vec::new(),
+            missing: None,
+        }
+    }
+
+    fn missing(witness: &str) -> Outcome {
+        Outcome {
+            unreachable: Vec::new(),
+            missing: Some(witness.to_owned()),
+        }
+    }
+
+    fn unreachable(arms: impl IntoIterator<Item = usize>) -> Outcome {
+        Outcome {
+            unreachable: arms.into_iter().collect(),
+            missing: None,
+        }
+    }
+
+    #[test]
+    fn booleans() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        let r#type = builder.boolean();
+
+        assert_eq!(
+            check(&env, r#type, [boolean(true), boolean(false)]),
+            exhaustive()
+        );
+        assert_eq!(check(&env, r#type, [boolean(false)]), missing("true"));
+        assert_eq!(check(&env, r#type, [boolean(true)]), missing("false"));
+        assert_eq!(check(&env, r#type, []), missing("_"));
+        assert_eq!(
+            check(&env, r#type, [boolean(true), wildcard(), boolean(false)]),
+            unreachable([2])
+        );
+        assert_eq!(
+            check(&env, r#type, [boolean(true), boolean(true), boolean(false)]),
+            unreachable([1])
+        );
+    }
+
+    #[test]
+    fn infinite_types_require_a_wildcard() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        let r#type = builder.integer();
+
+        assert_eq!(
+            check(&env, r#type, [integer(&heap, "1"), integer(&heap, "2")]),
+            missing("_")
+        );
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [integer(&heap, "1"), integer(&heap, "1"), wildcard()]
+            ),
+            unreachable([1])
+        );
+        assert_eq!(
+            check(&env, r#type, [wildcard(), integer(&heap, "1")]),
+            unreachable([1])
+        );
+    }
+
+    #[test]
+    fn nested_tuples() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        // ((Boolean, Boolean), Boolean)
+        let r#type = builder.tuple([
+            builder.tuple([builder.boolean(), builder.boolean()]),
+            builder.boolean(),
+        ]);
+
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    tuple([tuple([boolean(true), wildcard()]), wildcard()]),
+                    tuple([tuple([boolean(false), boolean(true)]), wildcard()]),
+                    tuple([tuple([wildcard(), wildcard()]), boolean(true)]),
+                ]
+            ),
+            missing("((false, false), false)")
+        );
+
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    tuple([tuple([boolean(true), wildcard()]), wildcard()]),
+                    tuple([tuple([boolean(false), boolean(true)]), wildcard()]),
+                    tuple([wildcard(), boolean(true)]),
+                    tuple([tuple([boolean(false), boolean(false)]), wildcard()]),
+                    // Every value has been matched by the previous arms
+                    tuple([tuple([boolean(false), wildcard()]), boolean(false)]),
+                ]
+            ),
+            unreachable([4])
+        );
+    }
+
+    #[test]
+    fn single_element_tuple_witness() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        let r#type = builder.tuple([builder.boolean()]);
+
+        assert_eq!(
+            check(&env, r#type, [tuple([boolean(true)])]),
+            missing("(false,)")
+        );
+    }
+
+    #[test]
+    fn nested_structs() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        // (a: Boolean, b: (c: Boolean, d: Integer))
+        let r#type = builder.r#struct([
+            ("a", builder.boolean()),
+            (
+                "b",
+                builder.r#struct([("c", builder.boolean()), ("d", builder.integer())]),
+            ),
+        ]);
+
+        let inner = |lhs, rhs| r#struct(&heap, [("c", lhs), ("d", rhs)]);
+
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    r#struct(&heap, [("a", boolean(true)), ("b", wildcard())]),
+                    r#struct(
+                        &heap,
+                        [
+                            ("a", boolean(false)),
+                            ("b", inner(boolean(true), wildcard()))
+                        ]
+                    ),
+                ]
+            ),
+            missing("(a: false, b: (c: false, d: _))")
+        );
+
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    r#struct(
+                        &heap,
+                        [("a", wildcard()), ("b", inner(boolean(true), wildcard()))]
+                    ),
+                    r#struct(
+                        &heap,
+                        [("a", wildcard()), ("b", inner(boolean(false), wildcard()))]
+                    ),
+                    r#struct(&heap, [("a", boolean(true)), ("b", wildcard())]),
+                ]
+            ),
+            unreachable([2])
+        );
+
+        // A literal never covers every value of a type with infinitely many values
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    r#struct(
+                        &heap,
+                        [("a", wildcard()), ("b", inner(boolean(true), wildcard()))]
+                    ),
+                    r#struct(
+                        &heap,
+                        [
+                            ("a", wildcard()),
+                            ("b", inner(boolean(false), integer(&heap, "0")))
+                        ]
+                    ),
+                ]
+            ),
+            missing("(a: _, b: (c: false, d: _))")
+        );
+    }
+
+    #[test]
+    fn opaque_unions() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        // Some(Integer) | None
+        let r#type = builder.union([
+            builder.opaque("::core::option::Some", builder.integer()),
+            builder.opaque("::core::option::None", builder.null()),
+        ]);
+
+        let some = |pattern| opaque(&heap, "::core::option::Some", pattern);
+        let none = || opaque(&heap, "::core::option::None", null());
+
+        assert_eq!(
+            check(&env, r#type, [some(wildcard()), none()]),
+            exhaustive()
+        );
+        assert_eq!(check(&env, r#type, [none(), wildcard()]), exhaustive());
+        assert_eq!(
+            check(&env, r#type, [some(wildcard())]),
+            missing("::core::option::None")
+        );
+        assert_eq!(
+            check(&env, r#type, [none(), some(integer(&heap, "1"))]),
+            missing("::core::option::Some(_)")
+        );
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [some(wildcard()), none(), some(integer(&heap, "1"))]
+            ),
+            unreachable([2])
+        );
+        assert_eq!(check(&env, r#type, [wildcard(), none()]), unreachable([1]));
+    }
+
+    #[test]
+    fn nested_opaque_unions() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        // Ok(Some(Boolean) | None) | Err(String)
+        let option = builder.union([
+            builder.opaque("::core::option::Some", builder.boolean()),
+            builder.opaque("::core::option::None", builder.null()),
+        ]);
+        let r#type = builder.union([
+            builder.opaque("::core::result::Ok", option),
+            builder.opaque("::core::result::Err", builder.string()),
+        ]);
+
+        let ok = |pattern| opaque(&heap, "::core::result::Ok", pattern);
+        let err = |pattern| opaque(&heap, "::core::result::Err", pattern);
+        let some = |pattern| opaque(&heap, "::core::option::Some", pattern);
+        let none = || opaque(&heap, "::core::option::None", null());
+
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [ok(some(boolean(true))), ok(none()), err(wildcard())]
+            ),
+            missing("::core::result::Ok(::core::option::Some(false))")
+        );
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [
+                    ok(some(wildcard())),
+                    ok(none()),
+                    err(wildcard()),
+                    ok(some(boolean(false))),
+                ]
+            ),
+            unreachable([3])
+        );
+    }
+
+    #[test]
+    fn constructors_of_other_types_are_unreachable() {
+        let heap = Heap::new();
+        let env = Environment::new(&heap);
+        let builder = TypeBuilder::synthetic(&env);
+
+        let r#type = builder.union([
+            builder.opaque("::core::option::Some", builder.integer()),
+            builder.opaque("::core::option::None", builder.null()),
+        ]);
+
+        // `Ok` is never a value of an `Option`
+        assert_eq!(
+            check(
+                &env,
+                r#type,
+                [opaque(&heap, "::core::result::Ok", wildcard()), wildcard()]
+            ),
+            unreachable([0])
+        );
+    }
+}
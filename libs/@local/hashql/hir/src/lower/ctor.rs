@@ -20,14 +20,16 @@ use hashql_core::{
 use super::error::{GenericArgumentContext, LoweringDiagnosticIssues, generic_argument_mismatch};
 use crate::{
     context::HirContext,
-    fold::{Fold, nested::Deep, walk_node},
+    fold::{Fold, nested::Deep, walk_node, walk_pattern},
     intern::Interner,
     node::{
         HirIdMap, Node, NodeData,
+        branch::r#match::{ConstructorPattern, OpaquePattern, Pattern, PatternKind},
         kind::NodeKind,
         operation::{Operation, TypeConstructor, TypeOperation},
         variable::{LocalVariable, QualifiedVariable, Variable},
     },
+    path::QualifiedPath,
 };
 
 pub struct ConvertTypeConstructor<'ctx, 'hir, 'env, 'heap, 'diag> {
@@ -92,6 +94,38 @@ impl<'heap> ConvertTypeConstructor<'_, '_, '_, 'heap, '_> {
         }
     }
 
+    /// Resolves the name of the opaque type constructed by the constructor at `path`.
+    fn resolve_pattern(&self, path: QualifiedPath<'heap>) -> Option<Symbol<'heap>> {
+        let item = self
+            .context
+            .modules
+            .lookup(path.0.iter().map(|ident| ident.value), Universe::Value)?;
+
+        let ItemKind::Constructor(constructor) = item.kind else {
+            return None;
+        };
+
+        let mut opaque_id = constructor.r#type.id;
+        if !constructor.r#type.arguments.is_empty() {
+            opaque_id = self
+                .environment
+                .r#type(opaque_id)
+                .kind
+                .generic()
+                .expect("opaque type with generics should be generic")
+                .base;
+        }
+
+        let opaque = self
+            .environment
+            .r#type(opaque_id)
+            .kind
+            .opaque()
+            .expect("constructor type should be opaque");
+
+        Some(opaque.name)
+    }
+
     fn build_closure(
         &self,
         span: SpanId,
@@ -275,4 +309,25 @@ impl<'heap> Fold<'heap> for ConvertTypeConstructor<'_, '_, '_, 'heap, '_> {
 
         Ok(node)
     }
+
+    fn fold_pattern(&mut self, pattern: Pattern<'heap>) -> Self::Output<Pattern<'heap>> {
+        let mut pattern = walk_pattern(self, pattern)?;
+
+        if let PatternKind::Constructor(ConstructorPattern {
+            path,
+            pattern: inner,
+        }) = pattern.kind
+        {
+            let name = self
+                .resolve_pattern(path)
+                .expect("constructor patterns should have been resolved during import resolution");
+
+            pattern.kind = PatternKind::Opaque(OpaquePattern {
+                name,
+                pattern: inner,
+            });
+        }
+
+        Ok(pattern)
+    }
 }
@@ -30,6 +30,16 @@ const ARGUMENT_OVERRIDE: TerminalDiagnosticCategory = TerminalDiagnosticCategory
     name: "Cannot apply type arguments to already-parameterized variable",
 };
 
+const NON_EXHAUSTIVE_MATCH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "non-exhaustive-match",
+    name: "Non-exhaustive match",
+};
+
+const UNREACHABLE_MATCH_ARM: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unreachable-match-arm",
+    name: "Unreachable match arm",
+};
+
 pub type LoweringDiagnostic<K = Severity> = Diagnostic<LoweringDiagnosticCategory, SpanId, K>;
 pub type LoweringDiagnosticIssues<K = Severity> =
     DiagnosticIssues<LoweringDiagnosticCategory, SpanId, K>;
@@ -39,6 +49,8 @@ pub type LoweringDiagnosticStatus<T> = Status<T, LoweringDiagnosticCategory, Spa
 pub enum LoweringDiagnosticCategory {
     GenericArgumentMismatch,
     ArgumentOverride,
+    NonExhaustiveMatch,
+    UnreachableMatchArm,
     TypeChecking(TypeCheckDiagnosticCategory),
     Specialization(SpecializationDiagnosticCategory),
 }
@@ -56,6 +68,8 @@ impl DiagnosticCategory for LoweringDiagnosticCategory {
         match self {
             Self::GenericArgumentMismatch => Some(&GENERIC_ARGUMENT_MISMATCH),
             Self::ArgumentOverride => Some(&ARGUMENT_OVERRIDE),
+            Self::NonExhaustiveMatch => Some(&NON_EXHAUSTIVE_MATCH),
+            Self::UnreachableMatchArm => Some(&UNREACHABLE_MATCH_ARM),
             Self::TypeChecking(category) => Some(category),
             Self::Specialization(category) => Some(category),
         }
@@ -260,3 +274,52 @@ pub(crate) fn type_mismatch_if<'heap>(
 
     diagnostic
 }
+
+pub(crate) fn non_exhaustive_match(
+    match_span: SpanId,
+    scrutinee_span: SpanId,
+    witness: impl Display,
+) -> LoweringDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        LoweringDiagnosticCategory::NonExhaustiveMatch,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        scrutinee_span,
+        format!("pattern `{witness}` is not covered"),
+    ));
+
+    diagnostic
+        .labels
+        .push(Label::new(match_span, "in this `match` expression"));
+
+    diagnostic.add_message(Message::help(format!(
+        "add an arm for `{witness}`, or use a wildcard pattern `_` to match every remaining value"
+    )));
+
+    diagnostic.add_message(Message::note(
+        "a `match` expression must handle every possible value of the matched expression, as \
+         there is no fallback value if no arm matches",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unreachable_match_arm(pattern_span: SpanId) -> LoweringDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        LoweringDiagnosticCategory::UnreachableMatchArm,
+        Severity::Warning,
+    )
+    .primary(Label::new(pattern_span, "this arm is never evaluated"));
+
+    diagnostic.add_message(Message::help(
+        "remove this arm, or move it before the arms that already match every value it matches",
+    ));
+
+    diagnostic.add_message(Message::note(
+        "arms are checked in order, an arm is unreachable if every value it matches has already \
+         been matched by a previous arm, or cannot occur for the type of the matched expression",
+    ));
+
+    diagnostic
+}
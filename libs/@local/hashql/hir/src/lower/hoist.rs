@@ -63,7 +63,7 @@ use crate::{
     lower::dataflow::VariableDependencies,
     node::{
         Node,
-        branch::If,
+        branch::{If, Match},
         closure::Closure,
         graph::Graph,
         r#let::{Binding, VarId},
@@ -340,6 +340,25 @@ impl<'heap> Fold<'heap> for GraphHoisting<'_, '_, 'heap> {
         Ok(If { test, then, r#else })
     }
 
+    /// Processes match expressions, preventing hoisting from arms to preserve evaluation order.
+    ///
+    /// Like the branches of an `if`, only a single arm of a `match` is evaluated. Hoisting from
+    /// an arm would additionally move bindings out of the scope of the variables bound by its
+    /// pattern.
+    fn fold_match(
+        &mut self,
+        Match { scrutinee, arms }: Match<'heap>,
+    ) -> Self::Output<Match<'heap>> {
+        // The scrutinee always executes, therefore hoisting is safe
+        let scrutinee = self.fold_node(scrutinee)?;
+
+        let prev_scope_sources = self.scope_sources.take();
+        let arms = self.fold_match_arms(arms)?;
+        self.scope_sources = prev_scope_sources;
+
+        Ok(Match { scrutinee, arms })
+    }
+
     /// Processes closure definitions, potentially enabling hoisting for nested expressions.
     ///
     /// The behavior depends on whether we're nested inside a graph operation:
@@ -1,12 +1,12 @@
 use hashql_core::{
-    collections::{FastHashMap, FastHashSet, HashMapExt as _},
+    collections::{FastHashMap, FastHashSet, HashMapExt as _, SmallVec},
     module::{
         Universe,
         item::{IntrinsicItem, IntrinsicValueItem, ItemKind},
         locals::TypeDef,
     },
     span::{SpanId, Spanned},
-    symbol::Symbol,
+    symbol::{Ident, IdentKind, Symbol},
     r#type::{
         PartialType, TypeBuilder, TypeId,
         environment::{
@@ -28,15 +28,18 @@ use crate::{
     node::{
         HirIdMap, HirIdSet, HirPtr, Node,
         access::{FieldAccess, IndexAccess},
-        branch::If,
+        branch::{
+            If, Match,
+            r#match::{OpaquePattern, Pattern, PatternKind, StructPattern},
+        },
         call::Call,
         closure::{Closure, extract_signature, extract_signature_generic},
         data::{Dict, List, Struct, Tuple},
         graph::Graph,
         r#let::{Binding, Let, VarIdMap},
         operation::{
-            BinaryOperation, InputOp, InputOperation, TypeAssertion, TypeConstructor,
-            UnaryOperation,
+            BinaryOperation, InputOp, InputOperation, IntrinsicOperation, TypeAssertion,
+            TypeConstructor, UnaryOperation,
         },
         thunk::Thunk,
         variable::{LocalVariable, QualifiedVariable},
@@ -147,6 +150,83 @@ impl<'ctx, 'env, 'hir, 'heap> TypeInference<'ctx, 'env, 'hir, 'heap> {
 
         (solver, residual, diagnostics)
     }
+
+    /// Infers the types of a pattern matched against a value of type `subject`.
+    ///
+    /// The type of the value each (sub-)pattern is matched against is recorded under the id of
+    /// the pattern, which is later used to check exhaustiveness.
+    fn infer_pattern(&mut self, pattern: &'heap Pattern<'heap>, subject: TypeId) {
+        self.context.map.insert_type_id(pattern.id, subject);
+
+        match &pattern.kind {
+            PatternKind::Wildcard => {}
+            PatternKind::Binding(binder) => {
+                self.locals.insert_unique(
+                    binder.id,
+                    Local {
+                        r#type: TypeDef {
+                            id: subject,
+                            arguments: self.env.intern_generic_argument_references(&[]),
+                        },
+                        intrinsic: None,
+                    },
+                );
+            }
+            PatternKind::Literal(literal) => {
+                let builder = TypeBuilder::spanned(pattern.span, self.env);
+                let literal = match literal {
+                    Primitive::Null => builder.null(),
+                    Primitive::Boolean(_) => builder.boolean(),
+                    Primitive::Float(_) => builder.number(),
+                    Primitive::Integer(_) => builder.integer(),
+                    Primitive::String(_) => builder.string(),
+                };
+
+                // literal <: subject
+                self.inference
+                    .collect_constraints(Variance::Covariant, literal, subject);
+            }
+            PatternKind::Tuple(elements) => {
+                for (index, element) in elements.iter().enumerate() {
+                    let field = Ident {
+                        span: element.span,
+                        value: self.env.heap.intern_symbol(&index.to_string()),
+                        kind: IdentKind::Lexical,
+                    };
+
+                    let variable = self.inference.add_projection(element.span, subject, field);
+                    self.infer_pattern(element, variable.into_type(self.env).id);
+                }
+            }
+            PatternKind::Struct(fields) => {
+                for StructPattern { name, pattern } in fields {
+                    let variable = self.inference.add_projection(pattern.span, subject, *name);
+                    self.infer_pattern(pattern, variable.into_type(self.env).id);
+                }
+            }
+            PatternKind::Constructor(_) => {
+                unreachable!("constructor patterns should have been converted prior to this point")
+            }
+            PatternKind::Opaque(OpaquePattern {
+                name,
+                pattern: inner,
+            }) => {
+                // The subject flows into a fresh hole, which binds the arguments of an applied
+                // generic (such as `Option<Integer>`), otherwise the representation would refer
+                // to the unbound parameters of the generic.
+                let hole = self.inference.fresh_hole(pattern.span);
+                self.inference.add_variables([hole]);
+
+                let hole = hole.into_type(self.env).id;
+                // subject <: hole
+                self.inference
+                    .collect_constraints(Variance::Covariant, subject, hole);
+
+                let variable = self.inference.add_downcast(pattern.span, hole, *name);
+                self.infer_pattern(inner, variable.into_type(self.env).id);
+            }
+        }
+    }
 }
 
 impl<'heap> Visitor<'heap> for TypeInference<'_, '_, '_, 'heap> {
@@ -421,6 +501,10 @@ impl<'heap> Visitor<'heap> for TypeInference<'_, '_, '_, 'heap> {
         unreachable!("access operations shouldn't be present yet");
     }
 
+    fn visit_intrinsic_operation(&mut self, _: &'heap IntrinsicOperation<'heap>) {
+        unreachable!("intrinsic operations shouldn't be present yet");
+    }
+
     fn visit_field_access(&mut self, access: &'heap FieldAccess<'heap>) {
         visit::walk_field_access(self, access);
 
@@ -517,6 +601,33 @@ impl<'heap> Visitor<'heap> for TypeInference<'_, '_, '_, 'heap> {
             .collect_constraints(Variance::Covariant, test, test_expected);
     }
 
+    fn visit_match(&mut self, r#match: &'heap Match<'heap>) {
+        self.visit_node(r#match.scrutinee);
+        let scrutinee = self.context.map.type_id(r#match.scrutinee.id);
+
+        for arm in r#match.arms {
+            // The bindings of the pattern must be in scope before the body is visited
+            self.infer_pattern(&arm.pattern, scrutinee);
+            self.visit_node(arm.body);
+        }
+
+        let variants: SmallVec<_> = r#match
+            .arms
+            .iter()
+            .map(|arm| self.context.map.type_id(arm.body.id))
+            .collect();
+
+        // The output is the union of all arms
+        let output = self.env.intern_type(PartialType {
+            span: self.current.span,
+            kind: self.env.intern_kind(TypeKind::Union(UnionType {
+                variants: self.env.intern_type_ids(&variants),
+            })),
+        });
+
+        self.context.map.insert_type_id(self.current.id, output);
+    }
+
     fn visit_closure(&mut self, Closure { signature, body }: &'heap Closure<'heap>) {
         // We create two versions of the closure's definition: one for type-checking the closure
         // body (`inner`) and another for type-checking calls to the closure (`outer`).
@@ -1,10 +1,14 @@
 use hashql_ast::lower::ExtractedTypes;
-use hashql_core::r#type::environment::Environment;
+use hashql_core::{
+    collections::FastHashMap,
+    symbol::Symbol,
+    r#type::{TypeId, environment::Environment},
+};
 use hashql_diagnostics::{DiagnosticIssues, StatusExt as _, Success};
 
 use self::{
     alias::AliasReplacement,
-    checking::TypeChecking,
+    checking::{TypeChecking, TypeCheckingResidual},
     ctor::ConvertTypeConstructor,
     error::{LoweringDiagnosticCategory, LoweringDiagnosticStatus},
     hoist::{GraphHoisting, GraphHoistingConfig},
@@ -26,21 +30,26 @@ pub mod normalization;
 pub mod specialization;
 pub mod thunking;
 
-/// Lowers the given node by performing different phases.
+/// Type checks the given node, without transforming it any further.
 ///
-/// This will set the "substitution" field of the environment given.
+/// This runs every phase of [`lower`] up to and including type checking, and will set the
+/// "substitution" field of the environment given. Afterwards the [`HirMap`] of the context
+/// contains the type of every node, while the returned node still mirrors the structure of the
+/// source, which makes it suitable for tooling.
+///
+/// [`HirMap`]: crate::map::HirMap
 ///
 /// # Errors
 ///
-/// Returns a vector of `LoweringDiagnostic` if any errors occurred during lowering.
+/// Returns a vector of `LoweringDiagnostic` if any errors occurred during type checking.
 ///
 /// The vector is guaranteed to be non-empty.
-pub fn lower<'env, 'heap>(
+pub fn check<'env, 'heap>(
     node: Node<'heap>,
     types: &'env ExtractedTypes<'heap>,
     env: &'env mut Environment<'heap>,
     context: &mut HirContext<'_, 'heap>,
-) -> LoweringDiagnosticStatus<Node<'heap>> {
+) -> LoweringDiagnosticStatus<(Node<'heap>, TypeCheckingResidual<'heap>)> {
     let mut diagnostics = DiagnosticIssues::new();
     let mut replacement = AliasReplacement::new(context, &mut diagnostics);
     let Ok(node) = replacement.fold_node(node);
@@ -84,10 +93,37 @@ pub fn lower<'env, 'heap>(
     let mut result = checking.finish();
     result.append_diagnostics(&mut diagnostics);
 
+    result.map_value(|residual| (node, residual))
+}
+
+/// Information gathered while lowering a node, which is required to evaluate it.
+pub struct LoweringResidual<'heap> {
+    /// The type of every input loaded by the node.
+    ///
+    /// If the same input is loaded with different types, this is the greatest lower bound of
+    /// them.
+    pub inputs: FastHashMap<Symbol<'heap>, TypeId>,
+}
+
+/// Lowers the given node by performing different phases.
+///
+/// This will set the "substitution" field of the environment given.
+///
+/// # Errors
+///
+/// Returns a vector of `LoweringDiagnostic` if any errors occurred during lowering.
+///
+/// The vector is guaranteed to be non-empty.
+pub fn lower<'env, 'heap>(
+    node: Node<'heap>,
+    types: &'env ExtractedTypes<'heap>,
+    env: &'env mut Environment<'heap>,
+    context: &mut HirContext<'_, 'heap>,
+) -> LoweringDiagnosticStatus<(Node<'heap>, LoweringResidual<'heap>)> {
     let Success {
-        value: residual,
+        value: (node, residual),
         advisories,
-    } = result?;
+    } = check(node, types, env, context)?;
 
     // Post type-checking diagnostic boundary
     let mut diagnostics = advisories.generalize();
@@ -111,5 +147,10 @@ pub fn lower<'env, 'heap>(
     let normalization = Normalization::new(context, env, &mut norm_state);
     let node = normalization.run(node);
 
-    diagnostics.into_status(node)
+    diagnostics.into_status((
+        node,
+        LoweringResidual {
+            inputs: residual.inputs,
+        },
+    ))
 }
@@ -35,7 +35,7 @@
 //!
 //! We define boundaries where `let` bindings are accumulated:
 //! - Closure definitions
-//! - Branching constructs (control flow)
+//! - Branching constructs (control flow), including every arm of a `match`
 //! - Short-circuiting operations (`&&`, `||`)
 //!
 //! # Examples
@@ -68,14 +68,14 @@ use crate::{
     node::{
         HirPtr, Node, NodeData,
         access::{FieldAccess, IndexAccess},
-        branch::{Branch, If},
+        branch::{Branch, If, Match, r#match::MatchArm},
         call::{Call, CallArgument},
         closure::Closure,
         data::{Data, DictField, List, StructField, Tuple},
         graph::read::GraphReadHead,
         kind::NodeKind,
         r#let::{Binder, Binding, Let},
-        operation::{BinOp, BinaryOperation, TypeAssertion, UnaryOperation},
+        operation::{BinOp, BinaryOperation, IntrinsicOperation, TypeAssertion, UnaryOperation},
         thunk::Thunk,
         variable::{LocalVariable, Variable},
     },
@@ -727,6 +727,29 @@ impl<'heap> Fold<'heap> for Normalization<'_, '_, '_, 'heap> {
         })
     }
 
+    /// Folds intrinsic operations, ensuring every argument is atomic.
+    ///
+    /// Intrinsic operations are treated as function calls, so their arguments must
+    /// be atoms to maintain ANF invariants.
+    fn fold_intrinsic_operation(
+        &mut self,
+        IntrinsicOperation { op, arguments }: IntrinsicOperation<'heap>,
+    ) -> Self::Output<IntrinsicOperation<'heap>> {
+        let op = Spanned {
+            span: self.fold_span(op.span)?,
+            value: op.value,
+        };
+
+        let mut arguments = Beef::new(arguments);
+        let Ok(()) = arguments.try_map::<_, Self::Output<()>>(|argument| {
+            self.fold_nested_node(argument)
+                .map(|node| self.ensure_atom(node))
+        });
+        let arguments = arguments.finish(&self.context.interner.nodes);
+
+        Ok(IntrinsicOperation { op, arguments })
+    }
+
     /// Folds field access expressions, ensuring the base expression is a projection.
     ///
     /// Field access creates a projection, so the base expression must itself be
@@ -824,6 +847,37 @@ impl<'heap> Fold<'heap> for Normalization<'_, '_, '_, 'heap> {
         })
     }
 
+    /// Folds match expressions with boundary handling.
+    ///
+    /// Like conditional expressions, the scrutinee is evaluated in the current boundary, while the
+    /// body of every arm forms its own boundary.
+    ///
+    /// The scrutinee must be a projection, so that the patterns of each arm can be checked
+    /// against (and bind) places inside of it.
+    fn fold_match(
+        &mut self,
+        Match { scrutinee, arms }: Match<'heap>,
+    ) -> Self::Output<Match<'heap>> {
+        let Ok(scrutinee) = fold::walk_nested_node(self, scrutinee);
+
+        let mut arms = Beef::new(arms);
+        let Ok(()) = arms.try_map::<_, Self::Output<()>>(|arm| {
+            let Ok(pattern) = self.fold_pattern(arm.pattern);
+
+            Ok(MatchArm {
+                span: arm.span,
+                pattern,
+                body: self.boundary(arm.body),
+            })
+        });
+        let arms = arms.finish(&self.context.interner.match_arms);
+
+        Ok(Match {
+            scrutinee: self.ensure_projection(scrutinee),
+            arms,
+        })
+    }
+
     /// Folds closure definitions with boundary handling.
     ///
     /// Closure bodies form natural boundaries since they represent separate
@@ -863,11 +917,9 @@ impl<'heap> Fold<'heap> for Normalization<'_, '_, '_, 'heap> {
     ) -> Self::Output<GraphReadHead<'heap>> {
         let Ok(head) = fold::walk_graph_read_head(self, head);
 
-        Ok(match head {
-            GraphReadHead::Entity { axis } => GraphReadHead::Entity {
-                // Ensure that the axis is evaluated *before* the pipeline is initiated
-                axis: self.ensure_atom(axis),
-            },
-        })
+        // Ensure that the axis is evaluated *before* the pipeline is initiated
+        let axis = self.ensure_atom(head.axis());
+
+        Ok(head.with_axis(axis))
     }
 }
@@ -13,7 +13,11 @@ use hashql_diagnostics::{
     severity::Severity,
 };
 
-use crate::{context::HirContext, node::Node, pretty::NodeFormatter};
+use crate::{
+    context::HirContext,
+    node::{Node, graph::read::GraphTraversal},
+    pretty::NodeFormatter,
+};
 
 pub type SpecializationDiagnostic = Diagnostic<SpecializationDiagnosticCategory, SpanId>;
 
@@ -42,6 +46,21 @@ const NON_GRAPH_INTRINSIC: TerminalDiagnosticCategory = TerminalDiagnosticCatego
     name: "Non-graph intrinsic in graph operation",
 };
 
+const INVALID_TRAVERSAL_DEPTH: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-traversal-depth",
+    name: "Invalid traversal depth",
+};
+
+const INVALID_PAGINATION_COUNT: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-pagination-count",
+    name: "Invalid pagination count",
+};
+
+const MISPLACED_GRAPH_ORDERING: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "misplaced-graph-ordering",
+    name: "Misplaced graph ordering",
+};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SpecializationDiagnosticCategory {
     UnsupportedIntrinsic,
@@ -49,6 +68,9 @@ pub enum SpecializationDiagnosticCategory {
     InvalidGraphChain,
     NonIntrinsicGraphOperation,
     NonGraphIntrinsic,
+    InvalidTraversalDepth,
+    InvalidPaginationCount,
+    MisplacedGraphOrdering,
 }
 
 impl DiagnosticCategory for SpecializationDiagnosticCategory {
@@ -67,6 +89,9 @@ impl DiagnosticCategory for SpecializationDiagnosticCategory {
             Self::InvalidGraphChain => Some(&INVALID_GRAPH_CHAIN),
             Self::NonIntrinsicGraphOperation => Some(&NON_INTRINSIC_GRAPH_OPERATION),
             Self::NonGraphIntrinsic => Some(&NON_GRAPH_INTRINSIC),
+            Self::InvalidTraversalDepth => Some(&INVALID_TRAVERSAL_DEPTH),
+            Self::InvalidPaginationCount => Some(&INVALID_PAGINATION_COUNT),
+            Self::MisplacedGraphOrdering => Some(&MISPLACED_GRAPH_ORDERING),
         }
     }
 }
@@ -245,3 +270,112 @@ pub(crate) fn non_graph_intrinsic(
 
     diagnostic
 }
+
+/// Creates a diagnostic for a traversal depth which isn't an integer literal between 1 and
+/// [`GraphTraversal::MAX_DEPTH`].
+///
+/// The depth of a traversal determines the shape of the generated query, so it must be known
+/// during compilation.
+pub(crate) fn invalid_traversal_depth<'heap>(
+    env: &Environment<'heap>,
+    context: &HirContext<'_, 'heap>,
+
+    span: SpanId,
+    depth: Node<'heap>,
+) -> SpecializationDiagnostic {
+    let formatter = Formatter::new(env.heap);
+    let mut formatter = NodeFormatter::with_defaults(&formatter, env, context);
+
+    let mut diagnostic = Diagnostic::new(
+        SpecializationDiagnosticCategory::InvalidTraversalDepth,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        format!(
+            "Expected an integer literal between 1 and {}",
+            GraphTraversal::MAX_DEPTH
+        ),
+    ));
+
+    diagnostic.add_message(Message::help(format!(
+        "{} is not a valid traversal depth. The depth of `::graph::body::traverse` must be an \
+         integer literal between 1 and {}, variables and computed values are not supported.",
+        formatter.render(depth, RenderOptions::default().with_max_width(60)),
+        GraphTraversal::MAX_DEPTH
+    )));
+
+    diagnostic.add_message(Message::note(
+        "The depth of a traversal determines how many links are followed, which is part of the \
+         structure of the compiled query and therefore must be known at compile time.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for a `limit` or `offset` count which isn't a non-negative integer literal.
+///
+/// Like the depth of a traversal, the count is part of the generated query, so it must be known
+/// during compilation.
+pub(crate) fn invalid_pagination_count<'heap>(
+    env: &Environment<'heap>,
+    context: &HirContext<'_, 'heap>,
+
+    span: SpanId,
+    intrinsic_name: Symbol<'_>,
+    count: Node<'heap>,
+) -> SpecializationDiagnostic {
+    let formatter = Formatter::new(env.heap);
+    let mut formatter = NodeFormatter::with_defaults(&formatter, env, context);
+
+    let mut diagnostic = Diagnostic::new(
+        SpecializationDiagnosticCategory::InvalidPaginationCount,
+        Severity::Error,
+    )
+    .primary(Label::new(span, "Expected a non-negative integer literal"));
+
+    diagnostic.add_message(Message::help(format!(
+        "{} is not a valid count. The count of `{intrinsic_name}` must be a non-negative integer \
+         literal, variables and computed values are not supported.",
+        formatter.render(count, RenderOptions::default().with_max_width(60)),
+    )));
+
+    diagnostic.add_message(Message::note(
+        "Pagination is compiled into the `LIMIT` and `OFFSET` clauses of the query whenever \
+         possible, which requires the count to be known at compile time.",
+    ));
+
+    diagnostic
+}
+
+/// Creates a diagnostic for an ordering operation which is followed by a filter or traversal.
+///
+/// Sorting and pagination are applied to the results of a graph read, after every item has been
+/// selected.
+pub(crate) fn misplaced_graph_ordering(
+    span: SpanId,
+    ordering: Symbol<'_>,
+    operation: Symbol<'_>,
+) -> SpecializationDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        SpecializationDiagnosticCategory::MisplacedGraphOrdering,
+        Severity::Error,
+    )
+    .primary(Label::new(
+        span,
+        format!("`{operation}` cannot follow `{ordering}`"),
+    ));
+
+    diagnostic.add_message(Message::help(format!(
+        "Move `{ordering}` after `{operation}`. Sorting and pagination must come after every \
+         filter and traversal of the graph read."
+    )));
+
+    diagnostic.add_message(Message::note(
+        "`::graph::tail::sort_by`, `::graph::tail::limit` and `::graph::tail::offset` arrange the \
+         results of a graph read, they cannot be used to narrow down the items which are filtered \
+         or traversed.",
+    ));
+
+    diagnostic
+}
@@ -7,10 +7,12 @@ use hashql_core::{
     span::Spanned,
     symbol::{Symbol, sym},
     r#type::environment::Environment,
+    value::{Integer, Primitive},
 };
 
 use self::error::{
-    SpecializationDiagnostic, invalid_graph_chain, non_graph_intrinsic,
+    SpecializationDiagnostic, invalid_graph_chain, invalid_pagination_count,
+    invalid_traversal_depth, misplaced_graph_ordering, non_graph_intrinsic,
     non_intrinsic_graph_operation, unknown_intrinsic, unsupported_intrinsic,
 };
 use super::error::{LoweringDiagnosticCategory, LoweringDiagnosticIssues};
@@ -21,13 +23,20 @@ use crate::{
     node::{
         HirIdMap, HirPtr, Node, NodeData,
         call::Call,
+        data::Data,
         graph::{
             Graph,
-            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+            read::{
+                GraphAggregate, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail,
+                GraphTraversal,
+            },
         },
         kind::NodeKind,
         r#let::{Binding, VarIdMap},
-        operation::{BinOp, BinaryOperation, Operation},
+        operation::{
+            BinOp, BinaryOperation, DictOp, IntrinsicOp, IntrinsicOperation, ListOp, Operation,
+            StringOp,
+        },
         variable::Variable,
     },
 };
@@ -69,21 +78,103 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
             .push(diagnostic.map_category(LoweringDiagnosticCategory::Specialization));
     }
 
+    /// Resolves `node` through the `let` bindings it refers to, returning the bound node together
+    /// with its value, if it is an integer constant.
+    ///
+    /// Locals which aren't bound by a `let`, such as closure parameters, aren't known and resolve
+    /// to themselves.
+    fn resolve_integer(&self, mut node: Node<'heap>) -> (Node<'heap>, Option<Integer<'heap>>) {
+        while let NodeKind::Variable(Variable::Local(local)) = node.kind
+            && let Some(&value) = self.locals.get(&local.id.value)
+        {
+            node = value;
+        }
+
+        if let NodeKind::Data(Data::Primitive(Primitive::Integer(integer))) = node.kind {
+            (node, Some(integer))
+        } else {
+            (node, None)
+        }
+    }
+
+    /// Resolves the depth of a traversal, which must be an integer literal between 1 and
+    /// [`GraphTraversal::MAX_DEPTH`].
+    fn traversal_depth(&mut self, node: Node<'heap>) -> Option<u8> {
+        let (node, integer) = self.resolve_integer(node);
+
+        if let Some(depth) = integer.and_then(Integer::as_u8)
+            && (1..=GraphTraversal::MAX_DEPTH).contains(&depth)
+        {
+            return Some(depth);
+        }
+
+        self.push_diagnostic(invalid_traversal_depth(
+            self.env,
+            self.context,
+            node.span,
+            node,
+        ));
+
+        None
+    }
+
+    /// Resolves the count of a `limit` or `offset`, which must be a non-negative integer literal.
+    fn pagination_count(&mut self, intrinsic: Symbol<'heap>, node: Node<'heap>) -> Option<usize> {
+        let (node, integer) = self.resolve_integer(node);
+
+        if let Some(count) = integer.and_then(Integer::as_usize) {
+            return Some(count);
+        }
+
+        self.push_diagnostic(invalid_pagination_count(
+            self.env,
+            self.context,
+            node.span,
+            intrinsic,
+            node,
+        ));
+
+        None
+    }
+
+    #[expect(clippy::too_many_lines)]
     fn fold_call_into_graph_read(
         &mut self,
         call: Call<'heap>,
         intrinsic: Symbol<'heap>,
     ) -> Option<GraphRead<'heap>> {
+        let closure = || call.arguments[1].value;
+
         // The first argument is always the graph we're referring to.
         let tail = match intrinsic.as_constant() {
             Some(sym::path::graph_tail_collect::CONST) => GraphReadTail::Collect,
+            Some(sym::path::graph_tail_count::CONST) => GraphReadTail::Count,
+            Some(sym::path::graph_tail_exists::CONST) => GraphReadTail::Exists,
+            Some(sym::path::graph_tail_first::CONST) => GraphReadTail::First,
+            Some(sym::path::graph_tail_group_by::CONST) => GraphReadTail::GroupBy(closure()),
+            Some(sym::path::graph_tail_sum::CONST) => {
+                GraphReadTail::Aggregate(GraphAggregate::Sum, closure())
+            }
+            Some(sym::path::graph_tail_min::CONST) => {
+                GraphReadTail::Aggregate(GraphAggregate::Min, closure())
+            }
+            Some(sym::path::graph_tail_max::CONST) => {
+                GraphReadTail::Aggregate(GraphAggregate::Max, closure())
+            }
+            Some(sym::path::graph_tail_avg::CONST) => {
+                GraphReadTail::Aggregate(GraphAggregate::Avg, closure())
+            }
             _ => unreachable!(),
         };
 
         let mut body = SmallVec::new();
 
+        // The closest filter or traversal after the current operation, ordering operations must
+        // not be followed by any.
+        let mut selection: Option<(Node<'heap>, Symbol<'heap>)> = None;
+
         let mut next = call.arguments[0].value;
-        loop {
+        let head = loop {
             // Follow any local variables
             while let NodeKind::Variable(Variable::Local(local)) = next.kind {
                 next = self.locals[&local.id.value];
@@ -106,7 +197,58 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
                 return None;
             };
 
+            let is_ordering = matches!(
+                intrinsic.as_constant(),
+                Some(
+                    sym::path::graph_tail_sort_by::CONST
+                        | sym::path::graph_tail_limit::CONST
+                        | sym::path::graph_tail_offset::CONST
+                )
+            );
+
+            if is_ordering && let Some((function, operation)) = selection {
+                self.push_diagnostic(misplaced_graph_ordering(
+                    function.span,
+                    intrinsic,
+                    operation,
+                ));
+
+                return None;
+            }
+
+            if !is_ordering {
+                selection = Some((call.function, intrinsic));
+            }
+
             match intrinsic.as_constant() {
+                Some(sym::path::graph_tail_sort_by::CONST) => {
+                    let &[follow, closure] = &*call.arguments else {
+                        unreachable!()
+                    };
+
+                    body.push(GraphReadBody::SortBy(closure.value));
+                    next = follow.value;
+                }
+                Some(sym::path::graph_tail_limit::CONST) => {
+                    let &[follow, count] = &*call.arguments else {
+                        unreachable!()
+                    };
+
+                    let count = self.pagination_count(intrinsic, count.value)?;
+
+                    body.push(GraphReadBody::Limit(count));
+                    next = follow.value;
+                }
+                Some(sym::path::graph_tail_offset::CONST) => {
+                    let &[follow, count] = &*call.arguments else {
+                        unreachable!()
+                    };
+
+                    let count = self.pagination_count(intrinsic, count.value)?;
+
+                    body.push(GraphReadBody::Offset(count));
+                    next = follow.value;
+                }
                 Some(sym::path::graph_body_filter::CONST) => {
                     let &[follow, closure] = &*call.arguments else {
                         unreachable!()
@@ -115,19 +257,51 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
                     body.push(GraphReadBody::Filter(closure.value));
                     next = follow.value;
                 }
-                Some(sym::path::graph_head_entities::CONST) => {
-                    let head = GraphReadHead::Entity {
-                        axis: call.arguments[0].value,
+                Some(sym::path::graph_body_outgoing_links::CONST) => {
+                    body.push(GraphReadBody::Traverse(GraphTraversal::OutgoingLinks));
+                    next = call.arguments[0].value;
+                }
+                Some(sym::path::graph_body_incoming_links::CONST) => {
+                    body.push(GraphReadBody::Traverse(GraphTraversal::IncomingLinks));
+                    next = call.arguments[0].value;
+                }
+                Some(sym::path::graph_body_left_entities::CONST) => {
+                    body.push(GraphReadBody::Traverse(GraphTraversal::LeftEntities));
+                    next = call.arguments[0].value;
+                }
+                Some(sym::path::graph_body_right_entities::CONST) => {
+                    body.push(GraphReadBody::Traverse(GraphTraversal::RightEntities));
+                    next = call.arguments[0].value;
+                }
+                Some(sym::path::graph_body_traverse::CONST) => {
+                    let &[follow, depth] = &*call.arguments else {
+                        unreachable!()
                     };
 
-                    // Reverse the body, as we "climb" the chain up.
-                    body.reverse();
+                    let depth = self.traversal_depth(depth.value)?;
 
-                    return Some(GraphRead {
-                        head,
-                        body: self.context.interner.graph_read_body.intern_slice(&body),
-                        tail,
-                    });
+                    body.push(GraphReadBody::Traverse(GraphTraversal::Linked { depth }));
+                    next = follow.value;
+                }
+                Some(sym::path::graph_head_entities::CONST) => {
+                    break GraphReadHead::Entity {
+                        axis: call.arguments[0].value,
+                    };
+                }
+                Some(sym::path::graph_head_data_types::CONST) => {
+                    break GraphReadHead::DataType {
+                        axis: call.arguments[0].value,
+                    };
+                }
+                Some(sym::path::graph_head_property_types::CONST) => {
+                    break GraphReadHead::PropertyType {
+                        axis: call.arguments[0].value,
+                    };
+                }
+                Some(sym::path::graph_head_entity_types::CONST) => {
+                    break GraphReadHead::EntityType {
+                        axis: call.arguments[0].value,
+                    };
                 }
                 _ => {
                     self.push_diagnostic(non_graph_intrinsic(call.function.span, intrinsic));
@@ -135,7 +309,16 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
                     return None;
                 }
             }
-        }
+        };
+
+        // Reverse the body, as we "climb" the chain up.
+        body.reverse();
+
+        Some(GraphRead {
+            head,
+            body: self.context.interner.graph_read_body.intern_slice(&body),
+            tail,
+        })
     }
 
     #[expect(clippy::too_many_lines, reason = "just a large match statement")]
@@ -147,6 +330,7 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
         #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
         enum OpKind {
             Bin(BinOp),
+            Intrinsic(IntrinsicOp),
         }
 
         #[expect(clippy::match_same_arms)]
@@ -204,15 +388,111 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
             }
             Some(sym::path::core::bool::and::CONST) => OpKind::Bin(BinOp::And),
             Some(sym::path::core::bool::or::CONST) => OpKind::Bin(BinOp::Or),
-            Some(sym::path::graph_head_entities::CONST | sym::path::graph_body_filter::CONST) => {
-                // We ignore this on purpose, as `graph::tail::collect` will process these
+            Some(sym::path::core::string::lowercase::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Lowercase))
+            }
+            Some(sym::path::core::string::uppercase::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Uppercase))
+            }
+            Some(sym::path::core::string::contains::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Contains))
+            }
+            Some(sym::path::core::string::starts_with::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::StartsWith))
+            }
+            Some(sym::path::core::string::ends_with::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::EndsWith))
+            }
+            Some(sym::path::core::string::length::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Length))
+            }
+            Some(sym::path::core::string::concat::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Concat))
+            }
+            Some(sym::path::core::string::split::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Split))
+            }
+            Some(sym::path::core::string::trim::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::String(StringOp::Trim))
+            }
+            Some(sym::path::core::list::len::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Len))
+            }
+            Some(sym::path::core::list::get::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Get))
+            }
+            Some(sym::path::core::list::contains::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Contains))
+            }
+            Some(sym::path::core::list::concat::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Concat))
+            }
+            Some(sym::path::core::list::sort::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Sort))
+            }
+            Some(sym::path::core::list::map::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Map))
+            }
+            Some(sym::path::core::list::filter::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Filter))
+            }
+            Some(sym::path::core::list::fold::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::List(ListOp::Fold))
+            }
+            Some(sym::path::core::dict::len::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Len))
+            }
+            Some(sym::path::core::dict::get::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Get))
+            }
+            Some(sym::path::core::dict::contains::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Contains))
+            }
+            Some(sym::path::core::dict::keys::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Keys))
+            }
+            Some(sym::path::core::dict::values::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Values))
+            }
+            Some(sym::path::core::dict::insert::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Insert))
+            }
+            Some(sym::path::core::dict::concat::CONST) => {
+                OpKind::Intrinsic(IntrinsicOp::Dict(DictOp::Concat))
+            }
+            Some(
+                sym::path::graph_head_entities::CONST
+                | sym::path::graph_head_data_types::CONST
+                | sym::path::graph_head_property_types::CONST
+                | sym::path::graph_head_entity_types::CONST
+                | sym::path::graph_body_filter::CONST
+                | sym::path::graph_body_outgoing_links::CONST
+                | sym::path::graph_body_incoming_links::CONST
+                | sym::path::graph_body_left_entities::CONST
+                | sym::path::graph_body_right_entities::CONST
+                | sym::path::graph_body_traverse::CONST
+                | sym::path::graph_tail_sort_by::CONST
+                | sym::path::graph_tail_limit::CONST
+                | sym::path::graph_tail_offset::CONST,
+            ) => {
+                // We ignore this on purpose, as the tail of the graph read will process these
                 return Ok(None);
             }
             Some(sym::path::graph::tmp::decision_time_now::CONST) => {
                 // currently a stand-in and not specialized in any way
                 return Ok(None);
             }
-            Some(sym::path::graph_tail_collect::CONST) => {
+            Some(
+                sym::path::graph_tail_collect::CONST
+                | sym::path::graph_tail_count::CONST
+                | sym::path::graph_tail_exists::CONST
+                | sym::path::graph_tail_first::CONST
+                | sym::path::graph_tail_group_by::CONST
+                | sym::path::graph_tail_sum::CONST
+                | sym::path::graph_tail_min::CONST
+                | sym::path::graph_tail_max::CONST
+                | sym::path::graph_tail_avg::CONST,
+            ) => {
                 let Some(read) = self.fold_call_into_graph_read(call, intrinsic) else {
                     return Ok(None);
                 };
@@ -251,6 +531,30 @@ impl<'ctx, 'env, 'hir, 'heap, 'diag> Specialization<'ctx, 'env, 'hir, 'heap, 'di
                     right: call.arguments[1].value,
                 })
             }
+            OpKind::Intrinsic(value) => {
+                let op = Spanned {
+                    span: call.function.span,
+                    value,
+                };
+
+                assert_eq!(
+                    call.arguments.len(),
+                    value.arity(),
+                    "Expected {} arguments for intrinsic operation",
+                    value.arity()
+                );
+
+                let arguments: SmallVec<_> = call
+                    .arguments
+                    .iter()
+                    .map(|argument| argument.value)
+                    .collect();
+
+                Operation::Intrinsic(IntrinsicOperation {
+                    op,
+                    arguments: self.context.interner.intern_nodes(&arguments),
+                })
+            }
         };
 
         let operation = fold::walk_operation(self, operation)?;
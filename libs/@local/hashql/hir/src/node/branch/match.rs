@@ -0,0 +1,91 @@
+use hashql_core::{
+    intern::Interned,
+    span::SpanId,
+    symbol::{Ident, Symbol},
+    value::Primitive,
+};
+
+use crate::{
+    node::{HirId, Node, r#let::Binder},
+    path::QualifiedPath,
+};
+
+/// A pattern matching expression in the HashQL HIR.
+///
+/// Evaluates the scrutinee and selects the first arm whose pattern matches the value. The arms
+/// are checked for exhaustiveness and reachability during type checking, so that at least one arm
+/// is guaranteed to match at runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Match<'heap> {
+    pub scrutinee: Node<'heap>,
+    pub arms: Interned<'heap, [MatchArm<'heap>]>,
+}
+
+/// A single arm of a [`Match`] expression.
+///
+/// The bindings introduced by the pattern are only in scope for the body of the arm.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MatchArm<'heap> {
+    pub span: SpanId,
+
+    pub pattern: Pattern<'heap>,
+    pub body: Node<'heap>,
+}
+
+/// A pattern in the HashQL HIR.
+///
+/// Each pattern carries its own [`HirId`], which is used to record the type of the value the
+/// pattern is matched against.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern<'heap> {
+    pub id: HirId,
+    pub span: SpanId,
+
+    pub kind: PatternKind<'heap>,
+}
+
+/// A field of a struct pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StructPattern<'heap> {
+    pub name: Ident<'heap>,
+    pub pattern: Pattern<'heap>,
+}
+
+/// A pattern over a constructor, which has not yet been resolved to the opaque type it constructs.
+///
+/// Constructor patterns only exist after reification and are replaced with an [`OpaquePattern`]
+/// once type constructors have been converted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConstructorPattern<'heap> {
+    pub path: QualifiedPath<'heap>,
+    pub pattern: Interned<'heap, Pattern<'heap>>,
+}
+
+/// A pattern over an opaque type.
+///
+/// Matches if the value is an instance of the opaque type with the given name, and the
+/// representation of the value matches the inner pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OpaquePattern<'heap> {
+    pub name: Symbol<'heap>,
+    pub pattern: Interned<'heap, Pattern<'heap>>,
+}
+
+/// The different kinds of patterns.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PatternKind<'heap> {
+    /// Matches any value.
+    Wildcard,
+    /// Matches any value and binds it to the binder.
+    Binding(Binder<'heap>),
+    /// Matches a value equal to the literal.
+    Literal(Primitive<'heap>),
+    /// Matches a tuple element-wise.
+    Tuple(Interned<'heap, [Pattern<'heap>]>),
+    /// Matches the mentioned fields of a struct, other fields are ignored.
+    Struct(Interned<'heap, [StructPattern<'heap>]>),
+    /// Matches a value created through a constructor.
+    Constructor(ConstructorPattern<'heap>),
+    /// Matches an instance of an opaque type.
+    Opaque(OpaquePattern<'heap>),
+}
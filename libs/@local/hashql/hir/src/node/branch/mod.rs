@@ -1,11 +1,11 @@
 //! Branch expressions in the HashQL HIR.
 //!
 //! This module represents control flow branching operations in the HashQL
-//! language. Currently implemented as a placeholder with planned support
-//! for conditional expressions like if/else statements.
+//! language, conditional expressions like if/else statements and pattern matching.
 mod r#if;
+pub mod r#match;
 
-pub use self::r#if::If;
+pub use self::{r#if::If, r#match::Match};
 
 /// A branch node in the HashQL HIR.
 ///
@@ -15,4 +15,6 @@ pub use self::r#if::If;
 pub enum Branch<'heap> {
     /// Conditional branching with if/else.
     If(If<'heap>),
+    /// Pattern matching over the shape of a value.
+    Match(Match<'heap>),
 }
@@ -5,7 +5,9 @@
 //! including traversals, pattern matching, and data retrieval operations.
 pub mod read;
 
-pub use self::read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail};
+pub use self::read::{
+    GraphAggregate, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, GraphTraversal,
+};
 
 /// A graph operation node in the HashQL HIR.
 ///
@@ -6,9 +6,12 @@
 //!
 //! - **Head**: Determines the starting point of the query.
 //! - **Body**: Contains filtering operations to narrow down results.
-//! - **Tail**: Specifies how to finalize the query.
+//! - **Tail**: Specifies how to finalize the query, such as collecting or counting the results.
 
-use hashql_core::intern::Interned;
+use hashql_core::{
+    intern::Interned,
+    symbol::{Symbol, sym},
+};
 
 use crate::node::Node;
 
@@ -21,6 +24,94 @@ pub enum GraphReadHead<'heap> {
     ///
     /// The `axis` [`Node`] specifies the time axis for the bi-temporal query.
     Entity { axis: Node<'heap> },
+    /// Start the query from data types in the bi-temporal graph.
+    ///
+    /// The `axis` [`Node`] specifies the time axis for the bi-temporal query.
+    DataType { axis: Node<'heap> },
+    /// Start the query from property types in the bi-temporal graph.
+    ///
+    /// The `axis` [`Node`] specifies the time axis for the bi-temporal query.
+    PropertyType { axis: Node<'heap> },
+    /// Start the query from entity types in the bi-temporal graph.
+    ///
+    /// The `axis` [`Node`] specifies the time axis for the bi-temporal query.
+    EntityType { axis: Node<'heap> },
+}
+
+impl<'heap> GraphReadHead<'heap> {
+    /// The fully qualified path of the function creating the head.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Entity { .. } => sym::path::graph_head_entities,
+            Self::DataType { .. } => sym::path::graph_head_data_types,
+            Self::PropertyType { .. } => sym::path::graph_head_property_types,
+            Self::EntityType { .. } => sym::path::graph_head_entity_types,
+        }
+    }
+
+    /// The time axis of the query.
+    #[must_use]
+    pub const fn axis(self) -> Node<'heap> {
+        match self {
+            Self::Entity { axis }
+            | Self::DataType { axis }
+            | Self::PropertyType { axis }
+            | Self::EntityType { axis } => axis,
+        }
+    }
+
+    /// Returns a head of the same kind with the given time axis.
+    #[must_use]
+    pub const fn with_axis(self, axis: Node<'heap>) -> Self {
+        match self {
+            Self::Entity { .. } => Self::Entity { axis },
+            Self::DataType { .. } => Self::DataType { axis },
+            Self::PropertyType { .. } => Self::PropertyType { axis },
+            Self::EntityType { .. } => Self::EntityType { axis },
+        }
+    }
+}
+
+/// An edge in the knowledge graph, which is followed by a [`GraphReadBody::Traverse`].
+///
+/// Links are entities themselves, which have a left and a right endpoint. A traversal either moves
+/// from an entity to the links attached to it, or from a link to one of its endpoints.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphTraversal {
+    /// The links which have the entity as their left endpoint.
+    OutgoingLinks,
+    /// The links which have the entity as their right endpoint.
+    IncomingLinks,
+    /// The entity on the left endpoint of the link.
+    LeftEntities,
+    /// The entity on the right endpoint of the link.
+    RightEntities,
+    /// The entities reachable by following outgoing links to their right endpoint.
+    ///
+    /// An entity is reachable if it can be reached in at least one and at most `depth` steps,
+    /// `depth` is never zero and never exceeds [`Self::MAX_DEPTH`].
+    Linked { depth: u8 },
+}
+
+impl GraphTraversal {
+    /// The maximum depth of a [`Self::Linked`] traversal.
+    ///
+    /// Every step embeds the previous one into the generated query, so deep traversals quickly
+    /// become too expensive to plan and execute.
+    pub const MAX_DEPTH: u8 = 8;
+
+    /// The fully qualified path of the function creating the traversal.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::OutgoingLinks => sym::path::graph_body_outgoing_links,
+            Self::IncomingLinks => sym::path::graph_body_incoming_links,
+            Self::LeftEntities => sym::path::graph_body_left_entities,
+            Self::RightEntities => sym::path::graph_body_right_entities,
+            Self::Linked { .. } => sym::path::graph_body_traverse,
+        }
+    }
 }
 
 /// Operations that can be applied to narrow down query results.
@@ -36,18 +127,101 @@ pub enum GraphReadBody<'heap> {
     /// result set and returns a boolean indicating whether the item should be
     /// included in the filtered results.
     Filter(Node<'heap>),
+
+    /// Replace every item with the items reachable along an edge of the knowledge graph.
+    ///
+    /// Operations before a traversal apply to the items the traversal starts from, operations
+    /// after it to the items it reaches.
+    Traverse(GraphTraversal),
+
+    /// Sort the items in ascending order of a key.
+    ///
+    /// The [`Node`] represents a closure that takes each item and returns the key it is sorted
+    /// by. Sorting is stable, items with equal keys keep their relative order.
+    ///
+    /// Like [`Self::Limit`] and [`Self::Offset`], sorting always comes after every filter and
+    /// traversal of the body.
+    SortBy(Node<'heap>),
+
+    /// Keep at most the given number of items.
+    Limit(usize),
+
+    /// Skip the given number of items.
+    Offset(usize),
+}
+
+/// A numeric aggregate over the items of a graph read, used by [`GraphReadTail::Aggregate`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GraphAggregate {
+    /// The sum of all values, zero if there are none.
+    Sum,
+    /// The smallest value, if any.
+    Min,
+    /// The largest value, if any.
+    Max,
+    /// The arithmetic mean of all values, if any.
+    Avg,
+}
+
+impl GraphAggregate {
+    /// The fully qualified path of the function computing the aggregate.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Sum => sym::path::graph_tail_sum,
+            Self::Min => sym::path::graph_tail_min,
+            Self::Max => sym::path::graph_tail_max,
+            Self::Avg => sym::path::graph_tail_avg,
+        }
+    }
 }
 
 /// The final operation that determines how the query results are returned.
 ///
 /// Specifies how the processed data should be finalized and returned to the caller.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum GraphReadTail {
+pub enum GraphReadTail<'heap> {
     /// Collect all results into a collection.
     ///
     /// Gathers all items that pass through the query pipeline and returns them as a list of
     /// entities.
     Collect,
+
+    /// The number of results.
+    Count,
+
+    /// Whether there is at least one result.
+    Exists,
+
+    /// The first result, if any.
+    First,
+
+    /// Group the results by a key.
+    ///
+    /// The [`Node`] represents a closure that takes each item and returns the key of its group.
+    /// Returns a dictionary from every key to the items in its group.
+    GroupBy(Node<'heap>),
+
+    /// Aggregate a numeric value of every result.
+    ///
+    /// The [`Node`] represents a closure that takes each item and returns the value to
+    /// aggregate.
+    Aggregate(GraphAggregate, Node<'heap>),
+}
+
+impl GraphReadTail<'_> {
+    /// The fully qualified path of the function creating the tail.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Collect => sym::path::graph_tail_collect,
+            Self::Count => sym::path::graph_tail_count,
+            Self::Exists => sym::path::graph_tail_exists,
+            Self::First => sym::path::graph_tail_first,
+            Self::GroupBy(_) => sym::path::graph_tail_group_by,
+            Self::Aggregate(aggregate, _) => aggregate.as_symbol(),
+        }
+    }
 }
 
 /// A complete graph read operation in the HashQL HIR.
@@ -70,5 +244,5 @@ pub struct GraphRead<'heap> {
     pub body: Interned<'heap, [GraphReadBody<'heap>]>,
 
     /// The final operation that determines how the results are returned.
-    pub tail: GraphReadTail,
+    pub tail: GraphReadTail<'heap>,
 }
@@ -0,0 +1,200 @@
+use hashql_core::{
+    intern::Interned,
+    span::Spanned,
+    symbol::{Symbol, sym},
+};
+
+use crate::node::Node;
+
+/// The string operations available in HashQL.
+///
+/// Each operation corresponds to a function in the `::core::string` module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StringOp {
+    /// `::core::string::lowercase`, converts every character to lowercase.
+    Lowercase,
+    /// `::core::string::uppercase`, converts every character to uppercase.
+    Uppercase,
+    /// `::core::string::contains`, whether the first string contains the second one.
+    Contains,
+    /// `::core::string::starts_with`, whether the first string starts with the second one.
+    StartsWith,
+    /// `::core::string::ends_with`, whether the first string ends with the second one.
+    EndsWith,
+    /// `::core::string::length`, the number of characters in a string.
+    Length,
+    /// `::core::string::concat`, joins two strings together.
+    Concat,
+    /// `::core::string::split`, splits a string at every occurrence of a separator.
+    Split,
+    /// `::core::string::trim`, removes leading and trailing spaces.
+    Trim,
+}
+
+impl StringOp {
+    /// The number of arguments the operation takes.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Lowercase | Self::Uppercase | Self::Length | Self::Trim => 1,
+            Self::Contains | Self::StartsWith | Self::EndsWith | Self::Concat | Self::Split => 2,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Lowercase => sym::path::core::string::lowercase,
+            Self::Uppercase => sym::path::core::string::uppercase,
+            Self::Contains => sym::path::core::string::contains,
+            Self::StartsWith => sym::path::core::string::starts_with,
+            Self::EndsWith => sym::path::core::string::ends_with,
+            Self::Length => sym::path::core::string::length,
+            Self::Concat => sym::path::core::string::concat,
+            Self::Split => sym::path::core::string::split,
+            Self::Trim => sym::path::core::string::trim,
+        }
+    }
+}
+
+/// The list operations available in HashQL.
+///
+/// Each operation corresponds to a function in the `::core::list` module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ListOp {
+    /// `::core::list::len`, the number of elements in a list.
+    Len,
+    /// `::core::list::get`, the element at an index, if any.
+    Get,
+    /// `::core::list::contains`, whether the list contains a value.
+    Contains,
+    /// `::core::list::concat`, joins two lists together.
+    Concat,
+    /// `::core::list::sort`, sorts the elements of a list in ascending order.
+    Sort,
+    /// `::core::list::map`, applies a closure to every element of a list.
+    Map,
+    /// `::core::list::filter`, keeps the elements for which a closure returns `true`.
+    Filter,
+    /// `::core::list::fold`, combines the elements of a list into a single value.
+    Fold,
+}
+
+impl ListOp {
+    /// The number of arguments the operation takes.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Len | Self::Sort => 1,
+            Self::Get | Self::Contains | Self::Concat | Self::Map | Self::Filter => 2,
+            Self::Fold => 3,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Len => sym::path::core::list::len,
+            Self::Get => sym::path::core::list::get,
+            Self::Contains => sym::path::core::list::contains,
+            Self::Concat => sym::path::core::list::concat,
+            Self::Sort => sym::path::core::list::sort,
+            Self::Map => sym::path::core::list::map,
+            Self::Filter => sym::path::core::list::filter,
+            Self::Fold => sym::path::core::list::fold,
+        }
+    }
+}
+
+/// The dictionary operations available in HashQL.
+///
+/// Each operation corresponds to a function in the `::core::dict` module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DictOp {
+    /// `::core::dict::len`, the number of entries in a dictionary.
+    Len,
+    /// `::core::dict::get`, the value associated with a key, if any.
+    Get,
+    /// `::core::dict::contains`, whether the dictionary contains a key.
+    Contains,
+    /// `::core::dict::keys`, the keys of a dictionary in ascending order.
+    Keys,
+    /// `::core::dict::values`, the values of a dictionary in ascending order of their keys.
+    Values,
+    /// `::core::dict::insert`, associates a value with a key, replacing any previous value.
+    Insert,
+    /// `::core::dict::concat`, merges two dictionaries, preferring the entries of the second.
+    Concat,
+}
+
+impl DictOp {
+    /// The number of arguments the operation takes.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Len | Self::Keys | Self::Values => 1,
+            Self::Get | Self::Contains | Self::Concat => 2,
+            Self::Insert => 3,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::Len => sym::path::core::dict::len,
+            Self::Get => sym::path::core::dict::get,
+            Self::Contains => sym::path::core::dict::contains,
+            Self::Keys => sym::path::core::dict::keys,
+            Self::Values => sym::path::core::dict::values,
+            Self::Insert => sym::path::core::dict::insert,
+            Self::Concat => sym::path::core::dict::concat,
+        }
+    }
+}
+
+/// The kinds of intrinsic operations available in HashQL.
+///
+/// Intrinsic operations are standard library functions, which are implemented directly by the
+/// compiler and runtime instead of being defined in HashQL itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntrinsicOp {
+    /// An operation from the `::core::string` module.
+    String(StringOp),
+    /// An operation from the `::core::list` module.
+    List(ListOp),
+    /// An operation from the `::core::dict` module.
+    Dict(DictOp),
+}
+
+impl IntrinsicOp {
+    /// The number of arguments the operation takes.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::String(op) => op.arity(),
+            Self::List(op) => op.arity(),
+            Self::Dict(op) => op.arity(),
+        }
+    }
+
+    /// The fully qualified path of the function implementing the operation.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::String(op) => op.as_symbol(),
+            Self::List(op) => op.as_symbol(),
+            Self::Dict(op) => op.as_symbol(),
+        }
+    }
+}
+
+/// An intrinsic operation expression in the HashQL HIR.
+///
+/// Represents a call to an intrinsic standard library function, which has been specialized after
+/// type checking. The arguments are in the same order as the parameters of the function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntrinsicOperation<'heap> {
+    pub op: Spanned<IntrinsicOp>,
+
+    pub arguments: Interned<'heap, [Node<'heap>]>,
+}
@@ -1,12 +1,14 @@
 pub use self::{
     binary::{BinOp, BinaryOperation},
     input::{InputOp, InputOperation},
+    intrinsic::{DictOp, IntrinsicOp, IntrinsicOperation, ListOp, StringOp},
     r#type::{TypeAssertion, TypeConstructor, TypeOperation},
     unary::{UnOp, UnaryOperation},
 };
 
 mod binary;
 mod input;
+mod intrinsic;
 mod r#type;
 mod unary;
 
@@ -25,4 +27,6 @@ pub enum Operation<'heap> {
     Unary(UnaryOperation<'heap>, !),
     /// Operations that work with externally supplied input values.
     Input(InputOperation<'heap>),
+    /// Operations implemented by the compiler for intrinsic standard library functions.
+    Intrinsic(IntrinsicOperation<'heap>),
 }
@@ -15,16 +15,19 @@ use crate::{
     node::{
         HirPtr, Node,
         access::{Access, FieldAccess, IndexAccess},
-        branch::{Branch, If},
+        branch::{
+            Branch, If, Match,
+            r#match::{ConstructorPattern, MatchArm, OpaquePattern, Pattern, PatternKind},
+        },
         call::{Call, CallArgument},
         closure::{Closure, ClosureSignature, extract_signature, extract_signature_generic},
         data::{Data, Dict, DictField, List, Struct, StructField, Tuple},
-        graph::{Graph, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+        graph::{Graph, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, GraphTraversal},
         kind::NodeKind,
         r#let::{Binding, Let},
         operation::{
-            BinaryOperation, InputOperation, Operation, TypeAssertion, TypeConstructor,
-            TypeOperation, UnaryOperation,
+            BinaryOperation, InputOperation, IntrinsicOperation, Operation, TypeAssertion,
+            TypeConstructor, TypeOperation, UnaryOperation,
         },
         thunk::Thunk,
         variable::{LocalVariable, QualifiedVariable, Variable},
@@ -371,6 +374,7 @@ impl<'fmt, 'heap> FormatNode<'fmt, &Operation<'heap>> for NodeFormatter<'fmt, '_
             Operation::Binary(binary_operation) => self.format_node(binary_operation),
             Operation::Unary(unary_operation, _) => self.format_node(unary_operation),
             Operation::Input(input_operation) => self.format_node(input_operation),
+            Operation::Intrinsic(intrinsic_operation) => self.format_node(intrinsic_operation),
         }
     }
 }
@@ -465,6 +469,25 @@ impl<'fmt, 'heap> FormatNode<'fmt, &InputOperation<'heap>> for NodeFormatter<'fm
     }
 }
 
+impl<'fmt, 'heap> FormatNode<'fmt, &IntrinsicOperation<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
+    fn format_node(
+        &mut self,
+        IntrinsicOperation { op, arguments }: &IntrinsicOperation<'heap>,
+    ) -> Doc<'fmt> {
+        // Format as: ::path::to::intrinsic(arg1, arg2, ...)
+        let fmt = self.fmt;
+
+        let function = fmt.variable(op.value.as_symbol());
+        let arguments = fmt.delimited(
+            "(",
+            arguments.iter().map(|argument| self.format_node(*argument)),
+            ")",
+        );
+
+        function.append(arguments)
+    }
+}
+
 impl<'fmt, 'heap> FormatNode<'fmt, &Access<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
     fn format_node(&mut self, node: &Access<'heap>) -> Doc<'fmt> {
         match node {
@@ -545,6 +568,7 @@ impl<'fmt, 'heap> FormatNode<'fmt, &Branch<'heap>> for NodeFormatter<'fmt, '_, '
     fn format_node(&mut self, node: &Branch<'heap>) -> Doc<'fmt> {
         match node {
             Branch::If(r#if) => self.format_node(r#if),
+            Branch::Match(r#match) => self.format_node(r#match),
         }
     }
 }
@@ -609,6 +633,108 @@ impl<'fmt, 'heap> FormatNode<'fmt, &If<'heap>> for NodeFormatter<'fmt, '_, 'heap
     }
 }
 
+impl<'fmt, 'heap> FormatNode<'fmt, &Match<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
+    fn format_node(&mut self, &Match { scrutinee, arms }: &Match<'heap>) -> Doc<'fmt> {
+        let fmt = self.fmt;
+
+        // Format as: match scrutinee {
+        //                pattern => body,
+        //                ...
+        //            }
+        let keyword = self.fmt.keyword(sym::r#match);
+        let scrutinee = self.format_node(scrutinee);
+
+        let arms = arms.iter().map(|arm| self.format_node(arm));
+        let arms = fmt.intersperse(arms, fmt.punct(sym::symbol::comma).append(fmt.hardline()));
+
+        keyword
+            .append(fmt.space())
+            .append(scrutinee)
+            .append(fmt.space())
+            .append(
+                fmt.braces(
+                    fmt.hardline()
+                        .append(arms)
+                        .nest(fmt.options.indent)
+                        .append(fmt.hardline()),
+                ),
+            )
+    }
+}
+
+impl<'fmt, 'heap> FormatNode<'fmt, &MatchArm<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
+    fn format_node(
+        &mut self,
+        MatchArm {
+            span: _,
+            pattern,
+            body,
+        }: &MatchArm<'heap>,
+    ) -> Doc<'fmt> {
+        let pattern = self.format_node(pattern);
+        let body = self.format_node(*body);
+
+        pattern
+            .append(self.fmt.space())
+            .append(self.fmt.op(sym::symbol::fat_arrow))
+            .append(
+                self.fmt
+                    .line()
+                    .append(body)
+                    .nest(self.fmt.options.indent)
+                    .group(),
+            )
+    }
+}
+
+impl<'fmt, 'heap> FormatNode<'fmt, &Pattern<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
+    fn format_node(
+        &mut self,
+        Pattern {
+            id: _,
+            span: _,
+            kind,
+        }: &Pattern<'heap>,
+    ) -> Doc<'fmt> {
+        let fmt = self.fmt;
+
+        match kind {
+            PatternKind::Wildcard => fmt.punct(sym::symbol::underscore),
+            PatternKind::Binding(binder) => fmt.variable_owned(binder.mangled().to_string()),
+            PatternKind::Literal(literal) => self.format_node(literal),
+            PatternKind::Tuple(elements) => {
+                let elements = elements.iter().map(|element| self.format_node(element));
+
+                fmt.tuple(elements)
+            }
+            PatternKind::Struct(fields) => {
+                let fields = fields.iter().map(|field| {
+                    (
+                        fmt.field(field.name.value),
+                        self.format_node(&field.pattern),
+                    )
+                });
+
+                fmt.r#struct(fields)
+            }
+            PatternKind::Constructor(ConstructorPattern { path, pattern }) => {
+                // Format as: ::path::to::Constructor(pattern)
+                fmt.punct(sym::symbol::coloncolon)
+                    .append(fmt.intersperse(
+                        path.0.iter().map(|ident| fmt.variable(ident.value)),
+                        fmt.punct(sym::symbol::coloncolon),
+                    ))
+                    .append(fmt.parens(self.format_node(&**pattern)))
+            }
+            PatternKind::Opaque(OpaquePattern { name, pattern }) => {
+                // Format as: Name(pattern)
+                fmt.type_name(*name)
+                    .append(fmt.parens(self.format_node(&**pattern)))
+            }
+        }
+    }
+}
+
 impl<'fmt, 'heap> FormatNode<'fmt, &Closure<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
     fn format_node(
         &mut self,
@@ -734,14 +860,10 @@ impl<'fmt, 'heap> FormatNode<'fmt, &GraphRead<'heap>> for NodeFormatter<'fmt, '_
 
 impl<'fmt, 'heap> FormatNode<'fmt, &GraphReadHead<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
     fn format_node(&mut self, node: &GraphReadHead<'heap>) -> Doc<'fmt> {
-        match node {
-            GraphReadHead::Entity { axis } => {
-                // Format as: entity(axis)
-                let keyword = self.fmt.keyword(sym::path::graph_head_entities);
-                let axis = self.format_node(*axis);
-                keyword.append(self.fmt.parens(axis))
-            }
-        }
+        // Format as: entities(axis)
+        let keyword = self.fmt.keyword(node.as_symbol());
+        let axis = self.format_node(node.axis());
+        keyword.append(self.fmt.parens(axis))
     }
 }
 
@@ -761,17 +883,65 @@ impl<'fmt, 'heap> FormatNode<'fmt, &GraphReadBody<'heap>> for NodeFormatter<'fmt
                     }],
                 )
             }
+            &GraphReadBody::Traverse(traversal) => {
+                // Format as: outgoing_links, or traverse(depth)
+                let keyword = self.fmt.keyword(traversal.as_symbol());
+
+                match traversal {
+                    GraphTraversal::Linked { depth } => {
+                        keyword.append(self.fmt.parens(self.fmt.literal_str(depth.to_string())))
+                    }
+                    GraphTraversal::OutgoingLinks
+                    | GraphTraversal::IncomingLinks
+                    | GraphTraversal::LeftEntities
+                    | GraphTraversal::RightEntities => keyword,
+                }
+            }
+            GraphReadBody::SortBy(closure) => {
+                // Format as: sort_by(closure)
+                let keyword = self.fmt.keyword(sym::path::graph_tail_sort_by);
+
+                format_call(
+                    self,
+                    keyword,
+                    &[CallArgument {
+                        span: SpanId::SYNTHETIC,
+                        value: *closure,
+                    }],
+                )
+            }
+            GraphReadBody::Limit(count) => {
+                // Format as: limit(count)
+                let keyword = self.fmt.keyword(sym::path::graph_tail_limit);
+                keyword.append(self.fmt.parens(self.fmt.literal_str(count.to_string())))
+            }
+            GraphReadBody::Offset(count) => {
+                // Format as: offset(count)
+                let keyword = self.fmt.keyword(sym::path::graph_tail_offset);
+                keyword.append(self.fmt.parens(self.fmt.literal_str(count.to_string())))
+            }
         }
     }
 }
 
-impl<'fmt> FormatNode<'fmt, GraphReadTail> for NodeFormatter<'fmt, '_, '_> {
-    fn format_node(&mut self, node: GraphReadTail) -> Doc<'fmt> {
+impl<'fmt, 'heap> FormatNode<'fmt, GraphReadTail<'heap>> for NodeFormatter<'fmt, '_, 'heap> {
+    fn format_node(&mut self, node: GraphReadTail<'heap>) -> Doc<'fmt> {
+        // Format as: collect, or group_by(closure)
+        let keyword = self.fmt.keyword(node.as_symbol());
+
         match node {
-            GraphReadTail::Collect => {
-                // Format as: collect
-                self.fmt.keyword(sym::path::graph_tail_collect)
-            }
+            GraphReadTail::Collect
+            | GraphReadTail::Count
+            | GraphReadTail::Exists
+            | GraphReadTail::First => keyword,
+            GraphReadTail::GroupBy(closure) | GraphReadTail::Aggregate(_, closure) => format_call(
+                self,
+                keyword,
+                &[CallArgument {
+                    span: SpanId::SYNTHETIC,
+                    value: closure,
+                }],
+            ),
         }
     }
 }
@@ -7,8 +7,10 @@ use hashql_ast::{
     node::{
         expr::{
             AsExpr, CallExpr, ClosureExpr, DictExpr, Expr, ExprKind, FieldExpr, IfExpr, IndexExpr,
-            InputExpr, LetExpr, ListExpr, LiteralExpr, StructExpr, TupleExpr, call::Argument,
+            InputExpr, LetExpr, ListExpr, LiteralExpr, MatchExpr, StructExpr, TupleExpr,
+            call::Argument,
             dict::DictEntry,
+            r#match::{self as ast_match, PatternKind as AstPatternKind},
         },
         path::{Path, PathSegmentArgument},
         r#type::Type,
@@ -33,7 +35,10 @@ use crate::{
     node::{
         HirId, Node, NodeData,
         access::{Access, FieldAccess, IndexAccess},
-        branch::{Branch, If},
+        branch::{
+            Branch, If, Match,
+            r#match::{ConstructorPattern, MatchArm, Pattern, PatternKind, StructPattern},
+        },
         call::{Call, CallArgument, PointerKind},
         closure::{Closure, ClosureParam, ClosureSignature},
         data::{Data, Dict, DictField, List, Struct, StructField, Tuple},
@@ -774,6 +779,179 @@ impl<'heap> ReificationContext<'_, '_, '_, 'heap> {
         })))
     }
 
+    fn constructor_pattern_path(&mut self, path: Path<'heap>) -> Option<QualifiedPath<'heap>> {
+        if !path.rooted {
+            self.diagnostics.push(internal_error(
+                path.span,
+                "constructor patterns should have been resolved during import resolution",
+            ));
+            return None;
+        }
+
+        // Generic arguments on constructor patterns are ignored, the type of the pattern is
+        // determined by the scrutinee.
+        let mut segments = SmallVec::with_capacity(path.segments.len());
+        for segment in path.segments {
+            segments.push(segment.name);
+        }
+
+        Some(QualifiedPath::new_unchecked(
+            self.context.interner.intern_idents(&segments),
+        ))
+    }
+
+    /// Converts a pattern, registering every binding it introduces in the binder scope.
+    ///
+    /// The names of all introduced bindings are pushed onto `binders`, the caller is responsible
+    /// to remove them from the scope once the arm has been processed.
+    fn pattern(
+        &mut self,
+        ast_match::Pattern { id: _, span, kind }: ast_match::Pattern<'heap>,
+        binders: &mut Vec<Symbol<'heap>>,
+    ) -> Option<Pattern<'heap>> {
+        let kind = match kind {
+            AstPatternKind::Wildcard => PatternKind::Wildcard,
+            AstPatternKind::Binding(name) => {
+                let binder = Binder {
+                    id: self.context.counter.var.next(),
+                    span: name.span,
+                    name: Some(name.value),
+                };
+
+                // The name manager guarantees that the name is unique within the program
+                self.binder_scope.insert_unique(name.value, binder.id);
+                self.context.symbols.binder.insert(binder.id, name.value);
+                binders.push(name.value);
+
+                PatternKind::Binding(binder)
+            }
+            AstPatternKind::Literal(literal) => PatternKind::Literal(literal),
+            AstPatternKind::Tuple(elements) => {
+                let mut patterns = SmallVec::with_capacity(elements.len());
+                let mut incomplete = false;
+
+                for element in elements {
+                    match self.pattern(element, binders) {
+                        Some(pattern) => patterns.push(pattern),
+                        None => incomplete = true,
+                    }
+                }
+
+                if incomplete {
+                    return None;
+                }
+
+                PatternKind::Tuple(self.context.interner.intern_patterns(&patterns))
+            }
+            AstPatternKind::Struct(fields) => {
+                let mut patterns = SmallVec::with_capacity(fields.len());
+                let mut incomplete = false;
+
+                for field in fields {
+                    match self.pattern(field.pattern, binders) {
+                        Some(pattern) => patterns.push(StructPattern {
+                            name: field.name,
+                            pattern,
+                        }),
+                        None => incomplete = true,
+                    }
+                }
+
+                if incomplete {
+                    return None;
+                }
+
+                PatternKind::Struct(self.context.interner.intern_struct_patterns(&patterns))
+            }
+            AstPatternKind::Constructor(ast_match::ConstructorPattern {
+                id: _,
+                span: constructor_span,
+                path,
+                argument,
+            }) => {
+                let path = self.constructor_pattern_path(path);
+
+                // Constructors without an argument match any value of the underlying type
+                let pattern = match argument {
+                    Some(argument) => self.pattern(*argument, binders),
+                    None => Some(Pattern {
+                        id: self.context.counter.hir.next(),
+                        span: constructor_span,
+                        kind: PatternKind::Wildcard,
+                    }),
+                };
+
+                PatternKind::Constructor(ConstructorPattern {
+                    path: path?,
+                    pattern: self.context.interner.intern_pattern(pattern?),
+                })
+            }
+        };
+
+        Some(Pattern {
+            id: self.context.counter.hir.next(),
+            span,
+            kind,
+        })
+    }
+
+    fn match_arm(
+        &mut self,
+        ast_match::MatchArm {
+            id: _,
+            span,
+            pattern,
+            body,
+        }: ast_match::MatchArm<'heap>,
+    ) -> Option<MatchArm<'heap>> {
+        let mut binders = Vec::new();
+
+        let pattern = self.pattern(pattern, &mut binders);
+        let body = self.expr(*body);
+
+        for binder in binders {
+            self.binder_scope.remove(&binder);
+        }
+
+        Some(MatchArm {
+            span,
+            pattern: pattern?,
+            body: body?,
+        })
+    }
+
+    fn match_expr(
+        &mut self,
+        MatchExpr {
+            id: _,
+            span: _,
+            scrutinee,
+            arms,
+        }: MatchExpr<'heap>,
+    ) -> Option<NodeKind<'heap>> {
+        let scrutinee = self.expr(*scrutinee);
+
+        let mut lowered = SmallVec::with_capacity(arms.len());
+        let mut incomplete = false;
+
+        for arm in arms {
+            match self.match_arm(arm) {
+                Some(arm) => lowered.push(arm),
+                None => incomplete = true,
+            }
+        }
+
+        let scrutinee = scrutinee?;
+        if incomplete {
+            return None;
+        }
+
+        Some(NodeKind::Branch(Branch::Match(Match {
+            scrutinee,
+            arms: self.context.interner.intern_match_arms(&lowered),
+        })))
+    }
+
     fn expr(&mut self, expr: Expr<'heap>) -> Option<Node<'heap>> {
         self.expr_fold(expr, None)
     }
@@ -822,6 +1000,7 @@ impl<'heap> ReificationContext<'_, '_, '_, 'heap> {
             ExprKind::Input(input) => (input.span, self.input_expr(hir_id, input)?),
             ExprKind::Closure(closure) => (closure.span, self.closure_expr(hir_id, closure)?),
             ExprKind::If(r#if) => (r#if.span, self.if_expr(r#if)?),
+            ExprKind::Match(r#match) => (r#match.span, self.match_expr(r#match)?),
             ExprKind::Field(field) => (field.span, self.field_expr(field)?),
             ExprKind::Index(index) => (index.span, self.index_expr(index)?),
             ExprKind::As(r#as) => (r#as.span, self.as_expr(r#as)?),
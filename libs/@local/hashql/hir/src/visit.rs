@@ -63,19 +63,24 @@ use crate::{
     node::{
         HirId, Node, NodeData,
         access::{Access, FieldAccess, IndexAccess},
-        branch::{Branch, If},
+        branch::{
+            Branch, If, Match,
+            r#match::{
+                ConstructorPattern, MatchArm, OpaquePattern, Pattern, PatternKind, StructPattern,
+            },
+        },
         call::{Call, CallArgument},
         closure::{Closure, ClosureParam, ClosureSignature},
         data::{Data, Dict, DictField, List, Struct, StructField, Tuple},
         graph::{
             Graph,
-            read::{GraphRead, GraphReadBody, GraphReadHead, GraphReadTail},
+            read::{GraphRead, GraphReadBody, GraphReadTail},
         },
         kind::NodeKind,
         r#let::{Binder, Binding, Let, VarId},
         operation::{
-            BinaryOperation, InputOperation, Operation, TypeAssertion, TypeConstructor,
-            TypeOperation, UnaryOperation,
+            BinaryOperation, InputOperation, IntrinsicOperation, Operation, TypeAssertion,
+            TypeConstructor, TypeOperation, UnaryOperation,
         },
         thunk::Thunk,
         variable::{LocalVariable, QualifiedVariable, Variable},
@@ -246,6 +251,10 @@ pub trait Visitor<'heap> {
         walk_input_operation(self, operation);
     }
 
+    fn visit_intrinsic_operation(&mut self, operation: &'heap IntrinsicOperation<'heap>) {
+        walk_intrinsic_operation(self, operation);
+    }
+
     fn visit_access(&mut self, access: &'heap Access<'heap>) {
         walk_access(self, access);
     }
@@ -274,6 +283,22 @@ pub trait Visitor<'heap> {
         walk_if(self, r#if);
     }
 
+    fn visit_match(&mut self, r#match: &'heap Match<'heap>) {
+        walk_match(self, r#match);
+    }
+
+    fn visit_match_arm(&mut self, arm: &'heap MatchArm<'heap>) {
+        walk_match_arm(self, arm);
+    }
+
+    fn visit_pattern(&mut self, pattern: &'heap Pattern<'heap>) {
+        walk_pattern(self, pattern);
+    }
+
+    fn visit_struct_pattern(&mut self, field: &'heap StructPattern<'heap>) {
+        walk_struct_pattern(self, field);
+    }
+
     fn visit_closure(&mut self, closure: &'heap Closure<'heap>) {
         walk_closure(self, closure);
     }
@@ -479,6 +504,7 @@ pub fn walk_operation<'heap, T: Visitor<'heap> + ?Sized>(
         Operation::Type(operation) => visitor.visit_type_operation(operation),
         Operation::Binary(operation) => visitor.visit_binary_operation(operation),
         Operation::Input(operation) => visitor.visit_input_operation(operation),
+        Operation::Intrinsic(operation) => visitor.visit_intrinsic_operation(operation),
     }
 }
 
@@ -539,6 +565,17 @@ pub fn walk_input_operation<'heap, T: Visitor<'heap> + ?Sized>(
     visitor.visit_ident(name);
 }
 
+pub fn walk_intrinsic_operation<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    IntrinsicOperation { op, arguments }: &'heap IntrinsicOperation<'heap>,
+) {
+    visitor.visit_span(op.span);
+
+    for argument in arguments {
+        visitor.visit_node(*argument);
+    }
+}
+
 pub fn walk_access<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     access: &'heap Access<'heap>,
@@ -594,6 +631,7 @@ pub fn walk_branch<'heap, T: Visitor<'heap> + ?Sized>(
 ) {
     match branch {
         Branch::If(r#if) => visitor.visit_if(r#if),
+        Branch::Match(r#match) => visitor.visit_match(r#match),
     }
 }
 
@@ -607,6 +645,70 @@ pub fn walk_if<'heap, T: Visitor<'heap> + ?Sized>(
     visitor.visit_node(*r#else);
 }
 
+pub fn walk_match<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Match { scrutinee, arms }: &'heap Match<'heap>,
+) {
+    visitor.visit_node(*scrutinee);
+
+    for arm in arms {
+        visitor.visit_match_arm(arm);
+    }
+}
+
+pub fn walk_match_arm<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    MatchArm {
+        span,
+        pattern,
+        body,
+    }: &'heap MatchArm<'heap>,
+) {
+    visitor.visit_span(*span);
+    visitor.visit_pattern(pattern);
+    visitor.visit_node(*body);
+}
+
+pub fn walk_pattern<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    Pattern { id, span, kind }: &'heap Pattern<'heap>,
+) {
+    visitor.visit_id(*id);
+    visitor.visit_span(*span);
+
+    match kind {
+        PatternKind::Wildcard => {}
+        PatternKind::Binding(binder) => visitor.visit_binder(binder),
+        PatternKind::Literal(literal) => visitor.visit_primitive(literal),
+        PatternKind::Tuple(elements) => {
+            for element in elements {
+                visitor.visit_pattern(element);
+            }
+        }
+        PatternKind::Struct(fields) => {
+            for field in fields {
+                visitor.visit_struct_pattern(field);
+            }
+        }
+        PatternKind::Constructor(ConstructorPattern { path, pattern }) => {
+            visitor.visit_qualified_path(path);
+            visitor.visit_pattern(pattern);
+        }
+        PatternKind::Opaque(OpaquePattern { name, pattern }) => {
+            visitor.visit_symbol(name);
+            visitor.visit_pattern(pattern);
+        }
+    }
+}
+
+pub fn walk_struct_pattern<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    StructPattern { name, pattern }: &'heap StructPattern<'heap>,
+) {
+    visitor.visit_ident(name);
+    visitor.visit_pattern(pattern);
+}
+
 pub fn walk_closure<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     Closure { signature, body }: &'heap Closure<'heap>,
@@ -652,17 +754,22 @@ pub fn walk_graph_read<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     GraphRead { head, body, tail }: &'heap GraphRead<'heap>,
 ) {
-    match head {
-        GraphReadHead::Entity { axis } => visitor.visit_node(*axis),
-    }
+    visitor.visit_node(head.axis());
 
     for body in body {
         match body {
-            GraphReadBody::Filter(node) => visitor.visit_node(*node),
+            GraphReadBody::Filter(node) | GraphReadBody::SortBy(node) => visitor.visit_node(*node),
+            GraphReadBody::Traverse(_) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => {}
         }
     }
 
     match tail {
-        GraphReadTail::Collect => {}
+        GraphReadTail::Collect
+        | GraphReadTail::Count
+        | GraphReadTail::Exists
+        | GraphReadTail::First => {}
+        GraphReadTail::GroupBy(node) | GraphReadTail::Aggregate(_, node) => {
+            visitor.visit_node(*node);
+        }
     }
 }
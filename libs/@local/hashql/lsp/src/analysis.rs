@@ -0,0 +1,254 @@
+//! Analysis of a single document.
+//!
+//! Every change re-runs the frontend from scratch: the source is parsed, lowered into the HIR and
+//! type checked against the standard library. The results are extracted into owned data, so that
+//! the heap used during the analysis is released right away.
+
+use anstream::adapter::strip_str;
+use hashql_ast::visit::Visitor as _;
+use hashql_core::{
+    heap::{Heap, Scratch},
+    module::ModuleRegistry,
+    pretty::{Formatter, RenderOptions},
+    span::{SpanId, SpanTable, TextRange},
+    r#type::{TypeFormatter, TypeFormatterOptions, TypeId, environment::Environment},
+};
+use hashql_diagnostics::{
+    Diagnostic, DiagnosticCategory, Failure, Severity, Status, Success,
+    category::{canonical_category_id, canonical_category_name},
+    diagnostic::BoxedDiagnostic,
+    issues::BoxedDiagnosticIssues,
+    source::SourceId,
+};
+use hashql_hir::{
+    context::HirContext,
+    node::Node,
+    visit::{self, Visitor},
+};
+use hashql_syntax_jexpr::span::Span;
+use line_index::LineIndex;
+use lsp_types::{DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Uri};
+
+use crate::{
+    convert,
+    definition::{Reference, ReferenceCollector},
+};
+
+/// Unwraps a [`Status`] into its success value, draining advisories and secondary diagnostics
+/// into the shared accumulator.
+///
+/// On failure, secondary diagnostics are drained and the primary diagnostic is returned as the
+/// error.
+fn process_status<T, C>(
+    diagnostics: &mut BoxedDiagnosticIssues<'static, SpanId>,
+    status: Status<T, C, SpanId>,
+) -> Result<T, BoxedDiagnostic<'static, SpanId>>
+where
+    C: DiagnosticCategory + 'static,
+{
+    match status {
+        Ok(Success { value, advisories }) => {
+            diagnostics.extend(
+                advisories
+                    .into_iter()
+                    .map(|advisory| advisory.generalize().boxed()),
+            );
+
+            Ok(value)
+        }
+        Err(Failure { primary, secondary }) => {
+            diagnostics.extend(secondary.into_iter().map(Diagnostic::boxed));
+
+            Err(primary.generalize().boxed())
+        }
+    }
+}
+
+const fn severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Bug | Severity::Fatal | Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Note => DiagnosticSeverity::INFORMATION,
+        Severity::Debug => DiagnosticSeverity::HINT,
+    }
+}
+
+fn convert_diagnostic(
+    uri: &Uri,
+    lines: &LineIndex,
+    spans: &SpanTable<Span>,
+    diagnostic: &BoxedDiagnostic<'static, SpanId>,
+) -> lsp_types::Diagnostic {
+    let resolve = |span: SpanId| {
+        spans
+            .absolute(span)
+            .map(|span| convert::range(lines, span.range()))
+    };
+
+    let mut labels = diagnostic.labels.iter();
+    let primary = labels.next();
+
+    let mut message = diagnostic.title.as_deref().map_or_else(
+        || canonical_category_name(&diagnostic.category).to_string(),
+        ToOwned::to_owned,
+    );
+
+    if let Some(label) = primary
+        && !label.message().is_empty()
+    {
+        message.push('\n');
+        message.push_str(label.message());
+    }
+
+    for note in diagnostic.messages.iter() {
+        message.push('\n');
+        message.push_str(note.message());
+    }
+
+    let related: Vec<_> = labels
+        .filter_map(|label| {
+            Some(DiagnosticRelatedInformation {
+                location: Location::new(uri.clone(), resolve(*label.span())?),
+                message: strip_str(label.message()).to_string(),
+            })
+        })
+        .collect();
+
+    lsp_types::Diagnostic {
+        // Diagnostics that cannot be traced back to the source are shown at its start
+        range: primary
+            .and_then(|label| resolve(*label.span()))
+            .unwrap_or_default(),
+        severity: Some(severity(diagnostic.severity)),
+        code: Some(NumberOrString::String(
+            canonical_category_id(&diagnostic.category).to_string(),
+        )),
+        source: Some("hashql".to_owned()),
+        // Messages are styled for the terminal, which clients would display verbatim
+        message: strip_str(&message).to_string(),
+        related_information: (!related.is_empty()).then_some(related),
+        ..lsp_types::Diagnostic::default()
+    }
+}
+
+/// Collects the type of every node, that can be traced back to the source.
+struct NodeTypes<'ctx, 'env, 'heap> {
+    spans: &'ctx SpanTable<Span>,
+    context: &'ctx HirContext<'env, 'heap>,
+
+    types: Vec<(TextRange, TypeId)>,
+}
+
+impl<'heap> Visitor<'heap> for NodeTypes<'_, '_, 'heap> {
+    fn visit_node(&mut self, node: Node<'heap>) {
+        let type_id = self.context.map.type_id(node.id);
+
+        if type_id != TypeId::PLACEHOLDER
+            && let Some(span) = self.spans.absolute(node.span)
+        {
+            self.types.push((span.range(), type_id));
+        }
+
+        visit::walk_node(self, node);
+    }
+}
+
+/// The result of analyzing a document, independent of the heap it has been analyzed on.
+#[derive(Debug, Default)]
+pub(crate) struct Analysis {
+    pub diagnostics: Vec<lsp_types::Diagnostic>,
+    /// The rendered type of every expression, expressions may be nested.
+    pub types: Vec<(TextRange, String)>,
+    pub references: Vec<Reference>,
+}
+
+impl Analysis {
+    pub(crate) fn new(uri: &Uri, source: &str, lines: &LineIndex) -> Self {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut diagnostics = BoxedDiagnosticIssues::default();
+
+        let mut analysis = Self::default();
+
+        if let Err(diagnostic) = analysis.analyze(&heap, source, &mut spans, &mut diagnostics) {
+            diagnostics.push(diagnostic);
+        }
+
+        analysis.diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| convert_diagnostic(uri, lines, &spans, diagnostic))
+            .collect();
+
+        analysis
+    }
+
+    fn analyze(
+        &mut self,
+        heap: &Heap,
+        source: &str,
+        spans: &mut SpanTable<Span>,
+        diagnostics: &mut BoxedDiagnosticIssues<'static, SpanId>,
+    ) -> Result<(), BoxedDiagnostic<'static, SpanId>> {
+        let mut parser = hashql_syntax_jexpr::Parser::new(heap, spans);
+        let mut expr = parser
+            .parse_expr(source.as_bytes())
+            .map_err(|diagnostic| diagnostic.generalize().boxed())?;
+
+        // References need to be collected before lowering, which resolves names in place
+        let mut references = ReferenceCollector::new(spans);
+        references.visit_expr(&mut expr);
+        self.references = references.finish();
+
+        let mut env = Environment::new(heap);
+        let registry = ModuleRegistry::new(&env);
+        let mut scratch = Scratch::new();
+
+        let types = process_status(
+            diagnostics,
+            hashql_ast::lower::lower(
+                heap.intern_symbol("::main"),
+                &mut expr,
+                &env,
+                &registry,
+                &mut scratch,
+            ),
+        )?;
+
+        let interner = hashql_hir::intern::Interner::new(heap);
+        let mut context = HirContext::new(&interner, &registry);
+
+        let node = process_status(
+            diagnostics,
+            hashql_hir::node::NodeData::from_ast(expr, &mut context, &types),
+        )?;
+
+        let (node, _) = process_status(
+            diagnostics,
+            hashql_hir::lower::check(node, &types, &mut env, &mut context),
+        )?;
+
+        let mut collector = NodeTypes {
+            spans,
+            context: &context,
+            types: Vec::new(),
+        };
+        collector.visit_node(node);
+
+        let formatter = Formatter::new(heap);
+        let mut formatter = TypeFormatter::new(&formatter, &env, TypeFormatterOptions::terse());
+
+        self.types = collector
+            .types
+            .into_iter()
+            .map(|(range, type_id)| {
+                let rendered = formatter
+                    .render(type_id, RenderOptions::default().with_plain())
+                    .to_string();
+
+                (range, rendered)
+            })
+            .collect();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! Completion of paths into the standard library.
+//!
+//! Completion is purely textual: the path in front of the cursor is resolved against the module
+//! registry, so that it also works in documents that don't parse.
+
+use hashql_core::{
+    heap::Heap,
+    module::{
+        Module, ModuleRegistry,
+        item::{IntrinsicItem, Item, ItemKind},
+        namespace::ModuleNamespace,
+    },
+    symbol::Symbol,
+    r#type::environment::Environment,
+};
+use lsp_types::{CompletionItem, CompletionItemKind};
+
+fn is_path_char(char: char) -> bool {
+    char.is_alphanumeric() || char == '_' || char == ':'
+}
+
+fn is_ident(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_alphabetic() || char == '_')
+}
+
+/// Resolves the module at the given (absolute) path.
+fn resolve_module<'heap>(registry: &ModuleRegistry<'heap>, path: &str) -> Option<Module<'heap>> {
+    let mut segments = path
+        .trim_start_matches("::")
+        .split("::")
+        .map(|segment| registry.heap.intern_symbol(segment));
+
+    let mut module = registry.find_by_name(segments.next()?)?;
+
+    for segment in segments {
+        module = module.items.iter().find_map(|item| {
+            if item.name == segment
+                && let ItemKind::Module(id) = item.kind
+            {
+                Some(registry.modules.index(id))
+            } else {
+                None
+            }
+        })?;
+    }
+
+    Some(module)
+}
+
+fn completion_item<'heap>(
+    registry: &ModuleRegistry<'heap>,
+    name: Symbol<'heap>,
+    item: Item<'heap>,
+) -> CompletionItem {
+    let kind = match item.kind {
+        ItemKind::Module(_) => CompletionItemKind::MODULE,
+        ItemKind::Type(_) | ItemKind::Intrinsic(IntrinsicItem::Type(_)) => {
+            CompletionItemKind::STRUCT
+        }
+        ItemKind::Constructor(_) => CompletionItemKind::CONSTRUCTOR,
+        ItemKind::Intrinsic(IntrinsicItem::Value(_)) => CompletionItemKind::FUNCTION,
+    };
+
+    let mut path: Vec<_> = item.absolute_path_rev(registry).collect();
+    path.reverse();
+
+    let mut detail = String::new();
+    for segment in path {
+        detail.push_str("::");
+        detail.push_str(segment.as_str());
+    }
+
+    CompletionItem {
+        label: name.as_str().to_owned(),
+        kind: Some(kind),
+        detail: Some(detail),
+        ..CompletionItem::default()
+    }
+}
+
+/// Completes the path at the end of `prefix`, which is the text of the line in front of the
+/// cursor.
+///
+/// Names without a module are completed from the prelude, otherwise from the items of the module.
+pub(crate) fn complete(prefix: &str) -> Vec<CompletionItem> {
+    let path = prefix
+        .rsplit(|char: char| !is_path_char(char))
+        .next()
+        .unwrap_or(prefix);
+
+    let heap = Heap::new();
+    let env = Environment::new(&heap);
+    let registry = ModuleRegistry::new(&env);
+
+    let mut namespace = ModuleNamespace::new(&registry);
+    namespace.import_prelude();
+
+    match path.rsplit_once("::") {
+        // A leading `::` refers to the root modules
+        Some(("", _)) => namespace
+            .items()
+            .filter(|(_, item)| matches!(item.kind, ItemKind::Module(_)))
+            .map(|(name, item)| completion_item(&registry, name, item))
+            .collect(),
+        Some((module, _)) => resolve_module(&registry, module)
+            .map(|module| {
+                module
+                    .items
+                    .iter()
+                    .map(|&item| completion_item(&registry, item.name, item))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        None => namespace
+            .items()
+            .filter(|(name, _)| is_ident(name.as_str()))
+            .map(|(name, item)| completion_item(&registry, name, item))
+            .collect(),
+    }
+}
@@ -0,0 +1,46 @@
+//! Conversion between byte offsets and LSP positions.
+//!
+//! Spans address the source in bytes, while LSP positions count the columns of a line in UTF-16
+//! code units, which is the only encoding every client supports.
+
+use hashql_core::span::{TextRange, TextSize};
+use line_index::{LineCol, LineIndex, WideEncoding, WideLineCol};
+use lsp_types::{Position, Range};
+
+pub(crate) fn position(lines: &LineIndex, offset: TextSize) -> Position {
+    let line_col = lines.line_col(offset);
+
+    let WideLineCol { line, col } =
+        lines
+            .to_wide(WideEncoding::Utf16, line_col)
+            .unwrap_or(WideLineCol {
+                line: line_col.line,
+                col: line_col.col,
+            });
+
+    Position::new(line, col)
+}
+
+pub(crate) fn range(lines: &LineIndex, range: TextRange) -> Range {
+    Range::new(position(lines, range.start()), position(lines, range.end()))
+}
+
+pub(crate) fn offset(lines: &LineIndex, position: Position) -> Option<TextSize> {
+    let line_col = lines.to_utf8(
+        WideEncoding::Utf16,
+        WideLineCol {
+            line: position.line,
+            col: position.character,
+        },
+    )?;
+
+    lines.offset(line_col)
+}
+
+/// Returns the offset of the start of the line `position` is on.
+pub(crate) fn line_start(lines: &LineIndex, position: Position) -> Option<TextSize> {
+    lines.offset(LineCol {
+        line: position.line,
+        col: 0,
+    })
+}
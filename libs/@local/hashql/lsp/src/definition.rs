@@ -0,0 +1,254 @@
+//! Go to definition for local bindings and imports.
+//!
+//! The expander resolves names in place and erases `use` entirely, which is why references are
+//! collected on the tree as it was parsed, by tracking the names that are bound by the special
+//! forms.
+
+use hashql_ast::{
+    node::{
+        expr::{CallExpr, Expr, ExprKind, call::Argument},
+        path::Path,
+    },
+    visit::{self, Visitor},
+};
+use hashql_core::{
+    span::{SpanId, SpanTable, TextRange},
+    symbol::{Ident, Symbol, sym},
+};
+use hashql_syntax_jexpr::span::Span;
+
+/// A reference to a binding in the source.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Reference {
+    pub range: TextRange,
+    pub definition: TextRange,
+}
+
+/// Returns the identifier, if the expression is a path consisting of a single identifier.
+fn ident<'heap>(expr: &Expr<'heap>) -> Option<Ident<'heap>> {
+    let ExprKind::Path(path) = &expr.kind else {
+        return None;
+    };
+
+    path.as_generic_ident().map(|(ident, _)| ident)
+}
+
+/// Returns the names bound by the elements of a tuple, or the keys of a struct, such as the
+/// generics or the parameters of a closure.
+fn field_bindings<'heap>(expr: &Expr<'heap>) -> Vec<Ident<'heap>> {
+    match &expr.kind {
+        ExprKind::Tuple(tuple) => tuple
+            .elements
+            .iter()
+            .filter_map(|element| ident(&element.value))
+            .collect(),
+        ExprKind::Struct(r#struct) => r#struct.entries.iter().map(|entry| entry.key).collect(),
+        ExprKind::Call(_)
+        | ExprKind::Dict(_)
+        | ExprKind::List(_)
+        | ExprKind::Literal(_)
+        | ExprKind::Path(_)
+        | ExprKind::Let(_)
+        | ExprKind::Type(_)
+        | ExprKind::NewType(_)
+        | ExprKind::Input(_)
+        | ExprKind::Closure(_)
+        | ExprKind::If(_)
+        | ExprKind::Match(_)
+        | ExprKind::Field(_)
+        | ExprKind::Index(_)
+        | ExprKind::As(_)
+        | ExprKind::Underscore
+        | ExprKind::Dummy => Vec::new(),
+    }
+}
+
+/// Returns the names bound by the imports of a `use`, these are the aliases if present.
+///
+/// Glob imports don't bind any name that is visible in the source.
+fn import_bindings<'heap>(expr: &Expr<'heap>) -> Vec<Ident<'heap>> {
+    if let ExprKind::Struct(r#struct) = &expr.kind {
+        return r#struct
+            .entries
+            .iter()
+            .map(|entry| ident(&entry.value).unwrap_or(entry.key))
+            .collect();
+    }
+
+    field_bindings(expr)
+}
+
+/// Collects the names bound by a pattern of a `match`.
+///
+/// Bindings and nullary constructors, such as `None`, are syntactically indistinguishable, names
+/// starting with an uppercase letter are assumed to be constructors.
+fn pattern_bindings<'heap>(expr: &Expr<'heap>, bindings: &mut Vec<Ident<'heap>>) {
+    match &expr.kind {
+        ExprKind::Path(_) => {
+            if let Some(ident) = ident(expr)
+                && !ident.value.as_str().starts_with(char::is_uppercase)
+            {
+                bindings.push(ident);
+            }
+        }
+        ExprKind::Call(call) => {
+            for argument in &call.arguments {
+                pattern_bindings(&argument.value, bindings);
+            }
+        }
+        ExprKind::Tuple(tuple) => {
+            for element in &tuple.elements {
+                pattern_bindings(&element.value, bindings);
+            }
+        }
+        ExprKind::Struct(r#struct) => {
+            for entry in &r#struct.entries {
+                pattern_bindings(&entry.value, bindings);
+            }
+        }
+        ExprKind::Dict(_)
+        | ExprKind::List(_)
+        | ExprKind::Literal(_)
+        | ExprKind::Let(_)
+        | ExprKind::Type(_)
+        | ExprKind::NewType(_)
+        | ExprKind::Input(_)
+        | ExprKind::Closure(_)
+        | ExprKind::If(_)
+        | ExprKind::Match(_)
+        | ExprKind::Field(_)
+        | ExprKind::Index(_)
+        | ExprKind::As(_)
+        | ExprKind::Underscore
+        | ExprKind::Dummy => {}
+    }
+}
+
+/// Collects every reference to a binding, that has been introduced by a special form.
+pub(crate) struct ReferenceCollector<'spans, 'heap> {
+    spans: &'spans SpanTable<Span>,
+
+    scope: Vec<Ident<'heap>>,
+    references: Vec<Reference>,
+}
+
+impl<'spans, 'heap> ReferenceCollector<'spans, 'heap> {
+    pub(crate) const fn new(spans: &'spans SpanTable<Span>) -> Self {
+        Self {
+            spans,
+            scope: Vec::new(),
+            references: Vec::new(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> Vec<Reference> {
+        self.references
+    }
+
+    fn range(&self, span: SpanId) -> Option<TextRange> {
+        self.spans.absolute(span).map(|span| span.range())
+    }
+
+    /// Visits `body` with the given bindings in scope.
+    fn visit_scoped(
+        &mut self,
+        bindings: impl IntoIterator<Item = Ident<'heap>>,
+        body: &mut Expr<'heap>,
+    ) {
+        let depth = self.scope.len();
+
+        self.scope.extend(bindings);
+        self.visit_expr(body);
+        self.scope.truncate(depth);
+    }
+
+    /// Visits the arguments of a special form, returns `false` if the arguments don't have the
+    /// shape of the special form, in which case they are visited like any other call.
+    fn visit_special_form(
+        &mut self,
+        form: Symbol<'heap>,
+        arguments: &mut [Argument<'heap>],
+    ) -> bool {
+        if form == sym::r#let
+            && let [name, rest @ .., body] = arguments
+            && matches!(rest.len(), 1 | 2)
+            && let Some(name) = ident(&name.value)
+        {
+            for argument in rest {
+                self.visit_argument(argument);
+            }
+
+            self.visit_scoped([name], &mut body.value);
+        } else if (form == sym::r#type || form == sym::r#newtype)
+            && let [name, value, body] = arguments
+            && let Some(name) = ident(&name.value)
+        {
+            self.visit_argument(value);
+            self.visit_scoped([name], &mut body.value);
+        } else if form == sym::r#use
+            && let [_, imports, body] = arguments
+        {
+            let bindings = import_bindings(&imports.value);
+            self.visit_scoped(bindings, &mut body.value);
+        } else if form == sym::r#fn
+            && let [generics, params, returns, body] = arguments
+        {
+            let depth = self.scope.len();
+
+            // Generics are in scope for the signature and the body
+            self.scope.extend(field_bindings(&generics.value));
+            self.visit_argument(params);
+            self.visit_argument(returns);
+
+            let bindings = field_bindings(&params.value);
+            self.visit_scoped(bindings, &mut body.value);
+
+            self.scope.truncate(depth);
+        } else if form == sym::r#match
+            && let [value, arms @ ..] = arguments
+            && let (arms @ [_, ..], []) = arms.as_chunks_mut::<2>()
+        {
+            self.visit_argument(value);
+
+            for [pattern, body] in arms {
+                let mut bindings = Vec::new();
+                pattern_bindings(&pattern.value, &mut bindings);
+
+                self.visit_scoped(bindings, &mut body.value);
+            }
+        } else {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl<'heap> Visitor<'heap> for ReferenceCollector<'_, 'heap> {
+    fn visit_path(&mut self, path: &mut Path<'heap>) {
+        if let Some((ident, _)) = path.as_generic_ident()
+            && let Some(binding) = self
+                .scope
+                .iter()
+                .rev()
+                .find(|binding| binding.value == ident.value)
+            && let Some(definition) = self.range(binding.span)
+            && let Some(range) = self.range(ident.span)
+        {
+            self.references.push(Reference { range, definition });
+        }
+
+        visit::walk_path(self, path);
+    }
+
+    fn visit_call_expr(&mut self, expr: &mut CallExpr<'heap>) {
+        if let Some(form) = ident(&expr.function)
+            && expr.labeled_arguments.is_empty()
+            && self.visit_special_form(form.value, &mut expr.arguments)
+        {
+            return;
+        }
+
+        visit::walk_call_expr(self, expr);
+    }
+}
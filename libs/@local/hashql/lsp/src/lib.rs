@@ -0,0 +1,46 @@
+//! # HashQL language server
+//!
+//! A language server for HashQL queries written in J-Expr, communicating with the editor over
+//! stdio. Queries are checked with the same parser and lowering passes as the `/hashql` endpoint,
+//! against the standard library only, and provides:
+//!
+//! - diagnostics from parsing, name resolution and type checking
+//! - the type of the expression under the cursor on hover
+//! - go to definition for local bindings and names imported with `use`
+//! - completion of paths into the standard library
+//!
+//! ## Workspace dependencies
+#![doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd")]
+
+use error_stack::{Report, ResultExt as _};
+use lsp_server::Connection;
+
+use self::server::Server;
+pub use self::server::ServerError;
+
+mod analysis;
+mod completion;
+mod convert;
+mod definition;
+mod server;
+
+/// Runs the language server over stdio, until the client requests it to shut down.
+///
+/// # Errors
+///
+/// - [`ServerError::Initialize`] if the handshake with the client failed
+/// - [`ServerError::Connection`] if the connection to the client broke down
+/// - [`ServerError::Shutdown`] if the connection could not be shut down cleanly
+pub fn run() -> Result<(), Report<ServerError>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities =
+        serde_json::to_value(Server::capabilities()).change_context(ServerError::Initialize)?;
+    connection
+        .initialize(capabilities)
+        .change_context(ServerError::Initialize)?;
+
+    Server::new(connection).run()?;
+
+    io_threads.join().change_context(ServerError::Shutdown)
+}
@@ -0,0 +1,9 @@
+//! ## Workspace dependencies
+#![doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd")]
+
+use error_stack::Report;
+use hashql_lsp::ServerError;
+
+fn main() -> Result<(), Report<ServerError>> {
+    hashql_lsp::run()
+}
@@ -0,0 +1,635 @@
+//! The main loop of the language server.
+//!
+//! Documents are synchronized in full, and analyzed whenever they are opened or changed.
+//! Requests are answered from the result of the latest analysis.
+
+use core::error::Error;
+
+use error_stack::{Report, ResultExt as _};
+use hashql_core::{collections::FastHashMap, span::TextSize};
+use line_index::LineIndex;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    CompletionOptions, CompletionParams, CompletionResponse, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverContents, HoverParams, HoverProviderCapability, Location,
+    MarkupContent, MarkupKind, OneOf, Position, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind, Uri,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+};
+
+use crate::{analysis::Analysis, completion, convert};
+
+/// Errors that cause the language server to stop.
+#[derive(Debug, derive_more::Display)]
+pub enum ServerError {
+    #[display("unable to initialize the connection to the client")]
+    Initialize,
+    #[display("unable to communicate with the client")]
+    Connection,
+    #[display("unable to shut down the connection to the client")]
+    Shutdown,
+}
+
+impl Error for ServerError {}
+
+struct Document {
+    text: String,
+    lines: LineIndex,
+
+    analysis: Analysis,
+}
+
+impl Document {
+    fn new(uri: &Uri, text: String) -> Self {
+        let lines = LineIndex::new(&text);
+        let analysis = Analysis::new(uri, &text, &lines);
+
+        Self {
+            text,
+            lines,
+            analysis,
+        }
+    }
+
+    fn offset(&self, position: Position) -> Option<TextSize> {
+        convert::offset(&self.lines, position)
+    }
+}
+
+/// Answers a request of type `R` using the `handler`.
+fn dispatch<R>(request: Request, handler: impl FnOnce(R::Params) -> R::Result) -> Response
+where
+    R: lsp_types::request::Request,
+{
+    let id = request.id.clone();
+
+    match request.extract(R::METHOD) {
+        Ok((id, params)) => Response::new_ok(id, handler(params)),
+        Err(error) => Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+}
+
+pub(crate) struct Server {
+    connection: Connection,
+    documents: FastHashMap<Uri, Document>,
+}
+
+impl Server {
+    pub(crate) fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                // Paths in J-Expr are strings, which start with a quote
+                trigger_characters: Some(vec![":".to_owned(), "\"".to_owned()]),
+                ..CompletionOptions::default()
+            }),
+            ..ServerCapabilities::default()
+        }
+    }
+
+    pub(crate) fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: FastHashMap::default(),
+        }
+    }
+
+    /// Handles messages until the client requests the server to shut down.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServerError::Connection`] if the connection to the client broke down.
+    pub(crate) fn run(mut self) -> Result<(), Report<ServerError>> {
+        // The channel is closed once the client disconnected
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self
+                        .connection
+                        .handle_shutdown(&request)
+                        .change_context(ServerError::Connection)?
+                    {
+                        return Ok(());
+                    }
+
+                    let response = self.handle_request(request);
+                    self.send(response.into())?;
+                }
+                Message::Notification(notification) => {
+                    self.handle_notification(notification)?;
+                }
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send(&self, message: Message) -> Result<(), Report<ServerError>> {
+        self.connection
+            .sender
+            .send(message)
+            .change_context(ServerError::Connection)
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => dispatch::<HoverRequest>(request, |params| self.hover(&params)),
+            GotoDefinition::METHOD => {
+                dispatch::<GotoDefinition>(request, |params| self.definition(&params))
+            }
+            Completion::METHOD => {
+                dispatch::<Completion>(request, |params| self.completion(&params))
+            }
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{}`", request.method),
+            ),
+        }
+    }
+
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<(), Report<ServerError>> {
+        // Malformed notifications cannot be answered, and are therefore ignored
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Ok(DidOpenTextDocumentParams { text_document }) =
+                    notification.extract(DidOpenTextDocument::METHOD)
+                else {
+                    return Ok(());
+                };
+
+                self.update(text_document.uri, text_document.text, text_document.version)
+            }
+            DidChangeTextDocument::METHOD => {
+                let Ok(DidChangeTextDocumentParams {
+                    text_document,
+                    content_changes,
+                }) = notification.extract(DidChangeTextDocument::METHOD)
+                else {
+                    return Ok(());
+                };
+
+                // The server only supports full synchronization, the last change is the document
+                let Some(change) = content_changes.into_iter().next_back() else {
+                    return Ok(());
+                };
+
+                self.update(text_document.uri, change.text, text_document.version)
+            }
+            DidCloseTextDocument::METHOD => {
+                let Ok(DidCloseTextDocumentParams { text_document }) =
+                    notification.extract(DidCloseTextDocument::METHOD)
+                else {
+                    return Ok(());
+                };
+
+                self.documents.remove(&text_document.uri);
+                self.publish_diagnostics(text_document.uri, Vec::new(), None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn update(&mut self, uri: Uri, text: String, version: i32) -> Result<(), Report<ServerError>> {
+        let document = Document::new(&uri, text);
+        let diagnostics = document.analysis.diagnostics.clone();
+
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Uri,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+        version: Option<i32>,
+    ) -> Result<(), Report<ServerError>> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version,
+        };
+
+        self.send(Notification::new(PublishDiagnostics::METHOD.to_owned(), params).into())
+    }
+
+    fn document(
+        &self,
+        TextDocumentPositionParams {
+            text_document,
+            position,
+        }: &TextDocumentPositionParams,
+    ) -> Option<(&Document, TextSize)> {
+        let document = self.documents.get(&text_document.uri)?;
+        let offset = document.offset(*position)?;
+
+        Some((document, offset))
+    }
+
+    fn hover(&self, params: &HoverParams) -> Option<Hover> {
+        let (document, offset) = self.document(&params.text_document_position_params)?;
+
+        // Expressions are nested, the innermost one is the one being pointed at
+        let (range, r#type) = document
+            .analysis
+            .types
+            .iter()
+            .filter(|(range, _)| range.contains_inclusive(offset))
+            .min_by_key(|(range, _)| range.len())?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```\n{type}\n```"),
+            }),
+            range: Some(convert::range(&document.lines, *range)),
+        })
+    }
+
+    fn definition(&self, params: &GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = &params.text_document_position_params;
+        let (document, offset) = self.document(position)?;
+
+        let reference = document
+            .analysis
+            .references
+            .iter()
+            .find(|reference| reference.range.contains_inclusive(offset))?;
+
+        Some(GotoDefinitionResponse::Scalar(Location::new(
+            position.text_document.uri.clone(),
+            convert::range(&document.lines, reference.definition),
+        )))
+    }
+
+    fn completion(&self, params: &CompletionParams) -> Option<CompletionResponse> {
+        let position = &params.text_document_position;
+        let (document, offset) = self.document(position)?;
+
+        let start = convert::line_start(&document.lines, position.position)?;
+        let prefix = document.text.get(usize::from(start)..usize::from(offset))?;
+
+        Some(CompletionResponse::Array(completion::complete(prefix)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::{self, JoinHandle};
+
+    use error_stack::Report;
+    use lsp_server::{Connection, Message, Notification, Request, RequestId};
+    use lsp_types::{
+        CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse,
+        DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+        DidOpenTextDocumentParams, GotoDefinitionParams, GotoDefinitionResponse, Hover,
+        HoverContents, HoverParams, Location, MarkupContent, MarkupKind, NumberOrString,
+        PartialResultParams, Position, PublishDiagnosticsParams, Range,
+        TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem,
+        TextDocumentPositionParams, Uri, VersionedTextDocumentIdentifier, WorkDoneProgressParams,
+        notification::{
+            DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Exit,
+            Notification as _, PublishDiagnostics,
+        },
+        request::{Completion, GotoDefinition, HoverRequest, Shutdown},
+    };
+
+    use super::{Server, ServerError};
+
+    /// A client driving the server over an in-memory connection.
+    struct Client {
+        connection: Connection,
+        server: JoinHandle<Result<(), Report<ServerError>>>,
+        next_id: i32,
+    }
+
+    impl Client {
+        fn start() -> Self {
+            let (server, connection) = Connection::memory();
+            let server = thread::spawn(move || Server::new(server).run());
+
+            Self {
+                connection,
+                server,
+                next_id: 0,
+            }
+        }
+
+        fn uri() -> Uri {
+            "file:///query.jsonc"
+                .parse()
+                .expect("should be a valid URI")
+        }
+
+        fn send(&self, message: Message) {
+            self.connection
+                .sender
+                .send(message)
+                .expect("server should be running");
+        }
+
+        fn notify<N: lsp_types::notification::Notification>(&self, params: N::Params) {
+            self.send(Notification::new(N::METHOD.to_owned(), params).into());
+        }
+
+        fn request<R: lsp_types::request::Request>(&mut self, params: R::Params) -> R::Result {
+            self.next_id += 1;
+            let id = RequestId::from(self.next_id);
+
+            self.send(Request::new(id.clone(), R::METHOD.to_owned(), params).into());
+
+            let Ok(Message::Response(response)) = self.connection.receiver.recv() else {
+                panic!("server should respond to `{}`", R::METHOD);
+            };
+
+            assert_eq!(response.id, id);
+            assert!(response.error.is_none(), "{:?}", response.error);
+
+            serde_json::from_value(response.result.unwrap_or_default())
+                .expect("response should match the request")
+        }
+
+        fn published_diagnostics(&self) -> PublishDiagnosticsParams {
+            let Ok(Message::Notification(notification)) = self.connection.receiver.recv() else {
+                panic!("server should publish diagnostics");
+            };
+
+            notification
+                .extract(PublishDiagnostics::METHOD)
+                .expect("notification should contain diagnostics")
+        }
+
+        fn open(&self, text: &str) -> PublishDiagnosticsParams {
+            self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem::new(
+                    Self::uri(),
+                    "jsonc".to_owned(),
+                    1,
+                    text.to_owned(),
+                ),
+            });
+
+            self.published_diagnostics()
+        }
+
+        fn position(line: u32, character: u32) -> TextDocumentPositionParams {
+            TextDocumentPositionParams::new(
+                TextDocumentIdentifier::new(Self::uri()),
+                Position::new(line, character),
+            )
+        }
+
+        fn hover(&mut self, line: u32, character: u32) -> Option<Hover> {
+            self.request::<HoverRequest>(HoverParams {
+                text_document_position_params: Self::position(line, character),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            })
+        }
+
+        fn definition(&mut self, line: u32, character: u32) -> Option<GotoDefinitionResponse> {
+            self.request::<GotoDefinition>(GotoDefinitionParams {
+                text_document_position_params: Self::position(line, character),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+        }
+
+        fn completion(&mut self, line: u32, character: u32) -> Vec<CompletionItem> {
+            let response = self.request::<Completion>(CompletionParams {
+                text_document_position: Self::position(line, character),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+                context: None,
+            });
+
+            let Some(CompletionResponse::Array(items)) = response else {
+                panic!("server should respond with a list of completions");
+            };
+
+            items
+        }
+
+        fn shutdown(mut self) {
+            self.request::<Shutdown>(());
+            self.notify::<Exit>(());
+
+            self.server
+                .join()
+                .expect("server should not panic")
+                .expect("server should shut down cleanly");
+        }
+    }
+
+    #[test]
+    fn publishes_diagnostics_on_open() {
+        let client = Client::start();
+
+        let published =
+            client.open(r##"["if", {"#literal": 2}, {"#literal": 3}, {"#literal": 4}]"##);
+
+        assert_eq!(published.uri, Client::uri());
+        assert_eq!(published.version, Some(1));
+
+        let [diagnostic] = published.diagnostics.as_slice() else {
+            panic!(
+                "expected a single diagnostic, found {:?}",
+                published.diagnostics
+            );
+        };
+
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(0, 20), Position::new(0, 21))
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String(
+                "lower::type-check::type-mismatch".to_owned()
+            ))
+        );
+        assert!(
+            diagnostic
+                .message
+                .contains("expected `Boolean`, found `Integer`"),
+            "{}",
+            diagnostic.message
+        );
+
+        client.shutdown();
+    }
+
+    #[test]
+    fn republishes_diagnostics_on_change_and_close() {
+        let client = Client::start();
+
+        let published = client.open(r#"["let", "x", "#);
+        assert_eq!(
+            published.diagnostics.len(),
+            1,
+            "{:?}",
+            published.diagnostics
+        );
+
+        client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(Client::uri(), 2),
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: r##"["let", "x", {"#literal": 1}, "x"]"##.to_owned(),
+            }],
+        });
+
+        let published = client.published_diagnostics();
+        assert_eq!(published.version, Some(2));
+        assert_eq!(published.diagnostics, []);
+
+        // Closing clears the diagnostics of the document, which is no longer versioned
+        client.notify::<DidCloseTextDocument>(DidCloseTextDocumentParams {
+            text_document: TextDocumentIdentifier::new(Client::uri()),
+        });
+
+        let published = client.published_diagnostics();
+        assert_eq!(published.version, None);
+        assert_eq!(published.diagnostics, []);
+
+        client.shutdown();
+    }
+
+    #[test]
+    fn completes_graph_paths() {
+        let mut client = Client::start();
+
+        client.open("[\"::graph::head::\n\"::graph::");
+
+        let find = |items: &[CompletionItem], label: &str| {
+            items
+                .iter()
+                .find(|item| item.label == label)
+                .cloned()
+                .unwrap_or_else(|| panic!("`{label}` should be completed"))
+        };
+
+        let heads = client.completion(0, 17);
+        let entities = find(&heads, "entities");
+        assert_eq!(entities.kind, Some(CompletionItemKind::FUNCTION));
+        assert_eq!(entities.detail.as_deref(), Some("::graph::head::entities"));
+
+        let modules = client.completion(1, 10);
+        for module in ["body", "head", "tail"] {
+            let item = find(&modules, module);
+            assert_eq!(item.kind, Some(CompletionItemKind::MODULE));
+            assert_eq!(item.detail, Some(format!("::graph::{module}")));
+        }
+
+        client.shutdown();
+    }
+
+    #[test]
+    fn goes_to_definition_of_let_bindings() {
+        let mut client = Client::start();
+
+        let published = client.open(
+            r##"["let", "x", {"#literal": 1},
+  ["let", "y", ["+", "x", {"#literal": 2}],
+    ["let", "x", "y",
+      ["*", "x", "y"]]]]"##,
+        );
+        assert_eq!(published.diagnostics, []);
+
+        let location = |start: (u32, u32), end: (u32, u32)| {
+            Some(GotoDefinitionResponse::Scalar(Location::new(
+                Client::uri(),
+                Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1)),
+            )))
+        };
+
+        // `x` in the value of `y` refers to the outer binding
+        assert_eq!(client.definition(1, 22), location((0, 9), (0, 10)));
+        // `y` in the value of the inner `x`
+        assert_eq!(client.definition(2, 18), location((1, 11), (1, 12)));
+        // The inner `x` shadows the outer one
+        assert_eq!(client.definition(3, 14), location((2, 13), (2, 14)));
+        assert_eq!(client.definition(3, 19), location((1, 11), (1, 12)));
+
+        // Neither the binding itself nor the operator are references
+        assert_eq!(client.definition(0, 9), None);
+        assert_eq!(client.definition(3, 9), None);
+
+        client.shutdown();
+    }
+
+    #[test]
+    fn hovers_types_of_expressions() {
+        let mut client = Client::start();
+
+        let published = client.open(
+            r##"["let", "x", {"#literal": 42},
+  ["::core::math::add", {"#literal": 42.12}, "x"]]"##,
+        );
+        assert_eq!(published.diagnostics, []);
+
+        let hover = |r#type: &str, start: (u32, u32), end: (u32, u32)| {
+            Some(Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!("```\n{type}\n```"),
+                }),
+                range: Some(Range::new(
+                    Position::new(start.0, start.1),
+                    Position::new(end.0, end.1),
+                )),
+            })
+        };
+
+        // The local `x`
+        assert_eq!(client.hover(1, 46), hover("Integer", (1, 46), (1, 47)));
+        // The path into the standard library
+        assert_eq!(
+            client.hover(1, 10),
+            hover("(Number, Integer) -> Number", (1, 4), (1, 21))
+        );
+        // The call itself
+        assert_eq!(client.hover(1, 2), hover("Number", (1, 2), (1, 49)));
+
+        // Outside of the document
+        assert_eq!(client.hover(5, 0), None);
+
+        client.shutdown();
+    }
+
+    #[test]
+    fn goes_to_definition_of_imports() {
+        let mut client = Client::start();
+
+        let published = client.open(
+            r##"["use", "core::math", { "#struct": { "add": "plus" } },
+  ["plus", {"#literal": 1}, {"#literal": 2}]]"##,
+        );
+        assert_eq!(published.diagnostics, []);
+
+        // `plus` refers to the alias of the import
+        assert_eq!(
+            client.definition(1, 4),
+            Some(GotoDefinitionResponse::Scalar(Location::new(
+                Client::uri(),
+                Range::new(Position::new(0, 45), Position::new(0, 49)),
+            )))
+        );
+        // The imported item is not a binding
+        assert_eq!(client.definition(0, 38), None);
+
+        client.shutdown();
+    }
+}
@@ -466,10 +466,12 @@ impl AsRef<Self> for Projection<'_> {
 /// - **Positional Access**: [`Field`] uses indices for closed/complete types
 /// - **Name-based Access**: [`FieldByName`] uses symbols for structural/partial types
 /// - **Dynamic Access**: [`Index`] uses computed values for collections
+/// - **Opaque Access**: [`Downcast`] accesses the value wrapped by an opaque type
 ///
 /// [`Field`]: ProjectionKind::Field
 /// [`FieldByName`]: ProjectionKind::FieldByName
 /// [`Index`]: ProjectionKind::Index
+/// [`Downcast`]: ProjectionKind::Downcast
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ProjectionKind<'heap> {
     /// Access a field by positional index in a closed/complete type.
@@ -504,6 +506,15 @@ pub enum ProjectionKind<'heap> {
     /// This projection navigates to an element within a list or dictionary. The
     /// [`Local`] contains the index value that determines which element to access.
     Index(Local),
+
+    /// Access the value wrapped by an opaque type.
+    ///
+    /// The value must be an instance of the opaque type with the given name. This is
+    /// guaranteed by first checking the [`Discriminant`] of the value, which is how `match`
+    /// expressions over opaque types are lowered.
+    ///
+    /// [`Discriminant`]: crate::body::rvalue::Discriminant
+    Downcast(Symbol<'heap>),
 }
 
 impl fmt::Display for ProjectionKind<'_> {
@@ -512,6 +523,7 @@ impl fmt::Display for ProjectionKind<'_> {
             ProjectionKind::Field(index) => write!(fmt, ".{index}"),
             ProjectionKind::FieldByName(name) => write!(fmt, ".{name}"),
             ProjectionKind::Index(index) => write!(fmt, "[{index}]"),
+            ProjectionKind::Downcast(name) => write!(fmt, "@{name}"),
         }
     }
 }
@@ -0,0 +1,27 @@
+//! Discriminant representation for HashQL MIR.
+//!
+//! The discriminant of a value identifies which of a fixed set of opaque types the value is an
+//! instance of. It is used to lower `match` expressions over unions of opaque types, such as
+//! `Option` or `Result`, into [`SwitchInt`] terminators.
+//!
+//! [`SwitchInt`]: crate::body::terminator::SwitchInt
+
+use hashql_core::{intern::Interned, symbol::Symbol};
+
+use crate::body::operand::Operand;
+
+/// A discriminant r-value in the HashQL MIR.
+///
+/// Evaluates to the position of the opaque type name of the operand within [`Self::variants`].
+/// If the operand is not an instance of any of the variants, the discriminant evaluates to the
+/// number of variants, which allows the otherwise branch of a [`SwitchInt`] to handle the case.
+///
+/// [`SwitchInt`]: crate::body::terminator::SwitchInt
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Discriminant<'heap> {
+    /// The operand whose discriminant is read.
+    pub operand: Operand<'heap>,
+
+    /// The names of the opaque types to discriminate between, in order.
+    pub variants: Interned<'heap, [Symbol<'heap>]>,
+}
@@ -0,0 +1,29 @@
+//! Intrinsic operation representation for HashQL MIR.
+//!
+//! Intrinsic operations are standard library functions, which are implemented directly by the
+//! runtime (and by the execution backends) instead of having a MIR body.
+
+use hashql_core::heap::Heap;
+use hashql_hir::node::operation::IntrinsicOp;
+
+use super::ArgVec;
+use crate::body::operand::Operand;
+
+/// An intrinsic operation r-value in the HashQL MIR.
+///
+/// Unlike [`Apply`], an intrinsic operation does not call into another body, the operation is
+/// evaluated directly on its arguments. Intrinsic operations are pure, their result only depends
+/// on the values of their arguments.
+///
+/// [`Apply`]: super::Apply
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Intrinsic<'heap> {
+    /// The intrinsic operation to perform.
+    pub op: IntrinsicOp,
+
+    /// The arguments of the operation.
+    ///
+    /// The arguments are in the same order as the parameters of the standard library function
+    /// implementing the operation.
+    pub arguments: ArgVec<Operand<'heap>, &'heap Heap>,
+}
@@ -7,14 +7,18 @@
 mod aggregate;
 mod apply;
 mod binary;
+mod discriminant;
 mod input;
+mod intrinsic;
 mod unary;
 
 pub use self::{
     aggregate::{Aggregate, AggregateKind},
     apply::{Apply, ArgIndex, ArgSlice, ArgVec},
     binary::{BinOp, Binary},
+    discriminant::Discriminant,
     input::Input,
+    intrinsic::Intrinsic,
     unary::{UnOp, Unary},
 };
 use crate::body::operand::Operand;
@@ -60,6 +64,20 @@ pub enum RValue<'heap> {
     /// or configuration values.
     Input(Input<'heap>),
 
+    /// Perform an intrinsic operation on a list of operands.
+    ///
+    /// This r-value computes the result of a standard library function, which
+    /// is implemented directly by the runtime, such as the string operations
+    /// of `::core::string`.
+    Intrinsic(Intrinsic<'heap>),
+
+    /// Read the discriminant of an operand.
+    ///
+    /// This r-value computes which of a fixed set of opaque types the operand
+    /// is an instance of, and is used to select the arm of a `match`
+    /// expression.
+    Discriminant(Discriminant<'heap>),
+
     /// Apply a function to a list of arguments.
     ///
     /// This r-value represents a function call operation, where a callable
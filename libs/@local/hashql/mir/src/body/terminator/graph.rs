@@ -7,6 +7,7 @@
 use core::{fmt, fmt::Display};
 
 use hashql_core::heap;
+pub use hashql_hir::node::graph::{GraphAggregate, GraphTraversal};
 
 use crate::{
     body::{basic_block::BasicBlockId, local::Local, location::Location, operand::Operand},
@@ -65,6 +66,41 @@ pub enum GraphReadHead<'heap> {
     /// store. The `axis` operand specifies the temporal context for the
     /// bi-temporal query.
     Entity { axis: Operand<'heap> },
+    /// Start the query from data types in the bi-temporal graph.
+    ///
+    /// The `axis` operand specifies the temporal context for the bi-temporal query.
+    DataType { axis: Operand<'heap> },
+    /// Start the query from property types in the bi-temporal graph.
+    ///
+    /// The `axis` operand specifies the temporal context for the bi-temporal query.
+    PropertyType { axis: Operand<'heap> },
+    /// Start the query from entity types in the bi-temporal graph.
+    ///
+    /// The `axis` operand specifies the temporal context for the bi-temporal query.
+    EntityType { axis: Operand<'heap> },
+}
+
+impl<'heap> GraphReadHead<'heap> {
+    /// Returns the operand specifying the temporal context of the query.
+    #[must_use]
+    pub const fn axis(&self) -> &Operand<'heap> {
+        match self {
+            Self::Entity { axis }
+            | Self::DataType { axis }
+            | Self::PropertyType { axis }
+            | Self::EntityType { axis } => axis,
+        }
+    }
+
+    /// Returns a mutable reference to the operand specifying the temporal context of the query.
+    pub const fn axis_mut(&mut self) -> &mut Operand<'heap> {
+        match self {
+            Self::Entity { axis }
+            | Self::DataType { axis }
+            | Self::PropertyType { axis }
+            | Self::EntityType { axis } => axis,
+        }
+    }
 }
 
 /// Operations that can be applied to process and filter query results.
@@ -96,6 +132,32 @@ pub enum GraphReadBody {
     ///   indicating whether to keep the item.
     /// - **Environment**: The [`Local`] refers to a variable that holds the captured environment.
     Filter(DefId, Local),
+
+    /// Replace every item with the items reachable along an edge of the knowledge graph.
+    ///
+    /// Operations before the traversal apply to the items the traversal starts from, while
+    /// operations after it apply to the items it reaches. The [`GraphTraversal`] determines
+    /// which edge is followed.
+    Traverse(GraphTraversal),
+
+    /// Sort the items in ascending order of a key.
+    ///
+    /// Sorting is stable, items with equal keys keep their relative order. Ordering operations
+    /// ([`Self::SortBy`], [`Self::Limit`] and [`Self::Offset`]) always come after every filter
+    /// and traversal.
+    ///
+    /// # Parameters
+    ///
+    /// - **Function**: The [`DefId`] identifies a function computing the key of an item. Like a
+    ///   filter it receives the captured environment and the item.
+    /// - **Environment**: The [`Local`] refers to a variable that holds the captured environment.
+    SortBy(DefId, Local),
+
+    /// Keep at most the given number of items.
+    Limit(usize),
+
+    /// Skip the given number of items.
+    Offset(usize),
 }
 
 /// The final operation that determines how the query results are returned.
@@ -120,6 +182,27 @@ pub enum GraphReadTail {
     /// or array). It ensures that all qualifying entities are captured and
     /// made available to the calling code.
     Collect,
+
+    /// Return the number of results as an integer.
+    Count,
+
+    /// Return whether there is at least one result.
+    Exists,
+
+    /// Return the first result as an option, or `None` if there are no results.
+    First,
+
+    /// Group the results into a dictionary from keys to lists of results.
+    ///
+    /// The [`DefId`] identifies a function computing the key of a result, and the [`Local`]
+    /// refers to a variable that holds its captured environment.
+    GroupBy(DefId, Local),
+
+    /// Aggregate a numeric value of every result.
+    ///
+    /// The [`DefId`] identifies a function computing the value of a result, and the [`Local`]
+    /// refers to a variable that holds its captured environment.
+    Aggregate(GraphAggregate, DefId, Local),
 }
 
 /// A graph read terminator in the HashQL MIR.
@@ -16,7 +16,10 @@ use hashql_core::span::SpanId;
 
 pub use self::{
     goto::Goto,
-    graph::{GraphRead, GraphReadBody, GraphReadHead, GraphReadLocation, GraphReadTail},
+    graph::{
+        GraphAggregate, GraphRead, GraphReadBody, GraphReadHead, GraphReadLocation, GraphReadTail,
+        GraphTraversal,
+    },
     r#return::Return,
     switch_int::{SwitchIf, SwitchInt, SwitchIntValue, SwitchTargets},
     target::Target,
@@ -35,7 +38,7 @@ macro_rules! for_both {
 // We could also contemplate implementing more API surface of iterator, but this should be
 // sufficient for now
 #[derive(Clone)]
-enum EitherIter<L, R> {
+pub(crate) enum EitherIter<L, R> {
     Left(L),
     Right(R),
 }
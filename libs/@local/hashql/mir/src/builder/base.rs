@@ -1,4 +1,4 @@
-use hashql_core::value::{Float, Primitive};
+use hashql_core::value::{Float, Primitive, String};
 
 use super::{
     operand::OperandBuilder,
@@ -50,6 +50,14 @@ impl<'env, 'heap> BaseBuilder<'env, 'heap> {
         ))))
     }
 
+    /// Creates a string constant operand.
+    #[must_use]
+    pub fn const_str(self, value: &str) -> Operand<'heap> {
+        Operand::Constant(Constant::Primitive(Primitive::String(String::new(
+            self.interner.heap.intern_symbol(value),
+        ))))
+    }
+
     /// Creates a boolean constant operand.
     #[must_use]
     pub const fn const_bool(self, value: bool) -> Operand<'heap> {
@@ -199,6 +199,7 @@ impl<'env, 'heap> Deref for BodyBuilder<'env, 'heap> {
 /// | `x = closure <def> <env>;` | Create closure aggregate |
 /// | `x = bin.<op> <lhs> <rhs>;` | Binary operation (e.g., `bin.== x y`) |
 /// | `x = un.<op> <operand>;` | Unary operation (e.g., `un.! cond`) |
+/// | `x = <module>.<op> <arg1>, <arg2>;` | Intrinsic operation (e.g., `str.concat x, y`) |
 /// | `x = input.load! "name";` | Load required input |
 /// | `x = input.load "name";` | Load optional input |
 /// | `x = input.exists "name";` | Check if input exists |
@@ -125,6 +125,23 @@ pub use self::{
 /// assert!(matches!(op![neg], UnOp::Neg)); // `neg` is used since `-` alone is ambiguous
 /// assert!(matches!(op![~], UnOp::BitNot));
 /// ```
+///
+/// # Intrinsic Operations
+///
+/// Operations of the `::core::string`, `::core::list` and `::core::dict` modules are prefixed
+/// with `str.`, `list.` and `dict.` respectively:
+///
+/// ```
+/// use hashql_hir::node::operation::{DictOp, IntrinsicOp, ListOp, StringOp};
+/// use hashql_mir::op;
+///
+/// assert!(matches!(
+///     op![str.lowercase],
+///     IntrinsicOp::String(StringOp::Lowercase)
+/// ));
+/// assert!(matches!(op![list.map], IntrinsicOp::List(ListOp::Map)));
+/// assert!(matches!(op![dict.keys], IntrinsicOp::Dict(DictOp::Keys)));
+/// ```
 #[macro_export]
 macro_rules! op {
     // Binary operators
@@ -144,6 +161,41 @@ macro_rules! op {
     // Unary operators
     [neg] => { $crate::body::rvalue::UnOp::Neg };
     [~] => { $crate::body::rvalue::UnOp::BitNot };
+
+    // Intrinsic operations
+    [str.$op:ident] => {
+        hashql_hir::node::operation::IntrinsicOp::String($crate::op!(@string $op))
+    };
+    [list.$op:ident] => {
+        hashql_hir::node::operation::IntrinsicOp::List($crate::op!(@list $op))
+    };
+    [dict.$op:ident] => {
+        hashql_hir::node::operation::IntrinsicOp::Dict($crate::op!(@dict $op))
+    };
+    (@string lowercase) => { hashql_hir::node::operation::StringOp::Lowercase };
+    (@string uppercase) => { hashql_hir::node::operation::StringOp::Uppercase };
+    (@string contains) => { hashql_hir::node::operation::StringOp::Contains };
+    (@string starts_with) => { hashql_hir::node::operation::StringOp::StartsWith };
+    (@string ends_with) => { hashql_hir::node::operation::StringOp::EndsWith };
+    (@string length) => { hashql_hir::node::operation::StringOp::Length };
+    (@string concat) => { hashql_hir::node::operation::StringOp::Concat };
+    (@string split) => { hashql_hir::node::operation::StringOp::Split };
+    (@string trim) => { hashql_hir::node::operation::StringOp::Trim };
+    (@list len) => { hashql_hir::node::operation::ListOp::Len };
+    (@list get) => { hashql_hir::node::operation::ListOp::Get };
+    (@list contains) => { hashql_hir::node::operation::ListOp::Contains };
+    (@list concat) => { hashql_hir::node::operation::ListOp::Concat };
+    (@list sort) => { hashql_hir::node::operation::ListOp::Sort };
+    (@list map) => { hashql_hir::node::operation::ListOp::Map };
+    (@list filter) => { hashql_hir::node::operation::ListOp::Filter };
+    (@list fold) => { hashql_hir::node::operation::ListOp::Fold };
+    (@dict len) => { hashql_hir::node::operation::DictOp::Len };
+    (@dict get) => { hashql_hir::node::operation::DictOp::Get };
+    (@dict contains) => { hashql_hir::node::operation::DictOp::Contains };
+    (@dict keys) => { hashql_hir::node::operation::DictOp::Keys };
+    (@dict values) => { hashql_hir::node::operation::DictOp::Values };
+    (@dict insert) => { hashql_hir::node::operation::DictOp::Insert };
+    (@dict concat) => { hashql_hir::node::operation::DictOp::Concat };
 }
 
 #[doc(hidden)]
@@ -33,6 +33,12 @@ impl<'heap> BuildOperand<'heap, bool> for OperandBuilder<'_, 'heap> {
     }
 }
 
+impl<'heap> BuildOperand<'heap, &str> for OperandBuilder<'_, 'heap> {
+    fn build_operand(&self, value: &str) -> Operand<'heap> {
+        self.base.const_str(value)
+    }
+}
+
 impl<'heap> BuildOperand<'heap, ()> for OperandBuilder<'_, 'heap> {
     fn build_operand(&self, (): ()) -> Operand<'heap> {
         self.base.const_unit()
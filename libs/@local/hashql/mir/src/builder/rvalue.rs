@@ -6,7 +6,7 @@ use hashql_core::{
     symbol::Symbol,
     r#type::builder::IntoSymbol,
 };
-use hashql_hir::node::operation::InputOp;
+use hashql_hir::node::operation::{InputOp, IntrinsicOp};
 
 use super::base::BaseBuilder;
 use crate::{
@@ -14,7 +14,9 @@ use crate::{
         constant::Constant,
         operand::Operand,
         place::Place,
-        rvalue::{Aggregate, AggregateKind, Apply, BinOp, Binary, Input, RValue, UnOp, Unary},
+        rvalue::{
+            Aggregate, AggregateKind, Apply, BinOp, Binary, Input, Intrinsic, RValue, UnOp, Unary,
+        },
     },
     def::DefId,
 };
@@ -190,6 +192,26 @@ impl<'env, 'heap> RValueBuilder<'env, 'heap> {
         self.apply(func, [] as [Operand<'heap>; 0])
     }
 
+    /// Creates an intrinsic operation r-value.
+    ///
+    /// Use the [`op!`] macro for the operation: `rv.intrinsic(op![str.lowercase], [x])`.
+    ///
+    /// [`op!`]: crate::op
+    #[must_use]
+    pub fn intrinsic(
+        self,
+        op: IntrinsicOp,
+        args: impl IntoIterator<Item = impl Into<Operand<'heap>>>,
+    ) -> RValue<'heap> {
+        let mut arguments = heap::Vec::new_in(self.interner.heap);
+        arguments.extend(args.into_iter().map(Into::into));
+
+        RValue::Intrinsic(Intrinsic {
+            op,
+            arguments: IdVec::from_raw(arguments),
+        })
+    }
+
     /// Creates an input r-value.
     #[must_use]
     pub fn input(self, op: InputOp, name: impl IntoSymbol<'heap>) -> RValue<'heap> {
@@ -302,6 +324,13 @@ macro_rules! rvalue {
             rv.unary($crate::builder::op![$op], lhs)
         }; $payload; $($rest)*)
     };
+    ($resume:path; $payload:tt; $module:ident.$op:ident $($args:tt),+; $($rest:tt)*) => {
+        $resume!(@rvalue |rv| {
+            let args = [$($crate::builder::_private::operand!(rv; $args)),+];
+
+            rv.intrinsic($crate::builder::op![$module.$op], args)
+        }; $payload; $($rest)*)
+    };
 }
 
 pub use rvalue;
@@ -0,0 +1,49 @@
+//! Cooperative cancellation of interpretation.
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A handle to cancel a running interpreter.
+///
+/// Cancellation is cooperative: the [`Runtime`] checks the token whenever it enters a basic block
+/// and stops with a cancellation error once the token has been cancelled. Clones of a token share
+/// the same state, so a token can be handed to the runtime while another clone is kept to cancel
+/// it, e.g. once the client of a request disconnected.
+///
+/// # Examples
+///
+/// ```
+/// use hashql_mir::interpret::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+///
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+///
+/// [`Runtime`]: super::Runtime
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token, which has not been cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every runtime holding a clone of this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether cancellation has been requested.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
@@ -7,6 +7,7 @@ use alloc::borrow::Cow;
 use core::{
     alloc::Allocator,
     fmt::{self, Display},
+    time::Duration,
 };
 
 use hashql_core::{span::SpanId, symbol::Symbol};
@@ -83,7 +84,7 @@ pub enum InterpretDiagnosticCategory {
     ControlFlow,
     /// Index out of bounds error.
     BoundsCheck,
-    /// Resource limit exceeded (e.g., recursion limit).
+    /// Resource limit exceeded (e.g., recursion limit, fuel) or execution cancelled.
     RuntimeLimit,
     /// Required input not provided.
     InputResolution,
@@ -330,6 +331,36 @@ pub enum RuntimeError<'heap, E, A: Allocator> {
         limit: usize,
     },
 
+    /// The number of executed steps exceeded the configured fuel.
+    ///
+    /// This is a user-facing error that occurs when a program performs too
+    /// much work, e.g. by looping over a large list or not terminating.
+    FuelExhausted {
+        fuel: u64,
+    },
+
+    /// The bytes allocated for values exceeded the configured limit.
+    ///
+    /// This is a user-facing error that occurs when a program builds
+    /// values that are too large, such as huge lists or dicts.
+    MemoryLimitExceeded {
+        limit: usize,
+    },
+
+    /// Interpretation took longer than the configured time limit.
+    ///
+    /// This is a user-facing error, checked whenever a basic block is
+    /// entered.
+    TimeLimitExceeded {
+        limit: Duration,
+    },
+
+    /// Interpretation was cancelled through a cancellation token.
+    ///
+    /// This is a user-facing error, checked whenever a basic block is
+    /// entered.
+    Cancelled,
+
     /// Integer arithmetic overflowed the supported range.
     ///
     /// This is a user-facing error caused by an implementation limitation:
@@ -358,6 +389,13 @@ pub enum RuntimeError<'heap, E, A: Allocator> {
         name: Symbol<'heap>,
     },
 
+    /// A closure called by an intrinsic operation tried to suspend.
+    ///
+    /// This is a user-facing error caused by an implementation limitation:
+    /// closures passed to intrinsic operations (e.g. `::core::list::map`) run
+    /// to completion and cannot request external data, such as a graph read.
+    NestedSuspension,
+
     Suspension(E),
 }
 
@@ -482,6 +520,18 @@ impl<E, A: Allocator> RuntimeError<'_, E, A> {
             Self::RecursionLimitExceeded { limit } => recursion_limit_exceeded(span, limit)
                 .map_category(on_otherwise)
                 .map_severity(Into::into),
+            Self::FuelExhausted { fuel } => fuel_exhausted(span, fuel)
+                .map_category(on_otherwise)
+                .map_severity(Into::into),
+            Self::MemoryLimitExceeded { limit } => memory_limit_exceeded(span, limit)
+                .map_category(on_otherwise)
+                .map_severity(Into::into),
+            Self::TimeLimitExceeded { limit } => time_limit_exceeded(span, limit)
+                .map_category(on_otherwise)
+                .map_severity(Into::into),
+            Self::Cancelled => cancelled(span)
+                .map_category(on_otherwise)
+                .map_severity(Into::into),
             Self::IntegerOverflow { operation } => integer_overflow(span, operation)
                 .map_category(on_otherwise)
                 .map_severity(Into::into),
@@ -493,6 +543,9 @@ impl<E, A: Allocator> RuntimeError<'_, E, A> {
             Self::InvalidConstructor { name } => invalid_constructor(span, name)
                 .map_category(on_otherwise)
                 .map_severity(Into::into),
+            Self::NestedSuspension => nested_suspension(span)
+                .map_category(on_otherwise)
+                .map_severity(Into::into),
             Self::Suspension(suspension) => on_suspension(suspension),
         }
     }
@@ -540,11 +593,16 @@ impl<'heap, A: Allocator> RuntimeError<'heap, !, A> {
             Self::RecursionLimitExceeded { limit } => {
                 RuntimeError::RecursionLimitExceeded { limit }
             }
+            Self::FuelExhausted { fuel } => RuntimeError::FuelExhausted { fuel },
+            Self::MemoryLimitExceeded { limit } => RuntimeError::MemoryLimitExceeded { limit },
+            Self::TimeLimitExceeded { limit } => RuntimeError::TimeLimitExceeded { limit },
+            Self::Cancelled => RuntimeError::Cancelled,
             Self::IntegerOverflow { operation } => RuntimeError::IntegerOverflow { operation },
             Self::UnexpectedValueType { expected, actual } => {
                 RuntimeError::UnexpectedValueType { expected, actual }
             }
             Self::InvalidConstructor { name } => RuntimeError::InvalidConstructor { name },
+            Self::NestedSuspension => RuntimeError::NestedSuspension,
         }
     }
 }
@@ -876,6 +934,60 @@ fn recursion_limit_exceeded(span: SpanId, limit: usize) -> InterpretDiagnostic {
     diagnostic
 }
 
+fn fuel_exhausted(span: SpanId, fuel: u64) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR).primary(
+            Label::new(
+                span,
+                format!("execution ran out of fuel after {fuel} steps"),
+            ),
+        );
+
+    diagnostic.add_message(Message::help(
+        "consider narrowing the query to reduce the amount of work or increasing the limit",
+    ));
+
+    diagnostic
+}
+
+fn memory_limit_exceeded(span: SpanId, limit: usize) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR).primary(
+            Label::new(span, format!("memory limit of {limit} bytes exceeded")),
+        );
+
+    diagnostic.add_message(Message::help(
+        "consider building smaller lists and dicts or increasing the limit",
+    ));
+
+    diagnostic
+}
+
+fn time_limit_exceeded(span: SpanId, limit: Duration) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR).primary(
+            Label::new(span, format!("time limit of {limit:?} exceeded")),
+        );
+
+    diagnostic.add_message(Message::help(
+        "consider narrowing the query to reduce the amount of work or increasing the limit",
+    ));
+
+    diagnostic
+}
+
+fn cancelled(span: SpanId) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR)
+            .primary(Label::new(span, "execution was cancelled"));
+
+    diagnostic.add_message(Message::note(
+        "interpretation was stopped before it completed, e.g. because the request was aborted",
+    ));
+
+    diagnostic
+}
+
 fn integer_overflow(span: SpanId, operation: &str) -> InterpretDiagnostic {
     let mut diagnostic =
         Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR).primary(
@@ -891,3 +1003,19 @@ fn integer_overflow(span: SpanId, operation: &str) -> InterpretDiagnostic {
 
     diagnostic
 }
+
+fn nested_suspension(span: SpanId) -> InterpretDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(InterpretDiagnosticCategory::RuntimeLimit, Critical::ERROR).primary(
+            Label::new(
+                span,
+                "closure tried to suspend inside an intrinsic operation",
+            ),
+        );
+
+    diagnostic.add_message(Message::note(
+        "closures passed to intrinsic operations cannot read from the graph",
+    ));
+
+    diagnostic
+}
@@ -102,6 +102,14 @@ impl<'ctx, 'heap, A: Allocator> Locals<'ctx, 'heap, A> {
         self.inner.fill_until(local, || Value::Unit)
     }
 
+    /// Returns an iterator over the initialized locals and their values.
+    ///
+    /// Locals below the highest initialized local, which have not been assigned yet, are
+    /// reported as unit.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Local, &Value<'heap, A>)> {
+        self.inner.iter_enumerated()
+    }
+
     /// Evaluates a place expression to get a reference to the value.
     ///
     /// Follows the chain of projections (field access, indexing) to reach
@@ -121,6 +129,9 @@ impl<'ctx, 'heap, A: Allocator> Locals<'ctx, 'heap, A> {
                 ProjectionKind::FieldByName(symbol) => {
                     value = value.project_by_name(symbol)?;
                 }
+                ProjectionKind::Downcast(name) => {
+                    value = value.downcast(name)?;
+                }
                 ProjectionKind::Index(local) => {
                     let index = self.local(local)?;
                     value = value.subscript(index)?;
@@ -151,7 +162,9 @@ impl<'ctx, 'heap, A: Allocator> Locals<'ctx, 'heap, A> {
             .rev()
             .filter_map(|projection| match projection.kind {
                 ProjectionKind::Index(local) => Some(self.local(local).cloned()),
-                ProjectionKind::Field(_) | ProjectionKind::FieldByName(_) => None,
+                ProjectionKind::Field(_)
+                | ProjectionKind::FieldByName(_)
+                | ProjectionKind::Downcast(_) => None,
             })
             .try_fold(&mut scratch.indices, |acc, index| {
                 acc.push(index?);
@@ -168,6 +181,9 @@ impl<'ctx, 'heap, A: Allocator> Locals<'ctx, 'heap, A> {
                 ProjectionKind::FieldByName(symbol) => {
                     value = value.project_by_name_mut(symbol)?;
                 }
+                ProjectionKind::Downcast(name) => {
+                    value = value.downcast_mut(name)?;
+                }
                 ProjectionKind::Index(_) => {
                     let index = scratch.indices.pop().unwrap_or_else(|| unreachable!());
                     value = value.subscript_mut(&index)?;
@@ -12,6 +12,17 @@
 //!   aggregates (structs, tuples), and collections (lists, dicts).
 //! - **[`suspension`]**: Types for the suspend/resume protocol when the interpreter needs external
 //!   data.
+//! - **[`Observer`]**: Hook notified of every block entry, statement, call, and return, used by
+//!   [`trace::Trace`] to record the execution.
+//!
+//! # Resource Limits
+//!
+//! Programs may be submitted by untrusted users, [`RuntimeConfig`] therefore bounds the resources
+//! a single interpretation may use: the call stack depth, the number of steps executed (fuel), the
+//! bytes allocated for values, and the wall-clock time. Additionally, interpretation can be
+//! cancelled at any point through a [`CancellationToken`]. Exceeding any limit stops the
+//! interpreter with a diagnostic pointing at the code being executed. The resources consumed are
+//! tracked in a [`Budget`], which can be shared by several runtimes.
 //!
 //! # Execution
 //!
@@ -19,22 +30,36 @@
 //! handling suspensions via a closure. For async or multi-backend orchestration, use
 //! [`Runtime::start`] and [`Runtime::resume`] to manually drive the suspend/resume loop.
 //!
+//! # Debugging
+//!
+//! When a program evaluates unexpectedly, attach a [`trace::Trace`] with
+//! [`Runtime::with_observer`] to record every step together with the values assigned, and render
+//! it next to the MIR with [`TextFormat::format_trace`]. To stop at specific blocks instead, drive
+//! the interpreter with [`Runtime::run_until_breakpoint`] and inspect the [`CallStack`] at every
+//! [`Breakpoint`].
+//!
+//! [`TextFormat::format_trace`]: crate::pretty::TextFormat::format_trace
 //! [`Severity::Bug`]: hashql_diagnostics::severity::Severity::Bug
 //! [`Severity::Error`]: hashql_diagnostics::severity::Severity::Error
 
+mod cancellation;
 pub mod error;
 mod inputs;
 mod locals;
+mod observer;
 mod runtime;
 mod scratch;
 pub mod suspension;
 #[cfg(test)]
 mod tests;
+pub mod trace;
 pub mod value;
 
 pub use self::{
+    cancellation::CancellationToken,
     error::{RuntimeError, TypeName},
     inputs::Inputs,
     locals::Locals,
-    runtime::{CallStack, Runtime, RuntimeConfig, Yield},
+    observer::{Breakpoint, Observer},
+    runtime::{Budget, CallStack, Runtime, RuntimeConfig, Yield},
 };
@@ -0,0 +1,107 @@
+//! Observation of the interpreter while it executes.
+//!
+//! An [`Observer`] attached to the [`Runtime`] through [`Runtime::with_observer`] is notified
+//! whenever a block is entered, a statement has been executed, a function is called, or a function
+//! returns. The default observer `()` ignores every event, so that an unobserved runtime does not
+//! pay for the hooks.
+//!
+//! [`Runtime`]: super::Runtime
+//! [`Runtime::with_observer`]: super::Runtime::with_observer
+
+use core::{alloc::Allocator, fmt};
+
+use super::{locals::Locals, value::Value};
+use crate::{
+    body::{basic_block::BasicBlockId, location::Location, statement::Statement},
+    def::DefId,
+};
+
+/// Receives the events of an executing [`Runtime`].
+///
+/// Every method has an empty default implementation, implementors only override the events they
+/// are interested in.
+///
+/// [`Runtime`]: super::Runtime
+pub trait Observer<'heap, A: Allocator> {
+    /// Called when the body `body` enters the basic block `block`.
+    ///
+    /// The block parameters have already been assigned, `locals` is the state of the frame at the
+    /// entry of the block.
+    #[expect(unused_variables, reason = "trait definition")]
+    fn on_block_enter(&mut self, body: DefId, block: BasicBlockId, locals: &Locals<'_, 'heap, A>) {}
+
+    /// Called once `statement` at `location` in `body` has been executed.
+    ///
+    /// `locals` is the state of the frame after the statement. For an assignment of a call, this
+    /// is only called once the callee returned.
+    #[expect(unused_variables, reason = "trait definition")]
+    fn on_statement(
+        &mut self,
+        body: DefId,
+        location: Location,
+        statement: &Statement<'heap>,
+        locals: &Locals<'_, 'heap, A>,
+    ) {
+    }
+
+    /// Called when `caller` calls `callee`, before the first block of the callee is entered.
+    ///
+    /// `locals` is the frame of the callee, which only holds the arguments of the call.
+    #[expect(unused_variables, reason = "trait definition")]
+    fn on_call(&mut self, caller: DefId, callee: DefId, locals: &Locals<'_, 'heap, A>) {}
+
+    /// Called when `body` returns `value`.
+    #[expect(unused_variables, reason = "trait definition")]
+    fn on_return(&mut self, body: DefId, value: &Value<'heap, A>) {}
+}
+
+impl<A: Allocator> Observer<'_, A> for () {}
+
+impl<'heap, A: Allocator, T: Observer<'heap, A>> Observer<'heap, A> for &mut T {
+    #[inline]
+    fn on_block_enter(&mut self, body: DefId, block: BasicBlockId, locals: &Locals<'_, 'heap, A>) {
+        T::on_block_enter(self, body, block, locals);
+    }
+
+    #[inline]
+    fn on_statement(
+        &mut self,
+        body: DefId,
+        location: Location,
+        statement: &Statement<'heap>,
+        locals: &Locals<'_, 'heap, A>,
+    ) {
+        T::on_statement(self, body, location, statement, locals);
+    }
+
+    #[expect(clippy::similar_names, reason = "caller and callee")]
+    #[inline]
+    fn on_call(&mut self, caller: DefId, callee: DefId, locals: &Locals<'_, 'heap, A>) {
+        T::on_call(self, caller, callee, locals);
+    }
+
+    #[inline]
+    fn on_return(&mut self, body: DefId, value: &Value<'heap, A>) {
+        T::on_return(self, body, value);
+    }
+}
+
+/// A point at which stepping through the interpreter stops.
+///
+/// A breakpoint is hit whenever the block `block` of the body `body` is entered, in any frame of
+/// the call stack. See [`Runtime::run_until_breakpoint`].
+///
+/// [`Runtime::run_until_breakpoint`]: super::Runtime::run_until_breakpoint
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Breakpoint {
+    /// The body containing the block.
+    pub body: DefId,
+    /// The block, which is entered.
+    pub block: BasicBlockId,
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{{def@{}}}:{}", self.body, self.block)
+    }
+}
@@ -7,8 +7,10 @@
 //! # Key Types
 //!
 //! - [`Runtime`]: The main interpreter, holding configuration, function bodies, and inputs
-//! - [`RuntimeConfig`]: Configuration options like recursion limits
+//! - [`RuntimeConfig`]: Configuration options like recursion, fuel, memory, and time limits
+//! - [`Budget`]: The resources consumed so far, checked against the limits of the configuration
 //! - [`CallStack`]: Manages call frames during execution
+//! - [`Observer`]: Notified of the blocks, statements, calls, and returns executed
 //! - [`Yield`]: Returned by the interpreter, containing either a final value or a suspension
 //!
 //! # Execution Model
@@ -36,30 +38,45 @@
 //! For callers that can handle suspensions synchronously, [`Runtime::run`] provides
 //! a convenience wrapper that drives the loop with a closure.
 //!
+//! # Stepping
+//!
+//! To inspect the interpreter while it executes, [`Runtime::run_until_block_entry`] stops
+//! whenever a basic block is about to be executed, and [`Runtime::run_until_breakpoint`] stops
+//! only at the given [`Breakpoint`]s. In both cases the call stack can be inspected, before
+//! calling the method again to continue.
+//!
 //! [`GraphRead`]: crate::body::terminator::GraphRead
 //! [`Continuation`]: super::suspension::Continuation
 
-use alloc::{alloc::Global, borrow::Cow};
-use core::{alloc::Allocator, debug_assert_matches, hint::cold_path, ops::ControlFlow};
+use alloc::{alloc::Global, borrow::Cow, rc::Rc, string::String};
+use core::{
+    alloc::Allocator, debug_assert_matches, hint::cold_path, ops::ControlFlow, time::Duration,
+};
+use std::time::Instant;
 
-use hashql_core::span::SpanId;
-use hashql_hir::node::operation::InputOp;
+use hashql_core::{span::SpanId, symbol::sym};
+use hashql_hir::node::operation::{DictOp, InputOp, IntrinsicOp, ListOp, StringOp};
 
 use super::{
-    Inputs,
+    Breakpoint, CancellationToken, Inputs, Observer,
     error::{BinaryTypeMismatch, InterpretDiagnostic, RuntimeError, TypeName, UnaryTypeMismatch},
     locals::Locals,
     scratch::Scratch,
     suspension::{Continuation, Suspension},
-    value::{Int, Value},
+    value::{Dict, Int, List, Opaque, Str, Value},
 };
 use crate::{
     body::{
         Body,
         basic_block::{BasicBlock, BasicBlockId},
-        rvalue::{Apply, BinOp, Binary, Input, RValue, UnOp, Unary},
+        location::Location,
+        operand::Operand,
+        rvalue::{
+            Apply, ArgIndex, ArgSlice, BinOp, Binary, Discriminant, Input, Intrinsic, RValue, UnOp,
+            Unary,
+        },
         statement::{Assign, StatementKind},
-        terminator::{Goto, GraphReadHead, Return, SwitchInt, Target, TerminatorKind},
+        terminator::{Goto, Return, SwitchInt, Target, TerminatorKind},
     },
     def::{DefId, DefIdSlice},
     interpret::suspension::{self, GraphReadSuspension},
@@ -130,8 +147,8 @@ impl<'ctx, 'heap, A: Allocator> CallStack<'ctx, 'heap, A> {
     ///
     /// The entry function is called with the provided arguments, which become
     /// the initial values of the function's parameter locals.
-    pub fn new(
-        runtime: &Runtime<'ctx, 'heap, A>,
+    pub fn new<O: Observer<'heap, A>>(
+        runtime: &Runtime<'ctx, 'heap, A, O>,
         entry: DefId,
         args: impl IntoIterator<Item = Value<'heap, A>, IntoIter: ExactSizeIterator>,
     ) -> Self
@@ -291,6 +308,33 @@ pub struct RuntimeConfig {
     ///
     /// Default: 1024.
     pub recursion_limit: usize,
+
+    /// Maximum number of steps executed before raising a fuel exhausted error.
+    ///
+    /// Every statement and terminator executed consumes one unit of fuel, including those of
+    /// closures called by intrinsic operations.
+    ///
+    /// Default: `None` (unlimited).
+    pub fuel: Option<u64>,
+
+    /// Maximum number of bytes allocated for values before raising a memory limit error.
+    ///
+    /// Counts the bytes of every value built by the interpreter, as estimated by
+    /// [`Value::allocation_size`]. The estimate is cumulative: values are never subtracted once
+    /// they are dropped, so this bounds the total amount of allocation over the lifetime of the
+    /// [`Budget`], rather than the amount of memory live at any time. Inputs and the results of
+    /// suspensions are provided externally and are not counted.
+    ///
+    /// Default: `None` (unlimited).
+    pub memory_limit: Option<usize>,
+
+    /// Maximum wall-clock time since the [`Budget`] started before raising a time limit error.
+    ///
+    /// The limit is checked whenever a basic block is entered, and includes the time spent
+    /// fulfilling suspensions.
+    ///
+    /// Default: `None` (unlimited).
+    pub time_limit: Option<Duration>,
 }
 
 impl Default for RuntimeConfig {
@@ -298,6 +342,39 @@ impl Default for RuntimeConfig {
     fn default() -> Self {
         Self {
             recursion_limit: 1024,
+            fuel: None,
+            memory_limit: None,
+            time_limit: None,
+        }
+    }
+}
+
+/// The resources consumed by the interpreter, checked against the limits of a [`RuntimeConfig`].
+///
+/// Every runtime carries a budget, which is restarted by [`Runtime::reset`]. To bound the
+/// resources of several runtimes together, for example all runtimes executing on behalf of the same
+/// request, hand a single budget from one to the next with [`Runtime::budget`] and
+/// [`Runtime::set_budget`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Budget {
+    /// Number of steps executed.
+    steps: u64,
+    /// Number of bytes allocated for values.
+    allocated: usize,
+    /// Point in time after which interpretation fails, derived from the time limit.
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    /// Starts a budget with no resources consumed, whose time limit starts counting from now.
+    #[must_use]
+    pub fn start(config: &RuntimeConfig) -> Self {
+        Self {
+            steps: 0,
+            allocated: 0,
+            deadline: config
+                .time_limit
+                .and_then(|limit| Instant::now().checked_add(limit)),
         }
     }
 }
@@ -307,7 +384,7 @@ impl Default for RuntimeConfig {
 /// Executes MIR code by stepping through basic blocks, handling statements
 /// and terminators, and managing function calls. The runtime holds:
 ///
-/// - Configuration options (recursion limits, etc.)
+/// - Configuration options (recursion, fuel, memory, and time limits)
 /// - All function bodies available for execution
 /// - Input values that can be loaded by [`Input`] rvalues
 ///
@@ -317,8 +394,11 @@ impl Default for RuntimeConfig {
 /// 2. Create a call stack with [`CallStack::new`] targeting the entry function
 /// 3. Execute with [`Runtime::run`] to get the result
 ///
+/// The events of the execution are reported to the [`Observer`] `O`, which can be attached with
+/// [`with_observer`](Self::with_observer). The default observer `()` ignores every event.
+///
 /// [`Input`]: crate::body::rvalue::Input
-pub struct Runtime<'ctx, 'heap, A: Allocator = Global> {
+pub struct Runtime<'ctx, 'heap, A: Allocator = Global, O = ()> {
     alloc: A,
 
     /// Runtime configuration.
@@ -329,6 +409,16 @@ pub struct Runtime<'ctx, 'heap, A: Allocator = Global> {
     inputs: &'ctx Inputs<'heap, A>,
 
     scratch: Scratch<'heap, A>,
+    /// Number of call stacks currently executing on behalf of intrinsic operations.
+    nested: usize,
+
+    /// Token checked at every block boundary to stop interpretation early.
+    cancellation: Option<CancellationToken>,
+    /// Resources consumed since the budget started.
+    budget: Budget,
+
+    /// Receives the events of the execution.
+    observer: O,
 }
 
 impl<'ctx, 'heap> Runtime<'ctx, 'heap> {
@@ -364,9 +454,118 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
             bodies,
             inputs,
             scratch: Scratch::new_in(alloc),
+            nested: 0,
+            cancellation: None,
+            budget: Budget::start(&config),
+            observer: (),
+        }
+    }
+}
+
+impl<'ctx, 'heap, A: Allocator + Clone, O: Observer<'heap, A>> Runtime<'ctx, 'heap, A, O> {
+    /// Replaces the observer, returning a new runtime reporting to the given observer.
+    ///
+    /// Pass a mutable reference to keep access to the observer once the runtime is dropped.
+    pub fn with_observer<O2: Observer<'heap, A>>(
+        self,
+        observer: O2,
+    ) -> Runtime<'ctx, 'heap, A, O2> {
+        Runtime {
+            alloc: self.alloc,
+            config: self.config,
+            bodies: self.bodies,
+            inputs: self.inputs,
+            scratch: self.scratch,
+            nested: self.nested,
+            cancellation: self.cancellation,
+            budget: self.budget,
+            observer,
+        }
+    }
+
+    /// Returns the observer of the runtime.
+    pub const fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns mutable access to the observer of the runtime.
+    pub const fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Attaches a cancellation token to the runtime.
+    ///
+    /// Once the token is cancelled, interpretation stops with [`RuntimeError::Cancelled`] the
+    /// next time a basic block is entered.
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Consumes a unit of fuel for the next step.
+    #[inline]
+    const fn consume_fuel<E>(&mut self) -> Result<(), RuntimeError<'heap, E, A>> {
+        self.budget.steps += 1;
+
+        match self.config.fuel {
+            Some(fuel) if self.budget.steps > fuel => {
+                cold_path();
+
+                Err(RuntimeError::FuelExhausted { fuel })
+            }
+            Some(_) | None => Ok(()),
+        }
+    }
+
+    /// Accounts for `bytes` newly allocated for values.
+    #[inline]
+    const fn allocate<E>(&mut self, bytes: usize) -> Result<(), RuntimeError<'heap, E, A>> {
+        self.budget.allocated = self.budget.allocated.saturating_add(bytes);
+
+        match self.config.memory_limit {
+            Some(limit) if self.budget.allocated > limit => {
+                cold_path();
+
+                Err(RuntimeError::MemoryLimitExceeded { limit })
+            }
+            Some(_) | None => Ok(()),
         }
     }
 
+    /// Accounts for the allocation of a freshly built value, and returns it.
+    #[inline]
+    fn allocated<E>(
+        &mut self,
+        value: Value<'heap, A>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        self.allocate(value.allocation_size())?;
+
+        Ok(value)
+    }
+
+    /// Checks for cancellation and the time limit when entering a block.
+    fn check_block_boundary<E>(&self) -> Result<(), RuntimeError<'heap, E, A>> {
+        if let Some(cancellation) = &self.cancellation
+            && cancellation.is_cancelled()
+        {
+            cold_path();
+
+            return Err(RuntimeError::Cancelled);
+        }
+
+        if let Some(deadline) = self.budget.deadline
+            && let Some(limit) = self.config.time_limit
+            && Instant::now() >= deadline
+        {
+            cold_path();
+
+            return Err(RuntimeError::TimeLimitExceeded { limit });
+        }
+
+        Ok(())
+    }
+
     fn make_frame<E>(
         &self,
         func: DefId,
@@ -460,6 +659,7 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
             }
             TerminatorKind::Return(Return { value }) => {
                 let value = frame.locals.operand(value)?.into_owned();
+                self.observer.on_return(frame.body.id, &value);
 
                 // No caller frame means we're returning from the entry function.
                 let Some(caller) = stack.last_mut() else {
@@ -481,14 +681,19 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
                 let lhs = caller.locals.place_mut(*lhs, &mut self.scratch)?;
                 *lhs = value;
 
+                let location = Location {
+                    block: caller.current_block.id,
+                    statement_index: caller.current_statement + 1,
+                };
+                self.observer
+                    .on_statement(caller.body.id, location, statement, &caller.locals);
+
                 caller.current_statement += 1;
 
                 Ok(ControlFlow::Continue(PopFrame::Yes))
             }
             TerminatorKind::GraphRead(read) => {
-                let axis = match read.head {
-                    GraphReadHead::Entity { axis } => frame.locals.operand(&axis)?,
-                };
+                let axis = frame.locals.operand(read.head.axis())?;
 
                 let axis = suspension::extract_axis(&axis)?;
 
@@ -679,6 +884,412 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
         }
     }
 
+    fn expect_string<'value, E>(
+        value: &'value Value<'heap, A>,
+    ) -> Result<&'value str, RuntimeError<'heap, E, A>> {
+        if let Value::String(string) = value {
+            Ok(string.as_str())
+        } else {
+            cold_path();
+
+            Err(RuntimeError::UnexpectedValueType {
+                expected: TypeName::terse("String"),
+                actual: value.type_name().into(),
+            })
+        }
+    }
+
+    fn make_string(&self, value: &str) -> Value<'heap, A> {
+        Value::String(Str::from(Rc::clone_from_ref_in(value, self.alloc.clone())))
+    }
+
+    fn eval_intrinsic_string<E>(
+        &mut self,
+        frame: &Frame<'ctx, 'heap, A>,
+        op: StringOp,
+        arguments: &ArgSlice<Operand<'heap>>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        let argument = |index: u32| frame.locals.operand(&arguments[ArgIndex::new(index)]);
+
+        let value = argument(0)?;
+        let value = Self::expect_string(&value)?;
+
+        match op {
+            StringOp::Lowercase => self.allocated(self.make_string(&value.to_lowercase())),
+            StringOp::Uppercase => self.allocated(self.make_string(&value.to_uppercase())),
+            // Only spaces, to be consistent with Postgres' `btrim`
+            StringOp::Trim => self.allocated(self.make_string(value.trim_matches(' '))),
+            // The number of characters, not bytes, to be consistent with Postgres' `length`
+            StringOp::Length => Ok(Value::Integer(Int::from(value.chars().count()))),
+            StringOp::Contains => {
+                let needle = argument(1)?;
+                let needle = Self::expect_string(&needle)?;
+
+                Ok(Value::Integer(Int::from(value.contains(needle))))
+            }
+            StringOp::StartsWith => {
+                let prefix = argument(1)?;
+                let prefix = Self::expect_string(&prefix)?;
+
+                Ok(Value::Integer(Int::from(value.starts_with(prefix))))
+            }
+            StringOp::EndsWith => {
+                let suffix = argument(1)?;
+                let suffix = Self::expect_string(&suffix)?;
+
+                Ok(Value::Integer(Int::from(value.ends_with(suffix))))
+            }
+            StringOp::Concat => {
+                let rhs = argument(1)?;
+                let rhs = Self::expect_string(&rhs)?;
+
+                let mut output = String::with_capacity(value.len() + rhs.len());
+                output.push_str(value);
+                output.push_str(rhs);
+
+                self.allocated(self.make_string(&output))
+            }
+            StringOp::Split => {
+                let separator = argument(1)?;
+                let separator = Self::expect_string(&separator)?;
+
+                // Mirrors Postgres' `string_to_array`: an empty string has no parts, and an empty
+                // separator does not split the string at all.
+                let mut list = List::new();
+                if value.is_empty() {
+                    // No parts
+                } else if separator.is_empty() {
+                    list.push_back(self.allocated(self.make_string(value))?);
+                } else {
+                    for part in value.split(separator) {
+                        list.push_back(self.allocated(self.make_string(part))?);
+                    }
+                }
+
+                self.allocated(Value::List(list))
+            }
+        }
+    }
+
+    fn expect_list<'value, E>(
+        value: &'value Value<'heap, A>,
+    ) -> Result<&'value List<'heap, A>, RuntimeError<'heap, E, A>> {
+        if let Value::List(list) = value {
+            Ok(list)
+        } else {
+            cold_path();
+
+            Err(RuntimeError::UnexpectedValueType {
+                expected: TypeName::terse("List"),
+                actual: value.type_name().into(),
+            })
+        }
+    }
+
+    fn expect_dict<'value, E>(
+        value: &'value Value<'heap, A>,
+    ) -> Result<&'value Dict<'heap, A>, RuntimeError<'heap, E, A>> {
+        if let Value::Dict(dict) = value {
+            Ok(dict)
+        } else {
+            cold_path();
+
+            Err(RuntimeError::UnexpectedValueType {
+                expected: TypeName::terse("Dict"),
+                actual: value.type_name().into(),
+            })
+        }
+    }
+
+    fn expect_bool<E>(value: &Value<'heap, A>) -> Result<bool, RuntimeError<'heap, E, A>> {
+        if let Value::Integer(int) = value
+            && let Some(value) = int.as_bool()
+        {
+            Ok(value)
+        } else {
+            cold_path();
+
+            Err(RuntimeError::UnexpectedValueType {
+                expected: TypeName::terse("Boolean"),
+                actual: value.type_name().into(),
+            })
+        }
+    }
+
+    #[expect(clippy::option_if_let_else, reason = "readability")]
+    fn make_option(&self, value: Option<Value<'heap, A>>) -> Value<'heap, A> {
+        match value {
+            Some(value) => Value::Opaque(Opaque::new(
+                sym::path::Some,
+                Rc::new_in(value, self.alloc.clone()),
+            )),
+            None => Value::Opaque(Opaque::new(
+                sym::path::None,
+                Rc::new_in(Value::Unit, self.alloc.clone()),
+            )),
+        }
+    }
+
+    /// Calls `function` to completion on a call stack of its own.
+    ///
+    /// Used by intrinsic operations which take a closure, such as `::core::list::map`. A closure
+    /// is a `(pointer, environment)` tuple, the environment is passed as the first argument.
+    fn call_nested<E, const N: usize>(
+        &mut self,
+        function: &Value<'heap, A>,
+        arguments: [Value<'heap, A>; N],
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        let (pointer, environment) = match function {
+            Value::Pointer(pointer) => (*pointer, None),
+            Value::Tuple(tuple) => match tuple.values() {
+                [Value::Pointer(pointer), environment] => (*pointer, Some(environment.clone())),
+                _ => {
+                    return Err(RuntimeError::ApplyNonPointer {
+                        r#type: function.type_name().into(),
+                    });
+                }
+            },
+            Value::Unit
+            | Value::Integer(_)
+            | Value::Number(_)
+            | Value::String(_)
+            | Value::Opaque(_)
+            | Value::Struct(_)
+            | Value::List(_)
+            | Value::Dict(_) => {
+                return Err(RuntimeError::ApplyNonPointer {
+                    r#type: function.type_name().into(),
+                });
+            }
+        };
+
+        if self.nested >= self.config.recursion_limit {
+            return Err(RuntimeError::RecursionLimitExceeded {
+                limit: self.config.recursion_limit,
+            });
+        }
+
+        let mut values = Vec::with_capacity(N + 1);
+        values.extend(environment);
+        values.extend(arguments);
+
+        let frame = self.make_frame(pointer.def(), values.into_iter().map(Ok))?;
+        let mut frames = Vec::new_in(self.alloc.clone());
+        frames.push(frame);
+        let mut callstack = CallStack { frames };
+
+        self.nested += 1;
+        let result = self.run_until_suspension(&mut callstack);
+        self.nested -= 1;
+
+        match result? {
+            Yield::Return(value) => Ok(value),
+            Yield::Suspension(_) => Err(RuntimeError::NestedSuspension),
+        }
+    }
+
+    fn eval_intrinsic_list<E>(
+        &mut self,
+        frame: &Frame<'ctx, 'heap, A>,
+        op: ListOp,
+        arguments: &ArgSlice<Operand<'heap>>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        let argument = |index: u32| frame.locals.operand(&arguments[ArgIndex::new(index)]);
+
+        let list = argument(0)?;
+        let list = Self::expect_list(&list)?;
+
+        match op {
+            ListOp::Len => Ok(Value::Integer(Int::from(list.len()))),
+            ListOp::Get => {
+                let index = argument(1)?;
+                let &Value::Integer(index) = index.as_ref() else {
+                    return Err(RuntimeError::UnexpectedValueType {
+                        expected: TypeName::terse("Integer"),
+                        actual: index.type_name().into(),
+                    });
+                };
+
+                self.allocated(self.make_option(list.get(index).cloned()))
+            }
+            ListOp::Contains => {
+                let value = argument(1)?;
+
+                Ok(Value::Integer(Int::from(
+                    list.iter().any(|element| *element == *value),
+                )))
+            }
+            ListOp::Concat => {
+                let rhs = argument(1)?;
+                let rhs = Self::expect_list(&rhs)?;
+
+                // The elements of `list` are shared with the output, only the new ones allocate
+                self.allocate(rhs.len() * size_of::<Value<'heap, A>>())?;
+
+                let mut output = list.clone();
+                for element in rhs {
+                    output.push_back(element.clone());
+                }
+
+                Ok(Value::List(output))
+            }
+            ListOp::Sort => {
+                let mut elements: Vec<_> = list.iter().collect();
+                elements.sort();
+
+                let mut output = List::new();
+                for element in elements {
+                    output.push_back(element.clone());
+                }
+
+                self.allocated(Value::List(output))
+            }
+            ListOp::Map => {
+                let function = argument(1)?;
+
+                let mut output = List::new();
+                for element in list {
+                    output.push_back(self.call_nested(&function, [element.clone()])?);
+                }
+
+                self.allocated(Value::List(output))
+            }
+            ListOp::Filter => {
+                let predicate = argument(1)?;
+
+                let mut output = List::new();
+                for element in list {
+                    let keep = self.call_nested(&predicate, [element.clone()])?;
+
+                    if Self::expect_bool(&keep)? {
+                        output.push_back(element.clone());
+                    }
+                }
+
+                self.allocated(Value::List(output))
+            }
+            ListOp::Fold => {
+                let mut accumulator = argument(1)?.into_owned();
+                let function = argument(2)?;
+
+                for element in list {
+                    accumulator = self.call_nested(&function, [accumulator, element.clone()])?;
+                }
+
+                Ok(accumulator)
+            }
+        }
+    }
+
+    fn eval_intrinsic_dict<E>(
+        &mut self,
+        frame: &Frame<'ctx, 'heap, A>,
+        op: DictOp,
+        arguments: &ArgSlice<Operand<'heap>>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        let argument = |index: u32| frame.locals.operand(&arguments[ArgIndex::new(index)]);
+
+        let dict = argument(0)?;
+        let dict = Self::expect_dict(&dict)?;
+
+        match op {
+            DictOp::Len => Ok(Value::Integer(Int::from(dict.len()))),
+            DictOp::Get => {
+                let key = argument(1)?;
+
+                self.allocated(self.make_option(dict.get(&key).cloned()))
+            }
+            DictOp::Contains => {
+                let key = argument(1)?;
+
+                Ok(Value::Integer(Int::from(dict.contains_key(&key))))
+            }
+            DictOp::Keys => {
+                let mut output = List::new();
+                for (key, _) in dict {
+                    output.push_back(key.clone());
+                }
+
+                self.allocated(Value::List(output))
+            }
+            DictOp::Values => {
+                let mut output = List::new();
+                for (_, value) in dict {
+                    output.push_back(value.clone());
+                }
+
+                self.allocated(Value::List(output))
+            }
+            DictOp::Insert => {
+                let key = argument(1)?.into_owned();
+                let value = argument(2)?.into_owned();
+
+                // The entries of `dict` are shared with the output, only the new one allocates
+                self.allocate(2 * size_of::<Value<'heap, A>>())?;
+
+                let mut output = dict.clone();
+                output.insert(key, value);
+
+                Ok(Value::Dict(output))
+            }
+            DictOp::Concat => {
+                let rhs = argument(1)?;
+                let rhs = Self::expect_dict(&rhs)?;
+
+                self.allocate(rhs.len() * 2 * size_of::<Value<'heap, A>>())?;
+
+                let mut output = dict.clone();
+                for (key, value) in rhs {
+                    output.insert(key.clone(), value.clone());
+                }
+
+                Ok(Value::Dict(output))
+            }
+        }
+    }
+
+    fn eval_rvalue_intrinsic<E>(
+        &mut self,
+        frame: &Frame<'ctx, 'heap, A>,
+        Intrinsic { op, arguments }: &Intrinsic<'heap>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        match *op {
+            IntrinsicOp::String(op) => self.eval_intrinsic_string(frame, op, arguments),
+            IntrinsicOp::List(op) => self.eval_intrinsic_list(frame, op, arguments),
+            IntrinsicOp::Dict(op) => self.eval_intrinsic_dict(frame, op, arguments),
+        }
+    }
+
+    /// Evaluates the discriminant of an operand.
+    ///
+    /// Values which are not an instance of any of the variants, including non-opaque values,
+    /// evaluate to the number of variants.
+    fn eval_rvalue_discriminant<E>(
+        frame: &Frame<'ctx, 'heap, A>,
+        Discriminant { operand, variants }: &Discriminant<'heap>,
+    ) -> Result<Value<'heap, A>, RuntimeError<'heap, E, A>> {
+        let operand = frame.locals.operand(operand)?;
+
+        let position = match operand.as_ref() {
+            Value::Opaque(opaque) => variants
+                .iter()
+                .position(|&variant| variant == opaque.name()),
+            Value::Unit
+            | Value::Integer(_)
+            | Value::Number(_)
+            | Value::String(_)
+            | Value::Pointer(_)
+            | Value::Struct(_)
+            | Value::Tuple(_)
+            | Value::List(_)
+            | Value::Dict(_) => None,
+        };
+
+        Ok(Value::Integer(Int::from(
+            position.unwrap_or(variants.len()),
+        )))
+    }
+
     fn eval_rvalue_apply<E>(
         &self,
         frame: &Frame<'ctx, 'heap, A>,
@@ -703,7 +1314,7 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
     }
 
     fn eval_rvalue<E>(
-        &self,
+        &mut self,
         frame: &Frame<'ctx, 'heap, A>,
         rvalue: &RValue<'heap>,
     ) -> Result<ControlFlow<Frame<'ctx, 'heap, A>, Value<'heap, A>>, RuntimeError<'heap, E, A>>
@@ -721,9 +1332,16 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
                 Self::eval_rvalue_unary(frame, unary).map(ControlFlow::Continue)
             }
             RValue::Aggregate(aggregate) => {
-                frame.locals.aggregate(aggregate).map(ControlFlow::Continue)
+                let value = frame.locals.aggregate(aggregate)?;
+                self.allocated(value).map(ControlFlow::Continue)
             }
             RValue::Input(input) => self.eval_rvalue_input(input).map(ControlFlow::Continue),
+            RValue::Intrinsic(intrinsic) => self
+                .eval_rvalue_intrinsic(frame, intrinsic)
+                .map(ControlFlow::Continue),
+            RValue::Discriminant(discriminant) => {
+                Self::eval_rvalue_discriminant(frame, discriminant).map(ControlFlow::Continue)
+            }
             RValue::Apply(apply) => self.eval_rvalue_apply(frame, apply).map(ControlFlow::Break),
         }
     }
@@ -753,6 +1371,13 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
             return Err(RuntimeError::CallstackEmpty);
         };
 
+        self.consume_fuel()?;
+        if frame.current_statement == 0 {
+            self.check_block_boundary()?;
+            self.observer
+                .on_block_enter(frame.body.id, frame.current_block.id, &frame.locals);
+        }
+
         if frame.current_statement >= frame.current_block.block.statements.len() {
             let next = self.step_terminator(stack, frame)?;
 
@@ -775,25 +1400,36 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
             }
         };
 
-        let Some(frame) = next_frame else {
+        let Some(callee) = next_frame else {
+            let location = Location {
+                block: frame.current_block.id,
+                statement_index: frame.current_statement + 1,
+            };
+            self.observer
+                .on_statement(frame.body.id, location, statement, &frame.locals);
+
             frame.current_statement += 1;
             return Ok(ControlFlow::Continue(()));
         };
 
+        let current = frame.body.id;
         if callstack.frames.len() >= self.config.recursion_limit {
             return Err(RuntimeError::RecursionLimitExceeded {
                 limit: self.config.recursion_limit,
             });
         }
 
-        callstack.frames.push(frame);
+        self.observer
+            .on_call(current, callee.body.id, &callee.locals);
+
+        callstack.frames.push(callee);
         Ok(ControlFlow::Continue(()))
     }
 
     /// Steps the interpreter until it either returns a value or suspends.
     ///
     /// This is the low-level driver loop. It does **not** clear scratch state,
-    /// so callers must call [`reset`](Self::reset) before the first invocation.
+    /// so callers must call [`clear_scratch`](Self::clear_scratch) before the first invocation.
     /// Prefer [`start`](Self::start) for the initial invocation and
     /// [`resume`](Self::resume) after fulfilling a suspension.
     ///
@@ -856,6 +1492,68 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
         }
     }
 
+    /// Steps the interpreter until a basic block is about to be executed, in any frame.
+    ///
+    /// Blocks are entered through jumps, calls, and continuations. The block the call stack is
+    /// positioned at when calling this method is not reported, so that calling the method again
+    /// executes the block it stopped at.
+    ///
+    /// # Return value
+    ///
+    /// - [`ControlFlow::Break`]: a block is about to be executed. The innermost frame of the
+    ///   callstack is positioned at the start of the returned block.
+    /// - [`ControlFlow::Continue`] with [`Yield::Return`]: interpretation completed.
+    /// - [`ControlFlow::Continue`] with [`Yield::Suspension`]: interpreter suspended for external
+    ///   data. Apply the continuation and call this method again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a runtime error if interpretation fails.
+    pub fn run_until_block_entry<E>(
+        &mut self,
+        callstack: &mut CallStack<'ctx, 'heap, A>,
+    ) -> Result<ControlFlow<Breakpoint, Yield<'ctx, 'heap, A>>, RuntimeError<'heap, E, A>> {
+        loop {
+            let next = self.step(callstack)?;
+            if let ControlFlow::Break(value) = next {
+                return Ok(ControlFlow::Continue(value));
+            }
+
+            if let Some(frame) = callstack.frames.last()
+                && frame.current_statement == 0
+            {
+                return Ok(ControlFlow::Break(Breakpoint {
+                    body: frame.body.id,
+                    block: frame.current_block.id,
+                }));
+            }
+        }
+    }
+
+    /// Steps the interpreter until one of the `breakpoints` is hit.
+    ///
+    /// Behaves like [`run_until_block_entry`](Self::run_until_block_entry), but only stops at
+    /// blocks in `breakpoints`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a runtime error if interpretation fails.
+    pub fn run_until_breakpoint<E>(
+        &mut self,
+        callstack: &mut CallStack<'ctx, 'heap, A>,
+        breakpoints: &[Breakpoint],
+    ) -> Result<ControlFlow<Breakpoint, Yield<'ctx, 'heap, A>>, RuntimeError<'heap, E, A>> {
+        loop {
+            match self.run_until_block_entry(callstack)? {
+                ControlFlow::Break(breakpoint) if breakpoints.contains(&breakpoint) => {
+                    return Ok(ControlFlow::Break(breakpoint));
+                }
+                ControlFlow::Break(_) => {}
+                ControlFlow::Continue(value) => return Ok(ControlFlow::Continue(value)),
+            }
+        }
+    }
+
     fn try_run(
         &mut self,
         callstack: &mut CallStack<'ctx, 'heap, A>,
@@ -864,7 +1562,7 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
         )
             -> Result<Continuation<'ctx, 'heap, A>, RuntimeError<'heap, !, A>>,
     ) -> Result<Value<'heap, A>, RuntimeError<'heap, !, A>> {
-        self.scratch.clear();
+        self.reset();
 
         loop {
             match self.run_until_suspension(callstack)? {
@@ -906,15 +1604,38 @@ impl<'ctx, 'heap, A: Allocator + Clone> Runtime<'ctx, 'heap, A> {
             })
     }
 
-    /// Clears ephemeral scratch state.
+    /// Clears ephemeral scratch state, keeping the budget.
     ///
-    /// Called automatically by [`start`](Self::start). Callers using the lower-level
-    /// [`run_until_suspension`](Self::run_until_suspension) directly must call this
-    /// before the first invocation.
-    pub fn reset(&mut self) {
+    /// Callers using the lower-level [`run_until_suspension`](Self::run_until_suspension) directly
+    /// must call this before the first invocation.
+    pub fn clear_scratch(&mut self) {
         self.scratch.clear();
     }
 
+    /// Restarts the budget, see [`Budget::start`].
+    pub fn restart_budget(&mut self) {
+        self.budget = Budget::start(&self.config);
+    }
+
+    /// Clears ephemeral scratch state and restarts the budget.
+    ///
+    /// Called automatically by [`start`](Self::start) and [`run`](Self::run).
+    pub fn reset(&mut self) {
+        self.clear_scratch();
+        self.restart_budget();
+    }
+
+    /// Returns the resources consumed so far.
+    #[must_use]
+    pub const fn budget(&self) -> Budget {
+        self.budget
+    }
+
+    /// Replaces the budget, continuing from the resources consumed by another runtime.
+    pub const fn set_budget(&mut self, budget: Budget) {
+        self.budget = budget;
+    }
+
     /// Begins interpretation from the given call stack.
     ///
     /// Clears scratch state and runs until the interpreter either returns
@@ -7,6 +7,7 @@
 //! - Function calls and returns
 //! - Aggregate construction
 //! - Input operations
+//! - Intrinsic operations
 //! - Error conditions
 #![expect(
     clippy::min_ident_chars,
@@ -14,8 +15,8 @@
     clippy::similar_names
 )]
 
-use alloc::rc::Rc;
-use core::{assert_matches, ops::ControlFlow};
+use alloc::{alloc::Global, rc::Rc};
+use core::{assert_matches, ops::ControlFlow, time::Duration};
 
 use hashql_core::{
     heap::{self, FromIteratorIn as _, Heap},
@@ -25,16 +26,20 @@ use hashql_core::{
 };
 
 use super::{
-    CallStack, Inputs, Runtime, RuntimeConfig,
+    Breakpoint, CallStack, CancellationToken, Inputs, Runtime, RuntimeConfig, RuntimeError,
     error::InterpretDiagnostic,
     runtime::Yield,
     suspension::Suspension,
-    value::{Int, Num, Opaque, Struct, Value},
+    trace::{Trace, TraceEvent},
+    value::{Dict, Int, List, Num, Opaque, Str, Struct, Value},
 };
 use crate::{
     body::{
         Body,
+        basic_block::BasicBlockId,
         constant::Constant,
+        local::Local,
+        location::Location,
         operand::Operand,
         rvalue::{Aggregate, AggregateKind, RValue},
         terminator::{GraphRead, GraphReadHead, GraphReadTail, TerminatorKind},
@@ -1134,46 +1139,692 @@ fn input_exists_returns_false() {
     assert_eq!(result, Value::Integer(Int::from(false)));
 }
 
+// =============================================================================
+// Intrinsic Operations
+// =============================================================================
+
+fn string(value: &str) -> Value<'static> {
+    Value::String(Str::from(Rc::<str>::from(value)))
+}
+
+#[test]
+fn intrinsic_string_case() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (String, String) {
+        decl lower: String, upper: String, result: (String, String);
+
+        bb0() {
+            lower = str.lowercase "Hello World";
+            upper = str.uppercase "Hello World";
+            result = tuple lower, upper;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [string("hello world"), string("HELLO WORLD")]
+    );
+}
+
+#[test]
+fn intrinsic_string_predicates() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (Bool, Bool, Bool, Bool) {
+        decl contains: Bool, starts: Bool, ends: Bool, missing: Bool, result: (Bool, Bool, Bool, Bool);
+
+        bb0() {
+            contains = str.contains "haystack", "st";
+            starts = str.starts_with "haystack", "hay";
+            ends = str.ends_with "haystack", "stack";
+            missing = str.contains "haystack", "needle";
+            result = tuple contains, starts, ends, missing;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [
+            Value::Integer(Int::from(true)),
+            Value::Integer(Int::from(true)),
+            Value::Integer(Int::from(true)),
+            Value::Integer(Int::from(false)),
+        ]
+    );
+}
+
+#[test]
+fn intrinsic_string_length_counts_characters() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = str.length "grüße";
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    assert_eq!(result, Value::Integer(Int::from(5_i128)));
+}
+
+#[test]
+fn intrinsic_string_concat_trim() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> String {
+        decl joined: String, result: String;
+
+        bb0() {
+            joined = str.concat "  hello", " world  ";
+            result = str.trim joined;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    assert_eq!(result, string("hello world"));
+}
+
+#[test]
+fn intrinsic_string_trim_only_spaces() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (String, String) {
+        decl tabs: String, newlines: String, result: (String, String);
+
+        bb0() {
+            tabs = str.trim " \t padded\t ";
+            newlines = str.trim "\n padded \r\n";
+            result = tuple tabs, newlines;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [string("\t padded\t"), string("\n padded \r\n")]
+    );
+}
+
+#[test]
+fn intrinsic_string_split() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> [List String] {
+        decl result: [List String];
+
+        bb0() {
+            result = str.split "a,b,,c", ",";
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+
+    let mut expected = List::new();
+    for part in ["a", "b", "", "c"] {
+        expected.push_back(string(part));
+    }
+    assert_eq!(result, Value::List(expected));
+}
+
+#[test]
+fn intrinsic_string_split_empty() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> ([List String], [List String]) {
+        decl empty: [List String], unsplit: [List String], result: ([List String], [List String]);
+
+        bb0() {
+            empty = str.split "", ",";
+            unsplit = str.split "a,b", "";
+            result = tuple empty, unsplit;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    let mut unsplit = List::new();
+    unsplit.push_back(string("a,b"));
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [Value::List(List::new()), Value::List(unsplit)]
+    );
+}
+
+fn int_list(values: impl IntoIterator<Item = i128>) -> List<'static, Global> {
+    let mut list = List::new();
+    for value in values {
+        list.push_back(Value::Integer(Int::from(value)));
+    }
+
+    list
+}
+
+fn some(value: Value<'static>) -> Value<'static> {
+    Value::Opaque(Opaque::new(sym::path::Some, Rc::new(value)))
+}
+
+fn none() -> Value<'static> {
+    Value::Opaque(Opaque::new(sym::path::None, Rc::new(Value::Unit)))
+}
+
+#[test]
+fn intrinsic_list_len_get() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (Int, [Opaque sym::path::Some; Int], [Opaque sym::path::Some; Int], [Opaque sym::path::None; Null]) {
+        decl xs: [List Int], len: Int, first: [Opaque sym::path::Some; Int], last: [Opaque sym::path::Some; Int],
+             missing: [Opaque sym::path::None; Null],
+             result: (Int, [Opaque sym::path::Some; Int], [Opaque sym::path::Some; Int], [Opaque sym::path::None; Null]);
+
+        bb0() {
+            xs = list 10, 20, 30;
+            len = list.len xs;
+            first = list.get xs, 0;
+            last = list.get xs, (-1);
+            missing = list.get xs, 3;
+            result = tuple len, first, last, missing;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [
+            Value::Integer(Int::from(3_i128)),
+            some(Value::Integer(Int::from(10_i128))),
+            some(Value::Integer(Int::from(30_i128))),
+            none(),
+        ]
+    );
+}
+
+#[test]
+fn intrinsic_list_contains_concat_sort() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (Bool, Bool, [List Int]) {
+        decl xs: [List Int], ys: [List Int], joined: [List Int], sorted: [List Int],
+             present: Bool, absent: Bool, result: (Bool, Bool, [List Int]);
+
+        bb0() {
+            xs = list 3, 1;
+            ys = list 2;
+            present = list.contains xs, 1;
+            absent = list.contains xs, 2;
+            joined = list.concat xs, ys;
+            sorted = list.sort joined;
+            result = tuple present, absent, sorted;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [
+            Value::Integer(Int::from(true)),
+            Value::Integer(Int::from(false)),
+            Value::List(int_list([1, 2, 3])),
+        ]
+    );
+}
+
+#[test]
+fn intrinsic_list_map_calls_closure() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let add_id = DefId::new(1);
+
+    let main = body!(interner, env; fn@0/0 -> [List Int] {
+        decl xs: [List Int], capture: (Int), func: [fn(Int) -> Int], result: [List Int];
+
+        bb0() {
+            xs = list 1, 2, 3;
+            capture = tuple 10;
+            func = closure add_id capture;
+            result = list.map xs, func;
+            return result;
+        }
+    });
+
+    let add = body!(interner, env; fn@add_id/2 -> Int {
+        decl env: (Int), x: Int, result: Int;
+        @proj offset = env.0: Int;
+
+        bb0() {
+            result = bin.+ x offset;
+            return result;
+        }
+    });
+
+    let result =
+        run_bodies(DefIdSlice::from_raw(&[main, add]), DefId::new(0), []).expect("should succeed");
+    assert_eq!(result, Value::List(int_list([11, 12, 13])));
+}
+
+#[test]
+fn intrinsic_list_filter_fold() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let is_large_id = DefId::new(1);
+    let sum_id = DefId::new(2);
+
+    let main = body!(interner, env; fn@0/0 -> ([List Int], Int) {
+        decl xs: [List Int], capture: (), predicate: [fn(Int) -> Bool], func: [fn(Int, Int) -> Int],
+             large: [List Int], sum: Int, result: ([List Int], Int);
+
+        bb0() {
+            xs = list 1, 5, 2, 7;
+            capture = tuple;
+            predicate = closure is_large_id capture;
+            func = closure sum_id capture;
+            large = list.filter xs, predicate;
+            sum = list.fold xs, 100, func;
+            result = tuple large, sum;
+            return result;
+        }
+    });
+
+    let is_large = body!(interner, env; fn@is_large_id/2 -> Bool {
+        decl env: (), x: Int, result: Bool;
+
+        bb0() {
+            result = bin.> x 2;
+            return result;
+        }
+    });
+
+    let sum = body!(interner, env; fn@sum_id/3 -> Int {
+        decl env: (), acc: Int, x: Int, result: Int;
+
+        bb0() {
+            result = bin.+ acc x;
+            return result;
+        }
+    });
+
+    let result = run_bodies(
+        DefIdSlice::from_raw(&[main, is_large, sum]),
+        DefId::new(0),
+        [],
+    )
+    .expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [
+            Value::List(int_list([5, 7])),
+            Value::Integer(Int::from(115_i128)),
+        ]
+    );
+}
+
+#[test]
+fn intrinsic_dict_operations() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> (Int, [Opaque sym::path::Some; Int], Bool, [List String], [List Int], [Dict String Int]) {
+        decl dict: [Dict String Int], other: [Dict String Int], len: Int, value: [Opaque sym::path::Some; Int],
+             contains: Bool, keys: [List String], values: [List Int], inserted: [Dict String Int],
+             merged: [Dict String Int],
+             result: (Int, [Opaque sym::path::Some; Int], Bool, [List String], [List Int], [Dict String Int]);
+
+        bb0() {
+            dict = input.load "dict";
+            other = input.load "other";
+            len = dict.len dict;
+            value = dict.get dict, "b";
+            contains = dict.contains dict, "c";
+            keys = dict.keys dict;
+            values = dict.values dict;
+            inserted = dict.insert dict, "c", 3;
+            merged = dict.concat inserted, other;
+            result = tuple len, value, contains, keys, values, merged;
+            return result;
+        }
+    });
+
+    let mut dict = Dict::new();
+    dict.insert(string("b"), Value::Integer(Int::from(2_i128)));
+    dict.insert(string("a"), Value::Integer(Int::from(1_i128)));
+
+    let mut other = Dict::new();
+    other.insert(string("a"), Value::Integer(Int::from(10_i128)));
+
+    let mut inputs = Inputs::default();
+    inputs.insert(heap.intern_symbol("dict"), Value::Dict(dict));
+    inputs.insert(heap.intern_symbol("other"), Value::Dict(other));
+
+    let result = run_body_with_inputs(body, inputs).expect("should succeed");
+    let Value::Tuple(tuple) = result else {
+        panic!("expected tuple, got {result:?}");
+    };
+
+    let mut keys = List::new();
+    keys.push_back(string("a"));
+    keys.push_back(string("b"));
+
+    let mut merged = Dict::new();
+    merged.insert(string("a"), Value::Integer(Int::from(10_i128)));
+    merged.insert(string("b"), Value::Integer(Int::from(2_i128)));
+    merged.insert(string("c"), Value::Integer(Int::from(3_i128)));
+
+    assert_eq!(
+        tuple.iter().cloned().collect::<Vec<_>>(),
+        [
+            Value::Integer(Int::from(2_i128)),
+            some(Value::Integer(Int::from(2_i128))),
+            Value::Integer(Int::from(false)),
+            Value::List(keys),
+            Value::List(int_list([1, 2])),
+            Value::Dict(merged),
+        ]
+    );
+}
+
 // =============================================================================
 // Error Conditions
 // =============================================================================
 
 #[test]
-fn input_load_missing_fails() {
+fn input_load_missing_fails() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = input.load "missing_input";
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect_err("should fail with missing input");
+    assert_eq!(
+        result.category,
+        InterpretDiagnosticCategory::InputResolution
+    );
+}
+
+#[test]
+fn recursion_limit_exceeded() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let self_id = DefId::new(0);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = apply self_id;
+            return result;
+        }
+    });
+
+    let bodies = [body];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
+
+    let config = RuntimeConfig {
+        recursion_limit: 5,
+        ..RuntimeConfig::default()
+    };
+    let mut runtime = Runtime::new(config, bodies, &inputs);
+    let callstack = CallStack::new(&runtime, DefId::new(0), []);
+
+    let result = runtime
+        .run(callstack, |_| unreachable!())
+        .expect_err("should fail with recursion limit");
+    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+}
+
+#[test]
+fn integer_add_overflow() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = bin.+ 170141183460469231731687303715884105727 1;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect_err("should fail with integer overflow");
+    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+}
+
+#[test]
+fn integer_sub_overflow() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = bin.- (-0x8000_0000_0000_0000_0000_0000_0000_0000) 1;
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect_err("should fail with integer overflow");
+    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+}
+
+#[test]
+fn integer_neg_overflow() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            result = un.neg (-0x8000_0000_0000_0000_0000_0000_0000_0000);
+            return result;
+        }
+    });
+
+    let result = run_body(body).expect_err("should fail with integer overflow");
+    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+}
+
+#[test]
+fn out_of_range_list_index() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let mut builder = BodyBuilder::new(&interner);
+
+    let list = builder.local("list", TypeId::MAX);
+    let idx = builder.local("idx", TypeId::MAX);
+    let result = builder.local("result", TypeId::MAX);
+
+    let const1 = builder.const_int(1);
+    let const2 = builder.const_int(2);
+    let const99 = builder.const_int(99);
+
+    let list_indexed = builder.place(|place| place.from(list).index(idx.local, TypeId::MAX));
+
+    let bb0 = builder.reserve_block([]);
+
+    builder
+        .build_block(bb0)
+        .assign_place(idx, |rv| rv.load(const99))
+        .assign_place(list, |rv| rv.list([const1, const2]))
+        .assign_place(list_indexed, |rv| rv.load(const99))
+        .assign_place(result, |rv| rv.load(list_indexed))
+        .ret(result);
+
+    let mut body = builder.finish(0, TypeId::MAX);
+    body.id = DefId::new(0);
+
+    let result = run_body(body).expect_err("should fail with out of range");
+    assert_eq!(result.category, InterpretDiagnosticCategory::BoundsCheck);
+}
+
+// =============================================================================
+// Resource Limits
+// =============================================================================
+
+fn run_body_limited(
+    body: Body<'_>,
+    config: RuntimeConfig,
+    cancellation: CancellationToken,
+) -> Result<Value<'_>, RuntimeError<'_, !, Global>> {
+    let bodies = [body];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
+
+    let mut runtime = Runtime::new(config, bodies, &inputs).with_cancellation(cancellation);
+    let mut callstack = CallStack::new(&runtime, DefId::new(0), []);
+    runtime.reset();
+
+    match runtime.run_until_suspension(&mut callstack)? {
+        Yield::Return(value) => Ok(value),
+        Yield::Suspension(_) => unreachable!("body does not suspend"),
+    }
+}
+
+#[test]
+fn fuel_exhausted_by_infinite_loop() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
     let env = Environment::new(&heap);
 
     let body = body!(interner, env; fn@0/0 -> Int {
-        decl result: Int;
+        decl;
 
         bb0() {
-            result = input.load "missing_input";
-            return result;
+            goto bb0();
         }
     });
 
-    let result = run_body(body).expect_err("should fail with missing input");
-    assert_eq!(
-        result.category,
-        InterpretDiagnosticCategory::InputResolution
-    );
+    let config = RuntimeConfig {
+        fuel: Some(100),
+        ..RuntimeConfig::default()
+    };
+
+    let result = run_body_limited(body, config, CancellationToken::new());
+    assert_matches!(result, Err(RuntimeError::FuelExhausted { fuel: 100 }));
 }
 
 #[test]
-fn recursion_limit_exceeded() {
+fn fuel_sufficient_for_program() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
     let env = Environment::new(&heap);
 
-    let self_id = DefId::new(0);
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int;
+
+        bb0() {
+            x = load 1;
+            goto bb1();
+        },
+        bb1() {
+            return x;
+        }
+    });
+
+    // One step for each statement and terminator
+    let config = RuntimeConfig {
+        fuel: Some(3),
+        ..RuntimeConfig::default()
+    };
+
+    let result = run_body_limited(body, config, CancellationToken::new());
+    assert_matches!(result, Ok(Value::Integer(int)) if int == Int::from(1_i128));
+}
+
+#[test]
+fn fuel_shared_through_budget() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
 
     let body = body!(interner, env; fn@0/0 -> Int {
-        decl result: Int;
+        decl x: Int;
 
         bb0() {
-            result = apply self_id;
-            return result;
+            x = load 1;
+            goto bb1();
+        },
+        bb1() {
+            return x;
         }
     });
 
@@ -1181,104 +1832,125 @@ fn recursion_limit_exceeded() {
     let bodies = DefIdSlice::from_raw(&bodies);
     let inputs = Inputs::default();
 
-    let config = RuntimeConfig { recursion_limit: 5 };
-    let mut runtime = Runtime::new(config, bodies, &inputs);
-    let callstack = CallStack::new(&runtime, DefId::new(0), []);
+    // Sufficient for one run of the program, but not for two
+    let config = RuntimeConfig {
+        fuel: Some(5),
+        ..RuntimeConfig::default()
+    };
 
-    let result = runtime
-        .run(callstack, |_| unreachable!())
-        .expect_err("should fail with recursion limit");
-    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+    let mut first = Runtime::new(config, bodies, &inputs);
+    let mut callstack = CallStack::new(&first, DefId::new(0), []);
+    first.reset();
+
+    let result = first.run_until_suspension::<!>(&mut callstack);
+    assert_matches!(result, Ok(Yield::Return(_)));
+
+    let mut second = Runtime::new(config, bodies, &inputs);
+    let mut callstack = CallStack::new(&second, DefId::new(0), []);
+    second.clear_scratch();
+    second.set_budget(first.budget());
+
+    let result = second.run_until_suspension::<!>(&mut callstack);
+    assert_matches!(result, Err(RuntimeError::FuelExhausted { fuel: 5 }));
 }
 
 #[test]
-fn integer_add_overflow() {
+fn memory_limit_exceeded_by_aggregate() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
     let env = Environment::new(&heap);
 
-    let body = body!(interner, env; fn@0/0 -> Int {
-        decl result: Int;
+    let body = body!(interner, env; fn@0/0 -> (Int, Int) {
+        decl tup: (Int, Int);
 
         bb0() {
-            result = bin.+ 170141183460469231731687303715884105727 1;
-            return result;
+            tup = tuple 1, 2;
+            return tup;
         }
     });
 
-    let result = run_body(body).expect_err("should fail with integer overflow");
-    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+    let config = RuntimeConfig {
+        memory_limit: Some(size_of::<Value<'_>>()),
+        ..RuntimeConfig::default()
+    };
+
+    let result = run_body_limited(body, config, CancellationToken::new());
+    assert_matches!(result, Err(RuntimeError::MemoryLimitExceeded { .. }));
 }
 
 #[test]
-fn integer_sub_overflow() {
+fn time_limit_exceeded_at_block_boundary() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
     let env = Environment::new(&heap);
 
     let body = body!(interner, env; fn@0/0 -> Int {
-        decl result: Int;
+        decl;
 
         bb0() {
-            result = bin.- (-0x8000_0000_0000_0000_0000_0000_0000_0000) 1;
-            return result;
+            goto bb0();
         }
     });
 
-    let result = run_body(body).expect_err("should fail with integer overflow");
-    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+    let config = RuntimeConfig {
+        time_limit: Some(Duration::ZERO),
+        ..RuntimeConfig::default()
+    };
+
+    let result = run_body_limited(body, config, CancellationToken::new());
+    assert_matches!(result, Err(RuntimeError::TimeLimitExceeded { .. }));
 }
 
 #[test]
-fn integer_neg_overflow() {
+fn cancelled_at_block_boundary() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
     let env = Environment::new(&heap);
 
     let body = body!(interner, env; fn@0/0 -> Int {
-        decl result: Int;
+        decl;
 
         bb0() {
-            result = un.neg (-0x8000_0000_0000_0000_0000_0000_0000_0000);
-            return result;
+            goto bb0();
         }
     });
 
-    let result = run_body(body).expect_err("should fail with integer overflow");
-    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let result = run_body_limited(body, RuntimeConfig::default(), token);
+    assert_matches!(result, Err(RuntimeError::Cancelled));
 }
 
 #[test]
-fn out_of_range_list_index() {
+fn resource_limit_diagnostic() {
     let heap = Heap::new();
     let interner = Interner::new(&heap);
-    let mut builder = BodyBuilder::new(&interner);
-
-    let list = builder.local("list", TypeId::MAX);
-    let idx = builder.local("idx", TypeId::MAX);
-    let result = builder.local("result", TypeId::MAX);
-
-    let const1 = builder.const_int(1);
-    let const2 = builder.const_int(2);
-    let const99 = builder.const_int(99);
+    let env = Environment::new(&heap);
 
-    let list_indexed = builder.place(|place| place.from(list).index(idx.local, TypeId::MAX));
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl;
 
-    let bb0 = builder.reserve_block([]);
+        bb0() {
+            goto bb0();
+        }
+    });
 
-    builder
-        .build_block(bb0)
-        .assign_place(idx, |rv| rv.load(const99))
-        .assign_place(list, |rv| rv.list([const1, const2]))
-        .assign_place(list_indexed, |rv| rv.load(const99))
-        .assign_place(result, |rv| rv.load(list_indexed))
-        .ret(result);
+    let bodies = [body];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
 
-    let mut body = builder.finish(0, TypeId::MAX);
-    body.id = DefId::new(0);
+    let config = RuntimeConfig {
+        fuel: Some(10),
+        ..RuntimeConfig::default()
+    };
+    let mut runtime = Runtime::new(config, bodies, &inputs);
+    let callstack = CallStack::new(&runtime, DefId::new(0), []);
 
-    let result = run_body(body).expect_err("should fail with out of range");
-    assert_eq!(result.category, InterpretDiagnosticCategory::BoundsCheck);
+    let result = runtime
+        .run(callstack, |_| unreachable!())
+        .expect_err("should fail with exhausted fuel");
+    assert_eq!(result.category, InterpretDiagnosticCategory::RuntimeLimit);
 }
 
 // =============================================================================
@@ -2159,6 +2831,215 @@ fn transition_fires_on_reentry_after_continuation_apply() {
     assert_eq!(current, bb1_id);
 }
 
+// =============================================================================
+// Observation and Stepping
+// =============================================================================
+
+#[test]
+fn trace_records_calls_and_statements() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let caller_id = DefId::new(0);
+    let callee_id = DefId::new(1);
+
+    let caller = body!(interner, env; fn@caller_id/0 -> Bool {
+        decl result: Bool;
+
+        bb0() {
+            result = apply callee_id, 10, 20;
+            return result;
+        }
+    });
+
+    let callee = body!(interner, env; fn@callee_id/2 -> Bool {
+        decl a: Int, b: Int, result: Bool;
+
+        bb0() {
+            result = bin.< a b;
+            return result;
+        }
+    });
+
+    let bodies = [caller, callee];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
+
+    let mut trace = Trace::new();
+    let mut runtime =
+        Runtime::new(RuntimeConfig::default(), bodies, &inputs).with_observer(&mut trace);
+    let callstack = CallStack::new(&runtime, caller_id, []);
+
+    let result = runtime
+        .run(callstack, |_| unreachable!())
+        .expect("should succeed");
+    assert_eq!(result, Value::Integer(Int::from(true)));
+    drop(runtime);
+
+    let events = trace.events();
+    assert_eq!(events.len(), 7);
+
+    assert_matches!(
+        events[0],
+        TraceEvent::BlockEnter { body, block } if body == caller_id && block == BasicBlockId::START
+    );
+    assert_matches!(
+        &events[1],
+        TraceEvent::Call { caller, callee, arguments }
+            if *caller == caller_id
+                && *callee == callee_id
+                && *arguments == [
+                    Value::Integer(Int::from(10_i128)),
+                    Value::Integer(Int::from(20_i128)),
+                ]
+    );
+    assert_matches!(
+        events[2],
+        TraceEvent::BlockEnter { body, block } if body == callee_id && block == BasicBlockId::START
+    );
+    assert_matches!(
+        &events[3],
+        TraceEvent::Statement { body, location, assigned: Some((local, value)) }
+            if *body == callee_id
+                && *location == Location { block: BasicBlockId::START, statement_index: 1 }
+                && *local == Local::new(2)
+                && *value == Value::Integer(Int::from(true))
+    );
+    assert_matches!(
+        &events[4],
+        TraceEvent::Return { body, value }
+            if *body == callee_id && *value == Value::Integer(Int::from(true))
+    );
+    // The call statement of the caller completes once the callee returned
+    assert_matches!(
+        &events[5],
+        TraceEvent::Statement { body, location, assigned: Some((local, value)) }
+            if *body == caller_id
+                && *location == Location { block: BasicBlockId::START, statement_index: 1 }
+                && *local == Local::new(0)
+                && *value == Value::Integer(Int::from(true))
+    );
+    assert_matches!(
+        &events[6],
+        TraceEvent::Return { body, value }
+            if *body == caller_id && *value == Value::Integer(Int::from(true))
+    );
+}
+
+#[test]
+fn block_entry_stops_at_every_block() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int;
+
+        bb0() {
+            goto bb1();
+        },
+        bb1() {
+            goto bb2(42);
+        },
+        bb2(x) {
+            return x;
+        }
+    });
+
+    let bodies = [body];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
+
+    let mut runtime = Runtime::new(RuntimeConfig::default(), bodies, &inputs);
+    let mut callstack = CallStack::new(&runtime, DefId::new(0), []);
+    runtime.reset();
+
+    for block in [1, 2] {
+        let result = runtime.run_until_block_entry::<!>(&mut callstack);
+        assert_matches!(
+            result,
+            Ok(ControlFlow::Break(Breakpoint { body, block: entered }))
+                if body == DefId::new(0) && entered == BasicBlockId::new(block)
+        );
+    }
+
+    // Block parameters are assigned before the block is entered
+    let locals = callstack
+        .locals::<!, Global>()
+        .expect("callstack should not be empty");
+    assert_eq!(
+        locals
+            .local::<!>(Local::new(0))
+            .expect("should be initialized"),
+        &Value::Integer(Int::from(42_i128))
+    );
+
+    let result = runtime.run_until_block_entry::<!>(&mut callstack);
+    assert_matches!(result, Ok(ControlFlow::Continue(Yield::Return(value))) if value == Value::Integer(Int::from(42_i128)));
+}
+
+#[test]
+fn breakpoint_stops_in_callee() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let callee_id = DefId::new(1);
+
+    let caller = body!(interner, env; fn@0/0 -> Int {
+        decl result: Int;
+
+        bb0() {
+            goto bb1();
+        },
+        bb1() {
+            result = apply callee_id, 10, 20;
+            return result;
+        }
+    });
+
+    let callee = body!(interner, env; fn@callee_id/2 -> Int {
+        decl a: Int, b: Int, result: Int;
+
+        bb0() {
+            result = bin.+ a b;
+            return result;
+        }
+    });
+
+    let bodies = [caller, callee];
+    let bodies = DefIdSlice::from_raw(&bodies);
+    let inputs = Inputs::default();
+
+    let breakpoint = Breakpoint {
+        body: callee_id,
+        block: BasicBlockId::START,
+    };
+
+    let mut runtime = Runtime::new(RuntimeConfig::default(), bodies, &inputs);
+    let mut callstack = CallStack::new(&runtime, DefId::new(0), []);
+    runtime.reset();
+
+    let result = runtime.run_until_breakpoint::<!>(&mut callstack, &[breakpoint]);
+    assert_matches!(result, Ok(ControlFlow::Break(hit)) if hit == breakpoint);
+
+    // Stopped before the callee executed any statement, only the arguments are initialized
+    let locals = callstack
+        .locals::<!, Global>()
+        .expect("callstack should not be empty");
+    assert_eq!(locals.iter().len(), 2);
+    assert_eq!(
+        locals
+            .local::<!>(Local::new(0))
+            .expect("should be initialized"),
+        &Value::Integer(Int::from(10_i128))
+    );
+
+    let result = runtime.run_until_breakpoint::<!>(&mut callstack, &[breakpoint]);
+    assert_matches!(result, Ok(ControlFlow::Continue(Yield::Return(value))) if value == Value::Integer(Int::from(30_i128)));
+}
+
 // =============================================================================
 // CallStack edge cases
 // =============================================================================
@@ -0,0 +1,152 @@
+//! Recording of execution traces.
+//!
+//! A [`Trace`] is an [`Observer`] which records every event of the interpreter, together with the
+//! values involved. Once interpretation finished, the trace can be rendered alongside the MIR using
+//! [`TextFormat::format_trace`].
+//!
+//! [`TextFormat::format_trace`]: crate::pretty::TextFormat::format_trace
+
+use alloc::alloc::Global;
+use core::alloc::Allocator;
+
+use super::{Observer, locals::Locals, value::Value};
+use crate::{
+    body::{
+        basic_block::BasicBlockId,
+        local::Local,
+        location::Location,
+        statement::{Assign, Statement, StatementKind},
+    },
+    def::DefId,
+};
+
+/// A single event recorded in a [`Trace`].
+#[derive(Debug, Clone)]
+pub enum TraceEvent<'heap, A: Allocator = Global> {
+    /// The block `block` of `body` has been entered.
+    BlockEnter { body: DefId, block: BasicBlockId },
+    /// The statement at `location` of `body` has been executed.
+    ///
+    /// For assignments, `assigned` is the local written to, together with its value after the
+    /// assignment.
+    Statement {
+        body: DefId,
+        location: Location,
+        assigned: Option<(Local, Value<'heap, A>)>,
+    },
+    /// `caller` called `callee` with `arguments`.
+    Call {
+        caller: DefId,
+        callee: DefId,
+        arguments: Vec<Value<'heap, A>>,
+    },
+    /// `body` returned `value`.
+    Return { body: DefId, value: Value<'heap, A> },
+}
+
+/// Records the events of the interpreter in the order they occurred.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut trace = Trace::new();
+/// let mut runtime = Runtime::new(config, &bodies, &inputs).with_observer(&mut trace);
+///
+/// let callstack = CallStack::new(&runtime, entry, []);
+/// let value = runtime.run(callstack, on_suspension)?;
+/// drop(runtime);
+///
+/// for event in trace.events() {
+///     println!("{event:?}");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trace<'heap, A: Allocator = Global> {
+    events: Vec<TraceEvent<'heap, A>>,
+}
+
+impl<'heap, A: Allocator> Trace<'heap, A> {
+    /// Creates an empty trace.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Returns the recorded events, oldest first.
+    #[must_use]
+    pub const fn events(&self) -> &[TraceEvent<'heap, A>] {
+        &self.events
+    }
+
+    /// Returns the number of recorded events.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns whether no event has been recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes every recorded event.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+impl<A: Allocator> Default for Trace<'_, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'heap, A: Allocator + Clone> Observer<'heap, A> for Trace<'heap, A> {
+    fn on_block_enter(&mut self, body: DefId, block: BasicBlockId, _: &Locals<'_, 'heap, A>) {
+        self.events.push(TraceEvent::BlockEnter { body, block });
+    }
+
+    fn on_statement(
+        &mut self,
+        body: DefId,
+        location: Location,
+        statement: &Statement<'heap>,
+        locals: &Locals<'_, 'heap, A>,
+    ) {
+        let assigned = match &statement.kind {
+            StatementKind::Assign(Assign { lhs, rhs: _ }) => locals
+                .local::<!>(lhs.local)
+                .ok()
+                .map(|value| (lhs.local, value.clone())),
+            StatementKind::Nop | StatementKind::StorageLive(_) | StatementKind::StorageDead(_) => {
+                None
+            }
+        };
+
+        self.events.push(TraceEvent::Statement {
+            body,
+            location,
+            assigned,
+        });
+    }
+
+    #[expect(clippy::similar_names, reason = "caller and callee")]
+    fn on_call(&mut self, caller: DefId, callee: DefId, locals: &Locals<'_, 'heap, A>) {
+        let arguments = locals.iter().map(|(_, value)| value.clone()).collect();
+
+        self.events.push(TraceEvent::Call {
+            caller,
+            callee,
+            arguments,
+        });
+    }
+
+    fn on_return(&mut self, body: DefId, value: &Value<'heap, A>) {
+        self.events.push(TraceEvent::Return {
+            body,
+            value: value.clone(),
+        });
+    }
+}
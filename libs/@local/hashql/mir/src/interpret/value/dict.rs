@@ -154,6 +154,27 @@ impl<'heap, A: Allocator> Dict<'heap, A> {
         self.inner.get(key)
     }
 
+    /// Returns `true` if the dictionary contains the `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # extern crate alloc;
+    /// # use alloc::alloc::Global;
+    /// use hashql_mir::interpret::value::{Dict, Value};
+    ///
+    /// let mut dict: Dict<'_, Global> = Dict::new();
+    /// dict.insert(Value::Integer(1.into()), Value::Unit);
+    ///
+    /// assert!(dict.contains_key(&Value::Integer(1.into())));
+    /// assert!(!dict.contains_key(&Value::Integer(2.into())));
+    /// ```
+    #[must_use]
+    pub fn contains_key(&self, key: &Value<'heap, A>) -> bool {
+        self.inner.contains_key(key)
+    }
+
     /// Returns a mutable reference to the value for `key`, inserting
     /// [`Value::Unit`] if absent.
     ///
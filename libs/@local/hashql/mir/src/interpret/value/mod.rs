@@ -152,6 +152,48 @@ impl<'heap, A: Allocator> Value<'heap, A> {
         ValueTypeName::from(self)
     }
 
+    /// Returns the number of bytes allocated directly by this value.
+    ///
+    /// Only the allocation owned by the value itself is counted, the values it contains are
+    /// shared through reference counting and are accounted for when they are created. The size is
+    /// an estimate used to enforce the memory limit of the runtime, not the exact size of the
+    /// allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # extern crate alloc;
+    /// # use alloc::alloc::Global;
+    /// use core::mem::size_of;
+    ///
+    /// use hashql_mir::interpret::value::{List, Value};
+    ///
+    /// assert_eq!(Value::<'_, Global>::Unit.allocation_size(), 0);
+    ///
+    /// let mut list: List<'_, Global> = List::new();
+    /// list.push_back(Value::Unit);
+    /// list.push_back(Value::Unit);
+    /// assert_eq!(
+    ///     Value::List(list).allocation_size(),
+    ///     2 * size_of::<Value<'_, Global>>()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn allocation_size(&self) -> usize {
+        let value = size_of::<Self>();
+
+        match self {
+            Value::Unit | Value::Integer(_) | Value::Number(_) | Value::Pointer(_) => 0,
+            Value::String(string) => string.allocation_size(),
+            Value::Opaque(_) => value,
+            Value::Struct(r#struct) => r#struct.len() * value,
+            Value::Tuple(tuple) => tuple.len().get() * value,
+            Value::List(list) => list.len() * value,
+            Value::Dict(dict) => dict.len() * 2 * value,
+        }
+    }
+
     const fn type_name_terse(&self) -> &'static str {
         match self {
             Value::Unit => "()",
@@ -480,6 +522,53 @@ impl<'heap, A: Allocator> Value<'heap, A> {
             }),
         }
     }
+
+    /// Returns the value wrapped by this opaque value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this value is not an instance of the opaque type named `name`.
+    pub fn downcast<'this, E>(
+        &'this self,
+        name: Symbol<'heap>,
+    ) -> Result<&'this Self, RuntimeError<'heap, E, A>> {
+        if let Value::Opaque(opaque) = self
+            && opaque.name() == name
+        {
+            return Ok(opaque.value());
+        }
+
+        Err(RuntimeError::InvalidProjectionType {
+            base: self.type_name().into(),
+        })
+    }
+
+    /// Mutably returns the value wrapped by this opaque value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this value is not an instance of the opaque type named `name`.
+    pub fn downcast_mut<'this, E>(
+        &'this mut self,
+        name: Symbol<'heap>,
+    ) -> Result<&'this mut Self, RuntimeError<'heap, E, A>>
+    where
+        A: Clone,
+    {
+        // Checked up front, as the error path would otherwise overlap the mutable borrow of the
+        // returned value.
+        if !matches!(self, Value::Opaque(opaque) if opaque.name() == name) {
+            return Err(RuntimeError::InvalidProjectionType {
+                base: self.type_name().into(),
+            });
+        }
+
+        let Value::Opaque(opaque) = self else {
+            unreachable!("the value has been checked to be an opaque value")
+        };
+
+        Ok(opaque.value_mut())
+    }
 }
 
 impl<'heap, A: Allocator> From<Constant<'heap>> for Value<'heap, A> {
@@ -123,6 +123,29 @@ impl<A: Allocator> Str<'_, A> {
             },
         }
     }
+
+    /// Returns the number of bytes allocated by this string.
+    ///
+    /// Interned strings are owned by the heap and do not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hashql_mir::interpret::value::Str;
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    ///
+    /// let s = Str::from(Rc::<str>::from("hello"));
+    /// assert_eq!(s.allocation_size(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn allocation_size(&self) -> usize {
+        match &self.inner {
+            StrInner::Owned(value) => value.len(),
+            StrInner::Interned(_) => 0,
+        }
+    }
 }
 
 impl<'heap, A: Allocator> From<Symbol<'heap>> for Str<'heap, A> {
@@ -63,7 +63,7 @@ use crate::{
     context::MirContext,
     def::{DefId, DefIdSlice},
     pass::AnalysisPass,
-    visit::Visitor,
+    visit::{self, Visitor},
 };
 
 /// Classification of [`DefId`] references in the call graph.
@@ -451,6 +451,11 @@ impl<'heap, A: Allocator> Visitor<'heap> for CallGraphVisitor<'_, 'heap, A> {
                     env,
                 )
             }
+            // Sort keys are ordinary closures, their references are opaque.
+            GraphReadBody::SortBy(..) => visit::r#ref::walk_graph_read_body(self, location, body),
+            &GraphReadBody::Traverse(_) | &GraphReadBody::Limit(_) | &GraphReadBody::Offset(_) => {
+                Ok(())
+            }
         }
     }
 }
@@ -93,7 +93,7 @@ impl<'heap> EdgeKind<'heap> {
         match projection {
             ProjectionKind::Field(index) => self.matches_projection_field_index(index),
             ProjectionKind::FieldByName(name) => self.matches_projection_field_name(name),
-            ProjectionKind::Index(_) => false,
+            ProjectionKind::Index(_) | ProjectionKind::Downcast(_) => false,
         }
     }
 }
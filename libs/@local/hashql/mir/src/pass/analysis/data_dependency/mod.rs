@@ -239,6 +239,8 @@ impl<'heap, A: Allocator> Visitor<'heap> for DataDependencyAnalysisVisitor<'_, '
             | RValue::Unary(_)
             | RValue::Apply(_)
             | RValue::Input(_)
+            | RValue::Intrinsic(_)
+            | RValue::Discriminant(_)
             | RValue::Aggregate(Aggregate {
                 kind: AggregateKind::List | AggregateKind::Dict | AggregateKind::Opaque(_),
                 ..
@@ -0,0 +1,381 @@
+//! Sparse conditional constant propagation for HashQL MIR.
+//!
+//! Constant propagation determines which locals hold a known integer (or boolean) value at each
+//! program point. Unlike a plain constant propagation, the analysis is *conditional*: it tracks
+//! which blocks are reachable at all, and only follows the edges of a [`SwitchInt`] that can be
+//! taken given the value of its discriminant. Constants are therefore found even if they are only
+//! known because a branch that would have changed them can never be executed.
+//!
+//! # Domain
+//!
+//! The state at each program point is a [`ConstantState`], consisting of whether the point is
+//! reachable, and the [`ConstantValue`] of each local:
+//!
+//! ```text
+//!            Varying
+//!        /  /   |   \  \
+//!   ... -1  0   1   2  ...
+//!        \  \   |   /  /
+//!            Unknown
+//! ```
+//!
+//! Unreachable states are the bottom of the lattice, joining two reachable states joins the value
+//! of every local.
+//!
+//! # Transfer Function
+//!
+//! - **Assignments** evaluate the rvalue: loads of constants and of locals with known values, as
+//!   well as unary and binary operations on known values are folded, everything else is
+//!   [`ConstantValue::Varying`]
+//! - **Edges** bind the values of the arguments to the parameters of the target block, graph reads
+//!   bind their result, which is never known
+//! - **Switches** only propagate into the arms that match the value of the discriminant; if the
+//!   discriminant is known, every other arm stays unreachable
+//!
+//! # Example
+//!
+//! ```text
+//! bb0:
+//!     x = 1
+//!     switchInt(x) -> [0: bb1, 1: bb2]
+//! bb1:                       // unreachable
+//!     goto bb3(2)
+//! bb2:
+//!     goto bb3(x)
+//! bb3(y):                    // y = 1, as bb1 never jumps to bb3
+//!     return y
+//! ```
+//!
+//! [`SwitchInt`]: crate::body::terminator::SwitchInt
+
+#[cfg(test)]
+mod tests;
+
+use core::alloc::Allocator;
+
+use hashql_core::id::{Id as _, IdVec};
+
+use super::{
+    framework::{DataflowAnalysis, Direction},
+    lattice::{HasBottom, JoinSemiLattice},
+};
+use crate::{
+    body::{
+        Body,
+        basic_block::BasicBlockId,
+        constant::Constant,
+        local::{Local, LocalVec},
+        location::Location,
+        operand::Operand,
+        rvalue::{BinOp, Binary, RValue, UnOp, Unary},
+        statement::{Assign, Statement, StatementKind},
+        terminator::{SwitchIntValue, SwitchTargets},
+    },
+    interpret::value::Int,
+};
+
+/// Evaluates a binary operation on two constant integers.
+///
+/// Returns `None` if the operation overflows.
+pub(crate) fn eval_bin_op(lhs: Int, op: BinOp, rhs: Int) -> Option<Int> {
+    let result = match op {
+        BinOp::Add => return lhs.checked_add(rhs),
+        BinOp::Sub => return lhs.checked_sub(rhs),
+        // Bitwise ops preserve bool provenance via the Int operators
+        BinOp::BitAnd => return Some(lhs & rhs),
+        BinOp::BitOr => return Some(lhs | rhs),
+        // Comparisons produce booleans
+        BinOp::Eq => lhs == rhs,
+        BinOp::Ne => lhs != rhs,
+        BinOp::Lt => lhs.as_int() < rhs.as_int(),
+        BinOp::Lte => lhs.as_int() <= rhs.as_int(),
+        BinOp::Gt => lhs.as_int() > rhs.as_int(),
+        BinOp::Gte => lhs.as_int() >= rhs.as_int(),
+    };
+
+    Some(Int::from(result))
+}
+
+/// Evaluates a unary operation on a constant integer.
+///
+/// Returns `None` if the operation overflows (only possible for negation of `i128::MIN`).
+pub(crate) fn eval_un_op(op: UnOp, operand: Int) -> Option<Int> {
+    match op {
+        UnOp::BitNot => Some(!operand),
+        UnOp::Neg => operand.checked_neg(),
+    }
+}
+
+/// The value of a local at a program point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ConstantValue {
+    /// The local has not been assigned on any path reaching this point.
+    Unknown,
+    /// The local holds the same integer on every path reaching this point.
+    Int(Int),
+    /// The local may hold different values, or a value that is not an integer.
+    Varying,
+}
+
+impl ConstantValue {
+    #[must_use]
+    pub const fn as_int(self) -> Option<Int> {
+        match self {
+            Self::Int(int) => Some(int),
+            Self::Unknown | Self::Varying => None,
+        }
+    }
+
+    fn join(&mut self, other: Self) -> bool {
+        match (*self, other) {
+            (Self::Varying, _) | (_, Self::Unknown) => false,
+            (Self::Unknown, other) => {
+                *self = other;
+                true
+            }
+            (Self::Int(lhs), Self::Int(rhs)) if lhs == rhs => false,
+            (Self::Int(_), Self::Int(_) | Self::Varying) => {
+                *self = Self::Varying;
+                true
+            }
+        }
+    }
+}
+
+/// The state of constant propagation at a program point.
+#[derive(Debug, Clone)]
+pub struct ConstantState<A: Allocator> {
+    /// Whether the program point can be reached from the entry of the body.
+    ///
+    /// Unreachable states never hold any values.
+    pub reachable: bool,
+    pub values: LocalVec<ConstantValue, A>,
+}
+
+// Implemented manually, as deriving would require the allocator to be comparable.
+impl<A: Allocator> PartialEq for ConstantState<A> {
+    fn eq(&self, other: &Self) -> bool {
+        let Self { reachable, values } = self;
+
+        *reachable == other.reachable && *values == other.values
+    }
+}
+
+impl<A: Allocator> Eq for ConstantState<A> {}
+
+impl<A: Allocator> ConstantState<A> {
+    /// Returns the value of an operand in this state.
+    #[must_use]
+    pub fn eval(&self, operand: &Operand<'_>) -> ConstantValue {
+        match operand {
+            &Operand::Constant(Constant::Int(int)) => ConstantValue::Int(int),
+            Operand::Place(place) if place.projections.is_empty() => self.values[place.local],
+            Operand::Constant(_) | Operand::Place(_) => ConstantValue::Varying,
+        }
+    }
+
+    fn eval_rvalue(&self, rvalue: &RValue<'_>) -> ConstantValue {
+        let value = match rvalue {
+            RValue::Load(operand) => return self.eval(operand),
+            RValue::Binary(Binary { op, left, right }) => {
+                match (self.eval(left), self.eval(right)) {
+                    (ConstantValue::Int(left), ConstantValue::Int(right)) => {
+                        eval_bin_op(left, *op, right)
+                    }
+                    // Wait for both operands to be known before deciding on the result
+                    (ConstantValue::Unknown, _) | (_, ConstantValue::Unknown) => {
+                        return ConstantValue::Unknown;
+                    }
+                    (ConstantValue::Int(_) | ConstantValue::Varying, _) => None,
+                }
+            }
+            RValue::Unary(Unary { op, operand }) => match self.eval(operand) {
+                ConstantValue::Int(operand) => eval_un_op(*op, operand),
+                ConstantValue::Unknown => return ConstantValue::Unknown,
+                ConstantValue::Varying => None,
+            },
+            RValue::Aggregate(_)
+            | RValue::Input(_)
+            | RValue::Intrinsic(_)
+            | RValue::Discriminant(_)
+            | RValue::Apply(_) => None,
+        };
+
+        value.map_or(ConstantValue::Varying, ConstantValue::Int)
+    }
+
+    /// Marks the state as unreachable, discarding all values.
+    fn make_unreachable(&mut self) {
+        self.reachable = false;
+        self.values.as_raw_mut().fill(ConstantValue::Unknown);
+    }
+}
+
+/// The lattice of [`ConstantState`], ordered by reachability and the value of each local.
+pub struct ConstantLattice<A> {
+    locals: usize,
+    alloc: A,
+}
+
+impl<A: Allocator + Clone> HasBottom<ConstantState<A>> for ConstantLattice<A> {
+    fn bottom(&self) -> ConstantState<A> {
+        ConstantState {
+            reachable: false,
+            values: IdVec::from_elem_in(ConstantValue::Unknown, self.locals, self.alloc.clone()),
+        }
+    }
+
+    fn is_bottom(&self, value: &ConstantState<A>) -> bool {
+        !value.reachable
+    }
+}
+
+impl<A: Allocator> JoinSemiLattice<ConstantState<A>> for ConstantLattice<A> {
+    fn join(&self, lhs: &mut ConstantState<A>, rhs: &ConstantState<A>) -> bool {
+        if !rhs.reachable {
+            return false;
+        }
+
+        if !lhs.reachable {
+            lhs.reachable = true;
+            lhs.values.as_raw_mut().copy_from_slice(rhs.values.as_raw());
+            return true;
+        }
+
+        let mut changed = false;
+        for (lhs, &rhs) in lhs.values.iter_mut().zip(rhs.values.iter()) {
+            changed |= lhs.join(rhs);
+        }
+
+        changed
+    }
+}
+
+/// Computes the constant value of every local and the reachability of every block.
+///
+/// A block is reachable if its entry state is [`reachable`](ConstantState::reachable). The
+/// arguments of the body are assumed to be [`ConstantValue::Varying`].
+pub struct ConstantPropagationAnalysis;
+
+impl<'heap> DataflowAnalysis<'heap> for ConstantPropagationAnalysis {
+    type Domain<A: Allocator> = ConstantState<A>;
+    type Lattice<A: Allocator + Clone> = ConstantLattice<A>;
+    type SwitchIntData = Operand<'heap>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn lattice_in<A: Allocator + Clone>(&self, body: &Body<'heap>, alloc: A) -> Self::Lattice<A> {
+        ConstantLattice {
+            locals: body.local_decls.len(),
+            alloc,
+        }
+    }
+
+    fn initialize_boundary<A: Allocator>(
+        &self,
+        body: &Body<'heap>,
+        domain: &mut Self::Domain<A>,
+        _: A,
+    ) {
+        domain.reachable = true;
+
+        for index in 0..body.args {
+            domain.values[Local::from_usize(index)] = ConstantValue::Varying;
+        }
+    }
+
+    fn switch_int_data(
+        &self,
+        _: BasicBlockId,
+        discriminant: &Operand<'heap>,
+    ) -> Option<Self::SwitchIntData> {
+        Some(*discriminant)
+    }
+
+    fn apply_switch_int_edge_effect<A: Allocator>(
+        &self,
+        targets: &SwitchTargets,
+        value: SwitchIntValue,
+        state: &mut Self::Domain<A>,
+        data: &mut Self::SwitchIntData,
+    ) {
+        if !state.reachable {
+            return;
+        }
+
+        // An unknown discriminant is only possible in malformed bodies, be conservative and keep
+        // every arm reachable.
+        let ConstantValue::Int(discriminant) = state.eval(data) else {
+            return;
+        };
+
+        let discriminant = discriminant.as_uint();
+        let taken = match value {
+            SwitchIntValue::Direct(value) => value == discriminant,
+            SwitchIntValue::Otherwise => !targets.values().contains(&discriminant),
+        };
+
+        if !taken {
+            state.make_unreachable();
+        }
+    }
+
+    fn transfer_statement<A: Allocator>(
+        &self,
+        _: Location,
+        statement: &Statement<'heap>,
+        state: &mut Self::Domain<A>,
+    ) {
+        if !state.reachable {
+            return;
+        }
+
+        let StatementKind::Assign(Assign { lhs, rhs }) = &statement.kind else {
+            return;
+        };
+
+        state.values[lhs.local] = if lhs.projections.is_empty() {
+            state.eval_rvalue(rhs)
+        } else {
+            // A partial write changes the value in a way we do not track
+            ConstantValue::Varying
+        };
+    }
+
+    fn transfer_edge<A: Allocator>(
+        &self,
+        _: BasicBlockId,
+        source_args: &[Operand<'heap>],
+        _: BasicBlockId,
+        target_params: &[Local],
+        state: &mut Self::Domain<A>,
+    ) {
+        if !state.reachable {
+            return;
+        }
+
+        // Arguments are bound simultaneously, an argument may refer to a parameter that is
+        // assigned before it (e.g. `goto bb0(b, a)` in a block with the parameters `a` and `b`).
+        let values: Vec<_> = source_args.iter().map(|arg| state.eval(arg)).collect();
+
+        for (&param, value) in target_params.iter().zip(values) {
+            state.values[param] = value;
+        }
+    }
+
+    fn transfer_graph_read_edge<A: Allocator>(
+        &self,
+        _: BasicBlockId,
+        _: BasicBlockId,
+        target_params: &[Local],
+        state: &mut Self::Domain<A>,
+    ) {
+        if !state.reachable {
+            return;
+        }
+
+        for &param in target_params {
+            state.values[param] = ConstantValue::Varying;
+        }
+    }
+}
@@ -0,0 +1,249 @@
+#![expect(clippy::min_ident_chars, reason = "tests")]
+
+use alloc::alloc::Global;
+
+use hashql_core::{
+    heap::Heap,
+    id::{Id as _, IdVec},
+    r#type::environment::Environment,
+};
+
+use super::{ConstantLattice, ConstantPropagationAnalysis, ConstantState, ConstantValue};
+use crate::{
+    body::{Body, basic_block::BasicBlockId, local::Local},
+    builder::body,
+    intern::Interner,
+    interpret::value::Int,
+    pass::analysis::dataflow::{
+        framework::{DataflowAnalysis as _, DataflowResults},
+        lattice::laws::{assert_bounded_join_semilattice, assert_is_bottom_consistent},
+    },
+};
+
+fn state(values: &[ConstantValue]) -> ConstantState<Global> {
+    ConstantState {
+        reachable: true,
+        values: IdVec::from_raw(values.to_vec()),
+    }
+}
+
+fn entry_value(
+    results: &DataflowResults<'_, ConstantPropagationAnalysis>,
+    block: usize,
+    local: usize,
+) -> ConstantValue {
+    results.entry_states[BasicBlockId::from_usize(block)].values[Local::from_usize(local)]
+}
+
+fn is_reachable(results: &DataflowResults<'_, ConstantPropagationAnalysis>, block: usize) -> bool {
+    results.entry_states[BasicBlockId::from_usize(block)].reachable
+}
+
+fn analyze<'heap>(body: &Body<'heap>) -> DataflowResults<'heap, ConstantPropagationAnalysis> {
+    ConstantPropagationAnalysis.iterate_to_fixpoint(body)
+}
+
+#[test]
+fn lattice_laws() {
+    let lattice = ConstantLattice {
+        locals: 3,
+        alloc: Global,
+    };
+
+    assert_is_bottom_consistent::<_, ConstantState<Global>>(&lattice);
+    assert_bounded_join_semilattice(
+        &lattice,
+        state(&[
+            ConstantValue::Int(Int::from(1_i32)),
+            ConstantValue::Unknown,
+            ConstantValue::Varying,
+        ]),
+        state(&[
+            ConstantValue::Int(Int::from(1_i32)),
+            ConstantValue::Int(Int::from(2_i32)),
+            ConstantValue::Unknown,
+        ]),
+        state(&[
+            ConstantValue::Int(Int::from(3_i32)),
+            ConstantValue::Unknown,
+            ConstantValue::Int(Int::from(true)),
+        ]),
+    );
+}
+
+/// Constants are folded through straight-line code: `x = 2; y = x + 3`.
+#[test]
+fn straight_line_folding() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int, y: Int;
+
+        bb0() {
+            x = load 2;
+            y = bin.+ x 3;
+            return y;
+        }
+    });
+
+    let results = analyze(&body);
+    assert_eq!(
+        results.exit_states[BasicBlockId::START].values[Local::from_usize(1)],
+        ConstantValue::Int(Int::from(5_i128))
+    );
+}
+
+/// Arguments of the body are never known.
+#[test]
+fn arguments_are_varying() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl a: Int, x: Int;
+
+        bb0() {
+            x = bin.+ a 1;
+            return x;
+        }
+    });
+
+    let results = analyze(&body);
+    assert_eq!(entry_value(&results, 0, 0), ConstantValue::Varying);
+    assert_eq!(
+        results.exit_states[BasicBlockId::START].values[Local::from_usize(1)],
+        ConstantValue::Varying
+    );
+}
+
+/// The arm of a switch on a known discriminant, that is never taken, is unreachable, and does not
+/// contribute to the parameters of the join block.
+#[test]
+fn known_switch_prunes_arm() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl cond: Bool, result: Int;
+
+        bb0() {
+            cond = load true;
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(1);
+        },
+        bb2() {
+            goto bb3(2);
+        },
+        bb3(result) {
+            return result;
+        }
+    });
+
+    let results = analyze(&body);
+    assert!(is_reachable(&results, 1));
+    assert!(!is_reachable(&results, 2));
+    assert!(is_reachable(&results, 3));
+    assert_eq!(
+        entry_value(&results, 3, 1),
+        ConstantValue::Int(Int::from(1_i128))
+    );
+}
+
+/// A switch on an unknown discriminant keeps both arms reachable, differing parameters vary.
+#[test]
+fn unknown_switch_keeps_arms() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl cond: Bool, result: Int;
+
+        bb0() {
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(1);
+        },
+        bb2() {
+            goto bb3(2);
+        },
+        bb3(result) {
+            return result;
+        }
+    });
+
+    let results = analyze(&body);
+    assert!(is_reachable(&results, 1));
+    assert!(is_reachable(&results, 2));
+    assert_eq!(entry_value(&results, 3, 1), ConstantValue::Varying);
+}
+
+/// Agreeing values on every incoming edge stay constant at the join.
+#[test]
+fn agreeing_parameters_stay_constant() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl cond: Bool, result: Int;
+
+        bb0() {
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(4);
+        },
+        bb2() {
+            goto bb3(4);
+        },
+        bb3(result) {
+            return result;
+        }
+    });
+
+    let results = analyze(&body);
+    assert_eq!(
+        entry_value(&results, 3, 1),
+        ConstantValue::Int(Int::from(4_i128))
+    );
+}
+
+/// A loop whose exit condition is known never reaches the exit, and the loop-carried value stays
+/// constant.
+#[test]
+fn loop_with_constant_condition() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int, cond: Bool;
+
+        bb0() {
+            goto bb1(0);
+        },
+        bb1(x) {
+            cond = bin.== x 0;
+            switch cond [1 => bb1(x), _ => bb2()];
+        },
+        bb2() {
+            return x;
+        }
+    });
+
+    let results = analyze(&body);
+    assert!(is_reachable(&results, 1));
+    assert!(!is_reachable(&results, 2));
+    assert_eq!(
+        entry_value(&results, 1, 0),
+        ConstantValue::Int(Int::from(0_i128))
+    );
+}
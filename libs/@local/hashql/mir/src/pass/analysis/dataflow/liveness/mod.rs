@@ -72,7 +72,7 @@ use crate::{
     },
     pass::execution::{
         VertexType,
-        traversal::{EntityPath, TraversalLattice, TraversalPathBitSet},
+        traversal::{EntityPath, OntologyPath, TraversalLattice, TraversalPathBitSet},
     },
     visit::{self, Visitor},
 };
@@ -81,7 +81,8 @@ use crate::{
 ///
 /// The domain is `(DenseBitSet<Local>, TraversalPathBitSet)`:
 /// - The local bitset tracks which locals are live, with the vertex local excluded entirely.
-/// - The path bitset tracks which vertex field paths are live (resolved via [`EntityPath`]).
+/// - The path bitset tracks which vertex field paths are live (resolved via [`EntityPath`], or
+///   [`OntologyPath`] for the types of the ontology).
 ///
 /// When the vertex is accessed through a resolvable projection (e.g., `_1.metadata.archived`),
 /// the corresponding [`EntityPath`] is gen'd in the path bitset. When the projection cannot be
@@ -186,6 +187,15 @@ impl Visitor<'_> for TraversalTransferFunction<'_> {
                         bitset.insert_all();
                     }
                 }
+                TraversalPathBitSet::DataType(bitset)
+                | TraversalPathBitSet::PropertyType(bitset)
+                | TraversalPathBitSet::EntityType(bitset) => {
+                    if let Some((path, _)) = OntologyPath::resolve(&place.projections) {
+                        bitset.insert(path);
+                    } else {
+                        bitset.insert_all();
+                    }
+                }
             }
         }
 
@@ -1,5 +1,12 @@
+mod constant_propagation;
 pub mod framework;
 pub mod lattice;
 mod liveness;
 
-pub use self::liveness::{LivenessAnalysis, TraversalLivenessAnalysis};
+pub(crate) use self::constant_propagation::{eval_bin_op, eval_un_op};
+pub use self::{
+    constant_propagation::{
+        ConstantLattice, ConstantPropagationAnalysis, ConstantState, ConstantValue,
+    },
+    liveness::{LivenessAnalysis, TraversalLivenessAnalysis},
+};
@@ -14,7 +14,7 @@ use hashql_core::{
     id::{Id as _, bit_vec::DenseBitSet},
     r#type::environment::Environment,
 };
-use hashql_hir::node::operation::InputOp;
+use hashql_hir::node::operation::{DictOp, InputOp, IntrinsicOp, ListOp, StringOp};
 
 use super::{
     Cardinal, InformationRange,
@@ -31,7 +31,8 @@ use crate::{
         operand::Operand,
         place::{FieldIndex, Place, Projection, ProjectionKind},
         rvalue::{
-            Aggregate, AggregateKind, Apply, ArgSlice, BinOp, Binary, Input, RValue, UnOp, Unary,
+            Aggregate, AggregateKind, Apply, ArgIndex, ArgSlice, BinOp, Binary, Input, Intrinsic,
+            RValue, UnOp, Unary,
         },
         statement::{Assign, Statement, StatementKind},
     },
@@ -330,6 +331,54 @@ impl<'ctx, 'footprints, 'env, 'heap, A: Allocator, C: Allocator>
         }
     }
 
+    /// Evaluates an intrinsic operation to determine its footprint.
+    fn eval_rvalue_intrinsic<B: Allocator>(
+        &self,
+        domain: &BodyFootprint<B>,
+        Intrinsic { op, arguments }: &Intrinsic<'heap>,
+    ) -> Eval {
+        #[expect(clippy::match_same_arms, reason = "explicit case handling for clarity")]
+        match op {
+            IntrinsicOp::String(
+                StringOp::Contains | StringOp::StartsWith | StringOp::EndsWith | StringOp::Length,
+            ) => Eval::Footprint(Footprint::scalar()),
+            // Changing the case or trimming a string does not (substantially) change its size
+            IntrinsicOp::String(StringOp::Lowercase | StringOp::Uppercase | StringOp::Trim) => {
+                self.lookup.operand(domain, &arguments[ArgIndex::new(0)])
+            }
+            IntrinsicOp::String(StringOp::Concat) => {
+                let mut units: Estimate<InformationRange> = SaturatingSemiring.zero();
+
+                for operand in arguments {
+                    let eval = self.lookup.operand(domain, operand);
+                    let materialized = eval.into_footprint(domain).materialize();
+
+                    SaturatingSemiring.plus(&mut units, &materialized);
+                }
+
+                Eval::Footprint(Footprint::one(units))
+            }
+            IntrinsicOp::List(ListOp::Len | ListOp::Contains)
+            | IntrinsicOp::Dict(DictOp::Len | DictOp::Contains) => {
+                Eval::Footprint(Footprint::scalar())
+            }
+            // Sorting only reorders the elements
+            IntrinsicOp::List(ListOp::Sort) => {
+                self.lookup.operand(domain, &arguments[ArgIndex::new(0)])
+            }
+            // The number of parts depends on the contents of the string
+            IntrinsicOp::String(StringOp::Split) => Eval::Footprint(Footprint::unknown()),
+            // The size of the result depends on the contents of the collection, or on the result of
+            // the closure
+            IntrinsicOp::List(
+                ListOp::Get | ListOp::Concat | ListOp::Map | ListOp::Filter | ListOp::Fold,
+            )
+            | IntrinsicOp::Dict(
+                DictOp::Get | DictOp::Keys | DictOp::Values | DictOp::Insert | DictOp::Concat,
+            ) => Eval::Footprint(Footprint::unknown()),
+        }
+    }
+
     /// Evaluates an rvalue to determine its footprint.
     fn eval_rvalue<B: Allocator>(&self, domain: &BodyFootprint<B>, rvalue: &RValue<'heap>) -> Eval {
         #[expect(clippy::match_same_arms, reason = "explicit case handling for clarity")]
@@ -366,6 +415,8 @@ impl<'ctx, 'footprints, 'env, 'heap, A: Allocator, C: Allocator>
                 // External inputs have unknown size at compile time
                 Eval::Footprint(Footprint::unknown())
             }
+            RValue::Intrinsic(intrinsic) => self.eval_rvalue_intrinsic(domain, intrinsic),
+            RValue::Discriminant(_) => Eval::Footprint(Footprint::scalar()),
             RValue::Apply(Apply {
                 function,
                 arguments,
@@ -115,6 +115,9 @@ impl<'heap> VisitorMut<'heap> for RemapBasicBlockId<'_> {
 
 /// Fuses adjacent same-target blocks in-place, compacting both `body` and `targets`.
 ///
+/// Returns the block each original block has been fused into, indexed by the original
+/// [`BasicBlockId`].
+///
 /// The algorithm has three phases:
 ///
 /// 1. **Head resolution** — Walk blocks in reverse postorder. For each fusable block, record which
@@ -131,7 +134,7 @@ fn fuse_blocks<A: Allocator, S: Allocator + Clone>(
     scratch: S,
     body: &mut Body<'_>,
     targets: &mut BasicBlockVec<TargetId, A>,
-) {
+) -> BasicBlockVec<BasicBlockId, S> {
     let reverse_postorder = body
         .basic_blocks
         .reverse_postorder()
@@ -216,6 +219,14 @@ fn fuse_blocks<A: Allocator, S: Allocator + Clone>(
 
     body.basic_blocks.as_mut().truncate(new_len);
     targets.truncate(new_len);
+
+    // Resolve fused blocks to the new ID of their head. Heads already map to themselves, so the
+    // remap table can be updated in place.
+    for block_id in head.ids() {
+        remap[block_id] = remap[head[block_id]];
+    }
+
+    remap
 }
 
 /// Fuses adjacent MIR [`BasicBlock`]s that share the same execution target.
@@ -249,17 +260,20 @@ impl<S: Allocator> BasicBlockFusion<S> {
     ///
     /// Modifies both `body` and `targets` in place. The `targets` vec is compacted to match
     /// the new block layout.
+    ///
+    /// Returns the block each original block has been fused into, so that per-block data
+    /// computed before fusion can be carried over to the new layout.
     pub(crate) fn fuse<A: Allocator>(
         &self,
         body: &mut Body<'_>,
         targets: &mut BasicBlockVec<TargetId, A>,
-    ) {
+    ) -> BasicBlockVec<BasicBlockId, &S> {
         debug_assert_eq!(
             body.basic_blocks.len(),
             targets.len(),
             "target vec length must match basic block count"
         );
 
-        fuse_blocks(&self.scratch, body, targets);
+        fuse_blocks(&self.scratch, body, targets)
     }
 }
@@ -708,3 +708,42 @@ fn fuse_backward_chain() {
 
     assert_fusion("fuse_backward_chain", &context, &body, &targets);
 }
+
+#[test]
+fn fuse_maps_blocks_to_fused_block() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let mut body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int, y: Int, z: Int;
+
+        bb0() {
+            x = load 1;
+            goto bb1();
+        },
+        bb1() {
+            y = load 2;
+            goto bb2();
+        },
+        bb2() {
+            z = load 3;
+            return z;
+        }
+    });
+
+    let mut targets = make_targets(&[
+        TargetId::Interpreter,
+        TargetId::Interpreter,
+        TargetId::Postgres,
+    ]);
+
+    let fusion = BasicBlockFusion::new();
+    let fused = fusion.fuse(&mut body, &mut targets);
+
+    // bb1 is fused into bb0, bb2 survives as the second block
+    assert_eq!(fused.len(), 3);
+    assert_eq!(fused[BasicBlockId::new(0)], BasicBlockId::new(0));
+    assert_eq!(fused[BasicBlockId::new(1)], BasicBlockId::new(0));
+    assert_eq!(fused[BasicBlockId::new(2)], BasicBlockId::new(1));
+}
@@ -54,6 +54,10 @@ use crate::{
 
 pub struct ExecutionAnalysisResidual<A: Allocator> {
     pub assignment: BasicBlockVec<TargetId, A>,
+    /// The estimated cost of every block on its assigned target.
+    ///
+    /// Blocks which have been fused together report the sum of their costs.
+    pub costs: BasicBlockVec<ApproxCost, A>,
     pub islands: IslandGraph<A>,
 }
 
@@ -61,6 +65,7 @@ impl<A: Allocator> core::fmt::Debug for ExecutionAnalysisResidual<A> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("ExecutionAnalysisResidual")
             .field("assignment", &self.assignment)
+            .field("costs", &self.costs)
             .field("islands", &self.islands)
             .finish()
     }
@@ -149,7 +154,13 @@ impl<'heap, S: BumpAllocator> ExecutionAnalysis<'_, 'heap, S> {
         let mut assignment = solver.run_in(context, body, alloc.clone());
 
         let fusion = BasicBlockFusion::new_in(&self.scratch);
-        fusion.fuse(body, &mut assignment);
+        let fused = fusion.fuse(body, &mut assignment);
+
+        let mut costs =
+            BasicBlockVec::from_elem_in(ApproxCost::ZERO, body.basic_blocks.len(), alloc.clone());
+        for (block, &head) in fused.iter_enumerated() {
+            costs[head] += block_costs.cost(block, assignment[head]);
+        }
 
         let islands =
             IslandPlacement::new_in(&self.scratch).run_in(body, vertex, &assignment, &self.scratch);
@@ -157,6 +168,7 @@ impl<'heap, S: BumpAllocator> ExecutionAnalysis<'_, 'heap, S> {
 
         ExecutionAnalysisResidual {
             assignment,
+            costs,
             islands,
         }
     }
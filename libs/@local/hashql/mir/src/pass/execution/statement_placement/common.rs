@@ -30,7 +30,7 @@ use crate::{
         execution::{
             Cost,
             cost::{StatementCostVec, TerminatorCostVec},
-            traversal::{Access, EntityPath},
+            traversal::{Access, EntityPath, OntologyPath},
         },
     },
     visit::Visitor,
@@ -366,3 +366,11 @@ where
 pub(crate) fn entity_projection_access(projections: &[Projection<'_>]) -> Option<Access> {
     EntityPath::resolve(projections).map(|(path, _)| path.access())
 }
+
+/// Determines which backend can access a projection of a data type, property type or entity type.
+///
+/// Every field of an ontology type is stored in Postgres, so this only returns `None` if the path
+/// doesn't map to any field.
+pub(crate) fn ontology_projection_access(projections: &[Projection<'_>]) -> Option<Access> {
+    OntologyPath::resolve(projections).map(|(path, _)| path.access())
+}
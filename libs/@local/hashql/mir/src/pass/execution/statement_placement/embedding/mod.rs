@@ -49,6 +49,10 @@ impl EmbeddingSupported {
                         Some(Access::Embedding(_))
                     );
                 }
+                // Types of the ontology have no embeddings
+                VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                    return false;
+                }
             }
         }
 
@@ -70,7 +74,9 @@ impl<'heap> Supported<'heap> for EmbeddingSupported {
             | RValue::Aggregate(_)
             | RValue::Binary(_)
             | RValue::Unary(_)
-            | RValue::Apply(_) => false,
+            | RValue::Apply(_)
+            | RValue::Intrinsic(_)
+            | RValue::Discriminant(_) => false,
         }
     }
 
@@ -136,6 +136,45 @@ fn non_vectors_entity_projection_rejected() {
     );
 }
 
+/// Projections of ontology types are rejected.
+///
+/// Tests that accessing fields of a data type, property type or entity type returns no cost for
+/// embedding - types of the ontology have no embeddings.
+#[test]
+fn ontology_projection_rejected() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::EntityType; ?], schema: ?;
+        @proj schema_proj = vertex.schema: ?;
+
+        bb0() {
+            schema = load schema_proj;
+            return schema;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = EmbeddingStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "ontology_projection_rejected",
+        "embedding",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
 /// `StorageLive`/`StorageDead` statements get `cost!(0)`.
 ///
 /// Tests that storage management statements have zero cost even for Embedding,
@@ -12,6 +12,7 @@ use hashql_core::{
         visit::{RecursiveVisitorGuard, Visitor as _},
     },
 };
+use hashql_hir::node::operation::IntrinsicOp;
 
 use super::{
     StatementPlacement,
@@ -24,14 +25,14 @@ use crate::{
         local::{Local, LocalDecl, LocalSlice},
         operand::Operand,
         place::{FieldIndex, Place, ProjectionKind},
-        rvalue::{Aggregate, AggregateKind, BinOp, Binary, RValue, Unary},
+        rvalue::{Aggregate, AggregateKind, BinOp, Binary, Intrinsic, RValue, Unary},
         terminator::{Goto, Return, SwitchInt, Terminator, TerminatorKind},
     },
     context::MirContext,
     pass::execution::{
         VertexType,
         cost::{Cost, StatementCostVec, TerminatorCostVec},
-        statement_placement::common::entity_projection_access,
+        statement_placement::common::{entity_projection_access, ontology_projection_access},
         traversal::Access,
     },
     visit::Visitor as _,
@@ -47,6 +48,16 @@ const fn is_supported_constant(constant: &Constant<'_>) -> bool {
     }
 }
 
+const fn is_supported_intrinsic(op: IntrinsicOp) -> bool {
+    match op {
+        // All string operations have Postgres equivalents
+        IntrinsicOp::String(_) => true,
+        // Collections are represented as JSONB, which has no equivalent to the higher-order
+        // operations, the remaining operations are evaluated by the interpreter for consistency
+        IntrinsicOp::List(_) | IntrinsicOp::Dict(_) => false,
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 struct Peel {
     opaque: bool,
@@ -370,6 +381,12 @@ impl<'heap, A: Allocator> PostgresSupported<'_, 'heap, A> {
                     entity_projection_access(&place.projections),
                     Some(Access::Postgres(_))
                 )),
+                VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                    Some(matches!(
+                        ontology_projection_access(&place.projections),
+                        Some(Access::Postgres(_))
+                    ))
+                }
             },
             _ => None,
         }
@@ -440,10 +457,17 @@ impl<'heap, A: Allocator> Supported<'heap> for PostgresSupported<'_, 'heap, A> {
                     .iter()
                     .all(|operand| self.is_supported_operand(context, body, domain, operand))
             }
+            RValue::Intrinsic(Intrinsic { op, arguments }) => {
+                is_supported_intrinsic(*op)
+                    && arguments
+                        .iter()
+                        .all(|operand| self.is_supported_operand(context, body, domain, operand))
+            }
             // Query parameters are passed to Postgres
             RValue::Input(_) => true,
-            // Function calls cannot be pushed to Postgres
-            RValue::Apply(_) => false,
+            // Function calls cannot be pushed to Postgres, neither can discriminant reads, as
+            // opaque types are erased in the database representation
+            RValue::Apply(_) | RValue::Discriminant(_) => false,
         }
     }
 
@@ -106,6 +106,160 @@ fn binary_unary_ops_supported() {
     );
 }
 
+/// `str.length` works (lowered to `char_length`).
+///
+/// Tests that the intrinsic is supported when its operand is dispatchable.
+#[test]
+fn string_length_supported() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> Bool {
+        decl env: (), vertex: [Opaque sym::path::Entity; ?], len: Int, cond: Bool;
+
+        bb0() {
+            len = str.length "grüße";
+            cond = bin.== len 5;
+            return cond;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "string_length_supported",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// `str.starts_with` and `str.ends_with` work.
+///
+/// `ends_with` has no native Postgres function and is lowered to `starts_with` over the reversed
+/// operands, both intrinsics must therefore be placeable.
+#[test]
+fn string_prefix_suffix_supported() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> Bool {
+        decl env: (), vertex: [Opaque sym::path::Entity; ?], starts: Bool, ends: Bool, cond: Bool;
+
+        bb0() {
+            starts = str.starts_with "haystack", "hay";
+            ends = str.ends_with "haystack", "stack";
+            cond = bin.& starts ends;
+            return cond;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "string_prefix_suffix_supported",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// `str.contains` works (lowered to `strpos(..) > 0`).
+///
+/// Tests that the intrinsic is supported when both operands are dispatchable.
+#[test]
+fn string_contains_supported() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> Bool {
+        decl env: (), vertex: [Opaque sym::path::Entity; ?], contains: Bool;
+
+        bb0() {
+            contains = str.contains "haystack", "st";
+            return contains;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "string_contains_supported",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// `str.split` works (lowered to `string_to_array`, converted to a JSONB array).
+///
+/// Tests that the resulting list can be consumed by further supported operations.
+#[test]
+fn string_split_supported() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> Bool {
+        decl env: (), vertex: [Opaque sym::path::Entity; ?], parts: [List String], other: [List String], cond: Bool;
+
+        bb0() {
+            parts = str.split "a,b", ",";
+            other = str.split "a;b", ";";
+            cond = bin.== parts other;
+            return cond;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "string_split_supported",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
 /// Tuple and struct aggregates work (constructed as JSONB in Postgres).
 ///
 /// Tests that `Aggregate` `RValue`s with `Tuple` and `Struct` kinds are supported.
@@ -434,6 +588,162 @@ fn entity_projection_jsonb() {
     );
 }
 
+/// Data type field projections mapping to Postgres columns are supported.
+///
+/// Tests that projecting `data_type.id.base_url` returns a cost since `base_url` maps to a
+/// direct Postgres column in `ontology_ids`.
+#[test]
+fn data_type_projection_column() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::DataType; ?], base_url: ?;
+        @proj id = vertex.id: ?, base_url_proj = id.base_url: ?;
+
+        bb0() {
+            base_url = load base_url_proj;
+            return base_url;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "data_type_projection_column",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// Property type schema projections mapping to JSONB paths are supported.
+///
+/// Tests that projecting into `property_type.schema` returns a cost since `schema` maps to a
+/// JSONB column in `property_types`.
+#[test]
+fn property_type_projection_jsonb() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::PropertyType; ?], title: ?;
+        @proj schema = vertex.schema: ?, title_proj = schema.title: ?;
+
+        bb0() {
+            title = load title_proj;
+            return title;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "property_type_projection_jsonb",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// Entity type web ID projections are supported.
+///
+/// Tests that projecting `entity_type.metadata.web_id` returns a cost since `web_id` maps to a
+/// Postgres column in `ontology_owned_metadata`.
+#[test]
+fn entity_type_projection_web_id() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::EntityType; ?], web_id: ?;
+        @proj metadata = vertex.metadata: ?, web_id_proj = metadata.web_id: ?;
+
+        bb0() {
+            web_id = load web_id_proj;
+            return web_id;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "entity_type_projection_web_id",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
+/// Ontology projections not mapping to any storage location are rejected.
+///
+/// Tests that loading `data_type.metadata` as a whole returns no cost, as only its fields are
+/// stored in Postgres.
+#[test]
+fn ontology_unresolved_projection_rejected() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::DataType; ?], meta: ?;
+        @proj metadata = vertex.metadata: ?;
+
+        bb0() {
+            meta = load metadata;
+            return meta;
+        }
+    });
+
+    let context = MirContext {
+        heap: &heap,
+        env: &env,
+        interner: &interner,
+        diagnostics: DiagnosticIssues::new(),
+    };
+
+    let mut placement = PostgresStatementPlacement::new_in(Global);
+    let (body, statement_costs) = run_placement(&context, &mut placement, body);
+
+    assert_placement(
+        "ontology_unresolved_projection_rejected",
+        "postgres",
+        &body,
+        &context,
+        &statement_costs,
+    );
+}
+
 /// `StorageLive`/`StorageDead` statements get `cost!(0)`.
 ///
 /// Tests that storage management statements have zero cost even for Postgres,
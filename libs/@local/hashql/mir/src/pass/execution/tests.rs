@@ -206,6 +206,7 @@ fn run_execution<'heap>(
     let heap = context.heap;
     let ExecutionAnalysisResidual {
         assignment,
+        costs: _,
         islands,
     } = analysis.run_in(context, body, heap);
 
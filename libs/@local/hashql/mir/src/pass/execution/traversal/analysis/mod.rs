@@ -8,7 +8,10 @@ use crate::{
         location::Location,
         place::{DefUse, Place, PlaceContext},
     },
-    pass::execution::{VertexType, traversal::EntityPath},
+    pass::execution::{
+        VertexType,
+        traversal::{EntityPath, OntologyPath},
+    },
     visit::{self, Visitor},
 };
 
@@ -17,14 +20,15 @@ use crate::{
 pub(crate) enum TraversalResult {
     /// The access resolved to a specific storage location.
     Path(TraversalPath),
-    /// The access could not be resolved; full entity hydration is required.
+    /// The access could not be resolved; full vertex hydration is required.
     Complete,
 }
 
 /// MIR visitor that resolves vertex field accesses to [`TraversalResult`]s.
 ///
 /// Walks a body's places, finds uses of [`Local::VERTEX`], resolves the projection chain
-/// via [`EntityPath::resolve`], and calls `on_traversal` with the [`Location`] and result.
+/// via [`EntityPath::resolve`] (or [`OntologyPath::resolve`] for ontology types), and calls
+/// `on_traversal` with the [`Location`] and result.
 pub(crate) struct TraversalAnalysisVisitor<F> {
     vertex: VertexType,
     on_traversal: F,
@@ -82,6 +86,16 @@ where
                     (self.on_traversal)(location, TraversalResult::Complete);
                 }
             }
+            VertexType::DataType | VertexType::PropertyType | VertexType::EntityType => {
+                if let Some((path, _)) = OntologyPath::resolve(&place.projections) {
+                    (self.on_traversal)(
+                        location,
+                        TraversalResult::Path(TraversalPath::ontology(self.vertex, path)),
+                    );
+                } else {
+                    (self.on_traversal)(location, TraversalResult::Complete);
+                }
+            }
         }
 
         visit::r#ref::walk_place(self, location, context, place)
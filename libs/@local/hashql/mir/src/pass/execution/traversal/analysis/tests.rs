@@ -338,6 +338,44 @@ fn paths_across_blocks() {
     assert!(bb2_term.is_empty());
 }
 
+/// `TraversalPathBitSet::from_body` joins the paths of every block of the body.
+#[test]
+fn from_body_joins_blocks() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; [graph::read::filter]@0/2 -> ? {
+        decl env: (), vertex: [Opaque sym::path::Entity; ?],
+             props: ?, val: Bool, cond: Bool;
+        @proj properties = vertex.properties: ?,
+              metadata = vertex.metadata: ?,
+              archived = metadata.archived: Bool;
+
+        bb0() {
+            props = load properties;
+            cond = load true;
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            val = load archived;
+            return val;
+        },
+        bb2() {
+            return cond;
+        }
+    });
+
+    let traversals = TraversalPathBitSet::from_body(VertexType::Entity, &body);
+    let traversals = traversals
+        .as_entity()
+        .expect("should be an entity path bitset");
+
+    assert_eq!(traversals.len(), 2);
+    assert!(traversals.contains(EntityPath::Properties));
+    assert!(traversals.contains(EntityPath::Archived));
+}
+
 /// Each statement records paths independently; no cross-statement interaction.
 ///
 /// A statement loading `_1.metadata.record_id` followed by one loading
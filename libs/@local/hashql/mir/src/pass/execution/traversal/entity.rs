@@ -787,7 +787,7 @@ const impl core::ops::Deref for EntityPathBitSet {
 /// Returns `None` if the projection is not a [`FieldByName`](ProjectionKind::FieldByName)
 /// or if `*index` is out of bounds.
 #[inline]
-fn project(projections: &[Projection<'_>], index: &mut usize) -> Option<ConstantSymbol> {
+pub(super) fn project(projections: &[Projection<'_>], index: &mut usize) -> Option<ConstantSymbol> {
     let projection = projections.get(*index).and_then(|projection| {
         if let ProjectionKind::FieldByName(name) = projection.kind {
             name.as_constant()
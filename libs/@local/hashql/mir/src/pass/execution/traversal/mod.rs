@@ -1,8 +1,9 @@
 //! Traversal path resolution, storage mapping, and transfer cost estimation.
 //!
 //! Maps property access projections on graph vertices to their backend storage locations.
-//! Each vertex type has its own path enum ([`EntityPath`] for entities) that resolves
-//! dot-notation field accesses to specific columns, JSONB paths, or embedding stores.
+//! Each vertex type has its own path enum ([`EntityPath`] for entities, [`OntologyPath`] for
+//! data types, property types and entity types) that resolves dot-notation field accesses to
+//! specific columns, JSONB paths, or embedding stores.
 //!
 //! Each path carries its origin backend (which execution targets serve it natively) and an
 //! estimated transfer size used by the cost analysis to charge a transfer premium on targets
@@ -13,6 +14,7 @@
 
 mod access;
 mod entity;
+mod ontology;
 
 mod analysis;
 #[cfg(test)]
@@ -25,12 +27,19 @@ use hashql_core::{
     r#type::{TypeId, environment::Environment},
 };
 
-pub use self::entity::{EntityPath, EntityPathBitSet};
 pub(crate) use self::{access::Access, entity::TransferCostConfig};
+pub use self::{
+    entity::{EntityPath, EntityPathBitSet},
+    ontology::{OntologyPath, OntologyPathBitSet},
+};
 use super::{VertexType, target::TargetBitSet};
-use crate::pass::analysis::{
-    dataflow::lattice::{HasBottom, HasTop, JoinSemiLattice},
-    size_estimation::InformationRange,
+use crate::{
+    body::{Body, terminator::EitherIter},
+    pass::analysis::{
+        dataflow::lattice::{HasBottom, HasTop, JoinSemiLattice},
+        size_estimation::InformationRange,
+    },
+    visit::Visitor as _,
 };
 
 /// Lattice structure for traversal path bitsets.
@@ -69,27 +78,50 @@ impl TraversalLattice {
 pub enum TraversalPathBitSet {
     /// Paths into the entity schema.
     Entity(EntityPathBitSet),
+    /// Paths into the data type schema.
+    DataType(OntologyPathBitSet),
+    /// Paths into the property type schema.
+    PropertyType(OntologyPathBitSet),
+    /// Paths into the entity type schema.
+    EntityType(OntologyPathBitSet),
 }
 
-#[expect(
-    clippy::unnecessary_wraps,
-    reason = "currently only entities are supported, this will change in the future"
-)]
 impl TraversalPathBitSet {
     /// Creates an empty bitset for the given vertex type.
     #[must_use]
     pub const fn empty(vertex: VertexType) -> Self {
         match vertex {
             VertexType::Entity => Self::Entity(EntityPathBitSet::new_empty()),
+            VertexType::DataType => Self::DataType(OntologyPathBitSet::new_empty()),
+            VertexType::PropertyType => Self::PropertyType(OntologyPathBitSet::new_empty()),
+            VertexType::EntityType => Self::EntityType(OntologyPathBitSet::new_empty()),
         }
     }
 
+    /// Collects the paths of the vertex which are read anywhere in `body`.
+    ///
+    /// Accesses which cannot be resolved to a path require the complete vertex, in which case
+    /// all paths are inserted.
+    #[must_use]
+    pub fn from_body(vertex: VertexType, body: &Body<'_>) -> Self {
+        let mut traversals = Self::empty(vertex);
+
+        let mut visitor = TraversalAnalysisVisitor::new(vertex, |_, result| match result {
+            TraversalResult::Path(path) => traversals.insert(path),
+            TraversalResult::Complete => traversals.insert_all(),
+        });
+        let Ok(()) = visitor.visit_body(body);
+
+        traversals
+    }
+
     /// Returns the inner [`EntityPathBitSet`] if this is the [`Entity`](Self::Entity) variant.
     #[inline]
     #[must_use]
     pub const fn as_entity(&self) -> Option<&EntityPathBitSet> {
         match self {
             Self::Entity(bitset) => Some(bitset),
+            Self::DataType(_) | Self::PropertyType(_) | Self::EntityType(_) => None,
         }
     }
 
@@ -100,6 +132,19 @@ impl TraversalPathBitSet {
     pub const fn as_entity_mut(&mut self) -> Option<&mut EntityPathBitSet> {
         match self {
             Self::Entity(bitset) => Some(bitset),
+            Self::DataType(_) | Self::PropertyType(_) | Self::EntityType(_) => None,
+        }
+    }
+
+    /// Returns the inner [`OntologyPathBitSet`] if this is the bitset of an ontology type.
+    #[inline]
+    #[must_use]
+    pub const fn as_ontology(&self) -> Option<&OntologyPathBitSet> {
+        match self {
+            Self::Entity(_) => None,
+            Self::DataType(bitset) | Self::PropertyType(bitset) | Self::EntityType(bitset) => {
+                Some(bitset)
+            }
         }
     }
 
@@ -109,6 +154,9 @@ impl TraversalPathBitSet {
     pub const fn is_empty(self) -> bool {
         match self {
             Self::Entity(bitset) => bitset.is_empty(),
+            Self::DataType(bitset) | Self::PropertyType(bitset) | Self::EntityType(bitset) => {
+                bitset.is_empty()
+            }
         }
     }
 
@@ -118,6 +166,9 @@ impl TraversalPathBitSet {
     pub fn len(self) -> usize {
         match self {
             Self::Entity(bitset) => bitset.len(),
+            Self::DataType(bitset) | Self::PropertyType(bitset) | Self::EntityType(bitset) => {
+                bitset.len()
+            }
         }
     }
 
@@ -128,6 +179,14 @@ impl TraversalPathBitSet {
     pub fn insert(&mut self, path: TraversalPath) {
         match (self, path) {
             (Self::Entity(bitset), TraversalPath::Entity(path)) => bitset.insert(path),
+            (Self::DataType(bitset), TraversalPath::DataType(path))
+            | (Self::PropertyType(bitset), TraversalPath::PropertyType(path))
+            | (Self::EntityType(bitset), TraversalPath::EntityType(path)) => bitset.insert(path),
+            (this, path) => unreachable!(
+                "cannot insert a path of {:?} into a bitset of {:?}",
+                path.vertex(),
+                this.vertex()
+            ),
         }
     }
 
@@ -136,6 +195,10 @@ impl TraversalPathBitSet {
     pub const fn contains(self, path: TraversalPath) -> bool {
         match (self, path) {
             (Self::Entity(bitset), TraversalPath::Entity(path)) => bitset.contains(path),
+            (Self::DataType(bitset), TraversalPath::DataType(path))
+            | (Self::PropertyType(bitset), TraversalPath::PropertyType(path))
+            | (Self::EntityType(bitset), TraversalPath::EntityType(path)) => bitset.contains(path),
+            _ => false,
         }
     }
 
@@ -144,6 +207,9 @@ impl TraversalPathBitSet {
     pub const fn insert_all(&mut self) {
         match self {
             Self::Entity(bitset) => bitset.insert_all(),
+            Self::DataType(bitset) | Self::PropertyType(bitset) | Self::EntityType(bitset) => {
+                bitset.insert_all();
+            }
         }
     }
 
@@ -159,6 +225,9 @@ impl TraversalPathBitSet {
     pub const fn vertex(self) -> VertexType {
         match self {
             Self::Entity(_) => VertexType::Entity,
+            Self::DataType(_) => VertexType::DataType,
+            Self::PropertyType(_) => VertexType::PropertyType,
+            Self::EntityType(_) => VertexType::EntityType,
         }
     }
 }
@@ -170,7 +239,20 @@ impl IntoIterator for &TraversalPathBitSet {
 
     fn into_iter(self) -> Self::IntoIter {
         match self {
-            TraversalPathBitSet::Entity(bitset) => bitset.into_iter().map(TraversalPath::Entity),
+            TraversalPathBitSet::Entity(bitset) => {
+                EitherIter::Left(bitset.into_iter().map(TraversalPath::Entity))
+            }
+            TraversalPathBitSet::DataType(bitset)
+            | TraversalPathBitSet::PropertyType(bitset)
+            | TraversalPathBitSet::EntityType(bitset) => {
+                let vertex = self.vertex();
+
+                EitherIter::Right(
+                    bitset
+                        .into_iter()
+                        .map(move |path| TraversalPath::ontology(vertex, path)),
+                )
+            }
         }
     }
 }
@@ -179,12 +261,18 @@ impl HasBottom<TraversalPathBitSet> for TraversalLattice {
     fn bottom(&self) -> TraversalPathBitSet {
         match self.vertex {
             VertexType::Entity => TraversalPathBitSet::Entity(self.bottom()),
+            VertexType::DataType => TraversalPathBitSet::DataType(self.bottom()),
+            VertexType::PropertyType => TraversalPathBitSet::PropertyType(self.bottom()),
+            VertexType::EntityType => TraversalPathBitSet::EntityType(self.bottom()),
         }
     }
 
     fn is_bottom(&self, value: &TraversalPathBitSet) -> bool {
         match value {
             TraversalPathBitSet::Entity(bitset) => self.is_bottom(bitset),
+            TraversalPathBitSet::DataType(bitset)
+            | TraversalPathBitSet::PropertyType(bitset)
+            | TraversalPathBitSet::EntityType(bitset) => self.is_bottom(bitset),
         }
     }
 }
@@ -193,12 +281,18 @@ impl HasTop<TraversalPathBitSet> for TraversalLattice {
     fn top(&self) -> TraversalPathBitSet {
         match self.vertex {
             VertexType::Entity => TraversalPathBitSet::Entity(self.top()),
+            VertexType::DataType => TraversalPathBitSet::DataType(self.top()),
+            VertexType::PropertyType => TraversalPathBitSet::PropertyType(self.top()),
+            VertexType::EntityType => TraversalPathBitSet::EntityType(self.top()),
         }
     }
 
     fn is_top(&self, value: &TraversalPathBitSet) -> bool {
         match value {
             TraversalPathBitSet::Entity(bitset) => self.is_top(bitset),
+            TraversalPathBitSet::DataType(bitset)
+            | TraversalPathBitSet::PropertyType(bitset)
+            | TraversalPathBitSet::EntityType(bitset) => self.is_top(bitset),
         }
     }
 }
@@ -209,6 +303,16 @@ impl JoinSemiLattice<TraversalPathBitSet> for TraversalLattice {
             (TraversalPathBitSet::Entity(lhs), TraversalPathBitSet::Entity(rhs)) => {
                 self.join(lhs, rhs)
             }
+            (TraversalPathBitSet::DataType(lhs), TraversalPathBitSet::DataType(rhs))
+            | (TraversalPathBitSet::PropertyType(lhs), TraversalPathBitSet::PropertyType(rhs))
+            | (TraversalPathBitSet::EntityType(lhs), TraversalPathBitSet::EntityType(rhs)) => {
+                self.join(lhs, rhs)
+            }
+            (lhs, rhs) => unreachable!(
+                "cannot join bitsets of {:?} and {:?}",
+                lhs.vertex(),
+                rhs.vertex()
+            ),
         }
     }
 
@@ -221,6 +325,20 @@ impl JoinSemiLattice<TraversalPathBitSet> for TraversalLattice {
             (TraversalPathBitSet::Entity(lhs), TraversalPathBitSet::Entity(rhs)) => {
                 TraversalPathBitSet::Entity(self.join_owned(lhs, rhs))
             }
+            (TraversalPathBitSet::DataType(lhs), TraversalPathBitSet::DataType(rhs)) => {
+                TraversalPathBitSet::DataType(self.join_owned(lhs, rhs))
+            }
+            (TraversalPathBitSet::PropertyType(lhs), TraversalPathBitSet::PropertyType(rhs)) => {
+                TraversalPathBitSet::PropertyType(self.join_owned(lhs, rhs))
+            }
+            (TraversalPathBitSet::EntityType(lhs), TraversalPathBitSet::EntityType(rhs)) => {
+                TraversalPathBitSet::EntityType(self.join_owned(lhs, rhs))
+            }
+            (lhs, rhs) => unreachable!(
+                "cannot join bitsets of {:?} and {:?}",
+                lhs.vertex(),
+                rhs.vertex()
+            ),
         }
     }
 }
@@ -230,9 +348,42 @@ impl JoinSemiLattice<TraversalPathBitSet> for TraversalLattice {
 pub enum TraversalPath {
     /// A path into the entity schema.
     Entity(EntityPath),
+    /// A path into the data type schema.
+    DataType(OntologyPath),
+    /// A path into the property type schema.
+    PropertyType(OntologyPath),
+    /// A path into the entity type schema.
+    EntityType(OntologyPath),
 }
 
 impl TraversalPath {
+    /// Creates the path of the ontology vertex type `vertex`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vertex` is not a type of the ontology.
+    #[must_use]
+    pub const fn ontology(vertex: VertexType, path: OntologyPath) -> Self {
+        match vertex {
+            VertexType::DataType => Self::DataType(path),
+            VertexType::PropertyType => Self::PropertyType(path),
+            VertexType::EntityType => Self::EntityType(path),
+            VertexType::Entity => panic!("entities are not a type of the ontology"),
+        }
+    }
+
+    /// Returns the vertex type this path belongs to.
+    #[inline]
+    #[must_use]
+    pub const fn vertex(self) -> VertexType {
+        match self {
+            Self::Entity(_) => VertexType::Entity,
+            Self::DataType(_) => VertexType::DataType,
+            Self::PropertyType(_) => VertexType::PropertyType,
+            Self::EntityType(_) => VertexType::EntityType,
+        }
+    }
+
     /// Returns a unique symbol identifying this path variant.
     ///
     /// Used as column aliases in SQL generation so the interpreter can locate
@@ -242,6 +393,9 @@ impl TraversalPath {
     pub const fn as_symbol(self) -> Symbol<'static> {
         match self {
             Self::Entity(path) => path.as_symbol(),
+            Self::DataType(path) | Self::PropertyType(path) | Self::EntityType(path) => {
+                path.as_symbol()
+            }
         }
     }
 
@@ -255,6 +409,9 @@ impl TraversalPath {
     pub fn resolve_type(self, env: &Environment<'_>) -> Option<TypeId> {
         match self {
             Self::Entity(path) => path.resolve_type(env),
+            Self::DataType(path) | Self::PropertyType(path) | Self::EntityType(path) => {
+                Some(path.resolve_type(env))
+            }
         }
     }
 
@@ -264,6 +421,9 @@ impl TraversalPath {
     pub const fn origin(self) -> TargetBitSet {
         match self {
             Self::Entity(path) => path.origin(),
+            Self::DataType(path) | Self::PropertyType(path) | Self::EntityType(path) => {
+                path.origin()
+            }
         }
     }
 
@@ -272,6 +432,9 @@ impl TraversalPath {
     pub(crate) fn estimate_size(self, config: &TransferCostConfig) -> InformationRange {
         match self {
             Self::Entity(path) => path.estimate_size(config),
+            Self::DataType(path) | Self::PropertyType(path) | Self::EntityType(path) => {
+                path.estimate_size()
+            }
         }
     }
 }
@@ -290,14 +453,28 @@ pub struct TraversalPathBitMap(
 );
 
 impl TraversalPathBitMap {
-    const BOTTOM: Self = Self(IdArray::from_raw([TraversalPathBitSet::empty(
-        VertexType::Entity,
-    )]));
+    const BOTTOM: Self = Self(IdArray::from_raw([
+        TraversalPathBitSet::empty(VertexType::Entity),
+        TraversalPathBitSet::empty(VertexType::DataType),
+        TraversalPathBitSet::empty(VertexType::PropertyType),
+        TraversalPathBitSet::empty(VertexType::EntityType),
+    ]));
     const TOP: Self = {
         let mut entity = TraversalPathBitSet::empty(VertexType::Entity);
         entity.insert_all();
-
-        Self(IdArray::from_raw([entity]))
+        let mut data_type = TraversalPathBitSet::empty(VertexType::DataType);
+        data_type.insert_all();
+        let mut property_type = TraversalPathBitSet::empty(VertexType::PropertyType);
+        property_type.insert_all();
+        let mut entity_type = TraversalPathBitSet::empty(VertexType::EntityType);
+        entity_type.insert_all();
+
+        Self(IdArray::from_raw([
+            entity,
+            data_type,
+            property_type,
+            entity_type,
+        ]))
     };
 
     /// Joins a [`TraversalPathBitSet`] into the slot for its vertex type.
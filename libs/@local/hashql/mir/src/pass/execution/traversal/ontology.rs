@@ -0,0 +1,361 @@
+use core::ops::Bound;
+
+use hashql_core::{
+    id::{
+        Id,
+        bit_vec::{BitRelations as _, FiniteBitSet},
+    },
+    symbol::{Symbol, sym},
+    r#type::{TypeBuilder, TypeId, environment::Environment},
+};
+
+use super::{
+    TraversalLattice,
+    access::{Access, AccessMode},
+    entity::project,
+};
+use crate::{
+    body::place::Projection,
+    pass::{
+        analysis::{
+            dataflow::lattice::{HasBottom, HasTop, JoinSemiLattice},
+            size_estimation::{InformationRange, InformationUnit},
+        },
+        execution::target::{TargetBitSet, TargetId},
+    },
+};
+
+/// Shorthand for accessing a compile-time [`ConstantSymbol`] from the [`sym`] module.
+///
+/// [`ConstantSymbol`]: hashql_core::symbol::ConstantSymbol
+macro_rules! sym {
+    ($($sym:tt)::*) => {
+        sym::$($sym)::*::CONST
+    };
+}
+
+/// Resolved ontology type field path.
+///
+/// Data types, property types and entity types share the same shape, and are stored in the same
+/// tables, with the exception of the schema, which lives in the table of the respective kind of
+/// type (`data_types`, `property_types` or `entity_types`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Id)]
+#[id(const)]
+pub enum OntologyPath {
+    /// `id` — composite of [`BaseUrl`] + [`Version`].
+    ///
+    /// [`BaseUrl`]: Self::BaseUrl
+    /// [`Version`]: Self::Version
+    VersionedUrl,
+    /// `id.base_url` — `ontology_ids.base_url`.
+    BaseUrl,
+    /// `id.version` — `ontology_ids.version`.
+    Version,
+    /// `metadata.web_id` — `ontology_owned_metadata.web_id` (via `LEFT JOIN`), `NULL` for
+    /// external types.
+    WebId,
+    /// `schema.*` — JSONB `schema` column of the table of the respective kind of type.
+    Schema,
+}
+
+/// Integer type backing the [`FiniteBitSet`] for [`OntologyPath`].
+type FiniteBitSetWidth = u8;
+const _: () = {
+    assert!(
+        (FiniteBitSetWidth::BITS as usize) >= core::mem::variant_count::<OntologyPath>(),
+        "ontology path count exceeds finite bitset width"
+    );
+};
+
+impl OntologyPath {
+    /// Resolves a sequence of field projections to an [`OntologyPath`].
+    ///
+    /// Returns the resolved path together with the number of projections consumed, or `None`
+    /// if the projections do not map to any known storage location.
+    #[must_use]
+    pub fn resolve(projections: &[Projection<'_>]) -> Option<(Self, usize)> {
+        resolve(projections)
+    }
+
+    /// Returns a unique symbol identifying this path variant.
+    ///
+    /// Used as column aliases in SQL generation so the interpreter can locate
+    /// result columns by name.
+    #[must_use]
+    pub const fn as_symbol(self) -> Symbol<'static> {
+        match self {
+            Self::VersionedUrl => sym::id,
+            Self::BaseUrl => sym::base_url,
+            Self::Version => sym::version,
+            Self::WebId => sym::web_id,
+            Self::Schema => sym::schema,
+        }
+    }
+
+    /// The sequence of struct field names from the type root to this path's position
+    /// in the type hierarchy.
+    #[must_use]
+    pub const fn field_path(self) -> &'static [Symbol<'static>] {
+        match self {
+            Self::VersionedUrl => &[sym::id],
+            Self::BaseUrl => &[sym::id, sym::base_url],
+            Self::Version => &[sym::id, sym::version],
+            Self::WebId => &[sym::metadata, sym::web_id],
+            Self::Schema => &[sym::schema],
+        }
+    }
+
+    /// Returns the type of this path.
+    ///
+    /// Unlike entity paths, every ontology path has a fixed type, the schema is not typed any
+    /// further.
+    pub fn resolve_type(self, env: &Environment<'_>) -> TypeId {
+        use hashql_core::module::std_lib::{
+            core::option::types as option,
+            graph::types::{
+                ontology::types as ontology, principal::actor_group::web::types as web,
+            },
+        };
+
+        let ty = TypeBuilder::synthetic(env);
+
+        match self {
+            Self::VersionedUrl => ontology::versioned_url(&ty, None),
+            Self::BaseUrl => ontology::base_url(&ty, None),
+            Self::Version => ontology::ontology_type_version(&ty),
+            Self::WebId => option::option(&ty, web::web_id(&ty, None)),
+            Self::Schema => ty.unknown(),
+        }
+    }
+
+    /// Returns the set of execution targets that natively serve this path.
+    pub(crate) const fn origin(self) -> TargetBitSet {
+        let mut set = TargetBitSet::new_empty(TargetId::VARIANT_COUNT_U32);
+
+        match self.access() {
+            Access::Postgres(_) => set.insert(TargetId::Postgres),
+            Access::Embedding(_) => set.insert(TargetId::Embedding),
+        }
+
+        set
+    }
+
+    /// Returns the backend access mode for this path.
+    pub(crate) const fn access(self) -> Access {
+        match self {
+            Self::VersionedUrl => Access::Postgres(AccessMode::Composite),
+            Self::BaseUrl | Self::Version | Self::WebId | Self::Schema => {
+                Access::Postgres(AccessMode::Direct)
+            }
+        }
+    }
+
+    /// Returns the transitive children of this path in the composite hierarchy.
+    const fn children(self) -> &'static [Self] {
+        match self {
+            Self::VersionedUrl => &[Self::BaseUrl, Self::Version],
+            Self::BaseUrl | Self::Version | Self::WebId | Self::Schema => &[],
+        }
+    }
+
+    /// Returns the ancestor composites of this path, nearest first.
+    pub(crate) const fn ancestors(self) -> &'static [Self] {
+        match self {
+            Self::BaseUrl | Self::Version => &[Self::VersionedUrl],
+            Self::VersionedUrl | Self::WebId | Self::Schema => &[],
+        }
+    }
+
+    /// Returns the estimated transfer size for this path in information units.
+    pub(crate) const fn estimate_size(self) -> InformationRange {
+        match self {
+            Self::VersionedUrl => InformationRange::value(InformationUnit::new(2)),
+            Self::BaseUrl | Self::Version | Self::WebId => InformationRange::one(),
+            Self::Schema => InformationRange::new(InformationUnit::new(1), Bound::Unbounded),
+        }
+    }
+
+    /// Returns `true` if this path targets a JSONB column that allows arbitrary sub-paths.
+    const fn is_jsonb(self) -> bool {
+        matches!(self, Self::Schema)
+    }
+}
+
+/// Bitset of [`OntologyPath`] values with composite swallowing.
+///
+/// Follows the same rules as [`EntityPathBitSet`]: inserting a composite removes its children,
+/// and inserting a child when its ancestor is already present is a no-op.
+///
+/// [`EntityPathBitSet`]: super::EntityPathBitSet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OntologyPathBitSet(FiniteBitSet<OntologyPath, FiniteBitSetWidth>);
+
+impl OntologyPathBitSet {
+    const BOTTOM: Self = Self::new_empty();
+    const TOP: Self = {
+        let mut set = Self::new_empty();
+
+        let paths = OntologyPath::all();
+        let mut index = 0;
+        while index < paths.len() {
+            if paths[index].ancestors().is_empty() {
+                set.0.insert(paths[index]);
+            }
+
+            index += 1;
+        }
+
+        set
+    };
+
+    /// Creates an empty bitset with no paths set.
+    #[expect(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn new_empty() -> Self {
+        Self(FiniteBitSet::new_empty(
+            core::mem::variant_count::<OntologyPath>() as u32,
+        ))
+    }
+
+    /// Inserts this path into `bitset` with composite swallowing.
+    pub(crate) fn insert(&mut self, path: OntologyPath) {
+        for &ancestor in path.ancestors() {
+            if self.0.contains(ancestor) {
+                return;
+            }
+        }
+
+        self.0.insert(path);
+
+        for &child in path.children() {
+            self.0.remove(child);
+        }
+    }
+
+    /// Returns `true` if `path` is present in the bitset.
+    #[must_use]
+    pub(crate) const fn contains(self, path: OntologyPath) -> bool {
+        self.0.contains(path)
+    }
+
+    /// Re-applies composite swallowing after a raw union.
+    fn normalize(&mut self) {
+        for path in &self.0 {
+            for &ancestor in path.ancestors() {
+                if self.0.contains(ancestor) {
+                    self.0.remove(path);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn insert_all(&mut self) {
+        *self = Self::TOP;
+    }
+}
+
+impl HasTop<OntologyPathBitSet> for TraversalLattice {
+    fn top(&self) -> OntologyPathBitSet {
+        debug_assert!(self.vertex().is_ontology());
+        OntologyPathBitSet::TOP
+    }
+
+    fn is_top(&self, value: &OntologyPathBitSet) -> bool {
+        debug_assert!(self.vertex().is_ontology());
+        *value == OntologyPathBitSet::TOP
+    }
+}
+
+impl HasBottom<OntologyPathBitSet> for TraversalLattice {
+    fn bottom(&self) -> OntologyPathBitSet {
+        debug_assert!(self.vertex().is_ontology());
+        OntologyPathBitSet::BOTTOM
+    }
+
+    fn is_bottom(&self, value: &OntologyPathBitSet) -> bool {
+        debug_assert!(self.vertex().is_ontology());
+        *value == OntologyPathBitSet::BOTTOM
+    }
+}
+
+impl JoinSemiLattice<OntologyPathBitSet> for TraversalLattice {
+    fn join(&self, lhs: &mut OntologyPathBitSet, rhs: &OntologyPathBitSet) -> bool {
+        debug_assert!(self.vertex().is_ontology());
+
+        let mut new = *lhs;
+
+        new.0.union(&rhs.0);
+        new.normalize();
+
+        let has_changed = new != *lhs;
+        *lhs = new;
+        has_changed
+    }
+
+    fn join_owned(
+        &self,
+        mut lhs: OntologyPathBitSet,
+        rhs: &OntologyPathBitSet,
+    ) -> OntologyPathBitSet
+    where
+        OntologyPathBitSet: Sized,
+    {
+        debug_assert!(self.vertex().is_ontology());
+
+        lhs.0.union(&rhs.0);
+        lhs.normalize();
+
+        lhs
+    }
+}
+
+const impl core::ops::Deref for OntologyPathBitSet {
+    type Target = FiniteBitSet<OntologyPath, FiniteBitSetWidth>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Resolves an ontology type field path to an [`OntologyPath`].
+fn resolve(projections: &[Projection<'_>]) -> Option<(OntologyPath, usize)> {
+    let mut index = 0;
+
+    macro_rules! next {
+        () => {
+            project(projections, &mut index)
+        };
+
+        (else $cond:expr) => {{
+            if index >= projections.len() {
+                return Some(($cond, index));
+            }
+
+            next!()?
+        }};
+    }
+
+    let path = match next!()? {
+        sym!(id) => match next!(else OntologyPath::VersionedUrl) {
+            sym!(base_url) => OntologyPath::BaseUrl,
+            sym!(version) => OntologyPath::Version,
+            _ => return None,
+        },
+        sym!(metadata) => match next!()? {
+            sym!(web_id) => OntologyPath::WebId,
+            _ => return None,
+        },
+        // <kind>_types.schema (JSONB)
+        sym!(schema) => OntologyPath::Schema,
+        _ => return None,
+    };
+
+    // JSONB paths allow arbitrary sub-paths; all others must be fully resolved
+    if !path.is_jsonb() && projections.get(index).is_some() {
+        return None;
+    }
+
+    Some((path, index))
+}
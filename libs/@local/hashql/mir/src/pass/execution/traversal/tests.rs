@@ -1,5 +1,5 @@
-//! Unit tests for entity projection path lookup, composite swallowing, transfer sizing,
-//! and traversal analysis.
+//! Unit tests for entity and ontology projection path lookup, composite swallowing, transfer
+//! sizing, and traversal analysis.
 
 use core::ops::Bound;
 
@@ -21,8 +21,8 @@ use crate::{
         execution::{
             VertexType,
             traversal::{
-                EntityPath, EntityPathBitSet, TransferCostConfig, TraversalLattice,
-                TraversalPathBitSet,
+                EntityPath, EntityPathBitSet, OntologyPath, OntologyPathBitSet, TransferCostConfig,
+                TraversalLattice, TraversalPathBitSet,
             },
         },
     },
@@ -532,3 +532,70 @@ fn inferred_provenance_transfer_size_is_static() {
         )
     );
 }
+
+/// Ontology paths resolve `id`, its children, `metadata.web_id` and arbitrary sub-paths of
+/// `schema`.
+#[test]
+fn ontology_path_resolution() {
+    assert_eq!(
+        OntologyPath::resolve(&[proj(sym::id)]),
+        Some((OntologyPath::VersionedUrl, 1))
+    );
+    assert_eq!(
+        OntologyPath::resolve(&[proj(sym::id), proj(sym::version)]),
+        Some((OntologyPath::Version, 2))
+    );
+    assert_eq!(
+        OntologyPath::resolve(&[proj(sym::metadata), proj(sym::web_id)]),
+        Some((OntologyPath::WebId, 2))
+    );
+    assert_eq!(
+        OntologyPath::resolve(&[proj(sym::schema), proj(sym::properties)]),
+        Some((OntologyPath::Schema, 1))
+    );
+
+    assert_eq!(OntologyPath::resolve(&[proj(sym::metadata)]), None);
+    assert_eq!(
+        OntologyPath::resolve(&[proj(sym::id), proj(sym::base_url), proj(sym::unknown)]),
+        None
+    );
+    assert_eq!(OntologyPath::resolve(&[proj(sym::properties)]), None);
+}
+
+/// `VersionedUrl` swallows `BaseUrl` and `Version`.
+#[test]
+fn ontology_composite_swallows_children() {
+    let mut bitset = OntologyPathBitSet::new_empty();
+    bitset.insert(OntologyPath::BaseUrl);
+    bitset.insert(OntologyPath::Schema);
+    bitset.insert(OntologyPath::VersionedUrl);
+
+    assert!(bitset.contains(OntologyPath::VersionedUrl));
+    assert!(bitset.contains(OntologyPath::Schema));
+    assert!(!bitset.contains(OntologyPath::BaseUrl));
+
+    bitset.insert(OntologyPath::Version);
+    assert!(!bitset.contains(OntologyPath::Version));
+}
+
+/// `TraversalPathBitSet` over an ontology vertex satisfies `BoundedJoinSemiLattice` laws.
+#[test]
+fn ontology_traversal_path_bitset_bounded_join_semilattice() {
+    let lattice = TraversalLattice::new(VertexType::EntityType);
+
+    let ontology_bitset_of = |paths: &[OntologyPath]| {
+        let mut bitset = OntologyPathBitSet::new_empty();
+        for &path in paths {
+            bitset.insert(path);
+        }
+
+        TraversalPathBitSet::EntityType(bitset)
+    };
+
+    let set_a = ontology_bitset_of(&[OntologyPath::BaseUrl, OntologyPath::WebId]);
+    let set_b = ontology_bitset_of(&[OntologyPath::VersionedUrl]);
+    let set_c = ontology_bitset_of(&[OntologyPath::Version, OntologyPath::Schema]);
+
+    assert_bounded_join_semilattice(&lattice, set_a, set_b, set_c);
+    assert_is_top_consistent::<_, TraversalPathBitSet>(&lattice);
+}
@@ -52,6 +52,9 @@ fn peel<'heap>(
 #[repr(u8)]
 pub enum VertexType {
     Entity,
+    DataType,
+    PropertyType,
+    EntityType,
 }
 
 impl VertexType {
@@ -64,7 +67,16 @@ impl VertexType {
 
         match opaque.name.as_constant()? {
             sym::path::Entity::CONST => Some(Self::Entity),
+            sym::path::DataType::CONST => Some(Self::DataType),
+            sym::path::PropertyType::CONST => Some(Self::PropertyType),
+            sym::path::EntityType::CONST => Some(Self::EntityType),
             _ => None,
         }
     }
+
+    /// Returns `true` if the vertex is a type of the ontology.
+    #[must_use]
+    pub const fn is_ontology(self) -> bool {
+        matches!(self, Self::DataType | Self::PropertyType | Self::EntityType)
+    }
 }
@@ -20,7 +20,8 @@ use crate::body::{
 /// - `Assign` with `Aggregate`: Struct/tuple construction
 ///
 /// Non-trivial statements include function calls (`Apply`), arithmetic (`Binary`, `Unary`),
-/// and storage markers (`StorageLive`, `StorageDead`).
+/// intrinsic operations (`Intrinsic`), discriminant reads (`Discriminant`), and storage markers
+/// (`StorageLive`, `StorageDead`).
 fn all_statements_trivial<'stmt, 'heap: 'stmt>(
     statements: impl IntoIterator<Item = &'stmt Statement<'heap>>,
 ) -> bool {
@@ -34,7 +35,13 @@ fn all_statements_trivial<'stmt, 'heap: 'stmt>(
             }) => true,
             StatementKind::Assign(Assign {
                 lhs: _,
-                rhs: RValue::Apply(_) | RValue::Binary(_) | RValue::Unary(_) | RValue::Input(_),
+                rhs:
+                    RValue::Apply(_)
+                    | RValue::Binary(_)
+                    | RValue::Unary(_)
+                    | RValue::Input(_)
+                    | RValue::Intrinsic(_)
+                    | RValue::Discriminant(_),
             })
             | StatementKind::StorageLive(_)
             | StatementKind::StorageDead(_) => false,
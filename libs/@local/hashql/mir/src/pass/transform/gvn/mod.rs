@@ -0,0 +1,290 @@
+//! Global value numbering pass.
+//!
+//! This pass eliminates repeated computations by replacing an rvalue with a load of a local that
+//! already holds the same value. Two rvalues compute the same value if they apply the same
+//! operation to the same operands, and the earlier computation is available wherever its
+//! definition dominates the later one.
+//!
+//! # Algorithm
+//!
+//! 1. **Eligibility**: Collect the locals that are defined at most once, are never partially
+//!    redefined through a projection, and never end their storage. Only the values of these locals
+//!    are stable, so only they can act as operands or leaders of a value.
+//! 2. **Numbering**: Visit the blocks in reverse postorder, which visits every block after its
+//!    dominators. For each block, collect the set of blocks dominating it from the dominator tree.
+//! 3. **Canonicalization**: Rewrite every use of a local, that has been found to be redundant, to
+//!    the local holding the value first (its leader), so that equivalent rvalues become
+//!    structurally equal.
+//! 4. **Elimination**: For each assignment `_x = <rvalue>` of a pure rvalue, look up an earlier
+//!    assignment `_y = <rvalue>` whose block dominates the current one. If found, the assignment is
+//!    replaced with `_x = _y`, and `_y` becomes the leader of `_x`. Otherwise, `_x` is recorded as
+//!    the leader of the rvalue.
+//!
+//! # Examples
+//!
+//! Repeated computation:
+//! ```text
+//! _2 = _0 + _1; _3 = _0 + _1  →  _2 = _0 + _1; _3 = _2
+//! ```
+//!
+//! Repeated projection in a dominated block:
+//! ```text
+//! bb0: _1 = _0.0; goto bb1
+//! bb1: _2 = _0.0             →  bb1: _2 = _1
+//! ```
+//!
+//! Chained computation:
+//! ```text
+//! _2 = _0 + 1; _3 = _0 + 1; _4 = _2 * 2; _5 = _3 * 2  →  ...; _3 = _2; _4 = _2 * 2; _5 = _4
+//! ```
+//!
+//! # Limitations
+//!
+//! - Function applications are never numbered, as they may not be pure
+//! - Plain copies and constants are left to [`CopyPropagation`]
+//! - Values of block parameters are not numbered, so values merging at join points are not found
+//!
+//! The loads left behind are meant to be cleaned up by [`CopyPropagation`] and
+//! [`DeadStoreElimination`].
+//!
+//! [`CopyPropagation`]: super::CopyPropagation
+//! [`DeadStoreElimination`]: super::DeadStoreElimination
+
+#[cfg(test)]
+mod tests;
+
+use core::{alloc::Allocator, convert::Infallible};
+
+use hashql_core::{
+    collections::{FastHashMap, fast_hash_map_in},
+    heap::{BumpAllocator, Scratch, TransferInto as _},
+    id::{IdVec, bit_vec::DenseBitSet},
+};
+
+use crate::{
+    body::{
+        Body,
+        basic_block::BasicBlockId,
+        local::{Local, LocalVec},
+        location::Location,
+        operand::Operand,
+        place::{DefUse, Place, PlaceContext, PlaceLivenessContext, PlaceWriteContext},
+        rvalue::RValue,
+        statement::Assign,
+    },
+    context::MirContext,
+    intern::Interner,
+    pass::{Changed, TransformPass},
+    visit::{self, Visitor, VisitorMut, r#mut::filter},
+};
+
+/// Collects the locals whose value never changes once defined.
+struct FindStableLocals {
+    defined: DenseBitSet<Local>,
+    unstable: DenseBitSet<Local>,
+}
+
+impl Visitor<'_> for FindStableLocals {
+    type Result = Result<(), !>;
+
+    fn visit_local(&mut self, _: Location, context: PlaceContext, local: Local) -> Self::Result {
+        match context {
+            PlaceContext::Write(PlaceWriteContext::Assign | PlaceWriteContext::BlockParam) => {
+                if !self.defined.insert(local) {
+                    self.unstable.insert(local);
+                }
+            }
+            PlaceContext::Write(PlaceWriteContext::Projection)
+            | PlaceContext::Liveness(PlaceLivenessContext::End) => {
+                self.unstable.insert(local);
+            }
+            PlaceContext::Read(_) | PlaceContext::Liveness(PlaceLivenessContext::Begin) => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether the rvalue is pure, and therefore always computes the same value from the same
+/// operands.
+fn is_numbered(rvalue: &RValue<'_>) -> bool {
+    match rvalue {
+        // Copies and constants are handled by copy propagation
+        RValue::Load(Operand::Place(place)) => !place.projections.is_empty(),
+        RValue::Load(Operand::Constant(_)) | RValue::Apply(_) => false,
+        RValue::Binary(_)
+        | RValue::Unary(_)
+        | RValue::Aggregate(_)
+        | RValue::Input(_)
+        | RValue::Intrinsic(_)
+        | RValue::Discriminant(_) => true,
+    }
+}
+
+/// Global value numbering pass.
+///
+/// Replaces pure rvalues, which have already been computed in a dominating position, with a load
+/// of the local holding the value.
+pub struct GlobalValueNumbering<A: BumpAllocator = Scratch> {
+    alloc: A,
+}
+
+impl GlobalValueNumbering {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            alloc: Scratch::new(),
+        }
+    }
+}
+
+impl Default for GlobalValueNumbering {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: BumpAllocator> GlobalValueNumbering<A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self { alloc }
+    }
+}
+
+impl<'env, 'heap, A: BumpAllocator> TransformPass<'env, 'heap> for GlobalValueNumbering<A> {
+    fn run(&mut self, context: &mut MirContext<'env, 'heap>, body: &mut Body<'heap>) -> Changed {
+        let mut stable_locals = FindStableLocals {
+            defined: DenseBitSet::new_empty(body.local_decls.len()),
+            unstable: DenseBitSet::new_empty(body.local_decls.len()),
+        };
+        Ok(()) = stable_locals.visit_body(body);
+
+        let mut visitor = GlobalValueNumberingVisitor {
+            interner: context.interner,
+            alloc: &self.alloc,
+            unstable: stable_locals.unstable,
+            dominating: DenseBitSet::new_empty(body.basic_blocks.len()),
+            leaders: IdVec::with_capacity_in(body.local_decls.len(), &self.alloc),
+            values: fast_hash_map_in(&self.alloc),
+            stable_operands: true,
+            changed: false,
+        };
+
+        let reverse_postorder = body
+            .basic_blocks
+            .reverse_postorder()
+            .transfer_into(&self.alloc);
+
+        for &mut id in reverse_postorder {
+            let dominators = body.basic_blocks.dominators();
+
+            visitor.dominating.clear();
+            let mut dominator = Some(id);
+            while let Some(block) = dominator {
+                visitor.dominating.insert(block);
+                dominator = dominators.immediate_dominator(block);
+            }
+
+            Ok(()) =
+                visitor.visit_basic_block(id, &mut body.basic_blocks.as_mut_preserving_cfg()[id]);
+        }
+
+        visitor.changed.into()
+    }
+}
+
+struct GlobalValueNumberingVisitor<'env, 'heap, A: Allocator + Clone> {
+    interner: &'env Interner<'heap>,
+    alloc: A,
+    unstable: DenseBitSet<Local>,
+
+    /// The blocks dominating the block currently visited, including the block itself.
+    dominating: DenseBitSet<BasicBlockId>,
+    /// The local first holding the value of a redundant local.
+    leaders: LocalVec<Option<Local>, A>,
+    /// The locals holding the value of an rvalue, together with the block they are defined in.
+    values: FastHashMap<RValue<'heap>, Vec<(Local, BasicBlockId), A>, A>,
+
+    /// Whether every operand of the rvalue currently visited is stable.
+    stable_operands: bool,
+    changed: bool,
+}
+
+impl<'heap, A: Allocator + Clone> GlobalValueNumberingVisitor<'_, 'heap, A> {
+    fn lookup(&self, rvalue: &RValue<'heap>) -> Option<Local> {
+        self.values
+            .get(rvalue)?
+            .iter()
+            .find(|&&(_, block)| self.dominating.contains(block))
+            .map(|&(local, _)| local)
+    }
+}
+
+impl<'heap, A: Allocator + Clone> VisitorMut<'heap> for GlobalValueNumberingVisitor<'_, 'heap, A> {
+    type Filter = filter::Deep;
+    type Residual = Result<Infallible, !>;
+    type Result<T>
+        = Result<T, !>
+    where
+        T: 'heap;
+
+    fn interner(&self) -> &Interner<'heap> {
+        self.interner
+    }
+
+    fn visit_local(
+        &mut self,
+        _: Location,
+        context: PlaceContext,
+        local: &mut Local,
+    ) -> Self::Result<()> {
+        if context.into_def_use() != Some(DefUse::Use) {
+            return Ok(());
+        }
+
+        if let Some(&leader) = self.leaders.lookup(*local) {
+            *local = leader;
+            self.changed = true;
+        }
+
+        self.stable_operands &= !self.unstable.contains(*local);
+
+        Ok(())
+    }
+
+    fn visit_statement_assign(
+        &mut self,
+        location: Location,
+        assign: &mut Assign<'heap>,
+    ) -> Self::Result<()> {
+        self.stable_operands = true;
+        Ok(()) = visit::r#mut::walk_statement_assign(self, location, assign);
+        let Assign { lhs, rhs } = assign;
+
+        if !lhs.projections.is_empty()
+            || self.unstable.contains(lhs.local)
+            || !self.stable_operands
+            || !is_numbered(rhs)
+        {
+            return Ok(());
+        }
+
+        if let Some(leader) = self.lookup(rhs) {
+            self.leaders.insert(lhs.local, leader);
+            *rhs = RValue::Load(Operand::Place(Place::local(leader)));
+            self.changed = true;
+
+            return Ok(());
+        }
+
+        if let Some(locals) = self.values.get_mut(rhs) {
+            locals.push((lhs.local, location.block));
+        } else {
+            let mut locals = Vec::new_in(self.alloc.clone());
+            locals.push((lhs.local, location.block));
+            self.values.insert(rhs.clone(), locals);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+#![expect(clippy::min_ident_chars, reason = "tests")]
+
+use std::{io::Write as _, path::PathBuf};
+
+use bstr::ByteVec as _;
+use hashql_core::{
+    heap::Heap,
+    pretty::Formatter,
+    r#type::{TypeFormatter, TypeFormatterOptions, environment::Environment},
+};
+use hashql_diagnostics::DiagnosticIssues;
+use insta::{Settings, assert_snapshot};
+
+use super::GlobalValueNumbering;
+use crate::{
+    body::Body, builder::body, context::MirContext, def::DefIdSlice, intern::Interner,
+    pass::TransformPass as _, pretty::TextFormatOptions,
+};
+
+#[track_caller]
+fn assert_gvn_pass<'heap>(
+    name: &'static str,
+    body: Body<'heap>,
+    context: &mut MirContext<'_, 'heap>,
+) {
+    let formatter = Formatter::new(context.heap);
+    let mut formatter = TypeFormatter::new(
+        &formatter,
+        context.env,
+        TypeFormatterOptions::terse().with_qualified_opaque_names(true),
+    );
+    let mut text_format = TextFormatOptions {
+        writer: Vec::new(),
+        indent: 4,
+        sources: (),
+        types: &mut formatter,
+        annotations: (),
+    }
+    .build();
+
+    let mut bodies = [body];
+
+    text_format
+        .format(DefIdSlice::from_raw(&bodies), &[])
+        .expect("should be able to write bodies");
+
+    let changed = GlobalValueNumbering::new().run(context, &mut bodies[0]);
+    write!(
+        text_format.writer,
+        "\n\n{:=^50}\n\n",
+        format!(" Changed: {changed:?} ")
+    )
+    .expect("infallible");
+
+    text_format
+        .format(DefIdSlice::from_raw(&bodies), &[])
+        .expect("should be able to write bodies");
+
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut settings = Settings::clone_current();
+    settings.set_snapshot_path(dir.join("tests/ui/pass/gvn"));
+    settings.set_prepend_module_to_snapshot(false);
+
+    let _drop = settings.bind_to_scope();
+
+    let value = text_format.writer.into_string_lossy();
+    assert_snapshot!(name, value);
+}
+
+/// Tests that a repeated binary operation is replaced with the first result.
+#[test]
+fn repeated_binary() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Bool {
+        decl x: Int, a: Int, b: Int, r: Bool;
+
+        bb0() {
+            a = bin.+ x 1;
+            b = bin.+ x 1;
+            r = bin.== a b;
+            return r;
+        }
+    });
+
+    assert_gvn_pass(
+        "repeated_binary",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that operations on redundant locals are numbered through their leader.
+#[test]
+fn chained() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Bool {
+        decl x: Int, a: Int, b: Int, c: Int, d: Int, r: Bool;
+
+        bb0() {
+            a = bin.+ x 1;
+            b = bin.+ x 1;
+            c = bin.- a 2;
+            d = bin.- b 2;
+            r = bin.== c d;
+            return r;
+        }
+    });
+
+    assert_gvn_pass(
+        "chained",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that loading the same input twice is replaced with the first load.
+#[test]
+fn repeated_input() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Bool {
+        decl a: Int, b: Int, r: Bool;
+
+        bb0() {
+            a = input.load! "input";
+            b = input.load! "input";
+            r = bin.== a b;
+            return r;
+        }
+    });
+
+    assert_gvn_pass(
+        "repeated_input",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that a projection computed in a dominating block is reused.
+#[test]
+fn dominating_projection() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl x: (Int, Int), cond: Bool, a: Int, b: Int;
+        @proj x_0 = x.0: Int;
+
+        bb0() {
+            cond = input.load! "cond";
+            a = load x_0;
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            b = load x_0;
+            return b;
+        },
+        bb2() {
+            return a;
+        }
+    });
+
+    assert_gvn_pass(
+        "dominating_projection",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that computations in blocks not dominating each other are kept.
+#[test]
+fn sibling_blocks_unchanged() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl x: Int, cond: Bool, a: Int, b: Int, c: Int;
+
+        bb0() {
+            cond = input.load! "cond";
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            a = bin.+ x 1;
+            goto bb3(a);
+        },
+        bb2() {
+            b = bin.+ x 1;
+            goto bb3(b);
+        },
+        bb3(c) {
+            return c;
+        }
+    });
+
+    assert_gvn_pass(
+        "sibling_blocks_unchanged",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that locals assigned more than once are never used as leaders.
+#[test]
+fn redefined_local_unchanged() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Bool {
+        decl x: Int, a: Int, b: Int, r: Bool;
+
+        bb0() {
+            a = bin.+ x 1;
+            a = bin.+ x 2;
+            b = bin.+ x 2;
+            r = bin.== a b;
+            return r;
+        }
+    });
+
+    assert_gvn_pass(
+        "redefined_local_unchanged",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
@@ -147,6 +147,10 @@ pub struct InlineCostEstimationConfig {
     pub rvalue_aggregate: f32,
     /// Cost of accessing function input parameters.
     pub rvalue_input: f32,
+    /// Cost of an intrinsic operation (string operations, etc).
+    pub rvalue_intrinsic: f32,
+    /// Cost of reading the discriminant of a value.
+    pub rvalue_discriminant: f32,
     /// Cost of a function application (call).
     pub rvalue_apply: f32,
 
@@ -179,6 +183,8 @@ impl Default for InlineCostEstimationConfig {
             rvalue_unary: 2.0,
             rvalue_aggregate: 3.0,
             rvalue_input: 5.0,
+            rvalue_intrinsic: 3.0,
+            rvalue_discriminant: 1.0,
             rvalue_apply: 4.0,
 
             terminator_switch_int_base: 1.0,
@@ -332,6 +338,8 @@ impl<'heap> Visitor<'heap> for CostEstimationVisitor {
             RValue::Unary(_) => self.config.rvalue_unary,
             RValue::Aggregate(_) => self.config.rvalue_aggregate,
             RValue::Input(_) => self.config.rvalue_input,
+            RValue::Intrinsic(_) => self.config.rvalue_intrinsic,
+            RValue::Discriminant(_) => self.config.rvalue_discriminant,
             RValue::Apply(_) => self.config.rvalue_apply,
         };
 
@@ -112,7 +112,10 @@ use crate::{
     context::MirContext,
     intern::Interner,
     interpret::value::Int,
-    pass::{Changed, TransformPass},
+    pass::{
+        Changed, TransformPass,
+        analysis::dataflow::{eval_bin_op, eval_un_op},
+    },
     visit::{self, VisitorMut, r#mut::filter},
 };
 
@@ -250,36 +253,6 @@ impl<'heap, A: Allocator> InstSimplifyVisitor<'_, 'heap, A> {
         OperandKind::Other
     }
 
-    /// Evaluates a binary operation on two constant integers.
-    fn eval_bin_op(lhs: Int, op: BinOp, rhs: Int) -> Option<Int> {
-        let result = match op {
-            BinOp::Add => return lhs.checked_add(rhs),
-            BinOp::Sub => return lhs.checked_sub(rhs),
-            // Bitwise ops preserve bool provenance via the Int operators
-            BinOp::BitAnd => return Some(lhs & rhs),
-            BinOp::BitOr => return Some(lhs | rhs),
-            // Comparisons produce booleans
-            BinOp::Eq => lhs == rhs,
-            BinOp::Ne => lhs != rhs,
-            BinOp::Lt => lhs.as_int() < rhs.as_int(),
-            BinOp::Lte => lhs.as_int() <= rhs.as_int(),
-            BinOp::Gt => lhs.as_int() > rhs.as_int(),
-            BinOp::Gte => lhs.as_int() >= rhs.as_int(),
-        };
-
-        Some(Int::from(result))
-    }
-
-    /// Evaluates a unary operation on a constant integer.
-    ///
-    /// Returns `None` if the operation overflows (only possible for negation of `i128::MIN`).
-    fn eval_un_op(op: UnOp, operand: Int) -> Option<Int> {
-        match op {
-            UnOp::BitNot => Some(!operand),
-            UnOp::Neg => operand.checked_neg(),
-        }
-    }
-
     /// Attempts to simplify a binary operation with a constant left operand and place right
     /// operand.
     ///
@@ -496,7 +469,7 @@ impl<'heap, A: Allocator> VisitorMut<'heap> for InstSimplifyVisitor<'_, 'heap, A
         match (self.try_eval(*left), self.try_eval(*right)) {
             (OperandKind::Int(lhs), OperandKind::Int(rhs)) => {
                 // The result may be none in case of overflow or division by zero.
-                if let Some(result) = Self::eval_bin_op(lhs, *op, rhs) {
+                if let Some(result) = eval_bin_op(lhs, *op, rhs) {
                     self.trampoline = Some(RValue::Load(Operand::Constant(Constant::Int(result))));
                 }
             }
@@ -530,7 +503,7 @@ impl<'heap, A: Allocator> VisitorMut<'heap> for InstSimplifyVisitor<'_, 'heap, A
         Unary { op, operand }: &mut Unary<'heap>,
     ) -> Self::Result<()> {
         if let OperandKind::Int(value) = self.try_eval(*operand)
-            && let Some(result) = Self::eval_un_op(*op, value)
+            && let Some(result) = eval_un_op(*op, value)
         {
             self.trampoline = Some(RValue::Load(Operand::Constant(Constant::Int(result))));
         }
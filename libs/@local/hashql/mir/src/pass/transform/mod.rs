@@ -7,10 +7,12 @@ mod dle;
 mod dse;
 pub mod error;
 mod forward_substitution;
+mod gvn;
 mod inline;
 mod inst_simplify;
 mod post_inline;
 mod pre_inline;
+mod sccp;
 mod ssa_repair;
 
 pub use self::{
@@ -22,6 +24,7 @@ pub use self::{
     dle::DeadLocalElimination,
     dse::DeadStoreElimination,
     forward_substitution::ForwardSubstitution,
+    gvn::GlobalValueNumbering,
     inline::{
         Inline, InlineConfig, InlineCostEstimationConfig, InlineHeuristicsConfig,
         InlineLoopBreakerConfig,
@@ -29,5 +32,6 @@ pub use self::{
     inst_simplify::InstSimplify,
     post_inline::PostInline,
     pre_inline::PreInline,
+    sccp::SparseConditionalConstantPropagation,
     ssa_repair::SsaRepair,
 };
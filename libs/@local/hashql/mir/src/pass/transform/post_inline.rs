@@ -1,28 +1,37 @@
 //! Post-inlining optimization pass.
 //!
-//! Runs [`Canonicalization`] to clean up redundancy from inlining.
+//! Runs [`Canonicalization`], [`SparseConditionalConstantPropagation`] and
+//! [`GlobalValueNumbering`] to clean up redundancy from inlining.
 
 use core::alloc::Allocator;
 
 use hashql_core::heap::BumpAllocator;
 
-use super::{Canonicalization, CanonicalizationConfig};
+use super::{
+    Canonicalization, CanonicalizationConfig, GlobalValueNumbering,
+    SparseConditionalConstantPropagation,
+};
 use crate::{
     body::Body,
     context::MirContext,
     def::DefIdSlice,
-    pass::{Changed, GlobalTransformPass, GlobalTransformState},
+    pass::{Changed, GlobalTransformPass, GlobalTransformState, TransformPass as _},
 };
 
 /// Post-inlining optimization driver.
 ///
-/// A thin wrapper around [`Canonicalization`] configured for post-inlining optimization. By running
+/// A wrapper around [`Canonicalization`] configured for post-inlining optimization. By running
 /// canonicalization after inlining, we ensure that:
 ///
 /// - Opportunities exposed by inlining (constant propagation, dead code) are exploited
 /// - Redundant operations introduced during inlining are eliminated
 /// - The final MIR is fully simplified before code generation
 ///
+/// Once canonicalized, [`SparseConditionalConstantPropagation`] folds the branches decided by
+/// values known across blocks, and [`GlobalValueNumbering`] replaces computations repeated by
+/// inlined bodies. Both leave loads and unreachable blocks behind, which is why canonicalization
+/// is run once more if either of them changed anything.
+///
 /// Uses a higher iteration limit than [`super::PreInline`] (16 vs 8) because inlining can expose
 /// more optimization opportunities that may require additional passes to fully resolve.
 ///
@@ -53,6 +62,27 @@ impl<'env, 'heap, A: BumpAllocator> GlobalTransformPass<'env, 'heap> for PostInl
         state: &mut GlobalTransformState<'_>,
         bodies: &mut DefIdSlice<Body<'heap>>,
     ) -> Changed {
-        self.canonicalization.run(context, state, bodies)
+        let mut changed = self.canonicalization.run(context, state, bodies);
+
+        let mut cleanup = Changed::No;
+        for (id, body) in bodies.iter_enumerated_mut() {
+            let alloc = self.canonicalization.allocator_mut();
+
+            let mut result: Changed = alloc.scoped_mut(|alloc| {
+                SparseConditionalConstantPropagation::new_in(alloc).run(context, body)
+            });
+            result |=
+                alloc.scoped_mut(|alloc| GlobalValueNumbering::new_in(alloc).run(context, body));
+
+            state.mark(id, result);
+            cleanup |= result;
+        }
+
+        if cleanup != Changed::No {
+            changed |= cleanup;
+            changed |= self.canonicalization.run(context, state, bodies);
+        }
+
+        changed
     }
 }
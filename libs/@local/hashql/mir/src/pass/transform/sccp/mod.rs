@@ -0,0 +1,249 @@
+//! Sparse conditional constant propagation pass.
+//!
+//! This pass replaces every use of a local with a known integer value by that value, and removes
+//! the blocks that can never be executed, because every switch leading to them is decided by a
+//! known discriminant.
+//!
+//! # Algorithm
+//!
+//! 1. **Analysis**: Run [`ConstantPropagationAnalysis`] to find the value of each local at the
+//!    entry of every block, and which blocks are reachable at all.
+//! 2. **Substitution**: Replay each reachable block from its entry state. Every use of a local with
+//!    a known value is replaced with the constant, and every assignment of a known value is
+//!    replaced with a load of that constant.
+//! 3. **Branch folding**: Switches on a discriminant, that is now constant, are replaced with a
+//!    jump to the arm taken.
+//! 4. **Cleanup**: The blocks no longer reachable are removed by [`DeadBlockElimination`].
+//!
+//! Unlike [`CopyPropagation`] and [`CfgSimplify`], the pass finds constants that flow through
+//! block parameters, as long as every reachable predecessor agrees on the value.
+//!
+//! # Example
+//!
+//! Before:
+//! ```text
+//! bb0: _0 = 1; switch_int(_0) -> [0: bb1, 1: bb2]
+//! bb1: goto bb3(2)
+//! bb2: goto bb3(_0)
+//! bb3(_1): _2 = _1 + 1; return _2
+//! ```
+//!
+//! After:
+//! ```text
+//! bb0: _0 = 1; goto bb1
+//! bb1: goto bb2(1)
+//! bb2(_1): _2 = 2; return 2
+//! ```
+//!
+//! The dead assignments left behind are meant to be cleaned up by [`DeadStoreElimination`].
+//!
+//! [`CopyPropagation`]: super::CopyPropagation
+//! [`CfgSimplify`]: super::CfgSimplify
+//! [`DeadStoreElimination`]: super::DeadStoreElimination
+
+#[cfg(test)]
+mod tests;
+
+use core::{alloc::Allocator, convert::Infallible};
+
+use hashql_core::heap::{BumpAllocator, Scratch};
+
+use super::{DeadBlockElimination, error::unreachable_switch_arm};
+use crate::{
+    body::{
+        Body,
+        basic_block::BasicBlockId,
+        constant::Constant,
+        location::Location,
+        operand::Operand,
+        rvalue::RValue,
+        statement::{Assign, Statement, StatementKind},
+        terminator::{Goto, TerminatorKind},
+    },
+    context::MirContext,
+    intern::Interner,
+    pass::{
+        Changed, TransformPass,
+        analysis::dataflow::{
+            ConstantPropagationAnalysis, ConstantState, ConstantValue,
+            framework::DataflowAnalysis as _,
+        },
+    },
+    visit::{self, VisitorMut, r#mut::filter},
+};
+
+/// Sparse conditional constant propagation pass.
+///
+/// Propagates known integer values across blocks and removes the blocks, which are unreachable
+/// because of them.
+pub struct SparseConditionalConstantPropagation<A: BumpAllocator = Scratch> {
+    alloc: A,
+}
+
+impl SparseConditionalConstantPropagation {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            alloc: Scratch::new(),
+        }
+    }
+}
+
+impl Default for SparseConditionalConstantPropagation {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: BumpAllocator> SparseConditionalConstantPropagation<A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self { alloc }
+    }
+}
+
+impl<'env, 'heap, A: BumpAllocator> TransformPass<'env, 'heap>
+    for SparseConditionalConstantPropagation<A>
+{
+    fn run(&mut self, context: &mut MirContext<'env, 'heap>, body: &mut Body<'heap>) -> Changed {
+        let mut changed = false;
+
+        {
+            let results = ConstantPropagationAnalysis.iterate_to_fixpoint_in(body, &self.alloc);
+
+            let mut visitor = SparseConditionalConstantPropagationVisitor {
+                interner: context.interner,
+                analysis: &results.analysis,
+                state: results.entry_states[BasicBlockId::START].clone(),
+                changed: false,
+            };
+
+            for id in body.basic_blocks.ids() {
+                let entry = &results.entry_states[id];
+                if !entry.reachable {
+                    // Removed by dead block elimination, once the edges leading here are folded
+                    continue;
+                }
+
+                visitor.state.clone_from(entry);
+                Ok(()) = visitor
+                    .visit_basic_block(id, &mut body.basic_blocks.as_mut_preserving_cfg()[id]);
+
+                changed |= fold_switch_int(context, body, id);
+            }
+
+            changed |= visitor.changed;
+        }
+
+        if !changed {
+            return Changed::No;
+        }
+
+        // Folded switches leave blocks without predecessors behind
+        let _: Changed = self
+            .alloc
+            .scoped_mut(|alloc| DeadBlockElimination::new_in(alloc).run(context, body));
+
+        Changed::Yes
+    }
+}
+
+/// Replaces a `SwitchInt` on a constant discriminant with a jump to the arm taken.
+///
+/// Returns whether the terminator has been replaced.
+fn fold_switch_int(
+    context: &mut MirContext<'_, '_>,
+    body: &mut Body<'_>,
+    id: BasicBlockId,
+) -> bool {
+    let terminator = &body.basic_blocks[id].terminator;
+    let TerminatorKind::SwitchInt(switch) = &terminator.kind else {
+        return false;
+    };
+
+    let Operand::Constant(Constant::Int(int)) = switch.discriminant else {
+        return false;
+    };
+
+    let discriminant = int.as_uint();
+
+    let target = switch
+        .targets
+        .values()
+        .iter()
+        .position(|&value| value == discriminant)
+        .map(|index| switch.targets.targets()[index])
+        .or_else(|| switch.targets.otherwise());
+
+    if let Some(target) = target {
+        body.basic_blocks.as_mut()[id].terminator.kind = TerminatorKind::Goto(Goto { target });
+    } else {
+        // No matching case and no otherwise, this violates compiler invariants.
+        context
+            .diagnostics
+            .push(unreachable_switch_arm(terminator.span));
+        body.basic_blocks.as_mut()[id].terminator.kind = TerminatorKind::Unreachable;
+    }
+
+    true
+}
+
+struct SparseConditionalConstantPropagationVisitor<'env, 'heap, A: Allocator> {
+    interner: &'env Interner<'heap>,
+    analysis: &'env ConstantPropagationAnalysis,
+
+    /// The state before the statement currently visited.
+    state: ConstantState<A>,
+    changed: bool,
+}
+
+impl<'heap, A: Allocator + Clone> VisitorMut<'heap>
+    for SparseConditionalConstantPropagationVisitor<'_, 'heap, A>
+{
+    type Filter = filter::Deep;
+    type Residual = Result<Infallible, !>;
+    type Result<T>
+        = Result<T, !>
+    where
+        T: 'heap;
+
+    fn interner(&self) -> &Interner<'heap> {
+        self.interner
+    }
+
+    fn visit_operand(&mut self, _: Location, operand: &mut Operand<'heap>) -> Self::Result<()> {
+        if matches!(operand, Operand::Place(_))
+            && let ConstantValue::Int(int) = self.state.eval(operand)
+        {
+            *operand = Operand::Constant(Constant::Int(int));
+            self.changed = true;
+        }
+
+        Ok(())
+    }
+
+    fn visit_statement(
+        &mut self,
+        location: Location,
+        statement: &mut Statement<'heap>,
+    ) -> Self::Result<()> {
+        Ok(()) = visit::r#mut::walk_statement(self, location, statement);
+        self.analysis
+            .transfer_statement(location, statement, &mut self.state);
+
+        let StatementKind::Assign(Assign { lhs, rhs }) = &mut statement.kind else {
+            return Ok(());
+        };
+
+        if !lhs.projections.is_empty() || matches!(rhs, RValue::Load(Operand::Constant(_))) {
+            return Ok(());
+        }
+
+        if let ConstantValue::Int(int) = self.state.values[lhs.local] {
+            *rhs = RValue::Load(Operand::Constant(Constant::Int(int)));
+            self.changed = true;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+#![expect(clippy::min_ident_chars, reason = "tests")]
+
+use std::{io::Write as _, path::PathBuf};
+
+use bstr::ByteVec as _;
+use hashql_core::{
+    heap::Heap,
+    pretty::Formatter,
+    r#type::{TypeFormatter, TypeFormatterOptions, environment::Environment},
+};
+use hashql_diagnostics::DiagnosticIssues;
+use insta::{Settings, assert_snapshot};
+
+use super::SparseConditionalConstantPropagation;
+use crate::{
+    body::Body, builder::body, context::MirContext, def::DefIdSlice, intern::Interner,
+    pass::TransformPass as _, pretty::TextFormatOptions,
+};
+
+#[track_caller]
+fn assert_sccp_pass<'heap>(
+    name: &'static str,
+    body: Body<'heap>,
+    context: &mut MirContext<'_, 'heap>,
+) {
+    let formatter = Formatter::new(context.heap);
+    let mut formatter = TypeFormatter::new(
+        &formatter,
+        context.env,
+        TypeFormatterOptions::terse().with_qualified_opaque_names(true),
+    );
+    let mut text_format = TextFormatOptions {
+        writer: Vec::new(),
+        indent: 4,
+        sources: (),
+        types: &mut formatter,
+        annotations: (),
+    }
+    .build();
+
+    let mut bodies = [body];
+
+    text_format
+        .format(DefIdSlice::from_raw(&bodies), &[])
+        .expect("should be able to write bodies");
+
+    let changed = SparseConditionalConstantPropagation::new().run(context, &mut bodies[0]);
+    write!(
+        text_format.writer,
+        "\n\n{:=^50}\n\n",
+        format!(" Changed: {changed:?} ")
+    )
+    .expect("infallible");
+
+    text_format
+        .format(DefIdSlice::from_raw(&bodies), &[])
+        .expect("should be able to write bodies");
+
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut settings = Settings::clone_current();
+    settings.set_snapshot_path(dir.join("tests/ui/pass/sccp"));
+    settings.set_prepend_module_to_snapshot(false);
+
+    let _drop = settings.bind_to_scope();
+
+    let value = text_format.writer.into_string_lossy();
+    assert_snapshot!(name, value);
+}
+
+/// Tests that constants are folded through straight-line code.
+#[test]
+fn straight_line_folding() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/1 -> Int {
+        decl a: Int, x: Int, y: Int, z: Int;
+
+        bb0() {
+            x = load 2;
+            y = bin.+ x 3;
+            z = bin.+ a y;
+            return z;
+        }
+    });
+
+    assert_sccp_pass(
+        "straight_line_folding",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that a switch on a known discriminant is folded, and that the arm never taken does not
+/// contribute to the parameters of the join block.
+#[test]
+fn known_switch_prunes_arm() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl cond: Bool, p: Int, r: Int;
+
+        bb0() {
+            cond = load true;
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(1);
+        },
+        bb2() {
+            goto bb3(2);
+        },
+        bb3(p) {
+            r = bin.+ p 1;
+            return r;
+        }
+    });
+
+    assert_sccp_pass(
+        "known_switch_prunes_arm",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that block parameters receiving the same constant from every predecessor are folded.
+#[test]
+fn agreeing_block_params() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl cond: Bool, p: Int;
+
+        bb0() {
+            cond = input.load! "cond";
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(4);
+        },
+        bb2() {
+            goto bb3(4);
+        },
+        bb3(p) {
+            return p;
+        }
+    });
+
+    assert_sccp_pass(
+        "agreeing_block_params",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that block parameters receiving different constants are kept.
+#[test]
+fn disagreeing_block_params_unchanged() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl cond: Bool, p: Int;
+
+        bb0() {
+            cond = input.load! "cond";
+            if cond then bb1() else bb2();
+        },
+        bb1() {
+            goto bb3(1);
+        },
+        bb2() {
+            goto bb3(2);
+        },
+        bb3(p) {
+            return p;
+        }
+    });
+
+    assert_sccp_pass(
+        "disagreeing_block_params_unchanged",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
+
+/// Tests that a loop whose condition is known keeps its loop-carried value constant, and that the
+/// exit, which is never taken, is removed.
+#[test]
+fn loop_constant_condition() {
+    let heap = Heap::new();
+    let interner = Interner::new(&heap);
+    let env = Environment::new(&heap);
+
+    let body = body!(interner, env; fn@0/0 -> Int {
+        decl x: Int, cond: Bool;
+
+        bb0() {
+            goto bb1(0);
+        },
+        bb1(x) {
+            cond = bin.== x 0;
+            switch cond [1 => bb1(x), _ => bb2()];
+        },
+        bb2() {
+            return x;
+        }
+    });
+
+    assert_sccp_pass(
+        "loop_constant_condition",
+        body,
+        &mut MirContext {
+            heap: &heap,
+            env: &env,
+            interner: &interner,
+            diagnostics: DiagnosticIssues::new(),
+        },
+    );
+}
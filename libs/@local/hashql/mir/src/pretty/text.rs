@@ -1,6 +1,9 @@
 // Textual representation of bodies, based on a similar syntax used by rustc
 
-use core::fmt::Display;
+use core::{
+    alloc::Allocator,
+    fmt::{Debug, Display},
+};
 use std::io::{self, Write as _};
 
 use hashql_core::{
@@ -22,14 +25,17 @@ use crate::{
         location::Location,
         operand::Operand,
         place::{Place, ProjectionKind},
-        rvalue::{Aggregate, AggregateKind, Apply, Binary, Input, RValue, Unary},
+        rvalue::{
+            Aggregate, AggregateKind, Apply, Binary, Discriminant, Input, Intrinsic, RValue, Unary,
+        },
         statement::{Assign, Statement, StatementKind},
         terminator::{
-            Goto, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, Return, SwitchInt,
-            Target, Terminator, TerminatorKind,
+            Goto, GraphAggregate, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail,
+            GraphTraversal, Return, SwitchInt, Target, Terminator, TerminatorKind,
         },
     },
     def::{DefId, DefIdSlice},
+    interpret::trace::TraceEvent,
 };
 
 const fn source_keyword(source: Source<'_>) -> &'static str {
@@ -310,6 +316,34 @@ where
         self.flush()
     }
 
+    /// Formats the events of an execution trace as human-readable text.
+    ///
+    /// Every executed statement is rendered like in [`format_body`], followed by the value
+    /// assigned, while block entries, calls, and returns are rendered on lines of their own. The
+    /// `bodies` must be the ones the trace has been recorded from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if writing to the underlying writer fails.
+    ///
+    /// [`format_body`]: Self::format_body
+    pub fn format_trace<'heap, L: Allocator + Debug>(
+        &mut self,
+        bodies: &DefIdSlice<Body<'heap>>,
+        events: &[TraceEvent<'heap, L>],
+    ) -> io::Result<()>
+    where
+        S: SourceLookup<'heap>,
+        A: TextFormatAnnotations,
+    {
+        for event in events {
+            self.format_part((bodies, event))?;
+            self.newline()?;
+        }
+
+        self.flush()
+    }
+
     fn separated_list<V>(
         &mut self,
         sep: &[u8],
@@ -417,6 +451,7 @@ where
             match projection.kind {
                 ProjectionKind::Field(index) => write!(self.line_buffer, ".{index}")?,
                 ProjectionKind::FieldByName(symbol) => write!(self.line_buffer, ".{symbol}")?,
+                ProjectionKind::Downcast(name) => write!(self.line_buffer, "@{name}")?,
                 ProjectionKind::Index(local) => {
                     write!(self.line_buffer, "[")?;
                     self.format_part(local)?;
@@ -656,13 +691,16 @@ where
     S: SourceLookup<'heap>,
 {
     fn format_part(&mut self, value: GraphReadHead<'heap>) -> io::Result<()> {
-        match value {
-            GraphReadHead::Entity { axis } => {
-                self.line_buffer.write_all(b"entities(")?;
-                self.format_part(axis)?;
-                self.line_buffer.write_all(b")")
-            }
-        }
+        let name: &[u8] = match value {
+            GraphReadHead::Entity { .. } => b"entities(",
+            GraphReadHead::DataType { .. } => b"data_types(",
+            GraphReadHead::PropertyType { .. } => b"property_types(",
+            GraphReadHead::EntityType { .. } => b"entity_types(",
+        };
+
+        self.line_buffer.write_all(name)?;
+        self.format_part(*value.axis())?;
+        self.line_buffer.write_all(b")")
     }
 }
 
@@ -680,18 +718,63 @@ where
                 self.format_part(local)?;
                 self.line_buffer.write_all(b")")
             }
+            GraphReadBody::Traverse(GraphTraversal::OutgoingLinks) => {
+                self.line_buffer.write_all(b"outgoing_links")
+            }
+            GraphReadBody::Traverse(GraphTraversal::IncomingLinks) => {
+                self.line_buffer.write_all(b"incoming_links")
+            }
+            GraphReadBody::Traverse(GraphTraversal::LeftEntities) => {
+                self.line_buffer.write_all(b"left_entities")
+            }
+            GraphReadBody::Traverse(GraphTraversal::RightEntities) => {
+                self.line_buffer.write_all(b"right_entities")
+            }
+            GraphReadBody::Traverse(GraphTraversal::Linked { depth }) => {
+                write!(self.line_buffer, "traverse({depth})")
+            }
+            GraphReadBody::SortBy(def_id, local) => {
+                self.line_buffer.write_all(b"sort_by(")?;
+                self.format_part(def_id)?;
+                self.line_buffer.write_all(b", ")?;
+                self.format_part(local)?;
+                self.line_buffer.write_all(b")")
+            }
+            GraphReadBody::Limit(count) => write!(self.line_buffer, "limit({count})"),
+            GraphReadBody::Offset(count) => write!(self.line_buffer, "offset({count})"),
         }
     }
 }
 
-impl<W, S, T, A> FormatPart<GraphReadTail> for TextFormat<W, S, T, A>
+impl<'heap, W, S, T, A> FormatPart<GraphReadTail> for TextFormat<W, S, T, A>
 where
     W: io::Write,
+    S: SourceLookup<'heap>,
 {
     fn format_part(&mut self, value: GraphReadTail) -> io::Result<()> {
-        match value {
-            GraphReadTail::Collect => self.line_buffer.write_all(b"collect"),
-        }
+        let (name, def_id, local) = match value {
+            GraphReadTail::Collect => return self.line_buffer.write_all(b"collect"),
+            GraphReadTail::Count => return self.line_buffer.write_all(b"count"),
+            GraphReadTail::Exists => return self.line_buffer.write_all(b"exists"),
+            GraphReadTail::First => return self.line_buffer.write_all(b"first"),
+            GraphReadTail::GroupBy(def_id, local) => ("group_by", def_id, local),
+            GraphReadTail::Aggregate(aggregate, def_id, local) => {
+                let name = match aggregate {
+                    GraphAggregate::Sum => "sum",
+                    GraphAggregate::Min => "min",
+                    GraphAggregate::Max => "max",
+                    GraphAggregate::Avg => "avg",
+                };
+
+                (name, def_id, local)
+            }
+        };
+
+        write!(self.line_buffer, "{name}(")?;
+        self.format_part(def_id)?;
+        self.line_buffer.write_all(b", ")?;
+        self.format_part(local)?;
+        self.line_buffer.write_all(b")")
     }
 }
 
@@ -973,6 +1056,41 @@ where
     }
 }
 
+impl<'heap, W, S, T, A> FormatPart<&Intrinsic<'heap>> for TextFormat<W, S, T, A>
+where
+    W: io::Write,
+    S: SourceLookup<'heap>,
+{
+    fn format_part(&mut self, Intrinsic { op, arguments }: &Intrinsic<'heap>) -> io::Result<()> {
+        self.line_buffer.write_all(b"intrinsic ")?;
+        self.line_buffer.write_all(op.as_symbol().as_bytes())?;
+
+        for argument in arguments {
+            self.line_buffer.write_all(b" ")?;
+            self.format_part(*argument)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'heap, W, S, T, A> FormatPart<Discriminant<'heap>> for TextFormat<W, S, T, A>
+where
+    W: io::Write,
+    S: SourceLookup<'heap>,
+{
+    fn format_part(
+        &mut self,
+        Discriminant { operand, variants }: Discriminant<'heap>,
+    ) -> io::Result<()> {
+        self.line_buffer.write_all(b"discriminant ")?;
+        self.format_part(operand)?;
+        self.line_buffer.write_all(b" [")?;
+        self.csv(variants.iter().copied())?;
+        self.line_buffer.write_all(b"]")
+    }
+}
+
 impl<'heap, W, S, T, A> FormatPart<&RValue<'heap>> for TextFormat<W, S, T, A>
 where
     W: io::Write,
@@ -985,6 +1103,8 @@ where
             &RValue::Unary(unary) => self.format_part(unary),
             RValue::Aggregate(aggregate) => self.format_part(aggregate),
             &RValue::Input(input) => self.format_part(input),
+            RValue::Intrinsic(intrinsic) => self.format_part(intrinsic),
+            &RValue::Discriminant(discriminant) => self.format_part(discriminant),
             RValue::Apply(apply) => self.format_part(apply),
         }
     }
@@ -1128,3 +1248,69 @@ where
         )
     }
 }
+
+impl<'heap, W, S, T, A, L> FormatPart<(&DefIdSlice<Body<'heap>>, &TraceEvent<'heap, L>)>
+    for TextFormat<W, S, T, A>
+where
+    W: io::Write,
+    S: SourceLookup<'heap>,
+    A: TextFormatAnnotations,
+    L: Allocator + Debug,
+{
+    #[expect(clippy::use_debug, reason = "values have no textual representation")]
+    fn format_part(
+        &mut self,
+        (bodies, event): (&DefIdSlice<Body<'heap>>, &TraceEvent<'heap, L>),
+    ) -> io::Result<()> {
+        match event {
+            &TraceEvent::BlockEnter { body, block } => {
+                self.line_buffer.write_all(b"enter ")?;
+                self.format_part(body)?;
+                write!(self.line_buffer, " {block}")
+            }
+            &TraceEvent::Statement {
+                body,
+                location,
+                ref assigned,
+            } => {
+                let statement = &bodies[body].basic_blocks[location.block].statements
+                    [location.statement_index - 1];
+                self.format_part((location, statement))?;
+
+                let Some((local, value)) = assigned else {
+                    return Ok(());
+                };
+
+                // We estimate that we never exceed 80 columns, calculate the remaining width, if we
+                // don't have enough space, we add 4 spaces breathing room.
+                let remaining_width = 80_usize.checked_sub(self.line_buffer.len()).unwrap_or(4);
+                self.line_buffer
+                    .resize(self.line_buffer.len() + remaining_width, b' ');
+                write!(self.line_buffer, "// {local} = {value:?}")
+            }
+            TraceEvent::Call {
+                caller,
+                callee,
+                arguments,
+            } => {
+                self.line_buffer.write_all(b"call ")?;
+                self.format_part(*callee)?;
+                self.line_buffer.write_all(b"(")?;
+                for (index, argument) in arguments.iter().enumerate() {
+                    if index > 0 {
+                        self.line_buffer.write_all(b", ")?;
+                    }
+
+                    write!(self.line_buffer, "{argument:?}")?;
+                }
+                self.line_buffer.write_all(b") from ")?;
+                self.format_part(*caller)
+            }
+            TraceEvent::Return { body, value } => {
+                self.line_buffer.write_all(b"return ")?;
+                self.format_part(*body)?;
+                write!(self.line_buffer, " = {value:?}")
+            }
+        }
+    }
+}
@@ -154,6 +154,18 @@ impl<'heap, A: Allocator, S: Allocator> Reifier<'_, '_, '_, '_, 'heap, A, S> {
         }
     }
 
+    pub(super) fn constant(primitive: Primitive<'heap>) -> Constant<'heap> {
+        if primitive == Primitive::Null {
+            return Constant::Unit;
+        }
+
+        // First try if we can promote the primitive to a non-opaque constant:
+        match Int::try_from(primitive) {
+            Ok(int) => Constant::Int(int),
+            Err(TryFromPrimitiveError { value, .. }) => Constant::Primitive(value),
+        }
+    }
+
     pub(super) fn operand(&mut self, node: Node<'heap>) -> Operand<'heap> {
         match node.kind {
             NodeKind::Variable(Variable::Qualified(_)) => {
@@ -165,15 +177,8 @@ impl<'heap, A: Allocator, S: Allocator> Reifier<'_, '_, '_, '_, 'heap, A, S> {
                 // In the future this would be a simple FnPtr
                 Operand::Constant(Constant::Unit)
             }
-            NodeKind::Data(Data::Primitive(Primitive::Null)) => Operand::Constant(Constant::Unit),
             NodeKind::Data(Data::Primitive(primitive)) => {
-                // First try if we can promote the primitive to a non-opaque constant:
-                let constant = match Int::try_from(primitive) {
-                    Ok(int) => Constant::Int(int),
-                    Err(TryFromPrimitiveError { value, .. }) => Constant::Primitive(value),
-                };
-
-                Operand::Constant(constant)
+                Operand::Constant(Self::constant(primitive))
             }
             NodeKind::Variable(Variable::Local(local))
                 if let Some(&ptr) = self
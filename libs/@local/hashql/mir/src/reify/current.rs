@@ -171,12 +171,15 @@ impl<'mir, 'heap> CurrentBlock<'mir, 'heap> {
         self.slot = Some(blocks.push(Self::empty_block(self.heap)));
     }
 
-    pub(crate) fn terminate<const N: usize>(
+    pub(crate) fn terminate<I>(
         &mut self,
         terminator: Terminator<'heap>,
-        forward_ref: impl FnOnce(BasicBlockId) -> [ForwardRef; N],
+        forward_ref: impl FnOnce(BasicBlockId) -> I,
         blocks: &mut BasicBlockVec<BasicBlock<'heap>, &'heap Heap>,
-    ) -> ExitBlock {
+    ) -> ExitBlock
+    where
+        I: IntoIterator<Item = ForwardRef>,
+    {
         // Finishes the current block, and starts a new one
         let previous = mem::replace(&mut self.block, Self::empty_block(self.heap));
         let (_, id) = Self::complete(
@@ -188,7 +191,7 @@ impl<'mir, 'heap> CurrentBlock<'mir, 'heap> {
             blocks,
         );
 
-        self.forward_ref.extend_from_slice(&forward_ref(id.0));
+        self.forward_ref.extend(forward_ref(id.0));
 
         id
     }
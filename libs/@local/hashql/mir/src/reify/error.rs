@@ -220,14 +220,14 @@ pub(crate) fn type_cannot_be_indexed(span: SpanId) -> ReifyDiagnostic {
     diagnostic
 }
 
-/// ICE: Expected closure filter in graph operations.
+/// ICE: Expected closure literal in graph operations.
 #[coverage(off)]
-pub(crate) fn expected_closure_filter(span: SpanId) -> ReifyDiagnostic {
+pub(crate) fn expected_closure_literal(span: SpanId) -> ReifyDiagnostic {
     let mut diagnostic = Diagnostic::new(ReifyDiagnosticCategory::TypeInvariant, Severity::Bug)
-        .primary(Label::new(span, "expected closure filter"));
+        .primary(Label::new(span, "expected closure literal"));
 
     diagnostic.add_message(Message::help(
-        "HIR specialization should ensure graph filters are closure literals",
+        "HIR specialization should ensure graph filters, keys and aggregates are closure literals",
     ));
 
     diagnostic
@@ -14,7 +14,8 @@ use hashql_hir::node::{
     kind::NodeKind,
     r#let::Binder,
     operation::{
-        BinaryOperation, InputOperation, Operation, TypeConstructor, TypeOperation, UnaryOperation,
+        BinaryOperation, InputOperation, IntrinsicOperation, Operation, TypeConstructor,
+        TypeOperation, UnaryOperation,
     },
     thunk::Thunk,
 };
@@ -30,7 +31,7 @@ use crate::{
         local::Local,
         operand::Operand,
         place::{FieldIndex, Place, ProjectionKind},
-        rvalue::{Aggregate, AggregateKind, Apply, Binary, Input, RValue, Unary},
+        rvalue::{Aggregate, AggregateKind, Apply, Binary, Input, Intrinsic, RValue, Unary},
     },
     interpret::value::{Int, TryFromPrimitiveError},
 };
@@ -177,6 +178,22 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
         })
     }
 
+    fn rvalue_intrinsic_operation(
+        &mut self,
+        IntrinsicOperation { op, arguments }: IntrinsicOperation<'heap>,
+    ) -> RValue<'heap> {
+        let mut operands = IdVec::with_capacity_in(arguments.len(), self.context.mir.heap);
+
+        for &argument in arguments {
+            operands.push(self.operand(argument));
+        }
+
+        RValue::Intrinsic(Intrinsic {
+            op: op.value,
+            arguments: operands,
+        })
+    }
+
     fn rvalue_operation(&mut self, hir: HirPtr, operation: Operation<'heap>) -> RValue<'heap> {
         #[expect(unreachable_patterns)]
         match operation {
@@ -184,6 +201,9 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
             Operation::Binary(binary_operation) => self.rvalue_binary_operation(binary_operation),
             Operation::Unary(unary_operation, _) => self.rvalue_unary_operation(unary_operation),
             Operation::Input(input_operation) => Self::rvalue_input_operation(input_operation),
+            Operation::Intrinsic(intrinsic_operation) => {
+                self.rvalue_intrinsic_operation(intrinsic_operation)
+            }
         }
     }
 
@@ -1,30 +1,83 @@
-use core::alloc::Allocator;
+use core::{alloc::Allocator, mem};
 
-use hashql_core::{heap, id::Id as _, span::SpanId};
+use hashql_core::{
+    heap,
+    id::Id as _,
+    intern::Interned,
+    span::SpanId,
+    symbol::Symbol,
+    r#type::{TypeBuilder, TypeId},
+};
 use hashql_hir::node::{
-    branch,
+    Node,
+    branch::{
+        self,
+        r#match::{MatchArm, OpaquePattern, Pattern, PatternKind},
+    },
     graph::{self, Graph},
     kind::NodeKind,
+    r#let::Binder,
 };
 
 use super::{
     Reifier,
     current::{CurrentBlock, ForwardRef},
-    error::expected_closure_filter,
+    error::expected_closure_literal,
 };
 use crate::{
     body::{
         Source,
-        basic_block::BasicBlockId,
-        local::Local,
+        basic_block::{BasicBlock, BasicBlockId},
+        constant::Constant,
+        local::{Local, LocalDecl},
+        operand::Operand,
+        place::{FieldIndex, Place, ProjectionKind},
+        rvalue::{BinOp, Binary, Discriminant, RValue},
+        statement::{Assign, Statement, StatementKind},
         terminator::{
-            GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, SwitchInt, SwitchTargets,
+            Goto, GraphRead, GraphReadBody, GraphReadHead, GraphReadTail, SwitchInt, SwitchTargets,
             Target, Terminator, TerminatorKind,
         },
     },
     def::DefId,
 };
 
+/// A single check that must succeed for a pattern to match.
+#[derive(Debug, Copy, Clone)]
+enum PatternTestKind<'heap> {
+    /// The value must be an instance of the opaque type with the given name.
+    Opaque(Symbol<'heap>),
+    /// The value must be equal to the constant.
+    Literal(Constant<'heap>),
+}
+
+#[derive(Debug, Copy, Clone)]
+struct PatternTest<'heap> {
+    span: SpanId,
+    place: Place<'heap>,
+    kind: PatternTestKind<'heap>,
+}
+
+/// A value that is bound by a pattern once all of its tests have succeeded.
+#[derive(Debug, Copy, Clone)]
+struct PatternBinding<'heap> {
+    span: SpanId,
+    binder: Binder<'heap>,
+    place: Place<'heap>,
+    r#type: TypeId,
+}
+
+/// A block performing a pattern test, whose terminator is only known once the next arm has been
+/// lowered.
+#[derive(Debug, Copy, Clone)]
+struct PendingTest<'heap> {
+    block: BasicBlockId,
+    span: SpanId,
+    discriminant: Operand<'heap>,
+    value: u128,
+    then: BasicBlockId,
+}
+
 impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A, S> {
     fn terminator_graph_read_head(
         &mut self,
@@ -34,12 +87,61 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
             graph::GraphReadHead::Entity { axis } => GraphReadHead::Entity {
                 axis: self.operand(axis),
             },
+            graph::GraphReadHead::DataType { axis } => GraphReadHead::DataType {
+                axis: self.operand(axis),
+            },
+            graph::GraphReadHead::PropertyType { axis } => GraphReadHead::PropertyType {
+                axis: self.operand(axis),
+            },
+            graph::GraphReadHead::EntityType { axis } => GraphReadHead::EntityType {
+                axis: self.operand(axis),
+            },
         }
     }
 
-    const fn terminator_graph_read_tail(tail: graph::GraphReadTail) -> GraphReadTail {
+    /// Lowers a closure literal of a graph read into its body and captured environment.
+    fn terminator_graph_read_closure(
+        &mut self,
+        block: &mut CurrentBlock<'mir, 'heap>,
+        node: Node<'heap>,
+        source: Source<'heap>,
+    ) -> (DefId, Local) {
+        let NodeKind::Closure(closure) = node.kind else {
+            self.state
+                .diagnostics
+                .push(expected_closure_literal(node.span));
+
+            // Return a bogus value, so that lowering can continue
+            return (DefId::MAX, Local::MAX);
+        };
+
+        let (ptr, env) = self.transform_closure(block, node.ptr(), source, closure);
+        (ptr.value, env.value)
+    }
+
+    fn terminator_graph_read_tail(
+        &mut self,
+        block: &mut CurrentBlock<'mir, 'heap>,
+        tail: graph::GraphReadTail<'heap>,
+    ) -> GraphReadTail {
         match tail {
             graph::GraphReadTail::Collect => GraphReadTail::Collect,
+            graph::GraphReadTail::Count => GraphReadTail::Count,
+            graph::GraphReadTail::Exists => GraphReadTail::Exists,
+            graph::GraphReadTail::First => GraphReadTail::First,
+            graph::GraphReadTail::GroupBy(key) => {
+                let (ptr, env) =
+                    self.terminator_graph_read_closure(block, key, Source::Closure(key.id, None));
+                GraphReadTail::GroupBy(ptr, env)
+            }
+            graph::GraphReadTail::Aggregate(aggregate, value) => {
+                let (ptr, env) = self.terminator_graph_read_closure(
+                    block,
+                    value,
+                    Source::Closure(value.id, None),
+                );
+                GraphReadTail::Aggregate(aggregate, ptr, env)
+            }
         }
     }
 
@@ -50,23 +152,21 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
     ) -> GraphReadBody {
         match body {
             graph::GraphReadBody::Filter(filter) => {
-                let NodeKind::Closure(closure) = filter.kind else {
-                    self.state
-                        .diagnostics
-                        .push(expected_closure_filter(filter.span));
-
-                    // Return a bogus value, so that lowering can continue
-                    return GraphReadBody::Filter(DefId::MAX, Local::MAX);
-                };
-
-                let (ptr, env) = self.transform_closure(
+                let (ptr, env) = self.terminator_graph_read_closure(
                     block,
-                    filter.ptr(),
-                    Source::GraphReadFilter(filter.ptr().id),
-                    closure,
+                    filter,
+                    Source::GraphReadFilter(filter.id),
                 );
-                GraphReadBody::Filter(ptr.value, env.value)
+                GraphReadBody::Filter(ptr, env)
             }
+            graph::GraphReadBody::Traverse(traversal) => GraphReadBody::Traverse(traversal),
+            graph::GraphReadBody::SortBy(key) => {
+                let (ptr, env) =
+                    self.terminator_graph_read_closure(block, key, Source::Closure(key.id, None));
+                GraphReadBody::SortBy(ptr, env)
+            }
+            graph::GraphReadBody::Limit(count) => GraphReadBody::Limit(count),
+            graph::GraphReadBody::Offset(count) => GraphReadBody::Offset(count),
         }
     }
 
@@ -95,7 +195,7 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
     ) {
         let head = self.terminator_graph_read_head(head);
         let body = self.terminator_graph_read_bodies(block, &body);
-        let tail = Self::terminator_graph_read_tail(tail);
+        let tail = self.terminator_graph_read_tail(block, tail);
 
         let terminator = Terminator {
             span,
@@ -180,6 +280,314 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
         block.replace_params(&[destination]);
     }
 
+    /// Collects the tests and bindings of a pattern matched against the value at `place`.
+    ///
+    /// Tests are collected outside-in, so that the tests of a sub-pattern are only run once the
+    /// tests of the enclosing pattern have succeeded. This ensures that a [`Downcast`] projection
+    /// is only evaluated after the discriminant of the value has been checked.
+    ///
+    /// [`Downcast`]: ProjectionKind::Downcast
+    fn pattern_tests(
+        &self,
+        place: Place<'heap>,
+        pattern: &Pattern<'heap>,
+        tests: &mut Vec<PatternTest<'heap>>,
+        bindings: &mut Vec<PatternBinding<'heap>>,
+    ) {
+        let interner = self.context.mir.interner;
+
+        match pattern.kind {
+            PatternKind::Wildcard => {}
+            PatternKind::Binding(binder) => bindings.push(PatternBinding {
+                span: pattern.span,
+                binder,
+                place,
+                r#type: self.context.hir.map.type_id(pattern.id),
+            }),
+            PatternKind::Literal(primitive) => tests.push(PatternTest {
+                span: pattern.span,
+                place,
+                kind: PatternTestKind::Literal(Self::constant(primitive)),
+            }),
+            PatternKind::Tuple(elements) => {
+                for (index, element) in elements.iter().enumerate() {
+                    let place = place.project(
+                        interner,
+                        self.context.hir.map.type_id(element.id),
+                        ProjectionKind::Field(FieldIndex::from_usize(index)),
+                    );
+
+                    self.pattern_tests(place, element, tests, bindings);
+                }
+            }
+            PatternKind::Struct(fields) => {
+                for field in fields {
+                    let place = place.project(
+                        interner,
+                        self.context.hir.map.type_id(field.pattern.id),
+                        ProjectionKind::FieldByName(field.name.value),
+                    );
+
+                    self.pattern_tests(place, &field.pattern, tests, bindings);
+                }
+            }
+            PatternKind::Opaque(OpaquePattern {
+                name,
+                pattern: inner,
+            }) => {
+                tests.push(PatternTest {
+                    span: pattern.span,
+                    place,
+                    kind: PatternTestKind::Opaque(name),
+                });
+
+                let place = place.project(
+                    interner,
+                    self.context.hir.map.type_id(inner.id),
+                    ProjectionKind::Downcast(name),
+                );
+
+                self.pattern_tests(place, &inner, tests, bindings);
+            }
+            PatternKind::Constructor(_) => {
+                unreachable!("constructor patterns are resolved to opaque patterns during lowering")
+            }
+        }
+    }
+
+    /// Fills the reserved `block` with the evaluation of a pattern test.
+    ///
+    /// The terminator of the block is left unreachable, and is set once the block to continue
+    /// with on failure is known.
+    fn terminator_branch_match_test(
+        &mut self,
+        block: BasicBlockId,
+        PatternTest { span, place, kind }: PatternTest<'heap>,
+        then: BasicBlockId,
+    ) -> PendingTest<'heap> {
+        let builder = TypeBuilder::spanned(span, self.context.mir.env);
+
+        // Both tests produce an integer, which is switched on, and `value` on success
+        let (r#type, rvalue, value) = match kind {
+            PatternTestKind::Opaque(name) => (
+                builder.integer(),
+                RValue::Discriminant(Discriminant {
+                    operand: Operand::Place(place),
+                    variants: self.context.mir.interner.symbols.intern_slice(&[name]),
+                }),
+                0,
+            ),
+            PatternTestKind::Literal(constant) => (
+                builder.boolean(),
+                RValue::Binary(Binary {
+                    op: BinOp::Eq,
+                    left: Operand::Place(place),
+                    right: Operand::Constant(constant),
+                }),
+                1,
+            ),
+        };
+
+        let local = self.local_decls.push(LocalDecl {
+            span,
+            r#type,
+            name: None,
+        });
+
+        self.blocks[block].statements.push(Statement {
+            span,
+            kind: StatementKind::Assign(Assign {
+                lhs: Place::local(local),
+                rhs: rvalue,
+            }),
+        });
+
+        PendingTest {
+            block,
+            span,
+            discriminant: Operand::Place(Place::local(local)),
+            value,
+            then,
+        }
+    }
+
+    /// Sets the terminator of every pending test, continuing with `r#else` if the test fails.
+    fn terminator_branch_match_resolve(
+        &mut self,
+        pending: Vec<PendingTest<'heap>>,
+        r#else: BasicBlockId,
+    ) {
+        for PendingTest {
+            block,
+            span,
+            discriminant,
+            value,
+            then,
+        } in pending
+        {
+            self.blocks[block].terminator = Terminator {
+                span,
+                kind: TerminatorKind::SwitchInt(SwitchInt {
+                    discriminant,
+                    targets: SwitchTargets::new(
+                        self.context.mir.heap,
+                        [(value, Target::block(then))],
+                        Some(Target::block(r#else)),
+                    ),
+                }),
+            };
+        }
+    }
+
+    fn unreachable_block(&mut self, span: SpanId) -> BasicBlockId {
+        self.blocks.push(BasicBlock {
+            params: Interned::empty(),
+            statements: heap::Vec::new_in(self.context.mir.heap),
+            terminator: Terminator {
+                span,
+                kind: TerminatorKind::Unreachable,
+            },
+        })
+    }
+
+    /// Lowers a single arm of a match expression.
+    ///
+    /// Returns the entry block of the arm, the exit block of its body, which jumps to the block
+    /// following the match expression, and the tests of the arm, which still need to be told
+    /// where to continue on failure.
+    fn terminator_branch_match_arm(
+        &mut self,
+        place: Place<'heap>,
+        arm: &MatchArm<'heap>,
+    ) -> (BasicBlockId, ForwardRef, Vec<PendingTest<'heap>>) {
+        let mut tests = Vec::new();
+        let mut bindings = Vec::new();
+        self.pattern_tests(place, &arm.pattern, &mut tests, &mut bindings);
+
+        // Reserve the test blocks first, so that they precede the body of the arm
+        let test_blocks: Vec<_> = tests
+            .iter()
+            .map(|test| self.unreachable_block(test.span))
+            .collect();
+
+        let mut body = CurrentBlock::new(self.context.mir.heap, self.context.mir.interner);
+
+        for PatternBinding {
+            span,
+            binder,
+            place,
+            r#type,
+        } in bindings
+        {
+            let local = self.local_decls.push(LocalDecl {
+                span,
+                r#type,
+                name: binder.name,
+            });
+            self.locals.insert(binder.id, local);
+
+            body.push_statement(Statement {
+                span,
+                kind: StatementKind::Assign(Assign {
+                    lhs: Place::local(local),
+                    rhs: RValue::Load(Operand::Place(place)),
+                }),
+            });
+        }
+
+        let operand = self.transform_body(&mut body, arm.body);
+        let (body_entry, body_exit) = body.finish_goto(
+            operand.span,
+            BasicBlockId::PLACEHOLDER,
+            &[operand.value],
+            &mut self.blocks,
+        );
+
+        // Every test continues with the next test on success, and the last test with the body
+        let mut then = body_entry.into();
+        let mut pending = Vec::with_capacity(tests.len());
+
+        for (test, block) in tests.into_iter().zip(test_blocks).rev() {
+            pending.push(self.terminator_branch_match_test(block, test, then));
+            then = block;
+        }
+
+        (then, ForwardRef::goto(body_exit), pending)
+    }
+
+    /// Lowers a match expression into a chain of pattern tests.
+    ///
+    /// Every arm first tests its pattern against the scrutinee, and continues with the next arm
+    /// if any of the tests fail. Exhaustiveness checking ensures that the last arm always
+    /// matches, the fallthrough of the last arm is therefore unreachable.
+    pub(super) fn terminator_branch_match(
+        &mut self,
+        block: &mut CurrentBlock<'mir, 'heap>,
+        destination: Local,
+        span: SpanId,
+        branch::Match { scrutinee, arms }: branch::Match<'heap>,
+    ) {
+        let place = match self.operand(scrutinee) {
+            Operand::Place(place) => place,
+            Operand::Constant(constant) => {
+                // Patterns are tested through projections, therefore the scrutinee must be a place
+                let local = self.local_decls.push(LocalDecl {
+                    span: scrutinee.span,
+                    r#type: self.context.hir.map.type_id(scrutinee.id),
+                    name: None,
+                });
+
+                block.push_statement(Statement {
+                    span: scrutinee.span,
+                    kind: StatementKind::Assign(Assign {
+                        lhs: Place::local(local),
+                        rhs: RValue::Load(Operand::Constant(constant)),
+                    }),
+                });
+
+                Place::local(local)
+            }
+        };
+
+        // make sure that we reserve a slot **before** we finish the arms
+        block.reserve(&mut self.blocks);
+
+        let mut entry = BasicBlockId::PLACEHOLDER;
+        let mut exits = Vec::with_capacity(arms.len());
+        let mut pending = Vec::new();
+
+        for (index, arm) in arms.iter().enumerate() {
+            let (arm_entry, exit, tests) = self.terminator_branch_match_arm(place, arm);
+
+            if index == 0 {
+                entry = arm_entry;
+            }
+
+            // The tests of the previous arm continue with this arm on failure
+            self.terminator_branch_match_resolve(mem::replace(&mut pending, tests), arm_entry);
+            exits.push(exit);
+        }
+
+        if !pending.is_empty() {
+            let unreachable = self.unreachable_block(span);
+            self.terminator_branch_match_resolve(pending, unreachable);
+        }
+
+        block.terminate(
+            Terminator {
+                span,
+                kind: TerminatorKind::Goto(Goto {
+                    target: Target::block(entry),
+                }),
+            },
+            |_| exits,
+            &mut self.blocks,
+        );
+
+        // Change the new block to take a single argument, which is where to store the result
+        block.replace_params(&[destination]);
+    }
+
     pub(super) fn terminator_branch(
         &mut self,
         block: &mut CurrentBlock<'mir, 'heap>,
@@ -189,6 +597,9 @@ impl<'mir, 'heap, A: Allocator, S: Allocator> Reifier<'_, 'mir, '_, '_, 'heap, A
     ) {
         match branch {
             branch::Branch::If(r#if) => self.terminator_branch_if(block, destination, span, r#if),
+            branch::Branch::Match(r#match) => {
+                self.terminator_branch_match(block, destination, span, r#match);
+            }
         }
     }
 }
@@ -25,7 +25,9 @@ use crate::{
             Place, PlaceContext, PlaceLivenessContext, PlaceReadContext, PlaceRef,
             PlaceWriteContext, Projection, ProjectionKind,
         },
-        rvalue::{Aggregate, AggregateKind, Apply, Binary, Input, RValue, Unary},
+        rvalue::{
+            Aggregate, AggregateKind, Apply, Binary, Discriminant, Input, Intrinsic, RValue, Unary,
+        },
         statement::{Assign, Statement, StatementKind},
         terminator::{
             Goto, GraphRead, GraphReadBody, GraphReadHead, GraphReadLocation, GraphReadTail,
@@ -435,6 +437,22 @@ pub trait VisitorMut<'heap> {
         walk_rvalue_input(self, location, input)
     }
 
+    fn visit_rvalue_intrinsic(
+        &mut self,
+        location: Location,
+        intrinsic: &mut Intrinsic<'heap>,
+    ) -> Self::Result<()> {
+        walk_rvalue_intrinsic(self, location, intrinsic)
+    }
+
+    fn visit_rvalue_discriminant(
+        &mut self,
+        location: Location,
+        discriminant: &mut Discriminant<'heap>,
+    ) -> Self::Result<()> {
+        walk_rvalue_discriminant(self, location, discriminant)
+    }
+
     fn visit_rvalue_apply(
         &mut self,
         location: Location,
@@ -724,7 +742,9 @@ pub fn walk_projection<'heap, T: VisitorMut<'heap> + ?Sized>(
 
     match &mut kind {
         ProjectionKind::Field(_) => {}
-        ProjectionKind::FieldByName(name) => visitor.visit_symbol(location, name)?,
+        ProjectionKind::FieldByName(name) | ProjectionKind::Downcast(name) => {
+            visitor.visit_symbol(location, name)?;
+        }
         ProjectionKind::Index(local) => {
             visitor.visit_local(location, PlaceContext::Read(PlaceReadContext::Load), local)?;
         }
@@ -887,6 +907,10 @@ pub fn walk_rvalue<'heap, T: VisitorMut<'heap> + ?Sized>(
         RValue::Unary(unary) => visitor.visit_rvalue_unary(location, unary),
         RValue::Aggregate(aggregate) => visitor.visit_rvalue_aggregate(location, aggregate),
         RValue::Input(input) => visitor.visit_rvalue_input(location, input),
+        RValue::Intrinsic(intrinsic) => visitor.visit_rvalue_intrinsic(location, intrinsic),
+        RValue::Discriminant(discriminant) => {
+            visitor.visit_rvalue_discriminant(location, discriminant)
+        }
         RValue::Apply(apply) => visitor.visit_rvalue_apply(location, apply),
     }
 }
@@ -957,6 +981,31 @@ pub fn walk_rvalue_input<'heap, T: VisitorMut<'heap> + ?Sized>(
     Ok!()
 }
 
+pub fn walk_rvalue_intrinsic<'heap, T: VisitorMut<'heap> + ?Sized>(
+    visitor: &mut T,
+    location: Location,
+    Intrinsic { op: _, arguments }: &mut Intrinsic<'heap>,
+) -> T::Result<()> {
+    for argument in arguments.iter_mut() {
+        visitor.visit_operand(location, argument)?;
+    }
+
+    Ok!()
+}
+
+pub fn walk_rvalue_discriminant<'heap, T: VisitorMut<'heap> + ?Sized>(
+    visitor: &mut T,
+    location: Location,
+    Discriminant {
+        operand,
+        variants: _,
+    }: &mut Discriminant<'heap>,
+) -> T::Result<()> {
+    visitor.visit_operand(location, operand)?;
+
+    Ok!()
+}
+
 pub fn walk_rvalue_apply<'heap, T: VisitorMut<'heap> + ?Sized>(
     visitor: &mut T,
     location: Location,
@@ -1061,9 +1110,7 @@ pub fn walk_graph_read_head<'heap, T: VisitorMut<'heap> + ?Sized>(
     location: GraphReadLocation,
     head: &mut GraphReadHead<'heap>,
 ) -> T::Result<()> {
-    match head {
-        GraphReadHead::Entity { axis } => visitor.visit_operand(location.base, axis),
-    }
+    visitor.visit_operand(location.base, head.axis_mut())
 }
 
 pub fn walk_graph_read_body<'heap, T: VisitorMut<'heap> + ?Sized>(
@@ -1072,7 +1119,7 @@ pub fn walk_graph_read_body<'heap, T: VisitorMut<'heap> + ?Sized>(
     body: &mut GraphReadBody,
 ) -> T::Result<()> {
     match body {
-        GraphReadBody::Filter(func, env) => {
+        GraphReadBody::Filter(func, env) | GraphReadBody::SortBy(func, env) => {
             visitor.visit_def_id(location.base, func)?;
             visitor.visit_local(
                 location.base,
@@ -1080,16 +1127,27 @@ pub fn walk_graph_read_body<'heap, T: VisitorMut<'heap> + ?Sized>(
                 env,
             )
         }
+        GraphReadBody::Traverse(_) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => Ok!(),
     }
 }
 
-#[expect(clippy::needless_pass_by_ref_mut, reason = "API uniformity")]
 pub fn walk_graph_read_tail<'heap, T: VisitorMut<'heap> + ?Sized>(
-    _visitor: &mut T,
-    _location: GraphReadLocation,
+    visitor: &mut T,
+    location: GraphReadLocation,
     tail: &mut GraphReadTail,
 ) -> T::Result<()> {
     match tail {
-        GraphReadTail::Collect => Ok!(),
+        GraphReadTail::Collect
+        | GraphReadTail::Count
+        | GraphReadTail::Exists
+        | GraphReadTail::First => Ok!(),
+        GraphReadTail::GroupBy(func, env) | GraphReadTail::Aggregate(_, func, env) => {
+            visitor.visit_def_id(location.base, func)?;
+            visitor.visit_local(
+                location.base,
+                PlaceContext::Read(PlaceReadContext::Load),
+                env,
+            )
+        }
     }
 }
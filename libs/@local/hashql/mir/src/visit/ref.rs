@@ -16,7 +16,9 @@ use crate::{
             Place, PlaceContext, PlaceLivenessContext, PlaceReadContext, PlaceRef,
             PlaceWriteContext, Projection, ProjectionKind,
         },
-        rvalue::{Aggregate, AggregateKind, Apply, Binary, Input, RValue, Unary},
+        rvalue::{
+            Aggregate, AggregateKind, Apply, Binary, Discriminant, Input, Intrinsic, RValue, Unary,
+        },
         statement::{Assign, Statement, StatementKind},
         terminator::{
             Goto, GraphRead, GraphReadBody, GraphReadHead, GraphReadLocation, GraphReadTail,
@@ -272,6 +274,22 @@ pub trait Visitor<'heap> {
         walk_rvalue_input(self, location, input)
     }
 
+    fn visit_rvalue_intrinsic(
+        &mut self,
+        location: Location,
+        intrinsic: &Intrinsic<'heap>,
+    ) -> Self::Result {
+        walk_rvalue_intrinsic(self, location, intrinsic)
+    }
+
+    fn visit_rvalue_discriminant(
+        &mut self,
+        location: Location,
+        discriminant: &Discriminant<'heap>,
+    ) -> Self::Result {
+        walk_rvalue_discriminant(self, location, discriminant)
+    }
+
     fn visit_rvalue_apply(&mut self, location: Location, apply: &Apply<'heap>) -> Self::Result {
         walk_rvalue_apply(self, location, apply)
     }
@@ -320,7 +338,6 @@ pub trait Visitor<'heap> {
         walk_graph_read_head(self, location, head)
     }
 
-    #[expect(clippy::trivially_copy_pass_by_ref)]
     fn visit_graph_read_body(
         &mut self,
         location: GraphReadLocation,
@@ -447,7 +464,9 @@ pub fn walk_projection<'heap, T: Visitor<'heap> + ?Sized>(
 
     match kind {
         ProjectionKind::Field(_) => Ok!(),
-        ProjectionKind::FieldByName(name) => visitor.visit_symbol(location, name),
+        ProjectionKind::FieldByName(name) | ProjectionKind::Downcast(name) => {
+            visitor.visit_symbol(location, name)
+        }
         ProjectionKind::Index(local) => {
             visitor.visit_local(location, PlaceContext::Read(PlaceReadContext::Load), local)
         }
@@ -587,6 +606,10 @@ pub fn walk_rvalue<'heap, T: Visitor<'heap> + ?Sized>(
         RValue::Unary(unary) => visitor.visit_rvalue_unary(location, unary),
         RValue::Aggregate(aggregate) => visitor.visit_rvalue_aggregate(location, aggregate),
         RValue::Input(input) => visitor.visit_rvalue_input(location, input),
+        RValue::Intrinsic(intrinsic) => visitor.visit_rvalue_intrinsic(location, intrinsic),
+        RValue::Discriminant(discriminant) => {
+            visitor.visit_rvalue_discriminant(location, discriminant)
+        }
         RValue::Apply(apply) => visitor.visit_rvalue_apply(location, apply),
     }
 }
@@ -648,6 +671,31 @@ pub fn walk_rvalue_input<'heap, T: Visitor<'heap> + ?Sized>(
     Ok!()
 }
 
+pub fn walk_rvalue_intrinsic<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    location: Location,
+    Intrinsic { op: _, arguments }: &Intrinsic<'heap>,
+) -> T::Result {
+    for argument in arguments {
+        visitor.visit_operand(location, argument)?;
+    }
+
+    Ok!()
+}
+
+pub fn walk_rvalue_discriminant<'heap, T: Visitor<'heap> + ?Sized>(
+    visitor: &mut T,
+    location: Location,
+    Discriminant {
+        operand,
+        variants: _,
+    }: &Discriminant<'heap>,
+) -> T::Result {
+    visitor.visit_operand(location, operand)?;
+
+    Ok!()
+}
+
 pub fn walk_rvalue_apply<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     location: Location,
@@ -752,19 +800,16 @@ pub fn walk_graph_read_head<'heap, T: Visitor<'heap> + ?Sized>(
     location: GraphReadLocation,
     head: &GraphReadHead<'heap>,
 ) -> T::Result {
-    match head {
-        GraphReadHead::Entity { axis } => visitor.visit_operand(location.base, axis),
-    }
+    visitor.visit_operand(location.base, head.axis())
 }
 
-#[expect(clippy::trivially_copy_pass_by_ref)]
 pub fn walk_graph_read_body<'heap, T: Visitor<'heap> + ?Sized>(
     visitor: &mut T,
     location: GraphReadLocation,
     body: &GraphReadBody,
 ) -> T::Result {
     match body {
-        GraphReadBody::Filter(func, env) => {
+        GraphReadBody::Filter(func, env) | GraphReadBody::SortBy(func, env) => {
             visitor.visit_def_id(location.base, *func)?;
             visitor.visit_local(
                 location.base,
@@ -772,15 +817,27 @@ pub fn walk_graph_read_body<'heap, T: Visitor<'heap> + ?Sized>(
                 *env,
             )
         }
+        GraphReadBody::Traverse(_) | GraphReadBody::Limit(_) | GraphReadBody::Offset(_) => Ok!(),
     }
 }
 
 pub fn walk_graph_read_tail<'heap, T: Visitor<'heap> + ?Sized>(
-    _visitor: &mut T,
-    _location: GraphReadLocation,
+    visitor: &mut T,
+    location: GraphReadLocation,
     tail: GraphReadTail,
 ) -> T::Result {
     match tail {
-        GraphReadTail::Collect => Ok!(),
+        GraphReadTail::Collect
+        | GraphReadTail::Count
+        | GraphReadTail::Exists
+        | GraphReadTail::First => Ok!(),
+        GraphReadTail::GroupBy(func, env) | GraphReadTail::Aggregate(_, func, env) => {
+            visitor.visit_def_id(location.base, func)?;
+            visitor.visit_local(
+                location.base,
+                PlaceContext::Read(PlaceReadContext::Load),
+                env,
+            )
+        }
     }
 }
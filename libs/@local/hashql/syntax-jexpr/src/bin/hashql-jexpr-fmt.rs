@@ -0,0 +1,118 @@
+//! Formats HashQL queries written in J-Expr.
+//!
+//! ## Workspace dependencies
+#![doc = simple_mermaid::mermaid!("../../docs/dependency-diagram.mmd")]
+
+use std::{
+    fs,
+    io::{self, IsTerminal as _, Read as _, Write as _},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser as _;
+use hashql_core::{heap::Heap, span::SpanTable};
+use hashql_diagnostics::{
+    diagnostic::render::{ColorDepth, Format, RenderOptions},
+    source::{Source, SourceId, Sources},
+};
+use hashql_syntax_jexpr::Parser;
+
+#[derive(Debug, clap::Parser)]
+#[command(
+    name = "hashql-jexpr-fmt",
+    about = "Formats HashQL queries written in J-Expr, keeping comments."
+)]
+struct Cli {
+    /// The files to format in place.
+    ///
+    /// If no files are given, the query is read from stdin and the formatted query is written to
+    /// stdout.
+    files: Vec<PathBuf>,
+
+    /// Only check if the queries are formatted, without modifying them.
+    ///
+    /// Exits with a non-zero status if any query is not formatted.
+    #[arg(long)]
+    check: bool,
+}
+
+/// Formats the source, rendering the diagnostic if the source cannot be parsed.
+fn format(path: Option<&Path>, source: &str) -> Result<String, String> {
+    let heap = Heap::new();
+    let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+    let mut parser = Parser::new(&heap, &mut spans);
+
+    let diagnostic = match parser.format(source) {
+        Ok(formatted) => return Ok(formatted),
+        Err(diagnostic) => diagnostic,
+    };
+
+    let mut sources = Sources::new();
+    let mut file = Source::new(source);
+    if let Some(path) = path {
+        file = file.with_path(path.display().to_string());
+    }
+    sources.push(file);
+
+    let mut options = RenderOptions::new(Format::Ansi, &sources);
+    if !io::stderr().is_terminal() {
+        options.color_depth = ColorDepth::Monochrome;
+    }
+
+    Err(diagnostic.render(options, &mut &spans))
+}
+
+fn run(cli: &Cli) -> io::Result<bool> {
+    let mut stderr = io::stderr().lock();
+
+    if cli.files.is_empty() {
+        let mut source = String::new();
+        io::stdin().read_to_string(&mut source)?;
+
+        return match format(None, &source) {
+            Ok(formatted) if cli.check => Ok(formatted == source),
+            Ok(formatted) => {
+                io::stdout().write_all(formatted.as_bytes())?;
+                Ok(true)
+            }
+            Err(diagnostic) => {
+                writeln!(stderr, "{diagnostic}")?;
+                Ok(false)
+            }
+        };
+    }
+
+    let mut success = true;
+
+    for path in &cli.files {
+        let source = fs::read_to_string(path)?;
+
+        match format(Some(path), &source) {
+            Ok(formatted) if formatted == source => {}
+            Ok(_) if cli.check => {
+                writeln!(stderr, "{} is not formatted", path.display())?;
+                success = false;
+            }
+            Ok(formatted) => fs::write(path, formatted)?,
+            Err(diagnostic) => {
+                writeln!(stderr, "{diagnostic}")?;
+                success = false;
+            }
+        }
+    }
+
+    Ok(success)
+}
+
+fn main() -> io::Result<ExitCode> {
+    let cli = Cli::parse();
+
+    let code = if run(&cli)? {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    };
+
+    Ok(code)
+}
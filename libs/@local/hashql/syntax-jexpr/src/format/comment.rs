@@ -0,0 +1,62 @@
+use text_size::{TextRange, TextSize};
+
+/// A comment in the source, the lexer skips these, so they are recovered separately.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Comment<'source> {
+    pub range: TextRange,
+    pub text: &'source str,
+}
+
+fn comment(source: &str, start: usize, end: usize) -> Option<Comment<'_>> {
+    let text = source.get(start..end)?.trim_end();
+
+    Some(Comment {
+        range: TextRange::new(
+            TextSize::try_from(start).ok()?,
+            TextSize::try_from(start + text.len()).ok()?,
+        ),
+        text,
+    })
+}
+
+/// Collects all comments of a source, in the order they appear in.
+///
+/// The source is expected to be valid J-Expr, comments are only recognized outside of strings.
+pub(crate) fn collect(source: &str) -> Vec<Comment<'_>> {
+    let bytes = source.as_bytes();
+    let mut comments = Vec::new();
+
+    let mut index = 0;
+    while let Some(&byte) = bytes.get(index) {
+        match (byte, bytes.get(index + 1)) {
+            (b'"', _) => {
+                index += 1;
+
+                while let Some(&byte) = bytes.get(index) {
+                    index += if byte == b'\\' { 2 } else { 1 };
+
+                    if byte == b'"' {
+                        break;
+                    }
+                }
+            }
+            (b'/', Some(b'/')) => {
+                let end =
+                    memchr::memchr(b'\n', &bytes[index..]).map_or(bytes.len(), |end| index + end);
+
+                comments.extend(comment(source, index, end));
+                index = end;
+            }
+            (b'/', Some(b'*')) => {
+                let end = memchr::memmem::find(&bytes[index + 2..], b"*/")
+                    .map_or(bytes.len(), |end| index + 2 + end + 2);
+
+                comments.extend(comment(source, index, end));
+                index = end;
+            }
+            _ => index += 1,
+        }
+    }
+
+    comments
+}
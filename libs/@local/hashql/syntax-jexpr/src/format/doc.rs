@@ -0,0 +1,248 @@
+use super::comment::Comment;
+
+/// The maximum width of a line, the same as the default of prettier.
+const WIDTH: usize = 80;
+const INDENT: usize = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Delimiter {
+    Bracket,
+    Brace,
+}
+
+impl Delimiter {
+    const fn open(self) -> &'static str {
+        match self {
+            Self::Bracket => "[",
+            Self::Brace => "{",
+        }
+    }
+
+    const fn close(self) -> &'static str {
+        match self {
+            Self::Bracket => "]",
+            Self::Brace => "}",
+        }
+    }
+
+    /// The padding between the delimiters and the elements, if printed on a single line.
+    const fn padding(self) -> &'static str {
+        match self {
+            Self::Bracket => "",
+            Self::Brace => " ",
+        }
+    }
+}
+
+/// An entry of an array or object, together with the comments surrounding it.
+#[derive(Debug)]
+pub(crate) struct Element<'source> {
+    pub leading: Vec<Comment<'source>>,
+    pub key: Option<String>,
+    pub doc: Doc<'source>,
+    pub trailing: Option<Comment<'source>>,
+}
+
+impl<'source> Element<'source> {
+    pub(crate) const fn new(key: Option<String>, doc: Doc<'source>) -> Self {
+        Self {
+            leading: Vec::new(),
+            key,
+            doc,
+            trailing: None,
+        }
+    }
+
+    fn flat_width(&self) -> Option<usize> {
+        if !self.leading.is_empty() || self.trailing.is_some() {
+            return None;
+        }
+
+        let key = self.key.as_ref().map_or(0, |key| key.chars().count() + 2);
+
+        Some(key + self.doc.flat_width()?)
+    }
+}
+
+/// The layout of a J-Expr document.
+///
+/// Groups are printed on a single line if they fit, otherwise every element is put on its own
+/// line, mirroring the output of prettier for JSON.
+#[derive(Debug)]
+pub(crate) enum Doc<'source> {
+    Text(String),
+    Group {
+        delimiter: Delimiter,
+        elements: Vec<Element<'source>>,
+        /// Comments after the last element.
+        dangling: Vec<Comment<'source>>,
+    },
+}
+
+impl Doc<'_> {
+    /// The width of the document on a single line, if it can be printed on one.
+    fn flat_width(&self) -> Option<usize> {
+        match self {
+            Self::Text(text) => Some(text.chars().count()),
+            Self::Group {
+                delimiter: _,
+                elements,
+                dangling,
+            } if elements.is_empty() && dangling.is_empty() => Some(2),
+            Self::Group {
+                delimiter,
+                elements,
+                dangling,
+            } => {
+                if !dangling.is_empty() {
+                    return None;
+                }
+
+                let mut width = 2 + delimiter.padding().len() * 2 + (elements.len() - 1) * 2;
+                for element in elements {
+                    width += element.flat_width()?;
+                }
+
+                Some(width)
+            }
+        }
+    }
+}
+
+pub(crate) struct Printer {
+    output: String,
+    column: usize,
+}
+
+impl Printer {
+    pub(crate) const fn new() -> Self {
+        Self {
+            output: String::new(),
+            column: 0,
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        self.output.push('\n');
+        self.output
+    }
+
+    fn write(&mut self, text: &str) {
+        self.output.push_str(text);
+
+        match text.rsplit_once('\n') {
+            Some((_, last)) => self.column = last.chars().count(),
+            None => self.column += text.chars().count(),
+        }
+    }
+
+    pub(crate) fn newline(&mut self, indent: usize) {
+        self.output.push('\n');
+        self.output.extend(core::iter::repeat_n(' ', indent));
+        self.column = indent;
+    }
+
+    pub(crate) fn comment(&mut self, comment: &Comment<'_>) {
+        self.write(comment.text);
+    }
+
+    pub(crate) fn trailing(&mut self, comment: &Comment<'_>) {
+        self.write(" ");
+        self.write(comment.text);
+    }
+
+    fn flat(&mut self, doc: &Doc<'_>) {
+        match doc {
+            Doc::Text(text) => self.write(text),
+            Doc::Group {
+                delimiter,
+                elements,
+                dangling: _,
+            } => {
+                self.write(delimiter.open());
+
+                if !elements.is_empty() {
+                    self.write(delimiter.padding());
+                }
+
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+
+                    if let Some(key) = &element.key {
+                        self.write(key);
+                        self.write(": ");
+                    }
+
+                    self.flat(&element.doc);
+                }
+
+                if !elements.is_empty() {
+                    self.write(delimiter.padding());
+                }
+
+                self.write(delimiter.close());
+            }
+        }
+    }
+
+    /// Prints the document at the current position.
+    ///
+    /// `suffix` is the width of the text that follows the document on the same line.
+    pub(crate) fn print(&mut self, doc: &Doc<'_>, indent: usize, suffix: usize) {
+        if let Some(width) = doc.flat_width()
+            && self.column + width + suffix <= WIDTH
+        {
+            self.flat(doc);
+            return;
+        }
+
+        let Doc::Group {
+            delimiter,
+            elements,
+            dangling,
+        } = doc
+        else {
+            // Text cannot be broken up
+            self.flat(doc);
+            return;
+        };
+
+        let inner = indent + INDENT;
+        self.write(delimiter.open());
+
+        for (index, element) in elements.iter().enumerate() {
+            let last = index + 1 == elements.len();
+
+            for comment in &element.leading {
+                self.newline(inner);
+                self.comment(comment);
+            }
+
+            self.newline(inner);
+            if let Some(key) = &element.key {
+                self.write(key);
+                self.write(": ");
+            }
+
+            self.print(&element.doc, inner, usize::from(!last));
+
+            if !last {
+                self.write(",");
+            }
+
+            if let Some(comment) = &element.trailing {
+                self.trailing(comment);
+            }
+        }
+
+        for comment in dangling {
+            self.newline(inner);
+            self.comment(comment);
+        }
+
+        self.newline(indent);
+        self.write(delimiter.close());
+    }
+}
@@ -0,0 +1,907 @@
+//! Canonical formatting of J-Expr documents.
+//!
+//! The parsed expression is printed back using the same layout as prettier uses for JSON, so that
+//! formatted queries are stable under both tools. Comments are recovered from the source and
+//! attached to the closest element by position, field and index accesses are printed using the
+//! string sugar (`"a.b"`, `"a[0]"`) wherever it is able to represent them.
+
+mod comment;
+mod doc;
+
+use core::fmt::Write as _;
+
+use hashql_ast::node::{
+    expr::{
+        CallExpr, DictExpr, Expr, ExprKind, FieldExpr, IndexExpr, ListExpr, LiteralExpr,
+        StructExpr, TupleExpr,
+        call::{Argument, LabeledArgument},
+    },
+    generic::GenericConstraint,
+    path::{Path, PathSegmentArgument},
+    r#type::{Type, TypeKind},
+};
+use hashql_core::{
+    span::{SpanId, SpanTable, TextRange, TextSize},
+    symbol::{Ident, IdentKind, Symbol, sym},
+    value::Primitive,
+};
+
+use self::{
+    comment::Comment,
+    doc::{Delimiter, Doc, Element, Printer},
+};
+use crate::span::Span;
+
+/// Quotes a value as a JSON string.
+fn quote(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(output, "\\u{:04x}", u32::from(char));
+            }
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+/// Writes an identifier, symbols are only written without backticks if `bare` is set, as they
+/// would otherwise be ambiguous with the surrounding syntax.
+fn write_ident(output: &mut String, ident: Ident<'_>, bare: bool) {
+    match ident.kind {
+        IdentKind::Lexical => output.push_str(ident.value.as_str()),
+        IdentKind::Symbol if bare => output.push_str(ident.value.as_str()),
+        IdentKind::Symbol | IdentKind::BaseUrl => {
+            output.push('`');
+            output.push_str(ident.value.as_str());
+            output.push('`');
+        }
+    }
+}
+
+fn write_constraint(output: &mut String, constraint: &GenericConstraint<'_>) {
+    write_ident(output, constraint.name, false);
+
+    if let Some(bound) = &constraint.bound {
+        output.push_str(": ");
+        write_type(output, bound);
+    }
+}
+
+/// Writes a path, `standalone` is set if nothing follows the path in the string.
+fn write_path(output: &mut String, path: &Path<'_>, standalone: bool) {
+    if path.rooted {
+        output.push_str("::");
+    }
+
+    let bare = standalone
+        && !path.rooted
+        && matches!(path.segments.as_slice(), [segment] if segment.arguments.is_empty());
+
+    for (index, segment) in path.segments.iter().enumerate() {
+        if index > 0 {
+            output.push_str("::");
+        }
+
+        write_ident(output, segment.name, bare);
+
+        if segment.arguments.is_empty() {
+            continue;
+        }
+
+        output.push('<');
+        for (index, argument) in segment.arguments.iter().enumerate() {
+            if index > 0 {
+                output.push_str(", ");
+            }
+
+            match argument {
+                PathSegmentArgument::Argument(argument) => write_type(output, &argument.r#type),
+                PathSegmentArgument::Constraint(constraint) => {
+                    write_constraint(output, constraint);
+                }
+            }
+        }
+        output.push('>');
+    }
+}
+
+fn write_type_operand(output: &mut String, r#type: &Type<'_>, parenthesize: bool) {
+    if parenthesize {
+        output.push('(');
+        write_type(output, r#type);
+        output.push(')');
+    } else {
+        write_type(output, r#type);
+    }
+}
+
+fn write_type(output: &mut String, r#type: &Type<'_>) {
+    match &r#type.kind {
+        TypeKind::Infer => output.push('_'),
+        TypeKind::Path(path) => write_path(output, path, false),
+        TypeKind::Tuple(tuple) => {
+            output.push('(');
+            for (index, field) in tuple.fields.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(", ");
+                }
+
+                write_type(output, &field.r#type);
+            }
+
+            if tuple.fields.len() == 1 {
+                output.push(',');
+            }
+            output.push(')');
+        }
+        TypeKind::Struct(r#struct) if r#struct.fields.is_empty() => output.push_str("(:)"),
+        TypeKind::Struct(r#struct) => {
+            output.push('(');
+            for (index, field) in r#struct.fields.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(", ");
+                }
+
+                write_ident(output, field.name, true);
+                output.push_str(": ");
+                write_type(output, &field.r#type);
+            }
+            output.push(')');
+        }
+        // Unions bind tighter than intersections, only nested intersections need to be
+        // parenthesized
+        TypeKind::Union(union) => {
+            for (index, r#type) in union.types.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(" | ");
+                }
+
+                write_type_operand(
+                    output,
+                    r#type,
+                    matches!(r#type.kind, TypeKind::Union(_) | TypeKind::Intersection(_)),
+                );
+            }
+        }
+        TypeKind::Intersection(intersection) => {
+            for (index, r#type) in intersection.types.iter().enumerate() {
+                if index > 0 {
+                    output.push_str(" & ");
+                }
+
+                write_type_operand(
+                    output,
+                    r#type,
+                    matches!(r#type.kind, TypeKind::Intersection(_)),
+                );
+            }
+        }
+        TypeKind::Dummy => unreachable!("the parser does not produce dummy types"),
+    }
+}
+
+/// Returns the integer if it can be written as part of the string sugar.
+fn sugar_integer<'heap>(expr: &Expr<'heap>) -> Option<&'heap str> {
+    if let ExprKind::Literal(LiteralExpr {
+        kind: Primitive::Integer(integer),
+        r#type: None,
+        ..
+    }) = &expr.kind
+        && integer.as_usize().is_some()
+    {
+        Some(integer.as_symbol().unwrap())
+    } else {
+        None
+    }
+}
+
+/// Returns the value and argument of a call to the access or index special form, together with
+/// whether it is a field access.
+fn access_form<'call, 'heap>(
+    call: &'call CallExpr<'heap>,
+) -> Option<(&'call Expr<'heap>, &'call Expr<'heap>, bool)> {
+    let ExprKind::Path(path) = &call.function.kind else {
+        return None;
+    };
+
+    let [segment] = path.segments.as_slice() else {
+        return None;
+    };
+
+    let [value, argument] = call.arguments.as_slice() else {
+        return None;
+    };
+
+    if path.rooted || !segment.arguments.is_empty() || !call.labeled_arguments.is_empty() {
+        return None;
+    }
+
+    let name = segment.name.value;
+    if name == sym::access || name == sym::symbol::dot {
+        Some((&value.value, &argument.value, true))
+    } else if name == sym::index || name == sym::symbol::brackets {
+        Some((&value.value, &argument.value, false))
+    } else {
+        None
+    }
+}
+
+/// Writes an expression using the string sugar, if it can be represented by it.
+fn write_sugar(output: &mut String, expr: &Expr<'_>, standalone: bool) -> Option<()> {
+    let (value, argument, is_field) = match &expr.kind {
+        ExprKind::Path(path) => {
+            write_path(output, path, standalone);
+            return Some(());
+        }
+        ExprKind::Field(FieldExpr { value, field, .. }) => {
+            write_sugar(output, value, false)?;
+            output.push('.');
+            write_ident(output, *field, false);
+            return Some(());
+        }
+        ExprKind::Index(IndexExpr { value, index, .. }) => (&**value, &**index, false),
+        ExprKind::Call(call) => access_form(call)?,
+        ExprKind::Struct(_)
+        | ExprKind::Dict(_)
+        | ExprKind::Tuple(_)
+        | ExprKind::List(_)
+        | ExprKind::Literal(_)
+        | ExprKind::Let(_)
+        | ExprKind::Type(_)
+        | ExprKind::NewType(_)
+        | ExprKind::Input(_)
+        | ExprKind::Closure(_)
+        | ExprKind::If(_)
+        | ExprKind::Match(_)
+        | ExprKind::As(_)
+        | ExprKind::Underscore
+        | ExprKind::Dummy => return None,
+    };
+
+    let mut sugar = String::new();
+    write_sugar(&mut sugar, value, false)?;
+
+    if let Some(integer) = sugar_integer(argument) {
+        sugar.push(if is_field { '.' } else { '[' });
+        sugar.push_str(integer);
+    } else if is_field {
+        let ExprKind::Path(path) = &argument.kind else {
+            return None;
+        };
+
+        let [segment] = path.segments.as_slice() else {
+            return None;
+        };
+
+        if path.rooted || !segment.arguments.is_empty() {
+            return None;
+        }
+
+        sugar.push('.');
+        write_ident(&mut sugar, segment.name, false);
+    } else {
+        sugar.push('[');
+        write_sugar(&mut sugar, argument, false)?;
+    }
+
+    if !is_field {
+        sugar.push(']');
+    }
+
+    output.push_str(&sugar);
+    Some(())
+}
+
+fn sugar(expr: &Expr<'_>) -> Option<String> {
+    let mut output = String::new();
+    write_sugar(&mut output, expr, true)?;
+
+    Some(output)
+}
+
+fn type_string(r#type: &Type<'_>) -> String {
+    let mut output = String::new();
+    write_type(&mut output, r#type);
+
+    quote(&output)
+}
+
+fn ident_string(ident: Ident<'_>) -> String {
+    let mut output = String::new();
+    write_ident(&mut output, ident, true);
+
+    quote(&output)
+}
+
+fn label_string(ident: Ident<'_>) -> String {
+    let mut output = String::from(":");
+    write_ident(&mut output, ident, true);
+
+    quote(&output)
+}
+
+fn literal_string(literal: &Primitive<'_>) -> String {
+    match literal {
+        Primitive::Null => "null".to_owned(),
+        Primitive::Boolean(value) => value.to_string(),
+        Primitive::Float(float) => float.as_symbol().as_str().to_owned(),
+        Primitive::Integer(integer) => integer.as_symbol().as_str().to_owned(),
+        Primitive::String(string) => quote(string.as_str()),
+    }
+}
+
+/// A call argument, labeled arguments are kept in a separate list by the parser.
+enum CallArgument<'expr, 'heap> {
+    Positional(&'expr Argument<'heap>),
+    Labeled(&'expr LabeledArgument<'heap>),
+}
+
+struct Builder<'source, 'spans> {
+    source: &'source str,
+    spans: &'spans SpanTable<Span>,
+
+    comments: Vec<Comment<'source>>,
+    /// The next comment that has not been attached yet.
+    next: usize,
+    /// The end of the group that is currently being built, comments after it belong to one of
+    /// the enclosing groups.
+    limit: TextSize,
+}
+
+impl<'source> Builder<'source, '_> {
+    fn range(&self, span: SpanId) -> Option<TextRange> {
+        self.spans.absolute(span).map(|span| span.range())
+    }
+
+    /// Takes all comments that start before `offset`.
+    fn take_before(&mut self, offset: TextSize) -> Vec<Comment<'source>> {
+        let start = self.next;
+
+        while let Some(comment) = self.comments.get(self.next)
+            && comment.range.start() < offset
+        {
+            self.next += 1;
+        }
+
+        self.comments[start..self.next].to_vec()
+    }
+
+    /// Takes the next comment, if it is on the same line as `offset` and within the group.
+    fn take_trailing(&mut self, offset: TextSize) -> Option<Comment<'source>> {
+        let comment = *self.comments.get(self.next)?;
+
+        if comment.range.start() >= self.limit {
+            return None;
+        }
+
+        let between = self
+            .source
+            .get(usize::from(offset)..usize::from(comment.range.start()))?;
+
+        if between.contains('\n') {
+            return None;
+        }
+
+        self.next += 1;
+        Some(comment)
+    }
+
+    fn element(
+        &mut self,
+        key: Option<String>,
+        span: Option<SpanId>,
+        build: impl FnOnce(&mut Self) -> Doc<'source>,
+    ) -> Element<'source> {
+        let range = span.and_then(|span| self.range(span));
+
+        let mut leading = range.map_or_else(Vec::new, |range| self.take_before(range.start()));
+        let doc = build(self);
+
+        if let Some(range) = range
+            && matches!(doc, Doc::Text(_))
+        {
+            // Comments inside of sugar cannot be kept in place
+            leading.extend(self.take_before(range.end()));
+        }
+
+        let trailing = range.and_then(|range| self.take_trailing(range.end()));
+
+        Element {
+            leading,
+            key,
+            doc,
+            trailing,
+        }
+    }
+
+    fn group(
+        &mut self,
+        delimiter: Delimiter,
+        span: SpanId,
+        build: impl FnOnce(&mut Self) -> Vec<Element<'source>>,
+    ) -> Doc<'source> {
+        let limit = self.range(span).map_or(self.limit, TextRange::end);
+        let limit = core::mem::replace(&mut self.limit, limit);
+
+        let elements = build(self);
+        let dangling = self.take_before(self.limit);
+
+        self.limit = limit;
+
+        Doc::Group {
+            delimiter,
+            elements,
+            dangling,
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr<'_>) -> Doc<'source> {
+        if let Some(sugar) = sugar(expr) {
+            return Doc::Text(quote(&sugar));
+        }
+
+        match &expr.kind {
+            ExprKind::Call(call) => self.call(call),
+            ExprKind::Struct(r#struct) => self.r#struct(r#struct),
+            ExprKind::Dict(dict) => self.dict(dict),
+            ExprKind::Tuple(tuple) => self.tuple(tuple),
+            ExprKind::List(list) => self.list(list),
+            ExprKind::Literal(literal) => self.literal(literal),
+            ExprKind::Underscore => Doc::Text(quote("_")),
+            ExprKind::Field(field) => {
+                // Numeric fields are written as integer literals
+                let argument = if field
+                    .field
+                    .value
+                    .as_str()
+                    .starts_with(|char: char| char.is_ascii_digit())
+                {
+                    Doc::Group {
+                        delimiter: Delimiter::Brace,
+                        elements: vec![Element::new(
+                            Some(quote("#literal")),
+                            Doc::Text(field.field.value.as_str().to_owned()),
+                        )],
+                        dangling: Vec::new(),
+                    }
+                } else {
+                    Doc::Text(ident_string(field.field))
+                };
+
+                self.desugared(expr.span, sym::symbol::dot, &field.value, argument)
+            }
+            ExprKind::Index(index) => {
+                let argument = self.expr(&index.index);
+                self.desugared(expr.span, sym::symbol::brackets, &index.value, argument)
+            }
+            ExprKind::Path(_) => unreachable!("paths are always printed as a string"),
+            ExprKind::Let(_)
+            | ExprKind::Type(_)
+            | ExprKind::NewType(_)
+            | ExprKind::Input(_)
+            | ExprKind::Closure(_)
+            | ExprKind::If(_)
+            | ExprKind::Match(_)
+            | ExprKind::As(_)
+            | ExprKind::Dummy => {
+                unreachable!("special forms are only resolved after parsing")
+            }
+        }
+    }
+
+    /// Prints an access that cannot be written using the string sugar as a call to the special
+    /// form.
+    fn desugared(
+        &mut self,
+        span: SpanId,
+        name: Symbol<'_>,
+        value: &Expr<'_>,
+        argument: Doc<'source>,
+    ) -> Doc<'source> {
+        self.group(Delimiter::Bracket, span, |this| {
+            vec![
+                Element::new(None, Doc::Text(quote(name.as_str()))),
+                this.element(None, Some(value.span), |this| this.expr(value)),
+                Element::new(None, argument),
+            ]
+        })
+    }
+
+    fn call(&mut self, call: &CallExpr<'_>) -> Doc<'source> {
+        let mut arguments: Vec<_> = call
+            .arguments
+            .iter()
+            .map(CallArgument::Positional)
+            .chain(call.labeled_arguments.iter().map(CallArgument::Labeled))
+            .collect();
+
+        // Keep the order of the source, positional and labeled arguments may be interleaved
+        arguments.sort_by_key(|argument| {
+            let span = match argument {
+                CallArgument::Positional(argument) => argument.span,
+                CallArgument::Labeled(argument) => argument.span,
+            };
+
+            self.range(span).map(TextRange::start)
+        });
+
+        self.group(Delimiter::Bracket, call.span, |this| {
+            let mut elements = Vec::with_capacity(arguments.len() + 1);
+            elements.push(this.element(None, Some(call.function.span), |this| {
+                this.expr(&call.function)
+            }));
+
+            for argument in arguments {
+                let element = match argument {
+                    CallArgument::Positional(argument) => {
+                        this.element(None, Some(argument.span), |this| this.expr(&argument.value))
+                    }
+                    CallArgument::Labeled(argument) => {
+                        this.element(None, Some(argument.span), |this| {
+                            this.labeled_argument(argument)
+                        })
+                    }
+                };
+
+                elements.push(element);
+            }
+
+            elements
+        })
+    }
+
+    fn labeled_argument(&mut self, argument: &LabeledArgument<'_>) -> Doc<'source> {
+        let value = &argument.value.value;
+
+        // `":name"` is shorthand for `{":name": "name"}`
+        if let ExprKind::Path(path) = &value.kind
+            && !path.rooted
+            && let [segment] = path.segments.as_slice()
+            && segment.arguments.is_empty()
+            && segment.name.value == argument.label.value
+            && segment.name.kind == argument.label.kind
+        {
+            return Doc::Text(label_string(argument.label));
+        }
+
+        self.group(Delimiter::Brace, argument.span, |this| {
+            vec![this.element(
+                Some(label_string(argument.label)),
+                Some(argument.value.span),
+                |this| this.expr(value),
+            )]
+        })
+    }
+
+    /// Builds an object of the form `{"#<key>": value, "#type": type}`.
+    fn typed(
+        &mut self,
+        span: SpanId,
+        key: &str,
+        r#type: Option<&Type<'_>>,
+        value: impl FnOnce(&mut Self) -> Doc<'source>,
+    ) -> Doc<'source> {
+        self.group(Delimiter::Brace, span, |this| {
+            let mut elements = vec![Element::new(Some(quote(key)), value(this))];
+
+            if let Some(r#type) = r#type {
+                elements.push(Element::new(
+                    Some(quote("#type")),
+                    Doc::Text(type_string(r#type)),
+                ));
+            }
+
+            elements
+        })
+    }
+
+    fn r#struct(&mut self, r#struct: &StructExpr<'_>) -> Doc<'source> {
+        self.typed(
+            r#struct.span,
+            "#struct",
+            r#struct.r#type.as_deref(),
+            |this| {
+                this.group(Delimiter::Brace, r#struct.span, |this| {
+                    r#struct
+                        .entries
+                        .iter()
+                        .map(|entry| {
+                            this.element(Some(ident_string(entry.key)), Some(entry.span), |this| {
+                                this.expr(&entry.value)
+                            })
+                        })
+                        .collect()
+                })
+            },
+        )
+    }
+
+    fn dict(&mut self, dict: &DictExpr<'_>) -> Doc<'source> {
+        // Keys that are strings without a type can use the object form
+        let keys: Option<Vec<_>> = dict
+            .entries
+            .iter()
+            .map(|entry| {
+                if let ExprKind::Literal(LiteralExpr {
+                    kind: Primitive::String(key),
+                    r#type: None,
+                    ..
+                }) = &entry.key.kind
+                {
+                    Some(quote(key.as_str()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        self.typed(dict.span, "#dict", dict.r#type.as_deref(), |this| {
+            if let Some(keys) = keys {
+                return this.group(Delimiter::Brace, dict.span, |this| {
+                    dict.entries
+                        .iter()
+                        .zip(keys)
+                        .map(|(entry, key)| {
+                            this.element(Some(key), Some(entry.span), |this| {
+                                this.expr(&entry.value)
+                            })
+                        })
+                        .collect()
+                });
+            }
+
+            this.group(Delimiter::Bracket, dict.span, |this| {
+                dict.entries
+                    .iter()
+                    .map(|entry| {
+                        this.element(None, Some(entry.span), |this| {
+                            this.group(Delimiter::Bracket, entry.span, |this| {
+                                vec![
+                                    this.element(None, Some(entry.key.span), |this| {
+                                        this.expr(&entry.key)
+                                    }),
+                                    this.element(None, Some(entry.value.span), |this| {
+                                        this.expr(&entry.value)
+                                    }),
+                                ]
+                            })
+                        })
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    fn tuple(&mut self, tuple: &TupleExpr<'_>) -> Doc<'source> {
+        self.typed(tuple.span, "#tuple", tuple.r#type.as_deref(), |this| {
+            this.group(Delimiter::Bracket, tuple.span, |this| {
+                tuple
+                    .elements
+                    .iter()
+                    .map(|element| {
+                        this.element(None, Some(element.span), |this| this.expr(&element.value))
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    fn list(&mut self, list: &ListExpr<'_>) -> Doc<'source> {
+        self.typed(list.span, "#list", list.r#type.as_deref(), |this| {
+            this.group(Delimiter::Bracket, list.span, |this| {
+                list.elements
+                    .iter()
+                    .map(|element| {
+                        this.element(None, Some(element.span), |this| this.expr(&element.value))
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    fn literal(&mut self, literal: &LiteralExpr<'_>) -> Doc<'source> {
+        self.typed(literal.span, "#literal", literal.r#type.as_deref(), |_| {
+            Doc::Text(literal_string(&literal.kind))
+        })
+    }
+}
+
+/// Prints a parsed expression in canonical form, keeping the comments of the source.
+pub(crate) fn format(source: &str, expr: &Expr<'_>, spans: &SpanTable<Span>) -> String {
+    let mut builder = Builder {
+        source,
+        spans,
+        comments: comment::collect(source),
+        next: 0,
+        limit: TextSize::of(source),
+    };
+
+    let root = builder.element(None, Some(expr.span), |builder| builder.expr(expr));
+    let remaining = builder.take_before(builder.limit);
+
+    let mut printer = Printer::new();
+
+    for comment in &root.leading {
+        printer.comment(comment);
+        printer.newline(0);
+    }
+
+    printer.print(&root.doc, 0, 0);
+
+    for (index, comment) in root.trailing.iter().chain(&remaining).enumerate() {
+        // Only the first comment can be on the same line as the expression
+        if index == 0 && root.trailing.is_some() {
+            printer.trailing(comment);
+        } else {
+            printer.newline(0);
+            printer.comment(comment);
+        }
+    }
+
+    printer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use hashql_ast::format::SyntaxDump as _;
+    use hashql_core::{heap::Heap, span::SpanTable};
+    use hashql_diagnostics::source::SourceId;
+
+    use crate::Parser;
+
+    fn format(source: &str) -> String {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut parser = Parser::new(&heap, &mut spans);
+
+        parser.format(source).expect("source should parse")
+    }
+
+    /// Removes the span ids from a syntax dump, as they depend on the layout of the source.
+    fn dump(source: &str) -> String {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut parser = Parser::new(&heap, &mut spans);
+
+        let expr = parser
+            .parse_expr(source.as_bytes())
+            .expect("source should parse");
+        let dump = expr.syntax_dump_to_string();
+
+        let mut output = String::with_capacity(dump.len());
+        let mut chars = dump.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            if char == '@' {
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+            } else {
+                output.push(char);
+            }
+        }
+
+        output
+    }
+
+    #[test]
+    fn flat() {
+        assert_eq!(
+            format(r##"["add",   "x",{"#literal":1}]"##),
+            "[\"add\", \"x\", { \"#literal\": 1 }]\n"
+        );
+    }
+
+    #[test]
+    fn breaks_long_lines() {
+        let source = r#"["let", "entities", ["::graph::head::entities", ["::graph::tmp::decision_time_now"]], "entities"]"#;
+
+        assert_eq!(
+            format(source),
+            r#"[
+  "let",
+  "entities",
+  ["::graph::head::entities", ["::graph::tmp::decision_time_now"]],
+  "entities"
+]
+"#
+        );
+    }
+
+    #[test]
+    fn keeps_comments() {
+        let source = r#"// leading
+["add", // trailing
+  "x", /* block */ "y"
+  // dangling
+] // end"#;
+
+        let formatted = format(source);
+        assert_eq!(
+            formatted,
+            r#"// leading
+[
+  "add", // trailing
+  "x", /* block */
+  "y"
+  // dangling
+] // end
+"#
+        );
+
+        assert_eq!(format(&formatted), formatted);
+    }
+
+    #[test]
+    fn access_sugar() {
+        assert_eq!(
+            format(r##"[".", [".", "a", "b"], {"#literal": 0}]"##),
+            "\"a.b.0\"\n"
+        );
+        assert_eq!(format(r#"["[]", "a", "b.c"]"#), "\"a[b.c]\"\n");
+        assert_eq!(format(r#""a . b [ 0 ]""#), "\"a.b[0]\"\n");
+
+        // The value of an access has to be a path for the sugar to apply
+        assert_eq!(
+            format(r#"["access", ["f"], "b"]"#),
+            "[\"access\", [\"f\"], \"b\"]\n"
+        );
+    }
+
+    #[test]
+    fn labeled_arguments() {
+        assert_eq!(
+            format(r##"["f", {":x": "x"}, {":y": {"#literal": 1}}]"##),
+            "[\"f\", \":x\", { \":y\": { \"#literal\": 1 } }]\n"
+        );
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(
+            format(r##"{"#type": "Float", "#literal": 1.5}"##),
+            "{ \"#literal\": 1.5, \"#type\": \"Float\" }\n"
+        );
+        assert_eq!(
+            format(r##"{"#struct": {"a": "_"}, "#type": "( a : Int|String&Boolean )"}"##),
+            "{ \"#struct\": { \"a\": \"_\" }, \"#type\": \"(a: Int | String & Boolean)\" }\n"
+        );
+        assert_eq!(
+            format(r##"{"#struct": {"a": "_"}, "#type": "(a: (Int&String)|Boolean)"}"##),
+            "{ \"#struct\": { \"a\": \"_\" }, \"#type\": \"(a: (Int & String) | Boolean)\" }\n"
+        );
+        assert_eq!(
+            format(r##"{"#dict": {"k": {"#literal": "v"}}}"##),
+            "{ \"#dict\": { \"k\": { \"#literal\": \"v\" } } }\n"
+        );
+        assert_eq!(
+            format(r##"{"#dict": [[{"#literal": 1}, {"#literal": 2}]]}"##),
+            "{ \"#dict\": [[{ \"#literal\": 1 }, { \"#literal\": 2 }]] }\n"
+        );
+        assert_eq!(format(r##"{"#tuple": []}"##), "{ \"#tuple\": [] }\n");
+    }
+
+    #[test]
+    fn round_trip() {
+        let source = r##"["let", "x", {"#literal": 10, "#type": "Integer"},
+            ["if", [">", "x.a[0]", {"#literal": 5}],
+                {"#struct": {"result": {"#list": [{"#literal": "large"}]}}},
+                {"#tuple": [{"#literal": null}, "::core::math::`+`", ["f", ":x"]]}
+            ]
+        ]"##;
+
+        let formatted = format(source);
+
+        assert_eq!(dump(&formatted), dump(source));
+        assert_eq!(format(&formatted), formatted);
+    }
+}
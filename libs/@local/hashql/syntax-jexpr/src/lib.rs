@@ -25,6 +25,7 @@ use self::{
 };
 
 pub mod error;
+pub(crate) mod format;
 pub(crate) mod lexer;
 pub(crate) mod parser;
 pub mod span;
@@ -62,6 +63,21 @@ impl<'heap, 'spans> Parser<'heap, 'spans> {
 
         Ok(expr)
     }
+
+    /// Format an expression into its canonical form.
+    ///
+    /// The expression is parsed and printed back with the layout prettier uses for JSON. Comments
+    /// are kept, field and index accesses are written using the string sugar (`"a.b"`, `"a[0]"`)
+    /// wherever possible.
+    ///
+    /// # Errors
+    ///
+    /// - Any error of [`Self::parse_expr`], if the source is not a valid expression
+    pub fn format(&mut self, source: &str) -> Result<String, JExprDiagnostic> {
+        let expr = self.parse_expr(source.as_bytes())?;
+
+        Ok(format::format(source, &expr, self.spans))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,46 @@
+use alloc::borrow::Cow;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{Diagnostic, DiagnosticIssues, category::DiagnosticCategory};
+
+use crate::{lexer::error::LexerDiagnosticCategory, parser::error::ParserDiagnosticCategory};
+
+pub type TextDiagnostic = Diagnostic<TextDiagnosticCategory, SpanId>;
+pub type TextDiagnosticIssues = DiagnosticIssues<TextDiagnosticCategory, SpanId>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TextDiagnosticCategory {
+    Lexer(LexerDiagnosticCategory),
+    Parser(ParserDiagnosticCategory),
+}
+
+impl DiagnosticCategory for TextDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("text")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Text syntax")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match self {
+            Self::Lexer(lexer) => Some(lexer),
+            Self::Parser(parser) => Some(parser),
+        }
+    }
+}
+
+impl From<LexerDiagnosticCategory> for TextDiagnosticCategory {
+    #[inline]
+    fn from(value: LexerDiagnosticCategory) -> Self {
+        Self::Lexer(value)
+    }
+}
+
+impl From<ParserDiagnosticCategory> for TextDiagnosticCategory {
+    #[inline]
+    fn from(value: ParserDiagnosticCategory) -> Self {
+        Self::Parser(value)
+    }
+}
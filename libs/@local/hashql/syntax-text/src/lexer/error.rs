@@ -0,0 +1,69 @@
+use alloc::borrow::Cow;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{
+    Diagnostic, Label,
+    category::{DiagnosticCategory, TerminalDiagnosticCategory},
+    diagnostic::Message,
+    severity::Severity,
+};
+
+pub(crate) type LexerDiagnostic = Diagnostic<LexerDiagnosticCategory, SpanId>;
+
+const INVALID_CHARACTER: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-character",
+    name: "Invalid character",
+};
+
+const UNTERMINATED_STRING: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unterminated-string",
+    name: "Unterminated string literal",
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LexerDiagnosticCategory {
+    InvalidCharacter,
+    UnterminatedString,
+}
+
+impl DiagnosticCategory for LexerDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("lexer")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Lexer")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match self {
+            Self::InvalidCharacter => Some(&INVALID_CHARACTER),
+            Self::UnterminatedString => Some(&UNTERMINATED_STRING),
+        }
+    }
+}
+
+pub(crate) fn invalid_character(span: SpanId, character: &str) -> LexerDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(LexerDiagnosticCategory::InvalidCharacter, Severity::Error).primary(
+            Label::new(span, format!("`{character}` is not valid in this position")),
+        );
+
+    diagnostic.add_message(Message::help(
+        "Remove this character, or wrap it in a string literal if it is meant to be text",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unterminated_string(span: SpanId) -> LexerDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(LexerDiagnosticCategory::UnterminatedString, Severity::Error)
+            .primary(Label::new(span, "String literal is never closed"));
+
+    diagnostic.add_message(Message::help(
+        "Add a closing `\"` to terminate the string literal",
+    ));
+
+    diagnostic
+}
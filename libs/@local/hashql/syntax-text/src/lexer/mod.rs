@@ -0,0 +1,174 @@
+pub(crate) mod error;
+pub(crate) mod token_kind;
+
+use core::ops::Range;
+
+use hashql_core::span::{TextRange, TextSize};
+use logos::Logos as _;
+
+use self::{error::LexerDiagnosticCategory, token_kind::TokenKind};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub kind: TokenKind,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct LexerError {
+    pub category: LexerDiagnosticCategory,
+    pub range: TextRange,
+}
+
+fn text_range(span: Range<usize>) -> TextRange {
+    let start = TextSize::try_from(span.start).expect("source should be smaller than 4GiB");
+    let end = TextSize::try_from(span.end).expect("source should be smaller than 4GiB");
+
+    TextRange::new(start, end)
+}
+
+/// Splits the source into tokens.
+///
+/// Invalid input does not stop the lexer, the offending characters are reported as an error and
+/// lexing resumes right after them, so that the parser is able to report as many errors as
+/// possible in a single pass.
+pub(crate) struct Lexer<'source> {
+    inner: logos::Lexer<'source, TokenKind>,
+}
+
+impl<'source> Lexer<'source> {
+    pub(crate) fn new(source: &'source str) -> Self {
+        Self {
+            inner: TokenKind::lexer(source),
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = self.inner.next()?;
+
+        match kind {
+            Ok(kind) => Some(Ok(Token {
+                kind,
+                range: text_range(self.inner.span()),
+            })),
+            Err(()) if self.inner.slice().starts_with('"') => {
+                // A string that is never terminated swallows the remainder of the input, otherwise
+                // the contents of the string would be lexed as tokens. The lexer usually already
+                // consumed the remainder while attempting to match the string.
+                let start = self.inner.span().start;
+                self.inner.bump(self.inner.remainder().len());
+
+                Some(Err(LexerError {
+                    category: LexerDiagnosticCategory::UnterminatedString,
+                    range: text_range(start..self.inner.span().end),
+                }))
+            }
+            Err(()) => Some(Err(LexerError {
+                category: LexerDiagnosticCategory::InvalidCharacter,
+                range: text_range(self.inner.span()),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lexer, token_kind::TokenKind};
+    use crate::lexer::error::LexerDiagnosticCategory;
+
+    fn kinds(source: &str) -> Vec<Result<TokenKind, LexerDiagnosticCategory>> {
+        Lexer::new(source)
+            .map(|token| {
+                token
+                    .map(|token| token.kind)
+                    .map_err(|error| error.category)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keywords_and_identifiers() {
+        assert_eq!(
+            kinds("let letter in input_ _"),
+            [
+                Ok(TokenKind::Let),
+                Ok(TokenKind::Ident),
+                Ok(TokenKind::In),
+                Ok(TokenKind::Ident),
+                Ok(TokenKind::Ident),
+            ]
+        );
+    }
+
+    #[test]
+    fn operators_prefer_longest_match() {
+        assert_eq!(
+            kinds("|> || | ** * => == = -> <= << <"),
+            [
+                Ok(TokenKind::Pipeline),
+                Ok(TokenKind::PipePipe),
+                Ok(TokenKind::Pipe),
+                Ok(TokenKind::StarStar),
+                Ok(TokenKind::Star),
+                Ok(TokenKind::FatArrow),
+                Ok(TokenKind::EqEq),
+                Ok(TokenKind::Eq),
+                Ok(TokenKind::Arrow),
+                Ok(TokenKind::Le),
+                Ok(TokenKind::Shl),
+                Ok(TokenKind::Lt),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            kinds("12 1.5 1e10 2.5E-3 x.0"),
+            [
+                Ok(TokenKind::Integer),
+                Ok(TokenKind::Float),
+                Ok(TokenKind::Float),
+                Ok(TokenKind::Float),
+                Ok(TokenKind::Ident),
+                Ok(TokenKind::Dot),
+                Ok(TokenKind::Integer),
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        assert_eq!(
+            kinds("1 // line comment\n/* block\n * comment */ 2"),
+            [Ok(TokenKind::Integer), Ok(TokenKind::Integer)]
+        );
+    }
+
+    #[test]
+    fn invalid_character_recovers() {
+        assert_eq!(
+            kinds("1 $ 2"),
+            [
+                Ok(TokenKind::Integer),
+                Err(LexerDiagnosticCategory::InvalidCharacter),
+                Ok(TokenKind::Integer),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_string_consumes_remainder() {
+        assert_eq!(
+            kinds(r#"1 "abc + 2"#),
+            [
+                Ok(TokenKind::Integer),
+                Err(LexerDiagnosticCategory::UnterminatedString),
+            ]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use core::fmt::{self, Display};
+
+use logos::Logos;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Logos)]
+#[logos(skip r"[ \t\r\n\f]+")]
+#[logos(skip(r"//[^\n]*", allow_greedy = true))]
+#[logos(skip r"/\*(?:[^*]|\*+[^*/])*\*+/")]
+pub(crate) enum TokenKind {
+    #[token("let")]
+    Let,
+    #[token("in")]
+    In,
+    #[token("if")]
+    If,
+    #[token("then")]
+    Then,
+    #[token("else")]
+    Else,
+    #[token("fn")]
+    Fn,
+    #[token("type")]
+    Type,
+    #[token("newtype")]
+    Newtype,
+    #[token("use")]
+    Use,
+    #[token("input")]
+    Input,
+    #[token("match")]
+    Match,
+    #[token("as")]
+    As,
+    #[token("true")]
+    True,
+    #[token("false")]
+    False,
+    #[token("null")]
+    Null,
+
+    #[token("(")]
+    LParen,
+    #[token(")")]
+    RParen,
+    #[token("[")]
+    LBracket,
+    #[token("]")]
+    RBracket,
+    #[token("{")]
+    LBrace,
+    #[token("}")]
+    RBrace,
+    #[token(",")]
+    Comma,
+    #[token(":")]
+    Colon,
+    #[token("::")]
+    ColonColon,
+    #[token(".")]
+    Dot,
+    #[token("=")]
+    Eq,
+    #[token("=>")]
+    FatArrow,
+    #[token("->")]
+    Arrow,
+    #[token("|>")]
+    Pipeline,
+
+    #[token("+")]
+    Plus,
+    #[token("-")]
+    Minus,
+    #[token("*")]
+    Star,
+    #[token("**")]
+    StarStar,
+    #[token("/")]
+    Slash,
+    #[token("%")]
+    Percent,
+    #[token("^")]
+    Caret,
+    #[token("~")]
+    Tilde,
+    #[token("!")]
+    Bang,
+    #[token("&")]
+    Amp,
+    #[token("&&")]
+    AmpAmp,
+    #[token("|")]
+    Pipe,
+    #[token("||")]
+    PipePipe,
+    #[token("<<")]
+    Shl,
+    #[token(">>")]
+    Shr,
+    #[token("<")]
+    Lt,
+    #[token("<=")]
+    Le,
+    #[token(">")]
+    Gt,
+    #[token(">=")]
+    Ge,
+    #[token("==")]
+    EqEq,
+    #[token("!=")]
+    NotEq,
+
+    #[regex(r"[\p{XID_Start}_]\p{XID_Continue}*")]
+    Ident,
+    /// An operator or other symbol escaped in backticks, such as `` `+` ``.
+    #[regex(r"`[^`\s]+`")]
+    Symbol,
+    #[regex(r"[0-9]+")]
+    Integer,
+    #[regex(r"[0-9]+\.[0-9]+(?:[eE][+-]?[0-9]+)?")]
+    #[regex(r"[0-9]+[eE][+-]?[0-9]+")]
+    Float,
+    #[regex(r#""(?:[^"\\]|\\.)*""#)]
+    String,
+}
+
+impl TokenKind {
+    /// Returns the binary operator symbol this token represents, if any.
+    ///
+    /// The symbol is the name under which the operator is exported from the prelude.
+    #[expect(clippy::wildcard_enum_match_arm)]
+    pub(crate) const fn as_operator(self) -> Option<&'static str> {
+        let operator = match self {
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Star => "*",
+            Self::StarStar => "**",
+            Self::Slash => "/",
+            Self::Percent => "%",
+            Self::Caret => "^",
+            Self::Amp => "&",
+            Self::AmpAmp => "&&",
+            Self::Pipe => "|",
+            Self::PipePipe => "||",
+            Self::Shl => "<<",
+            Self::Shr => ">>",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::EqEq => "==",
+            Self::NotEq => "!=",
+            Self::Tilde => "~",
+            Self::Bang => "!",
+            _ => return None,
+        };
+
+        Some(operator)
+    }
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::Let => "`let`",
+            Self::In => "`in`",
+            Self::If => "`if`",
+            Self::Then => "`then`",
+            Self::Else => "`else`",
+            Self::Fn => "`fn`",
+            Self::Type => "`type`",
+            Self::Newtype => "`newtype`",
+            Self::Use => "`use`",
+            Self::Input => "`input`",
+            Self::Match => "`match`",
+            Self::As => "`as`",
+            Self::True => "`true`",
+            Self::False => "`false`",
+            Self::Null => "`null`",
+            Self::LParen => "`(`",
+            Self::RParen => "`)`",
+            Self::LBracket => "`[`",
+            Self::RBracket => "`]`",
+            Self::LBrace => "`{`",
+            Self::RBrace => "`}`",
+            Self::Comma => "`,`",
+            Self::Colon => "`:`",
+            Self::ColonColon => "`::`",
+            Self::Dot => "`.`",
+            Self::Eq => "`=`",
+            Self::FatArrow => "`=>`",
+            Self::Arrow => "`->`",
+            Self::Pipeline => "`|>`",
+            Self::Plus => "`+`",
+            Self::Minus => "`-`",
+            Self::Star => "`*`",
+            Self::StarStar => "`**`",
+            Self::Slash => "`/`",
+            Self::Percent => "`%`",
+            Self::Caret => "`^`",
+            Self::Tilde => "`~`",
+            Self::Bang => "`!`",
+            Self::Amp => "`&`",
+            Self::AmpAmp => "`&&`",
+            Self::Pipe => "`|`",
+            Self::PipePipe => "`||`",
+            Self::Shl => "`<<`",
+            Self::Shr => "`>>`",
+            Self::Lt => "`<`",
+            Self::Le => "`<=`",
+            Self::Gt => "`>`",
+            Self::Ge => "`>=`",
+            Self::EqEq => "`==`",
+            Self::NotEq => "`!=`",
+            Self::Ident => "identifier",
+            Self::Symbol => "symbol",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::String => "string",
+        };
+
+        fmt.write_str(description)
+    }
+}
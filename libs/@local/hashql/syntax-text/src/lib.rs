@@ -0,0 +1,457 @@
+//! # HashQL text syntax
+//!
+//! A human-friendly, expression-oriented syntax for HashQL, living alongside J-Expr. Both
+//! frontends produce the same [`hashql_ast`] nodes, so everything after parsing is shared.
+//!
+//! ```text
+//! let threshold = 5 in
+//! ::graph::head::entities(::graph::tmp::decision_time_now())
+//!     |> ::graph::body::filter(fn(vertex: _) => vertex.metadata.archived == false)
+//!     |> ::graph::tail::collect
+//! ```
+//!
+//! Infix operators desugar into calls of the operator, like `a + b` into `+(a, b)`, and special
+//! forms into calls of the special form, like `let x = 1 in x` into `let(x, 1, x)`. A pipeline
+//! `x |> f(y)` inserts `x` as the first argument of the call, `x |> f` calls `f` with `x`.
+//!
+//! ## Workspace dependencies
+#![doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd")]
+#![expect(clippy::string_slice)]
+#![feature(
+    // Library Features
+    allocator_api,
+)]
+
+extern crate alloc;
+
+use hashql_ast::node::expr::Expr;
+use hashql_core::{
+    heap::Heap,
+    span::{SpanId, SpanTable},
+};
+use hashql_diagnostics::Status;
+
+use self::{error::TextDiagnosticCategory, parser::state::ParserState, span::Span};
+
+pub mod error;
+pub(crate) mod lexer;
+pub(crate) mod parser;
+pub mod span;
+
+pub struct Parser<'heap, 'spans> {
+    heap: &'heap Heap,
+    spans: &'spans mut SpanTable<Span>,
+}
+
+impl<'heap, 'spans> Parser<'heap, 'spans> {
+    pub const fn new(heap: &'heap Heap, spans: &'spans mut SpanTable<Span>) -> Self {
+        Self { heap, spans }
+    }
+
+    /// Parse an expression from source text.
+    ///
+    /// The parser recovers from errors, so that every error in the source is reported at once
+    /// instead of only the first one.
+    ///
+    /// # Errors
+    ///
+    /// - Lexer errors if the input contains invalid characters or unterminated strings
+    /// - Parser errors if the tokens don't form a valid expression
+    pub fn parse_expr(
+        &mut self,
+        source: &str,
+    ) -> Status<Expr<'heap>, TextDiagnosticCategory, SpanId> {
+        let mut state = ParserState::new(self.heap, source, self.spans);
+
+        let expr = parser::expr::parse_expr(&mut state);
+
+        state.finish().into_status(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hashql_ast::format::SyntaxDump as _;
+    use hashql_core::{
+        heap::Heap,
+        span::{SpanId, SpanTable},
+    };
+    use hashql_diagnostics::{diagnostic::Labels, source::SourceId};
+
+    use crate::{
+        Parser, error::TextDiagnosticCategory, lexer::error::LexerDiagnosticCategory,
+        parser::error::ParserDiagnosticCategory,
+    };
+
+    /// Removes the span ids from a syntax dump, as they depend on the order of parsing.
+    fn strip_spans(dump: &str) -> String {
+        let mut output = String::with_capacity(dump.len());
+        let mut chars = dump.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            if char == '@' {
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+            } else {
+                output.push(char);
+            }
+        }
+
+        output
+    }
+
+    fn dump_text(source: &str) -> String {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut parser = Parser::new(&heap, &mut spans);
+
+        let success = parser.parse_expr(source).expect("text source should parse");
+
+        strip_spans(&success.value.syntax_dump_to_string())
+    }
+
+    fn dump_jexpr(source: &str) -> String {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut parser = hashql_syntax_jexpr::Parser::new(&heap, &mut spans);
+
+        let expr = parser
+            .parse_expr(source.as_bytes())
+            .expect("J-Expr source should parse");
+
+        strip_spans(&expr.syntax_dump_to_string())
+    }
+
+    #[track_caller]
+    fn assert_same_ast(text: &str, jexpr: &str) {
+        assert_eq!(
+            dump_text(text),
+            dump_jexpr(jexpr),
+            "`{text}` differs from `{jexpr}`"
+        );
+    }
+
+    /// Returns the category of every reported error, together with the source it points at.
+    fn errors(source: &str) -> Vec<(TextDiagnosticCategory, &str)> {
+        let heap = Heap::new();
+        let mut spans = SpanTable::new(SourceId::new_unchecked(0x00));
+        let mut parser = Parser::new(&heap, &mut spans);
+
+        let failure = parser
+            .parse_expr(source)
+            .expect_err("source should fail to parse");
+
+        let locate = |labels: &Labels<SpanId>| {
+            let label = labels
+                .iter()
+                .next()
+                .expect("diagnostics should have a primary label");
+            let span = spans
+                .get(*label.span())
+                .expect("label span should be registered");
+
+            &source[span.range]
+        };
+
+        let mut errors = vec![(failure.primary.category, locate(&failure.primary.labels))];
+        errors.extend(
+            failure
+                .secondary
+                .iter()
+                .map(|diagnostic| (diagnostic.category, locate(&diagnostic.labels))),
+        );
+
+        errors
+    }
+
+    fn error_categories(source: &str) -> Vec<TextDiagnosticCategory> {
+        errors(source)
+            .into_iter()
+            .map(|(category, _)| category)
+            .collect()
+    }
+
+    #[test]
+    fn literals() {
+        assert_same_ast("42", r##"{"#literal": 42}"##);
+        assert_same_ast("-1.5", r##"{"#literal": -1.5}"##);
+        assert_same_ast("true", r##"{"#literal": true}"##);
+        assert_same_ast("null", r##"{"#literal": null}"##);
+        assert_same_ast(r#""a\tb""#, r##"{"#literal": "a\tb"}"##);
+    }
+
+    #[test]
+    fn operator_precedence() {
+        assert_same_ast(
+            "1 + 2 * 3 > 4 && !x",
+            r##"["&&", [">", ["+", {"#literal": 1}, ["*", {"#literal": 2}, {"#literal": 3}]], {"#literal": 4}], ["!", "x"]]"##,
+        );
+        assert_same_ast(
+            "2 ** 3 ** 4",
+            r##"["**", {"#literal": 2}, ["**", {"#literal": 3}, {"#literal": 4}]]"##,
+        );
+        assert_same_ast("a - b - c", r#"["-", ["-", "a", "b"], "c"]"#);
+    }
+
+    #[test]
+    fn binding_power_of_each_level() {
+        // Every operator binds tighter than the one on the previous level.
+        assert_same_ast("a || b && c", r#"["||", "a", ["&&", "b", "c"]]"#);
+        assert_same_ast("a && b == c", r#"["&&", "a", ["==", "b", "c"]]"#);
+        assert_same_ast("a != b | c", r#"["!=", "a", ["|", "b", "c"]]"#);
+        assert_same_ast("a | b ^ c", r#"["|", "a", ["^", "b", "c"]]"#);
+        assert_same_ast("a ^ b & c", r#"["^", "a", ["&", "b", "c"]]"#);
+        assert_same_ast("a & b << c", r#"["&", "a", ["<<", "b", "c"]]"#);
+        assert_same_ast("a >> b - c", r#"[">>", "a", ["-", "b", "c"]]"#);
+        assert_same_ast("a + b % c", r#"["+", "a", ["%", "b", "c"]]"#);
+        assert_same_ast("a / b ** c", r#"["/", "a", ["**", "b", "c"]]"#);
+
+        // ... regardless of the order they appear in.
+        assert_same_ast("a * b + c", r#"["+", ["*", "a", "b"], "c"]"#);
+        assert_same_ast("a == b || c", r#"["||", ["==", "a", "b"], "c"]"#);
+    }
+
+    #[test]
+    fn associativity() {
+        assert_same_ast("a / b * c", r#"["*", ["/", "a", "b"], "c"]"#);
+        assert_same_ast("a << b >> c", r#"[">>", ["<<", "a", "b"], "c"]"#);
+        assert_same_ast("a < b <= c", r#"["<=", ["<", "a", "b"], "c"]"#);
+        assert_same_ast("a || b || c", r#"["||", ["||", "a", "b"], "c"]"#);
+        assert_same_ast("a ** b ** c", r#"["**", "a", ["**", "b", "c"]]"#);
+    }
+
+    #[test]
+    fn parenthesized_expressions() {
+        assert_same_ast("(a + b) * c", r#"["*", ["+", "a", "b"], "c"]"#);
+        assert_same_ast("(a ** b) ** c", r#"["**", ["**", "a", "b"], "c"]"#);
+        assert_same_ast("((a))", r#""a""#);
+    }
+
+    #[test]
+    fn prefix_operators() {
+        assert_same_ast("!a.b", r#"["!", "a.b"]"#);
+        assert_same_ast("~a[0]", r#"["~", "a[0]"]"#);
+        assert_same_ast("!!a", r#"["!", ["!", "a"]]"#);
+        assert_same_ast("!a == ~b", r#"["==", ["!", "a"], ["~", "b"]]"#);
+        assert_same_ast("!a ** b", r#"["**", ["!", "a"], "b"]"#);
+    }
+
+    #[test]
+    fn type_assertion_precedence() {
+        assert_same_ast("a + b as Integer", r#"["+", "a", ["as", "b", "Integer"]]"#);
+        assert_same_ast(
+            "a ** b as Integer",
+            r#"["**", "a", ["as", "b", "Integer"]]"#,
+        );
+        assert_same_ast("!a as Boolean", r#"["as", ["!", "a"], "Boolean"]"#);
+        assert_same_ast(
+            "a as Integer as Number",
+            r#"["as", ["as", "a", "Integer"], "Number"]"#,
+        );
+    }
+
+    #[test]
+    fn access() {
+        assert_same_ast("a.b.c", r#""a.b.c""#);
+        assert_same_ast("pair.0.1", r#""pair.0.1""#);
+        assert_same_ast("list[0]", r#""list[0]""#);
+        assert_same_ast(
+            "::core::math::add(1, 2)",
+            r##"["::core::math::add", {"#literal": 1}, {"#literal": 2}]"##,
+        );
+    }
+
+    #[test]
+    fn aggregates() {
+        assert_same_ast(
+            "(a: 1, b: x)",
+            r##"{"#struct": {"a": {"#literal": 1}, "b": "x"}}"##,
+        );
+        assert_same_ast("(1, x)", r##"{"#tuple": [{"#literal": 1}, "x"]}"##);
+        assert_same_ast("()", r##"{"#tuple": []}"##);
+        assert_same_ast(
+            "[1, 2]",
+            r##"{"#list": [{"#literal": 1}, {"#literal": 2}]}"##,
+        );
+    }
+
+    #[test]
+    fn pipeline() {
+        assert_same_ast("x |> f(y) |> g", r#"["g", ["f", "x", "y"]]"#);
+    }
+
+    #[test]
+    fn pipeline_stages() {
+        // A call receives the value as its first argument, anything else is called with it.
+        assert_same_ast("x |> f()", r#"["f", "x"]"#);
+        assert_same_ast("x |> f(y, z)", r#"["f", "x", "y", "z"]"#);
+        assert_same_ast("x |> a.b", r#"["a.b", "x"]"#);
+        assert_same_ast("x |> ::core::math::abs", r#"["::core::math::abs", "x"]"#);
+
+        // Parenthesizing a call turns it into a function value, which is then called.
+        assert_same_ast("x |> (f(y))", r#"[["f", "y"], "x"]"#);
+        assert_same_ast("x |> f(y)(z)", r#"[["f", "y"], "x", "z"]"#);
+    }
+
+    #[test]
+    fn pipeline_precedence() {
+        // The pipeline binds looser than every other operator, on either side.
+        assert_same_ast("a || b |> f", r#"["f", ["||", "a", "b"]]"#);
+        assert_same_ast("a + b |> f(c)", r#"["f", ["+", "a", "b"], "c"]"#);
+        assert_same_ast("a |> f |> g == b", r#"["==", ["g", ["f", "a"]], "b"]"#);
+        assert_same_ast("(a |> f) + b", r#"["+", ["f", "a"], "b"]"#);
+        assert_same_ast("f(a |> g)", r#"["f", ["g", "a"]]"#);
+        assert_same_ast(
+            "let x = a |> f in x |> g",
+            r#"["let", "x", ["f", "a"], ["g", "x"]]"#,
+        );
+    }
+
+    #[test]
+    fn special_forms() {
+        assert_same_ast(
+            "let x: Integer = 1 in x",
+            r##"["let", "x", "Integer", {"#literal": 1}, "x"]"##,
+        );
+        assert_same_ast(
+            "if x then 1 else 2",
+            r##"["if", "x", {"#literal": 1}, {"#literal": 2}]"##,
+        );
+        assert_same_ast(
+            "fn(x: Integer) -> Integer => x",
+            r##"["fn", {"#tuple": []}, {"#struct": {"x": "Integer"}}, "Integer", "x"]"##,
+        );
+        assert_same_ast(
+            "type Pair<T> = (a: T, b: T) in x",
+            r##"["type", "Pair<T>", {"#struct": {"a": "T", "b": "T"}}, "x"]"##,
+        );
+        assert_same_ast(
+            "use ::core::math::{sin, cos: c} in x",
+            r##"["use", "::core::math", {"#struct": {"sin": "_", "cos": "c"}}, "x"]"##,
+        );
+        assert_same_ast(
+            "match x { Some(y) => y, _ => 0 }",
+            r##"["match", "x", ["Some", "y"], "y", "_", {"#literal": 0}]"##,
+        );
+    }
+
+    #[test]
+    fn recovers_from_errors() {
+        let categories = error_categories("let x = (1, ) + in [1, 2 3] $");
+
+        let unexpected = TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken);
+        let invalid = TextDiagnosticCategory::Lexer(LexerDiagnosticCategory::InvalidCharacter);
+
+        // Every error is reported, instead of only the first one.
+        assert_eq!(categories.len(), 3, "{categories:?}");
+        assert_eq!(
+            categories
+                .iter()
+                .filter(|&&category| category == unexpected)
+                .count(),
+            2
+        );
+        assert!(categories.contains(&invalid));
+    }
+
+    #[test]
+    fn rejects_negation_of_expressions() {
+        assert_eq!(
+            error_categories("-x"),
+            [TextDiagnosticCategory::Parser(
+                ParserDiagnosticCategory::UnsupportedNegation
+            )]
+        );
+    }
+
+    #[test]
+    fn reports_unclosed_delimiters() {
+        let unexpected = TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken);
+
+        assert_eq!(errors("(1, 2"), [(unexpected, "")]);
+        assert_eq!(errors("[1, 2"), [(unexpected, "")]);
+        assert_eq!(errors("f(1, 2"), [(unexpected, "")]);
+        assert_eq!(errors("(a: 1"), [(unexpected, "")]);
+    }
+
+    #[test]
+    fn reports_trailing_input() {
+        assert_eq!(
+            errors("1 2"),
+            [(
+                TextDiagnosticCategory::Parser(ParserDiagnosticCategory::ExpectedEof),
+                "2"
+            )]
+        );
+        assert_eq!(
+            errors("f(x))"),
+            [(
+                TextDiagnosticCategory::Parser(ParserDiagnosticCategory::ExpectedEof),
+                ")"
+            )]
+        );
+    }
+
+    #[test]
+    fn recovers_inside_delimited_lists() {
+        let unexpected = TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken);
+
+        // Parsing resumes at the next separator, so errors in later elements are reported too.
+        assert_eq!(
+            errors("[1, +, 2, *]"),
+            [(unexpected, "+"), (unexpected, "*")]
+        );
+        assert_eq!(errors("f(=, x, =)"), [(unexpected, "="), (unexpected, "=")]);
+        assert_eq!(
+            errors("(a: =, b: 2, c: =)"),
+            [(unexpected, "="), (unexpected, "=")]
+        );
+
+        // A token the parser synchronizes on is not skipped, instead the remainder of the
+        // malformed list is skipped up to its closing delimiter.
+        assert_eq!(errors("f(then, x, else)"), [(unexpected, "then")]);
+    }
+
+    #[test]
+    fn recovers_inside_special_forms() {
+        let unexpected = TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken);
+
+        assert_eq!(errors("let x = in x"), [(unexpected, "in")]);
+        assert_eq!(errors("if then 1 else 2"), [(unexpected, "then")]);
+        assert_eq!(
+            errors("let x = 1 + in if x then else 2"),
+            [(unexpected, "in"), (unexpected, "else")]
+        );
+        assert_eq!(
+            errors("match x { 1 + => y, _ => }"),
+            [(unexpected, "=>"), (unexpected, "}")]
+        );
+    }
+
+    #[test]
+    fn recovers_inside_pipelines() {
+        let unexpected = TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken);
+
+        assert_eq!(errors("(x |> ) |> f"), [(unexpected, ")")]);
+        assert_eq!(
+            errors("x |> f(,) |> g(1 +)"),
+            [(unexpected, ","), (unexpected, ")")]
+        );
+    }
+
+    #[test]
+    fn reports_lexer_and_parser_errors_together() {
+        assert_eq!(
+            errors("[1, 2 $ 3]"),
+            [
+                (
+                    TextDiagnosticCategory::Lexer(LexerDiagnosticCategory::InvalidCharacter),
+                    "$"
+                ),
+                (
+                    TextDiagnosticCategory::Parser(ParserDiagnosticCategory::UnexpectedToken),
+                    "3"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+use hashql_core::span::SpanId;
+use hashql_diagnostics::{
+    Diagnostic, Label,
+    category::{DiagnosticCategory, TerminalDiagnosticCategory},
+    diagnostic::Message,
+    severity::Severity,
+};
+
+use crate::lexer::token_kind::TokenKind;
+
+pub(crate) type ParserDiagnostic = Diagnostic<ParserDiagnosticCategory, SpanId>;
+
+const UNEXPECTED_TOKEN: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unexpected-token",
+    name: "Unexpected token",
+};
+
+const EXPECTED_EOF: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "expected-eof",
+    name: "Unexpected token after expression",
+};
+
+const INVALID_ESCAPE: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "invalid-escape",
+    name: "Invalid escape sequence",
+};
+
+const UNSUPPORTED_NEGATION: TerminalDiagnosticCategory = TerminalDiagnosticCategory {
+    id: "unsupported-negation",
+    name: "Unsupported negation",
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParserDiagnosticCategory {
+    UnexpectedToken,
+    ExpectedEof,
+    InvalidEscape,
+    UnsupportedNegation,
+}
+
+impl DiagnosticCategory for ParserDiagnosticCategory {
+    fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed("parser")
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Parser")
+    }
+
+    fn subcategory(&self) -> Option<&dyn DiagnosticCategory> {
+        match self {
+            Self::UnexpectedToken => Some(&UNEXPECTED_TOKEN),
+            Self::ExpectedEof => Some(&EXPECTED_EOF),
+            Self::InvalidEscape => Some(&INVALID_ESCAPE),
+            Self::UnsupportedNegation => Some(&UNSUPPORTED_NEGATION),
+        }
+    }
+}
+
+/// `found` is `None` if the end of the input has been reached.
+pub(crate) fn unexpected_token(
+    span: SpanId,
+    expected: impl Display,
+    found: Option<TokenKind>,
+) -> ParserDiagnostic {
+    #[expect(clippy::option_if_let_else, reason = "readability")]
+    let message = match found {
+        Some(found) => format!("Expected {expected}, found {found}"),
+        None => format!("Expected {expected}, found end of input"),
+    };
+
+    Diagnostic::new(ParserDiagnosticCategory::UnexpectedToken, Severity::Error)
+        .primary(Label::new(span, message))
+}
+
+const EXPECTED_EOF_HELP: &str =
+    "Remove this token or check for missing delimiters in the preceding expression";
+
+pub(crate) fn expected_eof(span: SpanId) -> ParserDiagnostic {
+    let mut diagnostic = Diagnostic::new(ParserDiagnosticCategory::ExpectedEof, Severity::Error)
+        .primary(Label::new(span, "Extra content after expression"));
+
+    diagnostic.add_message(Message::help(EXPECTED_EOF_HELP));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_escape(span: SpanId, sequence: &str) -> ParserDiagnostic {
+    let mut diagnostic =
+        Diagnostic::new(ParserDiagnosticCategory::InvalidEscape, Severity::Error).primary(
+            Label::new(span, format!("Unknown escape sequence `{sequence}`")),
+        );
+
+    diagnostic.add_message(Message::help(
+        "Valid escape sequences are `\\\"`, `\\\\`, `\\n`, `\\r`, `\\t`, `\\0` and `\\u{...}`",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unsupported_negation(span: SpanId) -> ParserDiagnostic {
+    let mut diagnostic = Diagnostic::new(
+        ParserDiagnosticCategory::UnsupportedNegation,
+        Severity::Error,
+    )
+    .primary(Label::new(span, "Only numeric literals can be negated"));
+
+    diagnostic.add_message(Message::help(
+        "To negate an arbitrary expression, subtract it from zero, e.g. `0 - x`",
+    ));
+
+    diagnostic
+}
@@ -0,0 +1,709 @@
+use hashql_ast::node::{
+    expr::{
+        CallExpr, DictExpr, Expr, ExprKind, FieldExpr, IndexExpr, ListExpr, StructExpr, TupleExpr,
+        call::{Argument, LabeledArgument},
+        dict::DictEntry,
+        list::ListElement,
+        r#struct::StructEntry,
+        tuple::TupleElement,
+    },
+    id::NodeId,
+};
+use hashql_core::{
+    heap,
+    span::{SpanId, TextRange, TextSize},
+    symbol::{Ident, IdentKind},
+};
+
+use super::{
+    error::unsupported_negation,
+    literal::{is_literal, parse_literal, parse_negative_literal},
+    path::{PathMode, is_path_start, make_ident, make_path, parse_path},
+    special_form,
+    state::ParserState,
+    r#type::parse_type_expr,
+};
+use crate::lexer::{Token, token_kind::TokenKind};
+
+/// Binding power of the operand of a prefix operator, which is tighter than any infix operator.
+const PREFIX_BINDING_POWER: u8 = 25;
+/// Left binding power of the `as` type ascription.
+const AS_BINDING_POWER: u8 = 23;
+/// Left binding power of the `|>` pipeline operator.
+const PIPELINE_BINDING_POWER: u8 = 1;
+
+/// Returns the left and right binding power of an infix operator.
+///
+/// A right binding power lower than the left one makes the operator right-associative.
+#[expect(clippy::wildcard_enum_match_arm)]
+const fn infix_binding_power(kind: TokenKind) -> Option<(u8, u8)> {
+    let power = match kind {
+        TokenKind::PipePipe => (3, 4),
+        TokenKind::AmpAmp => (5, 6),
+        TokenKind::EqEq
+        | TokenKind::NotEq
+        | TokenKind::Lt
+        | TokenKind::Le
+        | TokenKind::Gt
+        | TokenKind::Ge => (7, 8),
+        TokenKind::Pipe => (9, 10),
+        TokenKind::Caret => (11, 12),
+        TokenKind::Amp => (13, 14),
+        TokenKind::Shl | TokenKind::Shr => (15, 16),
+        TokenKind::Plus | TokenKind::Minus => (17, 18),
+        TokenKind::Star | TokenKind::Slash | TokenKind::Percent => (19, 20),
+        TokenKind::StarStar => (22, 21),
+        _ => return None,
+    };
+
+    Some(power)
+}
+
+pub(crate) const fn dummy(span: SpanId) -> Expr<'static> {
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Dummy,
+    }
+}
+
+const fn argument(value: Expr<'_>) -> Argument<'_> {
+    Argument {
+        id: NodeId::PLACEHOLDER,
+        span: value.span,
+        value,
+    }
+}
+
+/// Creates a call of `function`, spanning from `start` up to the last consumed token.
+pub(crate) fn make_call<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    function: Expr<'heap>,
+    arguments: impl IntoIterator<Item = Expr<'heap>>,
+) -> Expr<'heap> {
+    let heap = state.heap();
+    let span = state.span_from(start);
+
+    let mut positional = Vec::new_in(heap);
+    positional.extend(arguments.into_iter().map(argument));
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Call(CallExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            function: heap::Box::new_in(function, heap),
+            arguments: positional,
+            labeled_arguments: Vec::new_in(heap),
+        }),
+    }
+}
+
+/// Creates a call of the operator or special form `name`, whose head spans `range`.
+pub(crate) fn make_named_call<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    range: TextRange,
+    (name, kind): (&str, IdentKind),
+    arguments: impl IntoIterator<Item = Expr<'heap>>,
+) -> Expr<'heap> {
+    let span = state.span(range);
+    let function = Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Path(make_path(state, span, name, kind)),
+    };
+
+    make_call(state, start, function, arguments)
+}
+
+fn parse_argument<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    positional: &mut heap::Vec<'heap, Argument<'heap>>,
+    labeled: &mut heap::Vec<'heap, LabeledArgument<'heap>>,
+) {
+    if state.at(TokenKind::Ident) && state.peek_nth_kind(1) == Some(TokenKind::Colon) {
+        let start = state.start();
+        let token = state.advance().unwrap_or_else(|| unreachable!());
+        let label = make_ident(state, token);
+        state.advance();
+
+        let value = parse_expr(state);
+
+        labeled.push(LabeledArgument {
+            id: NodeId::PLACEHOLDER,
+            span: state.span_from(start),
+            label,
+            value: argument(value),
+        });
+    } else {
+        positional.push(argument(parse_expr(state)));
+    }
+}
+
+/// Parses the arguments of a call, the opening `(` must have already been consumed.
+fn parse_call<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    function: Expr<'heap>,
+) -> Expr<'heap> {
+    let heap = state.heap();
+
+    let mut arguments = Vec::new_in(heap);
+    let mut labeled_arguments = Vec::new_in(heap);
+
+    while !state.at(TokenKind::RParen) && !state.at_end() {
+        parse_argument(state, &mut arguments, &mut labeled_arguments);
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    state.expect_closing(TokenKind::RParen);
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Call(CallExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            function: heap::Box::new_in(function, heap),
+            arguments,
+            labeled_arguments,
+        }),
+    }
+}
+
+fn make_field<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    value: Expr<'heap>,
+    field: Ident<'heap>,
+) -> Expr<'heap> {
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Field(FieldExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            value: heap::Box::new_in(value, state.heap()),
+            field,
+        }),
+    }
+}
+
+fn make_tuple_field<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    range: TextRange,
+    index: &str,
+) -> Ident<'heap> {
+    Ident {
+        span: state.span(range),
+        value: state.intern_symbol(index),
+        kind: IdentKind::Lexical,
+    }
+}
+
+/// Parses a field access, the `.` must have already been consumed.
+///
+/// Tuple fields are accessed by index, such as `pair.0`. Because `pair.0.1` is lexed as the
+/// float `0.1`, floats made up of two indices are split into two consecutive accesses.
+fn parse_field<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    value: Expr<'heap>,
+) -> Expr<'heap> {
+    match state.peek_kind() {
+        Some(TokenKind::Ident | TokenKind::Symbol) => {
+            let token = state.advance().unwrap_or_else(|| unreachable!());
+            let field = make_ident(state, token);
+
+            make_field(state, start, value, field)
+        }
+        Some(TokenKind::Integer) => {
+            let token = state.advance().unwrap_or_else(|| unreachable!());
+            let index = state.text(token);
+            let field = make_tuple_field(state, token.range, index);
+
+            make_field(state, start, value, field)
+        }
+        Some(TokenKind::Float)
+            if let Some(token) = state.peek()
+                && let Some((first, second)) = state.text(token).split_once('.')
+                && second.bytes().all(|byte| byte.is_ascii_digit()) =>
+        {
+            state.advance();
+
+            let split = token.range.start() + TextSize::of(first);
+            let first_range = TextRange::new(token.range.start(), split);
+            let second_range = TextRange::new(split + TextSize::of('.'), token.range.end());
+
+            let field = make_tuple_field(state, first_range, first);
+            let inner_span = state.span(TextRange::new(start, split));
+            let inner = Expr {
+                id: NodeId::PLACEHOLDER,
+                span: inner_span,
+                kind: ExprKind::Field(FieldExpr {
+                    id: NodeId::PLACEHOLDER,
+                    span: inner_span,
+                    value: heap::Box::new_in(value, state.heap()),
+                    field,
+                }),
+            };
+
+            let field = make_tuple_field(state, second_range, second);
+            make_field(state, start, inner, field)
+        }
+        _ => {
+            state.report_unexpected("field name");
+
+            value
+        }
+    }
+}
+
+/// Parses an index access, the opening `[` must have already been consumed.
+fn parse_index<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    value: Expr<'heap>,
+) -> Expr<'heap> {
+    let index = parse_expr(state);
+    state.expect_closing(TokenKind::RBracket);
+
+    let span = state.span_from(start);
+    let heap = state.heap();
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Index(IndexExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            value: heap::Box::new_in(value, heap),
+            index: heap::Box::new_in(index, heap),
+        }),
+    }
+}
+
+/// Applies calls, field and index accesses to `expr`.
+///
+/// Returns whether the outermost access is a call, which determines how a pipeline stage is
+/// applied.
+fn parse_postfix<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    mut expr: Expr<'heap>,
+) -> (Expr<'heap>, bool) {
+    let mut is_call = false;
+
+    loop {
+        if state.eat(TokenKind::LParen).is_some() {
+            expr = parse_call(state, start, expr);
+            is_call = true;
+        } else if state.eat(TokenKind::Dot).is_some() {
+            expr = parse_field(state, start, expr);
+            is_call = false;
+        } else if state.eat(TokenKind::LBracket).is_some() {
+            expr = parse_index(state, start, expr);
+            is_call = false;
+        } else {
+            return (expr, is_call);
+        }
+    }
+}
+
+/// Parses a parenthesized expression, the opening `(` must have already been consumed.
+///
+/// Depending on the contents this is either a grouping `(a)`, a tuple `()`, `(a,)` or `(a, b)`,
+/// or a struct `(:)` or `(a: 1, b: 2)`.
+fn parse_paren<'heap>(state: &mut ParserState<'heap, '_, '_>, start: TextSize) -> Expr<'heap> {
+    let heap = state.heap();
+
+    if state.eat(TokenKind::Colon).is_some() {
+        state.expect_closing(TokenKind::RParen);
+        let span = state.span_from(start);
+
+        return Expr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind: ExprKind::Struct(StructExpr {
+                id: NodeId::PLACEHOLDER,
+                span,
+                entries: Vec::new_in(heap),
+                r#type: None,
+            }),
+        };
+    }
+
+    if state.at(TokenKind::Ident) && state.peek_nth_kind(1) == Some(TokenKind::Colon) {
+        let mut entries = Vec::new_in(heap);
+
+        while !state.at(TokenKind::RParen) && !state.at_end() {
+            let entry_start = state.start();
+
+            let Some(token) = state.expect(TokenKind::Ident) else {
+                break;
+            };
+            let key = make_ident(state, token);
+            state.expect(TokenKind::Colon);
+            let value = parse_expr(state);
+
+            entries.push(StructEntry {
+                id: NodeId::PLACEHOLDER,
+                span: state.span_from(entry_start),
+                key,
+                value,
+            });
+
+            if state.eat(TokenKind::Comma).is_none() {
+                break;
+            }
+        }
+
+        state.expect_closing(TokenKind::RParen);
+        let span = state.span_from(start);
+
+        return Expr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind: ExprKind::Struct(StructExpr {
+                id: NodeId::PLACEHOLDER,
+                span,
+                entries,
+                r#type: None,
+            }),
+        };
+    }
+
+    let mut elements = Vec::new_in(heap);
+
+    if !state.at(TokenKind::RParen) {
+        let first = parse_expr(state);
+
+        if state.eat(TokenKind::Comma).is_none() {
+            state.expect_closing(TokenKind::RParen);
+            return first;
+        }
+
+        elements.push(TupleElement {
+            id: NodeId::PLACEHOLDER,
+            span: first.span,
+            value: first,
+        });
+
+        while !state.at(TokenKind::RParen) && !state.at_end() {
+            let value = parse_expr(state);
+
+            elements.push(TupleElement {
+                id: NodeId::PLACEHOLDER,
+                span: value.span,
+                value,
+            });
+
+            if state.eat(TokenKind::Comma).is_none() {
+                break;
+            }
+        }
+    }
+
+    state.expect_closing(TokenKind::RParen);
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Tuple(TupleExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            elements,
+            r#type: None,
+        }),
+    }
+}
+
+/// Parses a list, the opening `[` must have already been consumed.
+fn parse_list<'heap>(state: &mut ParserState<'heap, '_, '_>, start: TextSize) -> Expr<'heap> {
+    let heap = state.heap();
+    let mut elements = Vec::new_in(heap);
+
+    while !state.at(TokenKind::RBracket) && !state.at_end() {
+        let value = parse_expr(state);
+
+        elements.push(ListElement {
+            id: NodeId::PLACEHOLDER,
+            span: value.span,
+            value: heap::Box::new_in(value, heap),
+        });
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    state.expect_closing(TokenKind::RBracket);
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::List(ListExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            elements,
+            r#type: None,
+        }),
+    }
+}
+
+/// Parses a dict, the opening `{` must have already been consumed.
+fn parse_dict<'heap>(state: &mut ParserState<'heap, '_, '_>, start: TextSize) -> Expr<'heap> {
+    let heap = state.heap();
+    let mut entries = Vec::new_in(heap);
+
+    while !state.at(TokenKind::RBrace) && !state.at_end() {
+        let entry_start = state.start();
+
+        let key = parse_expr(state);
+        state.expect(TokenKind::Colon);
+        let value = parse_expr(state);
+
+        entries.push(DictEntry {
+            id: NodeId::PLACEHOLDER,
+            span: state.span_from(entry_start),
+            key: heap::Box::new_in(key, heap),
+            value: heap::Box::new_in(value, heap),
+        });
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    state.expect_closing(TokenKind::RBrace);
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Dict(DictExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            entries,
+            r#type: None,
+        }),
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)]
+fn parse_primary<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let start = state.start();
+
+    let Some(token) = state.peek() else {
+        state.report_unexpected("expression");
+        return dummy(state.span_from(start));
+    };
+
+    match token.kind {
+        kind if is_literal(kind) => parse_literal(state),
+        TokenKind::Ident if state.text(token) == "_" => {
+            state.advance();
+
+            Expr {
+                id: NodeId::PLACEHOLDER,
+                span: state.span(token.range),
+                kind: ExprKind::Underscore,
+            }
+        }
+        kind if is_path_start(kind) => match parse_path(state, PathMode::Expr) {
+            Some(path) => Expr {
+                id: NodeId::PLACEHOLDER,
+                span: path.span,
+                kind: ExprKind::Path(path),
+            },
+            None => dummy(state.span_from(start)),
+        },
+        TokenKind::LParen => {
+            state.advance();
+            parse_paren(state, start)
+        }
+        TokenKind::LBracket => {
+            state.advance();
+            parse_list(state, start)
+        }
+        TokenKind::LBrace => {
+            state.advance();
+            parse_dict(state, start)
+        }
+        TokenKind::Let => special_form::parse_let(state),
+        TokenKind::Type | TokenKind::Newtype => special_form::parse_type(state),
+        TokenKind::Use => special_form::parse_use(state),
+        TokenKind::If => special_form::parse_if(state),
+        TokenKind::Fn => special_form::parse_fn(state),
+        TokenKind::Match => special_form::parse_match(state),
+        TokenKind::Input => special_form::parse_input(state),
+        _ => {
+            state.report_unexpected("expression");
+            state.recover();
+
+            dummy(state.span_from(start))
+        }
+    }
+}
+
+/// Parses a prefix operator and its operand, or a primary expression with its accesses.
+fn parse_prefix<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let start = state.start();
+
+    match state.peek_kind() {
+        Some(TokenKind::Minus)
+            if matches!(
+                state.peek_nth_kind(1),
+                Some(TokenKind::Integer | TokenKind::Float)
+            ) =>
+        {
+            let minus = state.advance().unwrap_or_else(|| unreachable!());
+            let literal = parse_negative_literal(state, minus);
+
+            parse_postfix(state, start, literal).0
+        }
+        Some(TokenKind::Minus) => {
+            let minus = state.advance().unwrap_or_else(|| unreachable!());
+            let span = state.span(minus.range);
+            state.report(unsupported_negation(span));
+
+            // The operand is still parsed, so that any errors inside of it are reported.
+            parse_expr_bp(state, PREFIX_BINDING_POWER);
+
+            dummy(state.span_from(start))
+        }
+        Some(kind @ (TokenKind::Bang | TokenKind::Tilde)) => {
+            let operator = state.advance().unwrap_or_else(|| unreachable!());
+            let operand = parse_expr_bp(state, PREFIX_BINDING_POWER);
+
+            make_operator_call(state, start, operator, kind, [operand])
+        }
+        _ => {
+            let primary = parse_primary(state);
+            parse_postfix(state, start, primary).0
+        }
+    }
+}
+
+fn make_operator_call<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    operator: Token,
+    kind: TokenKind,
+    arguments: impl IntoIterator<Item = Expr<'heap>>,
+) -> Expr<'heap> {
+    let name = kind
+        .as_operator()
+        .unwrap_or_else(|| unreachable!("operator tokens always have a symbol"));
+
+    make_named_call(
+        state,
+        start,
+        operator.range,
+        (name, IdentKind::Symbol),
+        arguments,
+    )
+}
+
+/// Applies a pipeline stage to `value`.
+///
+/// If the stage is a call, `value` is inserted as its first argument, so `x |> f(y)` is
+/// equivalent to `f(x, y)`. Otherwise the stage is called with `value` as its only argument.
+fn apply_pipeline<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+    value: Expr<'heap>,
+    (callee, is_call): (Expr<'heap>, bool),
+) -> Expr<'heap> {
+    if is_call && let ExprKind::Call(mut call) = callee.kind {
+        call.arguments.insert(0, argument(value));
+
+        let span = state.span_from(start);
+        call.span = span;
+
+        return Expr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind: ExprKind::Call(call),
+        };
+    }
+
+    make_call(state, start, callee, [value])
+}
+
+#[expect(clippy::wildcard_enum_match_arm)]
+fn parse_expr_bp<'heap>(state: &mut ParserState<'heap, '_, '_>, min_bp: u8) -> Expr<'heap> {
+    let start = state.start();
+    let mut lhs = parse_prefix(state);
+
+    while let Some(token) = state.peek() {
+        match token.kind {
+            TokenKind::Pipeline => {
+                if PIPELINE_BINDING_POWER < min_bp {
+                    break;
+                }
+
+                state.advance();
+
+                // A pipeline stage is restricted to a function or a call, anything more complex
+                // needs to be parenthesized.
+                let stage_start = state.start();
+                let primary = parse_primary(state);
+                let callee = parse_postfix(state, stage_start, primary);
+
+                lhs = apply_pipeline(state, start, lhs, callee);
+            }
+            TokenKind::As => {
+                if AS_BINDING_POWER < min_bp {
+                    break;
+                }
+
+                state.advance();
+                let r#type = parse_type_expr(state);
+
+                lhs = make_named_call(
+                    state,
+                    start,
+                    token.range,
+                    ("as", IdentKind::Lexical),
+                    [lhs, r#type],
+                );
+            }
+            kind => {
+                let Some((left, right)) = infix_binding_power(kind) else {
+                    break;
+                };
+
+                if left < min_bp {
+                    break;
+                }
+
+                state.advance();
+                let rhs = parse_expr_bp(state, right);
+
+                lhs = make_operator_call(state, start, token, kind, [lhs, rhs]);
+            }
+        }
+    }
+
+    lhs
+}
+
+/// Parses an expression.
+///
+/// Never fails, errors are reported to the parser state and the affected part of the expression
+/// is replaced with [`ExprKind::Dummy`].
+pub(crate) fn parse_expr<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    parse_expr_bp(state, 0)
+}
@@ -0,0 +1,169 @@
+use hashql_ast::node::{
+    expr::{Expr, ExprKind, LiteralExpr},
+    id::NodeId,
+};
+use hashql_core::{
+    span::{TextRange, TextSize},
+    value::{self, Primitive},
+};
+
+use super::{error::invalid_escape, state::ParserState};
+use crate::lexer::{Token, token_kind::TokenKind};
+
+/// Returns `true` if the token starts a literal.
+pub(crate) const fn is_literal(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Integer
+            | TokenKind::Float
+            | TokenKind::String
+            | TokenKind::True
+            | TokenKind::False
+            | TokenKind::Null
+    )
+}
+
+/// Resolves the escape sequences in the contents of a string literal.
+///
+/// Unknown escape sequences are reported and kept verbatim, so that the remainder of the literal
+/// is still checked.
+fn unescape(state: &mut ParserState<'_, '_, '_>, token: Token) -> String {
+    let source = state.text(token);
+    let contents = &source[1..source.len() - 1];
+    // Offset of the contents relative to the start of the source, skipping the opening quote.
+    let offset = token.range.start() + TextSize::of('"');
+
+    let mut output = String::with_capacity(contents.len());
+    let mut chars = contents.char_indices().peekable();
+
+    while let Some((index, char)) = chars.next() {
+        if char != '\\' {
+            output.push(char);
+            continue;
+        }
+
+        let Some((_, escape)) = chars.next() else {
+            // The lexer guarantees that a backslash is always followed by another character.
+            break;
+        };
+
+        let resolved = match escape {
+            '"' => Some('"'),
+            '\\' => Some('\\'),
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            '0' => Some('\0'),
+            'u' if chars.next_if(|&(_, char)| char == '{').is_some() => {
+                let mut digits = String::new();
+                while let Some((_, digit)) = chars.next_if(|&(_, char)| char != '}') {
+                    digits.push(digit);
+                }
+                let closed = chars.next().is_some();
+
+                closed
+                    .then(|| u32::from_str_radix(&digits, 16).ok())
+                    .flatten()
+                    .and_then(char::from_u32)
+            }
+            _ => None,
+        };
+
+        if let Some(resolved) = resolved {
+            output.push(resolved);
+            continue;
+        }
+
+        let end = chars
+            .peek()
+            .map_or(contents.len(), |&(position, _)| position);
+        let sequence = &contents[index..end];
+
+        let range = TextRange::new(
+            offset + TextSize::try_from(index).expect("source should be smaller than 4GiB"),
+            offset + TextSize::try_from(end).expect("source should be smaller than 4GiB"),
+        );
+
+        let span = state.span(range);
+        let diagnostic = invalid_escape(span, sequence);
+        state.report(diagnostic);
+
+        output.push_str(sequence);
+    }
+
+    output
+}
+
+fn make_literal<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    range: TextRange,
+    kind: Primitive<'heap>,
+) -> Expr<'heap> {
+    let span = state.span(range);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Literal(LiteralExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind,
+            r#type: None,
+        }),
+    }
+}
+
+/// Parses a literal, the next token must satisfy [`is_literal`].
+#[expect(clippy::wildcard_enum_match_arm)]
+pub(crate) fn parse_literal<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let token = state
+        .advance()
+        .expect("caller should have verified that the next token is a literal");
+
+    let kind = match token.kind {
+        TokenKind::Null => Primitive::Null,
+        TokenKind::True => Primitive::Boolean(true),
+        TokenKind::False => Primitive::Boolean(false),
+        TokenKind::Integer => Primitive::Integer(value::Integer::new_unchecked(
+            state.intern_symbol(state.text(token)),
+        )),
+        TokenKind::Float => Primitive::Float(value::Float::new_unchecked(
+            state.intern_symbol(state.text(token)),
+        )),
+        TokenKind::String => {
+            let value = unescape(state, token);
+            Primitive::String(value::String::new(state.intern_symbol(&value)))
+        }
+        _ => unreachable!("caller should have verified that the next token is a literal"),
+    };
+
+    make_literal(state, token.range, kind)
+}
+
+/// Parses a negated numeric literal, the next token must be an integer or float.
+///
+/// The sign is folded into the literal, as there is no unary negation operator.
+pub(crate) fn parse_negative_literal<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    minus: Token,
+) -> Expr<'heap> {
+    let token = state
+        .advance()
+        .expect("caller should have verified that the next token is a number");
+
+    let value = format!("-{}", state.text(token));
+    let value = state.intern_symbol(&value);
+
+    #[expect(clippy::wildcard_enum_match_arm)]
+    let kind = match token.kind {
+        TokenKind::Integer => Primitive::Integer(value::Integer::new_unchecked(value)),
+        TokenKind::Float => Primitive::Float(value::Float::new_unchecked(value)),
+        _ => unreachable!("caller should have verified that the next token is a number"),
+    };
+
+    make_literal(
+        state,
+        TextRange::new(minus.range.start(), token.range.end()),
+        kind,
+    )
+}
@@ -0,0 +1,7 @@
+pub(crate) mod error;
+pub(crate) mod expr;
+mod literal;
+mod path;
+mod special_form;
+pub(crate) mod state;
+mod r#type;
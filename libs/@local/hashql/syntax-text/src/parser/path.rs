@@ -0,0 +1,252 @@
+use hashql_ast::node::{
+    generic::{GenericArgument, GenericConstraint},
+    id::NodeId,
+    path::{Path, PathSegment, PathSegmentArgument},
+};
+use hashql_core::{
+    heap,
+    span::SpanId,
+    symbol::{Ident, IdentKind},
+};
+
+use super::{state::ParserState, r#type::parse_type};
+use crate::lexer::{Token, token_kind::TokenKind};
+
+/// The context a path is parsed in.
+///
+/// Generic arguments are introduced with `<` in types, while in expressions `<` is the less-than
+/// operator and arguments have to be introduced with `::<` instead, e.g. `Some::<Integer>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PathMode {
+    Expr,
+    Type,
+    /// The path of a `use` import, which is followed by `::*` or `::{...}` and never has generic
+    /// arguments.
+    Use,
+}
+
+/// Returns `true` if the token is able to start a path.
+pub(crate) const fn is_path_start(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Ident | TokenKind::Symbol | TokenKind::ColonColon
+    )
+}
+
+/// Creates an identifier from an identifier or escaped symbol token.
+#[expect(clippy::wildcard_enum_match_arm)]
+pub(crate) fn make_ident<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    token: Token,
+) -> Ident<'heap> {
+    let text = state.text(token);
+    let span = state.span(token.range);
+
+    match token.kind {
+        TokenKind::Symbol => {
+            let value = text.trim_matches('`');
+            let kind = if value.contains("://") {
+                IdentKind::BaseUrl
+            } else {
+                IdentKind::Symbol
+            };
+
+            Ident {
+                span,
+                value: state.intern_ident(value),
+                kind,
+            }
+        }
+        _ => Ident {
+            span,
+            value: state.intern_ident(text),
+            kind: IdentKind::Lexical,
+        },
+    }
+}
+
+/// Creates a single segment path that refers to `name`.
+///
+/// Used for the heads of special forms and operators, which are resolved like any other path.
+pub(crate) fn make_path<'heap>(
+    state: &ParserState<'heap, '_, '_>,
+    span: SpanId,
+    name: &str,
+    kind: IdentKind,
+) -> Path<'heap> {
+    let heap = state.heap();
+
+    let mut segments = Vec::with_capacity_in(1, heap);
+    segments.push(PathSegment {
+        id: NodeId::PLACEHOLDER,
+        span,
+        name: Ident {
+            span,
+            value: state.intern_symbol(name),
+            kind,
+        },
+        arguments: Vec::new_in(heap),
+    });
+
+    Path {
+        id: NodeId::PLACEHOLDER,
+        span,
+        rooted: false,
+        segments,
+    }
+}
+
+/// Creates a path from a single identifier.
+pub(crate) fn ident_to_path<'heap>(
+    state: &ParserState<'heap, '_, '_>,
+    ident: Ident<'heap>,
+) -> Path<'heap> {
+    let heap = state.heap();
+
+    let mut segments = Vec::with_capacity_in(1, heap);
+    segments.push(PathSegment {
+        id: NodeId::PLACEHOLDER,
+        span: ident.span,
+        name: ident,
+        arguments: Vec::new_in(heap),
+    });
+
+    Path {
+        id: NodeId::PLACEHOLDER,
+        span: ident.span,
+        rooted: false,
+        segments,
+    }
+}
+
+/// Parses an identifier, or an operator escaped in backticks.
+pub(crate) fn parse_ident<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Option<Ident<'heap>> {
+    if let Some(TokenKind::Ident | TokenKind::Symbol) = state.peek_kind() {
+        let token = state.advance()?;
+        Some(make_ident(state, token))
+    } else {
+        state.report_unexpected("identifier");
+        None
+    }
+}
+
+fn parse_generic_argument<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+) -> PathSegmentArgument<'heap> {
+    let start = state.start();
+
+    if state.at(TokenKind::Ident) && state.peek_nth_kind(1) == Some(TokenKind::Colon) {
+        let token = state.advance().unwrap_or_else(|| unreachable!());
+        let name = make_ident(state, token);
+        state.advance();
+
+        let bound = parse_type(state);
+
+        return PathSegmentArgument::Constraint(GenericConstraint {
+            id: NodeId::PLACEHOLDER,
+            span: state.span_from(start),
+            name,
+            bound: Some(bound),
+        });
+    }
+
+    let r#type = parse_type(state);
+
+    PathSegmentArgument::Argument(GenericArgument {
+        id: NodeId::PLACEHOLDER,
+        span: state.span_from(start),
+        r#type,
+    })
+}
+
+/// Parses the generic arguments of a segment, the opening `<` must have already been consumed.
+fn parse_generic_arguments<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+) -> heap::Vec<'heap, PathSegmentArgument<'heap>> {
+    let mut arguments = Vec::new_in(state.heap());
+
+    loop {
+        arguments.push(parse_generic_argument(state));
+
+        if state.eat(TokenKind::Comma).is_none()
+            || matches!(
+                state.peek_kind(),
+                Some(TokenKind::Gt | TokenKind::Shr | TokenKind::Ge)
+            )
+        {
+            break;
+        }
+    }
+
+    state.expect_closing_angle();
+
+    arguments
+}
+
+fn parse_segment<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    mode: PathMode,
+) -> Option<PathSegment<'heap>> {
+    let start = state.start();
+    let name = parse_ident(state)?;
+
+    let opens_arguments = match mode {
+        PathMode::Type => state.eat(TokenKind::Lt).is_some(),
+        PathMode::Expr => {
+            if state.at(TokenKind::ColonColon) && state.peek_nth_kind(1) == Some(TokenKind::Lt) {
+                state.advance();
+                state.advance();
+                true
+            } else {
+                false
+            }
+        }
+        PathMode::Use => false,
+    };
+
+    let arguments = if opens_arguments {
+        parse_generic_arguments(state)
+    } else {
+        Vec::new_in(state.heap())
+    };
+
+    Some(PathSegment {
+        id: NodeId::PLACEHOLDER,
+        span: state.span_from(start),
+        name,
+        arguments,
+    })
+}
+
+/// Parses a path, such as `x`, `::core::math::add` or `Dict<String, Integer>`.
+///
+/// Returns [`None`] if not even a single segment could be parsed, in which case an error has
+/// been reported.
+pub(crate) fn parse_path<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    mode: PathMode,
+) -> Option<Path<'heap>> {
+    let start = state.start();
+    let rooted = state.eat(TokenKind::ColonColon).is_some();
+
+    let mut segments = Vec::new_in(state.heap());
+    segments.push(parse_segment(state, mode)?);
+
+    while state.at(TokenKind::ColonColon) {
+        let next = state.peek_nth_kind(1);
+
+        if mode == PathMode::Use && matches!(next, Some(TokenKind::Star | TokenKind::LBrace)) {
+            break;
+        }
+
+        state.advance();
+        segments.push(parse_segment(state, mode)?);
+    }
+
+    Some(Path {
+        id: NodeId::PLACEHOLDER,
+        span: state.span_from(start),
+        rooted,
+        segments,
+    })
+}
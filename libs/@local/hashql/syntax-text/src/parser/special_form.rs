@@ -0,0 +1,457 @@
+//! Special forms, such as `let` or `fn`.
+//!
+//! Just like in J-Expr, every special form is represented as a call of the special form by name,
+//! for example `let x = 1 in x` becomes `let(x, 1, x)`. The calls are then turned into their
+//! dedicated nodes during expansion, which means both frontends share the same validation.
+
+use hashql_ast::node::{
+    expr::{Expr, ExprKind, StructExpr, TupleExpr, r#struct::StructEntry, tuple::TupleElement},
+    id::NodeId,
+};
+use hashql_core::{
+    span::{SpanId, TextRange, TextSize},
+    symbol::{Ident, IdentKind},
+};
+
+use super::{
+    expr::{dummy, make_named_call, parse_expr},
+    path::{PathMode, ident_to_path, make_path, parse_ident, parse_path},
+    state::ParserState,
+    r#type::parse_type_expr,
+};
+use crate::lexer::{Token, token_kind::TokenKind};
+
+fn ident_expr<'heap>(state: &ParserState<'heap, '_, '_>, ident: Ident<'heap>) -> Expr<'heap> {
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span: ident.span,
+        kind: ExprKind::Path(ident_to_path(state, ident)),
+    }
+}
+
+/// Parses the name of a binding, substituting a dummy if the name is missing.
+fn parse_binding_name<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let start = state.start();
+
+    match parse_ident(state) {
+        Some(ident) => ident_expr(state, ident),
+        None => dummy(state.span_from(start)),
+    }
+}
+
+/// An underscore, used in place of an omitted type.
+const fn underscore(span: SpanId) -> Expr<'static> {
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Underscore,
+    }
+}
+
+fn empty_span(state: &mut ParserState<'_, '_, '_>) -> SpanId {
+    let end = state.end();
+
+    state.span(TextRange::empty(end))
+}
+
+fn finish<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    keyword: Token,
+    name: &str,
+    arguments: impl IntoIterator<Item = Expr<'heap>>,
+) -> Expr<'heap> {
+    make_named_call(
+        state,
+        keyword.range.start(),
+        keyword.range,
+        (name, IdentKind::Lexical),
+        arguments,
+    )
+}
+
+/// Parses `let name = value in body` or `let name: Type = value in body`.
+pub(crate) fn parse_let<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let name = parse_binding_name(state);
+    let r#type = state.eat(TokenKind::Colon).map(|_| parse_type_expr(state));
+
+    state.expect(TokenKind::Eq);
+    let value = parse_expr(state);
+
+    state.expect(TokenKind::In);
+    let body = parse_expr(state);
+
+    let arguments = [Some(name), r#type, Some(value), Some(body)];
+    finish(state, keyword, "let", arguments.into_iter().flatten())
+}
+
+/// Parses `type Name = Type in body` or `newtype Name = Type in body`.
+///
+/// The name may declare generic parameters, such as `Pair<A, B: Integer>`.
+pub(crate) fn parse_type<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+    let name = if keyword.kind == TokenKind::Newtype {
+        "newtype"
+    } else {
+        "type"
+    };
+
+    let start = state.start();
+    let head = match parse_path(state, PathMode::Type) {
+        Some(path) => Expr {
+            id: NodeId::PLACEHOLDER,
+            span: path.span,
+            kind: ExprKind::Path(path),
+        },
+        None => dummy(state.span_from(start)),
+    };
+
+    state.expect(TokenKind::Eq);
+    let value = parse_type_expr(state);
+
+    state.expect(TokenKind::In);
+    let body = parse_expr(state);
+
+    finish(state, keyword, name, [head, value, body])
+}
+
+/// Parses the imports of a `use`, the `{` must be the next token.
+///
+/// Imports without any alias are represented as a tuple `(a, b)`, otherwise as a struct
+/// `(a: _, b: c)` in which `_` keeps the original name.
+fn parse_use_imports<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let start = state.start();
+    state.advance();
+
+    let mut imports = Vec::new();
+
+    while !state.at(TokenKind::RBrace) && !state.at_end() {
+        let entry_start = state.start();
+        let Some(name) = parse_ident(state) else {
+            break;
+        };
+
+        let alias = state.eat(TokenKind::Colon).map(|_| match state.peek() {
+            Some(token) if token.kind == TokenKind::Ident && state.text(token) == "_" => {
+                state.advance();
+                underscore(state.span(token.range))
+            }
+            _ => parse_binding_name(state),
+        });
+
+        imports.push((state.span_from(entry_start), name, alias));
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    state.expect_closing(TokenKind::RBrace);
+    let span = state.span_from(start);
+    let heap = state.heap();
+
+    let kind = if imports.iter().any(|(_, _, alias)| alias.is_some()) {
+        let mut entries = Vec::with_capacity_in(imports.len(), heap);
+
+        for (entry_span, key, alias) in imports {
+            entries.push(StructEntry {
+                id: NodeId::PLACEHOLDER,
+                span: entry_span,
+                key,
+                value: alias.unwrap_or_else(|| underscore(key.span)),
+            });
+        }
+
+        ExprKind::Struct(StructExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            entries,
+            r#type: None,
+        })
+    } else {
+        let mut elements = Vec::with_capacity_in(imports.len(), heap);
+
+        for (entry_span, name, _) in imports {
+            elements.push(TupleElement {
+                id: NodeId::PLACEHOLDER,
+                span: entry_span,
+                value: ident_expr(state, name),
+            });
+        }
+
+        ExprKind::Tuple(TupleExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            elements,
+            r#type: None,
+        })
+    };
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind,
+    }
+}
+
+/// Parses `use path::* in body` or `use path::{a, b: c} in body`.
+pub(crate) fn parse_use<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let start = state.start();
+    let path = match parse_path(state, PathMode::Use) {
+        Some(path) => Expr {
+            id: NodeId::PLACEHOLDER,
+            span: path.span,
+            kind: ExprKind::Path(path),
+        },
+        None => dummy(state.span_from(start)),
+    };
+
+    state.expect(TokenKind::ColonColon);
+
+    let imports = match state.peek() {
+        Some(token) if token.kind == TokenKind::Star => {
+            state.advance();
+            let span = state.span(token.range);
+
+            Expr {
+                id: NodeId::PLACEHOLDER,
+                span,
+                kind: ExprKind::Path(make_path(state, span, "*", IdentKind::Symbol)),
+            }
+        }
+        Some(token) if token.kind == TokenKind::LBrace => parse_use_imports(state),
+        _ => {
+            let start = state.start();
+            state.report_unexpected("`*` or `{`");
+
+            dummy(state.span_from(start))
+        }
+    };
+
+    state.expect(TokenKind::In);
+    let body = parse_expr(state);
+
+    finish(state, keyword, "use", [path, imports, body])
+}
+
+/// Parses `if condition then value` or `if condition then value else other`.
+pub(crate) fn parse_if<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let condition = parse_expr(state);
+
+    state.expect(TokenKind::Then);
+    let then = parse_expr(state);
+
+    let r#else = state.eat(TokenKind::Else).map(|_| parse_expr(state));
+
+    let arguments = [Some(condition), Some(then), r#else];
+    finish(state, keyword, "if", arguments.into_iter().flatten())
+}
+
+/// Parses the generic parameters of a function, the `<` must have already been consumed.
+///
+/// Parameters without any bound are represented as a tuple `(T, U)`, otherwise as a struct
+/// `(T: Bound, U: _)`.
+fn parse_fn_generics<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    start: TextSize,
+) -> Expr<'heap> {
+    let mut generics = Vec::new();
+
+    while !matches!(
+        state.peek_kind(),
+        None | Some(TokenKind::Gt | TokenKind::Shr | TokenKind::Ge)
+    ) {
+        let entry_start = state.start();
+        let Some(name) = parse_ident(state) else {
+            break;
+        };
+
+        let bound = state.eat(TokenKind::Colon).map(|_| parse_type_expr(state));
+
+        generics.push((state.span_from(entry_start), name, bound));
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    state.expect_closing_angle();
+    let span = state.span_from(start);
+    let heap = state.heap();
+
+    let kind = if generics.iter().any(|(_, _, bound)| bound.is_some()) {
+        let mut entries = Vec::with_capacity_in(generics.len(), heap);
+
+        for (entry_span, key, bound) in generics {
+            entries.push(StructEntry {
+                id: NodeId::PLACEHOLDER,
+                span: entry_span,
+                key,
+                value: bound.unwrap_or_else(|| underscore(key.span)),
+            });
+        }
+
+        ExprKind::Struct(StructExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            entries,
+            r#type: None,
+        })
+    } else {
+        let mut elements = Vec::with_capacity_in(generics.len(), heap);
+
+        for (entry_span, name, _) in generics {
+            elements.push(TupleElement {
+                id: NodeId::PLACEHOLDER,
+                span: entry_span,
+                value: ident_expr(state, name),
+            });
+        }
+
+        ExprKind::Tuple(TupleExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            elements,
+            r#type: None,
+        })
+    };
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind,
+    }
+}
+
+/// Parses the parameters of a function, such as `(x: Integer, y: _)`.
+///
+/// A parameter without a type annotation has its type inferred.
+fn parse_fn_params<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let start = state.start();
+    let mut entries = Vec::new_in(state.heap());
+
+    if state.expect(TokenKind::LParen).is_some() {
+        while !state.at(TokenKind::RParen) && !state.at_end() {
+            let entry_start = state.start();
+            let Some(key) = parse_ident(state) else {
+                break;
+            };
+
+            let value = match state.eat(TokenKind::Colon) {
+                Some(_) => parse_type_expr(state),
+                None => underscore(key.span),
+            };
+
+            entries.push(StructEntry {
+                id: NodeId::PLACEHOLDER,
+                span: state.span_from(entry_start),
+                key,
+                value,
+            });
+
+            if state.eat(TokenKind::Comma).is_none() {
+                break;
+            }
+        }
+
+        state.expect_closing(TokenKind::RParen);
+    }
+
+    let span = state.span_from(start);
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: ExprKind::Struct(StructExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            entries,
+            r#type: None,
+        }),
+    }
+}
+
+/// Parses `fn<T>(x: T) -> T => body`.
+///
+/// The generic parameters and the return type are optional, an omitted return type is inferred.
+pub(crate) fn parse_fn<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let start = state.start();
+    let generics = if state.eat(TokenKind::Lt).is_some() {
+        parse_fn_generics(state, start)
+    } else {
+        let span = empty_span(state);
+
+        Expr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            kind: ExprKind::Tuple(TupleExpr {
+                id: NodeId::PLACEHOLDER,
+                span,
+                elements: Vec::new_in(state.heap()),
+                r#type: None,
+            }),
+        }
+    };
+
+    let params = parse_fn_params(state);
+
+    let return_type = match state.eat(TokenKind::Arrow) {
+        Some(_) => parse_type_expr(state),
+        None => underscore(empty_span(state)),
+    };
+
+    state.expect(TokenKind::FatArrow);
+    let body = parse_expr(state);
+
+    finish(state, keyword, "fn", [generics, params, return_type, body])
+}
+
+/// Parses `match value { pattern => body, ... }`.
+///
+/// Patterns are parsed as regular expressions and are validated during expansion.
+pub(crate) fn parse_match<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let mut arguments = vec![parse_expr(state)];
+
+    if state.expect(TokenKind::LBrace).is_some() {
+        while !state.at(TokenKind::RBrace) && !state.at_end() {
+            let pattern = parse_expr(state);
+            state.expect(TokenKind::FatArrow);
+            let body = parse_expr(state);
+
+            arguments.push(pattern);
+            arguments.push(body);
+
+            if state.eat(TokenKind::Comma).is_none() {
+                break;
+            }
+        }
+
+        state.expect_closing(TokenKind::RBrace);
+    }
+
+    finish(state, keyword, "match", arguments)
+}
+
+/// Parses `input name: Type` or `input name: Type = default`.
+pub(crate) fn parse_input<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let keyword = state.advance().unwrap_or_else(|| unreachable!());
+
+    let name = parse_binding_name(state);
+
+    state.expect(TokenKind::Colon);
+    let r#type = parse_type_expr(state);
+
+    let default = state.eat(TokenKind::Eq).map(|_| parse_expr(state));
+
+    let arguments = [Some(name), Some(r#type), default];
+    finish(state, keyword, "input", arguments.into_iter().flatten())
+}
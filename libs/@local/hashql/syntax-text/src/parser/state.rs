@@ -0,0 +1,295 @@
+use core::fmt::Display;
+
+use hashql_core::{
+    heap::Heap,
+    span::{SpanAncestors, SpanId, SpanTable, TextRange, TextSize},
+    symbol::Symbol,
+};
+use hashql_diagnostics::DiagnosticIssues;
+use unicode_normalization::{IsNormalized, UnicodeNormalization as _, is_nfc_quick};
+
+use super::error::{ParserDiagnostic, expected_eof, unexpected_token};
+use crate::{
+    error::{TextDiagnosticCategory, TextDiagnosticIssues},
+    lexer::{
+        Lexer, Token,
+        error::{LexerDiagnosticCategory, invalid_character, unterminated_string},
+        token_kind::TokenKind,
+    },
+    span::Span,
+};
+
+/// Tokens at which error recovery stops skipping input.
+///
+/// These either close a surrounding construct or separate its parts, so the enclosing parser is
+/// able to pick up from there.
+const SYNCHRONIZATION: &[TokenKind] = &[
+    TokenKind::RParen,
+    TokenKind::RBracket,
+    TokenKind::RBrace,
+    TokenKind::Comma,
+    TokenKind::In,
+    TokenKind::Then,
+    TokenKind::Else,
+    TokenKind::FatArrow,
+];
+
+pub(crate) struct ParserState<'heap, 'source, 'spans> {
+    heap: &'heap Heap,
+    source: &'source str,
+
+    tokens: Vec<Token>,
+    position: usize,
+    /// The end of the last consumed token.
+    end: TextSize,
+
+    spans: &'spans mut SpanTable<Span>,
+    diagnostics: TextDiagnosticIssues,
+
+    /// The position of the token at which the last error has been reported.
+    ///
+    /// Used to suppress cascading errors, as once a token has been rejected any further
+    /// expectation at the same position is almost certainly a consequence of the first error.
+    last_error: Option<usize>,
+}
+
+impl<'heap, 'source, 'spans> ParserState<'heap, 'source, 'spans> {
+    pub(crate) fn new(
+        heap: &'heap Heap,
+        source: &'source str,
+        spans: &'spans mut SpanTable<Span>,
+    ) -> Self {
+        let mut tokens = Vec::new();
+        let mut diagnostics = DiagnosticIssues::new();
+
+        for token in Lexer::new(source) {
+            match token {
+                Ok(token) => tokens.push(token),
+                Err(error) => {
+                    let span = spans.insert(Span::new(error.range), SpanAncestors::EMPTY);
+
+                    let diagnostic = match error.category {
+                        LexerDiagnosticCategory::InvalidCharacter => {
+                            invalid_character(span, &source[error.range])
+                        }
+                        LexerDiagnosticCategory::UnterminatedString => unterminated_string(span),
+                    };
+
+                    diagnostics.push(diagnostic.map_category(TextDiagnosticCategory::Lexer));
+                }
+            }
+        }
+
+        Self {
+            heap,
+            source,
+            tokens,
+            position: 0,
+            end: TextSize::default(),
+            spans,
+            diagnostics,
+            last_error: None,
+        }
+    }
+
+    pub(crate) const fn heap(&self) -> &'heap Heap {
+        self.heap
+    }
+
+    pub(crate) fn intern_symbol(&self, value: &str) -> Symbol<'heap> {
+        self.heap.intern_symbol(value)
+    }
+
+    /// Interns an identifier, normalizing it to NFC first.
+    pub(crate) fn intern_ident(&self, value: &str) -> Symbol<'heap> {
+        if is_nfc_quick(value.chars()) == IsNormalized::Yes {
+            self.heap.intern_symbol(value)
+        } else {
+            let normalized: String = value.nfc().collect();
+            self.heap.intern_symbol(&normalized)
+        }
+    }
+
+    pub(crate) fn span(&mut self, range: TextRange) -> SpanId {
+        self.spans.insert(Span::new(range), SpanAncestors::EMPTY)
+    }
+
+    /// Creates a span from `start` up to the end of the last consumed token.
+    pub(crate) fn span_from(&mut self, start: TextSize) -> SpanId {
+        let end = self.end().max(start);
+
+        self.span(TextRange::new(start, end))
+    }
+
+    pub(crate) fn text(&self, token: Token) -> &'source str {
+        &self.source[token.range]
+    }
+
+    pub(crate) fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.position).copied()
+    }
+
+    pub(crate) fn peek_kind(&self) -> Option<TokenKind> {
+        self.peek().map(|token| token.kind)
+    }
+
+    pub(crate) fn peek_nth_kind(&self, n: usize) -> Option<TokenKind> {
+        self.tokens.get(self.position + n).map(|token| token.kind)
+    }
+
+    pub(crate) fn at(&self, kind: TokenKind) -> bool {
+        self.peek_kind() == Some(kind)
+    }
+
+    pub(crate) const fn at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    /// The start of the next token, or the end of the source if all tokens have been consumed.
+    pub(crate) fn start(&self) -> TextSize {
+        self.peek()
+            .map_or_else(|| self.source_end(), |token| token.range.start())
+    }
+
+    /// The end of the last consumed token.
+    pub(crate) const fn end(&self) -> TextSize {
+        self.end
+    }
+
+    fn source_end(&self) -> TextSize {
+        TextSize::of(self.source)
+    }
+
+    pub(crate) fn advance(&mut self) -> Option<Token> {
+        let token = self.peek()?;
+        self.position += 1;
+        self.end = token.range.end();
+
+        Some(token)
+    }
+
+    pub(crate) fn eat(&mut self, kind: TokenKind) -> Option<Token> {
+        if self.at(kind) { self.advance() } else { None }
+    }
+
+    /// Consumes the next token if it is of the given kind, otherwise reports an error.
+    ///
+    /// The offending token is not consumed, so that the caller is able to recover.
+    pub(crate) fn expect(&mut self, kind: TokenKind) -> Option<Token> {
+        let token = self.eat(kind);
+
+        if token.is_none() {
+            self.report_unexpected(kind);
+        }
+
+        token
+    }
+
+    /// Consumes the closing delimiter of a construct opened by the caller.
+    ///
+    /// If the next token is not the delimiter, an error is reported and everything up to and
+    /// including the matching delimiter is skipped, so that a malformed element does not cascade
+    /// into errors in the surrounding expression. Skipping stops early at any closing delimiter
+    /// that belongs to an enclosing construct.
+    #[expect(clippy::wildcard_enum_match_arm)]
+    pub(crate) fn expect_closing(&mut self, close: TokenKind) -> Option<Token> {
+        if let Some(token) = self.eat(close) {
+            return Some(token);
+        }
+
+        self.report_unexpected(close);
+
+        let mut depth = 0_usize;
+        while let Some(kind) = self.peek_kind() {
+            match kind {
+                TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => depth += 1,
+                TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace if depth > 0 => {
+                    depth -= 1;
+                }
+                _ if kind == close => return self.advance(),
+                TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace => return None,
+                _ => {}
+            }
+
+            self.advance();
+        }
+
+        None
+    }
+
+    /// Consumes a single `>`, splitting tokens that start with `>`, such as `>>`.
+    ///
+    /// This is required to close nested generic arguments, like `List<Option<Integer>>`.
+    pub(crate) fn expect_closing_angle(&mut self) -> Option<TextRange> {
+        let token = self.peek();
+
+        let remainder = match token.map(|token| token.kind) {
+            Some(TokenKind::Gt) => return self.advance().map(|token| token.range),
+            Some(TokenKind::Shr) => TokenKind::Gt,
+            Some(TokenKind::Ge) => TokenKind::Eq,
+            _ => {
+                self.report_unexpected(TokenKind::Gt);
+                return None;
+            }
+        };
+
+        let token = token?;
+        let split = token.range.start() + TextSize::of('>');
+
+        let slot = self.tokens.get_mut(self.position)?;
+        *slot = Token {
+            kind: remainder,
+            range: TextRange::new(split, token.range.end()),
+        };
+        self.end = split;
+
+        Some(TextRange::new(token.range.start(), split))
+    }
+
+    /// Reports that something else than the next token was expected.
+    pub(crate) fn report_unexpected(&mut self, expected: impl Display) {
+        if self.last_error == Some(self.position) {
+            return;
+        }
+
+        #[expect(clippy::option_if_let_else, reason = "readability")]
+        let (range, found) = match self.peek() {
+            Some(token) => (token.range, Some(token.kind)),
+            None => (TextRange::empty(self.source_end()), None),
+        };
+
+        let span = self.span(range);
+        self.report(unexpected_token(span, expected, found));
+        self.last_error = Some(self.position);
+    }
+
+    pub(crate) fn report(&mut self, diagnostic: ParserDiagnostic) {
+        self.diagnostics
+            .push(diagnostic.map_category(TextDiagnosticCategory::Parser));
+    }
+
+    /// Skips the next token, unless it is one the enclosing parser is able to recover from.
+    pub(crate) fn recover(&mut self) {
+        if self
+            .peek_kind()
+            .is_some_and(|kind| !SYNCHRONIZATION.contains(&kind))
+        {
+            self.advance();
+        }
+    }
+
+    /// Finishes parsing, returning every diagnostic reported along the way.
+    ///
+    /// Any remaining input is reported as an error.
+    pub(crate) fn finish(mut self) -> TextDiagnosticIssues {
+        if let Some(token) = self.peek()
+            && self.last_error != Some(self.position)
+        {
+            let span = self.span(TextRange::new(token.range.start(), self.source_end()));
+
+            self.report(expected_eof(span));
+        }
+
+        self.diagnostics
+    }
+}
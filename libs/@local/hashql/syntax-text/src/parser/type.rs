@@ -0,0 +1,321 @@
+use hashql_ast::node::{
+    expr::{
+        CallExpr, Expr, ExprKind, StructExpr, TupleExpr, call::Argument, r#struct::StructEntry,
+        tuple::TupleElement,
+    },
+    id::NodeId,
+    r#type::{
+        IntersectionType, StructField, StructType, TupleField, TupleType, Type, TypeKind, UnionType,
+    },
+};
+use hashql_core::{
+    heap::{self, CollectIn as _},
+    span::SpanId,
+    symbol::IdentKind,
+};
+
+use super::{
+    path::{PathMode, is_path_start, make_ident, make_path, parse_path},
+    state::ParserState,
+};
+use crate::lexer::token_kind::TokenKind;
+
+const fn dummy(span: SpanId) -> Type<'static> {
+    Type {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: TypeKind::Dummy,
+    }
+}
+
+fn parse_struct_fields<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+) -> heap::Vec<'heap, StructField<'heap>> {
+    let mut fields = Vec::new_in(state.heap());
+
+    while !state.at(TokenKind::RParen) && !state.at_end() {
+        let start = state.start();
+
+        let Some(token) = state.expect(TokenKind::Ident) else {
+            break;
+        };
+        let name = make_ident(state, token);
+        state.expect(TokenKind::Colon);
+        let r#type = parse_type(state);
+
+        fields.push(StructField {
+            id: NodeId::PLACEHOLDER,
+            span: state.span_from(start),
+            name,
+            r#type,
+        });
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    fields
+}
+
+fn parse_tuple_fields<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    first: Type<'heap>,
+) -> heap::Vec<'heap, TupleField<'heap>> {
+    let mut fields = Vec::new_in(state.heap());
+    fields.push(TupleField {
+        id: NodeId::PLACEHOLDER,
+        span: first.span,
+        r#type: first,
+    });
+
+    while !state.at(TokenKind::RParen) && !state.at_end() {
+        let r#type = parse_type(state);
+
+        fields.push(TupleField {
+            id: NodeId::PLACEHOLDER,
+            span: r#type.span,
+            r#type,
+        });
+
+        if state.eat(TokenKind::Comma).is_none() {
+            break;
+        }
+    }
+
+    fields
+}
+
+enum ParenType<'heap> {
+    Tuple(heap::Vec<'heap, TupleField<'heap>>),
+    Struct(heap::Vec<'heap, StructField<'heap>>),
+}
+
+/// Parses a parenthesized type, the opening `(` must be the next token.
+///
+/// Supported forms are `()`, `(:)`, `(a: A, b: B)`, `(A,)`, `(A, B)` and `(A)`, the latter being
+/// a grouping rather than a tuple.
+fn parse_paren_type<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Type<'heap> {
+    let start = state.start();
+    state.advance();
+
+    let paren = if state.at(TokenKind::RParen) {
+        ParenType::Tuple(Vec::new_in(state.heap()))
+    } else if state.at(TokenKind::Colon) && state.peek_nth_kind(1) == Some(TokenKind::RParen) {
+        state.advance();
+
+        ParenType::Struct(Vec::new_in(state.heap()))
+    } else if state.at(TokenKind::Ident) && state.peek_nth_kind(1) == Some(TokenKind::Colon) {
+        ParenType::Struct(parse_struct_fields(state))
+    } else {
+        let first = parse_type(state);
+
+        if state.eat(TokenKind::Comma).is_none() {
+            state.expect_closing(TokenKind::RParen);
+            return first;
+        }
+
+        ParenType::Tuple(parse_tuple_fields(state, first))
+    };
+
+    state.expect_closing(TokenKind::RParen);
+    let span = state.span_from(start);
+
+    let kind = match paren {
+        ParenType::Tuple(fields) => TypeKind::Tuple(TupleType {
+            id: NodeId::PLACEHOLDER,
+            span,
+            fields,
+        }),
+        ParenType::Struct(fields) => TypeKind::Struct(StructType {
+            id: NodeId::PLACEHOLDER,
+            span,
+            fields,
+        }),
+    };
+
+    Type {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind,
+    }
+}
+
+fn parse_type_atom<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Type<'heap> {
+    match state.peek_kind() {
+        Some(TokenKind::Ident) if state.peek().is_some_and(|token| state.text(token) == "_") => {
+            let token = state.advance().unwrap_or_else(|| unreachable!());
+
+            Type {
+                id: NodeId::PLACEHOLDER,
+                span: state.span(token.range),
+                kind: TypeKind::Infer,
+            }
+        }
+        Some(TokenKind::LParen) => parse_paren_type(state),
+        Some(kind) if is_path_start(kind) => {
+            let start = state.start();
+
+            match parse_path(state, PathMode::Type) {
+                Some(path) => Type {
+                    id: NodeId::PLACEHOLDER,
+                    span: path.span,
+                    kind: TypeKind::Path(path),
+                },
+                None => dummy(state.span_from(start)),
+            }
+        }
+        _ => {
+            let start = state.start();
+            state.report_unexpected("type");
+            state.recover();
+
+            dummy(state.span_from(start))
+        }
+    }
+}
+
+/// Parses a list of types separated by `separator`, binding tighter types with `next`.
+fn parse_type_operator<'heap>(
+    state: &mut ParserState<'heap, '_, '_>,
+    separator: TokenKind,
+    next: fn(&mut ParserState<'heap, '_, '_>) -> Type<'heap>,
+    make: fn(SpanId, heap::Vec<'heap, Type<'heap>>) -> TypeKind<'heap>,
+) -> Type<'heap> {
+    let start = state.start();
+    let first = next(state);
+
+    if !state.at(separator) {
+        return first;
+    }
+
+    let mut types = Vec::new_in(state.heap());
+    types.push(first);
+
+    while state.eat(separator).is_some() {
+        types.push(next(state));
+    }
+
+    let span = state.span_from(start);
+
+    Type {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind: make(span, types),
+    }
+}
+
+fn parse_union_type<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Type<'heap> {
+    parse_type_operator(state, TokenKind::Pipe, parse_type_atom, |span, types| {
+        TypeKind::Union(UnionType {
+            id: NodeId::PLACEHOLDER,
+            span,
+            types,
+        })
+    })
+}
+
+/// Parses a type, such as `Integer`, `List<String>`, `(a: Integer, b: _)` or `A | B & C`.
+///
+/// Mirrors the J-Expr type grammar, in which union binds tighter than intersection.
+pub(crate) fn parse_type<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Type<'heap> {
+    parse_type_operator(state, TokenKind::Amp, parse_union_type, |span, types| {
+        TypeKind::Intersection(IntersectionType {
+            id: NodeId::PLACEHOLDER,
+            span,
+            types,
+        })
+    })
+}
+
+/// Converts a type into the expression that denotes it.
+///
+/// Special forms take their types as regular arguments, which are converted back into types
+/// during expansion, unions and intersections become calls to `|` and `&` respectively.
+pub(crate) fn type_into_expr<'heap>(
+    state: &ParserState<'heap, '_, '_>,
+    r#type: Type<'heap>,
+) -> Expr<'heap> {
+    let heap = state.heap();
+    let span = r#type.span;
+
+    let operator = |name: &str, types: heap::Vec<'heap, Type<'heap>>| {
+        let arguments = types
+            .into_iter()
+            .map(|r#type| {
+                let value = type_into_expr(state, r#type);
+
+                Argument {
+                    id: NodeId::PLACEHOLDER,
+                    span: value.span,
+                    value,
+                }
+            })
+            .collect_in(heap);
+
+        ExprKind::Call(CallExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            function: heap::Box::new_in(
+                Expr {
+                    id: NodeId::PLACEHOLDER,
+                    span,
+                    kind: ExprKind::Path(make_path(state, span, name, IdentKind::Symbol)),
+                },
+                heap,
+            ),
+            arguments,
+            labeled_arguments: Vec::new_in(heap),
+        })
+    };
+
+    let kind = match r#type.kind {
+        TypeKind::Infer => ExprKind::Underscore,
+        TypeKind::Path(path) => ExprKind::Path(path),
+        TypeKind::Tuple(tuple) => ExprKind::Tuple(TupleExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            elements: tuple
+                .fields
+                .into_iter()
+                .map(|field| TupleElement {
+                    id: NodeId::PLACEHOLDER,
+                    span: field.span,
+                    value: type_into_expr(state, field.r#type),
+                })
+                .collect_in(heap),
+            r#type: None,
+        }),
+        TypeKind::Struct(r#struct) => ExprKind::Struct(StructExpr {
+            id: NodeId::PLACEHOLDER,
+            span,
+            entries: r#struct
+                .fields
+                .into_iter()
+                .map(|field| StructEntry {
+                    id: NodeId::PLACEHOLDER,
+                    span: field.span,
+                    key: field.name,
+                    value: type_into_expr(state, field.r#type),
+                })
+                .collect_in(heap),
+            r#type: None,
+        }),
+        TypeKind::Union(union) => operator("|", union.types),
+        TypeKind::Intersection(intersection) => operator("&", intersection.types),
+        TypeKind::Dummy => ExprKind::Dummy,
+    };
+
+    Expr {
+        id: NodeId::PLACEHOLDER,
+        span,
+        kind,
+    }
+}
+
+/// Parses a type in the position of an expression, see [`type_into_expr`].
+pub(crate) fn parse_type_expr<'heap>(state: &mut ParserState<'heap, '_, '_>) -> Expr<'heap> {
+    let r#type = parse_type(state);
+
+    type_into_expr(state, r#type)
+}
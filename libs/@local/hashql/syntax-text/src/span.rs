@@ -0,0 +1,18 @@
+use hashql_core::span::TextRange;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub range: TextRange,
+}
+
+impl Span {
+    pub(crate) const fn new(range: TextRange) -> Self {
+        Self { range }
+    }
+}
+
+impl hashql_core::span::Span for Span {
+    fn range(&self) -> TextRange {
+        self.range
+    }
+}
@@ -0,0 +1,412 @@
+#[cfg(feature = "std")]
+use std::io::{self, IsTerminal};
+
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{Background, BasicColor, Color, Foreground, IndexedColor, RgbColor, Style};
+
+/// Color support of a terminal.
+///
+/// The levels are ordered, a terminal that supports a level is assumed to support every level
+/// below it as well.
+///
+/// ## Detection
+///
+/// There is no reliable way to query a terminal for its capabilities, instead the de-facto
+/// standard is to inspect a set of environment variables:
+///
+/// * [`NO_COLOR`]: if set (and not empty), color output is disabled.
+/// * `TERM`: the name of the terminal, `dumb` terminals do not support any escape sequences, names
+///   ending in `-256color` indicate support for [`IndexedColor`], while names ending in `-direct`
+///   indicate support for [`RgbColor`].
+/// * `COLORTERM`: set to `truecolor` or `24bit` by terminals that support [`RgbColor`].
+///
+/// Output that isn't written to a terminal (like a pipe or file) never receives escape sequences.
+///
+/// Use [`Capability::from_env`] to detect the capability using arbitrary environment variables,
+/// or, with the `std` feature enabled, [`Capability::detect`], [`Capability::stdout`] and
+/// [`Capability::stderr`] to detect the capability of the current process.
+///
+/// [`NO_COLOR`]: https://no-color.org/
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Capability {
+    /// No support for escape sequences.
+    None,
+
+    /// Support for the [`BasicColor`] and [`BrightColor`] palette.
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    Basic,
+
+    /// Support for [`IndexedColor`], more commonly known as xterm-color or 256 colors.
+    Indexed,
+
+    /// Support for [`RgbColor`], more commonly known as truecolor.
+    TrueColor,
+}
+
+impl Capability {
+    /// Detects the capability from the environment.
+    ///
+    /// `lookup` is used to retrieve the value of an environment variable, `is_terminal` signals if
+    /// the output is written to a terminal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::Capability;
+    ///
+    /// fn env<'a>(vars: &'a [(&str, &'a str)]) -> impl Fn(&str) -> Option<&'a str> + 'a {
+    ///     move |name| {
+    ///         vars.iter()
+    ///             .find(|(key, _)| *key == name)
+    ///             .map(|(_, value)| *value)
+    ///     }
+    /// }
+    ///
+    /// let vars = [("TERM", "xterm-256color"), ("COLORTERM", "truecolor")];
+    /// assert_eq!(
+    ///     Capability::from_env(env(&vars), true),
+    ///     Capability::TrueColor
+    /// );
+    /// assert_eq!(Capability::from_env(env(&vars), false), Capability::None);
+    ///
+    /// let vars = [("TERM", "xterm-256color")];
+    /// assert_eq!(Capability::from_env(env(&vars), true), Capability::Indexed);
+    ///
+    /// let vars = [("TERM", "xterm-256color"), ("NO_COLOR", "1")];
+    /// assert_eq!(Capability::from_env(env(&vars), true), Capability::None);
+    ///
+    /// let vars = [("TERM", "dumb")];
+    /// assert_eq!(Capability::from_env(env(&vars), true), Capability::None);
+    /// ```
+    pub fn from_env<F, V>(lookup: F, is_terminal: bool) -> Self
+    where
+        F: Fn(&str) -> Option<V>,
+        V: AsRef<str>,
+    {
+        // see: https://no-color.org/
+        if lookup("NO_COLOR").is_some_and(|value| !value.as_ref().is_empty()) {
+            return Self::None;
+        }
+
+        if !is_terminal {
+            return Self::None;
+        }
+
+        let term = lookup("TERM");
+        let term = term.as_ref().map(AsRef::as_ref);
+
+        if term == Some("dumb") {
+            return Self::None;
+        }
+
+        let colorterm = lookup("COLORTERM");
+        if matches!(
+            colorterm.as_ref().map(AsRef::as_ref),
+            Some("truecolor" | "24bit")
+        ) {
+            return Self::TrueColor;
+        }
+
+        match term {
+            Some(term) if term.ends_with("-direct") => Self::TrueColor,
+            Some(term) if term.ends_with("-256color") || term.ends_with("-256") => Self::Indexed,
+            Some(_) => Self::Basic,
+            None => Self::None,
+        }
+    }
+
+    /// Detects the capability of the given stream from the environment of the current process.
+    ///
+    /// See [`Capability::from_env`] for details on how the capability is detected.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn detect(stream: &impl IsTerminal) -> Self {
+        Self::from_env(|name| std::env::var(name).ok(), stream.is_terminal())
+    }
+
+    /// Detects the capability of standard output.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn stdout() -> Self {
+        Self::detect(&io::stdout())
+    }
+
+    /// Detects the capability of standard error.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn stderr() -> Self {
+        Self::detect(&io::stderr())
+    }
+}
+
+/// Approximation of the [`BasicColor`] and [`BrightColor`] palette.
+///
+/// The actual colors are chosen by the terminal (and are often configurable by the user), these
+/// are the default values used by xterm.
+///
+/// [`BrightColor`]: crate::BrightColor
+const PALETTE: [RgbColor; 16] = [
+    RgbColor::new(0, 0, 0),
+    RgbColor::new(205, 0, 0),
+    RgbColor::new(0, 205, 0),
+    RgbColor::new(205, 205, 0),
+    RgbColor::new(0, 0, 238),
+    RgbColor::new(205, 0, 205),
+    RgbColor::new(0, 205, 205),
+    RgbColor::new(229, 229, 229),
+    RgbColor::new(127, 127, 127),
+    RgbColor::new(255, 0, 0),
+    RgbColor::new(0, 255, 0),
+    RgbColor::new(255, 255, 0),
+    RgbColor::new(92, 92, 255),
+    RgbColor::new(255, 0, 255),
+    RgbColor::new(0, 255, 255),
+    RgbColor::new(255, 255, 255),
+];
+
+/// Intensity of each step of the 6x6x6 color cube (`16` - `231`) of [`IndexedColor`].
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Squared euclidean distance between two colors.
+fn distance(lhs: RgbColor, rhs: RgbColor) -> u32 {
+    let component = |lhs: u8, rhs: u8| {
+        let delta = u32::from(lhs.abs_diff(rhs));
+        delta * delta
+    };
+
+    component(lhs.red(), rhs.red())
+        + component(lhs.green(), rhs.green())
+        + component(lhs.blue(), rhs.blue())
+}
+
+/// Index of the step in the color cube that is closest to the given intensity.
+fn cube_step(value: u8) -> u8 {
+    (0..)
+        .zip(CUBE)
+        .min_by_key(|&(_, step)| value.abs_diff(step))
+        .map_or(0, |(index, _)| index)
+}
+
+impl IndexedColor {
+    /// Approximation of the color in the RGB color space.
+    #[expect(clippy::integer_division, clippy::integer_division_remainder_used)]
+    const fn to_rgb(self) -> RgbColor {
+        match self.index() {
+            index @ 0..=15 => PALETTE[index as usize],
+            index @ 16..=231 => {
+                let index = index - 16;
+
+                RgbColor::new(
+                    CUBE[(index / 36) as usize],
+                    CUBE[(index / 6 % 6) as usize],
+                    CUBE[(index % 6) as usize],
+                )
+            }
+            index @ 232..=255 => {
+                let value = 8 + (index - 232) * 10;
+
+                RgbColor::new(value, value, value)
+            }
+        }
+    }
+}
+
+impl RgbColor {
+    /// Quantizes the color to the closest [`IndexedColor`].
+    ///
+    /// Only the color cube and grayscale ramp are considered, as the first 16 colors are often
+    /// configured by the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{IndexedColor, RgbColor};
+    ///
+    /// assert_eq!(
+    ///     RgbColor::new(255, 0, 0).to_indexed(),
+    ///     IndexedColor::new(196)
+    /// );
+    /// assert_eq!(
+    ///     RgbColor::new(128, 128, 128).to_indexed(),
+    ///     IndexedColor::new(244)
+    /// );
+    /// ```
+    #[must_use]
+    #[expect(clippy::integer_division, clippy::integer_division_remainder_used)]
+    pub fn to_indexed(self) -> IndexedColor {
+        let red = cube_step(self.red());
+        let green = cube_step(self.green());
+        let blue = cube_step(self.blue());
+
+        let cube = IndexedColor::new(16 + red * 36 + green * 6 + blue);
+
+        let average =
+            (u16::from(self.red()) + u16::from(self.green()) + u16::from(self.blue())) / 3;
+        // the grayscale ramp is `8, 18, ..., 238`, values outside of the ramp are clamped
+        let step = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray = IndexedColor::new(232 + step);
+
+        if distance(self, gray.to_rgb()) < distance(self, cube.to_rgb()) {
+            gray
+        } else {
+            cube
+        }
+    }
+
+    /// Quantizes the color to the closest color of the [`BasicColor`] or [`BrightColor`] palette.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Color, RgbColor};
+    ///
+    /// assert_eq!(
+    ///     RgbColor::new(200, 10, 10).to_basic(),
+    ///     Color::Basic(BasicColor::Red)
+    /// );
+    /// assert_eq!(
+    ///     RgbColor::new(250, 250, 250).to_basic(),
+    ///     Color::Bright(BasicColor::White.bright())
+    /// );
+    /// ```
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    #[must_use]
+    pub fn to_basic(self) -> Color {
+        let closest = (0..)
+            .zip(PALETTE)
+            .min_by_key(|&(_, color)| distance(self, color))
+            .map_or(0, |(index, _)| index);
+
+        palette_color(closest)
+    }
+}
+
+/// Color of the [`BasicColor`] or [`BrightColor`] palette at the given index (`0` - `15`).
+///
+/// [`BrightColor`]: crate::BrightColor
+#[expect(clippy::integer_division_remainder_used)]
+pub(crate) const fn palette_color(index: u8) -> Color {
+    let color = match index % 8 {
+        0 => BasicColor::Black,
+        1 => BasicColor::Red,
+        2 => BasicColor::Green,
+        3 => BasicColor::Yellow,
+        4 => BasicColor::Blue,
+        5 => BasicColor::Magenta,
+        6 => BasicColor::Cyan,
+        _ => BasicColor::White,
+    };
+
+    if index < 8 {
+        Color::Basic(color)
+    } else {
+        Color::Bright(color.bright())
+    }
+}
+
+impl Color {
+    /// Converts the color into a color that is supported by a terminal with the given
+    /// [`Capability`].
+    ///
+    /// Colors that are not supported are quantized to the closest supported color, returns
+    /// [`None`] for every color if the terminal does not support any colors. Transparent colors are
+    /// only supported from [`Capability::Indexed`] onwards, below they are dropped as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Capability, Color, IndexedColor, RgbColor};
+    ///
+    /// let color = Color::Rgb(RgbColor::new(255, 0, 0));
+    ///
+    /// assert_eq!(color.downsample(Capability::TrueColor), Some(color));
+    /// assert_eq!(
+    ///     color.downsample(Capability::Indexed),
+    ///     Some(Color::Indexed(IndexedColor::new(196)))
+    /// );
+    /// assert_eq!(
+    ///     color.downsample(Capability::Basic),
+    ///     Some(Color::Bright(BasicColor::Red.bright()))
+    /// );
+    /// assert_eq!(color.downsample(Capability::None), None);
+    /// assert_eq!(
+    ///     Color::Basic(BasicColor::Red).downsample(Capability::None),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn downsample(self, capability: Capability) -> Option<Self> {
+        if capability == Capability::None {
+            return None;
+        }
+
+        let rgb = match self {
+            Self::Basic(_) | Self::Bright(_) => return Some(self),
+            // Transparent colors are written in the ISO 8613-6 format, which terminals with only
+            // basic color support don't understand, the color is therefore dropped instead, which
+            // leaves the default color of the terminal.
+            Self::Transparent(_) => match capability {
+                Capability::None | Capability::Basic => return None,
+                Capability::Indexed | Capability::TrueColor => return Some(self),
+            },
+            Self::Indexed(color) => match capability {
+                Capability::None => return None,
+                Capability::Basic if color.index() < 16 => {
+                    return Some(palette_color(color.index()));
+                }
+                Capability::Basic => color.to_rgb(),
+                Capability::Indexed | Capability::TrueColor => return Some(self),
+            },
+            Self::Rgb(color) => color,
+            #[cfg(feature = "rgba")]
+            Self::Rgba(color) => RgbColor::new(color.red(), color.green(), color.blue()),
+            Self::Cmy(color) => color.into(),
+            Self::Cmyk(color) => color.into(),
+        };
+
+        match capability {
+            Capability::None => None,
+            Capability::Basic => Some(rgb.to_basic()),
+            Capability::Indexed => Some(Self::Indexed(rgb.to_indexed())),
+            Capability::TrueColor => Some(self),
+        }
+    }
+}
+
+impl Style<'_> {
+    /// Converts the style into a style that is supported by a terminal with the given
+    /// [`Capability`].
+    ///
+    /// Colors are quantized using [`Color::downsample`], if the terminal does not support any
+    /// escape sequences ([`Capability::None`]) the style is empty.
+    #[must_use]
+    pub fn downsample(self, capability: Capability) -> Self {
+        if capability == Capability::None {
+            return Self::new();
+        }
+
+        let mut style = self;
+
+        style.foreground = style
+            .foreground
+            .and_then(|color| color.color().downsample(capability))
+            .map(Foreground::new);
+        style.background = style
+            .background
+            .and_then(|color| color.color().downsample(capability))
+            .map(Background::new);
+
+        #[cfg(feature = "underline-color")]
+        {
+            style.underline_color = style
+                .underline_color
+                .and_then(|color| color.color().downsample(capability))
+                .map(UnderlineColor::new);
+        }
+
+        style
+    }
+}
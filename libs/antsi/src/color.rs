@@ -149,6 +149,12 @@ impl IndexedColor {
     pub const fn new(index: u8) -> Self {
         Self(index)
     }
+
+    /// The index into the preset table.
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self.0
+    }
 }
 
 impl From<BasicColor> for IndexedColor {
@@ -259,6 +265,54 @@ impl RgbColor {
     pub const fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
     }
+
+    /// The red component of the color.
+    #[must_use]
+    pub const fn red(self) -> u8 {
+        self.red
+    }
+
+    /// The green component of the color.
+    #[must_use]
+    pub const fn green(self) -> u8 {
+        self.green
+    }
+
+    /// The blue component of the color.
+    #[must_use]
+    pub const fn blue(self) -> u8 {
+        self.blue
+    }
+}
+
+impl From<CmyColor> for RgbColor {
+    fn from(value: CmyColor) -> Self {
+        Self {
+            red: u8::MAX - value.cyan,
+            green: u8::MAX - value.magenta,
+            blue: u8::MAX - value.yellow,
+        }
+    }
+}
+
+impl From<CmykColor> for RgbColor {
+    fn from(value: CmykColor) -> Self {
+        // `(255 - x) * (255 - k) / 255` is at most `255`, therefore the truncation is lossless
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::integer_division,
+            clippy::integer_division_remainder_used
+        )]
+        const fn component(value: u8, black: u8) -> u8 {
+            ((u8::MAX - value) as u16 * (u8::MAX - black) as u16 / u8::MAX as u16) as u8
+        }
+
+        Self {
+            red: component(value.cyan, value.black),
+            green: component(value.magenta, value.black),
+            blue: component(value.yellow, value.black),
+        }
+    }
 }
 
 /// Truecolor 32-bit RGBA support.
@@ -302,6 +356,30 @@ impl RgbaColor {
             alpha,
         }
     }
+
+    /// The red component of the color.
+    #[must_use]
+    pub const fn red(self) -> u8 {
+        self.red
+    }
+
+    /// The green component of the color.
+    #[must_use]
+    pub const fn green(self) -> u8 {
+        self.green
+    }
+
+    /// The blue component of the color.
+    #[must_use]
+    pub const fn blue(self) -> u8 {
+        self.blue
+    }
+
+    /// The alpha component of the color.
+    #[must_use]
+    pub const fn alpha(self) -> u8 {
+        self.alpha
+    }
 }
 
 /// Truecolor, 24-bit CMY colors.
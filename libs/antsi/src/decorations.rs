@@ -10,6 +10,78 @@ pub enum Frame {
     Encircled,
 }
 
+/// Hyperlink around text.
+///
+/// ## Support
+///
+/// Supported by most actively maintained terminal emulators, like [iTerm2], [kitty], [wezterm],
+/// [GNOME Terminal] (and every other terminal based on VTE) and [Windows Terminal]. Terminals that
+/// do not support hyperlinks ignore the escape sequence and only display the text.
+///
+/// ## Specification
+///
+/// Hyperlinks are not part of [ISO 6429], instead they are an extension introduced by
+/// [GNOME Terminal] and [iTerm2] in 2017 and use the **O**perating **S**ystem **C**ommand `8`
+/// instead of an SGR escape sequence.
+///
+/// The escape sequence is `ESC]8;{params};{uri}ESC\`, where `params` is a list of `:` separated
+/// `key=value` pairs, the only specified key is `id`, which is used to connect multiple cells of
+/// text (which may be non-contiguous) to the same hyperlink. The hyperlink is closed using an empty
+/// URI: `ESC]8;;ESC\`. Control characters in the URI or identifier, which would terminate the
+/// escape sequence, are percent-encoded when written, as are `:` and `;` in the identifier.
+///
+/// The encoding is one way: the [`Parser`] borrows the URI and identifier from its input and
+/// therefore returns them as written, including any `%XX` sequences. For the URI this is the same
+/// URI the terminal opens, while an identifier containing control characters, `:` or `;` does not
+/// survive a round trip.
+///
+/// The specification can be found in the [gist] which first introduced the extension.
+///
+/// [ISO 6429]: https://www.iso.org/standard/12782.html
+/// [iTerm2]: https://iterm2.com/
+/// [kitty]: https://sw.kovidgoyal.net/kitty/
+/// [wezterm]: https://wezfurlong.org/wezterm/
+/// [GNOME Terminal]: https://wiki.gnome.org/Apps/Terminal
+/// [Windows Terminal]: https://github.com/microsoft/terminal
+/// [`Parser`]: crate::Parser
+/// [gist]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Hyperlink<'a> {
+    uri: &'a str,
+    id: Option<&'a str>,
+}
+
+impl<'a> Hyperlink<'a> {
+    /// Creates a new hyperlink to the given URI.
+    #[must_use]
+    pub const fn new(uri: &'a str) -> Self {
+        Self { uri, id: None }
+    }
+
+    /// Sets the identifier of the hyperlink.
+    ///
+    /// Text with the same identifier and URI is treated as a single hyperlink by the terminal, e.g.
+    /// it is underlined together when hovered.
+    #[must_use]
+    pub const fn with_id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+
+        self
+    }
+
+    /// The URI the hyperlink points to.
+    #[must_use]
+    pub const fn uri(self) -> &'a str {
+        self.uri
+    }
+
+    /// The identifier of the hyperlink, if any.
+    #[must_use]
+    pub const fn id(self) -> Option<&'a str> {
+        self.id
+    }
+}
+
 /// Text Decorations.
 ///
 /// ## Support
@@ -42,15 +114,37 @@ pub enum Frame {
 /// * double line above character with horizontal line orientation or double line on the right side
 ///   of character with vertical line orientation
 ///
+/// In addition to the decorations specified in [ISO 6429], decorations also include
+/// [`Hyperlink`]s, which are widely supported through an extension.
+///
 /// [ISO 6429]: https://www.iso.org/standard/12782.html
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
-pub struct Decorations {
+pub struct Decorations<'a> {
     pub frame: Option<Frame>,
+
+    pub hyperlink: Option<Hyperlink<'a>>,
 }
 
-impl Decorations {
+impl<'a> Decorations<'a> {
     #[must_use]
     pub const fn new() -> Self {
-        Self { frame: None }
+        Self {
+            frame: None,
+            hyperlink: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn with_hyperlink(mut self, hyperlink: Hyperlink<'a>) -> Self {
+        self.hyperlink = Some(hyperlink);
+
+        self
     }
 }
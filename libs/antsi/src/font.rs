@@ -46,6 +46,12 @@ impl AlternativeFontFamily {
     pub fn new(variant: u8) -> Option<Self> {
         (0..9).contains(&variant).then_some(Self(variant))
     }
+
+    /// The variant of the alternative font, in the range of `0..9`.
+    #[must_use]
+    pub const fn variant(self) -> u8 {
+        self.0
+    }
 }
 
 /// Font Family.
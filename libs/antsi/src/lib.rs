@@ -21,19 +21,28 @@
     doc(test(attr(deny(warnings, clippy::pedantic, clippy::nursery))))
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use capability::Capability;
 #[cfg(feature = "rgba")]
 pub use color::RgbaColor;
 pub use color::{
     BasicColor, BrightColor, CmyColor, CmykColor, Color, IndexedColor, RgbColor, TransparentColor,
 };
-pub use decorations::{Decorations, Frame};
+pub use decorations::{Decorations, Frame, Hyperlink};
 #[cfg(feature = "script")]
 pub use font::FontScript;
 pub use font::{Blinking, Font, FontFamily, FontWeight, Underline};
+pub use paint::Painted;
+pub use parse::Parser;
 
+mod capability;
 mod color;
 mod decorations;
 mod font;
+mod paint;
+mod parse;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Foreground(Color);
@@ -122,6 +131,7 @@ where
 /// * [wezterm extension] ([`RgbaColor`])
 /// * [kitty + vte extension] ([`UnderlineColor`], extra [`Underline`] styles)
 /// * [mintty extension] ([`Font`] over-strike, sub-/super- script)
+/// * [OSC 8 extension] ([`Hyperlink`])
 ///
 /// Due to their ambiguity, not being implemented in any terminal and collisions in some terminals,
 /// the escape codes 60 - 69 from [ISO 6429] have **not** been included.
@@ -132,12 +142,13 @@ where
 /// [wezterm extension]: https://wezfurlong.org/wezterm/escape-sequences.html
 /// [kitty + vte extension]: https://sw.kovidgoyal.net/kitty/underlines/
 /// [mintty extension]: https://github.com/mintty/mintty/wiki/CtrlSeqs
+/// [OSC 8 extension]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 #[non_exhaustive]
-pub struct Style {
+pub struct Style<'a> {
     pub font: Font,
 
-    pub decorations: Decorations,
+    pub decorations: Decorations<'a>,
 
     pub foreground: Option<Foreground>,
     pub background: Option<Background>,
@@ -146,7 +157,7 @@ pub struct Style {
     pub underline_color: Option<UnderlineColor>,
 }
 
-impl Style {
+impl<'a> Style<'a> {
     #[must_use]
     pub const fn new() -> Self {
         Self {
@@ -187,4 +198,39 @@ impl Style {
 
         self
     }
+
+    #[must_use]
+    pub const fn with_decorations(mut self, decorations: Decorations<'a>) -> Self {
+        self.decorations = decorations;
+
+        self
+    }
+
+    /// Applies the style to the value.
+    ///
+    /// The returned [`Painted`] implements [`Display`], which emits the escape sequences required
+    /// to enable the style before, and to reset it after the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, FontWeight, Style};
+    ///
+    /// let style = Style::new()
+    ///     .with_foreground(BasicColor::Red.into())
+    ///     .with_font(Font::new().with_weight(FontWeight::Bold));
+    ///
+    /// assert_eq!(
+    ///     style.paint("breach").to_string(),
+    ///     "\u{1b}[1;31mbreach\u{1b}[22;39m"
+    /// );
+    ///
+    /// // an empty style does not emit any escape sequences
+    /// assert_eq!(Style::new().paint("breach").to_string(), "breach");
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub const fn paint<T>(self, value: T) -> Painted<'a, T> {
+        Painted::new(self, value)
+    }
 }
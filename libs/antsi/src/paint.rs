@@ -0,0 +1,481 @@
+use core::fmt::{self, Display, Formatter, Write as _};
+
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    Background, Blinking, Capability, Color, FontFamily, FontWeight, Foreground, Frame, Hyperlink,
+    IndexedColor, Style, Underline,
+};
+
+/// Writer for the parameters of a single SGR escape sequence.
+///
+/// The control sequence introducer (`ESC[`) is only written once the first parameter is pushed,
+/// which means that a sequence without any parameters results in no output at all.
+struct Parameters<'a, 'b> {
+    fmt: &'a mut Formatter<'b>,
+    empty: bool,
+}
+
+impl<'a, 'b> Parameters<'a, 'b> {
+    fn new(fmt: &'a mut Formatter<'b>) -> Self {
+        Self { fmt, empty: true }
+    }
+
+    fn push(&mut self, parameter: impl Display) -> fmt::Result {
+        if self.empty {
+            self.fmt.write_str("\x1b[")?;
+            self.empty = false;
+        } else {
+            self.fmt.write_char(';')?;
+        }
+
+        Display::fmt(&parameter, self.fmt)
+    }
+
+    fn finish(self) -> fmt::Result {
+        if self.empty {
+            Ok(())
+        } else {
+            self.fmt.write_char('m')
+        }
+    }
+}
+
+/// The layer a color is applied to, determines the base of the escape code used.
+#[derive(Debug, Copy, Clone)]
+enum Layer {
+    Foreground,
+    Background,
+    #[cfg(feature = "underline-color")]
+    Underline,
+}
+
+impl Layer {
+    /// Escape code used for extended colors (ISO 8613-6).
+    const fn extended(self) -> u8 {
+        match self {
+            Self::Foreground => 38,
+            Self::Background => 48,
+            #[cfg(feature = "underline-color")]
+            Self::Underline => 58,
+        }
+    }
+
+    /// Escape code used to reset the color to the terminal default.
+    const fn reset(self) -> u8 {
+        self.extended() + 1
+    }
+}
+
+fn push_color(parameters: &mut Parameters, layer: Layer, color: Color) -> fmt::Result {
+    let extended = layer.extended();
+
+    match color {
+        Color::Basic(color) => {
+            let index = IndexedColor::from(color).index();
+
+            match layer {
+                Layer::Foreground => parameters.push(30 + index),
+                Layer::Background => parameters.push(40 + index),
+                // There are no dedicated escape codes for the underline color palette
+                #[cfg(feature = "underline-color")]
+                Layer::Underline => parameters.push(format_args!("{extended};5;{index}")),
+            }
+        }
+        Color::Bright(color) => {
+            let index = IndexedColor::from(color).index();
+
+            match layer {
+                Layer::Foreground => parameters.push(90 + index - 8),
+                Layer::Background => parameters.push(100 + index - 8),
+                #[cfg(feature = "underline-color")]
+                Layer::Underline => parameters.push(format_args!("{extended};5;{index}")),
+            }
+        }
+        // Colors of the underline palette are written as indexed colors in the legacy format,
+        // indexed colors are therefore written in the ISO 8613-6 format to tell them apart.
+        #[cfg(feature = "underline-color")]
+        Color::Indexed(color) if matches!(layer, Layer::Underline) => {
+            parameters.push(format_args!("{extended}:5:{}", color.index()))
+        }
+        Color::Indexed(color) => parameters.push(format_args!("{extended};5;{}", color.index())),
+        // There is no legacy format for transparent colors, therefore the ISO 8613-6 format is
+        // used instead.
+        Color::Transparent(_) => parameters.push(format_args!("{extended}:1")),
+        Color::Rgb(color) => parameters.push(format_args!(
+            "{extended};2;{};{};{}",
+            color.red(),
+            color.green(),
+            color.blue()
+        )),
+        // wezterm only supports the ISO 8613-6 format for RGBA colors
+        #[cfg(feature = "rgba")]
+        Color::Rgba(color) => parameters.push(format_args!(
+            "{extended}:6::{}:{}:{}:{}",
+            color.red(),
+            color.green(),
+            color.blue(),
+            color.alpha()
+        )),
+        // No widely used terminal supports CMY(K), we convert them to their RGB counterpart to
+        // make sure they are displayed.
+        Color::Cmy(color) => push_color(parameters, layer, Color::Rgb(color.into())),
+        Color::Cmyk(color) => push_color(parameters, layer, Color::Rgb(color.into())),
+    }
+}
+
+/// Writes a parameter of an OSC 8 escape sequence.
+///
+/// Control characters, like `ESC` and `BEL`, would terminate the escape sequence and are therefore
+/// percent-encoded, as are the `reserved` characters.
+fn write_osc_parameter(parameter: &str, reserved: &[char], fmt: &mut Formatter) -> fmt::Result {
+    for char in parameter.chars() {
+        if char.is_control() || reserved.contains(&char) {
+            for byte in char.encode_utf8(&mut [0; 4]).bytes() {
+                write!(fmt, "%{byte:02X}")?;
+            }
+        } else {
+            fmt.write_char(char)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_hyperlink(hyperlink: Hyperlink, fmt: &mut Formatter) -> fmt::Result {
+    fmt.write_str("\x1b]8;")?;
+
+    if let Some(id) = hyperlink.id() {
+        fmt.write_str("id=")?;
+        // `:` and `;` separate the parameters of the escape sequence
+        write_osc_parameter(id, &[':', ';'], fmt)?;
+    }
+
+    fmt.write_char(';')?;
+    write_osc_parameter(hyperlink.uri(), &[], fmt)?;
+    fmt.write_str("\x1b\\")
+}
+
+fn write_prefix(style: &Style, fmt: &mut Formatter) -> fmt::Result {
+    let mut parameters = Parameters::new(fmt);
+    let font = style.font;
+
+    match font.weight {
+        Some(FontWeight::Bold) => parameters.push(1)?,
+        Some(FontWeight::Faint) => parameters.push(2)?,
+        None => {}
+    }
+
+    if font.is_italic() {
+        parameters.push(3)?;
+    }
+
+    match font.underline {
+        Some(Underline::Single) => parameters.push(4)?,
+        Some(Underline::Double) => parameters.push(21)?,
+        #[cfg(feature = "underline-variants")]
+        Some(Underline::Curly) => parameters.push("4:3")?,
+        #[cfg(feature = "underline-variants")]
+        Some(Underline::Dotted) => parameters.push("4:4")?,
+        #[cfg(feature = "underline-variants")]
+        Some(Underline::Dashed) => parameters.push("4:5")?,
+        None => {}
+    }
+
+    match font.blinking {
+        Some(Blinking::Slow) => parameters.push(5)?,
+        Some(Blinking::Fast) => parameters.push(6)?,
+        None => {}
+    }
+
+    if font.is_inverse() {
+        parameters.push(7)?;
+    }
+
+    if font.is_hidden() {
+        parameters.push(8)?;
+    }
+
+    #[cfg(feature = "overstrike")]
+    if font.is_overstrike() {
+        parameters.push("8:7")?;
+    }
+
+    if font.is_strikethrough() {
+        parameters.push(9)?;
+    }
+
+    match font.family {
+        Some(FontFamily::Alternative(family)) => parameters.push(11 + family.variant())?,
+        Some(FontFamily::Fraktur) => parameters.push(20)?,
+        None => {}
+    }
+
+    match style.decorations.frame {
+        Some(Frame::Framed) => parameters.push(51)?,
+        Some(Frame::Encircled) => parameters.push(52)?,
+        None => {}
+    }
+
+    if font.is_overline() {
+        parameters.push(53)?;
+    }
+
+    #[cfg(feature = "script")]
+    match font.script {
+        Some(crate::FontScript::Super) => parameters.push(73)?,
+        Some(crate::FontScript::Sub) => parameters.push(74)?,
+        None => {}
+    }
+
+    if let Some(Foreground(color)) = style.foreground {
+        push_color(&mut parameters, Layer::Foreground, color)?;
+    }
+
+    if let Some(Background(color)) = style.background {
+        push_color(&mut parameters, Layer::Background, color)?;
+    }
+
+    #[cfg(feature = "underline-color")]
+    if let Some(UnderlineColor(color)) = style.underline_color {
+        push_color(&mut parameters, Layer::Underline, color)?;
+    }
+
+    parameters.finish()
+}
+
+fn write_suffix(style: &Style, fmt: &mut Formatter) -> fmt::Result {
+    let mut parameters = Parameters::new(fmt);
+    let font = style.font;
+
+    // Instead of resetting everything using `ESC[0m` only the styles that have been applied are
+    // reset, this keeps the styles of a surrounding value that this style does not touch, e.g. the
+    // color of a red sentence around a bold word.
+    if font.weight.is_some() {
+        parameters.push(22)?;
+    }
+
+    if font.is_italic() {
+        parameters.push(23)?;
+    }
+
+    if font.underline.is_some() {
+        parameters.push(24)?;
+    }
+
+    if font.blinking.is_some() {
+        parameters.push(25)?;
+    }
+
+    if font.is_inverse() {
+        parameters.push(27)?;
+    }
+
+    // mintty resets overstrike together with hidden
+    #[cfg(feature = "overstrike")]
+    let hidden = font.is_hidden() || font.is_overstrike();
+    #[cfg(not(feature = "overstrike"))]
+    let hidden = font.is_hidden();
+
+    if hidden {
+        parameters.push(28)?;
+    }
+
+    if font.is_strikethrough() {
+        parameters.push(29)?;
+    }
+
+    if font.family.is_some() {
+        parameters.push(10)?;
+    }
+
+    if style.decorations.frame.is_some() {
+        parameters.push(54)?;
+    }
+
+    if font.is_overline() {
+        parameters.push(55)?;
+    }
+
+    #[cfg(feature = "script")]
+    if font.script.is_some() {
+        parameters.push(75)?;
+    }
+
+    if style.foreground.is_some() {
+        parameters.push(Layer::Foreground.reset())?;
+    }
+
+    if style.background.is_some() {
+        parameters.push(Layer::Background.reset())?;
+    }
+
+    #[cfg(feature = "underline-color")]
+    if style.underline_color.is_some() {
+        parameters.push(Layer::Underline.reset())?;
+    }
+
+    parameters.finish()
+}
+
+/// A value with a [`Style`] applied to it.
+///
+/// Created using [`Style::paint`], the [`Display`] implementation surrounds the value with the
+/// escape sequences required to enable the style and to reset it afterwards. Only the parameters of
+/// the style that are set are emitted, if the style is empty the value is written as-is.
+///
+/// Instead of resetting all styles (`ESC[0m`), the reset sequence only disables the styles that
+/// have been enabled. The terminal does not keep track of previous styles, so every disabled
+/// style is reset to the default of the terminal, not to the style of a surrounding painted
+/// value. Painted values can therefore only be nested if the inner style does not set anything
+/// the outer style sets as well, where bold and faint share a single reset. A bold word keeps a
+/// surrounding red sentence red, while a blue word inside a red sentence resets the rest of the
+/// sentence to the default foreground color. [`Hyperlink`]s cannot be nested at all, closing a
+/// hyperlink also closes any surrounding hyperlink.
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Style};
+///
+/// let red = Style::new().with_foreground(BasicColor::Red.into());
+/// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+///
+/// // the reset of the bold word only disables bold, the sentence stays red
+/// assert_eq!(
+///     red.paint(format_args!("a {} word", bold.paint("bold")))
+///         .to_string(),
+///     "\u{1b}[31ma \u{1b}[1mbold\u{1b}[22m word\u{1b}[39m"
+/// );
+///
+/// // the reset of the blue word resets the foreground to the default, not to red
+/// let blue = Style::new().with_foreground(BasicColor::Blue.into());
+/// assert_eq!(
+///     red.paint(format_args!("a {} word", blue.paint("blue")))
+///         .to_string(),
+///     "\u{1b}[31ma \u{1b}[34mblue\u{1b}[39m word\u{1b}[39m"
+/// );
+/// ```
+///
+/// Colors are emitted in the legacy format (using `;` as a delimiter, see [`RgbColor`]), as it is
+/// supported by the widest range of terminals. [`CmyColor`] and [`CmykColor`] are converted to
+/// [`RgbColor`], colors that do not have a legacy format ([`TransparentColor`] and `RgbaColor`) use
+/// the format specified in [ISO 8613-6].
+///
+/// By default the style is emitted as-is, use [`Painted::with_capability`] to adapt the style to
+/// the [`Capability`] of the terminal the value is written to, e.g. [`Capability::stderr`] for
+/// values written to standard error. The capability is never detected implicitly, as a painted
+/// value does not know which stream it is written to.
+///
+/// [`RgbColor`]: crate::RgbColor
+/// [`CmyColor`]: crate::CmyColor
+/// [`CmykColor`]: crate::CmykColor
+/// [`TransparentColor`]: crate::TransparentColor
+/// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[must_use = "a painted value does nothing unless it is displayed"]
+pub struct Painted<'a, T> {
+    style: Style<'a>,
+    capability: Capability,
+    value: T,
+}
+
+impl<'a, T> Painted<'a, T> {
+    pub(crate) const fn new(style: Style<'a>, value: T) -> Self {
+        Self {
+            style,
+            capability: Capability::TrueColor,
+            value,
+        }
+    }
+
+    /// Adapts the style to a terminal with the given [`Capability`].
+    ///
+    /// Colors that are not supported are quantized to the closest supported color, if the terminal
+    /// does not support escape sequences at all, the value is written as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{Capability, RgbColor, Style};
+    ///
+    /// let style = Style::new().with_foreground(RgbColor::new(255, 0, 0).into());
+    ///
+    /// assert_eq!(
+    ///     style.paint("breach").to_string(),
+    ///     "\u{1b}[38;2;255;0;0mbreach\u{1b}[39m"
+    /// );
+    /// assert_eq!(
+    ///     style
+    ///         .paint("breach")
+    ///         .with_capability(Capability::Indexed)
+    ///         .to_string(),
+    ///     "\u{1b}[38;5;196mbreach\u{1b}[39m"
+    /// );
+    /// assert_eq!(
+    ///     style
+    ///         .paint("breach")
+    ///         .with_capability(Capability::Basic)
+    ///         .to_string(),
+    ///     "\u{1b}[91mbreach\u{1b}[39m"
+    /// );
+    /// assert_eq!(
+    ///     style
+    ///         .paint("breach")
+    ///         .with_capability(Capability::None)
+    ///         .to_string(),
+    ///     "breach"
+    /// );
+    /// ```
+    pub const fn with_capability(mut self, capability: Capability) -> Self {
+        self.capability = capability;
+
+        self
+    }
+
+    /// The capability of the terminal the style is adapted to.
+    #[must_use]
+    pub const fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    /// The style which is applied to the value.
+    #[must_use]
+    pub const fn style(&self) -> Style<'a> {
+        self.style
+    }
+
+    /// The value that is painted.
+    #[must_use]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value that is painted, discarding the style.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for Painted<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let style = self.style.downsample(self.capability);
+
+        if let Some(hyperlink) = style.decorations.hyperlink {
+            write_hyperlink(hyperlink, fmt)?;
+        }
+
+        write_prefix(&style, fmt)?;
+        // Formatting options (like width or alignment) are forwarded to the value
+        Display::fmt(&self.value, fmt)?;
+        write_suffix(&style, fmt)?;
+
+        if style.decorations.hyperlink.is_some() {
+            // An empty URI closes the hyperlink
+            fmt.write_str("\x1b]8;;\x1b\\")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,450 @@
+use core::str::Split;
+
+#[cfg(feature = "rgba")]
+use crate::RgbaColor;
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    Background, Blinking, CmyColor, CmykColor, Color, FontFamily, FontWeight, Foreground, Frame,
+    Hyperlink, IndexedColor, RgbColor, Style, TransparentColor, Underline,
+    capability::palette_color, font::AlternativeFontFamily,
+};
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+
+/// Parser for text that contains escape sequences.
+///
+/// The parser is an [`Iterator`] over the spans of text in the input, every span is returned
+/// together with the [`Style`] that applies to it. Escape sequences themselves are never part of a
+/// span, empty spans are skipped.
+///
+/// The parser understands the subset of escape sequences that can be expressed through [`Style`],
+/// which is everything [`Painted`] emits, any other escape sequence is skipped. Parameters can
+/// either be separated using the legacy format (`;`) or the format specified in [ISO 8613-6] (`:`).
+/// Hyperlinks are parsed from the OSC 8 escape sequence, their URI and identifier are borrowed from
+/// the input and not percent-decoded (see [`Hyperlink`]).
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Parser, Style};
+///
+/// let style = Style::new()
+///     .with_foreground(BasicColor::Red.into())
+///     .with_font(Font::new().with_weight(FontWeight::Bold));
+///
+/// let text = format!("error: {}", style.paint("mainframe breach"));
+/// let mut spans = Parser::new(&text);
+///
+/// assert_eq!(spans.next(), Some((Style::new(), "error: ")));
+/// assert_eq!(spans.next(), Some((style, "mainframe breach")));
+/// assert_eq!(spans.next(), None);
+/// ```
+///
+/// [`Painted`]: crate::Painted
+/// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    input: &'a str,
+    style: Style<'a>,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new parser over the given input.
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            style: Style::new(),
+        }
+    }
+
+    /// The style that is active at the current position of the parser.
+    #[must_use]
+    pub const fn style(&self) -> Style<'a> {
+        self.style
+    }
+
+    /// Consumes a single escape sequence, the input must start with `ESC`.
+    fn escape(&mut self) {
+        let sequence = self.input.strip_prefix(ESC).unwrap_or(self.input);
+
+        if let Some(sequence) = sequence.strip_prefix('[') {
+            self.control_sequence(sequence);
+        } else if let Some(sequence) = sequence.strip_prefix(']') {
+            self.operating_system_command(sequence);
+        } else {
+            // Any other escape sequence consists of optional intermediate bytes (`0x20` - `0x2F`),
+            // followed by a single final byte, e.g. `ESC(B` to select the character set.
+            let mut chars = sequence
+                .trim_start_matches(|char| ('\x20'..='\x2F').contains(&char))
+                .chars();
+            chars.next();
+            self.input = chars.as_str();
+        }
+    }
+
+    /// Consumes a control sequence (`ESC[{parameters}{final byte}`).
+    fn control_sequence(&mut self, sequence: &'a str) {
+        // the final byte of a control sequence is in the range `0x40` - `0x7E`
+        let Some(end) = sequence.find(|char| ('\x40'..='\x7E').contains(&char)) else {
+            self.input = "";
+            return;
+        };
+
+        let (parameters, rest) = sequence.split_at(end);
+
+        let mut rest = rest.chars();
+        if rest.next() == Some('m') {
+            self.select_graphic_rendition(parameters);
+        }
+
+        self.input = rest.as_str();
+    }
+
+    /// Consumes an operating system command (`ESC]{command}ST`).
+    ///
+    /// The string terminator (`ST`) is either `ESC\` or `BEL`.
+    fn operating_system_command(&mut self, sequence: &'a str) {
+        let end = sequence.find([ESC, BEL]).unwrap_or(sequence.len());
+        let (command, rest) = sequence.split_at(end);
+
+        // If the command has been interrupted by another escape sequence, the rest is kept, so
+        // that the escape sequence is parsed.
+        let rest = rest
+            .strip_prefix(BEL)
+            .or_else(|| rest.strip_prefix("\x1b\\"))
+            .unwrap_or(rest);
+
+        if let Some(hyperlink) = command.strip_prefix("8;") {
+            self.hyperlink(hyperlink);
+        }
+
+        self.input = rest;
+    }
+
+    fn hyperlink(&mut self, command: &'a str) {
+        let Some((parameters, uri)) = command.split_once(';') else {
+            return;
+        };
+
+        if uri.is_empty() {
+            self.style.decorations.hyperlink = None;
+            return;
+        }
+
+        let mut hyperlink = Hyperlink::new(uri);
+
+        let id = parameters
+            .split(':')
+            .find_map(|parameter| parameter.strip_prefix("id="));
+        if let Some(id) = id {
+            hyperlink = hyperlink.with_id(id);
+        }
+
+        self.style.decorations.hyperlink = Some(hyperlink);
+    }
+
+    fn select_graphic_rendition(&mut self, parameters: &str) {
+        let mut parameters = parameters.split(';');
+
+        while let Some(parameter) = parameters.next() {
+            let mut elements = parameter.split(':');
+            // `split` always returns at least a single element
+            let code = elements.next().unwrap_or_default();
+
+            // an empty parameter is the same as `0`
+            let code = if code.is_empty() {
+                0
+            } else {
+                let Ok(code) = code.parse::<u8>() else {
+                    continue;
+                };
+
+                code
+            };
+
+            self.apply(code, &mut elements, &mut parameters);
+        }
+    }
+
+    /// Applies the underline color (`ESC[58m`) to the current style.
+    ///
+    /// There are no dedicated escape codes for the underline color palette, basic and bright colors
+    /// are therefore written as indexed colors in the legacy format and need to be mapped back.
+    /// Indexed colors are written in the ISO 8613-6 format to tell them apart.
+    #[cfg(feature = "underline-color")]
+    fn underline_color(&mut self, elements: &mut Split<char>, parameters: &mut Split<char>) {
+        let iso = elements.clone().next().is_some();
+
+        match extended_color(elements, parameters) {
+            Some(Color::Indexed(color)) if !iso && color.index() < 16 => {
+                self.style.underline_color =
+                    Some(UnderlineColor::new(palette_color(color.index())));
+            }
+            Some(color) => self.style.underline_color = Some(UnderlineColor::new(color)),
+            None => {}
+        }
+    }
+
+    /// Applies the code to the current style.
+    ///
+    /// `elements` are the sub-parameters of the code (separated by `:`), `parameters` are the
+    /// parameters following the code (separated by `;`), which are used by extended colors in the
+    /// legacy format.
+    fn apply(&mut self, code: u8, elements: &mut Split<char>, parameters: &mut Split<char>) {
+        let style = &mut self.style;
+        let font = &mut style.font;
+
+        match code {
+            0 => {
+                // hyperlinks are not part of SGR and therefore not affected by a reset
+                let hyperlink = style.decorations.hyperlink;
+
+                *style = Style::new();
+                style.decorations.hyperlink = hyperlink;
+            }
+            1 => font.weight = Some(FontWeight::Bold),
+            2 => font.weight = Some(FontWeight::Faint),
+            3 => {
+                font.set_italic(true);
+            }
+            4 => font.underline = underline(elements.next()),
+            5 => font.blinking = Some(Blinking::Slow),
+            6 => font.blinking = Some(Blinking::Fast),
+            7 => {
+                font.set_inverse(true);
+            }
+            #[cfg(feature = "overstrike")]
+            8 if elements.next() == Some("7") => {
+                font.set_overstrike(true);
+            }
+            8 => {
+                font.set_hidden(true);
+            }
+            9 => {
+                font.set_strikethrough(true);
+            }
+            10 => font.family = None,
+            11..=19 => {
+                font.family = AlternativeFontFamily::new(code - 11).map(FontFamily::Alternative);
+            }
+            20 => font.family = Some(FontFamily::Fraktur),
+            21 => font.underline = Some(Underline::Double),
+            22 => font.weight = None,
+            23 => {
+                font.set_italic(false);
+            }
+            24 => font.underline = None,
+            25 => font.blinking = None,
+            27 => {
+                font.set_inverse(false);
+            }
+            28 => {
+                font.set_hidden(false);
+                #[cfg(feature = "overstrike")]
+                font.set_overstrike(false);
+            }
+            29 => {
+                font.set_strikethrough(false);
+            }
+            30..=37 => style.foreground = Some(Foreground::new(palette_color(code - 30))),
+            38 => {
+                if let Some(color) = extended_color(elements, parameters) {
+                    style.foreground = Some(Foreground::new(color));
+                }
+            }
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(Background::new(palette_color(code - 40))),
+            48 => {
+                if let Some(color) = extended_color(elements, parameters) {
+                    style.background = Some(Background::new(color));
+                }
+            }
+            49 => style.background = None,
+            51 => style.decorations.frame = Some(Frame::Framed),
+            52 => style.decorations.frame = Some(Frame::Encircled),
+            53 => {
+                font.set_overline(true);
+            }
+            54 => style.decorations.frame = None,
+            55 => {
+                font.set_overline(false);
+            }
+            #[cfg(feature = "underline-color")]
+            58 => self.underline_color(elements, parameters),
+            // The color still needs to be consumed, otherwise the components of a color in the
+            // legacy format would be interpreted as codes
+            #[cfg(not(feature = "underline-color"))]
+            58 => {
+                extended_color(elements, parameters);
+            }
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            #[cfg(feature = "script")]
+            73 => font.script = Some(crate::FontScript::Super),
+            #[cfg(feature = "script")]
+            74 => font.script = Some(crate::FontScript::Sub),
+            #[cfg(feature = "script")]
+            75 => font.script = None,
+            90..=97 => style.foreground = Some(Foreground::new(palette_color(code - 90 + 8))),
+            100..=107 => style.background = Some(Background::new(palette_color(code - 100 + 8))),
+            _ => {}
+        }
+    }
+}
+
+/// Underline style of the sub-parameter of `ESC[4m`, e.g. `ESC[4:3m`.
+fn underline(style: Option<&str>) -> Option<Underline> {
+    match style {
+        None | Some("1") => Some(Underline::Single),
+        Some("0") => None,
+        Some("2") => Some(Underline::Double),
+        #[cfg(feature = "underline-variants")]
+        Some("3") => Some(Underline::Curly),
+        #[cfg(feature = "underline-variants")]
+        Some("4") => Some(Underline::Dotted),
+        #[cfg(feature = "underline-variants")]
+        Some("5") => Some(Underline::Dashed),
+        // unsupported variants are approximated using a single underline
+        Some(_) => Some(Underline::Single),
+    }
+}
+
+/// Parses an extended color (ISO 8613-6), which is used by the codes `38`, `48` and `58`.
+///
+/// If the code has sub-parameters (`:`), the color is read from them, otherwise the color is read
+/// from the following parameters (`;`), which is the legacy format.
+fn extended_color(elements: &mut Split<char>, parameters: &mut Split<char>) -> Option<Color> {
+    if elements.clone().next().is_some() {
+        // the number of sub-parameters following the color mode
+        let remaining = elements.clone().count().saturating_sub(1);
+
+        ExtendedColor {
+            elements,
+            remaining: Some(remaining),
+        }
+        .parse()
+    } else {
+        ExtendedColor {
+            elements: parameters,
+            remaining: None,
+        }
+        .parse()
+    }
+}
+
+struct ExtendedColor<'a, I> {
+    elements: &'a mut I,
+    // The ISO 8613-6 format includes a color space identifier for every color mode, except for
+    // indexed colors, while the legacy format does not. The identifier is commonly omitted, e.g.
+    // `38:2:r:g:b`, which is detected through the number of sub-parameters, these are only known
+    // for the ISO format.
+    remaining: Option<usize>,
+}
+
+#[cfg(feature = "rgba")]
+const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+    Color::Rgba(RgbaColor::new(red, green, blue, alpha))
+}
+
+// Without support for RGBA colors, the alpha channel is discarded
+#[cfg(not(feature = "rgba"))]
+const fn rgba(red: u8, green: u8, blue: u8, _: u8) -> Color {
+    Color::Rgb(RgbColor::new(red, green, blue))
+}
+
+impl<'a, I> ExtendedColor<'_, I>
+where
+    I: Iterator<Item = &'a str>,
+{
+    fn component(&mut self) -> Option<u8> {
+        self.elements.next()?.parse().ok()
+    }
+
+    /// Skips the color space identifier, if present, of a color with the given number of
+    /// components.
+    fn color_space(&mut self, components: usize) -> Option<()> {
+        if self
+            .remaining
+            .is_some_and(|remaining| remaining > components)
+        {
+            self.elements.next()?;
+        }
+
+        Some(())
+    }
+
+    fn parse(mut self) -> Option<Color> {
+        let mode = self.elements.next()?;
+
+        match mode {
+            "1" => Some(Color::Transparent(TransparentColor)),
+            "2" => {
+                self.color_space(3)?;
+
+                Some(Color::Rgb(RgbColor::new(
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                )))
+            }
+            "3" => {
+                self.color_space(3)?;
+
+                Some(Color::Cmy(CmyColor::new(
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                )))
+            }
+            "4" => {
+                self.color_space(4)?;
+
+                Some(Color::Cmyk(CmykColor::new(
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                )))
+            }
+            "5" => Some(Color::Indexed(IndexedColor::new(self.component()?))),
+            "6" => {
+                self.color_space(4)?;
+
+                Some(rgba(
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                    self.component()?,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = (Style<'a>, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.input.is_empty() {
+                return None;
+            }
+
+            let end = self.input.find(ESC).unwrap_or(self.input.len());
+
+            if end == 0 {
+                self.escape();
+                continue;
+            }
+
+            let (text, rest) = self.input.split_at(end);
+            self.input = rest;
+
+            return Some((self.style, text));
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use antsi::{BasicColor, Capability, CmykColor, Color, IndexedColor, RgbColor, TransparentColor};
+
+const COLORS: [Color; 7] = [
+    Color::Basic(BasicColor::Red),
+    Color::Bright(BasicColor::Red.bright()),
+    Color::Indexed(IndexedColor::new(3)),
+    Color::Indexed(IndexedColor::new(196)),
+    Color::Rgb(RgbColor::new(255, 0, 0)),
+    Color::Cmyk(CmykColor::new(0, 255, 255, 0)),
+    Color::Transparent(TransparentColor),
+];
+
+#[test]
+fn none_drops_every_color() {
+    for color in COLORS {
+        assert_eq!(color.downsample(Capability::None), None, "{color:?}");
+    }
+}
+
+#[test]
+fn basic_colors_are_kept() {
+    for capability in [
+        Capability::Basic,
+        Capability::Indexed,
+        Capability::TrueColor,
+    ] {
+        assert_eq!(
+            Color::Basic(BasicColor::Red).downsample(capability),
+            Some(Color::Basic(BasicColor::Red))
+        );
+    }
+}
+
+#[test]
+fn transparent_is_dropped_without_extended_colors() {
+    let color = Color::Transparent(TransparentColor);
+
+    assert_eq!(color.downsample(Capability::Basic), None);
+    assert_eq!(color.downsample(Capability::Indexed), Some(color));
+    assert_eq!(color.downsample(Capability::TrueColor), Some(color));
+}
+
+#[test]
+fn painted_is_not_downsampled_by_default() {
+    use antsi::Style;
+
+    let painted = Style::new()
+        .with_foreground(RgbColor::new(255, 0, 0).into())
+        .paint("breach");
+    assert_eq!(painted.capability(), Capability::TrueColor);
+    assert_eq!(painted.to_string(), "\u{1b}[38;2;255;0;0mbreach\u{1b}[39m");
+    assert_eq!(
+        painted.with_capability(Capability::Basic).capability(),
+        Capability::Basic
+    );
+}
@@ -0,0 +1,213 @@
+use antsi::{
+    BasicColor, Blinking, CmykColor, Color, Decorations, Font, FontWeight, Foreground, Frame,
+    Hyperlink, IndexedColor, Parser, RgbColor, Style, Underline,
+};
+
+fn round_trip(style: Style) {
+    let text = format!("before {} after", style.paint("painted"));
+    let spans: Vec<_> = Parser::new(&text).collect();
+
+    assert_eq!(
+        spans,
+        [
+            (Style::new(), "before "),
+            (style, "painted"),
+            (Style::new(), " after")
+        ]
+    );
+}
+
+#[test]
+fn font() {
+    round_trip(Style::new().with_font(Font::new().with_weight(FontWeight::Bold)));
+    round_trip(Style::new().with_font(Font::new().with_weight(FontWeight::Faint)));
+    round_trip(Style::new().with_font(Font::new().with_underline(Underline::Double)));
+    round_trip(Style::new().with_font(Font::new().with_blinking(Blinking::Fast)));
+    round_trip(
+        Style::new().with_font(
+            Font::new()
+                .with_italic()
+                .with_inverse()
+                .with_hidden()
+                .with_strikethrough()
+                .with_overline()
+                .with_underline(Underline::Single),
+        ),
+    );
+}
+
+#[test]
+fn decorations() {
+    round_trip(Style::new().with_decorations(Decorations::new().with_frame(Frame::Encircled)));
+    round_trip(Style::new().with_decorations(
+        Decorations::new().with_hyperlink(Hyperlink::new("https://hash.dev").with_id("hash")),
+    ));
+}
+
+#[test]
+fn colors() {
+    round_trip(Style::new().with_foreground(BasicColor::Red.into()));
+    round_trip(Style::new().with_background(BasicColor::Cyan.bright().into()));
+    round_trip(Style::new().with_foreground(IndexedColor::new(208).into()));
+    round_trip(
+        Style::new()
+            .with_foreground(RgbColor::new(12, 34, 56).into())
+            .with_background(RgbColor::new(78, 90, 123).into()),
+    );
+}
+
+#[cfg(feature = "underline-color")]
+#[test]
+fn underline_colors() {
+    use antsi::UnderlineColor;
+
+    round_trip(Style::new().with_underline_color(UnderlineColor::new(BasicColor::Red.into())));
+    round_trip(
+        Style::new().with_underline_color(UnderlineColor::new(BasicColor::Cyan.bright().into())),
+    );
+    round_trip(
+        Style::new().with_underline_color(UnderlineColor::new(IndexedColor::new(208).into())),
+    );
+    round_trip(Style::new().with_underline_color(UnderlineColor::new(IndexedColor::new(1).into())));
+    round_trip(
+        Style::new().with_underline_color(UnderlineColor::new(IndexedColor::new(15).into())),
+    );
+    round_trip(
+        Style::new().with_underline_color(UnderlineColor::new(RgbColor::new(12, 34, 56).into())),
+    );
+}
+
+#[test]
+fn cmyk_is_converted() {
+    let style = Style::new().with_foreground(CmykColor::new(0, 255, 255, 0).into());
+    let text = style.paint("painted").to_string();
+
+    let (parsed, _) = Parser::new(&text)
+        .next()
+        .expect("should have a single span");
+    assert_eq!(
+        parsed.foreground.map(Foreground::color),
+        Some(Color::Rgb(RgbColor::new(255, 0, 0)))
+    );
+}
+
+#[test]
+fn nested() {
+    let inner = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+    let outer = Style::new().with_foreground(BasicColor::Red.into());
+
+    let text = outer
+        .paint(format_args!("a {} c", inner.paint("b")))
+        .to_string();
+    let spans: Vec<_> = Parser::new(&text).collect();
+
+    assert_eq!(
+        spans,
+        [
+            (outer, "a "),
+            (
+                outer.with_font(Font::new().with_weight(FontWeight::Bold)),
+                "b"
+            ),
+            (outer, " c"),
+        ]
+    );
+}
+
+#[test]
+fn iso_format() {
+    let spans: Vec<_> = Parser::new("\x1b[38:2::1:2:3;48:5:42ma\x1b[0mb").collect();
+
+    assert_eq!(
+        spans,
+        [
+            (
+                Style::new()
+                    .with_foreground(RgbColor::new(1, 2, 3).into())
+                    .with_background(IndexedColor::new(42).into()),
+                "a"
+            ),
+            (Style::new(), "b"),
+        ]
+    );
+}
+
+#[test]
+fn iso_format_without_color_space() {
+    let spans: Vec<_> = Parser::new("\x1b[38:2:1:2:3;48:4:4:3:2:1ma").collect();
+
+    assert_eq!(
+        spans,
+        [(
+            Style::new()
+                .with_foreground(RgbColor::new(1, 2, 3).into())
+                .with_background(CmykColor::new(4, 3, 2, 1).into()),
+            "a"
+        )]
+    );
+}
+
+#[cfg(feature = "underline-color")]
+#[test]
+fn legacy_underline_palette() {
+    use antsi::UnderlineColor;
+
+    let spans: Vec<_> = Parser::new("\x1b[58;5;1ma\x1b[58:5:1mb").collect();
+
+    assert_eq!(
+        spans,
+        [
+            (
+                Style::new().with_underline_color(UnderlineColor::new(BasicColor::Red.into())),
+                "a"
+            ),
+            (
+                Style::new().with_underline_color(UnderlineColor::new(IndexedColor::new(1).into())),
+                "b"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn hyperlink_control_characters_stay_escaped() {
+    let style = Style::new().with_decorations(
+        Decorations::new()
+            .with_hyperlink(Hyperlink::new("https://hash.dev/\x07\x1b[31m").with_id("a;b:c\x1b")),
+    );
+    let text = format!("{} after", style.paint("painted"));
+
+    assert_eq!(
+        text,
+        "\x1b]8;id=a%3Bb%3Ac%1B;https://hash.dev/%07%1B[31m\x1b\\painted\x1b]8;;\x1b\\ after"
+    );
+
+    // The parser borrows from the input, the escaped values are therefore not decoded
+    let spans: Vec<_> = Parser::new(&text).collect();
+    assert_eq!(
+        spans,
+        [
+            (
+                Style::new().with_decorations(Decorations::new().with_hyperlink(
+                    Hyperlink::new("https://hash.dev/%07%1B[31m").with_id("a%3Bb%3Ac%1B")
+                )),
+                "painted"
+            ),
+            (Style::new(), " after"),
+        ]
+    );
+}
+
+#[test]
+fn unknown_sequences_are_skipped() {
+    let spans: Vec<_> = Parser::new("\x1b[2Ka\x1b]0;title\x07b\x1b(Bc").collect();
+
+    assert_eq!(
+        spans,
+        [
+            (Style::new(), "a"),
+            (Style::new(), "b"),
+            (Style::new(), "c")
+        ]
+    );
+}
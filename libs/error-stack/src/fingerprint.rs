@@ -0,0 +1,154 @@
+use core::{fmt, panic::Location};
+
+#[cfg(feature = "serde")]
+use crate::RemoteLocation;
+use crate::{AttachmentKind, Frame, FrameKind, Report};
+
+/// A fingerprint of the shape of a [`Report`].
+///
+/// Two reports have the same fingerprint if their frames are organized in the same tree, hold the
+/// same types of contexts and attachments, and were created at the same code locations. The values
+/// of the contexts and attachments are not taken into account, so reports which only differ in
+/// e.g. an identifier share the same fingerprint.
+///
+/// The fingerprint is computed from the type names and the locations of the frames only, it's
+/// therefore stable across multiple runs of the same program. As the name of a type is not
+/// guaranteed to be stable across compiler versions, fingerprints should not be compared across
+/// different builds.
+///
+/// A fingerprint is obtained by calling [`Report::fingerprint()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Returns the fingerprint as integer.
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    pub(crate) fn from_frames(frames: &[Frame]) -> Self {
+        let mut hasher = Hasher::new();
+
+        hasher.write_usize(frames.len());
+        for frame in frames {
+            hasher.write_frame(frame);
+        }
+
+        Self(hasher.finish())
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{:016x}", self.0)
+    }
+}
+
+/// 64-bit FNV-1a hasher.
+///
+/// The hashers provided by `core` and `std` are not guaranteed to produce the same output across
+/// releases or platforms, so a fixed hash function is used instead.
+struct Hasher(u64);
+
+#[expect(
+    clippy::little_endian_bytes,
+    reason = "The fingerprint must not depend on the platform"
+)]
+impl Hasher {
+    const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    const fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        // `usize` has a platform dependent size, so it's always hashed as `u64`
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write(value.as_bytes());
+        // Same as `str`s `Hash` implementation to avoid `"ab", "c"` and `"a", "bc"` colliding
+        self.write_u8(0xFF);
+    }
+
+    fn write_location(&mut self, file: &str, line: u32, column: u32) {
+        self.write_str(file);
+        self.write_u32(line);
+        self.write_u32(column);
+    }
+
+    fn write_frame(&mut self, frame: &Frame) {
+        self.write_u8(match frame.kind() {
+            FrameKind::Context(_) => 0,
+            FrameKind::Attachment(AttachmentKind::Printable(_)) => 1,
+            FrameKind::Attachment(AttachmentKind::Opaque(_)) => 2,
+        });
+        self.write_str(frame.type_name());
+
+        if let Some(location) = frame.downcast_ref::<Location<'static>>() {
+            self.write_location(location.file(), location.line(), location.column());
+        }
+        #[cfg(feature = "serde")]
+        if let Some(location) = frame.downcast_ref::<RemoteLocation>() {
+            self.write_location(location.file(), location.line(), location.column());
+        }
+
+        self.write_usize(frame.sources().len());
+        for source in frame.sources() {
+            self.write_frame(source);
+        }
+    }
+}
+
+impl<C: ?Sized> Report<C> {
+    /// Returns the [`Fingerprint`] of the shape of this `Report`.
+    ///
+    /// Reports, which are structurally identical, but differ in the values of their contexts or
+    /// attachments, have the same fingerprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{IntoReport as _, Report};
+    ///
+    /// fn read_entity(id: u32) -> Report<Error> {
+    ///     Error::from(ErrorKind::NotFound)
+    ///         .into_report()
+    ///         .attach(format!("entity {id} does not exist"))
+    /// }
+    ///
+    /// assert_eq!(read_entity(1).fingerprint(), read_entity(2).fingerprint());
+    /// assert_ne!(
+    ///     read_entity(1).fingerprint(),
+    ///     read_entity(1).attach_opaque(42).fingerprint()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::from_frames(self.current_frames_unchecked())
+    }
+}
@@ -474,6 +474,8 @@ mod default {
     #[cfg(feature = "spantrace")]
     use tracing_error::SpanTrace;
 
+    #[cfg(feature = "serde")]
+    use crate::RemoteLocation;
     use crate::{
         Report,
         fmt::{hook::HookContext, location::LocationAttachment},
@@ -505,6 +507,9 @@ mod default {
 
             Report::install_debug_hook::<Location>(location);
 
+            #[cfg(feature = "serde")]
+            Report::install_debug_hook::<RemoteLocation>(remote_location);
+
             #[cfg(feature = "backtrace")]
             Report::install_debug_hook::<Backtrace>(backtrace);
 
@@ -517,6 +522,11 @@ mod default {
         context.push_body(LocationAttachment::new(location, context.color_mode()).to_string());
     }
 
+    #[cfg(feature = "serde")]
+    fn remote_location(location: &RemoteLocation, context: &mut HookContext<RemoteLocation>) {
+        context.push_body(LocationAttachment::new(location, context.color_mode()).to_string());
+    }
+
     #[cfg(feature = "backtrace")]
     fn backtrace(backtrace: &Backtrace, context: &mut HookContext<Backtrace>) {
         let idx = context.increment_counter();
@@ -1,20 +1,24 @@
-use core::{fmt, panic::Location};
+use core::fmt;
 
 use crate::fmt::color::{Color, ColorMode, DisplayStyle, Style};
 
-pub(super) struct LocationAttachment<'a, 'loc> {
-    location: &'a Location<'loc>,
+/// Renders a [`Location`] or a deserialized [`RemoteLocation`].
+///
+/// [`Location`]: core::panic::Location
+/// [`RemoteLocation`]: crate::RemoteLocation
+pub(super) struct LocationAttachment<'a> {
+    location: &'a dyn fmt::Display,
     mode: ColorMode,
 }
 
-impl<'a, 'loc> LocationAttachment<'a, 'loc> {
+impl<'a> LocationAttachment<'a> {
     #[must_use]
-    pub(super) const fn new(location: &'a Location<'loc>, mode: ColorMode) -> Self {
+    pub(super) const fn new(location: &'a dyn fmt::Display, mode: ColorMode) -> Self {
         Self { location, mode }
     }
 }
 
-impl fmt::Display for LocationAttachment<'_, '_> {
+impl fmt::Display for LocationAttachment<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let location = self.location;
 
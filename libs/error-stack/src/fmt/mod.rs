@@ -872,11 +872,21 @@ fn debug_attachments_invoke<'a>(
                 Some(vec![attachment.to_string()])
             }
             #[cfg(not(any(feature = "std", feature = "hooks")))]
-            FrameKind::Attachment(AttachmentKind::Opaque(_)) => frame
-                .downcast_ref::<core::panic::Location<'static>>()
-                .map(|location| {
+            FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
+                let location = frame
+                    .downcast_ref::<core::panic::Location<'static>>()
+                    .map(|location| location as &dyn core::fmt::Display);
+                #[cfg(feature = "serde")]
+                let location = location.or_else(|| {
+                    frame
+                        .downcast_ref::<crate::RemoteLocation>()
+                        .map(|location| location as &dyn core::fmt::Display)
+                });
+
+                location.map(|location| {
                     vec![LocationAttachment::new(location, config.color_mode()).to_string()]
-                }),
+                })
+            }
         })
         .flat_map(|body| {
             body.unwrap_or_else(|| {
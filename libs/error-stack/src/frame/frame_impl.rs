@@ -1,7 +1,11 @@
 use alloc::boxed::Box;
 #[cfg(nightly)]
 use core::error::Request;
-use core::{any::Any, error::Error, fmt};
+use core::{
+    any::{Any, type_name},
+    error::Error,
+    fmt,
+};
 
 use crate::{AttachmentKind, Frame, FrameKind};
 
@@ -13,6 +17,9 @@ pub(super) trait FrameImpl: Send + Sync + 'static {
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
 
+    /// Returns the name of the type of the held context or attachment.
+    fn type_name(&self) -> &'static str;
+
     /// Provide values which can then be requested.
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>);
@@ -54,6 +61,10 @@ impl<C: Error + Send + Sync + 'static> FrameImpl for ContextFrame<C> {
         &mut self.context
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<C>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         Error::provide(&self.context, request);
@@ -77,6 +88,10 @@ impl<A: 'static + Send + Sync> FrameImpl for AttachmentFrame<A> {
         &mut self.attachment
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         request.provide_ref(&self.attachment);
@@ -102,6 +117,10 @@ impl<A: 'static + fmt::Debug + fmt::Display + Send + Sync> FrameImpl
         &mut self.attachment
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         request.provide_ref(&self.attachment);
@@ -148,6 +167,10 @@ impl FrameImpl for AnyhowContext {
         &mut self.0
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<anyhow::Error>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
@@ -195,6 +218,10 @@ impl FrameImpl for EyreContext {
         &mut self.0
     }
 
+    fn type_name(&self) -> &'static str {
+        type_name::<eyre::Report>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
@@ -2,6 +2,8 @@ mod frame_impl;
 mod kind;
 
 use alloc::boxed::Box;
+#[cfg(feature = "serde")]
+use core::any::Any;
 #[cfg(nightly)]
 use core::error;
 use core::{any::TypeId, error::Error, fmt};
@@ -96,9 +98,19 @@ impl Frame {
         self.frame.as_any().type_id()
     }
 
+    /// Returns the name of the type of the held context or attachment by this frame.
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.frame.type_name()
+    }
+
     pub(crate) fn as_error(&self) -> &impl Error {
         &self.frame
     }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn as_any(&self) -> &dyn Any {
+        self.frame.as_any()
+    }
 }
 
 impl fmt::Debug for Frame {
@@ -2,6 +2,11 @@ pub(crate) mod context;
 
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+use serde_core::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::serde::SerdeHooks;
 use crate::{
     Report,
     fmt::{Hooks, install_builtin_hooks},
@@ -17,6 +22,9 @@ type RwLock<T> = spin::rwlock::RwLock<T>;
 
 static FMT_HOOK: RwLock<Hooks> = RwLock::new(Hooks { inner: Vec::new() });
 
+#[cfg(feature = "serde")]
+static SERDE_HOOK: RwLock<SerdeHooks> = RwLock::new(SerdeHooks::new());
+
 impl Report<()> {
     /// Can be used to globally set a [`Debug`] format hook, for a specific type `T`.
     ///
@@ -237,4 +245,98 @@ impl Report<()> {
 
         closure(&hook)
     }
+
+    /// Can be used to globally set a serde hook, for a specific type `T`.
+    ///
+    /// Contexts and attachments of type `T` are serialized by converting them into `V` using
+    /// `serialize`. The serialized value is tagged with `name`, which is used to find the hook
+    /// again when deserializing the [`Report`]. Deserialized values are converted back using
+    /// `deserialize`, returning [`None`] signals that the value is invalid.
+    ///
+    /// Attachments of type `T` are restored as opaque attachments, while contexts of type `T` are
+    /// restored as [`RemoteContext`], from which the value can be retrieved using
+    /// [`RemoteContext::value`]. Values with a name, for which no hook has been installed, are
+    /// skipped during deserialization.
+    ///
+    /// Installing a hook replaces any hook previously installed for the same type or name.
+    ///
+    /// [`RemoteContext`]: crate::RemoteContext
+    /// [`RemoteContext::value`]: crate::RemoteContext::value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{IntoReport, RemoteContext, Report};
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct StatusCode(u16);
+    ///
+    /// Report::install_serde_hook::<StatusCode, u16>(
+    ///     "status_code",
+    ///     |StatusCode(code)| *code,
+    ///     |code| (100..600).contains(&code).then_some(StatusCode(code)),
+    /// );
+    ///
+    /// let report = Error::from(ErrorKind::InvalidInput)
+    ///     .into_report()
+    ///     .attach_opaque(StatusCode(400));
+    ///
+    /// let serialized = serde_json::to_string(&report)?;
+    /// let report: Report<RemoteContext> = serde_json::from_str(&serialized)?;
+    ///
+    /// assert_eq!(report.downcast_ref::<StatusCode>(), Some(&StatusCode(400)));
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_serde_hook<T, V>(
+        name: &'static str,
+        serialize: impl Fn(&T) -> V + Send + Sync + 'static,
+        deserialize: impl Fn(V) -> Option<T> + Send + Sync + 'static,
+    ) where
+        T: Send + Sync + 'static,
+        V: Serialize + for<'de> Deserialize<'de> + 'static,
+    {
+        #[cfg(feature = "std")]
+        let Ok(mut lock) = SERDE_HOOK.write() else {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        };
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERDE_HOOK.write();
+
+        lock.insert(name, serialize, deserialize);
+    }
+
+    /// Returns the hooks that were previously set by [`install_serde_hook`].
+    ///
+    /// The closure is called with a snapshot of the hooks, the lock is released beforehand. This
+    /// allows hooks to (de)serialize reports or to install other hooks without deadlocking.
+    ///
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    #[cfg(feature = "serde")]
+    pub(crate) fn invoke_serde_hook<T>(closure: impl FnOnce(&SerdeHooks) -> T) -> T {
+        #[cfg(feature = "std")]
+        let hooks = {
+            let Ok(hooks) = SERDE_HOOK.read() else {
+                unreachable!(
+                    "Hook is poisoned. This is considered a bug and should be reported to \
+                    https://github.com/hashintel/hash/issues/new/choose"
+                )
+            };
+
+            hooks.clone()
+        };
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let hooks = SERDE_HOOK.read().clone();
+
+        closure(&hooks)
+    }
 }
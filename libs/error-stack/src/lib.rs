@@ -431,6 +431,22 @@
 //! You can add new hooks with [`Report::install_debug_hook`]. Refer to the module-level
 //! documentation of [`fmt`] for further information.
 //!
+//! ### Serialization
+//!
+//! With the `serde` feature enabled, [`Report`] can be serialized and deserialized. As the types of
+//! the contexts are not known on the receiving side, a deserialized [`Report`] contains
+//! [`RemoteContext`]s. Opaque attachments are only serialized if a hook has been installed for
+//! their type with [`Report::install_serde_hook`].
+//!
+//! ### OpenTelemetry
+//!
+//! With the `opentelemetry` feature enabled, a [`Report`] can be recorded as an exception event on
+//! a span with [`Report::record_exception`]. Instead of a single string, the frames of the
+//! [`Report`] are exported as structured attributes, which are returned by
+//! [`Report::exception_attributes`]. Together with the `spantrace` feature, the span is linked to
+//! the `tracing` span the [`Report`] has been created in, if it's exported by
+//! `tracing-opentelemetry`.
+//!
 //! ### Additional Adaptors
 //!
 //! [`ResultExt`] is a convenient wrapper around `Result<_, impl Error>` and `Result<_, Report<impl
@@ -460,7 +476,8 @@
 //! `backtrace`    | Enables automatic capturing of [`Backtrace`]s (requires Rust 1.65+) | enabled
 //! `spantrace`    | Enables automatic capturing of [`SpanTrace`]s                       | disabled
 //! `hooks`        | Enables hooks on `no-std` platforms using spin locks                | disabled
-//! `serde`        | Enables serialization support for [`Report`]                        | disabled
+//! `serde`        | Enables (de)serialization support for [`Report`]                    | disabled
+//! `opentelemetry`| Records [`Report`]s as OpenTelemetry exception events               | disabled
 //! `anyhow`       | Provides `into_report` to convert [`anyhow::Error`] to [`Report`]   | disabled
 //! `eyre`         | Provides `into_report` to convert [`eyre::Report`] to [`Report`]    | disabled
 //! `futures`      | Enables support for [`Stream`], requires `unstable`                 | disabled
@@ -519,9 +536,12 @@ mod context;
 mod error;
 #[cfg(feature = "unstable")]
 pub mod ext;
+mod fingerprint;
 pub mod fmt;
 #[cfg(any(feature = "std", feature = "hooks"))]
 mod hook;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(feature = "unstable")]
@@ -531,11 +551,14 @@ mod sink;
 pub use self::ext::stream::TryReportStreamExt;
 #[cfg(feature = "unstable")]
 pub use self::ext::{iter::TryReportIteratorExt, tuple::TryReportTupleExt};
+#[cfg(feature = "serde")]
+pub use self::serde::{RemoteContext, RemoteLocation};
 #[cfg(feature = "unstable")]
-pub use self::sink::ReportSink;
+pub use self::sink::{Occurrences, ReportSink};
 pub use self::{
     compat::IntoReportCompat,
     context::{Attachment, OpaqueAttachment},
+    fingerprint::Fingerprint,
     frame::{AttachmentKind, Frame, FrameKind},
     report::{IntoReport, Report},
 };
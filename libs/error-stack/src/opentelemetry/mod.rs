@@ -0,0 +1,434 @@
+//! Export of a [`Report`] to [OpenTelemetry].
+//!
+//! A [`Report`] is recorded as an [exception event], its frame tree is exported as structured
+//! attributes of the event.
+//!
+//! [OpenTelemetry]: https://opentelemetry.io
+//! [exception event]: https://opentelemetry.io/docs/specs/semconv/exceptions/exceptions-spans/
+
+#[cfg(feature = "serde")]
+mod serializer;
+
+use alloc::{format, string::String, vec::Vec};
+use core::{any::type_name, panic::Location};
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+use opentelemetry::{
+    Array, KeyValue, Value,
+    trace::{Span, SpanContext},
+};
+#[cfg(feature = "spantrace")]
+use tracing_error::{SpanTrace, SpanTraceStatus};
+
+#[cfg(feature = "serde")]
+use crate::RemoteLocation;
+use crate::{AttachmentKind, Frame, FrameKind, Report};
+
+#[derive(Clone)]
+struct CodeLocation {
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+impl CodeLocation {
+    fn from_frame(frame: &Frame) -> Option<Self> {
+        if let Some(location) = frame.downcast_ref::<Location<'static>>() {
+            return Some(Self {
+                file: location.file().into(),
+                line: location.line(),
+                column: location.column(),
+            });
+        }
+
+        #[cfg(feature = "serde")]
+        if let Some(location) = frame.downcast_ref::<RemoteLocation>() {
+            return Some(Self {
+                file: location.file().into(),
+                line: location.line(),
+                column: location.column(),
+            });
+        }
+
+        None
+    }
+
+    fn push_attributes(self, prefix: &str, attributes: &mut Vec<KeyValue>) {
+        attributes.push(KeyValue::new(format!("{prefix}code.filepath"), self.file));
+        attributes.push(KeyValue::new(
+            format!("{prefix}code.lineno"),
+            i64::from(self.line),
+        ));
+        attributes.push(KeyValue::new(
+            format!("{prefix}code.column"),
+            i64::from(self.column),
+        ));
+    }
+}
+
+/// Returns the attributes of the value of `frame`, if a serde hook has been installed for its
+/// type.
+///
+/// The keys of the attributes are relative to the attribute of the frame.
+#[cfg(feature = "serde")]
+fn value_attributes(frame: &Frame) -> Vec<KeyValue> {
+    crate::serde::hook_value(frame)
+        .and_then(|(name, value)| serializer::flatten(format!("value.{name}"), &*value))
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "serde"))]
+const fn value_attributes(_: &Frame) -> Vec<KeyValue> {
+    Vec::new()
+}
+
+fn prefixed(prefix: &str, attributes: Vec<KeyValue>) -> impl Iterator<Item = KeyValue> + '_ {
+    attributes
+        .into_iter()
+        .map(move |attribute| KeyValue::new(format!("{prefix}{}", attribute.key), attribute.value))
+}
+
+#[derive(Clone)]
+struct Attachment {
+    type_name: &'static str,
+    message: Option<String>,
+    value: Vec<KeyValue>,
+}
+
+impl Attachment {
+    fn push_attributes(self, prefix: &str, attributes: &mut Vec<KeyValue>) {
+        attributes.push(KeyValue::new(format!("{prefix}type"), self.type_name));
+        if let Some(message) = self.message {
+            attributes.push(KeyValue::new(format!("{prefix}message"), message));
+        }
+        attributes.extend(prefixed(prefix, self.value));
+    }
+}
+
+/// Returns the context of the current `tracing` span, if it is exported to OpenTelemetry.
+///
+/// This is the innermost span of a [`SpanTrace`] captured at the same time.
+#[cfg(feature = "spantrace")]
+pub(crate) fn current_span_context() -> Option<SpanContext> {
+    use opentelemetry::trace::TraceContextExt as _;
+    use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+    let context = tracing::Span::current().context();
+    let span_context = context.span().span_context().clone();
+
+    span_context.is_valid().then_some(span_context)
+}
+
+#[derive(Default)]
+struct Exporter {
+    attributes: Vec<KeyValue>,
+    contexts: usize,
+    location: Option<CodeLocation>,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<String>,
+    #[cfg(feature = "spantrace")]
+    span_trace: Option<Vec<KeyValue>>,
+}
+
+impl Exporter {
+    /// Visits a [`Backtrace`] or [`SpanTrace`] attachment, returns `true` if `frame` is one.
+    ///
+    /// The [`SpanContext`] captured together with a [`SpanTrace`] is linked instead of being
+    /// exported as attribute, it's treated as part of the trace.
+    #[cfg(any(feature = "backtrace", feature = "spantrace"))]
+    fn visit_trace(&mut self, frame: &Frame) -> bool {
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = frame.downcast_ref::<Backtrace>() {
+            if self.backtrace.is_none() && backtrace.status() == BacktraceStatus::Captured {
+                self.backtrace = Some(backtrace.to_string());
+            }
+
+            return true;
+        }
+
+        #[cfg(feature = "spantrace")]
+        if let Some(span_trace) = frame.downcast_ref::<SpanTrace>() {
+            if self.span_trace.is_none() && span_trace.status() == SpanTraceStatus::CAPTURED {
+                let mut attributes = Vec::new();
+                let mut index = 0_usize;
+                span_trace.with_spans(|metadata, fields| {
+                    let prefix = format!("error_stack.spantrace.{index}.");
+                    index += 1;
+
+                    attributes.push(KeyValue::new(format!("{prefix}name"), metadata.name()));
+                    attributes.push(KeyValue::new(format!("{prefix}target"), metadata.target()));
+                    if !fields.is_empty() {
+                        attributes
+                            .push(KeyValue::new(format!("{prefix}fields"), fields.to_owned()));
+                    }
+                    if let Some(file) = metadata.file() {
+                        attributes.push(KeyValue::new(format!("{prefix}code.filepath"), file));
+                    }
+                    if let Some(line) = metadata.line() {
+                        attributes.push(KeyValue::new(
+                            format!("{prefix}code.lineno"),
+                            i64::from(line),
+                        ));
+                    }
+
+                    true
+                });
+
+                self.span_trace = Some(attributes);
+            }
+
+            return true;
+        }
+
+        #[cfg(feature = "spantrace")]
+        if frame.is::<SpanContext>() {
+            return true;
+        }
+
+        false
+    }
+
+    /// Visits `frame` and all frames below it until a context is found.
+    ///
+    /// The indices of the contexts found are appended to `indices`. If a frame has multiple
+    /// sources, the attachments collected so far are exported for every context found in them.
+    fn visit(
+        &mut self,
+        mut frame: &Frame,
+        mut attachments: Vec<Attachment>,
+        mut location: Option<CodeLocation>,
+        indices: &mut Vec<i64>,
+    ) {
+        loop {
+            match frame.kind() {
+                FrameKind::Context(context) => {
+                    let index = self.contexts;
+                    self.contexts += 1;
+                    indices.push(i64::try_from(index).unwrap_or(i64::MAX));
+
+                    let prefix = format!("error_stack.contexts.{index}.");
+                    self.attributes.push(KeyValue::new(
+                        format!("{prefix}message"),
+                        context.to_string(),
+                    ));
+                    self.attributes
+                        .extend(prefixed(&prefix, value_attributes(frame)));
+                    if index == 0 {
+                        self.location.clone_from(&location);
+                    }
+                    if let Some(location) = location {
+                        location.push_attributes(&prefix, &mut self.attributes);
+                    }
+                    for (attachment_index, attachment) in attachments.into_iter().enumerate() {
+                        attachment.push_attributes(
+                            &format!("{prefix}attachments.{attachment_index}."),
+                            &mut self.attributes,
+                        );
+                    }
+
+                    let mut sources = Vec::new();
+                    for source in frame.sources() {
+                        self.visit(source, Vec::new(), None, &mut sources);
+                    }
+                    if !sources.is_empty() {
+                        self.attributes.push(KeyValue::new(
+                            format!("{prefix}sources"),
+                            Value::Array(Array::I64(sources)),
+                        ));
+                    }
+
+                    return;
+                }
+                FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+                    attachments.push(Attachment {
+                        type_name: frame.type_name(),
+                        message: Some(attachment.to_string()),
+                        value: value_attributes(frame),
+                    });
+                }
+                FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
+                    #[cfg(any(feature = "backtrace", feature = "spantrace"))]
+                    let is_trace = self.visit_trace(frame);
+                    #[cfg(not(any(feature = "backtrace", feature = "spantrace")))]
+                    let is_trace = false;
+
+                    let frame_location = CodeLocation::from_frame(frame);
+                    if frame_location.is_none() && !is_trace {
+                        attachments.push(Attachment {
+                            type_name: frame.type_name(),
+                            message: None,
+                            value: value_attributes(frame),
+                        });
+                    }
+
+                    // The location closest to a context is the one it has been created at
+                    location = frame_location.or(location);
+                }
+            }
+
+            match frame.sources() {
+                [] => return,
+                [source] => frame = source,
+                sources => {
+                    for source in sources {
+                        self.visit(source, attachments.clone(), location.clone(), indices);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl<C: ?Sized> Report<C> {
+    fn export(&self) -> Exporter {
+        let mut exporter = Exporter::default();
+
+        let mut indices = Vec::new();
+        for frame in self.current_frames_unchecked() {
+            exporter.visit(frame, Vec::new(), None, &mut indices);
+        }
+
+        exporter
+    }
+
+    /// Returns the attributes of an [exception event] describing this `Report`.
+    ///
+    /// Besides `exception.type` and `exception.message`, the location of the current context is
+    /// exported as `code.filepath`, `code.lineno` and `code.column`, and a captured
+    /// [`Backtrace`] as `exception.stacktrace`. If the `spantrace` feature is enabled, the spans of
+    /// a captured [`SpanTrace`] are exported, starting with the innermost span, as
+    /// `error_stack.spantrace.<index>.name`, `.target`, `.fields`, `.code.filepath` and
+    /// `.code.lineno`.
+    ///
+    /// Every context is assigned an index in depth-first order, starting with `0` for the current
+    /// context, and is exported with the following attributes:
+    ///
+    /// - `error_stack.contexts.<index>.message`: [`Display`] output of the context
+    /// - `error_stack.contexts.<index>.value.<name>`: Value of the context, see below
+    /// - `error_stack.contexts.<index>.code.filepath`: File the context was created in
+    /// - `error_stack.contexts.<index>.code.lineno`: Line the context was created at
+    /// - `error_stack.contexts.<index>.code.column`: Column the context was created at
+    /// - `error_stack.contexts.<index>.sources`: Indices of the source contexts as `int[]`
+    ///
+    /// Attachments belong to the first context below them and are numbered from the top, their
+    /// attributes are prefixed with `error_stack.contexts.<index>.attachments.<attachment>`:
+    ///
+    /// - `.type`: Name of the type of the attachment
+    /// - `.message`: [`Display`] output of a printable attachment
+    /// - `.value.<name>`: Value of the attachment, see below
+    ///
+    /// If the `serde` feature is enabled and a hook has been installed for the type of a context
+    /// or an attachment with [`install_serde_hook()`], its serialized value is exported under
+    /// the name of the hook. Maps, structs and sequences are flattened, e.g. the field `code` of a
+    /// struct is exported as `.value.<name>.code`.
+    ///
+    /// The returned attributes can be used to record the `Report` on any span, e.g. on the current
+    /// `tracing` span by using `tracing-opentelemetry`:
+    ///
+    /// ```rust,ignore
+    /// use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+    ///
+    /// tracing::Span::current().add_event("exception", report.exception_attributes());
+    /// ```
+    ///
+    /// [exception event]: https://opentelemetry.io/docs/specs/semconv/exceptions/exceptions-spans/
+    /// [`Backtrace`]: std::backtrace::Backtrace
+    /// [`SpanTrace`]: tracing_error::SpanTrace
+    /// [`Display`]: core::fmt::Display
+    /// [`install_serde_hook()`]: Report::install_serde_hook
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::IntoReport as _;
+    /// use opentelemetry::{Key, Value};
+    ///
+    /// let report = Error::from(ErrorKind::NotFound)
+    ///     .into_report()
+    ///     .attach("the config file is missing");
+    ///
+    /// let attributes = report.exception_attributes();
+    /// let message = attributes
+    ///     .iter()
+    ///     .find(|attribute| attribute.key == Key::from_static_str("exception.message"))
+    ///     .map(|attribute| &attribute.value);
+    ///
+    /// assert_eq!(message, Some(&Value::from("entity not found")));
+    /// ```
+    #[must_use]
+    pub fn exception_attributes(&self) -> Vec<KeyValue> {
+        self.export()
+            .into_attributes(type_name::<C>(), self.to_string())
+    }
+
+    /// Records this `Report` as an [exception event] on `span`.
+    ///
+    /// The attributes of the event are described in [`exception_attributes()`].
+    ///
+    /// If the `spantrace` feature is enabled and the `tracing` spans are exported to OpenTelemetry
+    /// using `tracing-opentelemetry`, `span` is linked to the innermost span of every captured
+    /// [`SpanTrace`], the span the [`Report`] has been created in.
+    ///
+    /// [exception event]: https://opentelemetry.io/docs/specs/semconv/exceptions/exceptions-spans/
+    /// [`exception_attributes()`]: Self::exception_attributes
+    /// [`SpanTrace`]: tracing_error::SpanTrace
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::IntoReport as _;
+    /// use opentelemetry::{
+    ///     global,
+    ///     trace::{Span as _, Tracer as _},
+    /// };
+    ///
+    /// let mut span = global::tracer("example").start("read config");
+    ///
+    /// let report = Error::from(ErrorKind::NotFound).into_report();
+    /// report.record_exception(&mut span);
+    ///
+    /// span.end();
+    /// ```
+    pub fn record_exception(&self, span: &mut impl Span) {
+        span.add_event("exception", self.exception_attributes());
+
+        let mut links = Vec::new();
+        for span_context in self.frames().filter_map(Frame::downcast_ref::<SpanContext>) {
+            if !links.contains(span_context) {
+                links.push(span_context.clone());
+            }
+        }
+
+        for span_context in links {
+            span.add_link(span_context, Vec::new());
+        }
+    }
+}
+
+impl Exporter {
+    fn into_attributes(mut self, exception_type: &'static str, message: String) -> Vec<KeyValue> {
+        let mut attributes = Vec::with_capacity(self.attributes.len() + 6);
+        attributes.push(KeyValue::new("exception.type", exception_type));
+        attributes.push(KeyValue::new("exception.message", message));
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = self.backtrace {
+            attributes.push(KeyValue::new("exception.stacktrace", backtrace));
+        }
+        #[cfg(feature = "spantrace")]
+        if let Some(mut span_trace) = self.span_trace {
+            attributes.append(&mut span_trace);
+        }
+        if let Some(location) = self.location {
+            location.push_attributes("", &mut attributes);
+        }
+        attributes.append(&mut self.attributes);
+
+        attributes
+    }
+}
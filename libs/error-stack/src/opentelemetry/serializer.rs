@@ -0,0 +1,428 @@
+//! Flattening of serializable values into OpenTelemetry attributes.
+//!
+//! Attribute values are restricted to primitives and arrays of primitives, compound values are
+//! therefore flattened: every primitive is exported as its own attribute, whose key is the path to
+//! the primitive, joined by `.`.
+
+use alloc::{
+    borrow::ToOwned as _,
+    format,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::{error::Error, fmt};
+
+use opentelemetry::{Array, KeyValue, Value};
+use serde_core::{
+    Serialize, Serializer,
+    ser::{
+        self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+
+/// Flattens `value` into attributes, whose keys start with `key`.
+///
+/// Returns [`None`] if `value` cannot be represented as attributes, e.g. because it's a map with
+/// keys, which aren't primitives.
+pub(super) fn flatten<T: Serialize + ?Sized>(key: String, value: &T) -> Option<Vec<KeyValue>> {
+    let mut attributes = Vec::new();
+    value
+        .serialize(AttributeSerializer {
+            key,
+            attributes: &mut attributes,
+        })
+        .ok()?;
+
+    Some(attributes)
+}
+
+#[derive(Debug)]
+struct UnsupportedValue;
+
+impl fmt::Display for UnsupportedValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("value cannot be represented as attributes")
+    }
+}
+
+impl Error for UnsupportedValue {}
+
+impl ser::Error for UnsupportedValue {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        Self
+    }
+}
+
+struct AttributeSerializer<'a> {
+    key: String,
+    attributes: &'a mut Vec<KeyValue>,
+}
+
+impl<'a> AttributeSerializer<'a> {
+    fn push(self, value: impl Into<Value>) {
+        self.attributes.push(KeyValue::new(self.key, value));
+    }
+
+    /// Returns a serializer for the content of `variant`.
+    fn variant(self, variant: &str) -> Self {
+        Self {
+            key: format!("{}.{variant}", self.key),
+            attributes: self.attributes,
+        }
+    }
+
+    fn compound(self) -> Compound<'a> {
+        Compound {
+            key: self.key,
+            index: 0,
+            map_key: None,
+            attributes: self.attributes,
+        }
+    }
+}
+
+#[expect(
+    clippy::renamed_function_params,
+    reason = "`serde` uses single letter parameter names"
+)]
+impl<'a> Serializer for AttributeSerializer<'a> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+    type SerializeMap = Compound<'a>;
+    type SerializeSeq = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+
+    fn serialize_bool(self, value: bool) -> Result<(), Self::Error> {
+        self.push(value);
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<(), Self::Error> {
+        self.push(value);
+        Ok(())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<(), Self::Error> {
+        // Integers, which don't fit into an `i64`, are exported as strings
+        match i64::try_from(value) {
+            Ok(value) => self.push(value),
+            Err(_) => self.push(value.to_string()),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), Self::Error> {
+        self.push(i64::from(value));
+        Ok(())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<(), Self::Error> {
+        self.serialize_u128(u128::from(value))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<(), Self::Error> {
+        match i64::try_from(value) {
+            Ok(value) => self.push(value),
+            Err(_) => self.push(value.to_string()),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), Self::Error> {
+        self.push(f64::from(value));
+        Ok(())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), Self::Error> {
+        self.push(value);
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), Self::Error> {
+        self.push(value.to_string());
+        Ok(())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), Self::Error> {
+        self.push(value.to_owned());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), Self::Error> {
+        self.push(Value::Array(Array::I64(
+            value.iter().copied().map(i64::from).collect(),
+        )));
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.push(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self.variant(variant))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(self.variant(variant).compound())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(self.variant(variant).compound())
+    }
+}
+
+/// Serializes the elements of a compound value, each under the key of the value followed by the
+/// index, field name or map key of the element.
+struct Compound<'a> {
+    key: String,
+    index: usize,
+    map_key: Option<String>,
+    attributes: &'a mut Vec<KeyValue>,
+}
+
+impl Compound<'_> {
+    fn serialize_keyed<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), UnsupportedValue> {
+        value.serialize(AttributeSerializer {
+            key: format!("{}.{key}", self.key),
+            attributes: self.attributes,
+        })
+    }
+
+    fn serialize_indexed<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), UnsupportedValue> {
+        let index = self.index;
+        self.index += 1;
+
+        self.serialize_keyed(&index.to_string(), value)
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.serialize_indexed(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.serialize_indexed(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.serialize_indexed(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.serialize_indexed(value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        // Keys are serialized like values, only primitive keys are supported
+        let mut attributes = flatten(String::new(), key).ok_or(UnsupportedValue)?;
+        let key = match attributes.pop() {
+            Some(KeyValue {
+                value: value @ (Value::Bool(_) | Value::I64(_) | Value::F64(_) | Value::String(_)),
+                ..
+            }) if attributes.is_empty() => value.as_str().into_owned(),
+            _ => return Err(UnsupportedValue),
+        };
+
+        self.map_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.map_key.take().ok_or(UnsupportedValue)?;
+        self.serialize_keyed(&key, value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.serialize_keyed(key, value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Error = UnsupportedValue;
+    type Ok = ();
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.serialize_keyed(key, value)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
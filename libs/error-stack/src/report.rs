@@ -338,6 +338,13 @@ impl<C> Report<C> {
         #[cfg(feature = "spantrace")]
         if let Some(span_trace) = span_trace.filter(|st| st.status() == SpanTraceStatus::CAPTURED) {
             report = report.attach_opaque(span_trace);
+
+            // The span trace starts at the current span, which is linked when the report is
+            // recorded as an exception event
+            #[cfg(feature = "opentelemetry")]
+            if let Some(span_context) = crate::opentelemetry::current_span_context() {
+                report = report.attach_opaque(span_context);
+            }
         }
 
         report
@@ -492,6 +499,22 @@ impl<C: ?Sized> Report<C> {
         &self.frames
     }
 
+    /// Creates a `Report` from already constructed frames without capturing any additional
+    /// information.
+    #[cfg(any(feature = "serde", feature = "unstable"))]
+    pub(crate) fn from_frames(frames: Vec<Frame>) -> Self {
+        Self {
+            frames: Box::new(frames),
+            _context: PhantomData,
+        }
+    }
+
+    /// Consumes the `Report` and returns its current frames.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn into_frames(self) -> Vec<Frame> {
+        *self.frames
+    }
+
     /// Adds additional (printable) information to the [`Frame`] stack.
     ///
     /// This behaves like [`attach_opaque()`] but the display implementation will be called when
@@ -0,0 +1,137 @@
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::any::{Any, TypeId};
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+use serde_core::{Deserialize, Serialize, de::Error as _};
+
+use crate::Frame;
+
+pub(crate) type BoxedValue = Box<dyn Any + Send + Sync>;
+
+type SerializeHook =
+    Box<dyn Fn(&dyn Any) -> Option<Box<dyn erased_serde::Serialize>> + Send + Sync>;
+type DeserializeHook = Box<
+    dyn Fn(&mut dyn erased_serde::Deserializer<'_>) -> Result<BoxedValue, erased_serde::Error>
+        + Send
+        + Sync,
+>;
+
+/// A hook used to serialize and deserialize values of a single type.
+///
+/// The value is converted into a representation, which implements [`Serialize`] and
+/// [`Deserialize`], before it is serialized, and converted back after it has been deserialized.
+///
+/// [`Serialize`]: serde_core::Serialize
+/// [`Deserialize`]: serde_core::Deserialize
+pub(crate) struct SerdeHook {
+    type_id: TypeId,
+    name: &'static str,
+    serialize: SerializeHook,
+    deserialize: DeserializeHook,
+    attach: fn(BoxedValue, Box<[Frame]>) -> Frame,
+}
+
+impl SerdeHook {
+    pub(crate) const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub(crate) fn serialize(&self, value: &dyn Any) -> Option<Box<dyn erased_serde::Serialize>> {
+        (self.serialize)(value)
+    }
+
+    pub(crate) fn deserialize(
+        &self,
+        deserializer: &mut dyn erased_serde::Deserializer<'_>,
+    ) -> Result<BoxedValue, erased_serde::Error> {
+        (self.deserialize)(deserializer)
+    }
+
+    /// Returns the function creating an attachment frame from a value returned by
+    /// [`deserialize()`].
+    ///
+    /// [`deserialize()`]: Self::deserialize
+    pub(crate) const fn attach(&self) -> fn(BoxedValue, Box<[Frame]>) -> Frame {
+        self.attach
+    }
+}
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn attach<T: Send + Sync + 'static>(value: BoxedValue, sources: Box<[Frame]>) -> Frame {
+    let value = value.downcast::<T>().unwrap_or_else(|_| {
+        unreachable!("the value has been created by the hook of the same type")
+    });
+
+    Frame::from_attachment(*value, sources)
+}
+
+/// Holds the list of serde hooks.
+///
+/// Hooks are looked up by the [`TypeId`] of a value while serializing and by their name while
+/// deserializing.
+///
+/// Hooks are reference counted, so that a snapshot of the installed hooks can be taken cheaply and
+/// used without holding the global lock, which allows hooks to serialize or deserialize reports
+/// themselves.
+#[derive(Clone)]
+pub(crate) struct SerdeHooks {
+    // We use `Vec`, instead of `HashMap` or `BTreeMap`, to be consistent with the `Debug` hooks.
+    inner: Vec<Arc<SerdeHook>>,
+}
+
+impl SerdeHooks {
+    pub(crate) const fn new() -> Self {
+        Self { inner: Vec::new() }
+    }
+
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    pub(crate) fn insert<T, V>(
+        &mut self,
+        name: &'static str,
+        serialize: impl Fn(&T) -> V + Send + Sync + 'static,
+        deserialize: impl Fn(V) -> Option<T> + Send + Sync + 'static,
+    ) where
+        T: Send + Sync + 'static,
+        V: Serialize + for<'de> Deserialize<'de> + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+
+        // make sure that previous hooks of the same type or with the same name are deleted.
+        self.inner
+            .retain(|hook| hook.type_id != type_id && hook.name != name);
+
+        self.inner.push(Arc::new(SerdeHook {
+            type_id,
+            name,
+            serialize: Box::new(move |value| {
+                value
+                    .downcast_ref::<T>()
+                    .map(|value| Box::new(serialize(value)) as Box<dyn erased_serde::Serialize>)
+            }),
+            deserialize: Box::new(move |deserializer| {
+                let value = erased_serde::deserialize::<V>(deserializer)?;
+
+                deserialize(value)
+                    .map(|value| Box::new(value) as BoxedValue)
+                    .ok_or_else(|| {
+                        erased_serde::Error::custom(format_args!("invalid value for `{name}`"))
+                    })
+            }),
+            attach: attach::<T>,
+        }));
+    }
+
+    pub(crate) fn get(&self, type_id: TypeId) -> Option<&SerdeHook> {
+        self.inner
+            .iter()
+            .find(|hook| hook.type_id == type_id)
+            .map(AsRef::as_ref)
+    }
+
+    pub(crate) fn get_by_name(&self, name: &str) -> Option<&SerdeHook> {
+        self.inner
+            .iter()
+            .find(|hook| hook.name == name)
+            .map(AsRef::as_ref)
+    }
+}
@@ -0,0 +1,845 @@
+//! Implementation of general [`Report`] serialization and deserialization.
+//!
+//! A [`Report`] is serialized as the tree of its frames. Frames, which only have a single source,
+//! are grouped together, a new level in the tree is only introduced if a frame has multiple
+//! sources. The serialized [`Report`] is a list of all current frames with the following output:
+//!
+//! ```json
+//! {
+//!     "frames": [
+//!         { "value": { "status_code": 404 } },
+//!         { "attachment": "printable attachment display output" },
+//!         { "context": { "message": "context display output", "value": null } },
+//!         { "location": { "file": "src/main.rs", "line": 12, "column": 5 } }
+//!     ],
+//!     "sources": [] // recursive render of the sources of the last frame
+//! }
+//! ```
+//!
+//! Contexts and printable attachments are serialized using their [`Display`] output, the
+//! [`Location`] a frame was created at is serialized as structured data. Values, for which a hook
+//! has been installed using [`Report::install_serde_hook`], are serialized as a map from the name
+//! of the hook to the serialized value. Other opaque attachments are skipped.
+//!
+//! As the concrete types are not known on the receiving side, deserializing a [`Report`] restores
+//! every context as a [`RemoteContext`], every location as a [`RemoteLocation`] and every
+//! printable attachment as a [`String`]. Values of installed hooks are restored as their original
+//! type.
+//!
+//! [`Display`]: core::fmt::Display
+
+mod hook;
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::{any::Any, error::Error, fmt, panic::Location};
+
+use serde_core::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{
+        self, DeserializeSeed, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess as _,
+        Visitor,
+    },
+    ser::{SerializeMap as _, SerializeStruct as _, SerializeStructVariant as _},
+};
+
+pub(crate) use self::hook::SerdeHooks;
+use self::hook::{BoxedValue, SerdeHook};
+use crate::{AttachmentKind, Frame, FrameKind, Report};
+
+fn with_hooks<T>(closure: impl FnOnce(&SerdeHooks) -> T) -> T {
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    {
+        Report::invoke_serde_hook(closure)
+    }
+
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
+    {
+        closure(&SerdeHooks::new())
+    }
+}
+
+/// Serializes the value of `frame` using the hook installed for its type.
+///
+/// Returns the name of the hook together with the serializable representation of the value.
+/// Deserialized contexts are serialized using the hook of their original context.
+#[cfg(feature = "opentelemetry")]
+pub(crate) fn hook_value(
+    frame: &Frame,
+) -> Option<(&'static str, Box<dyn erased_serde::Serialize>)> {
+    with_hooks(|hooks| {
+        let value = frame.downcast_ref::<RemoteContext>().map_or_else(
+            || SerializeValue::new(hooks, frame.as_any()),
+            |context| SerializeValue::new(hooks, context.value.as_deref()?),
+        )?;
+
+        Some((value.name, value.value))
+    })
+}
+
+/// A context of a [`Report`], which has been deserialized.
+///
+/// The type of the original context is not preserved during serialization, only its [`Display`]
+/// output, which is returned by [`message()`]. If a serde hook has been installed for the type of
+/// the original context, its value can be retrieved using [`value()`].
+///
+/// [`Display`]: core::fmt::Display
+/// [`message()`]: Self::message
+/// [`value()`]: Self::value
+pub struct RemoteContext {
+    message: String,
+    value: Option<BoxedValue>,
+}
+
+impl RemoteContext {
+    /// Returns the [`Display`] output of the original context.
+    ///
+    /// [`Display`]: core::fmt::Display
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the original context if it has been restored by a serde hook and is of type `T`.
+    ///
+    /// See [`Report::install_serde_hook`] for more information.
+    #[must_use]
+    pub fn value<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.value.as_ref()?.downcast_ref()
+    }
+}
+
+impl fmt::Debug for RemoteContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("RemoteContext")
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for RemoteContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.message)
+    }
+}
+
+impl Error for RemoteContext {}
+
+/// The [`Location`] of a frame inside of a [`Report`], which has been deserialized.
+///
+/// [`Location`] can only refer to files known at compile time, so a deserialized location is
+/// attached as `RemoteLocation` instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RemoteLocation {
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+impl RemoteLocation {
+    /// Returns the name of the source file.
+    #[must_use]
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the line number.
+    #[must_use]
+    pub const fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column.
+    #[must_use]
+    pub const fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl fmt::Display for RemoteLocation {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+const FRAME_VARIANTS: &[&str] = &["context", "attachment", "location", "value"];
+const NODE_FIELDS: &[&str] = &["frames", "sources"];
+const CONTEXT_FIELDS: &[&str] = &["message", "value"];
+const LOCATION_FIELDS: &[&str] = &["file", "line", "column"];
+
+struct SerializeValue {
+    name: &'static str,
+    value: Box<dyn erased_serde::Serialize>,
+}
+
+impl SerializeValue {
+    fn new(hooks: &SerdeHooks, value: &dyn Any) -> Option<Self> {
+        let hook = hooks.get(value.type_id())?;
+
+        Some(Self {
+            name: hook.name(),
+            value: hook.serialize(value)?,
+        })
+    }
+}
+
+impl Serialize for SerializeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(self.name, &*self.value)?;
+
+        map.end()
+    }
+}
+
+enum SerializeFrame<'a> {
+    Context {
+        message: String,
+        value: Option<SerializeValue>,
+    },
+    Attachment(String),
+    Location {
+        file: &'a str,
+        line: u32,
+        column: u32,
+    },
+    Value(SerializeValue),
+}
+
+impl<'a> SerializeFrame<'a> {
+    fn new(frame: &'a Frame, hooks: &SerdeHooks) -> Option<Self> {
+        match frame.kind() {
+            FrameKind::Context(context) => {
+                // deserialized contexts are serialized using the hook of their original context
+                let value = frame.downcast_ref::<RemoteContext>().map_or_else(
+                    || SerializeValue::new(hooks, frame.as_any()),
+                    |context| SerializeValue::new(hooks, context.value.as_deref()?),
+                );
+
+                Some(Self::Context {
+                    message: format!("{context}"),
+                    value,
+                })
+            }
+            FrameKind::Attachment(kind) => {
+                if let Some(value) = SerializeValue::new(hooks, frame.as_any()) {
+                    return Some(Self::Value(value));
+                }
+
+                match kind {
+                    AttachmentKind::Printable(attachment) => {
+                        Some(Self::Attachment(format!("{attachment}")))
+                    }
+                    AttachmentKind::Opaque(_) => {
+                        if let Some(location) = frame.downcast_ref::<Location<'static>>() {
+                            return Some(Self::Location {
+                                file: location.file(),
+                                line: location.line(),
+                                column: location.column(),
+                            });
+                        }
+
+                        // opaque attachments other than locations are ignored if no hook is
+                        // installed
+                        frame
+                            .downcast_ref::<RemoteLocation>()
+                            .map(|location| Self::Location {
+                                file: &location.file,
+                                line: location.line,
+                                column: location.column,
+                            })
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Serialize for SerializeFrame<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Context { message, value } => {
+                let mut context = serializer.serialize_struct_variant("Frame", 0, "context", 2)?;
+                context.serialize_field("message", message)?;
+                context.serialize_field("value", value)?;
+
+                context.end()
+            }
+            Self::Attachment(attachment) => {
+                serializer.serialize_newtype_variant("Frame", 1, "attachment", attachment)
+            }
+            Self::Location { file, line, column } => {
+                let mut location =
+                    serializer.serialize_struct_variant("Frame", 2, "location", 3)?;
+                location.serialize_field("file", file)?;
+                location.serialize_field("line", line)?;
+                location.serialize_field("column", column)?;
+
+                location.end()
+            }
+            Self::Value(value) => serializer.serialize_newtype_variant("Frame", 3, "value", value),
+        }
+    }
+}
+
+struct SerializeNode<'a> {
+    frames: Vec<SerializeFrame<'a>>,
+    sources: SerializeSources<'a>,
+}
+
+impl<'a> SerializeNode<'a> {
+    // follow the frames until a frame with either no or multiple sources is found
+    fn new(mut current: &'a Frame, hooks: &'a SerdeHooks) -> Self {
+        let mut frames = Vec::new();
+
+        loop {
+            frames.extend(SerializeFrame::new(current, hooks));
+
+            match current.sources() {
+                [source] => current = source,
+                sources => {
+                    return Self {
+                        frames,
+                        sources: SerializeSources { sources, hooks },
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl Serialize for SerializeNode<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut node = serializer.serialize_struct("Node", 2)?;
+        node.serialize_field("frames", &self.frames)?;
+        node.serialize_field("sources", &self.sources)?;
+
+        node.end()
+    }
+}
+
+struct SerializeSources<'a> {
+    sources: &'a [Frame],
+    hooks: &'a SerdeHooks,
+}
+
+impl<'a> SerializeSources<'a> {
+    // nodes without any serializable frame are replaced by their sources
+    fn collect(&self, nodes: &mut Vec<SerializeNode<'a>>) {
+        for source in self.sources {
+            let node = SerializeNode::new(source, self.hooks);
+
+            if node.frames.is_empty() {
+                node.sources.collect(nodes);
+            } else {
+                nodes.push(node);
+            }
+        }
+    }
+}
+
+impl Serialize for SerializeSources<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut nodes = Vec::new();
+        self.collect(&mut nodes);
+
+        serializer.collect_seq(nodes)
+    }
+}
+
+impl<C: Error + Send + Sync + 'static> Serialize for Report<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        with_hooks(|hooks| {
+            SerializeSources {
+                sources: self.current_frames_unchecked(),
+                hooks,
+            }
+            .serialize(serializer)
+        })
+    }
+}
+
+impl<C: Error + Send + Sync + 'static> Serialize for Report<[C]> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        with_hooks(|hooks| {
+            SerializeSources {
+                sources: self.current_frames_unchecked(),
+                hooks,
+            }
+            .serialize(serializer)
+        })
+    }
+}
+
+/// Generates an identifier for the fields or variants used in the serialized [`Report`].
+///
+/// Unknown identifiers are deserialized as `Unknown`, so that fields added in the future can be
+/// skipped.
+macro_rules! identifier {
+    ($name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        enum $name {
+            $($variant,)*
+            Unknown,
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct IdentifierVisitor;
+
+                impl Visitor<'_> for IdentifierVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("an identifier")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        let variants = [$($name::$variant),*];
+
+                        Ok(usize::try_from(value)
+                            .ok()
+                            .and_then(|index| variants.into_iter().nth(index))
+                            .unwrap_or($name::Unknown))
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            $($value => Ok($name::$variant),)*
+                            _ => Ok($name::Unknown),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(IdentifierVisitor)
+            }
+        }
+    };
+}
+
+identifier!(FrameVariant {
+    Context => "context",
+    Attachment => "attachment",
+    Location => "location",
+    Value => "value",
+});
+
+identifier!(ContextField {
+    Message => "message",
+    Value => "value",
+});
+
+identifier!(NodeField {
+    Frames => "frames",
+    Sources => "sources",
+});
+
+identifier!(LocationField {
+    File => "file",
+    Line => "line",
+    Column => "column",
+});
+
+struct LocationVisitor;
+
+impl<'de> Visitor<'de> for LocationVisitor {
+    type Value = RemoteLocation;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a location")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let file = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let line = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let column = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+        Ok(RemoteLocation { file, line, column })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut file = None;
+        let mut line = None;
+        let mut column = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                LocationField::File => file = Some(map.next_value()?),
+                LocationField::Line => line = Some(map.next_value()?),
+                LocationField::Column => column = Some(map.next_value()?),
+                LocationField::Unknown => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(RemoteLocation {
+            file: file.ok_or_else(|| de::Error::missing_field("file"))?,
+            line: line.ok_or_else(|| de::Error::missing_field("line"))?,
+            column: column.ok_or_else(|| de::Error::missing_field("column"))?,
+        })
+    }
+}
+
+struct DeserializedValue {
+    value: BoxedValue,
+    attach: fn(BoxedValue, Box<[Frame]>) -> Frame,
+}
+
+struct HookSeed<'a>(&'a SerdeHook);
+
+impl<'de> DeserializeSeed<'de> for HookSeed<'_> {
+    type Value = DeserializedValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+
+        self.0
+            .deserialize(&mut deserializer)
+            .map(|value| DeserializedValue {
+                value,
+                attach: self.0.attach(),
+            })
+            .map_err(de::Error::custom)
+    }
+}
+
+/// A value serialized by a serde hook.
+///
+/// If no hook is installed for the name of the value, it's skipped and `None` is returned.
+struct DeserializeValue(Option<DeserializedValue>);
+
+impl<'de> Deserialize<'de> for DeserializeValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = DeserializeValue;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map with a single entry")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let name = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+                let value = with_hooks(|hooks| match hooks.get_by_name(&name) {
+                    Some(hook) => map.next_value_seed(HookSeed(hook)).map(Some),
+                    None => map.next_value::<IgnoredAny>().map(|_| None),
+                })?;
+
+                if map.next_key::<IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(2, &self));
+                }
+
+                Ok(DeserializeValue(value))
+            }
+        }
+
+        deserializer.deserialize_map(ValueVisitor)
+    }
+}
+
+struct ContextVisitor;
+
+impl<'de> Visitor<'de> for ContextVisitor {
+    type Value = DeserializeFrame;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a context")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let message = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element::<Option<DeserializeValue>>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok(DeserializeFrame::Context {
+            message,
+            value: value.and_then(|DeserializeValue(value)| value),
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut message = None;
+        let mut value = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                ContextField::Message => message = Some(map.next_value()?),
+                ContextField::Value => {
+                    value = map
+                        .next_value::<Option<DeserializeValue>>()?
+                        .and_then(|DeserializeValue(value)| value);
+                }
+                ContextField::Unknown => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(DeserializeFrame::Context {
+            message: message.ok_or_else(|| de::Error::missing_field("message"))?,
+            value,
+        })
+    }
+}
+
+enum DeserializeFrame {
+    Context {
+        message: String,
+        value: Option<DeserializedValue>,
+    },
+    Attachment(String),
+    Location(RemoteLocation),
+    Value(Option<DeserializedValue>),
+}
+
+impl DeserializeFrame {
+    // values without an installed hook are skipped, in which case the sources are returned
+    fn into_frames(self, sources: Box<[Frame]>) -> Box<[Frame]> {
+        let frame = match self {
+            Self::Context { message, value } => Frame::from_context(
+                RemoteContext {
+                    message,
+                    value: value.map(|value| value.value),
+                },
+                sources,
+            ),
+            Self::Attachment(attachment) => Frame::from_printable_attachment(attachment, sources),
+            Self::Location(location) => Frame::from_attachment(location, sources),
+            Self::Value(Some(DeserializedValue { value, attach })) => attach(value, sources),
+            Self::Value(None) => return sources,
+        };
+
+        Box::new([frame])
+    }
+}
+
+impl<'de> Deserialize<'de> for DeserializeFrame {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FrameVisitor;
+
+        impl<'de> Visitor<'de> for FrameVisitor {
+            type Value = DeserializeFrame;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a frame")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                match data.variant()? {
+                    (FrameVariant::Context, variant) => {
+                        variant.struct_variant(CONTEXT_FIELDS, ContextVisitor)
+                    }
+                    (FrameVariant::Attachment, variant) => {
+                        variant.newtype_variant().map(DeserializeFrame::Attachment)
+                    }
+                    (FrameVariant::Location, variant) => variant
+                        .struct_variant(LOCATION_FIELDS, LocationVisitor)
+                        .map(DeserializeFrame::Location),
+                    (FrameVariant::Value, variant) => variant
+                        .newtype_variant()
+                        .map(|DeserializeValue(value)| DeserializeFrame::Value(value)),
+                    (FrameVariant::Unknown, _) => Err(de::Error::custom("unknown frame variant")),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum("Frame", FRAME_VARIANTS, FrameVisitor)
+    }
+}
+
+struct DeserializeNode {
+    frames: Vec<DeserializeFrame>,
+    sources: Vec<Self>,
+}
+
+impl DeserializeNode {
+    fn into_frames(self, frames: &mut Vec<Frame>) -> Result<(), &'static str> {
+        if self.sources.is_empty()
+            && !matches!(self.frames.last(), Some(DeserializeFrame::Context { .. }))
+        {
+            // The bottom of the frame tree is always a context, otherwise no context would be
+            // available when requesting the current context of the report.
+            return Err("every frame tree has to end with a context");
+        }
+
+        let mut sources = Vec::new();
+        for source in self.sources {
+            source.into_frames(&mut sources)?;
+        }
+
+        let mut sources = sources.into_boxed_slice();
+        for frame in self.frames.into_iter().rev() {
+            sources = frame.into_frames(sources);
+        }
+
+        frames.extend(sources.into_vec());
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeserializeNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NodeVisitor;
+
+        impl<'de> Visitor<'de> for NodeVisitor {
+            type Value = DeserializeNode;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a frame node")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let frames = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let sources = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                Ok(DeserializeNode { frames, sources })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut frames = None;
+                let mut sources = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        NodeField::Frames => frames = Some(map.next_value()?),
+                        NodeField::Sources => sources = Some(map.next_value()?),
+                        NodeField::Unknown => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                Ok(DeserializeNode {
+                    frames: frames.ok_or_else(|| de::Error::missing_field("frames"))?,
+                    sources: sources.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("Node", NODE_FIELDS, NodeVisitor)
+    }
+}
+
+fn deserialize_frames<'de, D>(deserializer: D) -> Result<Vec<Frame>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let nodes = Vec::<DeserializeNode>::deserialize(deserializer)?;
+
+    let mut frames = Vec::new();
+    for node in nodes {
+        node.into_frames(&mut frames).map_err(de::Error::custom)?;
+    }
+
+    if frames.is_empty() {
+        return Err(de::Error::invalid_length(0, &"at least one frame"));
+    }
+
+    Ok(frames)
+}
+
+impl<'de> Deserialize<'de> for Report<RemoteContext> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let frames = deserialize_frames(deserializer)?;
+
+        if frames.len() != 1 {
+            return Err(de::Error::invalid_length(
+                frames.len(),
+                &"a single current frame",
+            ));
+        }
+
+        Ok(Self::from_frames(frames))
+    }
+}
+
+impl<'de> Deserialize<'de> for Report<[RemoteContext]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_frames(deserializer).map(Self::from_frames)
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
 #[cfg(any(all(not(target_arch = "wasm32"), feature = "std"), feature = "tracing"))]
 use core::panic::Location;
 #[cfg(nightly)]
@@ -5,8 +6,9 @@ use core::{
     convert::Infallible,
     ops::{FromResidual, Try},
 };
+use core::{fmt, slice};
 
-use crate::Report;
+use crate::{Fingerprint, Frame, Report};
 
 /// The `Bomb` type is used to enforce proper usage of `ReportSink` at runtime.
 ///
@@ -89,6 +91,89 @@ impl Drop for Bomb {
         }
     }
 }
+
+/// The number of times a [`Report`] has been added to a deduplicating [`ReportSink`].
+///
+/// This is attached to the first sample of every group of reports with the same [`Fingerprint`],
+/// which has been added more than once. See [`ReportSink::deduplicate`] for more information.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Occurrences(usize);
+
+impl Occurrences {
+    /// Returns how often a report with the same [`Fingerprint`] has been added to the sink.
+    #[must_use]
+    pub const fn count(self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for Occurrences {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "×{} occurrences", self.0)
+    }
+}
+
+/// Reports with the same [`Fingerprint`], of which only the first few are kept.
+struct Group {
+    /// The position of the group in the order the fingerprints were first encountered.
+    order: usize,
+    count: usize,
+    samples: Vec<Frame>,
+}
+
+struct Deduplication {
+    samples: usize,
+    groups: BTreeMap<Fingerprint, Group>,
+}
+
+impl Deduplication {
+    const fn new(samples: usize) -> Self {
+        Self {
+            samples,
+            groups: BTreeMap::new(),
+        }
+    }
+
+    fn push(&mut self, frame: Frame) {
+        let order = self.groups.len();
+        let group = self
+            .groups
+            .entry(Fingerprint::from_frames(slice::from_ref(&frame)))
+            .or_insert_with(|| Group {
+                order,
+                count: 0,
+                samples: Vec::new(),
+            });
+
+        group.count += 1;
+        if group.samples.len() < self.samples {
+            group.samples.push(frame);
+        }
+    }
+
+    fn into_frames(self) -> Vec<Frame> {
+        let mut groups: Vec<_> = self.groups.into_values().collect();
+        groups.sort_unstable_by_key(|group| group.order);
+
+        let mut frames = Vec::new();
+        for group in groups {
+            let count = group.count;
+            let mut samples = group.samples.into_iter();
+
+            if let Some(first) = samples.next() {
+                frames.push(if count > 1 {
+                    Frame::from_printable_attachment(Occurrences(count), Box::new([first]))
+                } else {
+                    first
+                });
+            }
+            frames.extend(samples);
+        }
+
+        frames
+    }
+}
+
 /// A sink for collecting multiple [`Report`]s into a single [`Result`].
 ///
 /// [`ReportSink`] allows you to accumulate multiple errors or reports and then
@@ -142,9 +227,19 @@ impl Drop for Bomb {
 /// }
 /// # let _result = process_data();
 /// ```
+///
+/// # Deduplication
+///
+/// When validating large batches, many of the collected reports are often structurally identical
+/// and only differ in e.g. identifiers. By calling [`deduplicate`], the sink only keeps a limited
+/// number of samples for every [`Fingerprint`] and attaches the total number of [`Occurrences`] to
+/// the first sample.
+///
+/// [`deduplicate`]: ReportSink::deduplicate
 #[must_use]
 pub struct ReportSink<C> {
     report: Option<Report<[C]>>,
+    deduplication: Option<Deduplication>,
     bomb: Bomb,
 }
 
@@ -156,6 +251,7 @@ impl<C> ReportSink<C> {
     pub const fn new() -> Self {
         Self {
             report: None,
+            deduplication: None,
             bomb: Bomb::warn(),
         }
     }
@@ -166,10 +262,65 @@ impl<C> ReportSink<C> {
     pub const fn new_armed() -> Self {
         Self {
             report: None,
+            deduplication: None,
             bomb: Bomb::panic(),
         }
     }
 
+    /// Deduplicates the reports added to the sink by their [`Fingerprint`].
+    ///
+    /// For every fingerprint, only the first `samples` reports are kept, but at least one. If more
+    /// than one report with the same fingerprint has been added, the first sample has the total
+    /// number of [`Occurrences`] attached, which is rendered as `×137 occurrences`.
+    ///
+    /// Reports, which have already been added to the sink, are deduplicated as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use error_stack::{Occurrences, ReportSink};
+    /// # use std::io;
+    /// let mut sink = ReportSink::new().deduplicate(2);
+    ///
+    /// for id in 0..137 {
+    ///     sink.capture(io::Error::new(
+    ///         io::ErrorKind::NotFound,
+    ///         format!("entity {id} is missing"),
+    ///     ));
+    /// }
+    ///
+    /// let report = sink.finish().expect_err("should have failed");
+    /// assert_eq!(report.current_frames().len(), 2);
+    ///
+    /// let occurrences = report.current_frames()[0].downcast_ref::<Occurrences>();
+    /// assert_eq!(
+    ///     occurrences.map(|occurrences| occurrences.count()),
+    ///     Some(137)
+    /// );
+    /// ```
+    pub fn deduplicate(mut self, samples: usize) -> Self {
+        let mut deduplication = Deduplication::new(samples.max(1));
+
+        if let Some(report) = self.take_report() {
+            for frame in report.into_frames() {
+                deduplication.push(frame);
+            }
+        }
+
+        self.deduplication = Some(deduplication);
+        self
+    }
+
+    fn take_report(&mut self) -> Option<Report<[C]>> {
+        match self.deduplication.take() {
+            Some(deduplication) => {
+                let frames = deduplication.into_frames();
+                (!frames.is_empty()).then(|| Report::from_frames(frames))
+            }
+            None => self.report.take(),
+        }
+    }
+
     /// Adds a [`Report`] to the sink.
     ///
     /// # Examples
@@ -187,6 +338,13 @@ impl<C> ReportSink<C> {
     pub fn append(&mut self, report: impl Into<Report<[C]>>) {
         let report = report.into();
 
+        if let Some(deduplication) = self.deduplication.as_mut() {
+            for frame in report.into_frames() {
+                deduplication.push(frame);
+            }
+            return;
+        }
+
         match self.report.as_mut() {
             Some(existing) => existing.append(report),
             None => self.report = Some(report),
@@ -212,6 +370,13 @@ impl<C> ReportSink<C> {
     pub fn capture(&mut self, error: impl Into<Report<C>>) {
         let report = error.into();
 
+        if let Some(deduplication) = self.deduplication.as_mut() {
+            for frame in report.into_frames() {
+                deduplication.push(frame);
+            }
+            return;
+        }
+
         match self.report.as_mut() {
             Some(existing) => existing.push(report),
             None => self.report = Some(report.into()),
@@ -279,7 +444,7 @@ impl<C> ReportSink<C> {
     /// ```
     pub fn finish(mut self) -> Result<(), Report<[C]>> {
         self.bomb.defuse();
-        self.report.map_or(Ok(()), Err)
+        self.take_report().map_or(Ok(()), Err)
     }
 
     /// Finishes the sink and returns a [`Result`] with a custom success value.
@@ -302,7 +467,7 @@ impl<C> ReportSink<C> {
     /// [`finish`]: ReportSink::finish
     pub fn finish_with<T>(mut self, ok: impl FnOnce() -> T) -> Result<T, Report<[C]>> {
         self.bomb.defuse();
-        self.report.map_or_else(|| Ok(ok()), Err)
+        self.take_report().map_or_else(|| Ok(ok()), Err)
     }
 
     /// Finishes the sink and returns a [`Result`] with a default success value.
@@ -325,7 +490,7 @@ impl<C> ReportSink<C> {
     /// [`finish`]: ReportSink::finish
     pub fn finish_default<T: Default>(mut self) -> Result<T, Report<[C]>> {
         self.bomb.defuse();
-        self.report.map_or_else(|| Ok(T::default()), Err)
+        self.take_report().map_or_else(|| Ok(T::default()), Err)
     }
 
     /// Finishes the sink and returns a [`Result`] with a provided success value.
@@ -348,7 +513,7 @@ impl<C> ReportSink<C> {
     /// [`finish`]: ReportSink::finish
     pub fn finish_ok<T>(mut self, ok: T) -> Result<T, Report<[C]>> {
         self.bomb.defuse();
-        self.report.map_or(Ok(ok), Err)
+        self.take_report().map_or(Ok(ok), Err)
     }
 }
 
@@ -364,6 +529,7 @@ impl<C> FromResidual for ReportSink<C> {
         match residual {
             Err(report) => Self {
                 report: Some(report),
+                deduplication: None,
                 bomb: Bomb::default(),
             },
         }
@@ -379,13 +545,14 @@ impl<C> Try for ReportSink<C> {
     fn from_output((): ()) -> Self {
         Self {
             report: None,
+            deduplication: None,
             bomb: Bomb::default(),
         }
     }
 
     fn branch(mut self) -> core::ops::ControlFlow<Self::Residual, Self::Output> {
         self.bomb.defuse();
-        self.report.map_or(
+        self.take_report().map_or(
             core::ops::ControlFlow::Continue(()), //
             |report| core::ops::ControlFlow::Break(Err(report)),
         )
@@ -397,7 +564,10 @@ mod test {
     use alloc::collections::BTreeSet;
     use core::fmt::Display;
 
-    use crate::{Report, sink::ReportSink};
+    use crate::{
+        Report,
+        sink::{Occurrences, ReportSink},
+    };
 
     #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     struct TestError(u8);
@@ -669,4 +839,70 @@ mod test {
         let value = sink.finish_ok(8).expect("should have succeeded");
         assert_eq!(value, 8);
     }
+
+    #[test]
+    fn deduplicate() {
+        let mut sink = ReportSink::new().deduplicate(2);
+
+        for value in 0..5 {
+            sink.capture(TestError(value));
+        }
+
+        let report = sink.finish().expect_err("should have failed");
+
+        let frames = report.current_frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[0].downcast_ref::<Occurrences>().copied(),
+            Some(Occurrences(5))
+        );
+        assert!(!frames[1].is::<Occurrences>());
+
+        let contexts: BTreeSet<_> = report.current_contexts().collect();
+        assert_eq!(contexts.len(), 2);
+        assert!(contexts.contains(&TestError(0)));
+        assert!(contexts.contains(&TestError(1)));
+    }
+
+    #[test]
+    fn deduplicate_distinct() {
+        let mut sink = ReportSink::new().deduplicate(1);
+
+        sink.capture(TestError(0));
+        sink.capture(TestError(1));
+
+        let report = sink.finish().expect_err("should have failed");
+
+        let frames = report.current_frames();
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|frame| !frame.is::<Occurrences>()));
+    }
+
+    #[test]
+    fn deduplicate_existing() {
+        let mut sink = ReportSink::new();
+
+        for value in 0..3 {
+            sink.append(Report::new(TestError(value)));
+        }
+
+        let report = sink
+            .deduplicate(1)
+            .finish()
+            .expect_err("should have failed");
+
+        let frames = report.current_frames();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(
+            frames[0].downcast_ref::<Occurrences>().copied(),
+            Some(Occurrences(3))
+        );
+    }
+
+    #[test]
+    fn deduplicate_ok() {
+        let sink: ReportSink<TestError> = ReportSink::new().deduplicate(1);
+
+        sink.finish().expect("should have succeeded");
+    }
 }
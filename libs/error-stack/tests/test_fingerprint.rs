@@ -0,0 +1,77 @@
+#![cfg_attr(nightly, feature(error_generic_member_access))]
+
+mod common;
+
+use common::*;
+use error_stack::Report;
+
+fn create(value: u32) -> Report<ContextA> {
+    create_report()
+        .attach(PrintableC(value))
+        .change_context(ContextA(value))
+}
+
+#[test]
+fn same_shape() {
+    assert_eq!(create(1).fingerprint(), create(2).fingerprint());
+}
+
+#[test]
+fn different_location() {
+    let report_a = create_report().change_context(ContextA(0));
+    let report_b = create_report().change_context(ContextA(0));
+
+    assert_ne!(report_a.fingerprint(), report_b.fingerprint());
+}
+
+#[test]
+fn different_context() {
+    let report_a = create_report().change_context(ContextA(0));
+    let report_b = create_report().change_context(ContextB(0));
+
+    assert_ne!(report_a.fingerprint(), report_b.fingerprint());
+}
+
+#[test]
+fn different_attachment() {
+    let mut fingerprints = Vec::new();
+    for value in 0..3 {
+        let report = create_report();
+        fingerprints.push(
+            match value {
+                0 => report.attach(PrintableA(0)),
+                1 => report.attach(PrintableB(0)),
+                _ => report.attach_opaque(AttachmentA(0)),
+            }
+            .fingerprint(),
+        );
+    }
+
+    assert_ne!(fingerprints[0], fingerprints[1]);
+    assert_ne!(fingerprints[0], fingerprints[2]);
+    assert_ne!(fingerprints[1], fingerprints[2]);
+}
+
+#[test]
+fn different_sources() {
+    let mut fingerprints = Vec::new();
+    for sources in 1..=2 {
+        let mut report = create_report().expand();
+        for _ in 1..sources {
+            report.push(create_report());
+        }
+        fingerprints.push(report.change_context(ContextA(0)).fingerprint());
+    }
+
+    assert_ne!(fingerprints[0], fingerprints[1]);
+}
+
+#[test]
+fn display() {
+    let fingerprint = create(0).fingerprint();
+
+    assert_eq!(
+        fingerprint.to_string(),
+        format!("{:016x}", fingerprint.as_u64())
+    );
+}
@@ -0,0 +1,296 @@
+#![cfg(feature = "opentelemetry")]
+#![cfg_attr(nightly, feature(error_generic_member_access))]
+
+mod common;
+
+use opentelemetry::{Array, Key, KeyValue, Value};
+
+use crate::common::{AttachmentA, ContextA, PrintableA, PrintableC, create_report};
+
+fn attribute<'a>(attributes: &'a [KeyValue], key: &'static str) -> Option<&'a Value> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.key == Key::from_static_str(key))
+        .map(|attribute| &attribute.value)
+}
+
+#[test]
+fn exception() {
+    let report = create_report()
+        .attach(PrintableA(0))
+        .change_context(ContextA(0));
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "exception.type"),
+        Some(&Value::from(core::any::type_name::<ContextA>()))
+    );
+    assert_eq!(
+        attribute(&attributes, "exception.message"),
+        Some(&Value::from("context A"))
+    );
+    assert_eq!(
+        attribute(&attributes, "code.filepath"),
+        Some(&Value::from(file!()))
+    );
+    assert!(matches!(
+        attribute(&attributes, "code.lineno"),
+        Some(Value::I64(_))
+    ));
+}
+
+#[test]
+fn contexts() {
+    let report = create_report()
+        .attach(PrintableA(0))
+        .change_context(ContextA(0))
+        .attach(PrintableC(1));
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.message"),
+        Some(&Value::from("context A"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.attachments.0.message"),
+        Some(&Value::from("printable C: 1"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.sources"),
+        Some(&Value::Array(Array::I64(vec![1])))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.code.filepath"),
+        Some(&Value::from(file!()))
+    );
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.message"),
+        Some(&Value::from("root error"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.attachments.0.message"),
+        Some(&Value::from("printable A"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.sources"),
+        None
+    );
+    assert!(matches!(
+        attribute(&attributes, "error_stack.contexts.1.code.filepath"),
+        Some(Value::String(file)) if file.as_str().ends_with("common.rs")
+    ));
+}
+
+#[test]
+fn multiple_sources() {
+    let mut report = create_report().attach(PrintableC(1)).expand();
+    report.push(create_report().attach(PrintableC(2)));
+
+    let report = report.attach(PrintableC(3)).change_context(ContextA(0));
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.sources"),
+        Some(&Value::Array(Array::I64(vec![1, 2])))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.attachments.0.message"),
+        Some(&Value::from("printable C: 3"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.attachments.1.message"),
+        Some(&Value::from("printable C: 1"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.2.attachments.0.message"),
+        Some(&Value::from("printable C: 3"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.2.attachments.1.message"),
+        Some(&Value::from("printable C: 2"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.3.message"),
+        None
+    );
+}
+
+#[test]
+fn multiple_current_contexts() {
+    let mut report = create_report().expand();
+    report.push(create_report());
+
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.message"),
+        Some(&Value::from("root error"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.1.message"),
+        Some(&Value::from("root error"))
+    );
+}
+
+#[test]
+fn opaque_attachment() {
+    let report = create_report().attach_opaque(AttachmentA(1));
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.attachments.0.type"),
+        Some(&Value::from(core::any::type_name::<AttachmentA>()))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.contexts.0.attachments.0.message"),
+        None
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_hook() {
+    use error_stack::Report;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Retry {
+        attempts: u8,
+        delays: Vec<u32>,
+        reason: Option<String>,
+    }
+
+    Report::install_serde_hook::<Retry, Retry>(
+        "retry",
+        |retry| Retry {
+            attempts: retry.attempts,
+            delays: retry.delays.clone(),
+            reason: retry.reason.clone(),
+        },
+        Some,
+    );
+
+    let report = create_report()
+        .attach_opaque(Retry {
+            attempts: 2,
+            delays: vec![100, 200],
+            reason: None,
+        })
+        .attach(PrintableA(0));
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(
+            &attributes,
+            "error_stack.contexts.0.attachments.1.value.retry.attempts"
+        ),
+        Some(&Value::I64(2))
+    );
+    assert_eq!(
+        attribute(
+            &attributes,
+            "error_stack.contexts.0.attachments.1.value.retry.delays.0"
+        ),
+        Some(&Value::I64(100))
+    );
+    assert_eq!(
+        attribute(
+            &attributes,
+            "error_stack.contexts.0.attachments.1.value.retry.delays.1"
+        ),
+        Some(&Value::I64(200))
+    );
+    assert_eq!(
+        attribute(
+            &attributes,
+            "error_stack.contexts.0.attachments.1.value.retry.reason"
+        ),
+        None
+    );
+}
+
+#[cfg(feature = "spantrace")]
+#[test]
+fn span_trace() {
+    use tracing_error::ErrorLayer;
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    let subscriber = tracing_subscriber::Registry::default().with(ErrorLayer::default());
+    let report = tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!("outer")
+            .in_scope(|| tracing::info_span!("inner", attempt = 1).in_scope(create_report))
+    });
+    let attributes = report.exception_attributes();
+
+    assert_eq!(
+        attribute(&attributes, "error_stack.spantrace.0.name"),
+        Some(&Value::from("inner"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.spantrace.0.fields"),
+        Some(&Value::from("attempt=1"))
+    );
+    assert_eq!(
+        attribute(&attributes, "error_stack.spantrace.1.name"),
+        Some(&Value::from("outer"))
+    );
+    assert_eq!(attribute(&attributes, "error_stack.spantrace.2.name"), None);
+}
+
+#[cfg(feature = "spantrace")]
+#[test]
+fn record_exception_with_tracing_opentelemetry() {
+    use opentelemetry::trace::{Span as _, Tracer as _, TracerProvider as _};
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use tracing_error::ErrorLayer;
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    let exporter = InMemorySpanExporter::default();
+    let provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let tracer = provider.tracer("error-stack");
+
+    let subscriber = tracing_subscriber::Registry::default()
+        .with(ErrorLayer::default())
+        .with(tracing_opentelemetry::layer().with_tracer(tracer.clone()));
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!("request").in_scope(|| {
+            let report = create_report().change_context(ContextA(0));
+
+            let mut span = tracer.start("handle");
+            report.record_exception(&mut span);
+            span.end();
+        });
+    });
+
+    let spans = exporter
+        .get_finished_spans()
+        .expect("spans should be exported");
+    let request = spans
+        .iter()
+        .find(|span| span.name == "request")
+        .expect("span should be exported");
+
+    let span = spans
+        .iter()
+        .find(|span| span.name == "handle")
+        .expect("span should be exported");
+    let [link] = span.links.links.as_slice() else {
+        panic!("expected a single link, got {:?}", span.links.links);
+    };
+    assert_eq!(link.span_context, request.span_context);
+
+    let [event] = span.events.events.as_slice() else {
+        panic!("expected a single event, got {:?}", span.events.events);
+    };
+    assert_eq!(event.name, "exception");
+    assert_eq!(
+        attribute(&event.attributes, "exception.message"),
+        Some(&Value::from("context A"))
+    );
+    assert_eq!(
+        attribute(&event.attributes, "error_stack.spantrace.0.name"),
+        Some(&Value::from("request"))
+    );
+}
@@ -6,6 +6,9 @@
 #![cfg(not(miri))]
 #![cfg_attr(nightly, feature(error_generic_member_access))]
 
+use core::{error::Error, fmt};
+
+use error_stack::{RemoteContext, Report};
 use insta::assert_ron_snapshot;
 
 use crate::common::{ContextA, PrintableA, PrintableC, create_report};
@@ -15,7 +18,9 @@ mod common;
 fn prepare() -> impl Drop {
     std::env::set_var("RUST_LIB_BACKTRACE", "0");
 
-    let settings = insta::Settings::clone_current();
+    let mut settings = insta::Settings::clone_current();
+    // line and column numbers change whenever the tests are modified
+    settings.add_filter(r"(line|column): \d+", "$1: 0");
 
     settings.bind_to_scope()
 }
@@ -68,3 +73,245 @@ fn multiple_sources_at_root() {
 
     assert_ron_snapshot!(report_a);
 }
+
+/// Serializes the report, deserializes it and checks that serializing the deserialized report
+/// results in the same output.
+fn round_trip<T: serde::Serialize>(report: &T) -> Report<[RemoteContext]> {
+    let serialized = serde_json::to_value(report).expect("report should be serializable");
+    let deserialized: Report<[RemoteContext]> =
+        serde_json::from_value(serialized.clone()).expect("report should be deserializable");
+
+    assert_eq!(
+        serde_json::to_value(&deserialized).expect("report should be serializable"),
+        serialized
+    );
+
+    deserialized
+}
+
+#[test]
+fn deserialize() {
+    let _guard = prepare();
+
+    let report = create_report()
+        .attach(PrintableA(2))
+        .change_context(ContextA(2));
+
+    let deserialized = round_trip(&report);
+    let mut contexts = deserialized.current_contexts();
+
+    assert_eq!(
+        contexts.next().map(RemoteContext::message),
+        Some("context A")
+    );
+    assert!(contexts.next().is_none());
+    assert_eq!(
+        deserialized
+            .frames()
+            .filter_map(|frame| frame.downcast_ref::<String>())
+            .collect::<Vec<_>>(),
+        ["printable A"]
+    );
+    assert_eq!(
+        deserialized
+            .frames()
+            .filter_map(|frame| frame.downcast_ref::<error_stack::RemoteLocation>())
+            .map(error_stack::RemoteLocation::file)
+            .collect::<Vec<_>>(),
+        [file!(), "libs/error-stack/tests/common.rs"]
+    );
+}
+
+#[test]
+fn deserialize_multiple_sources() {
+    let _guard = prepare();
+
+    let mut report_a = create_report().attach(PrintableC(1)).expand();
+    let report_b = create_report().attach(PrintableC(2));
+
+    report_a.push(report_b);
+
+    let report_a = report_a
+        .attach(PrintableC(3))
+        .change_context(ContextA(2))
+        .attach(PrintableC(4));
+
+    let deserialized = round_trip(&report_a);
+    assert_eq!(
+        deserialized
+            .frames()
+            .filter(|frame| frame.is::<RemoteContext>())
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn deserialize_multiple_sources_at_root() {
+    let _guard = prepare();
+
+    let mut report_a = create_report().attach(PrintableC(1)).expand();
+    let report_b = create_report().attach(PrintableC(2));
+
+    report_a.push(report_b);
+
+    let deserialized = round_trip(&report_a);
+    assert_eq!(deserialized.current_frames().len(), 2);
+
+    let serialized = serde_json::to_value(&report_a).expect("report should be serializable");
+    serde_json::from_value::<Report<RemoteContext>>(serialized)
+        .expect_err("report with multiple current frames should be rejected");
+}
+
+#[test]
+fn deserialize_without_context() {
+    let serialized = serde_json::json!([{
+        "frames": [{ "attachment": "printable A" }],
+        "sources": [],
+    }]);
+
+    serde_json::from_value::<Report<[RemoteContext]>>(serialized)
+        .expect_err("report without a context should be rejected");
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct StatusCode(u16);
+
+#[test]
+fn attachment_hook() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<StatusCode, u16>(
+        "status_code",
+        |StatusCode(code)| *code,
+        |code| Some(StatusCode(code)),
+    );
+
+    let report = create_report().attach_opaque(StatusCode(404));
+    assert_ron_snapshot!(report);
+
+    let deserialized = round_trip(&report);
+    assert_eq!(
+        deserialized.downcast_ref::<StatusCode>(),
+        Some(&StatusCode(404))
+    );
+}
+
+#[derive(Debug)]
+struct SqlError {
+    state: String,
+}
+
+impl fmt::Display for SqlError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("sql error")
+    }
+}
+
+impl Error for SqlError {}
+
+#[test]
+fn context_hook() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<SqlError, String>(
+        "sql_state",
+        |error| error.state.clone(),
+        |state| Some(SqlError { state }),
+    );
+
+    let report = create_report().change_context(SqlError {
+        state: "23505".to_owned(),
+    });
+    assert_ron_snapshot!(report);
+
+    let deserialized = round_trip(&report);
+    let context = deserialized
+        .current_contexts()
+        .next()
+        .expect("report should have a context");
+
+    assert_eq!(context.message(), "sql error");
+    assert_eq!(
+        context
+            .value::<SqlError>()
+            .map(|error| error.state.as_str()),
+        Some("23505")
+    );
+}
+
+#[derive(Debug)]
+struct Percentage(u8);
+
+#[test]
+fn invalid_hook_value() {
+    Report::install_serde_hook::<Percentage, u8>(
+        "percentage",
+        |Percentage(value)| *value,
+        |value| (value <= 100).then_some(Percentage(value)),
+    );
+
+    let serialized = serde_json::json!([{
+        "frames": [
+            { "value": { "percentage": 200 } },
+            { "context": { "message": "root error", "value": null } },
+        ],
+        "sources": [],
+    }]);
+
+    serde_json::from_value::<Report<[RemoteContext]>>(serialized)
+        .expect_err("invalid value should be rejected");
+}
+
+#[test]
+fn unknown_hook_is_skipped() {
+    let serialized = serde_json::json!([{
+        "frames": [
+            { "value": { "unknown": 1 } },
+            { "context": { "message": "root error", "value": { "unknown": 2 } } },
+        ],
+        "sources": [],
+    }]);
+
+    let report = serde_json::from_value::<Report<[RemoteContext]>>(serialized)
+        .expect("unknown values should be skipped");
+
+    assert_eq!(report.frames().count(), 1);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RetryCount(u8);
+
+#[derive(Debug)]
+struct RetryLimit(u8);
+
+#[test]
+fn reentrant_hook() {
+    // Hooks are invoked without holding the global hook lock, so a hook may install other hooks
+    // or (de)serialize reports itself.
+    Report::install_serde_hook::<RetryCount, u8>(
+        "retry_count",
+        |RetryCount(count)| {
+            Report::install_serde_hook::<RetryLimit, u8>(
+                "retry_limit",
+                |RetryLimit(limit)| *limit,
+                |limit| Some(RetryLimit(limit)),
+            );
+
+            *count
+        },
+        |count| {
+            serde_json::to_value(create_report()).expect("nested report should serialize");
+
+            Some(RetryCount(count))
+        },
+    );
+
+    let report = create_report().attach_opaque(RetryCount(3));
+    let deserialized = round_trip(&report);
+
+    assert_eq!(
+        deserialized.downcast_ref::<RetryCount>(),
+        Some(&RetryCount(3))
+    );
+}